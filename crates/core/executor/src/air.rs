@@ -9,6 +9,11 @@ use monerochan_stark::shape::Shape;
 use strum::{EnumIter, IntoEnumIterator, IntoStaticStr};
 use subenum::subenum;
 
+use crate::{
+    events::{ECDSA_NUM_BITS, MSM_NUM_WINDOWS, MSM_WINDOW_BITS},
+    syscalls::SyscallCode,
+};
+
 /// RV32IM AIR Identifiers.
 ///
 /// These identifiers are for the various chips in the rv32im prover. We need them in the
@@ -16,6 +21,10 @@ use subenum::subenum;
 ///
 /// The [`CoreAirId`]s are the AIRs that are not part of precompile shards and not the program or
 /// byte AIR.
+///
+/// The identifiers are declared in the order in which the machine generates the traces of their
+/// chips. The identifiers added after the byte AIR keep their discriminants, so the discriminants
+/// are not in declaration order.
 #[subenum(CoreAirId)]
 #[derive(
     Debug,
@@ -86,6 +95,86 @@ pub enum RiscvAirId {
     Bn254Fp2MulAssign = 24,
     /// The bls12-381 decompress chip.
     Bls12381Decompress = 25,
+    /// The bn254 fp6 mul assign chip.
+    Bn254Fp6MulAssign = 45,
+    /// The bn254 fp12 mul assign chip.
+    Bn254Fp12MulAssign = 46,
+    /// The bn254 g2 add assign chip.
+    Bn254G2AddAssign = 47,
+    /// The bn254 g2 double assign chip.
+    Bn254G2DoubleAssign = 48,
+    /// The bls12-381 fp6 mul assign chip.
+    Bls12381Fp6MulAssign = 49,
+    /// The bls12-381 fp12 mul assign chip.
+    Bls12381Fp12MulAssign = 50,
+    /// The bls12-381 g2 add assign chip.
+    Bls12381G2AddAssign = 51,
+    /// The bls12-381 g2 double assign chip.
+    Bls12381G2DoubleAssign = 52,
+    /// The secp256k1 scalar mul assign chip.
+    Secp256k1ScalarMulAssign = 53,
+    /// The secp256k1 msm assign chip.
+    Secp256k1MsmAssign = 54,
+    /// The secp256r1 scalar mul assign chip.
+    Secp256r1ScalarMulAssign = 55,
+    /// The secp256r1 msm assign chip.
+    Secp256r1MsmAssign = 56,
+    /// The bn254 scalar mul assign chip.
+    Bn254ScalarMulAssign = 57,
+    /// The bn254 msm assign chip.
+    Bn254MsmAssign = 58,
+    /// The bls12-381 scalar mul assign chip.
+    Bls12381ScalarMulAssign = 59,
+    /// The bls12-381 msm assign chip.
+    Bls12381MsmAssign = 60,
+    /// The uint256 add sub chip.
+    Uint256AddSub = 61,
+    /// The uint2048 mul mod chip.
+    Uint2048MulMod = 62,
+    /// The uint4096 mul mod chip.
+    Uint4096MulMod = 63,
+    /// The secp256k1 base field op assign chip.
+    Secp256k1FpOpAssign = 64,
+    /// The secp256k1 scalar field op assign chip.
+    Secp256k1FnOpAssign = 65,
+    /// The secp256r1 base field op assign chip.
+    Secp256r1FpOpAssign = 66,
+    /// The secp256r1 scalar field op assign chip.
+    Secp256r1FnOpAssign = 67,
+    /// The x25519 ladder step chip.
+    X25519LadderStep = 68,
+    /// The aes encrypt block chip.
+    AesEncryptBlock = 69,
+    /// The chacha20 block chip.
+    ChaCha20Block = 70,
+    /// The pallas add assign chip.
+    PallasAddAssign = 71,
+    /// The pallas double assign chip.
+    PallasDoubleAssign = 72,
+    /// The vesta add assign chip.
+    VestaAddAssign = 73,
+    /// The vesta double assign chip.
+    VestaDoubleAssign = 74,
+    /// The grumpkin add assign chip.
+    GrumpkinAddAssign = 75,
+    /// The grumpkin double assign chip.
+    GrumpkinDoubleAssign = 76,
+    /// The secp256k1 ecrecover chip.
+    Secp256k1Ecrecover = 77,
+    /// The secp256r1 ecrecover chip.
+    Secp256r1Ecrecover = 78,
+    /// The secp256k1 ECDSA verify chip.
+    Secp256k1EcdsaVerify = 79,
+    /// The secp256r1 ECDSA verify chip.
+    Secp256r1EcdsaVerify = 80,
+    /// The chip for user precompile slot 0.
+    UserPrecompile0 = 81,
+    /// The chip for user precompile slot 1.
+    UserPrecompile1 = 82,
+    /// The chip for user precompile slot 2.
+    UserPrecompile2 = 83,
+    /// The chip for user precompile slot 3.
+    UserPrecompile3 = 84,
     /// The syscall core chip.
     #[subenum(CoreAirId)]
     SyscallCore = 26,
@@ -209,21 +298,98 @@ impl RiscvAirId {
                 RiscvAirId::Bn254FpOpAssign |
                 RiscvAirId::Bn254Fp2AddSubAssign |
                 RiscvAirId::Bn254Fp2MulAssign |
-                RiscvAirId::Bls12381Decompress
+                RiscvAirId::Bls12381Decompress |
+                RiscvAirId::Bn254Fp6MulAssign |
+                RiscvAirId::Bn254Fp12MulAssign |
+                RiscvAirId::Bn254G2AddAssign |
+                RiscvAirId::Bn254G2DoubleAssign |
+                RiscvAirId::Bls12381Fp6MulAssign |
+                RiscvAirId::Bls12381Fp12MulAssign |
+                RiscvAirId::Bls12381G2AddAssign |
+                RiscvAirId::Bls12381G2DoubleAssign |
+                RiscvAirId::Secp256k1ScalarMulAssign |
+                RiscvAirId::Secp256k1MsmAssign |
+                RiscvAirId::Secp256r1ScalarMulAssign |
+                RiscvAirId::Secp256r1MsmAssign |
+                RiscvAirId::Bn254ScalarMulAssign |
+                RiscvAirId::Bn254MsmAssign |
+                RiscvAirId::Bls12381ScalarMulAssign |
+                RiscvAirId::Bls12381MsmAssign |
+                RiscvAirId::Uint256AddSub |
+                RiscvAirId::Uint2048MulMod |
+                RiscvAirId::Uint4096MulMod |
+                RiscvAirId::Secp256k1FpOpAssign |
+                RiscvAirId::Secp256k1FnOpAssign |
+                RiscvAirId::Secp256r1FpOpAssign |
+                RiscvAirId::Secp256r1FnOpAssign |
+                RiscvAirId::X25519LadderStep |
+                RiscvAirId::AesEncryptBlock |
+                RiscvAirId::ChaCha20Block |
+                RiscvAirId::PallasAddAssign |
+                RiscvAirId::PallasDoubleAssign |
+                RiscvAirId::VestaAddAssign |
+                RiscvAirId::VestaDoubleAssign |
+                RiscvAirId::GrumpkinAddAssign |
+                RiscvAirId::GrumpkinDoubleAssign |
+                RiscvAirId::Secp256k1Ecrecover |
+                RiscvAirId::Secp256r1Ecrecover |
+                RiscvAirId::Secp256k1EcdsaVerify |
+                RiscvAirId::Secp256r1EcdsaVerify |
+                RiscvAirId::UserPrecompile0 |
+                RiscvAirId::UserPrecompile1 |
+                RiscvAirId::UserPrecompile2 |
+                RiscvAirId::UserPrecompile3
         )
     }
 
     /// The number of rows in the AIR produced by each event.
+    ///
+    /// The user precompile slots count one row per event here. Their actual number of rows is
+    /// given by [`crate::syscalls::UserSyscall::rows_per_event`].
     #[must_use]
     pub fn rows_per_event(&self) -> usize {
         match self {
             Self::ShaCompress => 80,
             Self::ShaExtend => 48,
             Self::KeccakPermute => 24,
+            Self::AesEncryptBlock => 15,
+            Self::ChaCha20Block => 10,
+            Self::Secp256k1Ecrecover |
+            Self::Secp256r1Ecrecover |
+            Self::Secp256k1EcdsaVerify |
+            Self::Secp256r1EcdsaVerify => ECDSA_NUM_BITS,
             _ => 1,
         }
     }
 
+    /// The syscall code of the user precompile slot this ID represents, if any.
+    #[must_use]
+    pub fn user_syscall_code(self) -> Option<SyscallCode> {
+        match self {
+            Self::UserPrecompile0 => Some(SyscallCode::USER_PRECOMPILE_0),
+            Self::UserPrecompile1 => Some(SyscallCode::USER_PRECOMPILE_1),
+            Self::UserPrecompile2 => Some(SyscallCode::USER_PRECOMPILE_2),
+            Self::UserPrecompile3 => Some(SyscallCode::USER_PRECOMPILE_3),
+            _ => None,
+        }
+    }
+
+    /// The number of syscalls each event of the AIR sends to other precompile AIRs.
+    #[must_use]
+    pub fn nested_syscalls_per_event(&self) -> usize {
+        match self {
+            Self::Secp256k1ScalarMulAssign |
+            Self::Secp256r1ScalarMulAssign |
+            Self::Bn254ScalarMulAssign |
+            Self::Bls12381ScalarMulAssign => MSM_NUM_WINDOWS * (MSM_WINDOW_BITS + 1),
+            Self::Secp256k1MsmAssign |
+            Self::Secp256r1MsmAssign |
+            Self::Bn254MsmAssign |
+            Self::Bls12381MsmAssign => MSM_NUM_WINDOWS * (MSM_WINDOW_BITS + 2),
+            _ => 0,
+        }
+    }
+
     /// Returns the string representation of the AIR.
     #[must_use]
     pub fn as_str(&self) -> &'static str {
@@ -3,6 +3,7 @@ use core::mem::take;
 use crate::{
     hook::{hookify, BoxedHook, HookEnv, HookRegistry},
     subproof::SubproofVerifier,
+    syscalls::UserSyscalls,
 };
 use hashbrown::HashMap;
 use std::io::Write;
@@ -34,6 +35,9 @@ pub struct MONEROCHANContext<'a> {
 
     /// The IO options for the [`MONEROCHANExecutor`].
     pub io_options: IoOptions<'a>,
+
+    /// The executor side of the user precompiles of the machine.
+    pub user_syscalls: UserSyscalls,
 }
 
 impl Default for MONEROCHANContext<'_> {
@@ -51,6 +55,7 @@ pub struct MONEROCHANContextBuilder<'a> {
     deferred_proof_verification: bool,
     calculate_gas: bool,
     io_options: IoOptions<'a>,
+    user_syscalls: UserSyscalls,
 }
 
 impl Default for MONEROCHANContextBuilder<'_> {
@@ -64,6 +69,7 @@ impl Default for MONEROCHANContextBuilder<'_> {
             deferred_proof_verification: true,
             calculate_gas: true,
             io_options: IoOptions::default(),
+            user_syscalls: UserSyscalls::default(),
         }
     }
 }
@@ -126,6 +132,7 @@ impl<'a> MONEROCHANContextBuilder<'a> {
             deferred_proof_verification,
             calculate_gas,
             io_options: take(&mut self.io_options),
+            user_syscalls: take(&mut self.user_syscalls),
         }
     }
 
@@ -189,6 +196,14 @@ impl<'a> MONEROCHANContextBuilder<'a> {
         self
     }
 
+    /// Set the executor side of the user precompiles of the machine.
+    ///
+    /// The syscalls must match the user precompile chips of the machine that proves the execution.
+    pub fn user_syscalls(&mut self, user_syscalls: UserSyscalls) -> &mut Self {
+        self.user_syscalls = user_syscalls;
+        self
+    }
+
     /// Set the `stdout` writer.
     pub fn stdout<W: IoWriter>(&mut self, writer: &'a mut W) -> &mut Self {
         self.io_options.stdout = Some(writer);
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::{ByteOpcode, Opcode};
 
 /// The number of different byte operations.
-pub const NUM_BYTE_OPS: usize = 9;
+pub const NUM_BYTE_OPS: usize = 10;
 
 /// Byte Lookup Event.
 ///
@@ -171,6 +171,7 @@ impl ByteOpcode {
             ByteOpcode::LTU,
             ByteOpcode::MSB,
             ByteOpcode::U16Range,
+            ByteOpcode::AesSbox,
         ];
         debug_assert_eq!(opcodes.len(), NUM_BYTE_OPS);
         opcodes
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{memory::MemoryWriteRecord, MemoryLocalEvent};
+
+/// The number of words in a ChaCha20 state.
+pub const CHACHA20_STATE_WORDS: usize = 16;
+
+/// ChaCha20 Block Event.
+///
+/// This event is emitted when a ChaCha20 block function is computed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct ChaCha20BlockEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the state.
+    pub state_ptr: u32,
+    /// The input state as a list of words.
+    pub state: Vec<u32>,
+    /// The memory records for the state, which is overwritten with the keystream block.
+    pub state_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
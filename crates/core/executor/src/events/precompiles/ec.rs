@@ -88,6 +88,43 @@ pub struct EllipticCurveDecompressEvent {
     pub local_mem_access: Vec<MemoryLocalEvent>,
 }
 
+/// The number of scalar bits consumed by each window of an MSM syscall.
+pub const MSM_WINDOW_BITS: usize = 4;
+
+/// The number of windows processed by a single scalar mul or MSM syscall.
+pub const MSM_NUM_WINDOWS: usize = 16;
+
+/// The number of words of each scalar processed by a single scalar mul or MSM syscall.
+pub const MSM_SCALAR_WORDS: usize = MSM_NUM_WINDOWS * MSM_WINDOW_BITS / 32;
+
+/// The number of precomputed multiples `[1]P, .., [15]P` of each point in the MSM input.
+pub const MSM_TABLE_SIZE: usize = (1 << MSM_WINDOW_BITS) - 1;
+
+/// Elliptic Curve Multi-Scalar Multiplication Event.
+///
+/// This event is emitted when a scalar mul or MSM syscall processes one chunk of its scalars. The
+/// doublings and additions applied to the accumulator are emitted as separate
+/// [`EllipticCurveDoubleEvent`]s and [`EllipticCurveAddEvent`]s of the curve.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct EllipticCurveMsmEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the accumulator point.
+    pub p_ptr: u32,
+    /// The pointer to the scalar chunks, followed by the window tables of the points.
+    pub input_ptr: u32,
+    /// The number of points.
+    pub num_points: usize,
+    /// The scalar chunks as a list of words.
+    pub scalars: Vec<u32>,
+    /// The memory records for the scalar chunks.
+    pub scalar_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
 /// Create an elliptic curve add event. It takes two pointers to memory locations, reads the points
 /// from memory, adds them together, and writes the result back to the first memory location.
 /// The generic parameter `N` is the number of u32 words in the point representation. For example,
@@ -225,3 +262,53 @@ pub fn create_ec_decompress_event<E: EllipticCurve>(
         local_mem_access: rt.postprocess(),
     }
 }
+
+/// The number of cycles spent on each window of an MSM syscall with `num_points` points: one per
+/// doubling and two per addition.
+#[must_use]
+pub const fn msm_window_cycles(num_points: usize) -> u32 {
+    (MSM_WINDOW_BITS + 2 * num_points) as u32
+}
+
+/// The clock cycle of the `op`-th operation of the `window`-th window of an MSM syscall issued at
+/// `clk`. The doublings are operations `0..MSM_WINDOW_BITS` and the addition of the `i`-th point
+/// is operation `MSM_WINDOW_BITS + 2 * i`.
+#[must_use]
+pub const fn msm_op_clk(clk: u32, num_points: usize, window: usize, op: usize) -> u32 {
+    clk + 1 + window as u32 * msm_window_cycles(num_points) + op as u32
+}
+
+/// The window digits of the scalar chunks of an MSM syscall, most significant window first.
+#[must_use]
+pub fn msm_window_digits(scalars: &[u32], num_points: usize) -> Vec<Vec<u32>> {
+    debug_assert_eq!(scalars.len(), num_points * MSM_SCALAR_WORDS);
+    let digits_per_word = 32 / MSM_WINDOW_BITS;
+    (0..MSM_NUM_WINDOWS)
+        .rev()
+        .map(|digit| {
+            (0..num_points)
+                .map(|point| {
+                    let word = scalars[point * MSM_SCALAR_WORDS + digit / digits_per_word];
+                    (word >> (MSM_WINDOW_BITS * (digit % digits_per_word))) &
+                        ((1 << MSM_WINDOW_BITS) - 1)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The address of `[digit]P` for the `point`-th point in the input of an MSM syscall.
+///
+/// The input consists of the scalar chunks of every point, followed by the window table
+/// `[1]P, .., [15]P` of every point.
+#[must_use]
+pub fn msm_table_entry_ptr<E: EllipticCurve>(
+    input_ptr: u32,
+    num_points: usize,
+    point: usize,
+    digit: u32,
+) -> u32 {
+    let num_words = <E::BaseField as NumWords>::WordsCurvePoint::USIZE;
+    let entry = point * MSM_TABLE_SIZE + digit as usize - 1;
+    input_ptr + 4 * (num_points * MSM_SCALAR_WORDS + entry * num_words) as u32
+}
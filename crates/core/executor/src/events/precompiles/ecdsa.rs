@@ -0,0 +1,189 @@
+use num::{BigUint, Zero};
+use serde::{Deserialize, Serialize};
+
+use monerochan_curves::{
+    params::FieldParameters, weierstrass::EcdsaParameters, AffinePoint, EllipticCurve,
+};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    MemoryLocalEvent,
+};
+
+/// The number of bits of the scalars of the ECDSA precompiles, which is also the number of rows
+/// of each event.
+pub const ECDSA_NUM_BITS: usize = 256;
+
+/// The number of words of a field element or a scalar in the ECDSA precompiles.
+pub const ECDSA_WORDS: usize = 8;
+
+/// The number of input words of an ecrecover syscall: the message hash `z`, the signature `r` and
+/// `s`, and the recovery id.
+pub const ECRECOVER_INPUT_WORDS: usize = 3 * ECDSA_WORDS + 1;
+
+/// The number of output words of an ecrecover syscall: the public key and the status.
+pub const ECRECOVER_OUTPUT_WORDS: usize = 2 * ECDSA_WORDS + 1;
+
+/// The number of input words of an ECDSA verify syscall: the public key, the message hash `z` and
+/// the signature `r` and `s`.
+pub const ECDSA_VERIFY_INPUT_WORDS: usize = 5 * ECDSA_WORDS;
+
+/// The number of output words of an ECDSA verify syscall: the status.
+pub const ECDSA_VERIFY_OUTPUT_WORDS: usize = 1;
+
+/// The status of an ECDSA syscall when the signature is invalid.
+///
+/// For ecrecover, this means that `r` is not the x-coordinate of a point of the curve.
+pub const ECDSA_STATUS_FAILURE: u32 = 0;
+
+/// The status of an ECDSA syscall when the public key was recovered or the signature verified.
+pub const ECDSA_STATUS_SUCCESS: u32 = 1;
+
+/// The status of an ECDSA syscall when the double-and-add ladder met an exceptional case of the
+/// affine addition formulas.
+///
+/// This never happens for honestly generated signatures, but can be provoked by crafted inputs and
+/// also covers a recovered key at infinity. The caller must compute the result another way.
+pub const ECDSA_STATUS_FALLBACK: u32 = 2;
+
+/// ECDSA Event.
+///
+/// This event is emitted when an ecrecover or ECDSA verify operation is performed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct EcdsaEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the output.
+    pub out_ptr: u32,
+    /// The pointer to the input.
+    pub in_ptr: u32,
+    /// The input as a list of words.
+    pub input: Vec<u32>,
+    /// The memory records for the input.
+    pub input_memory_records: Vec<MemoryReadRecord>,
+    /// The output as a list of words.
+    pub output: Vec<u32>,
+    /// The memory records for the output.
+    pub output_memory_records: Vec<MemoryWriteRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
+
+/// Computes `[u1] G + [u2] Q` with the double-and-add ladder of the ECDSA precompiles.
+///
+/// The accumulator starts at [`EcdsaParameters::ecdsa_offset`] and processes the bits of `u1` and
+/// `u2` from the most significant one. For every bit it is doubled, and `G` and then `Q` are added
+/// to it when the corresponding bit is set. The offset is removed at the end.
+///
+/// Returns `None` if an addition would add two points with the same x-coordinate, or if the
+/// result is the point at infinity.
+pub fn ecdsa_double_and_add<E: EllipticCurve + EcdsaParameters>(
+    u1: &BigUint,
+    u2: &BigUint,
+    q: &AffinePoint<E>,
+) -> Option<AffinePoint<E>> {
+    let generator = E::ec_generator();
+    let (x, y) = E::ecdsa_offset();
+    let mut acc = AffinePoint::<E>::new(x, y);
+    let mut failed = false;
+    for bit in (0..ECDSA_NUM_BITS as u64).rev() {
+        acc = E::ec_double(&acc);
+        for (scalar, point) in [(u1, &generator), (u2, q)] {
+            if !scalar.bit(bit) {
+                continue;
+            }
+            if acc.x == point.x {
+                failed = true;
+            } else if !failed {
+                acc = E::ec_add(&acc, point);
+            }
+        }
+    }
+
+    let (x, y) = E::ecdsa_offset_correction();
+    if failed || acc.x == x {
+        return None;
+    }
+    Some(E::ec_add(&acc, &AffinePoint::new(x, y)))
+}
+
+/// Computes the output of an ecrecover syscall from its input.
+///
+/// The public key is `r^-1 (s R - z G)`, where `R` is the point with x-coordinate `r` and the
+/// y-coordinate of parity `v`.
+///
+/// # Panics
+///
+/// Panics if `r` is zero or not less than the group order, if `s` is not less than the group order
+/// or if `v` is not zero or one.
+#[must_use]
+pub fn ecrecover_output<E: EllipticCurve + EcdsaParameters>(input: &[u32]) -> Vec<u32> {
+    let modulus = E::BaseField::modulus();
+    let order = E::ScalarField::modulus();
+    let z = BigUint::from_slice(&input[0..ECDSA_WORDS]);
+    let r = BigUint::from_slice(&input[ECDSA_WORDS..2 * ECDSA_WORDS]);
+    let s = BigUint::from_slice(&input[2 * ECDSA_WORDS..3 * ECDSA_WORDS]);
+    let v = input[3 * ECDSA_WORDS];
+    assert!(v <= 1, "the recovery id must be 0 or 1");
+    assert!(!r.is_zero() && r < order, "r must be in [1, n)");
+    assert!(s < order, "s must be less than the group order");
+
+    let alpha = (&r * &r * &r + E::a_int() * &r + E::b_int()) % &modulus;
+    let mut output = vec![0; ECRECOVER_OUTPUT_WORDS];
+    let Some(y) = E::sqrt(&alpha) else {
+        output[2 * ECDSA_WORDS] = ECDSA_STATUS_FAILURE;
+        return output;
+    };
+    let y = if y.bit(0) == (v == 1) { y } else { (&modulus - y) % &modulus };
+
+    let r_inv = r.modpow(&(&order - 2u32), &order);
+    let u1 = (&order - (&z * &r_inv) % &order) % &order;
+    let u2 = (&s * &r_inv) % &order;
+    match ecdsa_double_and_add(&u1, &u2, &AffinePoint::<E>::new(r.clone(), y)) {
+        Some(key) => {
+            output[..2 * ECDSA_WORDS].copy_from_slice(&key.to_words_le());
+            output[2 * ECDSA_WORDS] = ECDSA_STATUS_SUCCESS;
+        }
+        None => output[2 * ECDSA_WORDS] = ECDSA_STATUS_FALLBACK,
+    }
+    output
+}
+
+/// Computes the output of an ECDSA verify syscall from its input.
+///
+/// The signature is valid if the x-coordinate of `s^-1 (z G + r P)` is `r` modulo the group
+/// order, where `P` is the public key.
+///
+/// # Panics
+///
+/// Panics if the coordinates of the public key are not reduced or the key is not on the curve, if
+/// `r` is not less than the group order or if `s` is zero or not less than the group order.
+#[must_use]
+pub fn ecdsa_verify_output<E: EllipticCurve + EcdsaParameters>(input: &[u32]) -> Vec<u32> {
+    let modulus = E::BaseField::modulus();
+    let order = E::ScalarField::modulus();
+    let key = AffinePoint::<E>::from_words_le(&input[0..2 * ECDSA_WORDS]);
+    let z = BigUint::from_slice(&input[2 * ECDSA_WORDS..3 * ECDSA_WORDS]);
+    let r = BigUint::from_slice(&input[3 * ECDSA_WORDS..4 * ECDSA_WORDS]);
+    let s = BigUint::from_slice(&input[4 * ECDSA_WORDS..5 * ECDSA_WORDS]);
+    assert!(key.x < modulus && key.y < modulus, "the public key must be reduced");
+    assert!(
+        (&key.y * &key.y) % &modulus ==
+            (&key.x * &key.x * &key.x + E::a_int() * &key.x + E::b_int()) % &modulus,
+        "the public key must be on the curve"
+    );
+    assert!(r < order, "r must be less than the group order");
+    assert!(!s.is_zero() && s < order, "s must be in [1, n)");
+
+    let s_inv = s.modpow(&(&order - 2u32), &order);
+    let u1 = (&z * &s_inv) % &order;
+    let u2 = (&r * &s_inv) % &order;
+    let status = match ecdsa_double_and_add(&u1, &u2, &key) {
+        Some(point) if point.x % &order == r => ECDSA_STATUS_SUCCESS,
+        Some(_) => ECDSA_STATUS_FAILURE,
+        None => ECDSA_STATUS_FALLBACK,
+    };
+    vec![status]
+}
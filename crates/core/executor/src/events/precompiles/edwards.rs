@@ -30,3 +30,28 @@ pub struct EdDecompressEvent {
     /// The local memory access events.
     pub local_mem_access: Vec<MemoryLocalEvent>,
 }
+
+/// X25519 Ladder Step Event.
+///
+/// This event is emitted when a step of the X25519 Montgomery ladder is performed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct X25519LadderStepEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the ladder state `[x2, z2, x3, z3]`.
+    pub state_ptr: u32,
+    /// The ladder state as a list of words.
+    pub state: Vec<u32>,
+    /// The pointer to the u-coordinate of the difference of the two points.
+    pub x1_ptr: u32,
+    /// The u-coordinate of the difference of the two points as a list of words.
+    pub x1: Vec<u32>,
+    /// The memory records for the ladder state.
+    pub state_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory records for the u-coordinate.
+    pub x1_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
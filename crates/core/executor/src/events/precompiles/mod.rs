@@ -1,4 +1,7 @@
+mod aes;
+mod chacha20;
 mod ec;
+mod ecdsa;
 mod edwards;
 mod fptower;
 mod keccak256_permute;
@@ -6,10 +9,14 @@ mod sha256_compress;
 mod sha256_extend;
 mod u256x2048_mul;
 mod uint256;
+mod user;
 
 use super::{MemoryLocalEvent, SyscallEvent};
 use crate::{deserialize_hashmap_as_vec, serialize_hashmap_as_vec, syscalls::SyscallCode};
+pub use aes::*;
+pub use chacha20::*;
 pub use ec::*;
+pub use ecdsa::*;
 pub use edwards::*;
 pub use fptower::*;
 use hashbrown::HashMap;
@@ -20,6 +27,7 @@ pub use sha256_extend::*;
 use strum::{EnumIter, IntoEnumIterator};
 pub use u256x2048_mul::*;
 pub use uint256::*;
+pub use user::*;
 
 #[derive(Clone, Debug, Serialize, Deserialize, EnumIter)]
 /// Precompile event.  There should be one variant for every precompile syscall.
@@ -58,6 +66,14 @@ pub enum PrecompileEvent {
     Bn254Fp2AddSub(Fp2AddSubEvent),
     /// Bn254 quadratic field mul precompile event.
     Bn254Fp2Mul(Fp2MulEvent),
+    /// Bn254 degree 6 field mul precompile event.
+    Bn254Fp6Mul(FpTowerMulEvent),
+    /// Bn254 degree 12 field mul precompile event.
+    Bn254Fp12Mul(FpTowerMulEvent),
+    /// Bn254 G2 add precompile event.
+    Bn254G2Add(EllipticCurveAddEvent),
+    /// Bn254 G2 double precompile event.
+    Bn254G2Double(EllipticCurveDoubleEvent),
     /// Bls12-381 curve add precompile event.
     Bls12381Add(EllipticCurveAddEvent),
     /// Bls12-381 curve double precompile event.
@@ -70,10 +86,76 @@ pub enum PrecompileEvent {
     Bls12381Fp2AddSub(Fp2AddSubEvent),
     /// Bls12-381 quadratic field mul precompile event.
     Bls12381Fp2Mul(Fp2MulEvent),
+    /// Bls12-381 degree 6 field mul precompile event.
+    Bls12381Fp6Mul(FpTowerMulEvent),
+    /// Bls12-381 degree 12 field mul precompile event.
+    Bls12381Fp12Mul(FpTowerMulEvent),
+    /// Bls12-381 G2 add precompile event.
+    Bls12381G2Add(EllipticCurveAddEvent),
+    /// Bls12-381 G2 double precompile event.
+    Bls12381G2Double(EllipticCurveDoubleEvent),
+    /// Secp256k1 scalar mul precompile event.
+    Secp256k1ScalarMul(EllipticCurveMsmEvent),
+    /// Secp256k1 multi-scalar mul precompile event.
+    Secp256k1Msm(EllipticCurveMsmEvent),
+    /// Secp256r1 scalar mul precompile event.
+    Secp256r1ScalarMul(EllipticCurveMsmEvent),
+    /// Secp256r1 multi-scalar mul precompile event.
+    Secp256r1Msm(EllipticCurveMsmEvent),
+    /// Bn254 scalar mul precompile event.
+    Bn254ScalarMul(EllipticCurveMsmEvent),
+    /// Bn254 multi-scalar mul precompile event.
+    Bn254Msm(EllipticCurveMsmEvent),
+    /// Bls12-381 scalar mul precompile event.
+    Bls12381ScalarMul(EllipticCurveMsmEvent),
+    /// Bls12-381 multi-scalar mul precompile event.
+    Bls12381Msm(EllipticCurveMsmEvent),
     /// Uint256 mul precompile event.
     Uint256Mul(Uint256MulEvent),
     /// U256XU2048 mul precompile event.
     U256xU2048Mul(U256xU2048MulEvent),
+    /// Uint256 add with carry and sub with borrow precompile event.
+    Uint256AddSub(Uint256AddSubEvent),
+    /// Uint2048 mul precompile event.
+    Uint2048Mul(Uint256MulEvent),
+    /// Uint4096 mul precompile event.
+    Uint4096Mul(Uint256MulEvent),
+    /// Secp256k1 base field operation precompile event.
+    Secp256k1Fp(FpOpEvent),
+    /// Secp256k1 scalar field operation precompile event.
+    Secp256k1Fn(FpOpEvent),
+    /// Secp256r1 base field operation precompile event.
+    Secp256r1Fp(FpOpEvent),
+    /// Secp256r1 scalar field operation precompile event.
+    Secp256r1Fn(FpOpEvent),
+    /// X25519 ladder step precompile event.
+    X25519LadderStep(X25519LadderStepEvent),
+    /// AES-128 and AES-256 block encryption precompile event.
+    AesEncryptBlock(AesEncryptBlockEvent),
+    /// ChaCha20 block precompile event.
+    ChaCha20Block(ChaCha20BlockEvent),
+    /// Pallas curve add precompile event.
+    PallasAdd(EllipticCurveAddEvent),
+    /// Pallas curve double precompile event.
+    PallasDouble(EllipticCurveDoubleEvent),
+    /// Vesta curve add precompile event.
+    VestaAdd(EllipticCurveAddEvent),
+    /// Vesta curve double precompile event.
+    VestaDouble(EllipticCurveDoubleEvent),
+    /// Grumpkin curve add precompile event.
+    GrumpkinAdd(EllipticCurveAddEvent),
+    /// Grumpkin curve double precompile event.
+    GrumpkinDouble(EllipticCurveDoubleEvent),
+    /// Secp256k1 ecrecover precompile event.
+    Secp256k1Ecrecover(EcdsaEvent),
+    /// Secp256r1 ecrecover precompile event.
+    Secp256r1Ecrecover(EcdsaEvent),
+    /// Secp256k1 ECDSA verify precompile event.
+    Secp256k1EcdsaVerify(EcdsaEvent),
+    /// Secp256r1 ECDSA verify precompile event.
+    Secp256r1EcdsaVerify(EcdsaEvent),
+    /// User-registered precompile event.
+    User(UserPrecompileEvent),
 }
 
 /// Trait to retrieve all the local memory events from a vec of precompile events.
@@ -104,13 +186,23 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 PrecompileEvent::Secp256r1Add(e) |
                 PrecompileEvent::EdAdd(e) |
                 PrecompileEvent::Bn254Add(e) |
-                PrecompileEvent::Bls12381Add(e) => {
+                PrecompileEvent::Bls12381Add(e) |
+                PrecompileEvent::PallasAdd(e) |
+                PrecompileEvent::VestaAdd(e) |
+                PrecompileEvent::GrumpkinAdd(e) |
+                PrecompileEvent::Bn254G2Add(e) |
+                PrecompileEvent::Bls12381G2Add(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
                 PrecompileEvent::Secp256k1Double(e) |
                 PrecompileEvent::Secp256r1Double(e) |
                 PrecompileEvent::Bn254Double(e) |
-                PrecompileEvent::Bls12381Double(e) => {
+                PrecompileEvent::Bls12381Double(e) |
+                PrecompileEvent::PallasDouble(e) |
+                PrecompileEvent::VestaDouble(e) |
+                PrecompileEvent::GrumpkinDouble(e) |
+                PrecompileEvent::Bn254G2Double(e) |
+                PrecompileEvent::Bls12381G2Double(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
                 PrecompileEvent::Secp256k1Decompress(e) |
@@ -119,13 +211,51 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 PrecompileEvent::Bls12381Decompress(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
-                PrecompileEvent::Uint256Mul(e) => {
+                PrecompileEvent::Secp256k1ScalarMul(e) |
+                PrecompileEvent::Secp256k1Msm(e) |
+                PrecompileEvent::Secp256r1ScalarMul(e) |
+                PrecompileEvent::Secp256r1Msm(e) |
+                PrecompileEvent::Bn254ScalarMul(e) |
+                PrecompileEvent::Bn254Msm(e) |
+                PrecompileEvent::Bls12381ScalarMul(e) |
+                PrecompileEvent::Bls12381Msm(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Uint256Mul(e) |
+                PrecompileEvent::Uint2048Mul(e) |
+                PrecompileEvent::Uint4096Mul(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Uint256AddSub(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::X25519LadderStep(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::AesEncryptBlock(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::ChaCha20Block(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::Secp256k1Ecrecover(e) |
+                PrecompileEvent::Secp256r1Ecrecover(e) |
+                PrecompileEvent::Secp256k1EcdsaVerify(e) |
+                PrecompileEvent::Secp256r1EcdsaVerify(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
+                PrecompileEvent::User(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
                 PrecompileEvent::U256xU2048Mul(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
-                PrecompileEvent::Bls12381Fp(e) | PrecompileEvent::Bn254Fp(e) => {
+                PrecompileEvent::Bls12381Fp(e) |
+                PrecompileEvent::Bn254Fp(e) |
+                PrecompileEvent::Secp256k1Fp(e) |
+                PrecompileEvent::Secp256k1Fn(e) |
+                PrecompileEvent::Secp256r1Fp(e) |
+                PrecompileEvent::Secp256r1Fn(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
                 PrecompileEvent::Bls12381Fp2AddSub(e) | PrecompileEvent::Bn254Fp2AddSub(e) => {
@@ -134,6 +264,12 @@ impl PrecompileLocalMemory for Vec<(SyscallEvent, PrecompileEvent)> {
                 PrecompileEvent::Bls12381Fp2Mul(e) | PrecompileEvent::Bn254Fp2Mul(e) => {
                     iterators.push(e.local_mem_access.iter());
                 }
+                PrecompileEvent::Bls12381Fp6Mul(e) |
+                PrecompileEvent::Bn254Fp6Mul(e) |
+                PrecompileEvent::Bls12381Fp12Mul(e) |
+                PrecompileEvent::Bn254Fp12Mul(e) => {
+                    iterators.push(e.local_mem_access.iter());
+                }
             }
         }
 
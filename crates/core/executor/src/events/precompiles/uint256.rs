@@ -2,12 +2,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::events::{
     memory::{MemoryReadRecord, MemoryWriteRecord},
-    MemoryLocalEvent,
+    FieldOperation, MemoryLocalEvent,
 };
 
 /// Uint256 Mul Event.
 ///
-/// This event is emitted when a uint256 mul operation is performed.
+/// This event is emitted when a uint256, uint2048 or uint4096 mul operation is performed.
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Uint256MulEvent {
     /// The shard number.
@@ -33,3 +33,34 @@ pub struct Uint256MulEvent {
     /// The local memory access records.
     pub local_mem_access: Vec<MemoryLocalEvent>,
 }
+
+/// Uint256 Add/Sub Event.
+///
+/// This event is emitted when a uint256 add with carry or sub with borrow operation is performed.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct Uint256AddSubEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The pointer to the x value, which is followed by the carry word.
+    pub x_ptr: u32,
+    /// The x value as a list of words.
+    pub x: Vec<u32>,
+    /// The incoming carry or borrow.
+    pub carry: u32,
+    /// The pointer to the y value.
+    pub y_ptr: u32,
+    /// The y value as a list of words.
+    pub y: Vec<u32>,
+    /// The operation, either [`FieldOperation::Add`] or [`FieldOperation::Sub`].
+    pub op: FieldOperation,
+    /// The memory records for the x value.
+    pub x_memory_records: Vec<MemoryWriteRecord>,
+    /// The memory record for the carry word.
+    pub carry_memory_record: MemoryWriteRecord,
+    /// The memory records for the y value.
+    pub y_memory_records: Vec<MemoryReadRecord>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
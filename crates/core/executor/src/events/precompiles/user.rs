@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::events::{
+    memory::{MemoryReadRecord, MemoryWriteRecord},
+    MemoryLocalEvent,
+};
+
+/// User Precompile Event.
+///
+/// This event is emitted by precompiles registered in one of the user slots. Since the executor
+/// does not know the semantics of these precompiles, the event only carries the memory records
+/// and an opaque list of witness words that the chip's trace generation can interpret.
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct UserPrecompileEvent {
+    /// The shard number.
+    pub shard: u32,
+    /// The clock cycle.
+    pub clk: u32,
+    /// The first argument of the syscall.
+    pub arg1: u32,
+    /// The second argument of the syscall.
+    pub arg2: u32,
+    /// The memory records for the words read by the precompile, in access order.
+    pub read_records: Vec<MemoryReadRecord>,
+    /// The memory records for the words written by the precompile, in access order.
+    pub write_records: Vec<MemoryWriteRecord>,
+    /// Additional witness words produced by the precompile.
+    pub values: Vec<u32>,
+    /// The local memory access records.
+    pub local_mem_access: Vec<MemoryLocalEvent>,
+}
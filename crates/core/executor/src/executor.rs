@@ -35,7 +35,7 @@ use crate::{
     report::ExecutionReport,
     state::{ExecutionState, ForkState},
     subproof::SubproofVerifier,
-    syscalls::{default_syscall_map, Syscall, SyscallCode, SyscallContext},
+    syscalls::{default_syscall_map, Syscall, SyscallCode, SyscallContext, UserSyscalls},
     CoreAirId, Instruction, MaximalShapes, Opcode, Program, Register, RiscvAirId,
 };
 
@@ -312,7 +312,10 @@ impl<'a> Executor<'a> {
         let record = ExecutionRecord::new(program.clone());
 
         // Determine the maximum number of cycles for any syscall.
-        let syscall_map = default_syscall_map();
+        let mut syscall_map = default_syscall_map();
+        for (code, user) in context.user_syscalls.iter() {
+            syscall_map.insert(code, user.syscall.clone());
+        }
         let max_syscall_cycles =
             syscall_map.values().map(|syscall| syscall.num_extra_cycles()).max().unwrap_or(0);
 
@@ -383,6 +386,14 @@ impl<'a> Executor<'a> {
         HookEnv { runtime: self }
     }
 
+    /// Adds the executor side of user precompiles to the syscall table.
+    pub fn register_user_syscalls(&mut self, user_syscalls: &UserSyscalls) {
+        for (code, user) in user_syscalls.iter() {
+            self.max_syscall_cycles = self.max_syscall_cycles.max(user.syscall.num_extra_cycles());
+            self.syscall_map.insert(code, user.syscall.clone());
+        }
+    }
+
     /// Recover runtime state from a program and existing execution state.
     #[must_use]
     pub fn recover(program: Program, state: ExecutionState, opts: MONEROCHANCoreOpts) -> Self {
@@ -1634,6 +1645,8 @@ impl<'a> Executor<'a> {
                 RiscvAirId::ShaExtend => self.opts.split_opts.sha_extend,
                 RiscvAirId::ShaCompress => self.opts.split_opts.sha_compress,
                 RiscvAirId::KeccakPermute => self.opts.split_opts.keccak,
+                RiscvAirId::AesEncryptBlock => self.opts.split_opts.aes,
+                RiscvAirId::ChaCha20Block => self.opts.split_opts.chacha20,
                 _ => self.opts.split_opts.deferred,
             } as u64;
             let shards = &mut estimator.precompile_records[syscall_id];
@@ -81,6 +81,7 @@ impl Default for HookRegistry<'_> {
             (FD_RSA_MUL_MOD, hookify(hook_rsa_mul_mod)),
             (FD_BLS12_381_SQRT, hookify(bls::hook_bls12_381_sqrt)),
             (FD_BLS12_381_INVERSE, hookify(bls::hook_bls12_381_inverse)),
+            (FD_BLS12_381_FP2_SQRT, hookify(bls::hook_bls12_381_fp2_sqrt)),
             (FD_FP_SQRT, hookify(fp_ops::hook_fp_sqrt)),
             (FD_FP_INV, hookify(fp_ops::hook_fp_inverse)),
         ]);
@@ -586,6 +587,76 @@ mod bls {
 
         vec![pad_to_be(&inverse, 48)]
     }
+
+    /// Given an element `c0 + c1 * u` of `Fp2 = Fp[u] / (u^2 + 1)`, encoded as `c0 || c1` in big
+    /// endian, this function computes the square root.
+    ///
+    /// - If the element is a quadratic residue, this function returns `vec![vec![1],
+    ///   vec![sqrt(fe)]]`.
+    /// - If the element is not a quadratic residue, this function returns `vec![vec![0],
+    ///   vec![sqrt((1 + u) * fe)]]`, since `1 + u` is a non-quadratic residue of `Fp2`.
+    ///
+    /// The roots are encoded like the input.
+    pub fn hook_bls12_381_fp2_sqrt(_: HookEnv, buf: &[u8]) -> Vec<Vec<u8>> {
+        assert_eq!(buf.len(), 96, "Fp2 element should have length 96");
+
+        let modulus = BigUint::from_bytes_le(BLS12_381_MODULUS);
+        let c0 = BigUint::from_bytes_be(&buf[..48]) % &modulus;
+        let c1 = BigUint::from_bytes_be(&buf[48..]) % &modulus;
+
+        if let Some((r0, r1)) = fp2_sqrt(&c0, &c1, &modulus) {
+            return vec![vec![1], [pad_to_be(&r0, 48), pad_to_be(&r1, 48)].concat()];
+        }
+
+        // The product of two non-quadratic residues is a quadratic residue, and
+        // `(1 + u) * (c0 + c1 * u) = (c0 - c1) + (c0 + c1) * u`.
+        let q0 = (&c0 + &modulus - &c1) % &modulus;
+        let q1 = (&c0 + &c1) % &modulus;
+        let (r0, r1) =
+            fp2_sqrt(&q0, &q1, &modulus).expect("NQR sanity check failed, this is a bug.");
+
+        vec![vec![0], [pad_to_be(&r0, 48), pad_to_be(&r1, 48)].concat()]
+    }
+
+    /// Computes a square root in `Fp2 = Fp[u] / (u^2 + 1)` for a modulus `p = 3 mod 4`, using
+    /// algorithm 9 of <https://eprint.iacr.org/2012/685>.
+    ///
+    /// Returns `None` if the element is not a quadratic residue.
+    fn fp2_sqrt(c0: &BigUint, c1: &BigUint, modulus: &BigUint) -> Option<(BigUint, BigUint)> {
+        let mul = |a: &(BigUint, BigUint), b: &(BigUint, BigUint)| {
+            let re = (&a.0 * &b.0 + modulus * modulus - &a.1 * &b.1) % modulus;
+            let im = (&a.0 * &b.1 + &a.1 * &b.0) % modulus;
+            (re, im)
+        };
+        let pow = |a: &(BigUint, BigUint), exp: &BigUint| {
+            let mut res = (BigUint::from(1u64), BigUint::zero());
+            for i in (0..exp.bits()).rev() {
+                res = mul(&res, &res);
+                if exp.bit(i) {
+                    res = mul(&res, a);
+                }
+            }
+            res
+        };
+
+        let a = (c0.clone(), c1.clone());
+        let a1 = pow(&a, &((modulus - BigUint::from(3u64)) / BigUint::from(4u64)));
+        let x0 = mul(&a1, &a);
+        let alpha = mul(&a1, &x0);
+
+        let root = if alpha == (modulus - BigUint::from(1u64), BigUint::zero()) {
+            // The root is `u * x0`.
+            ((modulus - &x0.1) % modulus, x0.0)
+        } else {
+            let b = pow(
+                &((alpha.0 + BigUint::from(1u64)) % modulus, alpha.1),
+                &((modulus - BigUint::from(1u64)) / BigUint::from(2u64)),
+            );
+            mul(&b, &x0)
+        };
+
+        (mul(&root, &root) == a).then_some(root)
+    }
 }
 
 /// Given the product of some 256-byte numbers and a modulus, this function does a modular
@@ -129,6 +129,8 @@ pub enum ByteOpcode {
     MSB = 7,
     /// Unsigned 16-bit Range Check.
     U16Range = 8,
+    /// AES S-box, together with its output doubled in the AES field.
+    AesSbox = 9,
 }
 
 impl Opcode {
@@ -121,6 +121,8 @@ impl ExecutionRecord {
                 SyscallCode::KECCAK_PERMUTE => opts.keccak,
                 SyscallCode::SHA_EXTEND => opts.sha_extend,
                 SyscallCode::SHA_COMPRESS => opts.sha_compress,
+                SyscallCode::AES128_ENCRYPT_BLOCK => opts.aes,
+                SyscallCode::CHACHA20_BLOCK => opts.chacha20,
                 _ => opts.deferred,
             };
 
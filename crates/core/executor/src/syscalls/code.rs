@@ -156,6 +156,158 @@ pub enum SyscallCode {
 
     /// Executes the `SECP256R1_DECOMPRESS` precompile.
     SECP256R1_DECOMPRESS = 0x00_00_01_2E,
+
+    /// Executes the `BN254_FP6_MUL` precompile.
+    BN254_FP6_MUL = 0x00_01_01_30,
+
+    /// Executes the `BN254_FP12_MUL` precompile.
+    BN254_FP12_MUL = 0x00_01_01_31,
+
+    /// Executes the `BN254_G2_ADD` precompile.
+    BN254_G2_ADD = 0x00_01_01_32,
+
+    /// Executes the `BN254_G2_DOUBLE` precompile.
+    BN254_G2_DOUBLE = 0x00_01_01_33,
+
+    /// Executes the `BLS12381_FP6_MUL` precompile.
+    BLS12381_FP6_MUL = 0x00_01_01_34,
+
+    /// Executes the `BLS12381_FP12_MUL` precompile.
+    BLS12381_FP12_MUL = 0x00_01_01_35,
+
+    /// Executes the `BLS12381_G2_ADD` precompile.
+    BLS12381_G2_ADD = 0x00_01_01_36,
+
+    /// Executes the `BLS12381_G2_DOUBLE` precompile.
+    BLS12381_G2_DOUBLE = 0x00_01_01_37,
+
+    /// Executes the `SECP256K1_SCALAR_MUL` precompile.
+    SECP256K1_SCALAR_MUL = 0x00_60_01_38,
+
+    /// Executes the `SECP256K1_MSM` precompile.
+    SECP256K1_MSM = 0x00_80_01_39,
+
+    /// Executes the `SECP256R1_SCALAR_MUL` precompile.
+    SECP256R1_SCALAR_MUL = 0x00_60_01_3A,
+
+    /// Executes the `SECP256R1_MSM` precompile.
+    SECP256R1_MSM = 0x00_80_01_3B,
+
+    /// Executes the `BN254_SCALAR_MUL` precompile.
+    BN254_SCALAR_MUL = 0x00_60_01_3C,
+
+    /// Executes the `BN254_MSM` precompile.
+    BN254_MSM = 0x00_80_01_3D,
+
+    /// Executes the `BLS12381_SCALAR_MUL` precompile.
+    BLS12381_SCALAR_MUL = 0x00_60_01_3E,
+
+    /// Executes the `BLS12381_MSM` precompile.
+    BLS12381_MSM = 0x00_80_01_3F,
+
+    /// Executes the `UINT256_ADD_CARRY` precompile.
+    UINT256_ADD_CARRY = 0x00_01_01_40,
+
+    /// Executes the `UINT256_SUB_BORROW` precompile.
+    UINT256_SUB_BORROW = 0x00_01_01_41,
+
+    /// Executes the `UINT2048_MUL` precompile.
+    UINT2048_MUL = 0x00_01_01_42,
+
+    /// Executes the `UINT4096_MUL` precompile.
+    UINT4096_MUL = 0x00_01_01_43,
+
+    /// Executes the `SECP256K1_FP_ADD` precompile.
+    SECP256K1_FP_ADD = 0x00_01_01_44,
+
+    /// Executes the `SECP256K1_FP_SUB` precompile.
+    SECP256K1_FP_SUB = 0x00_01_01_45,
+
+    /// Executes the `SECP256K1_FP_MUL` precompile.
+    SECP256K1_FP_MUL = 0x00_01_01_46,
+
+    /// Executes the `SECP256K1_FN_ADD` precompile.
+    SECP256K1_FN_ADD = 0x00_01_01_47,
+
+    /// Executes the `SECP256K1_FN_SUB` precompile.
+    SECP256K1_FN_SUB = 0x00_01_01_48,
+
+    /// Executes the `SECP256K1_FN_MUL` precompile.
+    SECP256K1_FN_MUL = 0x00_01_01_49,
+
+    /// Executes the `SECP256R1_FP_ADD` precompile.
+    SECP256R1_FP_ADD = 0x00_01_01_4A,
+
+    /// Executes the `SECP256R1_FP_SUB` precompile.
+    SECP256R1_FP_SUB = 0x00_01_01_4B,
+
+    /// Executes the `SECP256R1_FP_MUL` precompile.
+    SECP256R1_FP_MUL = 0x00_01_01_4C,
+
+    /// Executes the `SECP256R1_FN_ADD` precompile.
+    SECP256R1_FN_ADD = 0x00_01_01_4D,
+
+    /// Executes the `SECP256R1_FN_SUB` precompile.
+    SECP256R1_FN_SUB = 0x00_01_01_4E,
+
+    /// Executes the `SECP256R1_FN_MUL` precompile.
+    SECP256R1_FN_MUL = 0x00_01_01_4F,
+
+    /// Executes the `X25519_LADDER_STEP` precompile.
+    X25519_LADDER_STEP = 0x00_01_01_50,
+
+    /// Executes the `AES128_ENCRYPT_BLOCK` precompile.
+    AES128_ENCRYPT_BLOCK = 0x00_01_01_51,
+
+    /// Executes the `AES256_ENCRYPT_BLOCK` precompile.
+    AES256_ENCRYPT_BLOCK = 0x00_01_01_52,
+
+    /// Executes the `CHACHA20_BLOCK` precompile.
+    CHACHA20_BLOCK = 0x00_00_01_53,
+
+    /// Executes the `PALLAS_ADD` precompile.
+    PALLAS_ADD = 0x00_01_01_54,
+
+    /// Executes the `PALLAS_DOUBLE` precompile.
+    PALLAS_DOUBLE = 0x00_00_01_55,
+
+    /// Executes the `VESTA_ADD` precompile.
+    VESTA_ADD = 0x00_01_01_56,
+
+    /// Executes the `VESTA_DOUBLE` precompile.
+    VESTA_DOUBLE = 0x00_00_01_57,
+
+    /// Executes the `GRUMPKIN_ADD` precompile.
+    GRUMPKIN_ADD = 0x00_01_01_58,
+
+    /// Executes the `GRUMPKIN_DOUBLE` precompile.
+    GRUMPKIN_DOUBLE = 0x00_00_01_59,
+
+    /// Executes the `SECP256K1_ECRECOVER` precompile.
+    SECP256K1_ECRECOVER = 0x00_01_01_5A,
+
+    /// Executes the `SECP256R1_ECRECOVER` precompile.
+    SECP256R1_ECRECOVER = 0x00_01_01_5B,
+
+    /// Executes the `SECP256K1_ECDSA_VERIFY` precompile.
+    SECP256K1_ECDSA_VERIFY = 0x00_01_01_5C,
+
+    /// Executes the `SECP256R1_ECDSA_VERIFY` precompile.
+    SECP256R1_ECDSA_VERIFY = 0x00_01_01_5D,
+
+    /// Executes the precompile registered in user slot 0.
+    ///
+    /// See [`crate::syscalls::UserSyscalls`].
+    USER_PRECOMPILE_0 = 0x00_01_01_E0,
+
+    /// Executes the precompile registered in user slot 1.
+    USER_PRECOMPILE_1 = 0x00_01_01_E1,
+
+    /// Executes the precompile registered in user slot 2.
+    USER_PRECOMPILE_2 = 0x00_01_01_E2,
+
+    /// Executes the precompile registered in user slot 3.
+    USER_PRECOMPILE_3 = 0x00_01_01_E3,
 }
 
 impl SyscallCode {
@@ -202,6 +354,56 @@ impl SyscallCode {
             0x00_01_01_2C => SyscallCode::SECP256R1_ADD,
             0x00_00_01_2D => SyscallCode::SECP256R1_DOUBLE,
             0x00_00_01_2E => SyscallCode::SECP256R1_DECOMPRESS,
+            0x00_01_01_30 => SyscallCode::BN254_FP6_MUL,
+            0x00_01_01_31 => SyscallCode::BN254_FP12_MUL,
+            0x00_01_01_32 => SyscallCode::BN254_G2_ADD,
+            0x00_01_01_33 => SyscallCode::BN254_G2_DOUBLE,
+            0x00_01_01_34 => SyscallCode::BLS12381_FP6_MUL,
+            0x00_01_01_35 => SyscallCode::BLS12381_FP12_MUL,
+            0x00_01_01_36 => SyscallCode::BLS12381_G2_ADD,
+            0x00_01_01_37 => SyscallCode::BLS12381_G2_DOUBLE,
+            0x00_60_01_38 => SyscallCode::SECP256K1_SCALAR_MUL,
+            0x00_80_01_39 => SyscallCode::SECP256K1_MSM,
+            0x00_60_01_3A => SyscallCode::SECP256R1_SCALAR_MUL,
+            0x00_80_01_3B => SyscallCode::SECP256R1_MSM,
+            0x00_60_01_3C => SyscallCode::BN254_SCALAR_MUL,
+            0x00_80_01_3D => SyscallCode::BN254_MSM,
+            0x00_60_01_3E => SyscallCode::BLS12381_SCALAR_MUL,
+            0x00_80_01_3F => SyscallCode::BLS12381_MSM,
+            0x00_01_01_40 => SyscallCode::UINT256_ADD_CARRY,
+            0x00_01_01_41 => SyscallCode::UINT256_SUB_BORROW,
+            0x00_01_01_42 => SyscallCode::UINT2048_MUL,
+            0x00_01_01_43 => SyscallCode::UINT4096_MUL,
+            0x00_01_01_44 => SyscallCode::SECP256K1_FP_ADD,
+            0x00_01_01_45 => SyscallCode::SECP256K1_FP_SUB,
+            0x00_01_01_46 => SyscallCode::SECP256K1_FP_MUL,
+            0x00_01_01_47 => SyscallCode::SECP256K1_FN_ADD,
+            0x00_01_01_48 => SyscallCode::SECP256K1_FN_SUB,
+            0x00_01_01_49 => SyscallCode::SECP256K1_FN_MUL,
+            0x00_01_01_4A => SyscallCode::SECP256R1_FP_ADD,
+            0x00_01_01_4B => SyscallCode::SECP256R1_FP_SUB,
+            0x00_01_01_4C => SyscallCode::SECP256R1_FP_MUL,
+            0x00_01_01_4D => SyscallCode::SECP256R1_FN_ADD,
+            0x00_01_01_4E => SyscallCode::SECP256R1_FN_SUB,
+            0x00_01_01_4F => SyscallCode::SECP256R1_FN_MUL,
+            0x00_01_01_50 => SyscallCode::X25519_LADDER_STEP,
+            0x00_01_01_51 => SyscallCode::AES128_ENCRYPT_BLOCK,
+            0x00_01_01_52 => SyscallCode::AES256_ENCRYPT_BLOCK,
+            0x00_00_01_53 => SyscallCode::CHACHA20_BLOCK,
+            0x00_01_01_54 => SyscallCode::PALLAS_ADD,
+            0x00_00_01_55 => SyscallCode::PALLAS_DOUBLE,
+            0x00_01_01_56 => SyscallCode::VESTA_ADD,
+            0x00_00_01_57 => SyscallCode::VESTA_DOUBLE,
+            0x00_01_01_58 => SyscallCode::GRUMPKIN_ADD,
+            0x00_00_01_59 => SyscallCode::GRUMPKIN_DOUBLE,
+            0x00_01_01_5A => SyscallCode::SECP256K1_ECRECOVER,
+            0x00_01_01_5B => SyscallCode::SECP256R1_ECRECOVER,
+            0x00_01_01_5C => SyscallCode::SECP256K1_ECDSA_VERIFY,
+            0x00_01_01_5D => SyscallCode::SECP256R1_ECDSA_VERIFY,
+            0x00_01_01_E0 => SyscallCode::USER_PRECOMPILE_0,
+            0x00_01_01_E1 => SyscallCode::USER_PRECOMPILE_1,
+            0x00_01_01_E2 => SyscallCode::USER_PRECOMPILE_2,
+            0x00_01_01_E3 => SyscallCode::USER_PRECOMPILE_3,
             _ => panic!("invalid syscall number: {value}"),
         }
     }
@@ -235,10 +437,32 @@ impl SyscallCode {
             SyscallCode::BLS12381_FP_SUB => SyscallCode::BLS12381_FP_ADD,
             SyscallCode::BLS12381_FP_MUL => SyscallCode::BLS12381_FP_ADD,
             SyscallCode::BLS12381_FP2_SUB => SyscallCode::BLS12381_FP2_ADD,
+            SyscallCode::UINT256_SUB_BORROW => SyscallCode::UINT256_ADD_CARRY,
+            SyscallCode::SECP256K1_FP_SUB => SyscallCode::SECP256K1_FP_ADD,
+            SyscallCode::SECP256K1_FP_MUL => SyscallCode::SECP256K1_FP_ADD,
+            SyscallCode::SECP256K1_FN_SUB => SyscallCode::SECP256K1_FN_ADD,
+            SyscallCode::SECP256K1_FN_MUL => SyscallCode::SECP256K1_FN_ADD,
+            SyscallCode::SECP256R1_FP_SUB => SyscallCode::SECP256R1_FP_ADD,
+            SyscallCode::SECP256R1_FP_MUL => SyscallCode::SECP256R1_FP_ADD,
+            SyscallCode::SECP256R1_FN_SUB => SyscallCode::SECP256R1_FN_ADD,
+            SyscallCode::SECP256R1_FN_MUL => SyscallCode::SECP256R1_FN_ADD,
+            SyscallCode::AES256_ENCRYPT_BLOCK => SyscallCode::AES128_ENCRYPT_BLOCK,
             _ => *self,
         }
     }
 
+    /// Whether the syscall is one of the slots reserved for user-registered precompiles.
+    #[must_use]
+    pub fn is_user_precompile(self) -> bool {
+        matches!(
+            self,
+            SyscallCode::USER_PRECOMPILE_0 |
+                SyscallCode::USER_PRECOMPILE_1 |
+                SyscallCode::USER_PRECOMPILE_2 |
+                SyscallCode::USER_PRECOMPILE_3
+        )
+    }
+
     /// Get the ID of the AIR used in the syscall implementation.
     #[must_use]
     pub fn as_air_id(self) -> Option<RiscvAirId> {
@@ -275,6 +499,58 @@ impl SyscallCode {
             SyscallCode::SECP256R1_ADD => RiscvAirId::Secp256r1AddAssign,
             SyscallCode::SECP256R1_DOUBLE => RiscvAirId::Secp256r1DoubleAssign,
             SyscallCode::SECP256R1_DECOMPRESS => RiscvAirId::Secp256r1Decompress,
+            SyscallCode::BN254_FP6_MUL => RiscvAirId::Bn254Fp6MulAssign,
+            SyscallCode::BN254_FP12_MUL => RiscvAirId::Bn254Fp12MulAssign,
+            SyscallCode::BN254_G2_ADD => RiscvAirId::Bn254G2AddAssign,
+            SyscallCode::BN254_G2_DOUBLE => RiscvAirId::Bn254G2DoubleAssign,
+            SyscallCode::BLS12381_FP6_MUL => RiscvAirId::Bls12381Fp6MulAssign,
+            SyscallCode::BLS12381_FP12_MUL => RiscvAirId::Bls12381Fp12MulAssign,
+            SyscallCode::BLS12381_G2_ADD => RiscvAirId::Bls12381G2AddAssign,
+            SyscallCode::BLS12381_G2_DOUBLE => RiscvAirId::Bls12381G2DoubleAssign,
+            SyscallCode::SECP256K1_SCALAR_MUL => RiscvAirId::Secp256k1ScalarMulAssign,
+            SyscallCode::SECP256K1_MSM => RiscvAirId::Secp256k1MsmAssign,
+            SyscallCode::SECP256R1_SCALAR_MUL => RiscvAirId::Secp256r1ScalarMulAssign,
+            SyscallCode::SECP256R1_MSM => RiscvAirId::Secp256r1MsmAssign,
+            SyscallCode::BN254_SCALAR_MUL => RiscvAirId::Bn254ScalarMulAssign,
+            SyscallCode::BN254_MSM => RiscvAirId::Bn254MsmAssign,
+            SyscallCode::BLS12381_SCALAR_MUL => RiscvAirId::Bls12381ScalarMulAssign,
+            SyscallCode::BLS12381_MSM => RiscvAirId::Bls12381MsmAssign,
+            SyscallCode::UINT256_ADD_CARRY | SyscallCode::UINT256_SUB_BORROW => {
+                RiscvAirId::Uint256AddSub
+            }
+            SyscallCode::UINT2048_MUL => RiscvAirId::Uint2048MulMod,
+            SyscallCode::UINT4096_MUL => RiscvAirId::Uint4096MulMod,
+            SyscallCode::SECP256K1_FP_ADD |
+            SyscallCode::SECP256K1_FP_SUB |
+            SyscallCode::SECP256K1_FP_MUL => RiscvAirId::Secp256k1FpOpAssign,
+            SyscallCode::SECP256K1_FN_ADD |
+            SyscallCode::SECP256K1_FN_SUB |
+            SyscallCode::SECP256K1_FN_MUL => RiscvAirId::Secp256k1FnOpAssign,
+            SyscallCode::SECP256R1_FP_ADD |
+            SyscallCode::SECP256R1_FP_SUB |
+            SyscallCode::SECP256R1_FP_MUL => RiscvAirId::Secp256r1FpOpAssign,
+            SyscallCode::SECP256R1_FN_ADD |
+            SyscallCode::SECP256R1_FN_SUB |
+            SyscallCode::SECP256R1_FN_MUL => RiscvAirId::Secp256r1FnOpAssign,
+            SyscallCode::X25519_LADDER_STEP => RiscvAirId::X25519LadderStep,
+            SyscallCode::AES128_ENCRYPT_BLOCK | SyscallCode::AES256_ENCRYPT_BLOCK => {
+                RiscvAirId::AesEncryptBlock
+            }
+            SyscallCode::CHACHA20_BLOCK => RiscvAirId::ChaCha20Block,
+            SyscallCode::PALLAS_ADD => RiscvAirId::PallasAddAssign,
+            SyscallCode::PALLAS_DOUBLE => RiscvAirId::PallasDoubleAssign,
+            SyscallCode::VESTA_ADD => RiscvAirId::VestaAddAssign,
+            SyscallCode::VESTA_DOUBLE => RiscvAirId::VestaDoubleAssign,
+            SyscallCode::GRUMPKIN_ADD => RiscvAirId::GrumpkinAddAssign,
+            SyscallCode::GRUMPKIN_DOUBLE => RiscvAirId::GrumpkinDoubleAssign,
+            SyscallCode::SECP256K1_ECRECOVER => RiscvAirId::Secp256k1Ecrecover,
+            SyscallCode::SECP256R1_ECRECOVER => RiscvAirId::Secp256r1Ecrecover,
+            SyscallCode::SECP256K1_ECDSA_VERIFY => RiscvAirId::Secp256k1EcdsaVerify,
+            SyscallCode::SECP256R1_ECDSA_VERIFY => RiscvAirId::Secp256r1EcdsaVerify,
+            SyscallCode::USER_PRECOMPILE_0 => RiscvAirId::UserPrecompile0,
+            SyscallCode::USER_PRECOMPILE_1 => RiscvAirId::UserPrecompile1,
+            SyscallCode::USER_PRECOMPILE_2 => RiscvAirId::UserPrecompile2,
+            SyscallCode::USER_PRECOMPILE_3 => RiscvAirId::UserPrecompile3,
             SyscallCode::HALT |
             SyscallCode::WRITE |
             SyscallCode::ENTER_UNCONSTRAINED |
@@ -3,6 +3,7 @@ use hashbrown::HashMap;
 use crate::{
     events::{
         MemoryLocalEvent, MemoryReadRecord, MemoryWriteRecord, PrecompileEvent, SyscallEvent,
+        UserPrecompileEvent,
     },
     record::ExecutionRecord,
     Executor, ExecutorMode, Register,
@@ -61,6 +62,23 @@ impl<'a, 'b> SyscallContext<'a, 'b> {
         }
     }
 
+    /// Add an event for a user-registered precompile to the execution record.
+    ///
+    /// This records both the syscall event, which is received by the precompile's chip, and the
+    /// precompile event itself. Call [`Self::postprocess`] to fill in
+    /// [`UserPrecompileEvent::local_mem_access`] before adding the event.
+    pub fn add_user_precompile_event(
+        &mut self,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+        event: UserPrecompileEvent,
+    ) {
+        let syscall_event =
+            self.rt.syscall_event(self.clk, None, None, syscall_code, arg1, arg2, self.next_pc);
+        self.add_precompile_event(syscall_code, syscall_event, PrecompileEvent::User(event));
+    }
+
     /// Get the current shard.
     #[must_use]
     pub fn current_shard(&self) -> u32 {
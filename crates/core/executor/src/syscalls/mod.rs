@@ -8,6 +8,7 @@ mod halt;
 mod hint;
 mod precompiles;
 mod unconstrained;
+mod user;
 mod verify;
 mod write;
 
@@ -22,15 +23,26 @@ pub use code::*;
 pub use context::*;
 use hint::{HintLenSyscall, HintReadSyscall};
 use precompiles::{
-    edwards::{add::EdwardsAddAssignSyscall, decompress::EdwardsDecompressSyscall},
-    fptower::{Fp2AddSubSyscall, Fp2MulSyscall, FpOpSyscall},
+    aes::AesEncryptBlockSyscall,
+    chacha20::ChaCha20BlockSyscall,
+    edwards::{
+        add::EdwardsAddAssignSyscall, decompress::EdwardsDecompressSyscall,
+        x25519::X25519LadderStepSyscall,
+    },
+    fptower::{
+        Fp2AddSubSyscall, Fp2MulSyscall, FpOpSyscall, FpTowerMulSyscall, G2AddAssignSyscall,
+        G2DoubleAssignSyscall,
+    },
     keccak256::permute::Keccak256PermuteSyscall,
     sha256::{compress::Sha256CompressSyscall, extend::Sha256ExtendSyscall},
     u256x2048_mul::U256xU2048MulSyscall,
-    uint256::Uint256MulSyscall,
+    uint256::{Uint256AddSubSyscall, Uint256MulSyscall, UintMulModSyscall},
     weierstrass::{
-        add::WeierstrassAddAssignSyscall, decompress::WeierstrassDecompressSyscall,
+        add::WeierstrassAddAssignSyscall,
+        decompress::WeierstrassDecompressSyscall,
         double::WeierstrassDoubleAssignSyscall,
+        ecdsa::{WeierstrassEcdsaVerifySyscall, WeierstrassEcrecoverSyscall},
+        msm::WeierstrassMsmSyscall,
     },
 };
 
@@ -39,11 +51,15 @@ use monerochan_curves::{
     weierstrass::{
         bls12_381::{Bls12381, Bls12381BaseField},
         bn254::{Bn254, Bn254BaseField},
-        secp256k1::Secp256k1,
-        secp256r1::Secp256r1,
+        grumpkin::Grumpkin,
+        pasta::{Pallas, Vesta},
+        secp256k1::{Secp256k1, Secp256k1BaseField, Secp256k1ScalarField},
+        secp256r1::{Secp256r1, Secp256r1BaseField, Secp256r1ScalarField},
+        tower::{FP12_DEGREE, FP6_DEGREE},
     },
 };
 use unconstrained::{EnterUnconstrainedSyscall, ExitUnconstrainedSyscall};
+pub use user::*;
 use verify::VerifySyscall;
 use write::WriteSyscall;
 
@@ -126,6 +142,26 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(WeierstrassDecompressSyscall::<Secp256r1>::new()),
     );
 
+    syscall_map.insert(
+        SyscallCode::SECP256K1_ECRECOVER,
+        Arc::new(WeierstrassEcrecoverSyscall::<Secp256k1>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256R1_ECRECOVER,
+        Arc::new(WeierstrassEcrecoverSyscall::<Secp256r1>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256K1_ECDSA_VERIFY,
+        Arc::new(WeierstrassEcdsaVerifySyscall::<Secp256k1>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256R1_ECDSA_VERIFY,
+        Arc::new(WeierstrassEcdsaVerifySyscall::<Secp256r1>::new()),
+    );
+
     syscall_map
         .insert(SyscallCode::BN254_ADD, Arc::new(WeierstrassAddAssignSyscall::<Bn254>::new()));
 
@@ -144,10 +180,53 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
         Arc::new(WeierstrassDoubleAssignSyscall::<Bls12381>::new()),
     );
 
+    syscall_map.insert(
+        SyscallCode::SECP256K1_SCALAR_MUL,
+        Arc::new(WeierstrassMsmSyscall::<Secp256k1>::new(1)),
+    );
+
+    syscall_map
+        .insert(SyscallCode::SECP256K1_MSM, Arc::new(WeierstrassMsmSyscall::<Secp256k1>::new(2)));
+
+    syscall_map.insert(
+        SyscallCode::SECP256R1_SCALAR_MUL,
+        Arc::new(WeierstrassMsmSyscall::<Secp256r1>::new(1)),
+    );
+
+    syscall_map
+        .insert(SyscallCode::SECP256R1_MSM, Arc::new(WeierstrassMsmSyscall::<Secp256r1>::new(2)));
+
+    syscall_map
+        .insert(SyscallCode::BN254_SCALAR_MUL, Arc::new(WeierstrassMsmSyscall::<Bn254>::new(1)));
+
+    syscall_map.insert(SyscallCode::BN254_MSM, Arc::new(WeierstrassMsmSyscall::<Bn254>::new(2)));
+
+    syscall_map.insert(
+        SyscallCode::BLS12381_SCALAR_MUL,
+        Arc::new(WeierstrassMsmSyscall::<Bls12381>::new(1)),
+    );
+
+    syscall_map
+        .insert(SyscallCode::BLS12381_MSM, Arc::new(WeierstrassMsmSyscall::<Bls12381>::new(2)));
+
     syscall_map.insert(SyscallCode::UINT256_MUL, Arc::new(Uint256MulSyscall));
 
     syscall_map.insert(SyscallCode::U256XU2048_MUL, Arc::new(U256xU2048MulSyscall));
 
+    syscall_map.insert(
+        SyscallCode::UINT256_ADD_CARRY,
+        Arc::new(Uint256AddSubSyscall::new(FieldOperation::Add)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::UINT256_SUB_BORROW,
+        Arc::new(Uint256AddSubSyscall::new(FieldOperation::Sub)),
+    );
+
+    syscall_map.insert(SyscallCode::UINT2048_MUL, Arc::new(UintMulModSyscall::<64>));
+
+    syscall_map.insert(SyscallCode::UINT4096_MUL, Arc::new(UintMulModSyscall::<128>));
+
     syscall_map.insert(
         SyscallCode::BLS12381_FP_ADD,
         Arc::new(FpOpSyscall::<Bls12381BaseField>::new(FieldOperation::Add)),
@@ -176,6 +255,26 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
     syscall_map
         .insert(SyscallCode::BLS12381_FP2_MUL, Arc::new(Fp2MulSyscall::<Bls12381BaseField>::new()));
 
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP6_MUL,
+        Arc::new(FpTowerMulSyscall::<Bls12381BaseField>::new(FP6_DEGREE)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BLS12381_FP12_MUL,
+        Arc::new(FpTowerMulSyscall::<Bls12381BaseField>::new(FP12_DEGREE)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BLS12381_G2_ADD,
+        Arc::new(G2AddAssignSyscall::<Bls12381BaseField>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BLS12381_G2_DOUBLE,
+        Arc::new(G2DoubleAssignSyscall::<Bls12381BaseField>::new()),
+    );
+
     syscall_map.insert(
         SyscallCode::BN254_FP_ADD,
         Arc::new(FpOpSyscall::<Bn254BaseField>::new(FieldOperation::Add)),
@@ -204,6 +303,122 @@ pub fn default_syscall_map() -> HashMap<SyscallCode, Arc<dyn Syscall>> {
     syscall_map
         .insert(SyscallCode::BN254_FP2_MUL, Arc::new(Fp2MulSyscall::<Bn254BaseField>::new()));
 
+    syscall_map.insert(
+        SyscallCode::BN254_FP6_MUL,
+        Arc::new(FpTowerMulSyscall::<Bn254BaseField>::new(FP6_DEGREE)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::BN254_FP12_MUL,
+        Arc::new(FpTowerMulSyscall::<Bn254BaseField>::new(FP12_DEGREE)),
+    );
+
+    syscall_map
+        .insert(SyscallCode::BN254_G2_ADD, Arc::new(G2AddAssignSyscall::<Bn254BaseField>::new()));
+
+    syscall_map.insert(
+        SyscallCode::BN254_G2_DOUBLE,
+        Arc::new(G2DoubleAssignSyscall::<Bn254BaseField>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256K1_FP_ADD,
+        Arc::new(FpOpSyscall::<Secp256k1BaseField>::new(FieldOperation::Add)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256K1_FP_SUB,
+        Arc::new(FpOpSyscall::<Secp256k1BaseField>::new(FieldOperation::Sub)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256K1_FP_MUL,
+        Arc::new(FpOpSyscall::<Secp256k1BaseField>::new(FieldOperation::Mul)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256K1_FN_ADD,
+        Arc::new(FpOpSyscall::<Secp256k1ScalarField>::new(FieldOperation::Add)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256K1_FN_SUB,
+        Arc::new(FpOpSyscall::<Secp256k1ScalarField>::new(FieldOperation::Sub)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256K1_FN_MUL,
+        Arc::new(FpOpSyscall::<Secp256k1ScalarField>::new(FieldOperation::Mul)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256R1_FP_ADD,
+        Arc::new(FpOpSyscall::<Secp256r1BaseField>::new(FieldOperation::Add)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256R1_FP_SUB,
+        Arc::new(FpOpSyscall::<Secp256r1BaseField>::new(FieldOperation::Sub)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256R1_FP_MUL,
+        Arc::new(FpOpSyscall::<Secp256r1BaseField>::new(FieldOperation::Mul)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256R1_FN_ADD,
+        Arc::new(FpOpSyscall::<Secp256r1ScalarField>::new(FieldOperation::Add)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256R1_FN_SUB,
+        Arc::new(FpOpSyscall::<Secp256r1ScalarField>::new(FieldOperation::Sub)),
+    );
+
+    syscall_map.insert(
+        SyscallCode::SECP256R1_FN_MUL,
+        Arc::new(FpOpSyscall::<Secp256r1ScalarField>::new(FieldOperation::Mul)),
+    );
+
+    syscall_map.insert(SyscallCode::X25519_LADDER_STEP, Arc::new(X25519LadderStepSyscall));
+
+    syscall_map.insert(SyscallCode::AES128_ENCRYPT_BLOCK, Arc::new(AesEncryptBlockSyscall));
+
+    syscall_map.insert(SyscallCode::AES256_ENCRYPT_BLOCK, Arc::new(AesEncryptBlockSyscall));
+
+    syscall_map.insert(SyscallCode::CHACHA20_BLOCK, Arc::new(ChaCha20BlockSyscall));
+
+    syscall_map.insert(
+        SyscallCode::PALLAS_ADD,
+        Arc::new(WeierstrassAddAssignSyscall::<Pallas>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::PALLAS_DOUBLE,
+        Arc::new(WeierstrassDoubleAssignSyscall::<Pallas>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::VESTA_ADD,
+        Arc::new(WeierstrassAddAssignSyscall::<Vesta>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::VESTA_DOUBLE,
+        Arc::new(WeierstrassDoubleAssignSyscall::<Vesta>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::GRUMPKIN_ADD,
+        Arc::new(WeierstrassAddAssignSyscall::<Grumpkin>::new()),
+    );
+
+    syscall_map.insert(
+        SyscallCode::GRUMPKIN_DOUBLE,
+        Arc::new(WeierstrassDoubleAssignSyscall::<Grumpkin>::new()),
+    );
+
     syscall_map.insert(SyscallCode::ENTER_UNCONSTRAINED, Arc::new(EnterUnconstrainedSyscall));
 
     syscall_map.insert(SyscallCode::EXIT_UNCONSTRAINED, Arc::new(ExitUnconstrainedSyscall));
@@ -0,0 +1,98 @@
+use monerochan_primitives::consts::{bytes_to_words_le, words_to_bytes_le_vec};
+
+use crate::{
+    events::{aes_xtime, AesEncryptBlockEvent, PrecompileEvent, AES_BLOCK_WORDS, AES_SBOX},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+pub(crate) struct AesEncryptBlockSyscall;
+
+impl Syscall for AesEncryptBlockSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let block_ptr = arg1;
+        assert!(block_ptr.is_multiple_of(4), "block_ptr must be 4-byte aligned");
+        let key_ptr = arg2;
+        assert!(key_ptr.is_multiple_of(4), "key_ptr must be 4-byte aligned");
+
+        let num_rounds = match syscall_code {
+            SyscallCode::AES128_ENCRYPT_BLOCK => 10,
+            SyscallCode::AES256_ENCRYPT_BLOCK => 14,
+            _ => unreachable!(),
+        };
+
+        // The block is written with the ciphertext later, so it can be read with `slice_unsafe`.
+        let block = rt.slice_unsafe(block_ptr, AES_BLOCK_WORDS);
+        let (key_memory_records, round_keys) =
+            rt.mr_slice(key_ptr, AES_BLOCK_WORDS * (num_rounds + 1));
+
+        let round_key_bytes = words_to_bytes_le_vec(&round_keys);
+        let mut state: [u8; 16] = words_to_bytes_le_vec(&block).try_into().unwrap();
+        for (i, byte) in state.iter_mut().enumerate() {
+            *byte ^= round_key_bytes[i];
+        }
+        for round in 1..=num_rounds {
+            let round_key = round_key_bytes[16 * round..16 * (round + 1)].try_into().unwrap();
+            state = aes_round(&state, round_key, round == num_rounds);
+        }
+        let result = bytes_to_words_le::<AES_BLOCK_WORDS>(&state);
+
+        // Increment clk so that the write is not at the same cycle as the key reads.
+        rt.clk += 1;
+        let block_memory_records = rt.mw_slice(block_ptr, &result);
+
+        let event = AesEncryptBlockEvent {
+            shard: rt.current_shard(),
+            clk: start_clk,
+            num_rounds,
+            block_ptr,
+            block,
+            key_ptr,
+            round_keys,
+            block_memory_records,
+            key_memory_records,
+            local_mem_access: rt.postprocess(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        rt.add_precompile_event(
+            syscall_code,
+            syscall_event,
+            PrecompileEvent::AesEncryptBlock(event),
+        );
+
+        None
+    }
+}
+
+/// Computes one AES round on a column-major state.
+///
+/// The round applies `SubBytes`, `ShiftRows`, `MixColumns` (unless `is_last` is set) and
+/// `AddRoundKey`, in that order.
+fn aes_round(state: &[u8; 16], round_key: &[u8; 16], is_last: bool) -> [u8; 16] {
+    let shifted: [u8; 16] = core::array::from_fn(|i| {
+        let (column, row) = (i / 4, i % 4);
+        AES_SBOX[state[((column + row) % 4) * 4 + row] as usize]
+    });
+    core::array::from_fn(|i| {
+        let value = if is_last {
+            shifted[i]
+        } else {
+            let (column, row) = (i / 4, i % 4);
+            let a = |j: usize| shifted[column * 4 + (row + j) % 4];
+            aes_xtime(a(0)) ^ aes_xtime(a(1)) ^ a(1) ^ a(2) ^ a(3)
+        };
+        value ^ round_key[i]
+    })
+}
+
@@ -0,0 +1,78 @@
+use crate::{
+    events::{ChaCha20BlockEvent, PrecompileEvent, CHACHA20_STATE_WORDS},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// The number of double rounds in the ChaCha20 block function.
+pub(crate) const CHACHA20_DOUBLE_ROUNDS: usize = 10;
+
+pub(crate) struct ChaCha20BlockSyscall;
+
+impl Syscall for ChaCha20BlockSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+        let state_ptr = arg1;
+        assert!(state_ptr.is_multiple_of(4), "state_ptr must be 4-byte aligned");
+
+        // The state is overwritten with the keystream block, so it can be read with
+        // `slice_unsafe`.
+        let state = rt.slice_unsafe(state_ptr, CHACHA20_STATE_WORDS);
+
+        let mut working: [u32; CHACHA20_STATE_WORDS] = state.clone().try_into().unwrap();
+        for _ in 0..CHACHA20_DOUBLE_ROUNDS {
+            chacha20_double_round(&mut working);
+        }
+        let result =
+            working.iter().zip(state.iter()).map(|(x, y)| x.wrapping_add(*y)).collect::<Vec<_>>();
+
+        let state_memory_records = rt.mw_slice(state_ptr, &result);
+
+        let event = ChaCha20BlockEvent {
+            shard: rt.current_shard(),
+            clk,
+            state_ptr,
+            state,
+            state_memory_records,
+            local_mem_access: rt.postprocess(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        rt.add_precompile_event(syscall_code, syscall_event, PrecompileEvent::ChaCha20Block(event));
+
+        None
+    }
+}
+
+/// Applies a column round followed by a diagonal round to the state.
+fn chacha20_double_round(state: &mut [u32; CHACHA20_STATE_WORDS]) {
+    for [a, b, c, d] in [[0, 4, 8, 12], [1, 5, 9, 13], [2, 6, 10, 14], [3, 7, 11, 15]] {
+        chacha20_quarter_round(state, a, b, c, d);
+    }
+    for [a, b, c, d] in [[0, 5, 10, 15], [1, 6, 11, 12], [2, 7, 8, 13], [3, 4, 9, 14]] {
+        chacha20_quarter_round(state, a, b, c, d);
+    }
+}
+
+/// Applies the ChaCha quarter round to the words at the given indices.
+fn chacha20_quarter_round(
+    state: &mut [u32; CHACHA20_STATE_WORDS],
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] = (state[d] ^ state[a]).rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_left(7);
+}
@@ -1,2 +1,3 @@
 pub mod add;
 pub mod decompress;
+pub mod x25519;
@@ -0,0 +1,73 @@
+use num::BigUint;
+use monerochan_curves::edwards::{ed25519::x25519_ladder_step, WORDS_FIELD_ELEMENT};
+
+use crate::{
+    events::{PrecompileEvent, X25519LadderStepEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// The number of words in the ladder state `[x2, z2, x3, z3]`.
+pub(crate) const WORDS_LADDER_STATE: usize = 4 * WORDS_FIELD_ELEMENT;
+
+pub(crate) struct X25519LadderStepSyscall;
+
+impl Syscall for X25519LadderStepSyscall {
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let state_ptr = arg1;
+        assert!(state_ptr.is_multiple_of(4), "state_ptr must be 4-byte aligned");
+        let x1_ptr = arg2;
+        assert!(x1_ptr.is_multiple_of(4), "x1_ptr must be 4-byte aligned");
+
+        let state = rt.slice_unsafe(state_ptr, WORDS_LADDER_STATE);
+        let (x1_memory_records, x1) = rt.mr_slice(x1_ptr, WORDS_FIELD_ELEMENT);
+
+        let state_biguint: [BigUint; 4] = core::array::from_fn(|i| {
+            BigUint::from_slice(&state[i * WORDS_FIELD_ELEMENT..(i + 1) * WORDS_FIELD_ELEMENT])
+        });
+        let result = x25519_ladder_step(&BigUint::from_slice(&x1), &state_biguint);
+        let result_words = result
+            .iter()
+            .flat_map(|value| {
+                let mut words = value.to_u32_digits();
+                words.resize(WORDS_FIELD_ELEMENT, 0);
+                words
+            })
+            .collect::<Vec<_>>();
+
+        // The state is written in the next cycle, after x1 has been read.
+        rt.clk += 1;
+        let state_memory_records = rt.mw_slice(state_ptr, &result_words);
+
+        let event = X25519LadderStepEvent {
+            shard: rt.current_shard(),
+            clk: start_clk,
+            state_ptr,
+            state,
+            x1_ptr,
+            x1,
+            state_memory_records,
+            x1_memory_records,
+            local_mem_access: rt.postprocess(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        rt.add_precompile_event(
+            syscall_code,
+            syscall_event,
+            PrecompileEvent::X25519LadderStep(event),
+        );
+
+        None
+    }
+}
@@ -80,40 +80,60 @@ impl<P: FpOpField> Syscall for FpOpSyscall<P> {
         // Group all of the events for a specific curve into the same syscall code key.
         // TODO:  FIX THIS.
 
-        match P::FIELD_TYPE {
-            FieldType::Bn254 => {
-                let syscall_code_key = match syscall_code {
-                    SyscallCode::BN254_FP_ADD |
-                    SyscallCode::BN254_FP_SUB |
-                    SyscallCode::BN254_FP_MUL => SyscallCode::BN254_FP_ADD,
-                    _ => unreachable!(),
-                };
-
-                let syscall_event =
-                    rt.rt.syscall_event(clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
-                rt.add_precompile_event(
-                    syscall_code_key,
-                    syscall_event,
-                    PrecompileEvent::Bn254Fp(event),
-                );
-            }
-            FieldType::Bls12381 => {
-                let syscall_code_key = match syscall_code {
-                    SyscallCode::BLS12381_FP_ADD |
-                    SyscallCode::BLS12381_FP_SUB |
-                    SyscallCode::BLS12381_FP_MUL => SyscallCode::BLS12381_FP_ADD,
-                    _ => unreachable!(),
-                };
+        let (syscall_code_key, precompile_event) = match P::FIELD_TYPE {
+            FieldType::Bn254 => match syscall_code {
+                SyscallCode::BN254_FP_ADD |
+                SyscallCode::BN254_FP_SUB |
+                SyscallCode::BN254_FP_MUL => {
+                    (SyscallCode::BN254_FP_ADD, PrecompileEvent::Bn254Fp(event))
+                }
+                _ => unreachable!(),
+            },
+            FieldType::Bls12381 => match syscall_code {
+                SyscallCode::BLS12381_FP_ADD |
+                SyscallCode::BLS12381_FP_SUB |
+                SyscallCode::BLS12381_FP_MUL => {
+                    (SyscallCode::BLS12381_FP_ADD, PrecompileEvent::Bls12381Fp(event))
+                }
+                _ => unreachable!(),
+            },
+            FieldType::Secp256k1 => match syscall_code {
+                SyscallCode::SECP256K1_FP_ADD |
+                SyscallCode::SECP256K1_FP_SUB |
+                SyscallCode::SECP256K1_FP_MUL => {
+                    (SyscallCode::SECP256K1_FP_ADD, PrecompileEvent::Secp256k1Fp(event))
+                }
+                _ => unreachable!(),
+            },
+            FieldType::Secp256k1Scalar => match syscall_code {
+                SyscallCode::SECP256K1_FN_ADD |
+                SyscallCode::SECP256K1_FN_SUB |
+                SyscallCode::SECP256K1_FN_MUL => {
+                    (SyscallCode::SECP256K1_FN_ADD, PrecompileEvent::Secp256k1Fn(event))
+                }
+                _ => unreachable!(),
+            },
+            FieldType::Secp256r1 => match syscall_code {
+                SyscallCode::SECP256R1_FP_ADD |
+                SyscallCode::SECP256R1_FP_SUB |
+                SyscallCode::SECP256R1_FP_MUL => {
+                    (SyscallCode::SECP256R1_FP_ADD, PrecompileEvent::Secp256r1Fp(event))
+                }
+                _ => unreachable!(),
+            },
+            FieldType::Secp256r1Scalar => match syscall_code {
+                SyscallCode::SECP256R1_FN_ADD |
+                SyscallCode::SECP256R1_FN_SUB |
+                SyscallCode::SECP256R1_FN_MUL => {
+                    (SyscallCode::SECP256R1_FN_ADD, PrecompileEvent::Secp256r1Fn(event))
+                }
+                _ => unreachable!(),
+            },
+        };
 
-                let syscall_event =
-                    rt.rt.syscall_event(clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
-                rt.add_precompile_event(
-                    syscall_code_key,
-                    syscall_event,
-                    PrecompileEvent::Bls12381Fp(event),
-                );
-            }
-        }
+        let syscall_event =
+            rt.rt.syscall_event(clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        rt.add_precompile_event(syscall_code_key, syscall_event, precompile_event);
 
         None
     }
@@ -1,7 +1,7 @@
 use num::BigUint;
 use monerochan_curves::{
     params::NumWords,
-    weierstrass::{FieldType, FpOpField},
+    weierstrass::{FieldType, FpTowerField},
 };
 use std::marker::PhantomData;
 use typenum::Unsigned;
@@ -22,7 +22,7 @@ impl<P> Fp2AddSubSyscall<P> {
     }
 }
 
-impl<P: FpOpField> Syscall for Fp2AddSubSyscall<P> {
+impl<P: FpTowerField> Syscall for Fp2AddSubSyscall<P> {
     fn execute(
         &self,
         rt: &mut SyscallContext,
@@ -121,6 +121,7 @@ impl<P: FpOpField> Syscall for Fp2AddSubSyscall<P> {
                     PrecompileEvent::Bls12381Fp2AddSub(event),
                 );
             }
+            _ => unreachable!(),
         }
         None
     }
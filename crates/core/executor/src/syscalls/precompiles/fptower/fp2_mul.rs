@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 use num::BigUint;
 use monerochan_curves::{
     params::NumWords,
-    weierstrass::{FieldType, FpOpField},
+    weierstrass::{FieldType, FpTowerField},
 };
 use typenum::Unsigned;
 
@@ -22,7 +22,7 @@ impl<P> Fp2MulSyscall<P> {
     }
 }
 
-impl<P: FpOpField> Syscall for Fp2MulSyscall<P> {
+impl<P: FpTowerField> Syscall for Fp2MulSyscall<P> {
     fn execute(
         &self,
         rt: &mut SyscallContext,
@@ -95,6 +95,7 @@ impl<P: FpOpField> Syscall for Fp2MulSyscall<P> {
                 syscall_event,
                 PrecompileEvent::Bls12381Fp2Mul(event),
             ),
+            _ => unreachable!(),
         }
         None
     }
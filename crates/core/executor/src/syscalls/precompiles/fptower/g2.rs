@@ -0,0 +1,169 @@
+use std::marker::PhantomData;
+
+use num::BigUint;
+use monerochan_curves::{
+    params::NumWords,
+    weierstrass::{
+        tower::{g2_add, g2_double, G2Point},
+        FieldType, FpTowerField,
+    },
+};
+use typenum::Unsigned;
+
+use crate::{
+    events::{EllipticCurveAddEvent, EllipticCurveDoubleEvent, PrecompileEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+fn g2_point_from_words<P: FpTowerField>(words: &[u32]) -> G2Point {
+    let num_words_field_element = <P as NumWords>::WordsFieldElement::USIZE;
+    let mut coeffs = words.chunks(num_words_field_element).map(BigUint::from_slice);
+    core::array::from_fn(|_| coeffs.next().unwrap())
+}
+
+fn g2_point_to_words<P: FpTowerField>(point: &G2Point) -> Vec<u32> {
+    let num_words_field_element = <P as NumWords>::WordsFieldElement::USIZE;
+    let mut result = Vec::with_capacity(4 * num_words_field_element);
+    for coeff in point {
+        let mut words = coeff.to_u32_digits();
+        words.resize(num_words_field_element, 0);
+        result.append(&mut words);
+    }
+    result
+}
+
+/// Addition of two points of the G2 subgroup, given in affine coordinates over `Fp2`.
+pub struct G2AddAssignSyscall<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P> G2AddAssignSyscall<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
+
+impl<P: FpTowerField> Syscall for G2AddAssignSyscall<P> {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let p_ptr = arg1;
+        if !p_ptr.is_multiple_of(4) {
+            panic!();
+        }
+        let q_ptr = arg2;
+        if !q_ptr.is_multiple_of(4) {
+            panic!();
+        }
+
+        let num_words = 2 * <P as NumWords>::WordsCurvePoint::USIZE;
+
+        let p = rt.slice_unsafe(p_ptr, num_words);
+        let (q_memory_records, q) = rt.mr_slice(q_ptr, num_words);
+
+        // When we write to p, we want the clk to be incremented because p and q could be the same.
+        rt.clk += 1;
+
+        let result = g2_add::<P>(&g2_point_from_words::<P>(&p), &g2_point_from_words::<P>(&q));
+        let p_memory_records = rt.mw_slice(p_ptr, &g2_point_to_words::<P>(&result));
+
+        let event = EllipticCurveAddEvent {
+            shard: rt.current_shard(),
+            clk: start_clk,
+            p_ptr,
+            p,
+            q_ptr,
+            q,
+            p_memory_records,
+            q_memory_records,
+            local_mem_access: rt.postprocess(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::Bn254G2Add(event),
+            ),
+            FieldType::Bls12381 => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::Bls12381G2Add(event),
+            ),
+            _ => unreachable!(),
+        }
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+/// Doubling of a point of the G2 subgroup, given in affine coordinates over `Fp2`.
+pub struct G2DoubleAssignSyscall<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P> G2DoubleAssignSyscall<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
+
+impl<P: FpTowerField> Syscall for G2DoubleAssignSyscall<P> {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let p_ptr = arg1;
+        if !p_ptr.is_multiple_of(4) {
+            panic!();
+        }
+
+        let num_words = 2 * <P as NumWords>::WordsCurvePoint::USIZE;
+
+        let p = rt.slice_unsafe(p_ptr, num_words);
+        let result = g2_double::<P>(&g2_point_from_words::<P>(&p));
+        let p_memory_records = rt.mw_slice(p_ptr, &g2_point_to_words::<P>(&result));
+
+        let event = EllipticCurveDoubleEvent {
+            shard: rt.current_shard(),
+            clk: start_clk,
+            p_ptr,
+            p,
+            p_memory_records,
+            local_mem_access: rt.postprocess(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::Bn254G2Double(event),
+            ),
+            FieldType::Bls12381 => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::Bls12381G2Double(event),
+            ),
+            _ => unreachable!(),
+        }
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        0
+    }
+}
@@ -1,7 +1,11 @@
 mod fp;
 mod fp2_addsub;
 mod fp2_mul;
+mod g2;
+mod tower_mul;
 
 pub use fp::*;
 pub use fp2_addsub::*;
 pub use fp2_mul::*;
+pub use g2::*;
+pub use tower_mul::*;
@@ -0,0 +1,98 @@
+use std::marker::PhantomData;
+
+use num::BigUint;
+use monerochan_curves::{
+    params::NumWords,
+    weierstrass::{
+        tower::{fp_tower_mul, FP12_DEGREE, FP6_DEGREE},
+        FieldType, FpTowerField,
+    },
+};
+use typenum::Unsigned;
+
+use crate::{
+    events::{FpTowerMulEvent, PrecompileEvent},
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// Multiplication in `Fp6` or `Fp12`, seen as extensions of degree `degree` over `Fp2`.
+pub struct FpTowerMulSyscall<P> {
+    degree: usize,
+    _marker: PhantomData<P>,
+}
+
+impl<P> FpTowerMulSyscall<P> {
+    pub const fn new(degree: usize) -> Self {
+        Self { degree, _marker: PhantomData }
+    }
+}
+
+impl<P: FpTowerField> Syscall for FpTowerMulSyscall<P> {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+        let x_ptr = arg1;
+        if !x_ptr.is_multiple_of(4) {
+            panic!();
+        }
+        let y_ptr = arg2;
+        if !y_ptr.is_multiple_of(4) {
+            panic!();
+        }
+
+        let num_words_field_element = <P as NumWords>::WordsFieldElement::USIZE;
+        let num_words = 2 * self.degree * num_words_field_element;
+
+        let x = rt.slice_unsafe(x_ptr, num_words);
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, num_words);
+        rt.clk += 1;
+
+        let x_coeffs =
+            x.chunks(num_words_field_element).map(BigUint::from_slice).collect::<Vec<_>>();
+        let y_coeffs =
+            y.chunks(num_words_field_element).map(BigUint::from_slice).collect::<Vec<_>>();
+
+        // Each coefficient of the result should use the same number of words.
+        // This is regardless of how many u32 digits are required to express them.
+        let mut result = Vec::with_capacity(num_words);
+        for coeff in fp_tower_mul::<P>(self.degree, &x_coeffs, &y_coeffs) {
+            let mut words = coeff.to_u32_digits();
+            words.resize(num_words_field_element, 0);
+            result.append(&mut words);
+        }
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let shard = rt.current_shard();
+        let event = FpTowerMulEvent {
+            shard,
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            x_memory_records,
+            y_memory_records,
+            local_mem_access: rt.postprocess(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        let event = match (P::FIELD_TYPE, self.degree) {
+            (FieldType::Bn254, FP6_DEGREE) => PrecompileEvent::Bn254Fp6Mul(event),
+            (FieldType::Bn254, FP12_DEGREE) => PrecompileEvent::Bn254Fp12Mul(event),
+            (FieldType::Bls12381, FP6_DEGREE) => PrecompileEvent::Bls12381Fp6Mul(event),
+            (FieldType::Bls12381, FP12_DEGREE) => PrecompileEvent::Bls12381Fp12Mul(event),
+            _ => unreachable!(),
+        };
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -1,3 +1,5 @@
+pub mod aes;
+pub mod chacha20;
 pub mod edwards;
 pub mod fptower;
 pub mod keccak256;
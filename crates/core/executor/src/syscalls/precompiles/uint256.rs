@@ -4,7 +4,7 @@ use monerochan_curves::edwards::WORDS_FIELD_ELEMENT;
 use monerochan_primitives::consts::{bytes_to_words_le, words_to_bytes_le_vec, WORD_SIZE};
 
 use crate::{
-    events::{PrecompileEvent, Uint256MulEvent},
+    events::{FieldOperation, PrecompileEvent, Uint256AddSubEvent, Uint256MulEvent},
     syscalls::{Syscall, SyscallCode, SyscallContext},
 };
 
@@ -89,3 +89,183 @@ impl Syscall for Uint256MulSyscall {
         1
     }
 }
+
+/// The modular multiplication of `NUM_WORDS`-word integers, used by `UINT2048_MUL` and
+/// `UINT4096_MUL`.
+///
+/// The memory layout matches `UINT256_MUL`: `x` is overwritten with `x * y mod modulus`, and the
+/// modulus follows `y`. A zero modulus stands for `2^(32 * NUM_WORDS)`.
+pub(crate) struct UintMulModSyscall<const NUM_WORDS: usize>;
+
+impl<const NUM_WORDS: usize> Syscall for UintMulModSyscall<NUM_WORDS> {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        if !x_ptr.is_multiple_of(4) {
+            panic!();
+        }
+        let y_ptr = arg2;
+        if !y_ptr.is_multiple_of(4) {
+            panic!();
+        }
+
+        let x = rt.slice_unsafe(x_ptr, NUM_WORDS);
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, NUM_WORDS);
+        let modulus_ptr = y_ptr + NUM_WORDS as u32 * WORD_SIZE as u32;
+        let (modulus_memory_records, modulus) = rt.mr_slice(modulus_ptr, NUM_WORDS);
+
+        let uint_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&y));
+        let uint_modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&modulus));
+
+        let result: BigUint = if uint_modulus.is_zero() {
+            (uint_x * uint_y) % (BigUint::one() << (32 * NUM_WORDS))
+        } else {
+            (uint_x * uint_y) % uint_modulus
+        };
+
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(NUM_WORDS * WORD_SIZE, 0u8);
+        let result = result_bytes
+            .chunks_exact(WORD_SIZE)
+            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect::<Vec<_>>();
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+
+        let shard = rt.current_shard();
+        let event = Uint256MulEvent {
+            shard,
+            clk,
+            x_ptr,
+            x,
+            y_ptr,
+            y,
+            modulus,
+            x_memory_records,
+            y_memory_records,
+            modulus_memory_records,
+            local_mem_access: rt.postprocess(),
+        };
+        let event = match syscall_code {
+            SyscallCode::UINT2048_MUL => PrecompileEvent::Uint2048Mul(event),
+            SyscallCode::UINT4096_MUL => PrecompileEvent::Uint4096Mul(event),
+            _ => unreachable!(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+/// The addition with carry and subtraction with borrow of uint256 values.
+///
+/// `x` points to eight words followed by a carry word holding the incoming carry (or borrow),
+/// which must be 0 or 1. Both are overwritten with the result and the outgoing carry.
+pub(crate) struct Uint256AddSubSyscall {
+    op: FieldOperation,
+}
+
+impl Uint256AddSubSyscall {
+    pub(crate) const fn new(op: FieldOperation) -> Self {
+        Self { op }
+    }
+}
+
+impl Syscall for Uint256AddSubSyscall {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let clk = rt.clk;
+
+        let x_ptr = arg1;
+        if !x_ptr.is_multiple_of(4) {
+            panic!();
+        }
+        let y_ptr = arg2;
+        if !y_ptr.is_multiple_of(4) {
+            panic!();
+        }
+
+        let x = rt.slice_unsafe(x_ptr, WORDS_FIELD_ELEMENT);
+        let carry_ptr = x_ptr + WORDS_FIELD_ELEMENT as u32 * WORD_SIZE as u32;
+        let carry = rt.slice_unsafe(carry_ptr, 1)[0];
+        assert!(carry <= 1, "the carry of a uint256 add/sub must be 0 or 1, got {carry}");
+        let (y_memory_records, y) = rt.mr_slice(y_ptr, WORDS_FIELD_ELEMENT);
+
+        let uint256_x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&x));
+        let uint256_y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&y));
+        let modulus = BigUint::one() << 256;
+        let (result, carry_out) = match self.op {
+            FieldOperation::Add => {
+                let sum = uint256_x + uint256_y + carry;
+                let carry_out = u32::from(sum >= modulus);
+                (sum % &modulus, carry_out)
+            }
+            FieldOperation::Sub => {
+                let subtrahend = uint256_y + carry;
+                if uint256_x >= subtrahend {
+                    (uint256_x - subtrahend, 0)
+                } else {
+                    (uint256_x + &modulus - subtrahend, 1)
+                }
+            }
+            _ => unreachable!(),
+        };
+
+        let mut result_bytes = result.to_bytes_le();
+        result_bytes.resize(32, 0u8);
+        let result = bytes_to_words_le::<8>(&result_bytes);
+
+        // Increment clk so that the write is not at the same cycle as the read.
+        rt.clk += 1;
+        let x_memory_records = rt.mw_slice(x_ptr, &result);
+        let carry_memory_record = rt.mw(carry_ptr, carry_out);
+
+        let shard = rt.current_shard();
+        let event = PrecompileEvent::Uint256AddSub(Uint256AddSubEvent {
+            shard,
+            clk,
+            x_ptr,
+            x,
+            carry,
+            y_ptr,
+            y,
+            op: self.op,
+            x_memory_records,
+            carry_memory_record,
+            y_memory_records,
+            local_mem_access: rt.postprocess(),
+        });
+
+        // Both operations are on the same table, so their events share the syscall code key.
+        let syscall_event =
+            rt.rt.syscall_event(clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        rt.add_precompile_event(SyscallCode::UINT256_ADD_CARRY, syscall_event, event);
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
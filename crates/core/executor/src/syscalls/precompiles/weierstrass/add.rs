@@ -52,6 +52,21 @@ impl<E: EllipticCurve> Syscall for WeierstrassAddAssignSyscall<E> {
                 syscall_event,
                 PrecompileEvent::Secp256r1Add(event),
             ),
+            CurveType::Pallas => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::PallasAdd(event),
+            ),
+            CurveType::Vesta => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::VestaAdd(event),
+            ),
+            CurveType::Grumpkin => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::GrumpkinAdd(event),
+            ),
             _ => panic!("Unsupported curve"),
         }
         None
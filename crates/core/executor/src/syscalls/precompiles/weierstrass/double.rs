@@ -56,6 +56,21 @@ impl<E: EllipticCurve> Syscall for WeierstrassDoubleAssignSyscall<E> {
                     PrecompileEvent::Bls12381Double(event),
                 );
             }
+            CurveType::Pallas => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::PallasDouble(event),
+            ),
+            CurveType::Vesta => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::VestaDouble(event),
+            ),
+            CurveType::Grumpkin => rt.add_precompile_event(
+                syscall_code,
+                syscall_event,
+                PrecompileEvent::GrumpkinDouble(event),
+            ),
             _ => panic!("Unsupported curve"),
         }
         None
@@ -0,0 +1,128 @@
+use std::marker::PhantomData;
+
+use monerochan_curves::{weierstrass::EcdsaParameters, CurveType, EllipticCurve};
+
+use crate::{
+    events::{
+        ecdsa_verify_output, ecrecover_output, EcdsaEvent, PrecompileEvent,
+        ECDSA_VERIFY_INPUT_WORDS, ECRECOVER_INPUT_WORDS,
+    },
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// Recovers the public key of an ECDSA signature on a Weierstrass curve.
+///
+/// The input at `arg2` holds the message hash, `r`, `s` and the recovery id, and the public key
+/// and the status are written to `arg1`. See [`ecrecover_output`].
+pub(crate) struct WeierstrassEcrecoverSyscall<E: EllipticCurve> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: EllipticCurve> WeierstrassEcrecoverSyscall<E> {
+    /// Create a new instance of the [`WeierstrassEcrecoverSyscall`].
+    pub const fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+/// Verifies an ECDSA signature on a Weierstrass curve.
+///
+/// The input at `arg2` holds the public key, the message hash, `r` and `s`, and the status is
+/// written to `arg1`. See [`ecdsa_verify_output`].
+pub(crate) struct WeierstrassEcdsaVerifySyscall<E: EllipticCurve> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: EllipticCurve> WeierstrassEcdsaVerifySyscall<E> {
+    /// Create a new instance of the [`WeierstrassEcdsaVerifySyscall`].
+    pub const fn new() -> Self {
+        Self { _phantom: PhantomData }
+    }
+}
+
+/// Reads the input, computes the output with `f` and writes it, returning the event.
+///
+/// The input is read at the start clock and the output is written one cycle later.
+fn create_ecdsa_event(
+    rt: &mut SyscallContext,
+    arg1: u32,
+    arg2: u32,
+    input_words: usize,
+    f: impl FnOnce(&[u32]) -> Vec<u32>,
+) -> EcdsaEvent {
+    let start_clk = rt.clk;
+    let out_ptr = arg1;
+    assert!(out_ptr.is_multiple_of(4), "out_ptr must be 4-byte aligned");
+    let in_ptr = arg2;
+    assert!(in_ptr.is_multiple_of(4), "in_ptr must be 4-byte aligned");
+
+    let (input_memory_records, input) = rt.mr_slice(in_ptr, input_words);
+    let output = f(&input);
+
+    rt.clk += 1;
+    let output_memory_records = rt.mw_slice(out_ptr, &output);
+
+    EcdsaEvent {
+        shard: rt.current_shard(),
+        clk: start_clk,
+        out_ptr,
+        in_ptr,
+        input,
+        input_memory_records,
+        output,
+        output_memory_records,
+        local_mem_access: rt.postprocess(),
+    }
+}
+
+impl<E: EllipticCurve + EcdsaParameters> Syscall for WeierstrassEcrecoverSyscall<E> {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let event =
+            create_ecdsa_event(rt, arg1, arg2, ECRECOVER_INPUT_WORDS, ecrecover_output::<E>);
+        let syscall_event =
+            rt.rt.syscall_event(event.clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        let event = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => PrecompileEvent::Secp256k1Ecrecover(event),
+            CurveType::Secp256r1 => PrecompileEvent::Secp256r1Ecrecover(event),
+            _ => panic!("Unsupported curve"),
+        };
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
+
+impl<E: EllipticCurve + EcdsaParameters> Syscall for WeierstrassEcdsaVerifySyscall<E> {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let event =
+            create_ecdsa_event(rt, arg1, arg2, ECDSA_VERIFY_INPUT_WORDS, ecdsa_verify_output::<E>);
+        let syscall_event =
+            rt.rt.syscall_event(event.clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        let event = match E::CURVE_TYPE {
+            CurveType::Secp256k1 => PrecompileEvent::Secp256k1EcdsaVerify(event),
+            CurveType::Secp256r1 => PrecompileEvent::Secp256r1EcdsaVerify(event),
+            _ => panic!("Unsupported curve"),
+        };
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        1
+    }
+}
@@ -0,0 +1,152 @@
+use std::marker::PhantomData;
+
+use monerochan_curves::{CurveType, EllipticCurve};
+
+use crate::{
+    events::{
+        create_ec_add_event, create_ec_double_event, msm_op_clk, msm_table_entry_ptr,
+        msm_window_cycles, msm_window_digits, EllipticCurveMsmEvent, PrecompileEvent,
+        MSM_NUM_WINDOWS, MSM_SCALAR_WORDS, MSM_WINDOW_BITS,
+    },
+    syscalls::{Syscall, SyscallCode, SyscallContext},
+};
+
+/// Executes one chunk of a fixed-window scalar multiplication (one point) or multi-scalar
+/// multiplication (several points) on a Weierstrass curve.
+///
+/// For every window, the accumulator at `arg1` is doubled [`MSM_WINDOW_BITS`] times and the
+/// nonzero window digit of each scalar selects the multiple of the point to add from the window
+/// tables at `arg2`. The doublings and additions are delegated to the curve's add and double
+/// precompiles.
+pub(crate) struct WeierstrassMsmSyscall<E: EllipticCurve> {
+    num_points: usize,
+    _phantom: PhantomData<E>,
+}
+
+impl<E: EllipticCurve> WeierstrassMsmSyscall<E> {
+    /// Create a new instance of the [`WeierstrassMsmSyscall`].
+    pub const fn new(num_points: usize) -> Self {
+        Self { num_points, _phantom: PhantomData }
+    }
+}
+
+impl<E: EllipticCurve> Syscall for WeierstrassMsmSyscall<E> {
+    fn execute(
+        &self,
+        rt: &mut SyscallContext,
+        syscall_code: SyscallCode,
+        arg1: u32,
+        arg2: u32,
+    ) -> Option<u32> {
+        let start_clk = rt.clk;
+        let p_ptr = arg1;
+        assert!(p_ptr.is_multiple_of(4), "p_ptr must be 4-byte aligned");
+        let input_ptr = arg2;
+        assert!(input_ptr.is_multiple_of(4), "input_ptr must be 4-byte aligned");
+
+        let (scalar_memory_records, scalars) =
+            rt.mr_slice(input_ptr, self.num_points * MSM_SCALAR_WORDS);
+
+        let event = EllipticCurveMsmEvent {
+            shard: rt.current_shard(),
+            clk: start_clk,
+            p_ptr,
+            input_ptr,
+            num_points: self.num_points,
+            scalars: scalars.clone(),
+            scalar_memory_records,
+            local_mem_access: rt.postprocess(),
+        };
+        let syscall_event =
+            rt.rt.syscall_event(start_clk, None, None, syscall_code, arg1, arg2, rt.next_pc);
+        let (add_code, double_code, event) = match (E::CURVE_TYPE, self.num_points) {
+            (CurveType::Secp256k1, 1) => (
+                SyscallCode::SECP256K1_ADD,
+                SyscallCode::SECP256K1_DOUBLE,
+                PrecompileEvent::Secp256k1ScalarMul(event),
+            ),
+            (CurveType::Secp256k1, _) => (
+                SyscallCode::SECP256K1_ADD,
+                SyscallCode::SECP256K1_DOUBLE,
+                PrecompileEvent::Secp256k1Msm(event),
+            ),
+            (CurveType::Secp256r1, 1) => (
+                SyscallCode::SECP256R1_ADD,
+                SyscallCode::SECP256R1_DOUBLE,
+                PrecompileEvent::Secp256r1ScalarMul(event),
+            ),
+            (CurveType::Secp256r1, _) => (
+                SyscallCode::SECP256R1_ADD,
+                SyscallCode::SECP256R1_DOUBLE,
+                PrecompileEvent::Secp256r1Msm(event),
+            ),
+            (CurveType::Bn254, 1) => (
+                SyscallCode::BN254_ADD,
+                SyscallCode::BN254_DOUBLE,
+                PrecompileEvent::Bn254ScalarMul(event),
+            ),
+            (CurveType::Bn254, _) => (
+                SyscallCode::BN254_ADD,
+                SyscallCode::BN254_DOUBLE,
+                PrecompileEvent::Bn254Msm(event),
+            ),
+            (CurveType::Bls12381, 1) => (
+                SyscallCode::BLS12381_ADD,
+                SyscallCode::BLS12381_DOUBLE,
+                PrecompileEvent::Bls12381ScalarMul(event),
+            ),
+            (CurveType::Bls12381, _) => (
+                SyscallCode::BLS12381_ADD,
+                SyscallCode::BLS12381_DOUBLE,
+                PrecompileEvent::Bls12381Msm(event),
+            ),
+            _ => panic!("Unsupported curve"),
+        };
+        rt.add_precompile_event(syscall_code, syscall_event, event);
+
+        for (window, digits) in msm_window_digits(&scalars, self.num_points).iter().enumerate() {
+            for op in 0..MSM_WINDOW_BITS {
+                rt.clk = msm_op_clk(start_clk, self.num_points, window, op);
+                let event = create_ec_double_event::<E>(rt, p_ptr, 0);
+                let syscall_event =
+                    rt.rt.syscall_event(event.clk, None, None, double_code, p_ptr, 0, rt.next_pc);
+                let event = match E::CURVE_TYPE {
+                    CurveType::Secp256k1 => PrecompileEvent::Secp256k1Double(event),
+                    CurveType::Secp256r1 => PrecompileEvent::Secp256r1Double(event),
+                    CurveType::Bn254 => PrecompileEvent::Bn254Double(event),
+                    CurveType::Bls12381 => PrecompileEvent::Bls12381Double(event),
+                    _ => panic!("Unsupported curve"),
+                };
+                rt.add_precompile_event(double_code, syscall_event, event);
+            }
+
+            for (point, &digit) in digits.iter().enumerate() {
+                // A zero digit leaves the accumulator unchanged.
+                if digit == 0 {
+                    continue;
+                }
+                rt.clk =
+                    msm_op_clk(start_clk, self.num_points, window, MSM_WINDOW_BITS + 2 * point);
+                let q_ptr = msm_table_entry_ptr::<E>(input_ptr, self.num_points, point, digit);
+                let event = create_ec_add_event::<E>(rt, p_ptr, q_ptr);
+                let syscall_event =
+                    rt.rt.syscall_event(event.clk, None, None, add_code, p_ptr, q_ptr, rt.next_pc);
+                let event = match E::CURVE_TYPE {
+                    CurveType::Secp256k1 => PrecompileEvent::Secp256k1Add(event),
+                    CurveType::Secp256r1 => PrecompileEvent::Secp256r1Add(event),
+                    CurveType::Bn254 => PrecompileEvent::Bn254Add(event),
+                    CurveType::Bls12381 => PrecompileEvent::Bls12381Add(event),
+                    _ => panic!("Unsupported curve"),
+                };
+                rt.add_precompile_event(add_code, syscall_event, event);
+            }
+        }
+        rt.clk = start_clk;
+
+        None
+    }
+
+    fn num_extra_cycles(&self) -> u32 {
+        MSM_NUM_WINDOWS as u32 * msm_window_cycles(self.num_points)
+    }
+}
@@ -0,0 +1,85 @@
+//! The executor side of precompiles defined outside of this crate.
+//!
+//! The syscall codes [`SyscallCode::USER_PRECOMPILE_0`] through
+//! [`SyscallCode::USER_PRECOMPILE_3`] are reserved for precompiles that are not part of the
+//! default zkVM. A downstream crate collects the executor side of such precompiles in a
+//! [`UserSyscalls`] and passes it to the executor with
+//! [`crate::MONEROCHANContextBuilder::user_syscalls`]. The registered [`Syscall`]s are added to the
+//! syscall table of that executor only, and should emit a
+//! [`crate::events::UserPrecompileEvent`] through
+//! [`super::SyscallContext::add_user_precompile_event`].
+//!
+//! The matching AIR chips are collected by `monerochan_core_machine`'s `UserPrecompiles`, which
+//! builds the [`UserSyscalls`] alongside the machine.
+
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+
+use super::{Syscall, SyscallCode};
+
+/// The number of syscall codes reserved for user precompiles.
+pub const NUM_USER_PRECOMPILES: usize = 4;
+
+/// The syscall codes reserved for user precompiles.
+pub const USER_PRECOMPILE_CODES: [SyscallCode; NUM_USER_PRECOMPILES] = [
+    SyscallCode::USER_PRECOMPILE_0,
+    SyscallCode::USER_PRECOMPILE_1,
+    SyscallCode::USER_PRECOMPILE_2,
+    SyscallCode::USER_PRECOMPILE_3,
+];
+
+/// The executor side of a user precompile.
+#[derive(Clone)]
+pub struct UserSyscall {
+    /// The implementation of the syscall.
+    pub syscall: Arc<dyn Syscall>,
+    /// The number of rows the chip of the precompile uses for each event.
+    ///
+    /// This is used to size the shards and to derive the precompile shapes.
+    pub rows_per_event: usize,
+}
+
+/// The executor side of the user precompiles of a machine, by slot.
+#[derive(Clone, Default)]
+pub struct UserSyscalls {
+    syscalls: HashMap<SyscallCode, UserSyscall>,
+}
+
+impl UserSyscalls {
+    /// Creates an empty set of user precompiles.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the executor side of a user precompile in the slot `code`.
+    ///
+    /// Registering a slot twice replaces the previous registration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code` is not one of the [`USER_PRECOMPILE_CODES`] or if `rows_per_event` is zero.
+    pub fn insert(&mut self, code: SyscallCode, syscall: UserSyscall) {
+        assert!(code.is_user_precompile(), "{code} is not a user precompile slot");
+        assert!(syscall.rows_per_event > 0, "rows_per_event must be positive");
+        self.syscalls.insert(code, syscall);
+    }
+
+    /// Returns the user precompile registered in the slot `code`, if any.
+    #[must_use]
+    pub fn get(&self, code: SyscallCode) -> Option<&UserSyscall> {
+        self.syscalls.get(&code)
+    }
+
+    /// Returns the registered user precompiles, ordered by syscall code.
+    pub fn iter(&self) -> impl Iterator<Item = (SyscallCode, &UserSyscall)> {
+        USER_PRECOMPILE_CODES.into_iter().filter_map(|code| Some((code, self.get(code)?)))
+    }
+
+    /// Whether no user precompile is registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.syscalls.is_empty()
+    }
+}
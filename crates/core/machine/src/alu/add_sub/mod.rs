@@ -72,6 +72,10 @@ impl<F: PrimeField32> MachineAir<F> for AddSubChip {
         "AddSub".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        AddSubCols::<F>::column_names()
+    }
+
     fn num_rows(&self, input: &Self::Record) -> Option<usize> {
         let nb_rows = next_power_of_two(
             input.add_events.len() + input.sub_events.len(),
@@ -66,6 +66,10 @@ impl<F: PrimeField32> MachineAir<F> for BitwiseChip {
         "Bitwise".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        BitwiseCols::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
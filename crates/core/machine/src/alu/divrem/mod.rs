@@ -207,6 +207,10 @@ impl<F: PrimeField32> MachineAir<F> for DivRemChip {
         "DivRem".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        DivRemCols::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
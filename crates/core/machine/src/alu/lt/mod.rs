@@ -101,6 +101,10 @@ impl<F: PrimeField32> MachineAir<F> for LtChip {
         "Lt".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        LtCols::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
@@ -126,6 +126,10 @@ impl<F: PrimeField32> MachineAir<F> for MulChip {
         "Mul".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        MulCols::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
@@ -110,6 +110,10 @@ impl<F: PrimeField32> MachineAir<F> for ShiftLeft {
         "ShiftLeft".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        ShiftLeftCols::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
@@ -143,6 +143,10 @@ impl<F: PrimeField32> MachineAir<F> for ShiftRightChip {
         "ShiftRight".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        ShiftRightCols::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
@@ -66,6 +66,14 @@ impl<AB: MONEROCHANAirBuilder + PairBuilder> Air<AB> for ByteChip<AB::F> {
                     AB::F::zero(),
                     mult,
                 ),
+                ByteOpcode::AesSbox => builder.receive_byte_pair(
+                    field_op,
+                    local.sbox,
+                    local.sbox_xtime,
+                    local.b,
+                    AB::F::zero(),
+                    mult,
+                ),
             }
         }
     }
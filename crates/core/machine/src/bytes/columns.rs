@@ -42,6 +42,10 @@ pub struct BytePreprocessedCols<T> {
 
     /// A u16 value used for `U16Range`.
     pub value_u16: T,
+
+    /// The result of the AES S-box on `b`, and that result doubled in the AES field.
+    pub sbox: T,
+    pub sbox_xtime: T,
 }
 
 /// For each byte operation in the preprocessed table, a corresponding ByteMultCols row tracks the
@@ -5,7 +5,10 @@ pub mod columns;
 pub mod trace;
 pub mod utils;
 
-use monerochan_core_executor::{events::ByteLookupEvent, ByteOpcode};
+use monerochan_core_executor::{
+    events::{aes_xtime, ByteLookupEvent, AES_SBOX},
+    ByteOpcode,
+};
 
 use core::borrow::BorrowMut;
 use std::marker::PhantomData;
@@ -21,7 +24,7 @@ use self::{
 use crate::{bytes::trace::NUM_ROWS, utils::zeroed_f_vec};
 
 /// The number of different byte operations.
-pub const NUM_BYTE_OPS: usize = 9;
+pub const NUM_BYTE_OPS: usize = 10;
 
 /// A chip for computing byte operations.
 ///
@@ -99,6 +102,13 @@ impl<F: Field> ByteChip<F> {
                         col.value_u16 = F::from_canonical_u32(v);
                         ByteLookupEvent::new(*opcode, v as u16, 0, 0, 0)
                     }
+                    ByteOpcode::AesSbox => {
+                        let sbox = AES_SBOX[b as usize];
+                        let sbox_xtime = aes_xtime(sbox);
+                        col.sbox = F::from_canonical_u8(sbox);
+                        col.sbox_xtime = F::from_canonical_u8(sbox_xtime);
+                        ByteLookupEvent::new(*opcode, sbox as u16, sbox_xtime, b, 0)
+                    }
                 };
             }
         }
@@ -23,6 +23,10 @@ impl<F: PrimeField32> MachineAir<F> for ByteChip<F> {
         "Byte".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        ByteMultCols::<F>::column_names()
+    }
+
     fn preprocessed_width(&self) -> usize {
         NUM_BYTE_PREPROCESSED_COLS
     }
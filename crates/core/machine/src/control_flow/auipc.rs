@@ -165,6 +165,10 @@ impl<F: PrimeField32> MachineAir<F> for AuipcChip {
         "Auipc".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        AuipcColumns::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
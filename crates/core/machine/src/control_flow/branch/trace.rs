@@ -24,6 +24,10 @@ impl<F: PrimeField32> MachineAir<F> for BranchChip {
         "Branch".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        BranchColumns::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
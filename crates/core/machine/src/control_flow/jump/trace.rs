@@ -24,6 +24,10 @@ impl<F: PrimeField32> MachineAir<F> for JumpChip {
         "Jump".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        JumpColumns::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
@@ -26,6 +26,10 @@ impl<F: PrimeField32> MachineAir<F> for CpuChip {
         self.id().to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        CpuCols::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
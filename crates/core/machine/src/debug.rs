@@ -0,0 +1,126 @@
+//! Tests of the constraint and interaction debugging of `monerochan-stark` on the RISC-V chips.
+
+use core::borrow::BorrowMut;
+
+use p3_baby_bear::BabyBear;
+use p3_field::AbstractField;
+use p3_matrix::dense::RowMajorMatrix;
+use monerochan_core_executor::{events::AluEvent, ExecutionRecord, Executor, Opcode};
+use monerochan_stark::{
+    air::{InteractionScope, MachineAir},
+    baby_bear_poseidon2::BabyBearPoseidon2,
+    debug_constraints, find_unmatched_interactions,
+    septic_digest::SepticDigest,
+    Chip, InteractionKind, MONEROCHANCoreOpts, StarkGenericConfig,
+};
+
+use crate::{
+    alu::{AddSubChip, AddSubCols, NUM_ADD_SUB_COLS},
+    programs::tests::simple_program,
+    riscv::RiscvAir,
+};
+
+#[test]
+fn test_debug_constraints_report() {
+    type Challenge = <BabyBearPoseidon2 as StarkGenericConfig>::Challenge;
+
+    let mut shard = ExecutionRecord::default();
+    shard.add_events = vec![AluEvent::new(0, Opcode::ADD, 14, 8, 6, false)];
+    let chip = Chip::new(AddSubChip::default());
+    let mut trace: RowMajorMatrix<BabyBear> =
+        chip.generate_trace(&shard, &mut ExecutionRecord::default());
+
+    // Corrupt the result of the addition in the first row.
+    let cols: &mut AddSubCols<BabyBear> = trace.values[..NUM_ADD_SUB_COLS].borrow_mut();
+    cols.add_operation.value.0[0] += BabyBear::one();
+
+    let challenges = [Challenge::from_canonical_u32(7), Challenge::from_canonical_u32(11)];
+    let (perm_trace, local_cumulative_sum) =
+        chip.generate_permutation_trace(None, &trace, &challenges);
+    let report = debug_constraints::<BabyBearPoseidon2, _>(
+        &chip,
+        None,
+        &trace,
+        &perm_trace,
+        &challenges,
+        &[],
+        &local_cumulative_sum,
+        &SepticDigest::zero(),
+    )
+    .unwrap_err();
+
+    assert_eq!(report.chip, "AddSub");
+    assert_eq!(report.column_names, AddSubCols::<BabyBear>::column_names());
+    assert_eq!(report.column_names.len(), NUM_ADD_SUB_COLS);
+    assert!(report.column_names.contains(&"add_operation[0]".to_string()));
+    assert_eq!(report.num_failing_rows, 1);
+    assert!(report.num_failures > 0);
+    assert_eq!(report.failures.len(), report.num_failures);
+    assert!(report.failures.iter().all(|failure| failure.row == 0));
+    assert!(report.rows.contains_key(&0));
+
+    // The failures are located in the chip, not in the builders forwarding the assertions.
+    for location in report.failures.iter().filter_map(|failure| failure.location.as_deref()) {
+        assert!(!location.contains("p3-air"), "{location}");
+        assert!(!location.contains("stark/src/debug.rs"), "{location}");
+    }
+
+    // The report shows the failures and the failing row with its neighbours.
+    let report_string = report.to_string();
+    assert!(report_string.starts_with(&format!(
+        "chip AddSub: {} failing constraints in 1 of {} rows",
+        report.num_failures, report.height
+    )));
+    assert!(report_string.contains("add_operation[0]"));
+    assert!(report_string.contains("row 0"));
+}
+
+#[test]
+fn test_find_unmatched_interactions() {
+    let program = simple_program();
+    let mut runtime = Executor::new(program.clone(), MONEROCHANCoreOpts::default());
+    runtime.run().unwrap();
+    let machine = RiscvAir::machine(BabyBearPoseidon2::new());
+    let (pkey, _) = machine.setup(&program);
+    let mut shards = runtime.records.into_iter().map(|r| *r).collect::<Vec<_>>();
+    machine.generate_dependencies(&mut shards, &MONEROCHANCoreOpts::default(), None);
+
+    let report = find_unmatched_interactions(
+        &machine,
+        &pkey,
+        &shards[..1],
+        &[InteractionKind::Instruction],
+        InteractionScope::Local,
+    );
+    assert!(report.is_balanced());
+
+    // Drop the last addition of a copy of the shard, so the instruction the CPU sends for it is
+    // never received. The local interactions of each shard are checked separately.
+    let mut tampered = shards[0].clone();
+    tampered.add_events.pop();
+    let report = find_unmatched_interactions(
+        &machine,
+        &pkey,
+        &[shards[0].clone(), tampered],
+        &[InteractionKind::Instruction],
+        InteractionScope::Local,
+    );
+
+    assert_eq!(report.unmatched.len(), 1);
+    let unmatched = &report.unmatched[0];
+    assert_eq!(unmatched.kind, InteractionKind::Instruction);
+    assert_eq!(unmatched.balance, BabyBear::one());
+    assert_eq!(unmatched.occurrences.len(), 1);
+    assert_eq!(unmatched.occurrences[0].shard, 1);
+    assert!(unmatched.occurrences[0].data.is_send);
+    assert_eq!(unmatched.occurrences[0].data.chip_name, "Cpu");
+
+    // The report shows the unbalanced message and where it was sent.
+    let report_string = report.to_string();
+    assert!(report_string.starts_with(&format!(
+        "1 of the {} {} messages do not balance",
+        report.num_messages,
+        InteractionScope::Local
+    )));
+    assert!(report_string.contains("sent by Cpu in shard 1"));
+}
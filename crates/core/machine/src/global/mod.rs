@@ -71,6 +71,10 @@ impl<F: PrimeField32> MachineAir<F> for GlobalChip {
         "Global".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        GlobalCols::<F>::column_names()
+    }
+
     fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
         let events = &input.global_interaction_events;
 
@@ -39,6 +39,11 @@ pub mod reduce {
     pub use monerochan_stark::MONEROCHANReduceProof;
 }
 
+#[cfg(test)]
+mod debug;
+#[cfg(test)]
+mod monero;
+
 #[cfg(test)]
 pub mod programs {
     #[allow(dead_code)]
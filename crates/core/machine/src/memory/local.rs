@@ -90,6 +90,10 @@ impl<F: PrimeField32> MachineAir<F> for MemoryLocalChip {
         "MemoryLocal".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        MemoryLocalCols::<F>::column_names()
+    }
+
     fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
         let mut events = Vec::new();
 
@@ -71,6 +71,10 @@ impl<F: PrimeField32> MachineAir<F> for MemoryProgramChip {
         "MemoryProgram".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        MemoryProgramMultCols::<F>::column_names()
+    }
+
     fn preprocessed_width(&self) -> usize {
         NUM_MEMORY_PROGRAM_PREPROCESSED_COLS
     }
@@ -0,0 +1,34 @@
+//! Tests of the Ed25519 based programs of `monerochan-lib`, which exercise several precompiles at
+//! once.
+
+use monerochan_core_executor::Program;
+use monerochan_stark::CpuProver;
+use test_artifacts::{BULLETPROOFS_PLUS_ELF, CLSAG_ELF, MONERO_ELF, RISTRETTO255_ELF};
+
+use crate::{io::MONEROCHANStdin, utils};
+
+fn prove_program(elf: &[u8]) {
+    utils::setup_logger();
+    let program = Program::from(elf).unwrap();
+    utils::run_test::<CpuProver<_, _>>(program, MONEROCHANStdin::new()).unwrap();
+}
+
+#[test]
+fn test_monero_key_image_program() {
+    prove_program(MONERO_ELF);
+}
+
+#[test]
+fn test_clsag_program() {
+    prove_program(CLSAG_ELF);
+}
+
+#[test]
+fn test_bulletproofs_plus_program() {
+    prove_program(BULLETPROOFS_PLUS_ELF);
+}
+
+#[test]
+fn test_ristretto255_program() {
+    prove_program(RISTRETTO255_ELF);
+}
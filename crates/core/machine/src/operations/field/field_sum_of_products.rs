@@ -0,0 +1,299 @@
+use num::{BigUint, Zero};
+use p3_air::AirBuilder;
+use p3_field::{AbstractField, PrimeField32};
+use monerochan_core_executor::events::{ByteLookupEvent, ByteOpcode, ByteRecord};
+use monerochan_curves::params::{FieldParameters, Limbs};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::air::{Polynomial, MONEROCHANAirBuilder};
+
+use super::util::compute_root_quotient_and_shift;
+use crate::air::WordAirBuilder;
+
+/// The number of limbs by which the carry and the witness exceed the sizes used by `FieldOpCols`.
+pub const NB_EXTRA_LIMBS: usize = 2;
+
+/// The shift applied to the witness coefficients, so that `|w_i| < 2^21`.
+const WITNESS_OFFSET: usize = 1 << 21;
+
+/// The number of bits of the high part of a witness coefficient (the low part is a `u16`).
+const WITNESS_HIGH_BITS: u32 = 6;
+
+/// A set of columns to compute `sum_i c_i * a_i * b_i mod M`, where `a_i, b_i` are emulated
+/// elements and `c_i` are small signed integer constants.
+///
+/// This generalizes `FieldInnerProductCols` to the long, signed linear combinations that show up
+/// in extension field arithmetic. Negative terms are handled by adding a multiple of the modulus,
+/// the carry has two extra limbs, and each witness coefficient is `22` bits wide.
+///
+/// *Safety*: The columns assert that `result = sum_i c_i * a_i * b_i mod M` as long as the sum of
+/// the absolute values of the `c_i` is at most `128`, which keeps the coefficients of the
+/// vanishing polynomial within the witness range and the constraints from overflowing. The
+/// `result` is not asserted to be reduced; callers that need a canonical value should add a
+/// `FieldLtCols` check.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct FieldSumOfProductsCols<T, P: FieldParameters> {
+    /// The result of the linear combination.
+    pub result: Limbs<T, P::Limbs>,
+    pub(crate) carry: Limbs<T, P::Limbs>,
+    pub(crate) carry_ext: [T; NB_EXTRA_LIMBS],
+    pub(crate) witness_low: Limbs<T, P::Witness>,
+    pub(crate) witness_low_ext: [T; NB_EXTRA_LIMBS],
+    pub(crate) witness_high: Limbs<T, P::Witness>,
+    pub(crate) witness_high_ext: [T; NB_EXTRA_LIMBS],
+}
+
+/// The maximum allowed sum of the absolute values of the coefficients.
+pub const MAX_TOTAL_WEIGHT: u32 = 128;
+
+/// Returns `k * modulus`, the smallest multiple of the modulus that dominates the negative terms
+/// of a linear combination of products of `P::nb_bits()`-bit values.
+fn negative_offset<P: FieldParameters>(negative_weight: u32) -> BigUint {
+    let modulus = P::modulus();
+    let bound = BigUint::from(negative_weight) << (2 * P::nb_bits());
+    (bound / &modulus + 1u32) * modulus
+}
+
+fn split_coefficients(coefficients: impl Iterator<Item = i32>) -> (u32, u32) {
+    coefficients.fold((0, 0), |(pos, neg), c| {
+        if c >= 0 {
+            (pos + c.unsigned_abs(), neg)
+        } else {
+            (pos, neg + c.unsigned_abs())
+        }
+    })
+}
+
+impl<F: PrimeField32, P: FieldParameters> FieldSumOfProductsCols<F, P> {
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        terms: &[(i32, &BigUint, &BigUint)],
+    ) -> BigUint {
+        let (positive_weight, negative_weight) = split_coefficients(terms.iter().map(|t| t.0));
+        assert!(positive_weight + negative_weight <= MAX_TOTAL_WEIGHT);
+
+        let modulus = &P::modulus();
+        let offset = negative_offset::<P>(negative_weight);
+
+        let mut positive = offset.clone();
+        let mut negative = BigUint::zero();
+        for (c, a, b) in terms.iter() {
+            let product = BigUint::from(c.unsigned_abs()) * *a * *b;
+            if *c >= 0 {
+                positive += product;
+            } else {
+                negative += product;
+            }
+        }
+        let sum = positive - negative;
+
+        let result = &sum % modulus;
+        let carry = (&sum - &result) / modulus;
+        debug_assert!(carry.bits() as usize <= (P::NB_LIMBS + NB_EXTRA_LIMBS) * 8);
+
+        let p_modulus: Polynomial<F> = P::to_limbs_field::<F, _>(modulus).into();
+        let p_result: Polynomial<F> = P::to_limbs_field::<F, _>(&result).into();
+        let mut carry_limbs = carry.to_bytes_le();
+        carry_limbs.resize(P::NB_LIMBS + NB_EXTRA_LIMBS, 0u8);
+        let p_carry = Polynomial::<u8>::from_coefficients(&carry_limbs).as_field::<F>();
+        let p_offset = Polynomial::<u8>::from_coefficients(&offset.to_bytes_le()).as_field::<F>();
+
+        // Compute the vanishing polynomial.
+        let p_sum = terms.iter().fold(p_offset, |acc, (c, a, b)| {
+            let p_a: Polynomial<F> = P::to_limbs_field::<F, _>(a).into();
+            let p_b: Polynomial<F> = P::to_limbs_field::<F, _>(b).into();
+            acc + &(&p_a * &p_b) * coefficient_to_field::<F>(*c)
+        });
+        let p_vanishing = p_sum - &p_result - &p_carry * &p_modulus;
+
+        let nb_witness_limbs = P::NB_WITNESS_LIMBS + NB_EXTRA_LIMBS;
+        let p_witness = compute_root_quotient_and_shift(
+            &p_vanishing,
+            WITNESS_OFFSET,
+            P::NB_BITS_PER_LIMB as u32,
+            nb_witness_limbs,
+        );
+
+        self.result = p_result.into();
+        for (i, limb) in carry_limbs.iter().enumerate() {
+            let limb = F::from_canonical_u8(*limb);
+            if i < P::NB_LIMBS {
+                self.carry[i] = limb;
+            } else {
+                self.carry_ext[i - P::NB_LIMBS] = limb;
+            }
+        }
+        for (i, w) in p_witness.iter().enumerate() {
+            let w = w.as_canonical_u32();
+            let (low, high) = (w & 0xffff, w >> 16);
+            debug_assert!(high < (1 << WITNESS_HIGH_BITS));
+            record.add_u16_range_check(low as u16);
+            record.add_byte_lookup_event(ByteLookupEvent {
+                opcode: ByteOpcode::LTU,
+                a1: 1,
+                a2: 0,
+                b: high as u8,
+                c: 1 << WITNESS_HIGH_BITS,
+            });
+            let (low, high) = (F::from_canonical_u32(low), F::from_canonical_u32(high));
+            if i < P::NB_WITNESS_LIMBS {
+                self.witness_low[i] = low;
+                self.witness_high[i] = high;
+            } else {
+                self.witness_low_ext[i - P::NB_WITNESS_LIMBS] = low;
+                self.witness_high_ext[i - P::NB_WITNESS_LIMBS] = high;
+            }
+        }
+
+        // Range checks
+        record.add_u8_range_checks_field(&self.result.0);
+        record.add_u8_range_checks_field(&self.carry.0);
+        record.add_u8_range_checks_field(&self.carry_ext);
+
+        result
+    }
+}
+
+impl<V: Copy, P: FieldParameters> FieldSumOfProductsCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    pub fn eval<AB: MONEROCHANAirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        terms: &[(i32, Polynomial<AB::Expr>, Polynomial<AB::Expr>)],
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        let (positive_weight, negative_weight) = split_coefficients(terms.iter().map(|t| t.0));
+        assert!(positive_weight + negative_weight <= MAX_TOTAL_WEIGHT);
+
+        let offset = negative_offset::<P>(negative_weight);
+        let p_offset = Polynomial::from_iter(
+            offset.to_bytes_le().into_iter().map(|x| AB::Expr::from_canonical_u8(x)),
+        );
+
+        let p_sum = terms.iter().fold(p_offset, |acc, (c, a, b)| {
+            acc + &(a * b) * AB::Expr::from(coefficient_to_field::<AB::F>(*c))
+        });
+
+        let p_result: Polynomial<<AB as AirBuilder>::Expr> = self.result.into();
+        let p_carry = Polynomial::from_iter(
+            self.carry.0.iter().chain(self.carry_ext.iter()).map(|&x| x.into()),
+        );
+        let p_limbs = Polynomial::from_iter(P::modulus_field_iter::<AB::F>().map(AB::Expr::from));
+        let p_vanishing = &(&p_sum - &p_result) - &(&p_carry * &p_limbs);
+
+        // Reconstruct and shift back the witness polynomial.
+        let two_16: AB::Expr = AB::F::from_canonical_u32(1 << 16).into();
+        let offset: AB::Expr = AB::F::from_canonical_u32(WITNESS_OFFSET as u32).into();
+        let p_witness = Polynomial::from_iter(
+            self.witness_low
+                .0
+                .iter()
+                .chain(self.witness_low_ext.iter())
+                .zip(self.witness_high.0.iter().chain(self.witness_high_ext.iter()))
+                .map(|(&low, &high)| low.into() + high.into() * two_16.clone() - offset.clone()),
+        );
+
+        // Multiply by (x-2^NB_BITS_PER_LIMB) and make the constraint.
+        let limb: AB::Expr = AB::F::from_canonical_u32(1 << P::NB_BITS_PER_LIMB).into();
+        let root_monomial = Polynomial::new(vec![-limb, AB::F::one().into()]);
+        let constraints = p_vanishing - &(p_witness * root_monomial);
+        for constr in constraints.as_coefficients() {
+            builder.assert_zero(constr);
+        }
+
+        // Range checks for the result, carry, and witness columns.
+        builder.slice_range_check_u8(&self.result.0, is_real.clone());
+        builder.slice_range_check_u8(&self.carry.0, is_real.clone());
+        builder.slice_range_check_u8(&self.carry_ext, is_real.clone());
+        builder.slice_range_check_u16(&self.witness_low.0, is_real.clone());
+        builder.slice_range_check_u16(&self.witness_low_ext, is_real.clone());
+        for &high in self.witness_high.0.iter().chain(self.witness_high_ext.iter()) {
+            builder.send_byte(
+                ByteOpcode::LTU.as_field::<AB::F>(),
+                AB::F::one(),
+                high,
+                AB::F::from_canonical_u32(1 << WITNESS_HIGH_BITS),
+                is_real.clone(),
+            );
+        }
+    }
+}
+
+fn coefficient_to_field<F: PrimeField32>(c: i32) -> F {
+    let abs = F::from_canonical_u32(c.unsigned_abs());
+    if c >= 0 {
+        abs
+    } else {
+        -abs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{borrow::BorrowMut, mem::size_of};
+
+    use num::{bigint::RandBigInt, BigInt};
+    use p3_baby_bear::BabyBear;
+    use p3_field::AbstractField;
+    use rand::thread_rng;
+    use monerochan_core_executor::ExecutionRecord;
+    use monerochan_curves::{
+        params::FieldParameters,
+        weierstrass::{bls12_381::Bls12381BaseField, bn254::Bn254BaseField},
+    };
+
+    use super::FieldSumOfProductsCols;
+
+    fn check_random_combinations<P: FieldParameters>(coefficients: &[i32]) {
+        let mut rng = thread_rng();
+        let modulus = BigInt::from(P::modulus());
+        for _ in 0..16 {
+            let operands = coefficients
+                .iter()
+                .map(|_| (rng.gen_biguint(P::nb_bits() as u64), rng.gen_biguint(P::nb_bits() as u64)))
+                .collect::<Vec<_>>();
+            let terms = coefficients
+                .iter()
+                .zip(operands.iter())
+                .map(|(c, (a, b))| (*c, a, b))
+                .collect::<Vec<_>>();
+
+            let mut row = vec![BabyBear::zero(); size_of::<FieldSumOfProductsCols<u8, P>>()];
+            let cols: &mut FieldSumOfProductsCols<BabyBear, P> = row.as_mut_slice().borrow_mut();
+            let result = cols.populate(&mut ExecutionRecord::default(), &terms);
+
+            let expected = terms.iter().fold(BigInt::from(0), |acc, (c, a, b)| {
+                acc + BigInt::from(*c) * BigInt::from((*a).clone()) * BigInt::from((*b).clone())
+            });
+            let expected = ((expected % &modulus) + &modulus) % &modulus;
+            assert_eq!(BigInt::from(result), expected);
+        }
+    }
+
+    #[test]
+    fn test_bn254_sum_of_products() {
+        // The heaviest coefficient of an Fp12 product over BN254, where `xi = 9 + u`.
+        let mut coefficients = vec![1, -1];
+        for _ in 0..5 {
+            coefficients.extend([9, -9, -1, -1]);
+        }
+        check_random_combinations::<Bn254BaseField>(&coefficients);
+        check_random_combinations::<Bn254BaseField>(&[1, 1, -1, 1]);
+    }
+
+    #[test]
+    fn test_bls12381_sum_of_products() {
+        // The heaviest coefficient of an Fp12 product over BLS12-381, where `xi = 1 + u`.
+        let mut coefficients = vec![1, -1];
+        for _ in 0..5 {
+            coefficients.extend([1, -1, -1, -1]);
+        }
+        check_random_combinations::<Bls12381BaseField>(&coefficients);
+        check_random_combinations::<Bls12381BaseField>(&[-1, -1, -1]);
+    }
+}
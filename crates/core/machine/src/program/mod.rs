@@ -58,6 +58,10 @@ impl<F: PrimeField32> MachineAir<F> for ProgramChip {
         "Program".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        ProgramMultiplicityCols::<F>::column_names()
+    }
+
     fn preprocessed_width(&self) -> usize {
         NUM_PROGRAM_PREPROCESSED_COLS
     }
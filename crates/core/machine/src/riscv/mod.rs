@@ -5,7 +5,12 @@ use core::fmt;
 use hashbrown::{HashMap, HashSet};
 use p3_field::PrimeField32;
 use monerochan_core_executor::{ExecutionRecord, Program, RiscvAirId};
-use monerochan_curves::weierstrass::{bls12_381::Bls12381BaseField, bn254::Bn254BaseField};
+use monerochan_curves::weierstrass::{
+    bls12_381::Bls12381BaseField,
+    bn254::Bn254BaseField,
+    secp256k1::{Secp256k1BaseField, Secp256k1ScalarField},
+    secp256r1::{Secp256r1BaseField, Secp256r1ScalarField},
+};
 use monerochan_stark::{
     air::{InteractionScope, MachineAir, MONEROCHAN_PROOF_NUM_PV_ELTS},
     Chip, InteractionKind, StarkGenericConfig, StarkMachine,
@@ -20,7 +25,13 @@ use crate::{
     shape::Shapeable,
     syscall::{
         instructions::SyscallInstrsChip,
-        precompiles::fptower::{Fp2AddSubAssignChip, Fp2MulAssignChip, FpOpChip},
+        precompiles::{
+            fptower::{
+                Fp12MulAssignChip, Fp2AddSubAssignChip, Fp2MulAssignChip, Fp6MulAssignChip,
+                FpOpChip, G2AddAssignChip, G2DoubleAssignChip,
+            },
+            user::{UserPrecompileChip, UserPrecompiles},
+        },
     },
 };
 
@@ -35,23 +46,32 @@ pub(crate) mod riscv_chips {
         syscall::{
             chip::SyscallChip,
             precompiles::{
-                edwards::{EdAddAssignChip, EdDecompressChip},
+                aes::AesEncryptBlockChip,
+                chacha20::ChaCha20BlockChip,
+                edwards::{EdAddAssignChip, EdDecompressChip, X25519LadderStepChip},
                 keccak256::KeccakPermuteChip,
                 sha256::{ShaCompressChip, ShaExtendChip},
                 u256x2048_mul::U256x2048MulChip,
-                uint256::Uint256MulChip,
+                uint256::{Uint256AddSubChip, Uint256MulChip, UintMulModChip},
                 weierstrass::{
                     WeierstrassAddAssignChip, WeierstrassDecompressChip,
-                    WeierstrassDoubleAssignChip,
+                    WeierstrassDoubleAssignChip, WeierstrassEcdsaVerifyChip,
+                    WeierstrassEcrecoverChip, WeierstrassMsmChip,
                 },
             },
         },
     };
     pub use monerochan_curves::{
         edwards::{ed25519::Ed25519Parameters, EdwardsCurve},
+        uint256::{U2048Field, U4096Field},
         weierstrass::{
-            bls12_381::Bls12381Parameters, bn254::Bn254Parameters, secp256k1::Secp256k1Parameters,
-            secp256r1::Secp256r1Parameters, SwCurve,
+            bls12_381::Bls12381Parameters,
+            bn254::Bn254Parameters,
+            grumpkin::GrumpkinParameters,
+            pasta::{PallasParameters, VestaParameters},
+            secp256k1::Secp256k1Parameters,
+            secp256r1::Secp256r1Parameters,
+            SwCurve,
         },
     };
 }
@@ -160,6 +180,86 @@ pub enum RiscvAir<F: PrimeField32> {
     Bn254Fp2Mul(Fp2MulAssignChip<Bn254BaseField>),
     /// A precompile for BN-254 fp2 addition/subtraction.
     Bn254Fp2AddSub(Fp2AddSubAssignChip<Bn254BaseField>),
+    /// A precompile for BN-254 fp6 multiplication.
+    Bn254Fp6Mul(Fp6MulAssignChip<Bn254BaseField>),
+    /// A precompile for BN-254 fp12 multiplication.
+    Bn254Fp12Mul(Fp12MulAssignChip<Bn254BaseField>),
+    /// A precompile for addition on the G2 subgroup of BN-254.
+    Bn254G2Add(G2AddAssignChip<Bn254BaseField>),
+    /// A precompile for doubling a point on the G2 subgroup of BN-254.
+    Bn254G2Double(G2DoubleAssignChip<Bn254BaseField>),
+    /// A precompile for BLS12-381 fp6 multiplication.
+    Bls12381Fp6Mul(Fp6MulAssignChip<Bls12381BaseField>),
+    /// A precompile for BLS12-381 fp12 multiplication.
+    Bls12381Fp12Mul(Fp12MulAssignChip<Bls12381BaseField>),
+    /// A precompile for addition on the G2 subgroup of BLS12-381.
+    Bls12381G2Add(G2AddAssignChip<Bls12381BaseField>),
+    /// A precompile for doubling a point on the G2 subgroup of BLS12-381.
+    Bls12381G2Double(G2DoubleAssignChip<Bls12381BaseField>),
+    /// A precompile for fixed-window scalar multiplication on the secp256k1 curve.
+    Secp256k1ScalarMul(WeierstrassMsmChip<SwCurve<Secp256k1Parameters>, 1>),
+    /// A precompile for two-point multi-scalar multiplication on the secp256k1 curve.
+    Secp256k1Msm(WeierstrassMsmChip<SwCurve<Secp256k1Parameters>, 2>),
+    /// A precompile for fixed-window scalar multiplication on the secp256r1 curve.
+    Secp256r1ScalarMul(WeierstrassMsmChip<SwCurve<Secp256r1Parameters>, 1>),
+    /// A precompile for two-point multi-scalar multiplication on the secp256r1 curve.
+    Secp256r1Msm(WeierstrassMsmChip<SwCurve<Secp256r1Parameters>, 2>),
+    /// A precompile for fixed-window scalar multiplication on the BN-254 curve.
+    Bn254ScalarMul(WeierstrassMsmChip<SwCurve<Bn254Parameters>, 1>),
+    /// A precompile for two-point multi-scalar multiplication on the BN-254 curve.
+    Bn254Msm(WeierstrassMsmChip<SwCurve<Bn254Parameters>, 2>),
+    /// A precompile for fixed-window scalar multiplication on the BLS12-381 curve.
+    Bls12381ScalarMul(WeierstrassMsmChip<SwCurve<Bls12381Parameters>, 1>),
+    /// A precompile for two-point multi-scalar multiplication on the BLS12-381 curve.
+    Bls12381Msm(WeierstrassMsmChip<SwCurve<Bls12381Parameters>, 2>),
+    /// A precompile for uint256 add with carry and sub with borrow.
+    Uint256AddSub(Uint256AddSubChip),
+    /// A precompile for uint2048 mul.
+    Uint2048MulMod(UintMulModChip<U2048Field>),
+    /// A precompile for uint4096 mul.
+    Uint4096MulMod(UintMulModChip<U4096Field>),
+    /// A precompile for secp256k1 base field operations.
+    Secp256k1Fp(FpOpChip<Secp256k1BaseField>),
+    /// A precompile for secp256k1 scalar field operations.
+    Secp256k1Fn(FpOpChip<Secp256k1ScalarField>),
+    /// A precompile for secp256r1 base field operations.
+    Secp256r1Fp(FpOpChip<Secp256r1BaseField>),
+    /// A precompile for secp256r1 scalar field operations.
+    Secp256r1Fn(FpOpChip<Secp256r1ScalarField>),
+    /// A precompile for a step of the X25519 Montgomery ladder.
+    X25519LadderStep(X25519LadderStepChip),
+    /// A precompile for AES-128 and AES-256 block encryption.
+    AesEncryptBlock(AesEncryptBlockChip),
+    /// A precompile for the ChaCha20 block function.
+    ChaCha20Block(ChaCha20BlockChip),
+    /// A precompile for addition on the Elliptic curve pallas.
+    PallasAdd(WeierstrassAddAssignChip<SwCurve<PallasParameters>>),
+    /// A precompile for doubling a point on the Elliptic curve pallas.
+    PallasDouble(WeierstrassDoubleAssignChip<SwCurve<PallasParameters>>),
+    /// A precompile for addition on the Elliptic curve vesta.
+    VestaAdd(WeierstrassAddAssignChip<SwCurve<VestaParameters>>),
+    /// A precompile for doubling a point on the Elliptic curve vesta.
+    VestaDouble(WeierstrassDoubleAssignChip<SwCurve<VestaParameters>>),
+    /// A precompile for addition on the Elliptic curve grumpkin.
+    GrumpkinAdd(WeierstrassAddAssignChip<SwCurve<GrumpkinParameters>>),
+    /// A precompile for doubling a point on the Elliptic curve grumpkin.
+    GrumpkinDouble(WeierstrassDoubleAssignChip<SwCurve<GrumpkinParameters>>),
+    /// A precompile for ECDSA public key recovery on the Elliptic curve secp256k1.
+    Secp256k1Ecrecover(WeierstrassEcrecoverChip<SwCurve<Secp256k1Parameters>>),
+    /// A precompile for ECDSA public key recovery on the Elliptic curve secp256r1.
+    Secp256r1Ecrecover(WeierstrassEcrecoverChip<SwCurve<Secp256r1Parameters>>),
+    /// A precompile for ECDSA signature verification on the Elliptic curve secp256k1.
+    Secp256k1EcdsaVerify(WeierstrassEcdsaVerifyChip<SwCurve<Secp256k1Parameters>>),
+    /// A precompile for ECDSA signature verification on the Elliptic curve secp256r1.
+    Secp256r1EcdsaVerify(WeierstrassEcdsaVerifyChip<SwCurve<Secp256r1Parameters>>),
+    /// A precompile registered in user slot 0.
+    UserPrecompile0(UserPrecompileChip<F>),
+    /// A precompile registered in user slot 1.
+    UserPrecompile1(UserPrecompileChip<F>),
+    /// A precompile registered in user slot 2.
+    UserPrecompile2(UserPrecompileChip<F>),
+    /// A precompile registered in user slot 3.
+    UserPrecompile3(UserPrecompileChip<F>),
 }
 
 impl<F: PrimeField32> RiscvAir<F> {
@@ -172,6 +272,17 @@ impl<F: PrimeField32> RiscvAir<F> {
         StarkMachine::new(config, chips, MONEROCHAN_PROOF_NUM_PV_ELTS, true)
     }
 
+    /// Get the RISC-V machine extended with the chips of the given user precompiles.
+    ///
+    /// The machine has its own verifying keys, which differ from those of [`Self::machine`].
+    pub fn machine_with_user_precompiles<SC: StarkGenericConfig<Val = F>>(
+        config: SC,
+        user_precompiles: &UserPrecompiles<F>,
+    ) -> StarkMachine<SC, Self> {
+        let (chips, _) = Self::chips_and_costs(user_precompiles.chips());
+        StarkMachine::new(config, chips, MONEROCHAN_PROOF_NUM_PV_ELTS, true)
+    }
+
     /// Get all the different RISC-V AIRs.
     pub fn chips() -> Vec<Chip<F, Self>> {
         let (chips, _) = Self::get_chips_and_costs();
@@ -192,6 +303,13 @@ impl<F: PrimeField32> RiscvAir<F> {
 
     /// Get all the different RISC-V chips and their costs.
     pub fn get_chips_and_costs() -> (Vec<Chip<F, Self>>, HashMap<String, u64>) {
+        Self::chips_and_costs(&[])
+    }
+
+    /// Get the RISC-V chips extended with the given user precompile chips, and their costs.
+    fn chips_and_costs(
+        user_precompiles: &[UserPrecompileChip<F>],
+    ) -> (Vec<Chip<F, Self>>, HashMap<String, u64>) {
         let mut costs: HashMap<String, u64> = HashMap::new();
 
         // The order of the chips is used to determine the order of trace generation.
@@ -333,6 +451,214 @@ impl<F: PrimeField32> RiscvAir<F> {
         costs.insert(bls12381_decompress.name(), bls12381_decompress.cost());
         chips.push(bls12381_decompress);
 
+        let bn254_fp6_mul =
+            Chip::new(RiscvAir::Bn254Fp6Mul(Fp6MulAssignChip::<Bn254BaseField>::new()));
+        costs.insert(bn254_fp6_mul.name(), bn254_fp6_mul.cost());
+        chips.push(bn254_fp6_mul);
+
+        let bn254_fp12_mul =
+            Chip::new(RiscvAir::Bn254Fp12Mul(Fp12MulAssignChip::<Bn254BaseField>::new()));
+        costs.insert(bn254_fp12_mul.name(), bn254_fp12_mul.cost());
+        chips.push(bn254_fp12_mul);
+
+        let bn254_g2_add =
+            Chip::new(RiscvAir::Bn254G2Add(G2AddAssignChip::<Bn254BaseField>::new()));
+        costs.insert(bn254_g2_add.name(), bn254_g2_add.cost());
+        chips.push(bn254_g2_add);
+
+        let bn254_g2_double =
+            Chip::new(RiscvAir::Bn254G2Double(G2DoubleAssignChip::<Bn254BaseField>::new()));
+        costs.insert(bn254_g2_double.name(), bn254_g2_double.cost());
+        chips.push(bn254_g2_double);
+
+        let bls12381_fp6_mul =
+            Chip::new(RiscvAir::Bls12381Fp6Mul(Fp6MulAssignChip::<Bls12381BaseField>::new()));
+        costs.insert(bls12381_fp6_mul.name(), bls12381_fp6_mul.cost());
+        chips.push(bls12381_fp6_mul);
+
+        let bls12381_fp12_mul =
+            Chip::new(RiscvAir::Bls12381Fp12Mul(Fp12MulAssignChip::<Bls12381BaseField>::new()));
+        costs.insert(bls12381_fp12_mul.name(), bls12381_fp12_mul.cost());
+        chips.push(bls12381_fp12_mul);
+
+        let bls12381_g2_add =
+            Chip::new(RiscvAir::Bls12381G2Add(G2AddAssignChip::<Bls12381BaseField>::new()));
+        costs.insert(bls12381_g2_add.name(), bls12381_g2_add.cost());
+        chips.push(bls12381_g2_add);
+
+        let bls12381_g2_double =
+            Chip::new(RiscvAir::Bls12381G2Double(G2DoubleAssignChip::<Bls12381BaseField>::new()));
+        costs.insert(bls12381_g2_double.name(), bls12381_g2_double.cost());
+        chips.push(bls12381_g2_double);
+
+        let secp256k1_scalar_mul = Chip::new(RiscvAir::Secp256k1ScalarMul(WeierstrassMsmChip::<
+            SwCurve<Secp256k1Parameters>,
+            1,
+        >::new()));
+        costs.insert(secp256k1_scalar_mul.name(), secp256k1_scalar_mul.cost());
+        chips.push(secp256k1_scalar_mul);
+
+        let secp256k1_msm = Chip::new(RiscvAir::Secp256k1Msm(WeierstrassMsmChip::<
+            SwCurve<Secp256k1Parameters>,
+            2,
+        >::new()));
+        costs.insert(secp256k1_msm.name(), secp256k1_msm.cost());
+        chips.push(secp256k1_msm);
+
+        let secp256r1_scalar_mul = Chip::new(RiscvAir::Secp256r1ScalarMul(WeierstrassMsmChip::<
+            SwCurve<Secp256r1Parameters>,
+            1,
+        >::new()));
+        costs.insert(secp256r1_scalar_mul.name(), secp256r1_scalar_mul.cost());
+        chips.push(secp256r1_scalar_mul);
+
+        let secp256r1_msm = Chip::new(RiscvAir::Secp256r1Msm(WeierstrassMsmChip::<
+            SwCurve<Secp256r1Parameters>,
+            2,
+        >::new()));
+        costs.insert(secp256r1_msm.name(), secp256r1_msm.cost());
+        chips.push(secp256r1_msm);
+
+        let bn254_scalar_mul = Chip::new(RiscvAir::Bn254ScalarMul(WeierstrassMsmChip::<
+            SwCurve<Bn254Parameters>,
+            1,
+        >::new()));
+        costs.insert(bn254_scalar_mul.name(), bn254_scalar_mul.cost());
+        chips.push(bn254_scalar_mul);
+
+        let bn254_msm =
+            Chip::new(RiscvAir::Bn254Msm(WeierstrassMsmChip::<SwCurve<Bn254Parameters>, 2>::new()));
+        costs.insert(bn254_msm.name(), bn254_msm.cost());
+        chips.push(bn254_msm);
+
+        let bls12381_scalar_mul = Chip::new(RiscvAir::Bls12381ScalarMul(WeierstrassMsmChip::<
+            SwCurve<Bls12381Parameters>,
+            1,
+        >::new()));
+        costs.insert(bls12381_scalar_mul.name(), bls12381_scalar_mul.cost());
+        chips.push(bls12381_scalar_mul);
+
+        let bls12381_msm = Chip::new(RiscvAir::Bls12381Msm(WeierstrassMsmChip::<
+            SwCurve<Bls12381Parameters>,
+            2,
+        >::new()));
+        costs.insert(bls12381_msm.name(), bls12381_msm.cost());
+        chips.push(bls12381_msm);
+
+        let uint256_add_sub = Chip::new(RiscvAir::Uint256AddSub(Uint256AddSubChip::new()));
+        costs.insert(uint256_add_sub.name(), uint256_add_sub.cost());
+        chips.push(uint256_add_sub);
+
+        let uint2048_mul = Chip::new(RiscvAir::Uint2048MulMod(UintMulModChip::new()));
+        costs.insert(uint2048_mul.name(), uint2048_mul.cost());
+        chips.push(uint2048_mul);
+
+        let uint4096_mul = Chip::new(RiscvAir::Uint4096MulMod(UintMulModChip::new()));
+        costs.insert(uint4096_mul.name(), uint4096_mul.cost());
+        chips.push(uint4096_mul);
+
+        let secp256k1_fp = Chip::new(RiscvAir::Secp256k1Fp(FpOpChip::<Secp256k1BaseField>::new()));
+        costs.insert(secp256k1_fp.name(), secp256k1_fp.cost());
+        chips.push(secp256k1_fp);
+
+        let secp256k1_fn =
+            Chip::new(RiscvAir::Secp256k1Fn(FpOpChip::<Secp256k1ScalarField>::new()));
+        costs.insert(secp256k1_fn.name(), secp256k1_fn.cost());
+        chips.push(secp256k1_fn);
+
+        let secp256r1_fp = Chip::new(RiscvAir::Secp256r1Fp(FpOpChip::<Secp256r1BaseField>::new()));
+        costs.insert(secp256r1_fp.name(), secp256r1_fp.cost());
+        chips.push(secp256r1_fp);
+
+        let secp256r1_fn =
+            Chip::new(RiscvAir::Secp256r1Fn(FpOpChip::<Secp256r1ScalarField>::new()));
+        costs.insert(secp256r1_fn.name(), secp256r1_fn.cost());
+        chips.push(secp256r1_fn);
+
+        let x25519_ladder_step = Chip::new(RiscvAir::X25519LadderStep(X25519LadderStepChip::new()));
+        costs.insert(x25519_ladder_step.name(), x25519_ladder_step.cost());
+        chips.push(x25519_ladder_step);
+
+        let aes_encrypt_block = Chip::new(RiscvAir::AesEncryptBlock(AesEncryptBlockChip::new()));
+        costs.insert(aes_encrypt_block.name(), aes_encrypt_block.cost());
+        chips.push(aes_encrypt_block);
+
+        let chacha20_block = Chip::new(RiscvAir::ChaCha20Block(ChaCha20BlockChip::new()));
+        costs.insert(chacha20_block.name(), chacha20_block.cost());
+        chips.push(chacha20_block);
+
+        let pallas_add_assign = Chip::new(RiscvAir::PallasAdd(WeierstrassAddAssignChip::<
+            SwCurve<PallasParameters>,
+        >::new()));
+        costs.insert(pallas_add_assign.name(), pallas_add_assign.cost());
+        chips.push(pallas_add_assign);
+
+        let pallas_double_assign =
+            Chip::new(RiscvAir::PallasDouble(WeierstrassDoubleAssignChip::<
+                SwCurve<PallasParameters>,
+            >::new()));
+        costs.insert(pallas_double_assign.name(), pallas_double_assign.cost());
+        chips.push(pallas_double_assign);
+
+        let vesta_add_assign = Chip::new(RiscvAir::VestaAdd(WeierstrassAddAssignChip::<
+            SwCurve<VestaParameters>,
+        >::new()));
+        costs.insert(vesta_add_assign.name(), vesta_add_assign.cost());
+        chips.push(vesta_add_assign);
+
+        let vesta_double_assign = Chip::new(RiscvAir::VestaDouble(WeierstrassDoubleAssignChip::<
+            SwCurve<VestaParameters>,
+        >::new()));
+        costs.insert(vesta_double_assign.name(), vesta_double_assign.cost());
+        chips.push(vesta_double_assign);
+
+        let grumpkin_add_assign = Chip::new(RiscvAir::GrumpkinAdd(WeierstrassAddAssignChip::<
+            SwCurve<GrumpkinParameters>,
+        >::new()));
+        costs.insert(grumpkin_add_assign.name(), grumpkin_add_assign.cost());
+        chips.push(grumpkin_add_assign);
+
+        let grumpkin_double_assign =
+            Chip::new(RiscvAir::GrumpkinDouble(WeierstrassDoubleAssignChip::<
+                SwCurve<GrumpkinParameters>,
+            >::new()));
+        costs.insert(grumpkin_double_assign.name(), grumpkin_double_assign.cost());
+        chips.push(grumpkin_double_assign);
+
+        let secp256k1_ecrecover =
+            Chip::new(RiscvAir::Secp256k1Ecrecover(WeierstrassEcrecoverChip::<
+                SwCurve<Secp256k1Parameters>,
+            >::new()));
+        costs.insert(secp256k1_ecrecover.name(), secp256k1_ecrecover.cost());
+        chips.push(secp256k1_ecrecover);
+
+        let secp256r1_ecrecover =
+            Chip::new(RiscvAir::Secp256r1Ecrecover(WeierstrassEcrecoverChip::<
+                SwCurve<Secp256r1Parameters>,
+            >::new()));
+        costs.insert(secp256r1_ecrecover.name(), secp256r1_ecrecover.cost());
+        chips.push(secp256r1_ecrecover);
+
+        let secp256k1_ecdsa_verify =
+            Chip::new(RiscvAir::Secp256k1EcdsaVerify(WeierstrassEcdsaVerifyChip::<
+                SwCurve<Secp256k1Parameters>,
+            >::new()));
+        costs.insert(secp256k1_ecdsa_verify.name(), secp256k1_ecdsa_verify.cost());
+        chips.push(secp256k1_ecdsa_verify);
+
+        let secp256r1_ecdsa_verify =
+            Chip::new(RiscvAir::Secp256r1EcdsaVerify(WeierstrassEcdsaVerifyChip::<
+                SwCurve<Secp256r1Parameters>,
+            >::new()));
+        costs.insert(secp256r1_ecdsa_verify.name(), secp256r1_ecdsa_verify.cost());
+        chips.push(secp256r1_ecdsa_verify);
+
+        for user_precompile in user_precompiles {
+            let user_precompile = Chip::new(RiscvAir::from(user_precompile.clone()));
+            costs.insert(user_precompile.name(), user_precompile.cost());
+            chips.push(user_precompile);
+        }
+
         let syscall_core = Chip::new(RiscvAir::SyscallCore(SyscallChip::core()));
         costs.insert(syscall_core.name(), syscall_core.cost());
         chips.push(syscall_core);
@@ -462,8 +788,13 @@ impl<F: PrimeField32> RiscvAir<F> {
     /// Returns the upper bound of the number of memory events per row of each precompile. Used in
     /// shape-fitting.
     pub(crate) fn precompile_airs_with_memory_events_per_row(
+        user_precompiles: &UserPrecompiles<F>,
     ) -> impl Iterator<Item = (RiscvAirId, usize)> {
-        let mut airs: HashSet<_> = Self::get_airs_and_costs().0.into_iter().collect();
+        let mut airs: HashSet<_> = Self::chips_and_costs(user_precompiles.chips())
+            .0
+            .into_iter()
+            .map(|chip| chip.into_inner())
+            .collect();
 
         // Remove the core airs.
         for core_air in Self::get_all_core_airs() {
@@ -517,6 +848,18 @@ impl<F: PrimeField32> fmt::Debug for RiscvAir<F> {
     }
 }
 
+impl<F: PrimeField32> From<UserPrecompileChip<F>> for RiscvAir<F> {
+    fn from(chip: UserPrecompileChip<F>) -> Self {
+        match chip.id() {
+            RiscvAirId::UserPrecompile0 => RiscvAir::UserPrecompile0(chip),
+            RiscvAirId::UserPrecompile1 => RiscvAir::UserPrecompile1(chip),
+            RiscvAirId::UserPrecompile2 => RiscvAir::UserPrecompile2(chip),
+            RiscvAirId::UserPrecompile3 => RiscvAir::UserPrecompile3(chip),
+            id => unreachable!("{id} is not a user precompile slot"),
+        }
+    }
+}
+
 impl From<RiscvAirDiscriminants> for RiscvAirId {
     fn from(value: RiscvAirDiscriminants) -> Self {
         match value {
@@ -565,6 +908,46 @@ impl From<RiscvAirDiscriminants> for RiscvAirId {
             RiscvAirDiscriminants::Bn254Fp => RiscvAirId::Bn254FpOpAssign,
             RiscvAirDiscriminants::Bn254Fp2Mul => RiscvAirId::Bn254Fp2MulAssign,
             RiscvAirDiscriminants::Bn254Fp2AddSub => RiscvAirId::Bn254Fp2AddSubAssign,
+            RiscvAirDiscriminants::Bn254Fp6Mul => RiscvAirId::Bn254Fp6MulAssign,
+            RiscvAirDiscriminants::Bn254Fp12Mul => RiscvAirId::Bn254Fp12MulAssign,
+            RiscvAirDiscriminants::Bn254G2Add => RiscvAirId::Bn254G2AddAssign,
+            RiscvAirDiscriminants::Bn254G2Double => RiscvAirId::Bn254G2DoubleAssign,
+            RiscvAirDiscriminants::Bls12381Fp6Mul => RiscvAirId::Bls12381Fp6MulAssign,
+            RiscvAirDiscriminants::Bls12381Fp12Mul => RiscvAirId::Bls12381Fp12MulAssign,
+            RiscvAirDiscriminants::Bls12381G2Add => RiscvAirId::Bls12381G2AddAssign,
+            RiscvAirDiscriminants::Bls12381G2Double => RiscvAirId::Bls12381G2DoubleAssign,
+            RiscvAirDiscriminants::Secp256k1ScalarMul => RiscvAirId::Secp256k1ScalarMulAssign,
+            RiscvAirDiscriminants::Secp256k1Msm => RiscvAirId::Secp256k1MsmAssign,
+            RiscvAirDiscriminants::Secp256r1ScalarMul => RiscvAirId::Secp256r1ScalarMulAssign,
+            RiscvAirDiscriminants::Secp256r1Msm => RiscvAirId::Secp256r1MsmAssign,
+            RiscvAirDiscriminants::Bn254ScalarMul => RiscvAirId::Bn254ScalarMulAssign,
+            RiscvAirDiscriminants::Bn254Msm => RiscvAirId::Bn254MsmAssign,
+            RiscvAirDiscriminants::Bls12381ScalarMul => RiscvAirId::Bls12381ScalarMulAssign,
+            RiscvAirDiscriminants::Bls12381Msm => RiscvAirId::Bls12381MsmAssign,
+            RiscvAirDiscriminants::Uint256AddSub => RiscvAirId::Uint256AddSub,
+            RiscvAirDiscriminants::Uint2048MulMod => RiscvAirId::Uint2048MulMod,
+            RiscvAirDiscriminants::Uint4096MulMod => RiscvAirId::Uint4096MulMod,
+            RiscvAirDiscriminants::Secp256k1Fp => RiscvAirId::Secp256k1FpOpAssign,
+            RiscvAirDiscriminants::Secp256k1Fn => RiscvAirId::Secp256k1FnOpAssign,
+            RiscvAirDiscriminants::Secp256r1Fp => RiscvAirId::Secp256r1FpOpAssign,
+            RiscvAirDiscriminants::Secp256r1Fn => RiscvAirId::Secp256r1FnOpAssign,
+            RiscvAirDiscriminants::X25519LadderStep => RiscvAirId::X25519LadderStep,
+            RiscvAirDiscriminants::AesEncryptBlock => RiscvAirId::AesEncryptBlock,
+            RiscvAirDiscriminants::ChaCha20Block => RiscvAirId::ChaCha20Block,
+            RiscvAirDiscriminants::PallasAdd => RiscvAirId::PallasAddAssign,
+            RiscvAirDiscriminants::PallasDouble => RiscvAirId::PallasDoubleAssign,
+            RiscvAirDiscriminants::VestaAdd => RiscvAirId::VestaAddAssign,
+            RiscvAirDiscriminants::VestaDouble => RiscvAirId::VestaDoubleAssign,
+            RiscvAirDiscriminants::GrumpkinAdd => RiscvAirId::GrumpkinAddAssign,
+            RiscvAirDiscriminants::GrumpkinDouble => RiscvAirId::GrumpkinDoubleAssign,
+            RiscvAirDiscriminants::Secp256k1Ecrecover => RiscvAirId::Secp256k1Ecrecover,
+            RiscvAirDiscriminants::Secp256r1Ecrecover => RiscvAirId::Secp256r1Ecrecover,
+            RiscvAirDiscriminants::Secp256k1EcdsaVerify => RiscvAirId::Secp256k1EcdsaVerify,
+            RiscvAirDiscriminants::Secp256r1EcdsaVerify => RiscvAirId::Secp256r1EcdsaVerify,
+            RiscvAirDiscriminants::UserPrecompile0 => RiscvAirId::UserPrecompile0,
+            RiscvAirDiscriminants::UserPrecompile1 => RiscvAirId::UserPrecompile1,
+            RiscvAirDiscriminants::UserPrecompile2 => RiscvAirId::UserPrecompile2,
+            RiscvAirDiscriminants::UserPrecompile3 => RiscvAirId::UserPrecompile3,
         }
     }
 }
@@ -583,17 +966,20 @@ pub mod tests {
     use crate::programs::tests::*;
     use hashbrown::HashMap;
     use itertools::Itertools;
+    use p3_air::BaseAir;
     use p3_baby_bear::BabyBear;
     use monerochan_core_executor::{Instruction, Opcode, Program, RiscvAirId, MONEROCHANContext};
     use monerochan_stark::{
-        air::MachineAir, baby_bear_poseidon2::BabyBearPoseidon2, CpuProver, MachineProver,
-        MONEROCHANCoreOpts, StarkProvingKey, StarkVerifyingKey,
+        air::MachineAir, baby_bear_poseidon2::BabyBearPoseidon2, CompactShardProof, CpuProver,
+        MachineProver, MONEROCHANCoreOpts, StarkGenericConfig, StarkProvingKey, StarkVerifyingKey,
     };
     use strum::IntoEnumIterator;
     #[test]
     fn test_primitives_and_machine_air_names_match() {
         let chips = RiscvAir::<BabyBear>::chips();
-        for (a, b) in chips.iter().zip_eq(RiscvAirId::iter()) {
+        // The user precompile slots only have a chip in a machine built with user precompiles.
+        let ids = RiscvAirId::iter().filter(|id| id.user_syscall_code().is_none());
+        for (a, b) in chips.iter().zip_eq(ids) {
             assert_eq!(a.name(), b.to_string());
         }
     }
@@ -608,6 +994,27 @@ pub mod tests {
         assert_eq!(costs, machine_costs);
     }
 
+    #[test]
+    fn test_chip_reports() {
+        let costs = RiscvAir::<BabyBear>::costs();
+        for chip in RiscvAir::<BabyBear>::chips() {
+            let report = chip.report();
+            assert_eq!(report.cost, costs[&report.name]);
+            assert_eq!(report.main_width, chip.width());
+            assert_eq!(report.num_interactions(), chip.num_interactions());
+            assert!(report.max_constraint_degree() <= 3, "{} has a degree above 3", report.name);
+        }
+    }
+
+    #[test]
+    fn test_column_names() {
+        for chip in RiscvAir::<BabyBear>::chips() {
+            let names = chip.column_names();
+            assert_eq!(names.len(), chip.width(), "{}", chip.name());
+            assert_ne!(names[0], "main[0]", "{} has no column names", chip.name());
+        }
+    }
+
     #[test]
     #[ignore]
     fn write_core_air_costs() {
@@ -816,6 +1223,40 @@ pub mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_fibonacci_verify_compact() {
+        setup_logger();
+        let program = fibonacci_program();
+        let stdin = MONEROCHANStdin::new();
+
+        let opts = MONEROCHANCoreOpts::default();
+        let config = BabyBearPoseidon2::new();
+        let machine = RiscvAir::machine(config);
+        let prover = CpuProver::new(machine);
+        let (pk, vk) = prover.setup(&program);
+        let (proof, _, _) = prove_core::<_, _>(
+            &prover,
+            &pk,
+            &vk,
+            program,
+            &stdin,
+            opts,
+            MONEROCHANContext::default(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let compact = proof.shard_proofs.iter().map(CompactShardProof::new).collect::<Vec<_>>();
+        let size = bincode::serialize(&proof).unwrap().len();
+        let compact_size = bincode::serialize(&compact).unwrap().len();
+        assert!(compact_size < size, "{compact_size} >= {size}");
+
+        let machine = prover.machine();
+        let mut challenger = machine.config().challenger();
+        machine.verify_compact(&vk, &compact, &mut challenger).unwrap();
+    }
+
     #[test]
     fn test_simple_memory_program_prove() {
         setup_logger();
@@ -14,6 +14,7 @@ use monerochan_core_executor::{ExecutionRecord, Instruction, Opcode, Program, Ri
 use monerochan_stark::{
     air::MachineAir,
     shape::{OrderedShape, Shape, ShapeCluster},
+    Chip,
 };
 use thiserror::Error;
 
@@ -22,6 +23,7 @@ use crate::{
     global::GlobalChip,
     memory::{MemoryLocalChip, NUM_LOCAL_MEMORY_ENTRIES_PER_ROW},
     riscv::RiscvAir,
+    syscall::precompiles::user::UserPrecompiles,
 };
 
 /// The set of maximal shapes.
@@ -45,10 +47,40 @@ pub struct CoreShapeConfig<F: PrimeField32> {
     partial_precompile_shapes: HashMap<RiscvAirId, (usize, Vec<usize>)>,
     partial_small_shapes: Vec<ShapeCluster<RiscvAirId>>,
     costs: HashMap<RiscvAirId, usize>,
+    user_rows_per_event: HashMap<RiscvAirId, usize>,
     _data: PhantomData<F>,
 }
 
 impl<F: PrimeField32> CoreShapeConfig<F> {
+    /// Adds the shapes of the precompile shards of the given user precompiles.
+    ///
+    /// The config must be used with a machine built by
+    /// [`RiscvAir::machine_with_user_precompiles`] from the same user precompiles.
+    #[must_use]
+    pub fn with_user_precompiles(mut self, user_precompiles: &UserPrecompiles<F>) -> Self {
+        let precompile_heights = (3..21).collect::<Vec<_>>();
+        for (air, memory_events_per_row) in
+            RiscvAir::<F>::precompile_airs_with_memory_events_per_row(user_precompiles)
+                .filter(|(air, _)| air.user_syscall_code().is_some())
+        {
+            self.partial_precompile_shapes
+                .insert(air, (memory_events_per_row, precompile_heights.clone()));
+        }
+        for (code, syscall) in user_precompiles.syscalls().iter() {
+            self.user_rows_per_event.insert(code.as_air_id().unwrap(), syscall.rows_per_event);
+        }
+        for chip in user_precompiles.chips() {
+            let id = chip.id();
+            self.costs.insert(id, Chip::new(RiscvAir::from(chip.clone())).cost() as usize);
+        }
+        self
+    }
+
+    /// The number of rows the AIR `air` uses for each event.
+    fn rows_per_event(&self, air: RiscvAirId) -> usize {
+        self.user_rows_per_event.get(&air).copied().unwrap_or_else(|| air.rows_per_event())
+    }
+
     /// Fix the preprocessed shape of the proof.
     pub fn fix_preprocessed_shape(&self, program: &mut Program) -> Result<(), CoreShapeError> {
         // If the preprocessed shape is already fixed, return an error.
@@ -182,6 +214,8 @@ impl<F: PrimeField32> CoreShapeConfig<F> {
                     else {
                         continue;
                     };
+                    // The record counts one row per event of a user precompile.
+                    let height = height * self.user_rows_per_event.get(&air).unwrap_or(&1);
                     for allowed_log2_height in allowed_log2_heights {
                         let allowed_height = 1 << allowed_log2_height;
                         if height <= allowed_height {
@@ -250,7 +284,8 @@ impl<F: PrimeField32> CoreShapeConfig<F> {
         allowed_log2_height: usize,
     ) -> Vec<[(String, usize); 4]> {
         // TODO: This is a temporary fix to the shape, concretely fix this
-        (1..=4 * air_id.rows_per_event())
+        let air_rows_per_event = self.rows_per_event(air_id);
+        (1..=4 * air_rows_per_event)
             .rev()
             .map(|rows_per_event| {
                 let num_local_mem_events =
@@ -260,7 +295,7 @@ impl<F: PrimeField32> CoreShapeConfig<F> {
                     (
                         RiscvAir::<F>::SyscallPrecompile(SyscallChip::precompile()).name(),
                         ((1 << allowed_log2_height)
-                            .div_ceil(&air_id.rows_per_event())
+                            .div_ceil(&air_rows_per_event)
                             .next_power_of_two()
                             .ilog2() as usize)
                             .max(4),
@@ -276,7 +311,8 @@ impl<F: PrimeField32> CoreShapeConfig<F> {
                     (
                         RiscvAir::<F>::Global(GlobalChip).name(),
                         ((2 * num_local_mem_events +
-                            (1 << allowed_log2_height).div_ceil(&air_id.rows_per_event()))
+                            (1 << allowed_log2_height).div_ceil(&air_rows_per_event) *
+                                (1 + air_id.nested_syscalls_per_event()))
                         .next_power_of_two()
                         .ilog2() as usize)
                             .max(4),
@@ -508,7 +544,7 @@ impl<F: PrimeField32> Default for CoreShapeConfig<F> {
         let mut precompile_allowed_log2_heights = HashMap::new();
         let precompile_heights = (3..21).collect::<Vec<_>>();
         for (air, memory_events_per_row) in
-            RiscvAir::<F>::precompile_airs_with_memory_events_per_row()
+            RiscvAir::<F>::precompile_airs_with_memory_events_per_row(&UserPrecompiles::new())
         {
             precompile_allowed_log2_heights
                 .insert(air, (memory_events_per_row, precompile_heights.clone()));
@@ -527,6 +563,7 @@ impl<F: PrimeField32> Default for CoreShapeConfig<F> {
                 .collect(),
             costs: serde_json::from_str(include_str!("rv32im_costs.json"))
                 .expect("Failed to load rv32im_costs.json file. Verify that `git config core.symlinks` is not set to false."),
+            user_rows_per_event: HashMap::new(),
             _data: PhantomData,
         }
     }
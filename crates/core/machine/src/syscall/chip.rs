@@ -81,6 +81,10 @@ impl<F: PrimeField32> MachineAir<F> for SyscallChip {
         format!("Syscall{}", self.shard_kind).to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        SyscallCols::<F>::column_names()
+    }
+
     fn generate_dependencies(&self, input: &ExecutionRecord, output: &mut ExecutionRecord) {
         let events = match self.shard_kind {
             SyscallShardKind::Core => &input
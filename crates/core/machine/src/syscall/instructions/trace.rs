@@ -28,6 +28,10 @@ impl<F: PrimeField32> MachineAir<F> for SyscallInstrsChip {
         "SyscallInstrs".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        SyscallInstrColumns::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
@@ -0,0 +1,191 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use monerochan_core_executor::{syscalls::SyscallCode, ByteOpcode};
+use monerochan_stark::air::{InteractionScope, MONEROCHANAirBuilder};
+
+use super::{
+    columns::{AesEncryptBlockCols, NUM_AES_ENCRYPT_BLOCK_COLS},
+    mix_columns_sources, shift_rows_source, AesEncryptBlockChip, AES128_ROUNDS, AES256_ROUNDS,
+    AES_BLOCK_BYTES,
+};
+use crate::{air::MemoryAirBuilder, memory::MemoryCols};
+
+impl<F> BaseAir<F> for AesEncryptBlockChip {
+    fn width(&self) -> usize {
+        NUM_AES_ENCRYPT_BLOCK_COLS
+    }
+}
+
+impl<AB> Air<AB> for AesEncryptBlockChip
+where
+    AB: MONEROCHANAirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &AesEncryptBlockCols<AB::Var> = (*local).borrow();
+        let next: &AesEncryptBlockCols<AB::Var> = (*next).borrow();
+
+        let first_round = local.round[0];
+        let last_round = local.is_last_round;
+
+        // Exactly one round flag is set on real rows, and none on padding rows.
+        builder.assert_bool(local.is_real);
+        builder.assert_bool(local.is_aes256);
+        for flag in local.round {
+            builder.assert_bool(flag);
+        }
+        builder.assert_eq(
+            local.round.iter().map(|flag| (*flag).into()).sum::<AB::Expr>(),
+            local.is_real,
+        );
+
+        // The last round is the tenth for AES-128 and the fourteenth for AES-256.
+        builder.assert_eq(
+            last_round,
+            local.round[AES128_ROUNDS] * (AB::Expr::one() - local.is_aes256) +
+                local.round[AES256_ROUNDS] * local.is_aes256,
+        );
+
+        // Real rows come before padding rows. A syscall starts on the first row and after the last
+        // round of the previous syscall, and the trace does not end in the middle of a syscall.
+        builder.when_first_row().assert_eq(first_round, local.is_real);
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_transition().when(next.is_real).assert_eq(next.round[0], last_round);
+        builder.when_last_row().assert_eq(last_round, local.is_real);
+
+        // Within a syscall, the rounds follow each other and the inputs stay the same.
+        let is_continuing = local.is_real - last_round;
+        {
+            let mut transition_builder = builder.when_transition();
+            let mut continuing_builder = transition_builder.when(is_continuing.clone());
+            continuing_builder.assert_one(next.is_real);
+            for i in 1..=AES256_ROUNDS {
+                continuing_builder.assert_eq(next.round[i], local.round[i - 1]);
+            }
+            continuing_builder.assert_eq(next.shard, local.shard);
+            continuing_builder.assert_eq(next.clk, local.clk);
+            continuing_builder.assert_eq(next.block_ptr, local.block_ptr);
+            continuing_builder.assert_eq(next.key_ptr, local.key_ptr);
+            continuing_builder.assert_eq(next.is_aes256, local.is_aes256);
+            for i in 0..AES_BLOCK_BYTES {
+                continuing_builder.assert_eq(next.input[i], local.input[i]);
+                // The next round starts from the output of this one.
+                continuing_builder.assert_eq(next.state[i], local.output[i]);
+            }
+        }
+
+        // The first round starts from the plaintext.
+        for i in 0..AES_BLOCK_BYTES {
+            builder.when(first_round).assert_eq(local.state[i], local.input[i]);
+        }
+
+        // SubBytes, with the doubled S-box output used by MixColumns.
+        for i in 0..AES_BLOCK_BYTES {
+            builder.send_byte_pair(
+                AB::F::from_canonical_u32(ByteOpcode::AesSbox as u32),
+                local.sbox[i],
+                local.sbox_xtime[i],
+                local.state[i],
+                AB::F::zero(),
+                local.is_real,
+            );
+        }
+
+        // ShiftRows and MixColumns. Each mixed byte is `2 * a0 + 3 * a1 + a2 + a3` in the AES
+        // field, computed as a chain of xors starting from the doubled bytes.
+        for i in 0..AES_BLOCK_BYTES {
+            let [a0, a1, a2, a3] = mix_columns_sources(i).map(shift_rows_source);
+            let chain = [
+                (local.sbox_xtime[a0], local.sbox_xtime[a1]),
+                (local.mix[i][0], local.sbox[a1]),
+                (local.mix[i][1], local.sbox[a2]),
+                (local.mix[i][2], local.sbox[a3]),
+            ];
+            for (j, (b, c)) in chain.into_iter().enumerate() {
+                builder.send_byte(
+                    AB::F::from_canonical_u32(ByteOpcode::XOR as u32),
+                    local.mix[i][j],
+                    b,
+                    c,
+                    local.is_real,
+                );
+            }
+        }
+
+        // The round key is added to the state on the first round, to the shifted S-box output on
+        // the last round, and to the mixed bytes otherwise.
+        for i in 0..AES_BLOCK_BYTES {
+            builder.assert_eq(
+                local.pre_key[i],
+                local.state[i] * first_round +
+                    local.sbox[shift_rows_source(i)] * last_round +
+                    local.mix[i][3] * (is_continuing.clone() - first_round),
+            );
+        }
+
+        // AddRoundKey.
+        for i in 0..AES_BLOCK_BYTES {
+            builder.send_byte(
+                AB::F::from_canonical_u32(ByteOpcode::XOR as u32),
+                local.output[i],
+                local.pre_key[i],
+                local.key_access[i / 4].value()[i % 4],
+                local.is_real,
+            );
+        }
+
+        // Read the round key of this row.
+        let round_index = local
+            .round
+            .iter()
+            .enumerate()
+            .map(|(i, flag)| *flag * AB::F::from_canonical_usize(i))
+            .sum::<AB::Expr>();
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.key_ptr + round_index * AB::F::from_canonical_usize(AES_BLOCK_BYTES),
+            &local.key_access,
+            local.is_real,
+        );
+
+        // On the last row, the block is overwritten with the ciphertext.
+        for i in 0..AES_BLOCK_BYTES {
+            builder
+                .when(last_round)
+                .assert_eq(local.block_access[i / 4].prev_value()[i % 4], local.input[i]);
+            builder
+                .when(last_round)
+                .assert_eq(local.block_access[i / 4].value()[i % 4], local.output[i]);
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk + AB::F::one(),
+            local.block_ptr,
+            &local.block_access,
+            last_round,
+        );
+
+        // Receive the syscall on the last row.
+        let syscall_id =
+            AB::Expr::from_canonical_u32(SyscallCode::AES128_ENCRYPT_BLOCK.syscall_id()) +
+                local.is_aes256 *
+                    AB::F::from_canonical_u32(
+                        SyscallCode::AES256_ENCRYPT_BLOCK.syscall_id() -
+                            SyscallCode::AES128_ENCRYPT_BLOCK.syscall_id(),
+                    );
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            syscall_id,
+            local.block_ptr,
+            local.key_ptr,
+            last_round,
+            InteractionScope::Local,
+        );
+    }
+}
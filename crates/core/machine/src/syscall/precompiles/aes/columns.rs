@@ -0,0 +1,61 @@
+use std::mem::size_of;
+
+use monerochan_core_executor::events::AES_BLOCK_WORDS;
+use monerochan_derive::AlignedBorrow;
+
+use super::{AES256_ROUNDS, AES_BLOCK_BYTES};
+use crate::memory::{MemoryReadCols, MemoryWriteCols};
+
+pub const NUM_AES_ENCRYPT_BLOCK_COLS: usize = size_of::<AesEncryptBlockCols<u8>>();
+
+/// A set of columns needed to compute an AES block encryption.
+///
+/// Each syscall is processed over one row per round key. The first row adds the first round key
+/// to the plaintext, and every other row computes a full round. The last row writes the
+/// ciphertext back to memory.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct AesEncryptBlockCols<T> {
+    /// Inputs.
+    pub shard: T,
+    pub clk: T,
+    pub block_ptr: T,
+    pub key_ptr: T,
+
+    /// Whether the syscall encrypts with AES-256 rather than AES-128.
+    pub is_aes256: T,
+
+    /// Which round the row computes, where round zero is the initial round key addition.
+    pub round: [T; AES256_ROUNDS + 1],
+
+    /// Whether the row computes the last round of the syscall.
+    pub is_last_round: T,
+
+    /// The plaintext block, which is the same in all rows of a syscall.
+    pub input: [T; AES_BLOCK_BYTES],
+
+    /// The state before the round of this row.
+    pub state: [T; AES_BLOCK_BYTES],
+
+    /// The S-box of each byte of the state, and that value doubled in the AES field.
+    pub sbox: [T; AES_BLOCK_BYTES],
+    pub sbox_xtime: [T; AES_BLOCK_BYTES],
+
+    /// The running xors of `MixColumns` for each byte, the last of which is the mixed byte.
+    pub mix: [[T; 4]; AES_BLOCK_BYTES],
+
+    /// The bytes the round key is added to. These are the state on round zero, the shifted S-box
+    /// bytes on the last round and the mixed bytes otherwise.
+    pub pre_key: [T; AES_BLOCK_BYTES],
+
+    /// The state after the round of this row.
+    pub output: [T; AES_BLOCK_BYTES],
+
+    /// Memory columns for the round key of this row.
+    pub key_access: [MemoryReadCols<T>; AES_BLOCK_WORDS],
+
+    /// Memory columns for the block, which are only used on the last row.
+    pub block_access: [MemoryWriteCols<T>; AES_BLOCK_WORDS],
+
+    pub is_real: T,
+}
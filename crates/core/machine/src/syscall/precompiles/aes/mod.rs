@@ -0,0 +1,65 @@
+mod air;
+mod columns;
+mod trace;
+
+/// The number of bytes in an AES block.
+pub const AES_BLOCK_BYTES: usize = 16;
+
+/// The number of rounds of AES-128.
+pub const AES128_ROUNDS: usize = 10;
+
+/// The number of rounds of AES-256, which is the largest number of rounds of a syscall.
+pub const AES256_ROUNDS: usize = 14;
+
+/// Implements AES-128 and AES-256 block encryption with an expanded key. The inputs to the syscall
+/// are a pointer to the 4 word block, which is overwritten with the ciphertext, and a pointer to
+/// the round keys.
+///
+/// In the AIR, each syscall takes up one row per round, plus one row for the initial round key
+/// addition. The state is stored as the column-major byte array of FIPS 197, which is also its
+/// layout in memory.
+#[derive(Default)]
+pub struct AesEncryptBlockChip;
+
+impl AesEncryptBlockChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Returns the index of the state byte that `ShiftRows` moves to index `i`.
+const fn shift_rows_source(i: usize) -> usize {
+    let (column, row) = (i / 4, i % 4);
+    ((column + row) % 4) * 4 + row
+}
+
+/// Returns the indices of the shifted bytes that `MixColumns` combines into the byte at index `i`,
+/// starting with the byte that is multiplied by two and followed by the one multiplied by three.
+const fn mix_columns_sources(i: usize) -> [usize; 4] {
+    let (column, row) = (i / 4, i % 4);
+    [
+        column * 4 + row,
+        column * 4 + (row + 1) % 4,
+        column * 4 + (row + 2) % 4,
+        column * 4 + (row + 3) % 4,
+    ]
+}
+
+#[cfg(test)]
+pub mod aes_tests {
+    use monerochan_core_executor::Program;
+    use monerochan_stark::CpuProver;
+    use test_artifacts::AES_ELF;
+
+    use crate::{
+        io::MONEROCHANStdin,
+        utils::{run_test, setup_logger},
+    };
+
+    #[test]
+    fn test_aes_program() {
+        setup_logger();
+        let program = Program::from(AES_ELF).unwrap();
+        run_test::<CpuProver<_, _>>(program, MONEROCHANStdin::new()).unwrap();
+    }
+}
@@ -0,0 +1,200 @@
+use std::borrow::BorrowMut;
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use monerochan_core_executor::{
+    events::{
+        aes_xtime, AesEncryptBlockEvent, ByteLookupEvent, ByteRecord, PrecompileEvent, AES_SBOX,
+    },
+    syscalls::SyscallCode,
+    ByteOpcode, ExecutionRecord, Program,
+};
+use monerochan_stark::air::MachineAir;
+
+use super::{
+    columns::{AesEncryptBlockCols, NUM_AES_ENCRYPT_BLOCK_COLS},
+    mix_columns_sources, shift_rows_source, AesEncryptBlockChip, AES256_ROUNDS, AES_BLOCK_BYTES,
+};
+use crate::utils::pad_rows_fixed;
+
+impl<F: PrimeField32> MachineAir<F> for AesEncryptBlockChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "AesEncryptBlock".to_string()
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        AesEncryptBlockCols::<F>::column_names()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        for (_, event) in input.get_precompile_events(SyscallCode::AES128_ENCRYPT_BLOCK) {
+            let event = if let PrecompileEvent::AesEncryptBlock(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            self.event_to_rows(event, &mut rows, &mut Vec::new());
+        }
+        let mut rows = rows.unwrap();
+
+        pad_rows_fixed(
+            &mut rows,
+            || [F::zero(); NUM_AES_ENCRYPT_BLOCK_COLS],
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_AES_ENCRYPT_BLOCK_COLS,
+        )
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events = input.get_precompile_events(SyscallCode::AES128_ENCRYPT_BLOCK);
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<ByteLookupEvent, usize> = HashMap::new();
+                events.iter().for_each(|(_, event)| {
+                    let event = if let PrecompileEvent::AesEncryptBlock(event) = event {
+                        event
+                    } else {
+                        unreachable!()
+                    };
+                    self.event_to_rows::<F>(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_byte_lookup_events_from_maps(blu_batches.iter().collect_vec());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::AES128_ENCRYPT_BLOCK).is_empty()
+        }
+    }
+}
+
+impl AesEncryptBlockChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &AesEncryptBlockEvent,
+        rows: &mut Option<Vec<[F; NUM_AES_ENCRYPT_BLOCK_COLS]>>,
+        blu: &mut impl ByteRecord,
+    ) {
+        let input: [u8; AES_BLOCK_BYTES] = event
+            .block
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect_vec()
+            .try_into()
+            .unwrap();
+        let mut state = input;
+
+        for round in 0..=event.num_rounds {
+            let mut row = [F::zero(); NUM_AES_ENCRYPT_BLOCK_COLS];
+            let cols: &mut AesEncryptBlockCols<F> = row.as_mut_slice().borrow_mut();
+
+            let is_last_round = round == event.num_rounds;
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.block_ptr = F::from_canonical_u32(event.block_ptr);
+            cols.key_ptr = F::from_canonical_u32(event.key_ptr);
+            cols.is_aes256 = F::from_bool(event.num_rounds == AES256_ROUNDS);
+            cols.round[round] = F::one();
+            cols.is_last_round = F::from_bool(is_last_round);
+            cols.is_real = F::one();
+
+            cols.input = input.map(F::from_canonical_u8);
+            cols.state = state.map(F::from_canonical_u8);
+
+            // SubBytes.
+            let sbox = state.map(|byte| AES_SBOX[byte as usize]);
+            let sbox_xtime = sbox.map(aes_xtime);
+            for i in 0..AES_BLOCK_BYTES {
+                blu.add_byte_lookup_event(ByteLookupEvent::new(
+                    ByteOpcode::AesSbox,
+                    sbox[i] as u16,
+                    sbox_xtime[i],
+                    state[i],
+                    0,
+                ));
+            }
+            cols.sbox = sbox.map(F::from_canonical_u8);
+            cols.sbox_xtime = sbox_xtime.map(F::from_canonical_u8);
+
+            // ShiftRows and MixColumns.
+            let mut mixed = [0u8; AES_BLOCK_BYTES];
+            for i in 0..AES_BLOCK_BYTES {
+                let [a0, a1, a2, a3] = mix_columns_sources(i).map(shift_rows_source);
+                let chain = [sbox_xtime[a1], sbox[a1], sbox[a2], sbox[a3]];
+                let mut acc = sbox_xtime[a0];
+                for (j, byte) in chain.into_iter().enumerate() {
+                    acc = xor(blu, acc, byte);
+                    cols.mix[i][j] = F::from_canonical_u8(acc);
+                }
+                mixed[i] = acc;
+            }
+
+            // AddRoundKey.
+            let pre_key: [u8; AES_BLOCK_BYTES] = core::array::from_fn(|i| {
+                if round == 0 {
+                    state[i]
+                } else if is_last_round {
+                    sbox[shift_rows_source(i)]
+                } else {
+                    mixed[i]
+                }
+            });
+            let key_records = &event.key_memory_records[4 * round..4 * round + 4];
+            let key =
+                key_records.iter().flat_map(|record| record.value.to_le_bytes()).collect_vec();
+            for i in 0..AES_BLOCK_BYTES {
+                state[i] = xor(blu, pre_key[i], key[i]);
+            }
+            cols.pre_key = pre_key.map(F::from_canonical_u8);
+            cols.output = state.map(F::from_canonical_u8);
+
+            for (access, record) in cols.key_access.iter_mut().zip(key_records) {
+                access.populate(*record, blu);
+            }
+            if is_last_round {
+                for (access, record) in
+                    cols.block_access.iter_mut().zip(&event.block_memory_records)
+                {
+                    access.populate(*record, blu);
+                }
+            }
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+    }
+}
+
+/// Xors two bytes and records the byte lookup.
+fn xor(blu: &mut impl ByteRecord, b: u8, c: u8) -> u8 {
+    let a = b ^ c;
+    blu.add_byte_lookup_event(ByteLookupEvent::new(ByteOpcode::XOR, a as u16, 0, b, c));
+    a
+}
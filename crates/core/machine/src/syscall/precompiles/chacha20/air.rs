@@ -0,0 +1,193 @@
+use core::borrow::Borrow;
+
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::AbstractField;
+use p3_matrix::Matrix;
+use monerochan_core_executor::{events::CHACHA20_STATE_WORDS, syscalls::SyscallCode};
+use monerochan_stark::{
+    air::{InteractionScope, MONEROCHANAirBuilder},
+    Word,
+};
+
+use super::{
+    columns::{ChaCha20BlockCols, QuarterRoundCols, NUM_CHACHA20_BLOCK_COLS},
+    ChaCha20BlockChip, CHACHA20_DOUBLE_ROUNDS, COLUMN_ROUNDS, DIAGONAL_ROUNDS,
+};
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::MemoryCols,
+    operations::{AddOperation, FixedRotateRightOperation, XorOperation},
+};
+
+impl<F> BaseAir<F> for ChaCha20BlockChip {
+    fn width(&self) -> usize {
+        NUM_CHACHA20_BLOCK_COLS
+    }
+}
+
+impl<AB> Air<AB> for ChaCha20BlockChip
+where
+    AB: MONEROCHANAirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &ChaCha20BlockCols<AB::Var> = (*local).borrow();
+        let next: &ChaCha20BlockCols<AB::Var> = (*next).borrow();
+
+        let first_round = local.round[0];
+        let last_round = local.round[CHACHA20_DOUBLE_ROUNDS - 1];
+
+        // Exactly one round flag is set on real rows, and none on padding rows.
+        builder.assert_bool(local.is_real);
+        for flag in local.round {
+            builder.assert_bool(flag);
+        }
+        builder.assert_eq(
+            local.round.iter().map(|flag| (*flag).into()).sum::<AB::Expr>(),
+            local.is_real,
+        );
+
+        // Real rows come before padding rows. A syscall starts on the first row and after the last
+        // round of the previous syscall, and the trace does not end in the middle of a syscall.
+        builder.when_first_row().assert_eq(first_round, local.is_real);
+        builder.when_transition().when(next.is_real).assert_one(local.is_real);
+        builder.when_transition().when(next.is_real).assert_eq(next.round[0], last_round);
+        builder.when_last_row().assert_eq(last_round, local.is_real);
+
+        // Within a syscall, the rounds follow each other and the inputs stay the same.
+        let is_continuing = local.is_real - last_round;
+        {
+            let mut transition_builder = builder.when_transition();
+            let mut continuing_builder = transition_builder.when(is_continuing.clone());
+            continuing_builder.assert_one(next.is_real);
+            for i in 1..CHACHA20_DOUBLE_ROUNDS {
+                continuing_builder.assert_eq(next.round[i], local.round[i - 1]);
+            }
+            continuing_builder.assert_eq(next.shard, local.shard);
+            continuing_builder.assert_eq(next.clk, local.clk);
+            continuing_builder.assert_eq(next.state_ptr, local.state_ptr);
+            for i in 0..CHACHA20_STATE_WORDS {
+                continuing_builder.assert_word_eq(next.initial_state[i], local.initial_state[i]);
+            }
+        }
+
+        // The first double round starts from the initial state.
+        for i in 0..CHACHA20_STATE_WORDS {
+            builder.when(first_round).assert_word_eq(local.state[i], local.initial_state[i]);
+        }
+
+        // Compute the double round.
+        let mut state = local.state;
+        for (cols, [a, b, c, d]) in local.column_rounds.iter().zip(COLUMN_ROUNDS) {
+            [state[a], state[b], state[c], state[d]] = eval_quarter_round(
+                builder,
+                [state[a], state[b], state[c], state[d]],
+                cols,
+                local.is_real,
+            );
+        }
+        for (cols, [a, b, c, d]) in local.diagonal_rounds.iter().zip(DIAGONAL_ROUNDS) {
+            [state[a], state[b], state[c], state[d]] = eval_quarter_round(
+                builder,
+                [state[a], state[b], state[c], state[d]],
+                cols,
+                local.is_real,
+            );
+        }
+
+        // The next double round starts from the output of this one.
+        for i in 0..CHACHA20_STATE_WORDS {
+            builder
+                .when_transition()
+                .when(is_continuing.clone())
+                .assert_word_eq(next.state[i], state[i]);
+        }
+
+        // Add the initial state to the output of the rounds.
+        for i in 0..CHACHA20_STATE_WORDS {
+            AddOperation::<AB::F>::eval(
+                builder,
+                state[i],
+                local.initial_state[i],
+                local.output[i],
+                local.is_real.into(),
+            );
+        }
+
+        // On the last row, the state is overwritten with the keystream block.
+        for i in 0..CHACHA20_STATE_WORDS {
+            builder
+                .when(last_round)
+                .assert_word_eq(*local.state_access[i].prev_value(), local.initial_state[i]);
+            builder
+                .when(last_round)
+                .assert_word_eq(*local.state_access[i].value(), local.output[i].value);
+        }
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk,
+            local.state_ptr,
+            &local.state_access,
+            last_round,
+        );
+
+        // Receive the syscall on the last row.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            AB::F::from_canonical_u32(SyscallCode::CHACHA20_BLOCK.syscall_id()),
+            local.state_ptr,
+            AB::Expr::zero(),
+            last_round,
+            InteractionScope::Local,
+        );
+    }
+}
+
+/// Evaluates a quarter round on the words `[a, b, c, d]` and returns the resulting words.
+fn eval_quarter_round<AB: MONEROCHANAirBuilder>(
+    builder: &mut AB,
+    [a, b, c, d]: [Word<AB::Var>; 4],
+    cols: &QuarterRoundCols<AB::Var>,
+    is_real: AB::Var,
+) -> [Word<AB::Var>; 4] {
+    // a += b; d ^= a; d <<<= 16.
+    AddOperation::<AB::F>::eval(builder, a, b, cols.a_add, is_real.into());
+    let a = cols.a_add.value;
+    XorOperation::<AB::F>::eval(builder, d, a, cols.d_xor, is_real);
+    let d = rotate_left_bytes(cols.d_xor.value, 2);
+
+    // c += d; b ^= c; b <<<= 12.
+    AddOperation::<AB::F>::eval(builder, c, d, cols.c_add, is_real.into());
+    let c = cols.c_add.value;
+    XorOperation::<AB::F>::eval(builder, b, c, cols.b_xor, is_real);
+    FixedRotateRightOperation::<AB::F>::eval(builder, cols.b_xor.value, 20, cols.b_rotate, is_real);
+    let b = cols.b_rotate.value;
+
+    // a += b; d ^= a; d <<<= 8.
+    AddOperation::<AB::F>::eval(builder, a, b, cols.a_add_2, is_real.into());
+    let a = cols.a_add_2.value;
+    XorOperation::<AB::F>::eval(builder, d, a, cols.d_xor_2, is_real);
+    let d = rotate_left_bytes(cols.d_xor_2.value, 1);
+
+    // c += d; b ^= c; b <<<= 7.
+    AddOperation::<AB::F>::eval(builder, c, d, cols.c_add_2, is_real.into());
+    let c = cols.c_add_2.value;
+    XorOperation::<AB::F>::eval(builder, b, c, cols.b_xor_2, is_real);
+    FixedRotateRightOperation::<AB::F>::eval(
+        builder,
+        cols.b_xor_2.value,
+        25,
+        cols.b_rotate_2,
+        is_real,
+    );
+    let b = cols.b_rotate_2.value;
+
+    [a, b, c, d]
+}
+
+/// Rotates a little endian word to the left by a whole number of bytes.
+fn rotate_left_bytes<T: Copy>(word: Word<T>, num_bytes: usize) -> Word<T> {
+    Word(core::array::from_fn(|i| word[(i + 4 - num_bytes) % 4]))
+}
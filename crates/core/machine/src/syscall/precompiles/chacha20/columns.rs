@@ -0,0 +1,77 @@
+use std::mem::size_of;
+
+use monerochan_core_executor::events::CHACHA20_STATE_WORDS;
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::Word;
+
+use super::CHACHA20_DOUBLE_ROUNDS;
+use crate::{
+    memory::MemoryWriteCols,
+    operations::{AddOperation, FixedRotateRightOperation, XorOperation},
+};
+
+pub const NUM_CHACHA20_BLOCK_COLS: usize = size_of::<ChaCha20BlockCols<u8>>();
+
+/// A set of columns needed to compute the ChaCha20 block function.
+///
+/// Each syscall is processed over 10 rows, one for each double round. The state is carried from
+/// one row to the next, and the last row adds the input state to the output of the rounds and
+/// writes the result back to memory.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct ChaCha20BlockCols<T> {
+    /// Inputs.
+    pub shard: T,
+    pub clk: T,
+    pub state_ptr: T,
+
+    /// Which double round the row computes.
+    pub round: [T; CHACHA20_DOUBLE_ROUNDS],
+
+    /// The input state of the block function, which is the same in all rows of a syscall.
+    pub initial_state: [Word<T>; CHACHA20_STATE_WORDS],
+
+    /// The state before the double round of this row.
+    pub state: [Word<T>; CHACHA20_STATE_WORDS],
+
+    /// The quarter rounds on the columns of the state.
+    pub column_rounds: [QuarterRoundCols<T>; 4],
+
+    /// The quarter rounds on the diagonals of the state.
+    pub diagonal_rounds: [QuarterRoundCols<T>; 4],
+
+    /// The sum of the initial state and the output of the double round. This is the keystream
+    /// block on the last row.
+    pub output: [AddOperation<T>; CHACHA20_STATE_WORDS],
+
+    /// Memory columns for the state, which are only used on the last row.
+    pub state_access: [MemoryWriteCols<T>; CHACHA20_STATE_WORDS],
+
+    pub is_real: T,
+}
+
+/// A set of columns needed to compute a ChaCha quarter round on the words `(a, b, c, d)`.
+///
+/// The rotations by 16 and 8 bits are byte aligned, so they are done by permuting the bytes of the
+/// xor results and need no columns.
+#[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct QuarterRoundCols<T> {
+    /// `a += b; d ^= a; d <<<= 16`.
+    pub a_add: AddOperation<T>,
+    pub d_xor: XorOperation<T>,
+
+    /// `c += d; b ^= c; b <<<= 12`.
+    pub c_add: AddOperation<T>,
+    pub b_xor: XorOperation<T>,
+    pub b_rotate: FixedRotateRightOperation<T>,
+
+    /// `a += b; d ^= a; d <<<= 8`.
+    pub a_add_2: AddOperation<T>,
+    pub d_xor_2: XorOperation<T>,
+
+    /// `c += d; b ^= c; b <<<= 7`.
+    pub c_add_2: AddOperation<T>,
+    pub b_xor_2: XorOperation<T>,
+    pub b_rotate_2: FixedRotateRightOperation<T>,
+}
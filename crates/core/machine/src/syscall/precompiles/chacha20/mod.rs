@@ -0,0 +1,46 @@
+mod air;
+mod columns;
+mod trace;
+
+/// The number of double rounds in the ChaCha20 block function.
+pub const CHACHA20_DOUBLE_ROUNDS: usize = 10;
+
+/// The state indices of the quarter rounds of a column round.
+const COLUMN_ROUNDS: [[usize; 4]; 4] =
+    [[0, 4, 8, 12], [1, 5, 9, 13], [2, 6, 10, 14], [3, 7, 11, 15]];
+
+/// The state indices of the quarter rounds of a diagonal round.
+const DIAGONAL_ROUNDS: [[usize; 4]; 4] =
+    [[0, 5, 10, 15], [1, 6, 11, 12], [2, 7, 8, 13], [3, 4, 9, 14]];
+
+/// Implements the ChaCha20 block function of RFC 8439. The input to the syscall is a pointer to
+/// the 16 word state, which is overwritten with the keystream block.
+///
+/// In the AIR, each syscall takes up 10 rows, one for each double round.
+#[derive(Default)]
+pub struct ChaCha20BlockChip;
+
+impl ChaCha20BlockChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(test)]
+pub mod chacha20_tests {
+    use monerochan_core_executor::Program;
+    use monerochan_stark::CpuProver;
+    use test_artifacts::CHACHA20_ELF;
+
+    use crate::{
+        io::MONEROCHANStdin,
+        utils::{run_test, setup_logger},
+    };
+
+    #[test]
+    fn test_chacha20_program() {
+        setup_logger();
+        let program = Program::from(CHACHA20_ELF).unwrap();
+        run_test::<CpuProver<_, _>>(program, MONEROCHANStdin::new()).unwrap();
+    }
+}
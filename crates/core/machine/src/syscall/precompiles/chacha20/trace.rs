@@ -0,0 +1,163 @@
+use std::borrow::BorrowMut;
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use p3_field::{Field, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{ParallelIterator, ParallelSlice};
+use monerochan_core_executor::{
+    events::{
+        ByteLookupEvent, ByteRecord, ChaCha20BlockEvent, PrecompileEvent, CHACHA20_STATE_WORDS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use monerochan_stark::{air::MachineAir, Word};
+
+use super::{
+    columns::{ChaCha20BlockCols, QuarterRoundCols, NUM_CHACHA20_BLOCK_COLS},
+    ChaCha20BlockChip, CHACHA20_DOUBLE_ROUNDS, COLUMN_ROUNDS, DIAGONAL_ROUNDS,
+};
+use crate::utils::pad_rows_fixed;
+
+impl<F: PrimeField32> MachineAir<F> for ChaCha20BlockChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "ChaCha20Block".to_string()
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        ChaCha20BlockCols::<F>::column_names()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let mut rows = Some(Vec::new());
+        for (_, event) in input.get_precompile_events(SyscallCode::CHACHA20_BLOCK) {
+            let event = if let PrecompileEvent::ChaCha20Block(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+            self.event_to_rows(event, &mut rows, &mut Vec::new());
+        }
+        let mut rows = rows.unwrap();
+
+        pad_rows_fixed(
+            &mut rows,
+            || [F::zero(); NUM_CHACHA20_BLOCK_COLS],
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_CHACHA20_BLOCK_COLS)
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events = input.get_precompile_events(SyscallCode::CHACHA20_BLOCK);
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<ByteLookupEvent, usize> = HashMap::new();
+                events.iter().for_each(|(_, event)| {
+                    let event = if let PrecompileEvent::ChaCha20Block(event) = event {
+                        event
+                    } else {
+                        unreachable!()
+                    };
+                    self.event_to_rows::<F>(event, &mut None, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_byte_lookup_events_from_maps(blu_batches.iter().collect_vec());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::CHACHA20_BLOCK).is_empty()
+        }
+    }
+}
+
+impl ChaCha20BlockChip {
+    fn event_to_rows<F: PrimeField32>(
+        &self,
+        event: &ChaCha20BlockEvent,
+        rows: &mut Option<Vec<[F; NUM_CHACHA20_BLOCK_COLS]>>,
+        blu: &mut impl ByteRecord,
+    ) {
+        let initial_state: [u32; CHACHA20_STATE_WORDS] = event.state.clone().try_into().unwrap();
+        let mut state = initial_state;
+
+        for round in 0..CHACHA20_DOUBLE_ROUNDS {
+            let mut row = [F::zero(); NUM_CHACHA20_BLOCK_COLS];
+            let cols: &mut ChaCha20BlockCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+            cols.round[round] = F::one();
+            cols.is_real = F::one();
+
+            cols.initial_state = initial_state.map(Word::from);
+            cols.state = state.map(Word::from);
+
+            for (round_cols, [a, b, c, d]) in cols.column_rounds.iter_mut().zip(COLUMN_ROUNDS) {
+                [state[a], state[b], state[c], state[d]] =
+                    round_cols.populate(blu, [state[a], state[b], state[c], state[d]]);
+            }
+            for (round_cols, [a, b, c, d]) in cols.diagonal_rounds.iter_mut().zip(DIAGONAL_ROUNDS) {
+                [state[a], state[b], state[c], state[d]] =
+                    round_cols.populate(blu, [state[a], state[b], state[c], state[d]]);
+            }
+
+            for i in 0..CHACHA20_STATE_WORDS {
+                cols.output[i].populate(blu, state[i], initial_state[i]);
+            }
+
+            if round == CHACHA20_DOUBLE_ROUNDS - 1 {
+                for i in 0..CHACHA20_STATE_WORDS {
+                    cols.state_access[i].populate(event.state_memory_records[i], blu);
+                }
+            }
+
+            if let Some(rows) = rows.as_mut() {
+                rows.push(row);
+            }
+        }
+    }
+}
+
+impl<F: Field> QuarterRoundCols<F> {
+    /// Populates the columns of a quarter round on the words `[a, b, c, d]` and returns the
+    /// resulting words.
+    fn populate(&mut self, blu: &mut impl ByteRecord, [a, b, c, d]: [u32; 4]) -> [u32; 4] {
+        let a = self.a_add.populate(blu, a, b);
+        let d = self.d_xor.populate(blu, d, a).rotate_left(16);
+
+        let c = self.c_add.populate(blu, c, d);
+        let b = self.b_xor.populate(blu, b, c);
+        let b = self.b_rotate.populate(blu, b, 20);
+
+        let a = self.a_add_2.populate(blu, a, b);
+        let d = self.d_xor_2.populate(blu, d, a).rotate_left(8);
+
+        let c = self.c_add_2.populate(blu, c, d);
+        let b = self.b_xor_2.populate(blu, b, c);
+        let b = self.b_rotate_2.populate(blu, b, 25);
+
+        [a, b, c, d]
+    }
+}
@@ -1,5 +1,7 @@
 mod ed_add;
 mod ed_decompress;
+mod x25519_ladder;
 
 pub use ed_add::*;
 pub use ed_decompress::*;
+pub use x25519_ladder::*;
@@ -0,0 +1,450 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+
+use hashbrown::HashMap;
+use itertools::Itertools;
+use num::{BigUint, Zero};
+
+use crate::air::MemoryAirBuilder;
+use p3_air::{Air, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{IntoParallelRefIterator, ParallelIterator, ParallelSlice};
+use monerochan_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, FieldOperation, PrecompileEvent, X25519LadderStepEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use monerochan_curves::{
+    edwards::{
+        ed25519::{Ed25519BaseField, CURVE25519_A24},
+        NUM_LIMBS, WORDS_FIELD_ELEMENT,
+    },
+    params::{FieldParameters, Limbs, NumLimbs},
+};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, MONEROCHANAirBuilder};
+
+use crate::{
+    memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
+    operations::field::{field_op::FieldOpCols, range::FieldLtCols},
+    utils::{limbs_from_prev_access, pad_rows_fixed},
+};
+
+pub const NUM_X25519_LADDER_STEP_COLS: usize = size_of::<X25519LadderStepCols<u8>>();
+
+/// The number of words in the ladder state `[x2, z2, x3, z3]`.
+const WORDS_LADDER_STATE: usize = 4 * WORDS_FIELD_ELEMENT;
+
+/// A set of columns to compute one step of the X25519 Montgomery ladder of RFC 7748.
+///
+/// The state `[x2, z2, x3, z3]` holds the projective u-coordinates of two points whose difference
+/// has the u-coordinate `x1`, and is overwritten with the doubling of the first point and the sum
+/// of both points.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct X25519LadderStepCols<T> {
+    pub is_real: T,
+    pub shard: T,
+    pub clk: T,
+    pub state_ptr: T,
+    pub x1_ptr: T,
+    pub state_access: [MemoryWriteCols<T>; WORDS_LADDER_STATE],
+    pub x1_access: [MemoryReadCols<T>; WORDS_FIELD_ELEMENT],
+    pub(crate) a: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) aa: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) b: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) bb: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) e: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) c: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) d: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) da: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) cb: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) da_plus_cb: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) da_minus_cb: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) da_minus_cb_squared: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) a24_mul_e: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) aa_plus_a24_mul_e: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) x2_out: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) z2_out: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) x3_out: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) z3_out: FieldOpCols<T, Ed25519BaseField>,
+    pub(crate) x2_range: FieldLtCols<T, Ed25519BaseField>,
+    pub(crate) z2_range: FieldLtCols<T, Ed25519BaseField>,
+    pub(crate) x3_range: FieldLtCols<T, Ed25519BaseField>,
+    pub(crate) z3_range: FieldLtCols<T, Ed25519BaseField>,
+}
+
+#[derive(Default)]
+pub struct X25519LadderStepChip;
+
+impl X25519LadderStepChip {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        record: &mut impl ByteRecord,
+        cols: &mut X25519LadderStepCols<F>,
+        x1: &BigUint,
+        [x2, z2, x3, z3]: &[BigUint; 4],
+    ) {
+        let a = cols.a.populate(record, x2, z2, FieldOperation::Add);
+        let aa = cols.aa.populate(record, &a, &a, FieldOperation::Mul);
+        let b = cols.b.populate(record, x2, z2, FieldOperation::Sub);
+        let bb = cols.bb.populate(record, &b, &b, FieldOperation::Mul);
+        let e = cols.e.populate(record, &aa, &bb, FieldOperation::Sub);
+        let c = cols.c.populate(record, x3, z3, FieldOperation::Add);
+        let d = cols.d.populate(record, x3, z3, FieldOperation::Sub);
+        let da = cols.da.populate(record, &d, &a, FieldOperation::Mul);
+        let cb = cols.cb.populate(record, &c, &b, FieldOperation::Mul);
+        let da_plus_cb = cols.da_plus_cb.populate(record, &da, &cb, FieldOperation::Add);
+        let da_minus_cb = cols.da_minus_cb.populate(record, &da, &cb, FieldOperation::Sub);
+        let da_minus_cb_squared = cols.da_minus_cb_squared.populate(
+            record,
+            &da_minus_cb,
+            &da_minus_cb,
+            FieldOperation::Mul,
+        );
+        let a24 = BigUint::from(CURVE25519_A24);
+        let a24_mul_e = cols.a24_mul_e.populate(record, &a24, &e, FieldOperation::Mul);
+        let aa_plus_a24_mul_e =
+            cols.aa_plus_a24_mul_e.populate(record, &aa, &a24_mul_e, FieldOperation::Add);
+
+        let x2_out = cols.x2_out.populate(record, &aa, &bb, FieldOperation::Mul);
+        let z2_out = cols.z2_out.populate(record, &e, &aa_plus_a24_mul_e, FieldOperation::Mul);
+        let x3_out = cols.x3_out.populate(record, &da_plus_cb, &da_plus_cb, FieldOperation::Mul);
+        let z3_out = cols.z3_out.populate(record, x1, &da_minus_cb_squared, FieldOperation::Mul);
+
+        let modulus = Ed25519BaseField::modulus();
+        cols.x2_range.populate(record, &x2_out, &modulus);
+        cols.z2_range.populate(record, &z2_out, &modulus);
+        cols.x3_range.populate(record, &x3_out, &modulus);
+        cols.z3_range.populate(record, &z3_out, &modulus);
+    }
+
+    /// Create a row from an event.
+    fn event_to_row<F: PrimeField32>(
+        &self,
+        event: &X25519LadderStepEvent,
+        cols: &mut X25519LadderStepCols<F>,
+        blu: &mut impl ByteRecord,
+    ) {
+        let x1 = BigUint::from_slice(&event.x1);
+        let state: [BigUint; 4] = core::array::from_fn(|i| {
+            BigUint::from_slice(
+                &event.state[i * WORDS_FIELD_ELEMENT..(i + 1) * WORDS_FIELD_ELEMENT],
+            )
+        });
+
+        // Populate basic columns.
+        cols.is_real = F::one();
+        cols.shard = F::from_canonical_u32(event.shard);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.state_ptr = F::from_canonical_u32(event.state_ptr);
+        cols.x1_ptr = F::from_canonical_u32(event.x1_ptr);
+
+        Self::populate_field_ops(blu, cols, &x1, &state);
+
+        // Populate the memory access columns.
+        for i in 0..WORDS_FIELD_ELEMENT {
+            cols.x1_access[i].populate(event.x1_memory_records[i], blu);
+        }
+        for i in 0..WORDS_LADDER_STATE {
+            cols.state_access[i].populate(event.state_memory_records[i], blu);
+        }
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for X25519LadderStepChip {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "X25519LadderStep".to_string()
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        X25519LadderStepCols::<F>::column_names()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(SyscallCode::X25519_LADDER_STEP);
+
+        let mut rows = events
+            .par_iter()
+            .map(|(_, event)| {
+                let event = if let PrecompileEvent::X25519LadderStep(event) = event {
+                    event
+                } else {
+                    unreachable!();
+                };
+
+                let mut row = [F::zero(); NUM_X25519_LADDER_STEP_COLS];
+                let cols: &mut X25519LadderStepCols<F> = row.as_mut_slice().borrow_mut();
+                let mut blu = Vec::new();
+                self.event_to_row(event, cols, &mut blu);
+                row
+            })
+            .collect::<Vec<_>>();
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = [F::zero(); NUM_X25519_LADDER_STEP_COLS];
+                let cols: &mut X25519LadderStepCols<F> = row.as_mut_slice().borrow_mut();
+                let state = core::array::from_fn(|_| BigUint::zero());
+                Self::populate_field_ops(&mut vec![], cols, &BigUint::zero(), &state);
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            NUM_X25519_LADDER_STEP_COLS,
+        )
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events = input.get_precompile_events(SyscallCode::X25519_LADDER_STEP);
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        let blu_batches = events
+            .par_chunks(chunk_size)
+            .map(|events| {
+                let mut blu: HashMap<ByteLookupEvent, usize> = HashMap::new();
+                events.iter().for_each(|(_, event)| {
+                    let event = if let PrecompileEvent::X25519LadderStep(event) = event {
+                        event
+                    } else {
+                        unreachable!();
+                    };
+
+                    let mut row = [F::zero(); NUM_X25519_LADDER_STEP_COLS];
+                    let cols: &mut X25519LadderStepCols<F> = row.as_mut_slice().borrow_mut();
+                    self.event_to_row(event, cols, &mut blu);
+                });
+                blu
+            })
+            .collect::<Vec<_>>();
+
+        output.add_byte_lookup_events_from_maps(blu_batches.iter().collect_vec());
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::X25519_LADDER_STEP).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F> BaseAir<F> for X25519LadderStepChip {
+    fn width(&self) -> usize {
+        NUM_X25519_LADDER_STEP_COLS
+    }
+}
+
+impl<AB> Air<AB> for X25519LadderStepChip
+where
+    AB: MONEROCHANAirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &X25519LadderStepCols<AB::Var> = (*local).borrow();
+
+        let x1: Limbs<AB::Var, <Ed25519BaseField as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.x1_access);
+        let x2: Limbs<AB::Var, <Ed25519BaseField as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.state_access[0..8]);
+        let z2: Limbs<AB::Var, <Ed25519BaseField as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.state_access[8..16]);
+        let x3: Limbs<AB::Var, <Ed25519BaseField as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.state_access[16..24]);
+        let z3: Limbs<AB::Var, <Ed25519BaseField as NumLimbs>::Limbs> =
+            limbs_from_prev_access(&local.state_access[24..32]);
+
+        // A = x2 + z2, AA = A^2, B = x2 - z2, BB = B^2 and E = AA - BB.
+        local.a.eval(builder, &x2, &z2, FieldOperation::Add, local.is_real);
+        local.aa.eval(
+            builder,
+            &local.a.result,
+            &local.a.result,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+        local.b.eval(builder, &x2, &z2, FieldOperation::Sub, local.is_real);
+        local.bb.eval(
+            builder,
+            &local.b.result,
+            &local.b.result,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+        local.e.eval(
+            builder,
+            &local.aa.result,
+            &local.bb.result,
+            FieldOperation::Sub,
+            local.is_real,
+        );
+
+        // C = x3 + z3, D = x3 - z3, DA = D * A and CB = C * B.
+        local.c.eval(builder, &x3, &z3, FieldOperation::Add, local.is_real);
+        local.d.eval(builder, &x3, &z3, FieldOperation::Sub, local.is_real);
+        local.da.eval(
+            builder,
+            &local.d.result,
+            &local.a.result,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+        local.cb.eval(
+            builder,
+            &local.c.result,
+            &local.b.result,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+
+        // x3' = (DA + CB)^2 and z3' = x1 * (DA - CB)^2.
+        local.da_plus_cb.eval(
+            builder,
+            &local.da.result,
+            &local.cb.result,
+            FieldOperation::Add,
+            local.is_real,
+        );
+        local.da_minus_cb.eval(
+            builder,
+            &local.da.result,
+            &local.cb.result,
+            FieldOperation::Sub,
+            local.is_real,
+        );
+        local.da_minus_cb_squared.eval(
+            builder,
+            &local.da_minus_cb.result,
+            &local.da_minus_cb.result,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+        local.x3_out.eval(
+            builder,
+            &local.da_plus_cb.result,
+            &local.da_plus_cb.result,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+        local.z3_out.eval(
+            builder,
+            &x1,
+            &local.da_minus_cb_squared.result,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+
+        // x2' = AA * BB and z2' = E * (AA + a24 * E).
+        let a24 = Ed25519BaseField::to_limbs_field::<AB::Expr, _>(&BigUint::from(CURVE25519_A24));
+        local.a24_mul_e.eval(builder, &a24, &local.e.result, FieldOperation::Mul, local.is_real);
+        local.aa_plus_a24_mul_e.eval(
+            builder,
+            &local.aa.result,
+            &local.a24_mul_e.result,
+            FieldOperation::Add,
+            local.is_real,
+        );
+        local.x2_out.eval(
+            builder,
+            &local.aa.result,
+            &local.bb.result,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+        local.z2_out.eval(
+            builder,
+            &local.e.result,
+            &local.aa_plus_a24_mul_e.result,
+            FieldOperation::Mul,
+            local.is_real,
+        );
+
+        // The new state is reduced.
+        let modulus =
+            Ed25519BaseField::to_limbs_field::<AB::Expr, AB::F>(&Ed25519BaseField::modulus());
+        local.x2_range.eval(builder, &local.x2_out.result, &modulus, local.is_real);
+        local.z2_range.eval(builder, &local.z2_out.result, &modulus, local.is_real);
+        local.x3_range.eval(builder, &local.x3_out.result, &modulus, local.is_real);
+        local.z3_range.eval(builder, &local.z3_out.result, &modulus, local.is_real);
+
+        // Constrain the state to be updated with the new values.
+        let state_vec = value_as_limbs(&local.state_access);
+        for (i, result) in
+            [local.x2_out.result, local.z2_out.result, local.x3_out.result, local.z3_out.result]
+                .into_iter()
+                .enumerate()
+        {
+            builder
+                .when(local.is_real)
+                .assert_all_eq(result, state_vec[i * NUM_LIMBS..(i + 1) * NUM_LIMBS].to_vec());
+        }
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.x1_ptr,
+            &local.x1_access,
+            local.is_real,
+        );
+
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk + AB::F::from_canonical_u32(1),
+            local.state_ptr,
+            &local.state_access,
+            local.is_real,
+        );
+
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            AB::F::from_canonical_u32(SyscallCode::X25519_LADDER_STEP.syscall_id()),
+            local.state_ptr,
+            local.x1_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use monerochan_core_executor::Program;
+    use monerochan_stark::CpuProver;
+    use test_artifacts::X25519_ELF;
+
+    use crate::{io::MONEROCHANStdin, utils};
+
+    #[test]
+    fn test_x25519_program() {
+        utils::setup_logger();
+        let program = Program::from(X25519_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        utils::run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+}
@@ -84,9 +84,17 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for FpOpChip<P> {
         match P::FIELD_TYPE {
             FieldType::Bn254 => "Bn254FpOpAssign".to_string(),
             FieldType::Bls12381 => "Bls12381FpOpAssign".to_string(),
+            FieldType::Secp256k1 => "Secp256k1FpOpAssign".to_string(),
+            FieldType::Secp256k1Scalar => "Secp256k1FnOpAssign".to_string(),
+            FieldType::Secp256r1 => "Secp256r1FpOpAssign".to_string(),
+            FieldType::Secp256r1Scalar => "Secp256r1FnOpAssign".to_string(),
         }
     }
 
+    fn column_names(&self) -> Vec<String> {
+        FpOpCols::<F, P>::column_names()
+    }
+
     fn generate_trace(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
         // All the fp events for a given curve are coalesce to the curve's Add operation.  Only
         // retrieve precompile events for that operation.
@@ -95,6 +103,18 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for FpOpChip<P> {
         let events = match P::FIELD_TYPE {
             FieldType::Bn254 => input.get_precompile_events(SyscallCode::BN254_FP_ADD).iter(),
             FieldType::Bls12381 => input.get_precompile_events(SyscallCode::BLS12381_FP_ADD).iter(),
+            FieldType::Secp256k1 => {
+                input.get_precompile_events(SyscallCode::SECP256K1_FP_ADD).iter()
+            }
+            FieldType::Secp256k1Scalar => {
+                input.get_precompile_events(SyscallCode::SECP256K1_FN_ADD).iter()
+            }
+            FieldType::Secp256r1 => {
+                input.get_precompile_events(SyscallCode::SECP256R1_FP_ADD).iter()
+            }
+            FieldType::Secp256r1Scalar => {
+                input.get_precompile_events(SyscallCode::SECP256R1_FN_ADD).iter()
+            }
         };
 
         let mut rows = Vec::new();
@@ -104,6 +124,10 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for FpOpChip<P> {
             let event = match (P::FIELD_TYPE, event) {
                 (FieldType::Bn254, PrecompileEvent::Bn254Fp(event)) => event,
                 (FieldType::Bls12381, PrecompileEvent::Bls12381Fp(event)) => event,
+                (FieldType::Secp256k1, PrecompileEvent::Secp256k1Fp(event)) => event,
+                (FieldType::Secp256k1Scalar, PrecompileEvent::Secp256k1Fn(event)) => event,
+                (FieldType::Secp256r1, PrecompileEvent::Secp256r1Fp(event)) => event,
+                (FieldType::Secp256r1Scalar, PrecompileEvent::Secp256r1Fn(event)) => event,
                 _ => unreachable!(),
             };
 
@@ -167,7 +191,15 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for FpOpChip<P> {
             shard.get_precompile_events(SyscallCode::BN254_FP_SUB).is_empty() &&
                 shard.get_precompile_events(SyscallCode::BN254_FP_MUL).is_empty() &&
                 shard.get_precompile_events(SyscallCode::BLS12381_FP_SUB).is_empty() &&
-                shard.get_precompile_events(SyscallCode::BLS12381_FP_MUL).is_empty()
+                shard.get_precompile_events(SyscallCode::BLS12381_FP_MUL).is_empty() &&
+                shard.get_precompile_events(SyscallCode::SECP256K1_FP_SUB).is_empty() &&
+                shard.get_precompile_events(SyscallCode::SECP256K1_FP_MUL).is_empty() &&
+                shard.get_precompile_events(SyscallCode::SECP256K1_FN_SUB).is_empty() &&
+                shard.get_precompile_events(SyscallCode::SECP256K1_FN_MUL).is_empty() &&
+                shard.get_precompile_events(SyscallCode::SECP256R1_FP_SUB).is_empty() &&
+                shard.get_precompile_events(SyscallCode::SECP256R1_FP_MUL).is_empty() &&
+                shard.get_precompile_events(SyscallCode::SECP256R1_FN_SUB).is_empty() &&
+                shard.get_precompile_events(SyscallCode::SECP256R1_FN_MUL).is_empty()
         );
 
         if let Some(shape) = shard.shape.as_ref() {
@@ -180,6 +212,18 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for FpOpChip<P> {
                 FieldType::Bls12381 => {
                     !shard.get_precompile_events(SyscallCode::BLS12381_FP_ADD).is_empty()
                 }
+                FieldType::Secp256k1 => {
+                    !shard.get_precompile_events(SyscallCode::SECP256K1_FP_ADD).is_empty()
+                }
+                FieldType::Secp256k1Scalar => {
+                    !shard.get_precompile_events(SyscallCode::SECP256K1_FN_ADD).is_empty()
+                }
+                FieldType::Secp256r1 => {
+                    !shard.get_precompile_events(SyscallCode::SECP256R1_FP_ADD).is_empty()
+                }
+                FieldType::Secp256r1Scalar => {
+                    !shard.get_precompile_events(SyscallCode::SECP256R1_FN_ADD).is_empty()
+                }
             }
         }
     }
@@ -267,6 +311,26 @@ where
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_FP_SUB.syscall_id()),
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_FP_MUL.syscall_id()),
             ),
+            FieldType::Secp256k1 => (
+                AB::F::from_canonical_u32(SyscallCode::SECP256K1_FP_ADD.syscall_id()),
+                AB::F::from_canonical_u32(SyscallCode::SECP256K1_FP_SUB.syscall_id()),
+                AB::F::from_canonical_u32(SyscallCode::SECP256K1_FP_MUL.syscall_id()),
+            ),
+            FieldType::Secp256k1Scalar => (
+                AB::F::from_canonical_u32(SyscallCode::SECP256K1_FN_ADD.syscall_id()),
+                AB::F::from_canonical_u32(SyscallCode::SECP256K1_FN_SUB.syscall_id()),
+                AB::F::from_canonical_u32(SyscallCode::SECP256K1_FN_MUL.syscall_id()),
+            ),
+            FieldType::Secp256r1 => (
+                AB::F::from_canonical_u32(SyscallCode::SECP256R1_FP_ADD.syscall_id()),
+                AB::F::from_canonical_u32(SyscallCode::SECP256R1_FP_SUB.syscall_id()),
+                AB::F::from_canonical_u32(SyscallCode::SECP256R1_FP_MUL.syscall_id()),
+            ),
+            FieldType::Secp256r1Scalar => (
+                AB::F::from_canonical_u32(SyscallCode::SECP256R1_FN_ADD.syscall_id()),
+                AB::F::from_canonical_u32(SyscallCode::SECP256R1_FN_SUB.syscall_id()),
+                AB::F::from_canonical_u32(SyscallCode::SECP256R1_FN_MUL.syscall_id()),
+            ),
         };
         let syscall_id_felt = local.is_add * add_syscall_id +
             local.is_sub * sub_syscall_id +
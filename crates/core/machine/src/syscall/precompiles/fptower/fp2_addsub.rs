@@ -18,7 +18,7 @@ use monerochan_core_executor::{
 };
 use monerochan_curves::{
     params::{Limbs, NumLimbs},
-    weierstrass::{FieldType, FpOpField},
+    weierstrass::{FieldType, FpTowerField},
 };
 use monerochan_derive::AlignedBorrow;
 use monerochan_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, Polynomial, MONEROCHANAirBuilder};
@@ -30,14 +30,14 @@ use crate::{
     utils::{limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le_vec},
 };
 
-pub const fn num_fp2_addsub_cols<P: FpOpField>() -> usize {
+pub const fn num_fp2_addsub_cols<P: FpTowerField>() -> usize {
     size_of::<Fp2AddSubAssignCols<u8, P>>()
 }
 
 /// A set of columns for the Fp2AddSub operation.
 #[derive(Debug, Clone, AlignedBorrow)]
 #[repr(C)]
-pub struct Fp2AddSubAssignCols<T, P: FpOpField> {
+pub struct Fp2AddSubAssignCols<T, P: FpTowerField> {
     pub is_real: T,
     pub shard: T,
     pub clk: T,
@@ -56,7 +56,7 @@ pub struct Fp2AddSubAssignChip<P> {
     _marker: PhantomData<P>,
 }
 
-impl<P: FpOpField> Fp2AddSubAssignChip<P> {
+impl<P: FpTowerField> Fp2AddSubAssignChip<P> {
     pub const fn new() -> Self {
         Self { _marker: PhantomData }
     }
@@ -80,7 +80,7 @@ impl<P: FpOpField> Fp2AddSubAssignChip<P> {
     }
 }
 
-impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2AddSubAssignChip<P> {
+impl<F: PrimeField32, P: FpTowerField> MachineAir<F> for Fp2AddSubAssignChip<P> {
     type Record = ExecutionRecord;
 
     type Program = Program;
@@ -89,9 +89,14 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2AddSubAssignChip<P> {
         match P::FIELD_TYPE {
             FieldType::Bn254 => "Bn254Fp2AddSubAssign".to_string(),
             FieldType::Bls12381 => "Bls12381Fp2AddSubAssign".to_string(),
+            _ => unreachable!(),
         }
     }
 
+    fn column_names(&self) -> Vec<String> {
+        Fp2AddSubAssignCols::<F, P>::column_names()
+    }
+
     fn generate_trace(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
         // All the fp2 sub and add events for a given curve are coalesce to the curve's Add
         // operation.  Only retrieve precompile events for that operation.
@@ -102,6 +107,7 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2AddSubAssignChip<P> {
             FieldType::Bls12381 => {
                 input.get_precompile_events(SyscallCode::BLS12381_FP2_ADD).iter()
             }
+            _ => unreachable!(),
         };
 
         let mut rows = Vec::new();
@@ -201,6 +207,7 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2AddSubAssignChip<P> {
                 FieldType::Bls12381 => {
                     !shard.get_precompile_events(SyscallCode::BLS12381_FP2_ADD).is_empty()
                 }
+                _ => unreachable!(),
             }
         }
     }
@@ -210,13 +217,13 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2AddSubAssignChip<P> {
     }
 }
 
-impl<F, P: FpOpField> BaseAir<F> for Fp2AddSubAssignChip<P> {
+impl<F, P: FpTowerField> BaseAir<F> for Fp2AddSubAssignChip<P> {
     fn width(&self) -> usize {
         num_fp2_addsub_cols::<P>()
     }
 }
 
-impl<AB, P: FpOpField> Air<AB> for Fp2AddSubAssignChip<P>
+impl<AB, P: FpTowerField> Air<AB> for Fp2AddSubAssignChip<P>
 where
     AB: MONEROCHANAirBuilder,
     Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
@@ -302,6 +309,7 @@ where
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_FP2_ADD.syscall_id()),
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_FP2_SUB.syscall_id()),
             ),
+            _ => unreachable!(),
         };
 
         let syscall_id_felt =
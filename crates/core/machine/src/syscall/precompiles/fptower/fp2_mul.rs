@@ -17,7 +17,7 @@ use monerochan_core_executor::{
 };
 use monerochan_curves::{
     params::{FieldParameters, Limbs, NumLimbs, NumWords},
-    weierstrass::{FieldType, FpOpField},
+    weierstrass::{FieldType, FpTowerField},
 };
 use monerochan_derive::AlignedBorrow;
 use monerochan_stark::air::{BaseAirBuilder, InteractionScope, MachineAir, Polynomial, MONEROCHANAirBuilder};
@@ -60,7 +60,7 @@ pub struct Fp2MulAssignChip<P> {
     _marker: PhantomData<P>,
 }
 
-impl<P: FpOpField> Fp2MulAssignChip<P> {
+impl<P: FpTowerField> Fp2MulAssignChip<P> {
     pub const fn new() -> Self {
         Self { _marker: PhantomData }
     }
@@ -123,7 +123,7 @@ impl<P: FpOpField> Fp2MulAssignChip<P> {
     }
 }
 
-impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2MulAssignChip<P> {
+impl<F: PrimeField32, P: FpTowerField> MachineAir<F> for Fp2MulAssignChip<P> {
     type Record = ExecutionRecord;
 
     type Program = Program;
@@ -132,13 +132,19 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2MulAssignChip<P> {
         match P::FIELD_TYPE {
             FieldType::Bn254 => "Bn254Fp2MulAssign".to_string(),
             FieldType::Bls12381 => "Bls12381Fp2MulAssign".to_string(),
+            _ => unreachable!(),
         }
     }
 
+    fn column_names(&self) -> Vec<String> {
+        Fp2MulAssignCols::<F, P>::column_names()
+    }
+
     fn generate_trace(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
         let events = match P::FIELD_TYPE {
             FieldType::Bn254 => input.get_precompile_events(SyscallCode::BN254_FP2_MUL),
             FieldType::Bls12381 => input.get_precompile_events(SyscallCode::BLS12381_FP2_MUL),
+            _ => unreachable!(),
         };
 
         let mut rows = Vec::new();
@@ -215,6 +221,7 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2MulAssignChip<P> {
                 FieldType::Bls12381 => {
                     !shard.get_precompile_events(SyscallCode::BLS12381_FP2_MUL).is_empty()
                 }
+                _ => unreachable!(),
             }
         }
     }
@@ -224,13 +231,13 @@ impl<F: PrimeField32, P: FpOpField> MachineAir<F> for Fp2MulAssignChip<P> {
     }
 }
 
-impl<F, P: FpOpField> BaseAir<F> for Fp2MulAssignChip<P> {
+impl<F, P: FpTowerField> BaseAir<F> for Fp2MulAssignChip<P> {
     fn width(&self) -> usize {
         num_fp2_mul_cols::<P>()
     }
 }
 
-impl<AB, P: FpOpField> Air<AB> for Fp2MulAssignChip<P>
+impl<AB, P: FpTowerField> Air<AB> for Fp2MulAssignChip<P>
 where
     AB: MONEROCHANAirBuilder,
     Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
@@ -342,6 +349,7 @@ where
             FieldType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_FP2_MUL.syscall_id())
             }
+            _ => unreachable!(),
         };
 
         builder.receive_syscall(
@@ -0,0 +1,407 @@
+use std::{
+    borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
+};
+
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    utils::zeroed_f_vec,
+};
+use generic_array::GenericArray;
+use itertools::Itertools;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use monerochan_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use monerochan_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::{tower::g2_add_slope, FieldType, FpTowerField},
+};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::air::{
+    BaseAirBuilder, InteractionScope, MachineAir, Polynomial, MONEROCHANAirBuilder,
+};
+use std::mem::size_of;
+use typenum::Unsigned;
+
+use crate::{
+    memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
+    operations::field::{field_sum_of_products::FieldSumOfProductsCols, range::FieldLtCols},
+    utils::{limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le_vec},
+};
+
+pub const fn num_g2_add_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<G2AddAssignCols<u8, P>>()
+}
+
+/// The terms of `slope * (q.x - p.x) - (q.y - p.y)`, whose coefficients are zero in `Fp2` exactly
+/// when `slope` is the slope of the line through `p` and `q`.
+///
+/// Points are given by the `Fp` coefficients `[x.re, x.im, y.re, y.im]`.
+pub(crate) fn g2_add_slope_terms<T: Clone>(
+    p: &[T; 4],
+    q: &[T; 4],
+    slope: &[T; 2],
+    one: &T,
+) -> [Vec<(i32, T, T)>; 2] {
+    let [p_x_re, p_x_im, p_y_re, p_y_im] = p.clone();
+    let [q_x_re, q_x_im, q_y_re, q_y_im] = q.clone();
+    let [s_re, s_im] = slope.clone();
+    [
+        vec![
+            (1, s_re.clone(), q_x_re.clone()),
+            (-1, s_re.clone(), p_x_re.clone()),
+            (-1, s_im.clone(), q_x_im.clone()),
+            (1, s_im.clone(), p_x_im.clone()),
+            (-1, q_y_re, one.clone()),
+            (1, p_y_re, one.clone()),
+        ],
+        vec![
+            (1, s_re.clone(), q_x_im),
+            (-1, s_re, p_x_im),
+            (1, s_im.clone(), q_x_re),
+            (-1, s_im, p_x_re),
+            (-1, q_y_im, one.clone()),
+            (1, p_y_im, one.clone()),
+        ],
+    ]
+}
+
+/// The terms of `slope^2 - p.x - q.x`, the x-coordinate of the sum of `p` and `q`.
+pub(crate) fn g2_x3_terms<T: Clone>(
+    p_x: &[T; 2],
+    q_x: &[T; 2],
+    slope: &[T; 2],
+    one: &T,
+) -> [Vec<(i32, T, T)>; 2] {
+    let [s_re, s_im] = slope.clone();
+    [
+        vec![
+            (1, s_re.clone(), s_re.clone()),
+            (-1, s_im.clone(), s_im.clone()),
+            (-1, p_x[0].clone(), one.clone()),
+            (-1, q_x[0].clone(), one.clone()),
+        ],
+        vec![(2, s_re, s_im), (-1, p_x[1].clone(), one.clone()), (-1, q_x[1].clone(), one.clone())],
+    ]
+}
+
+/// The terms of `slope * (p.x - x3) - p.y`, the y-coordinate of the sum of `p` and `q`.
+pub(crate) fn g2_y3_terms<T: Clone>(
+    p: &[T; 4],
+    x3: &[T; 2],
+    slope: &[T; 2],
+    one: &T,
+) -> [Vec<(i32, T, T)>; 2] {
+    let [p_x_re, p_x_im, p_y_re, p_y_im] = p.clone();
+    let [x3_re, x3_im] = x3.clone();
+    let [s_re, s_im] = slope.clone();
+    [
+        vec![
+            (1, s_re.clone(), p_x_re.clone()),
+            (-1, s_re.clone(), x3_re.clone()),
+            (-1, s_im.clone(), p_x_im.clone()),
+            (1, s_im.clone(), x3_im.clone()),
+            (-1, p_y_re, one.clone()),
+        ],
+        vec![
+            (1, s_re.clone(), p_x_im),
+            (-1, s_re, x3_im),
+            (1, s_im.clone(), p_x_re),
+            (-1, s_im, x3_re),
+            (-1, p_y_im, one.clone()),
+        ],
+    ]
+}
+
+/// Borrows the terms of a linear combination of products of `BigUint`s.
+pub(crate) fn borrow_terms(terms: &[(i32, BigUint, BigUint)]) -> Vec<(i32, &BigUint, &BigUint)> {
+    terms.iter().map(|(c, a, b)| (*c, a, b)).collect()
+}
+
+/// A set of columns for the addition of two points of the G2 subgroup.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct G2AddAssignCols<T, P: FieldParameters + NumWords> {
+    pub is_real: T,
+    pub shard: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub q_ptr: T,
+    pub p_access: [GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>; 2],
+    pub q_access: [GenericArray<MemoryReadCols<T>, P::WordsCurvePoint>; 2],
+    pub(crate) slope: [Limbs<T, P::Limbs>; 2],
+    pub(crate) slope_check: [FieldSumOfProductsCols<T, P>; 2],
+    pub(crate) x3: [FieldSumOfProductsCols<T, P>; 2],
+    pub(crate) y3: [FieldSumOfProductsCols<T, P>; 2],
+    pub(crate) x3_range: [FieldLtCols<T, P>; 2],
+    pub(crate) y3_range: [FieldLtCols<T, P>; 2],
+}
+
+#[derive(Default)]
+pub struct G2AddAssignChip<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P: FpTowerField> G2AddAssignChip<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn syscall_code() -> SyscallCode {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => SyscallCode::BN254_G2_ADD,
+            FieldType::Bls12381 => SyscallCode::BLS12381_G2_ADD,
+            _ => unreachable!(),
+        }
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        cols: &mut G2AddAssignCols<F, P>,
+        p: [BigUint; 4],
+        q: [BigUint; 4],
+        slope: [BigUint; 2],
+    ) {
+        let modulus = P::modulus();
+        let one = BigUint::one();
+
+        for (limbs, value) in cols.slope.iter_mut().zip(slope.iter()) {
+            *limbs = P::to_limbs_field::<F, F>(value);
+            blu_events.add_u8_range_checks_field(&limbs.0);
+        }
+
+        let slope_terms = g2_add_slope_terms(&p, &q, &slope, &one);
+        for (check, terms) in cols.slope_check.iter_mut().zip(slope_terms.iter()) {
+            let result = check.populate(blu_events, &borrow_terms(terms));
+            debug_assert!(result.is_zero());
+        }
+
+        let p_x = [p[0].clone(), p[1].clone()];
+        let q_x = [q[0].clone(), q[1].clone()];
+        let x3_terms = g2_x3_terms(&p_x, &q_x, &slope, &one);
+        let x3: [BigUint; 2] = core::array::from_fn(|i| {
+            let x3 = cols.x3[i].populate(blu_events, &borrow_terms(&x3_terms[i]));
+            cols.x3_range[i].populate(blu_events, &x3, &modulus);
+            x3
+        });
+
+        let y3_terms = g2_y3_terms(&p, &x3, &slope, &one);
+        for i in 0..2 {
+            let y3 = cols.y3[i].populate(blu_events, &borrow_terms(&y3_terms[i]));
+            cols.y3_range[i].populate(blu_events, &y3, &modulus);
+        }
+    }
+}
+
+impl<F: PrimeField32, P: FpTowerField> MachineAir<F> for G2AddAssignChip<P> {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => "Bn254G2AddAssign".to_string(),
+            FieldType::Bls12381 => "Bls12381G2AddAssign".to_string(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        G2AddAssignCols::<F, P>::column_names()
+    }
+
+    fn generate_trace(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(Self::syscall_code());
+        let num_words_field_element = <P as NumWords>::WordsFieldElement::USIZE;
+
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in events {
+            let event = match (P::FIELD_TYPE, event) {
+                (FieldType::Bn254, PrecompileEvent::Bn254G2Add(event)) => event,
+                (FieldType::Bls12381, PrecompileEvent::Bls12381G2Add(event)) => event,
+                _ => unreachable!(),
+            };
+
+            let mut row = zeroed_f_vec(num_g2_add_cols::<P>());
+            let cols: &mut G2AddAssignCols<F, P> = row.as_mut_slice().borrow_mut();
+
+            let to_point = |words: &[u32]| -> [BigUint; 4] {
+                let mut coeffs = words
+                    .chunks(num_words_field_element)
+                    .map(|words| BigUint::from_bytes_le(&words_to_bytes_le_vec(words)));
+                core::array::from_fn(|_| coeffs.next().unwrap())
+            };
+            let p = to_point(&event.p);
+            let q = to_point(&event.q);
+            let slope = g2_add_slope::<P>(&p, &q);
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+            cols.q_ptr = F::from_canonical_u32(event.q_ptr);
+
+            Self::populate_field_ops(&mut new_byte_lookup_events, cols, p, q, slope);
+
+            // Populate the memory access columns.
+            for (i, access) in cols.q_access.iter_mut().flatten().enumerate() {
+                access.populate(event.q_memory_records[i], &mut new_byte_lookup_events);
+            }
+            for (i, access) in cols.p_access.iter_mut().flatten().enumerate() {
+                access.populate(event.p_memory_records[i], &mut new_byte_lookup_events);
+            }
+            rows.push(row);
+        }
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = zeroed_f_vec(num_g2_add_cols::<P>());
+                let cols: &mut G2AddAssignCols<F, P> = row.as_mut_slice().borrow_mut();
+                let zero = BigUint::zero();
+                Self::populate_field_ops(
+                    &mut vec![],
+                    cols,
+                    core::array::from_fn(|_| zero.clone()),
+                    core::array::from_fn(|_| zero.clone()),
+                    core::array::from_fn(|_| zero.clone()),
+                );
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), num_g2_add_cols::<P>())
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(Self::syscall_code()).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F, P: FpTowerField> BaseAir<F> for G2AddAssignChip<P> {
+    fn width(&self) -> usize {
+        num_g2_add_cols::<P>()
+    }
+}
+
+impl<AB, P: FpTowerField> Air<AB> for G2AddAssignChip<P>
+where
+    AB: MONEROCHANAirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &G2AddAssignCols<AB::Var, P> = (*local).borrow();
+
+        let num_words_field_element = <P as NumLimbs>::Limbs::USIZE / 4;
+        let coefficient_range = |k: usize| {
+            (k % 2) * num_words_field_element..(k % 2 + 1) * num_words_field_element
+        };
+
+        let p: [Polynomial<AB::Expr>; 4] = core::array::from_fn(|k| {
+            let words = &local.p_access[k / 2][coefficient_range(k)];
+            limbs_from_prev_access::<_, <P as NumLimbs>::Limbs, _>(words).into()
+        });
+        let q: [Polynomial<AB::Expr>; 4] = core::array::from_fn(|k| {
+            let words = &local.q_access[k / 2][coefficient_range(k)];
+            limbs_from_prev_access::<_, <P as NumLimbs>::Limbs, _>(words).into()
+        });
+        let slope: [Polynomial<AB::Expr>; 2] = core::array::from_fn(|i| local.slope[i].into());
+        let one = Polynomial::from_coefficients(&[AB::Expr::one()]);
+
+        let modulus_coeffs =
+            P::MODULUS.iter().map(|&limbs| AB::Expr::from_canonical_u8(limbs)).collect_vec();
+        let p_modulus = Polynomial::from_coefficients(&modulus_coeffs);
+
+        // Verify that the slope is the slope of the line through `p` and `q`.
+        for limbs in local.slope.iter() {
+            builder.slice_range_check_u8(&limbs.0, local.is_real);
+        }
+        let slope_terms = g2_add_slope_terms(&p, &q, &slope, &one);
+        for (check, terms) in local.slope_check.iter().zip(slope_terms.iter()) {
+            check.eval(builder, terms, local.is_real);
+            for limb in check.result.0.iter() {
+                builder.when(local.is_real).assert_zero(*limb);
+            }
+        }
+
+        // Compute the coordinates of the sum.
+        let p_x = [p[0].clone(), p[1].clone()];
+        let q_x = [q[0].clone(), q[1].clone()];
+        let x3_terms = g2_x3_terms(&p_x, &q_x, &slope, &one);
+        for i in 0..2 {
+            local.x3[i].eval(builder, &x3_terms[i], local.is_real);
+            local.x3_range[i].eval(builder, &local.x3[i].result, &p_modulus, local.is_real);
+        }
+        let x3: [Polynomial<AB::Expr>; 2] = core::array::from_fn(|i| local.x3[i].result.into());
+        let y3_terms = g2_y3_terms(&p, &x3, &slope, &one);
+        for i in 0..2 {
+            local.y3[i].eval(builder, &y3_terms[i], local.is_real);
+            local.y3_range[i].eval(builder, &local.y3[i].result, &p_modulus, local.is_real);
+        }
+
+        // Constrain the result written to `p`.
+        for (k, result) in
+            [local.x3[0].result, local.x3[1].result, local.y3[0].result, local.y3[1].result]
+                .into_iter()
+                .enumerate()
+        {
+            let words = &local.p_access[k / 2][coefficient_range(k)];
+            builder.when(local.is_real).assert_all_eq(result, value_as_limbs(words));
+        }
+
+        let num_bytes_fp2 = 8 * num_words_field_element;
+        for i in 0..2 {
+            builder.eval_memory_access_slice(
+                local.shard,
+                local.clk.into(),
+                local.q_ptr + AB::F::from_canonical_usize(i * num_bytes_fp2),
+                &local.q_access[i],
+                local.is_real,
+            );
+        }
+        for i in 0..2 {
+            builder.eval_memory_access_slice(
+                local.shard,
+                local.clk + AB::F::from_canonical_u32(1), /* We read p at +1 since p, q could be
+                                                           * the same. */
+                local.p_ptr + AB::F::from_canonical_usize(i * num_bytes_fp2),
+                &local.p_access[i],
+                local.is_real,
+            );
+        }
+
+        let syscall_id_felt = AB::F::from_canonical_u32(Self::syscall_code().syscall_id());
+
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            syscall_id_felt,
+            local.p_ptr,
+            local.q_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+    }
+}
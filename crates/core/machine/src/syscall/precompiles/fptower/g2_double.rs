@@ -0,0 +1,317 @@
+use std::{
+    borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
+};
+
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    utils::zeroed_f_vec,
+};
+use generic_array::GenericArray;
+use itertools::Itertools;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use monerochan_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use monerochan_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::{tower::g2_double_slope, FieldType, FpTowerField},
+};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::air::{
+    BaseAirBuilder, InteractionScope, MachineAir, Polynomial, MONEROCHANAirBuilder,
+};
+use std::mem::size_of;
+use typenum::Unsigned;
+
+use super::g2_add::{borrow_terms, g2_x3_terms, g2_y3_terms};
+use crate::{
+    memory::{value_as_limbs, MemoryWriteCols},
+    operations::field::{field_sum_of_products::FieldSumOfProductsCols, range::FieldLtCols},
+    utils::{limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le_vec},
+};
+
+pub const fn num_g2_double_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<G2DoubleAssignCols<u8, P>>()
+}
+
+/// The terms of `slope * 2 p.y - 3 p.x^2`, whose coefficients are zero in `Fp2` exactly when
+/// `slope` is the slope of the tangent at `p`.
+///
+/// Points are given by the `Fp` coefficients `[x.re, x.im, y.re, y.im]`.
+fn g2_double_slope_terms<T: Clone>(p: &[T; 4], slope: &[T; 2]) -> [Vec<(i32, T, T)>; 2] {
+    let [x_re, x_im, y_re, y_im] = p.clone();
+    let [s_re, s_im] = slope.clone();
+    [
+        vec![
+            (2, s_re.clone(), y_re.clone()),
+            (-2, s_im.clone(), y_im.clone()),
+            (-3, x_re.clone(), x_re.clone()),
+            (3, x_im.clone(), x_im.clone()),
+        ],
+        vec![(2, s_re, y_im), (2, s_im, y_re), (-6, x_re, x_im)],
+    ]
+}
+
+/// A set of columns for the doubling of a point of the G2 subgroup.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct G2DoubleAssignCols<T, P: FieldParameters + NumWords> {
+    pub is_real: T,
+    pub shard: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub p_access: [GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>; 2],
+    pub(crate) slope: [Limbs<T, P::Limbs>; 2],
+    pub(crate) slope_check: [FieldSumOfProductsCols<T, P>; 2],
+    pub(crate) x3: [FieldSumOfProductsCols<T, P>; 2],
+    pub(crate) y3: [FieldSumOfProductsCols<T, P>; 2],
+    pub(crate) x3_range: [FieldLtCols<T, P>; 2],
+    pub(crate) y3_range: [FieldLtCols<T, P>; 2],
+}
+
+#[derive(Default)]
+pub struct G2DoubleAssignChip<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P: FpTowerField> G2DoubleAssignChip<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn syscall_code() -> SyscallCode {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => SyscallCode::BN254_G2_DOUBLE,
+            FieldType::Bls12381 => SyscallCode::BLS12381_G2_DOUBLE,
+            _ => unreachable!(),
+        }
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        cols: &mut G2DoubleAssignCols<F, P>,
+        p: [BigUint; 4],
+        slope: [BigUint; 2],
+    ) {
+        let modulus = P::modulus();
+        let one = BigUint::one();
+
+        for (limbs, value) in cols.slope.iter_mut().zip(slope.iter()) {
+            *limbs = P::to_limbs_field::<F, F>(value);
+            blu_events.add_u8_range_checks_field(&limbs.0);
+        }
+
+        let slope_terms = g2_double_slope_terms(&p, &slope);
+        for (check, terms) in cols.slope_check.iter_mut().zip(slope_terms.iter()) {
+            let result = check.populate(blu_events, &borrow_terms(terms));
+            debug_assert!(result.is_zero());
+        }
+
+        let p_x = [p[0].clone(), p[1].clone()];
+        let x3_terms = g2_x3_terms(&p_x, &p_x, &slope, &one);
+        let x3: [BigUint; 2] = core::array::from_fn(|i| {
+            let x3 = cols.x3[i].populate(blu_events, &borrow_terms(&x3_terms[i]));
+            cols.x3_range[i].populate(blu_events, &x3, &modulus);
+            x3
+        });
+
+        let y3_terms = g2_y3_terms(&p, &x3, &slope, &one);
+        for i in 0..2 {
+            let y3 = cols.y3[i].populate(blu_events, &borrow_terms(&y3_terms[i]));
+            cols.y3_range[i].populate(blu_events, &y3, &modulus);
+        }
+    }
+}
+
+impl<F: PrimeField32, P: FpTowerField> MachineAir<F> for G2DoubleAssignChip<P> {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match P::FIELD_TYPE {
+            FieldType::Bn254 => "Bn254G2DoubleAssign".to_string(),
+            FieldType::Bls12381 => "Bls12381G2DoubleAssign".to_string(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        G2DoubleAssignCols::<F, P>::column_names()
+    }
+
+    fn generate_trace(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(Self::syscall_code());
+        let num_words_field_element = <P as NumWords>::WordsFieldElement::USIZE;
+
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in events {
+            let event = match (P::FIELD_TYPE, event) {
+                (FieldType::Bn254, PrecompileEvent::Bn254G2Double(event)) => event,
+                (FieldType::Bls12381, PrecompileEvent::Bls12381G2Double(event)) => event,
+                _ => unreachable!(),
+            };
+
+            let mut row = zeroed_f_vec(num_g2_double_cols::<P>());
+            let cols: &mut G2DoubleAssignCols<F, P> = row.as_mut_slice().borrow_mut();
+
+            let mut coeffs = event
+                .p
+                .chunks(num_words_field_element)
+                .map(|words| BigUint::from_bytes_le(&words_to_bytes_le_vec(words)));
+            let p: [BigUint; 4] = core::array::from_fn(|_| coeffs.next().unwrap());
+            let slope = g2_double_slope::<P>(&p);
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+
+            Self::populate_field_ops(&mut new_byte_lookup_events, cols, p, slope);
+
+            // Populate the memory access columns.
+            for (i, access) in cols.p_access.iter_mut().flatten().enumerate() {
+                access.populate(event.p_memory_records[i], &mut new_byte_lookup_events);
+            }
+            rows.push(row);
+        }
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = zeroed_f_vec(num_g2_double_cols::<P>());
+                let cols: &mut G2DoubleAssignCols<F, P> = row.as_mut_slice().borrow_mut();
+                let zero = BigUint::zero();
+                Self::populate_field_ops(
+                    &mut vec![],
+                    cols,
+                    core::array::from_fn(|_| zero.clone()),
+                    core::array::from_fn(|_| zero.clone()),
+                );
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_g2_double_cols::<P>(),
+        )
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(Self::syscall_code()).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F, P: FpTowerField> BaseAir<F> for G2DoubleAssignChip<P> {
+    fn width(&self) -> usize {
+        num_g2_double_cols::<P>()
+    }
+}
+
+impl<AB, P: FpTowerField> Air<AB> for G2DoubleAssignChip<P>
+where
+    AB: MONEROCHANAirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &G2DoubleAssignCols<AB::Var, P> = (*local).borrow();
+
+        let num_words_field_element = <P as NumLimbs>::Limbs::USIZE / 4;
+        let coefficient_range = |k: usize| {
+            (k % 2) * num_words_field_element..(k % 2 + 1) * num_words_field_element
+        };
+
+        let p: [Polynomial<AB::Expr>; 4] = core::array::from_fn(|k| {
+            let words = &local.p_access[k / 2][coefficient_range(k)];
+            limbs_from_prev_access::<_, <P as NumLimbs>::Limbs, _>(words).into()
+        });
+        let slope: [Polynomial<AB::Expr>; 2] = core::array::from_fn(|i| local.slope[i].into());
+        let one = Polynomial::from_coefficients(&[AB::Expr::one()]);
+
+        let modulus_coeffs =
+            P::MODULUS.iter().map(|&limbs| AB::Expr::from_canonical_u8(limbs)).collect_vec();
+        let p_modulus = Polynomial::from_coefficients(&modulus_coeffs);
+
+        // Verify that the slope is the slope of the tangent at `p`.
+        for limbs in local.slope.iter() {
+            builder.slice_range_check_u8(&limbs.0, local.is_real);
+        }
+        let slope_terms = g2_double_slope_terms(&p, &slope);
+        for (check, terms) in local.slope_check.iter().zip(slope_terms.iter()) {
+            check.eval(builder, terms, local.is_real);
+            for limb in check.result.0.iter() {
+                builder.when(local.is_real).assert_zero(*limb);
+            }
+        }
+
+        // Compute the coordinates of the double.
+        let p_x = [p[0].clone(), p[1].clone()];
+        let x3_terms = g2_x3_terms(&p_x, &p_x, &slope, &one);
+        for i in 0..2 {
+            local.x3[i].eval(builder, &x3_terms[i], local.is_real);
+            local.x3_range[i].eval(builder, &local.x3[i].result, &p_modulus, local.is_real);
+        }
+        let x3: [Polynomial<AB::Expr>; 2] = core::array::from_fn(|i| local.x3[i].result.into());
+        let y3_terms = g2_y3_terms(&p, &x3, &slope, &one);
+        for i in 0..2 {
+            local.y3[i].eval(builder, &y3_terms[i], local.is_real);
+            local.y3_range[i].eval(builder, &local.y3[i].result, &p_modulus, local.is_real);
+        }
+
+        // Constrain the result written to `p`.
+        for (k, result) in
+            [local.x3[0].result, local.x3[1].result, local.y3[0].result, local.y3[1].result]
+                .into_iter()
+                .enumerate()
+        {
+            let words = &local.p_access[k / 2][coefficient_range(k)];
+            builder.when(local.is_real).assert_all_eq(result, value_as_limbs(words));
+        }
+
+        let num_bytes_fp2 = 8 * num_words_field_element;
+        for i in 0..2 {
+            builder.eval_memory_access_slice(
+                local.shard,
+                local.clk.into(),
+                local.p_ptr + AB::F::from_canonical_usize(i * num_bytes_fp2),
+                &local.p_access[i],
+                local.is_real,
+            );
+        }
+
+        let syscall_id_felt = AB::F::from_canonical_u32(Self::syscall_code().syscall_id());
+
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            syscall_id_felt,
+            local.p_ptr,
+            AB::Expr::zero(),
+            local.is_real,
+            InteractionScope::Local,
+        );
+    }
+}
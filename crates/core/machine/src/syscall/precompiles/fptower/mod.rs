@@ -1,10 +1,16 @@
 mod fp;
 mod fp2_addsub;
 mod fp2_mul;
+mod g2_add;
+mod g2_double;
+mod tower_mul;
 
 pub use fp::*;
 pub use fp2_addsub::*;
 pub use fp2_mul::*;
+pub use g2_add::*;
+pub use g2_double::*;
+pub use tower_mul::*;
 
 #[cfg(test)]
 mod tests {
@@ -12,8 +18,9 @@ mod tests {
 
     use monerochan_core_executor::Program;
     use test_artifacts::{
-        BLS12381_FP2_ADDSUB_ELF, BLS12381_FP2_MUL_ELF, BLS12381_FP_ELF, BN254_FP2_ADDSUB_ELF,
-        BN254_FP2_MUL_ELF, BN254_FP_ELF,
+        BLS12381_FP2_ADDSUB_ELF, BLS12381_FP2_MUL_ELF, BLS12381_FP_ELF, BLS12381_G2_ELF,
+        BLS12381_TOWER_MUL_ELF, BN254_FP2_ADDSUB_ELF, BN254_FP2_MUL_ELF, BN254_FP_ELF,
+        BN254_G2_ELF, BN254_TOWER_MUL_ELF, HASH_TO_CURVE_ELF, SECP256K1_FP_ELF, SECP256R1_FP_ELF,
     };
 
     use crate::{io::MONEROCHANStdin, utils};
@@ -42,6 +49,30 @@ mod tests {
         utils::run_test::<CpuProver<_, _>>(program, stdin).unwrap();
     }
 
+    #[test]
+    fn test_bls12381_tower_mul() {
+        utils::setup_logger();
+        let program = Program::from(BLS12381_TOWER_MUL_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        utils::run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+
+    #[test]
+    fn test_bls12381_g2() {
+        utils::setup_logger();
+        let program = Program::from(BLS12381_G2_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        utils::run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        utils::setup_logger();
+        let program = Program::from(HASH_TO_CURVE_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        utils::run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+
     #[test]
     fn test_bn254_fp_ops() {
         utils::setup_logger();
@@ -65,4 +96,36 @@ mod tests {
         let stdin = MONEROCHANStdin::new();
         utils::run_test::<CpuProver<_, _>>(program, stdin).unwrap();
     }
+
+    #[test]
+    fn test_bn254_tower_mul() {
+        utils::setup_logger();
+        let program = Program::from(BN254_TOWER_MUL_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        utils::run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+
+    #[test]
+    fn test_bn254_g2() {
+        utils::setup_logger();
+        let program = Program::from(BN254_G2_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        utils::run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+
+    #[test]
+    fn test_secp256k1_fp_ops() {
+        utils::setup_logger();
+        let program = Program::from(SECP256K1_FP_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        utils::run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+
+    #[test]
+    fn test_secp256r1_fp_ops() {
+        utils::setup_logger();
+        let program = Program::from(SECP256R1_FP_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        utils::run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
 }
@@ -0,0 +1,289 @@
+use std::{
+    borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
+};
+
+use crate::{air::MemoryAirBuilder, utils::zeroed_f_vec};
+use generic_array::GenericArray;
+use itertools::Itertools;
+use num::{BigUint, Zero};
+use p3_air::{Air, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use monerochan_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use monerochan_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::{
+        tower::{fp_tower_mul_terms, FP12_DEGREE, FP6_DEGREE},
+        FieldType, FpTowerField,
+    },
+};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::air::{
+    BaseAirBuilder, InteractionScope, MachineAir, Polynomial, MONEROCHANAirBuilder,
+};
+use std::mem::size_of;
+use typenum::Unsigned;
+
+use crate::{
+    memory::{value_as_limbs, MemoryReadCols, MemoryWriteCols},
+    operations::field::{field_sum_of_products::FieldSumOfProductsCols, range::FieldLtCols},
+    utils::{limbs_from_prev_access, pad_rows_fixed, words_to_bytes_le_vec},
+};
+
+pub const fn num_fp_tower_mul_cols<P: FieldParameters + NumWords, const D: usize>() -> usize {
+    size_of::<FpTowerMulAssignCols<u8, P, D>>()
+}
+
+/// A set of columns for the multiplication in an extension of degree `D` over `Fp2`.
+///
+/// Each of the `2 * D` coefficients of the result is a signed linear combination of the products
+/// of the coefficients of the inputs, which is computed with a single `FieldSumOfProductsCols`.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct FpTowerMulAssignCols<T, P: FieldParameters + NumWords, const D: usize> {
+    pub is_real: T,
+    pub shard: T,
+    pub clk: T,
+    pub x_ptr: T,
+    pub y_ptr: T,
+    pub x_access: [GenericArray<MemoryWriteCols<T>, P::WordsCurvePoint>; D],
+    pub y_access: [GenericArray<MemoryReadCols<T>, P::WordsCurvePoint>; D],
+    pub(crate) c: [[FieldSumOfProductsCols<T, P>; 2]; D],
+    pub(crate) c_range: [[FieldLtCols<T, P>; 2]; D],
+}
+
+/// A chip for the multiplication in an extension of degree `D` over `Fp2`.
+#[derive(Default)]
+pub struct FpTowerMulAssignChip<P, const D: usize> {
+    _marker: PhantomData<P>,
+}
+
+/// A chip for the multiplication in `Fp6 = Fp2[v] / (v^3 - xi)`.
+pub type Fp6MulAssignChip<P> = FpTowerMulAssignChip<P, FP6_DEGREE>;
+
+/// A chip for the multiplication in `Fp12 = Fp6[w] / (w^2 - v)`.
+pub type Fp12MulAssignChip<P> = FpTowerMulAssignChip<P, FP12_DEGREE>;
+
+impl<P: FpTowerField, const D: usize> FpTowerMulAssignChip<P, D> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn syscall_code() -> SyscallCode {
+        match (P::FIELD_TYPE, D) {
+            (FieldType::Bn254, FP6_DEGREE) => SyscallCode::BN254_FP6_MUL,
+            (FieldType::Bn254, FP12_DEGREE) => SyscallCode::BN254_FP12_MUL,
+            (FieldType::Bls12381, FP6_DEGREE) => SyscallCode::BLS12381_FP6_MUL,
+            (FieldType::Bls12381, FP12_DEGREE) => SyscallCode::BLS12381_FP12_MUL,
+            _ => panic!("unsupported extension degree"),
+        }
+    }
+
+    fn populate_field_ops<F: PrimeField32>(
+        blu_events: &mut Vec<ByteLookupEvent>,
+        cols: &mut FpTowerMulAssignCols<F, P, D>,
+        x: &[BigUint],
+        y: &[BigUint],
+    ) {
+        let modulus = P::modulus();
+        for (k, terms) in fp_tower_mul_terms(D, P::TOWER_NONRESIDUE).iter().enumerate() {
+            let terms = terms.iter().map(|&(c, i, j)| (c, &x[i], &y[j])).collect_vec();
+            let result = cols.c[k / 2][k % 2].populate(blu_events, &terms);
+            cols.c_range[k / 2][k % 2].populate(blu_events, &result, &modulus);
+        }
+    }
+}
+
+impl<F: PrimeField32, P: FpTowerField, const D: usize> MachineAir<F> for FpTowerMulAssignChip<P, D> {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match (P::FIELD_TYPE, D) {
+            (FieldType::Bn254, FP6_DEGREE) => "Bn254Fp6MulAssign".to_string(),
+            (FieldType::Bn254, FP12_DEGREE) => "Bn254Fp12MulAssign".to_string(),
+            (FieldType::Bls12381, FP6_DEGREE) => "Bls12381Fp6MulAssign".to_string(),
+            (FieldType::Bls12381, FP12_DEGREE) => "Bls12381Fp12MulAssign".to_string(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        FpTowerMulAssignCols::<F, P, D>::column_names()
+    }
+
+    fn generate_trace(&self, input: &Self::Record, output: &mut Self::Record) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(Self::syscall_code());
+        let num_words_field_element = <P as NumWords>::WordsFieldElement::USIZE;
+
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in events {
+            let event = match (P::FIELD_TYPE, D, event) {
+                (FieldType::Bn254, FP6_DEGREE, PrecompileEvent::Bn254Fp6Mul(event)) => event,
+                (FieldType::Bn254, FP12_DEGREE, PrecompileEvent::Bn254Fp12Mul(event)) => event,
+                (FieldType::Bls12381, FP6_DEGREE, PrecompileEvent::Bls12381Fp6Mul(event)) => event,
+                (FieldType::Bls12381, FP12_DEGREE, PrecompileEvent::Bls12381Fp12Mul(event)) => {
+                    event
+                }
+                _ => unreachable!(),
+            };
+
+            let mut row = zeroed_f_vec(num_fp_tower_mul_cols::<P, D>());
+            let cols: &mut FpTowerMulAssignCols<F, P, D> = row.as_mut_slice().borrow_mut();
+
+            let x = event
+                .x
+                .chunks(num_words_field_element)
+                .map(|words| BigUint::from_bytes_le(&words_to_bytes_le_vec(words)))
+                .collect_vec();
+            let y = event
+                .y
+                .chunks(num_words_field_element)
+                .map(|words| BigUint::from_bytes_le(&words_to_bytes_le_vec(words)))
+                .collect_vec();
+
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+            cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+            Self::populate_field_ops(&mut new_byte_lookup_events, cols, &x, &y);
+
+            // Populate the memory access columns.
+            for (i, access) in cols.y_access.iter_mut().flatten().enumerate() {
+                access.populate(event.y_memory_records[i], &mut new_byte_lookup_events);
+            }
+            for (i, access) in cols.x_access.iter_mut().flatten().enumerate() {
+                access.populate(event.x_memory_records[i], &mut new_byte_lookup_events);
+            }
+            rows.push(row);
+        }
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(
+            &mut rows,
+            || {
+                let mut row = zeroed_f_vec(num_fp_tower_mul_cols::<P, D>());
+                let cols: &mut FpTowerMulAssignCols<F, P, D> = row.as_mut_slice().borrow_mut();
+                let zero = vec![BigUint::zero(); 2 * D];
+                Self::populate_field_ops(&mut vec![], cols, &zero, &zero);
+                row
+            },
+            input.fixed_log2_rows::<F, _>(self),
+        );
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(
+            rows.into_iter().flatten().collect::<Vec<_>>(),
+            num_fp_tower_mul_cols::<P, D>(),
+        )
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(Self::syscall_code()).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F, P: FpTowerField, const D: usize> BaseAir<F> for FpTowerMulAssignChip<P, D> {
+    fn width(&self) -> usize {
+        num_fp_tower_mul_cols::<P, D>()
+    }
+}
+
+impl<AB, P: FpTowerField, const D: usize> Air<AB> for FpTowerMulAssignChip<P, D>
+where
+    AB: MONEROCHANAirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &FpTowerMulAssignCols<AB::Var, P, D> = (*local).borrow();
+
+        let num_words_field_element = <P as NumLimbs>::Limbs::USIZE / 4;
+
+        // The `Fp` coefficient `k` of an element is the half `k % 2` of its `Fp2` coefficient
+        // `k / 2`.
+        let coefficient_range = |k: usize| {
+            (k % 2) * num_words_field_element..(k % 2 + 1) * num_words_field_element
+        };
+        let x: Vec<Polynomial<AB::Expr>> = (0..2 * D)
+            .map(|k| {
+                let words = &local.x_access[k / 2][coefficient_range(k)];
+                limbs_from_prev_access::<_, <P as NumLimbs>::Limbs, _>(words).into()
+            })
+            .collect();
+        let y: Vec<Polynomial<AB::Expr>> = (0..2 * D)
+            .map(|k| {
+                let words = &local.y_access[k / 2][coefficient_range(k)];
+                limbs_from_prev_access::<_, <P as NumLimbs>::Limbs, _>(words).into()
+            })
+            .collect();
+
+        let modulus_coeffs =
+            P::MODULUS.iter().map(|&limbs| AB::Expr::from_canonical_u8(limbs)).collect_vec();
+        let p_modulus = Polynomial::from_coefficients(&modulus_coeffs);
+
+        for (k, terms) in fp_tower_mul_terms(D, P::TOWER_NONRESIDUE).iter().enumerate() {
+            let terms =
+                terms.iter().map(|&(c, i, j)| (c, x[i].clone(), y[j].clone())).collect_vec();
+            let c = &local.c[k / 2][k % 2];
+            c.eval(builder, &terms, local.is_real);
+            local.c_range[k / 2][k % 2].eval(builder, &c.result, &p_modulus, local.is_real);
+
+            let words = &local.x_access[k / 2][coefficient_range(k)];
+            builder.when(local.is_real).assert_all_eq(c.result, value_as_limbs(words));
+        }
+
+        let num_bytes_fp2 = 8 * num_words_field_element;
+        for i in 0..D {
+            builder.eval_memory_access_slice(
+                local.shard,
+                local.clk.into(),
+                local.y_ptr + AB::F::from_canonical_usize(i * num_bytes_fp2),
+                &local.y_access[i],
+                local.is_real,
+            );
+        }
+        for i in 0..D {
+            builder.eval_memory_access_slice(
+                local.shard,
+                local.clk + AB::F::from_canonical_u32(1), /* We read x at +1 since x, y could be
+                                                           * the same. */
+                local.x_ptr + AB::F::from_canonical_usize(i * num_bytes_fp2),
+                &local.x_access[i],
+                local.is_real,
+            );
+        }
+
+        let syscall_id_felt = AB::F::from_canonical_u32(Self::syscall_code().syscall_id());
+
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            syscall_id_felt,
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+    }
+}
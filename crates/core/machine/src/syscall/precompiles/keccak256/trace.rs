@@ -27,6 +27,10 @@ impl<F: PrimeField32> MachineAir<F> for KeccakPermuteChip {
         "KeccakPermute".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        KeccakMemCols::<F>::column_names()
+    }
+
     fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
         let chunk_size = 8;
 
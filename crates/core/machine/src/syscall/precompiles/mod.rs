@@ -1,7 +1,10 @@
+pub mod aes;
+pub mod chacha20;
 pub mod edwards;
 pub mod fptower;
 pub mod keccak256;
 pub mod sha256;
 pub mod u256x2048_mul;
 pub mod uint256;
+pub mod user;
 pub mod weierstrass;
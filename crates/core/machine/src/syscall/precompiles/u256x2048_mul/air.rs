@@ -93,6 +93,10 @@ impl<F: PrimeField32> MachineAir<F> for U256x2048MulChip {
         "U256XU2048Mul".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        U256x2048MulCols::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
@@ -0,0 +1,268 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{value_as_limbs, MemoryCols, MemoryReadCols, MemoryWriteCols},
+    utils::{pad_rows_fixed, words_to_bytes_le_vec, zeroed_f_vec},
+};
+
+use generic_array::GenericArray;
+use p3_air::{Air, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use monerochan_core_executor::{
+    events::{ByteRecord, FieldOperation, PrecompileEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use monerochan_curves::{params::NumWords, uint256::U256Field};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::air::{InteractionScope, MachineAir, MONEROCHANAirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The number of columns in the Uint256AddSubCols.
+const NUM_COLS: usize = size_of::<Uint256AddSubCols<u8>>();
+
+type WordsFieldElement = <U256Field as NumWords>::WordsFieldElement;
+const WORDS_FIELD_ELEMENT: usize = WordsFieldElement::USIZE;
+const NUM_BYTES: usize = WORDS_FIELD_ELEMENT * 4;
+
+/// A chip for the uint256 addition with carry and subtraction with borrow.
+#[derive(Default)]
+pub struct Uint256AddSubChip;
+
+impl Uint256AddSubChip {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// A set of columns for the Uint256AddSub operation.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct Uint256AddSubCols<T> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The pointer to the first input, which is followed by the carry word.
+    pub x_ptr: T,
+
+    /// The pointer to the second input.
+    pub y_ptr: T,
+
+    // Memory columns.
+    // x_memory and carry_memory are written to with the result and the outgoing carry.
+    pub x_memory: GenericArray<MemoryWriteCols<T>, WordsFieldElement>,
+    pub carry_memory: MemoryWriteCols<T>,
+    pub y_memory: GenericArray<MemoryReadCols<T>, WordsFieldElement>,
+
+    /// The carry (or borrow) out of each byte of the result. The last one is the outgoing carry.
+    pub carries: [T; NUM_BYTES],
+
+    pub is_add: T,
+    pub is_sub: T,
+    pub is_real: T,
+}
+
+impl<F: PrimeField32> MachineAir<F> for Uint256AddSubChip {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        "Uint256AddSub".to_string()
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        Uint256AddSubCols::<F>::column_names()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        // The add and sub events are both stored under the add syscall code.
+        let events = input.get_precompile_events(SyscallCode::UINT256_ADD_CARRY);
+
+        let mut rows = Vec::new();
+        let mut new_byte_lookup_events = Vec::new();
+
+        for (_, event) in events {
+            let event = if let PrecompileEvent::Uint256AddSub(event) = event {
+                event
+            } else {
+                unreachable!()
+            };
+
+            let mut row = zeroed_f_vec(NUM_COLS);
+            let cols: &mut Uint256AddSubCols<F> = row.as_mut_slice().borrow_mut();
+
+            cols.is_add = F::from_bool(event.op == FieldOperation::Add);
+            cols.is_sub = F::from_bool(event.op == FieldOperation::Sub);
+            cols.is_real = F::one();
+            cols.shard = F::from_canonical_u32(event.shard);
+            cols.clk = F::from_canonical_u32(event.clk);
+            cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+            cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+            // Populate memory columns.
+            for i in 0..WORDS_FIELD_ELEMENT {
+                cols.x_memory[i].populate(event.x_memory_records[i], &mut new_byte_lookup_events);
+                cols.y_memory[i].populate(event.y_memory_records[i], &mut new_byte_lookup_events);
+            }
+            cols.carry_memory.populate(event.carry_memory_record, &mut new_byte_lookup_events);
+
+            // Propagate the carry through the bytes of the inputs.
+            let x_bytes = words_to_bytes_le_vec(&event.x);
+            let y_bytes = words_to_bytes_le_vec(&event.y);
+            let mut carry = event.carry as i32;
+            for (i, (x_byte, y_byte)) in x_bytes.iter().zip(y_bytes.iter()).enumerate() {
+                let (x_byte, y_byte) = (*x_byte as i32, *y_byte as i32);
+                carry = match event.op {
+                    FieldOperation::Add => (x_byte + y_byte + carry >= 256) as i32,
+                    FieldOperation::Sub => (x_byte - y_byte - carry < 0) as i32,
+                    _ => unreachable!(),
+                };
+                cols.carries[i] = F::from_canonical_u32(carry as u32);
+            }
+
+            let result_bytes = words_to_bytes_le_vec(
+                &event.x_memory_records.iter().map(|record| record.value).collect::<Vec<_>>(),
+            );
+            new_byte_lookup_events.add_u8_range_checks(&result_bytes);
+
+            rows.push(row);
+        }
+
+        output.add_byte_lookup_events(new_byte_lookup_events);
+
+        pad_rows_fixed(&mut rows, || zeroed_f_vec(NUM_COLS), input.fixed_log2_rows::<F, _>(self));
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(rows.into_iter().flatten().collect::<Vec<_>>(), NUM_COLS)
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        // The sub events are coalesced to the add operation, so only check for that one.
+        assert!(shard.get_precompile_events(SyscallCode::UINT256_SUB_BORROW).is_empty());
+
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(SyscallCode::UINT256_ADD_CARRY).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F> BaseAir<F> for Uint256AddSubChip {
+    fn width(&self) -> usize {
+        NUM_COLS
+    }
+}
+
+impl<AB> Air<AB> for Uint256AddSubChip
+where
+    AB: MONEROCHANAirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &Uint256AddSubCols<AB::Var> = (*local).borrow();
+
+        // Check that the operation flags are boolean and that exactly one is set on real rows.
+        builder.assert_bool(local.is_add);
+        builder.assert_bool(local.is_sub);
+        builder.assert_bool(local.is_real);
+        builder.assert_eq(local.is_add + local.is_sub, local.is_real);
+
+        // The incoming carry is the first byte of the previous carry word and has to be boolean.
+        let carry_in = local.carry_memory.prev_value()[0];
+        builder.when(local.is_real).assert_bool(carry_in);
+        for i in 1..4 {
+            builder.when(local.is_real).assert_zero(local.carry_memory.prev_value()[i]);
+        }
+
+        // Constrain each byte of the result, where for addition
+        //     x_i + y_i + c_{i-1} = r_i + 256 * c_i
+        // and for subtraction
+        //     x_i - y_i - c_{i-1} = r_i - 256 * c_i.
+        // As all values are bytes and the carries are boolean, these equations hold over the
+        // integers.
+        let x_limbs = local.x_memory.iter().flat_map(|access| access.prev_value().0);
+        let y_limbs = local.y_memory.iter().flat_map(|access| access.value().0);
+        let result_limbs = value_as_limbs(&local.x_memory);
+        let base = AB::Expr::from_canonical_u32(256);
+        let mut carry: AB::Expr = carry_in.into();
+        for (((x, y), result), &carry_out) in
+            x_limbs.zip(y_limbs).zip(result_limbs.iter()).zip(local.carries.iter())
+        {
+            builder.assert_bool(carry_out);
+            builder.assert_zero(
+                local.is_add *
+                    (x.into() + y.into() + carry.clone() -
+                        (*result).into() -
+                        base.clone() * carry_out) +
+                    local.is_sub *
+                        (x.into() - y.into() - carry.clone() - (*result).into() +
+                            base.clone() * carry_out),
+            );
+            carry = carry_out.into();
+        }
+        builder.slice_range_check_u8(&result_limbs, local.is_real);
+
+        // The outgoing carry is written to the carry word.
+        builder.when(local.is_real).assert_eq(local.carry_memory.value()[0], carry);
+        for i in 1..4 {
+            builder.when(local.is_real).assert_zero(local.carry_memory.value()[i]);
+        }
+
+        // Read y.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.y_ptr,
+            &local.y_memory,
+            local.is_real,
+        );
+
+        // Read and write x and the carry word, which follows x in memory.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+        builder.eval_memory_access(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr.into() + AB::Expr::from_canonical_usize(NUM_BYTES),
+            &local.carry_memory,
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        let syscall_id = local.is_add *
+            AB::F::from_canonical_u32(SyscallCode::UINT256_ADD_CARRY.syscall_id()) +
+            local.is_sub *
+                AB::F::from_canonical_u32(SyscallCode::UINT256_SUB_BORROW.syscall_id());
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            syscall_id,
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+    }
+}
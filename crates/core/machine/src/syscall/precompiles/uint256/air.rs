@@ -97,6 +97,10 @@ impl<F: PrimeField32> MachineAir<F> for Uint256MulChip {
         "Uint256MulMod".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        Uint256MulCols::<F>::column_names()
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
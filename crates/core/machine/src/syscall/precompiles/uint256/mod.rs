@@ -1,14 +1,22 @@
+mod add_sub;
 mod air;
+mod mul_mod;
 
+pub use add_sub::*;
 pub use air::*;
+pub use mul_mod::*;
 
 #[cfg(test)]
 mod tests {
 
     use monerochan_core_executor::Program;
-    use monerochan_curves::{params::FieldParameters, uint256::U256Field, utils::biguint_from_limbs};
+    use monerochan_curves::{
+        params::FieldParameters,
+        uint256::{U2048Field, U256Field, U4096Field},
+        utils::biguint_from_limbs,
+    };
     use monerochan_stark::CpuProver;
-    use test_artifacts::UINT256_MUL_ELF;
+    use test_artifacts::{BIGINT_ELF, UINT256_MUL_ELF};
 
     use crate::{
         io::MONEROCHANStdin,
@@ -22,8 +30,21 @@ mod tests {
         run_test::<CpuProver<_, _>>(program, MONEROCHANStdin::new()).unwrap();
     }
 
+    #[test]
+    fn test_bigint() {
+        utils::setup_logger();
+        let program = Program::from(BIGINT_ELF).unwrap();
+        run_test::<CpuProver<_, _>>(program, MONEROCHANStdin::new()).unwrap();
+    }
+
     #[test]
     fn test_uint256_modulus() {
         assert_eq!(biguint_from_limbs(U256Field::MODULUS), U256Field::modulus());
     }
+
+    #[test]
+    fn test_uint2048_and_uint4096_modulus() {
+        assert_eq!(biguint_from_limbs(U2048Field::MODULUS), U2048Field::modulus());
+        assert_eq!(biguint_from_limbs(U4096Field::MODULUS), U4096Field::modulus());
+    }
 }
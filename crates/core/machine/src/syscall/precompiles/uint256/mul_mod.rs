@@ -0,0 +1,409 @@
+use crate::{
+    air::{MemoryAirBuilder, WordAirBuilder},
+    memory::{MemoryReadCols, MemoryWriteCols},
+    operations::{
+        field::{range::FieldLtCols, util::compute_root_quotient_and_shift},
+        IsZeroOperation,
+    },
+    utils::{limbs_from_access, limbs_from_prev_access, words_to_bytes_le_vec, zeroed_f_vec},
+};
+
+use generic_array::GenericArray;
+use num::{BigUint, One, Zero};
+use p3_air::{Air, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelBridge, ParallelIterator, ParallelSlice};
+use monerochan_core_executor::{
+    events::{ByteLookupEvent, ByteRecord, PrecompileEvent, SyscallEvent, Uint256MulEvent},
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use monerochan_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    uint256::{U2048Field, U4096Field},
+};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::air::{InteractionScope, MachineAir, Polynomial, MONEROCHANAirBuilder};
+use std::{
+    borrow::{Borrow, BorrowMut},
+    marker::PhantomData,
+    mem::size_of,
+};
+use typenum::Unsigned;
+
+/// The parameters of a wide modular multiplication chip.
+pub trait UintMulModParameters: FieldParameters + NumWords {
+    /// The name of the chip.
+    const NAME: &'static str;
+
+    /// The syscall handled by the chip.
+    const SYSCALL_CODE: SyscallCode;
+}
+
+impl UintMulModParameters for U2048Field {
+    const NAME: &'static str = "Uint2048MulMod";
+    const SYSCALL_CODE: SyscallCode = SyscallCode::UINT2048_MUL;
+}
+
+impl UintMulModParameters for U4096Field {
+    const NAME: &'static str = "Uint4096MulMod";
+    const SYSCALL_CODE: SyscallCode = SyscallCode::UINT4096_MUL;
+}
+
+pub const fn num_uint_mul_mod_cols<P: FieldParameters + NumWords>() -> usize {
+    size_of::<UintMulModCols<u8, P>>()
+}
+
+/// A chip for the 2048 and 4096-bit modular multiplication.
+///
+/// Unlike [`super::Uint256MulChip`], the witness of the product is too large for two bytes, so
+/// the shifted witness coefficients are decomposed into two bytes and two extra bits.
+#[derive(Default)]
+pub struct UintMulModChip<P> {
+    _marker: PhantomData<P>,
+}
+
+impl<P: UintMulModParameters> UintMulModChip<P> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
+
+/// A set of columns for the wide modular multiplication.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct UintMulModCols<T, P: FieldParameters + NumWords> {
+    /// The shard number of the syscall.
+    pub shard: T,
+
+    /// The clock cycle of the syscall.
+    pub clk: T,
+
+    /// The pointer to the first input.
+    pub x_ptr: T,
+
+    /// The pointer to the second input, which contains the y value and the modulus.
+    pub y_ptr: T,
+
+    // Memory columns.
+    // x_memory is written to with the result, which is why it is of type MemoryWriteCols.
+    pub x_memory: GenericArray<MemoryWriteCols<T>, P::WordsFieldElement>,
+    pub y_memory: GenericArray<MemoryReadCols<T>, P::WordsFieldElement>,
+    pub modulus_memory: GenericArray<MemoryReadCols<T>, P::WordsFieldElement>,
+
+    /// Columns for checking if modulus is zero. If it's zero, then use 2^N as the effective
+    /// modulus.
+    pub modulus_is_zero: IsZeroOperation<T>,
+
+    /// Column that is equal to is_real * (1 - modulus_is_zero.result).
+    pub modulus_is_not_zero: T,
+
+    /// The quotient of `x * y` by the effective modulus.
+    pub quotient: Limbs<T, P::Limbs>,
+
+    /// The shifted witness coefficients, as `low + 2^8 * high + 2^16 * bit_16 + 2^17 * bit_17`.
+    pub witness_low: Limbs<T, P::Witness>,
+    pub witness_high: Limbs<T, P::Witness>,
+    pub witness_bit_16: Limbs<T, P::Witness>,
+    pub witness_bit_17: Limbs<T, P::Witness>,
+
+    pub output_range_check: FieldLtCols<T, P>,
+
+    pub is_real: T,
+}
+
+impl<P: UintMulModParameters> UintMulModChip<P> {
+    fn populate_row<F: PrimeField32>(
+        event: &Uint256MulEvent,
+        cols: &mut UintMulModCols<F, P>,
+        new_byte_lookup_events: &mut Vec<ByteLookupEvent>,
+    ) {
+        let x = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.x));
+        let y = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.y));
+        let modulus = BigUint::from_bytes_le(&words_to_bytes_le_vec(&event.modulus));
+
+        // Assign basic values to the columns.
+        cols.is_real = F::one();
+        cols.shard = F::from_canonical_u32(event.shard);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.x_ptr = F::from_canonical_u32(event.x_ptr);
+        cols.y_ptr = F::from_canonical_u32(event.y_ptr);
+
+        // Populate memory columns.
+        for i in 0..P::WordsFieldElement::USIZE {
+            cols.x_memory[i].populate(event.x_memory_records[i], new_byte_lookup_events);
+            cols.y_memory[i].populate(event.y_memory_records[i], new_byte_lookup_events);
+            cols.modulus_memory[i]
+                .populate(event.modulus_memory_records[i], new_byte_lookup_events);
+        }
+
+        let modulus_bytes = words_to_bytes_le_vec(&event.modulus);
+        let modulus_byte_sum = modulus_bytes.iter().map(|b| *b as u32).sum::<u32>();
+        IsZeroOperation::populate(&mut cols.modulus_is_zero, modulus_byte_sum);
+
+        // Compute the result and the quotient with respect to the effective modulus.
+        let effective_modulus = if modulus.is_zero() { P::modulus() } else { modulus.clone() };
+        let result = (&x * &y) % &effective_modulus;
+        let quotient = (&x * &y - &result) / &effective_modulus;
+        assert!(quotient < P::modulus(), "the product must be smaller than modulus * 2^N");
+
+        let p_x: Polynomial<F> = P::to_limbs_field::<F, _>(&x).into();
+        let p_y: Polynomial<F> = P::to_limbs_field::<F, _>(&y).into();
+        let p_result: Polynomial<F> = P::to_limbs_field::<F, _>(&result).into();
+        let p_quotient: Polynomial<F> = P::to_limbs_field::<F, _>(&quotient).into();
+        // The effective modulus can be 2^N, which has one limb more than the other values.
+        let p_modulus: Polynomial<F> = Polynomial::new(
+            effective_modulus.to_bytes_le().iter().map(|x| F::from_canonical_u8(*x)).collect(),
+        );
+        let p_vanishing = &(&p_x * &p_y) - &p_result - &p_quotient * &p_modulus;
+
+        let p_witness = compute_root_quotient_and_shift(
+            &p_vanishing,
+            P::WITNESS_OFFSET,
+            P::NB_BITS_PER_LIMB as u32,
+            P::NB_WITNESS_LIMBS,
+        );
+        for (i, coefficient) in p_witness.iter().enumerate() {
+            let coefficient = coefficient.as_canonical_u32();
+            debug_assert!(coefficient < 1 << 18);
+            cols.witness_low[i] = F::from_canonical_u32(coefficient & 0xff);
+            cols.witness_high[i] = F::from_canonical_u32((coefficient >> 8) & 0xff);
+            cols.witness_bit_16[i] = F::from_canonical_u32((coefficient >> 16) & 1);
+            cols.witness_bit_17[i] = F::from_canonical_u32((coefficient >> 17) & 1);
+        }
+        cols.quotient = P::to_limbs_field::<F, _>(&quotient);
+
+        // Range checks.
+        new_byte_lookup_events.add_u8_range_checks_field(&cols.quotient.0);
+        new_byte_lookup_events.add_u8_range_checks_field(&p_result.as_coefficients());
+        new_byte_lookup_events.add_u8_range_checks_field(&cols.witness_low.0);
+        new_byte_lookup_events.add_u8_range_checks_field(&cols.witness_high.0);
+
+        cols.modulus_is_not_zero = F::one() - cols.modulus_is_zero.result;
+        if cols.modulus_is_not_zero == F::one() {
+            cols.output_range_check.populate(new_byte_lookup_events, &result, &effective_modulus);
+        }
+    }
+}
+
+impl<F: PrimeField32, P: UintMulModParameters> MachineAir<F> for UintMulModChip<P> {
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        P::NAME.to_string()
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        UintMulModCols::<F, P>::column_names()
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events = input.get_precompile_events(P::SYSCALL_CODE);
+
+        let num_cols = num_uint_mul_mod_cols::<P>();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        let blu_events: Vec<Vec<ByteLookupEvent>> = events
+            .par_chunks(chunk_size)
+            .map(|ops: &[(SyscallEvent, PrecompileEvent)]| {
+                let mut blu = Vec::new();
+                ops.iter().for_each(|(_, op)| match op {
+                    PrecompileEvent::Uint2048Mul(event) | PrecompileEvent::Uint4096Mul(event) => {
+                        let mut row = zeroed_f_vec(num_cols);
+                        let cols: &mut UintMulModCols<F, P> = row.as_mut_slice().borrow_mut();
+                        Self::populate_row(event, cols, &mut blu);
+                    }
+                    _ => unreachable!(),
+                });
+                blu
+            })
+            .collect();
+
+        for blu in blu_events {
+            output.add_byte_lookup_events(blu);
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(P::SYSCALL_CODE);
+
+        let num_cols = num_uint_mul_mod_cols::<P>();
+        let num_rows = input
+            .fixed_log2_rows::<F, _>(self)
+            .map(|x| 1 << x)
+            .unwrap_or(std::cmp::max(events.len().next_power_of_two(), 4));
+        let mut values = zeroed_f_vec(num_rows * num_cols);
+
+        // The witness constraints are not gated by is_real, so the padding rows hold the
+        // decomposition of the shifted zero witness, which is 2^17.
+        let mut dummy_row = zeroed_f_vec(num_cols);
+        let cols: &mut UintMulModCols<F, P> = dummy_row.as_mut_slice().borrow_mut();
+        for bit in cols.witness_bit_17.0.iter_mut() {
+            *bit = F::one();
+        }
+
+        values.chunks_mut(num_cols).enumerate().par_bridge().for_each(|(idx, row)| {
+            if idx < events.len() {
+                let cols: &mut UintMulModCols<F, P> = row.borrow_mut();
+                match &events[idx].1 {
+                    PrecompileEvent::Uint2048Mul(event) | PrecompileEvent::Uint4096Mul(event) => {
+                        Self::populate_row(event, cols, &mut vec![]);
+                    }
+                    _ => unreachable!(),
+                }
+            } else {
+                row.copy_from_slice(&dummy_row);
+            }
+        });
+
+        // Convert the trace to a row major matrix.
+        RowMajorMatrix::new(values, num_cols)
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(P::SYSCALL_CODE).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F, P: UintMulModParameters> BaseAir<F> for UintMulModChip<P> {
+    fn width(&self) -> usize {
+        num_uint_mul_mod_cols::<P>()
+    }
+}
+
+impl<AB, P: UintMulModParameters> Air<AB> for UintMulModChip<P>
+where
+    AB: MONEROCHANAirBuilder,
+    Limbs<AB::Var, <P as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &UintMulModCols<AB::Var, P> = (*local).borrow();
+
+        // We are computing (x * y) % modulus. The value of x is stored in the "prev_value" of
+        // the x_memory, and the result in its "value".
+        let x_limbs = limbs_from_prev_access(&local.x_memory);
+        let y_limbs = limbs_from_access(&local.y_memory);
+        let modulus_limbs = limbs_from_access(&local.modulus_memory);
+        let result_limbs: Limbs<AB::Var, P::Limbs> = limbs_from_access(&local.x_memory);
+
+        // If the modulus is zero, then we don't perform the modulus operation. The sum of the
+        // modulus bytes does not overflow, as there are at most 512 of them.
+        let modulus_byte_sum =
+            modulus_limbs.0.iter().fold(AB::Expr::zero(), |acc, &limb| acc + limb);
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            modulus_byte_sum,
+            local.modulus_is_zero,
+            local.is_real.into(),
+        );
+
+        // If the modulus is zero, we'll actually use 2^N as the modulus, so nothing happens.
+        // Otherwise, we use the modulus passed in.
+        let modulus_is_zero = local.modulus_is_zero.result;
+        let mut coeff_2_n = Vec::new();
+        coeff_2_n.resize(P::NB_LIMBS, AB::Expr::zero());
+        coeff_2_n.push(AB::Expr::one());
+        let modulus_polynomial: Polynomial<AB::Expr> = modulus_limbs.into();
+        let p_modulus: Polynomial<AB::Expr> = modulus_polynomial *
+            (AB::Expr::one() - modulus_is_zero.into()) +
+            Polynomial::from_coefficients(&coeff_2_n) * modulus_is_zero.into();
+
+        // The vanishing polynomial x * y - result - quotient * modulus.
+        let p_x: Polynomial<AB::Expr> = x_limbs.into();
+        let p_y: Polynomial<AB::Expr> = y_limbs.into();
+        let p_result: Polynomial<AB::Expr> = result_limbs.into();
+        let p_quotient: Polynomial<AB::Expr> = local.quotient.into();
+        let p_vanishing = p_x * p_y - p_result - p_quotient * p_modulus;
+
+        // Reconstruct and shift back the witness polynomial, and check that it is the quotient of
+        // the vanishing polynomial by (x - 2^8).
+        let offset = AB::Expr::from_canonical_u32(P::WITNESS_OFFSET as u32);
+        let p_witness: Polynomial<AB::Expr> = (0..P::NB_WITNESS_LIMBS)
+            .map(|i| {
+                builder.assert_bool(local.witness_bit_16[i]);
+                builder.assert_bool(local.witness_bit_17[i]);
+                local.witness_low[i] +
+                    local.witness_high[i] * AB::F::from_canonical_u32(1 << 8) +
+                    local.witness_bit_16[i] * AB::F::from_canonical_u32(1 << 16) +
+                    local.witness_bit_17[i] * AB::F::from_canonical_u32(1 << 17) -
+                    offset.clone()
+            })
+            .collect();
+        let root_monomial = Polynomial::new(vec![
+            -AB::Expr::from_canonical_u32(1 << P::NB_BITS_PER_LIMB),
+            AB::Expr::one(),
+        ]);
+        let constraints = p_vanishing - &(p_witness * root_monomial);
+        for constraint in constraints.as_coefficients() {
+            builder.assert_zero(constraint);
+        }
+
+        // Range checks for the quotient, the result, and the witness bytes.
+        builder.slice_range_check_u8(&local.quotient.0, local.is_real);
+        builder.slice_range_check_u8(&result_limbs.0, local.is_real);
+        builder.slice_range_check_u8(&local.witness_low.0, local.is_real);
+        builder.slice_range_check_u8(&local.witness_high.0, local.is_real);
+
+        // Verify the range of the output if the modulus is not zero. Also, check the value of
+        // modulus_is_not_zero.
+        local.output_range_check.eval(
+            builder,
+            &result_limbs,
+            &modulus_limbs,
+            local.modulus_is_not_zero,
+        );
+        builder.assert_eq(
+            local.modulus_is_not_zero,
+            local.is_real * (AB::Expr::one() - modulus_is_zero.into()),
+        );
+
+        // Read and write x.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into() + AB::Expr::one(),
+            local.x_ptr,
+            &local.x_memory,
+            local.is_real,
+        );
+
+        // Evaluate the y_ptr memory access. We concatenate y and modulus into a single array since
+        // we read it contiguously from the y_ptr memory location.
+        builder.eval_memory_access_slice(
+            local.shard,
+            local.clk.into(),
+            local.y_ptr,
+            &[local.y_memory.clone(), local.modulus_memory.clone()].concat(),
+            local.is_real,
+        );
+
+        // Receive the arguments.
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            AB::F::from_canonical_u32(P::SYSCALL_CODE.syscall_id()),
+            local.x_ptr,
+            local.y_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+
+        // Assert that is_real is a boolean.
+        builder.assert_bool(local.is_real);
+    }
+}
@@ -0,0 +1,193 @@
+use hashbrown::HashMap;
+use p3_air::{Air, AirBuilder, AirBuilderWithPublicValues};
+use p3_field::Field;
+use p3_matrix::dense::RowMajorMatrix;
+use p3_uni_stark::{Entry, SymbolicExpression, SymbolicVariable};
+use monerochan_stark::{
+    air::{AirInteraction, InteractionScope, MessageBuilder, MONEROCHAN_PROOF_NUM_PV_ELTS},
+    InteractionKind,
+};
+
+/// The builder that user precompile AIRs are evaluated with.
+///
+/// The builder records the constraints and interactions of the AIR symbolically, so that
+/// [`super::UserPrecompileChip`] can replay them with any of the builders used by the prover and
+/// the verifier. It provides all the helpers of [`crate::air::MONEROCHANCoreAirBuilder`], but it
+/// does not provide preprocessed columns.
+pub struct UserPrecompileBuilder<F: Field> {
+    main: RowMajorMatrix<SymbolicVariable<F>>,
+    public_values: Vec<SymbolicVariable<F>>,
+    constraints: Vec<SymbolicExpression<F>>,
+    sends: Vec<(AirInteraction<SymbolicExpression<F>>, InteractionScope)>,
+    receives: Vec<(AirInteraction<SymbolicExpression<F>>, InteractionScope)>,
+}
+
+impl<F: Field> UserPrecompileBuilder<F> {
+    fn new(width: usize) -> Self {
+        let main_values = [0, 1]
+            .into_iter()
+            .flat_map(|offset| {
+                (0..width).map(move |column| SymbolicVariable::new(Entry::Main { offset }, column))
+            })
+            .collect();
+        let public_values = (0..MONEROCHAN_PROOF_NUM_PV_ELTS)
+            .map(|index| SymbolicVariable::new(Entry::Public, index))
+            .collect();
+
+        Self {
+            main: RowMajorMatrix::new(main_values, width),
+            public_values,
+            constraints: vec![],
+            sends: vec![],
+            receives: vec![],
+        }
+    }
+}
+
+impl<F: Field> AirBuilder for UserPrecompileBuilder<F> {
+    type F = F;
+    type Expr = SymbolicExpression<F>;
+    type Var = SymbolicVariable<F>;
+    type M = RowMajorMatrix<Self::Var>;
+
+    fn main(&self) -> Self::M {
+        self.main.clone()
+    }
+
+    fn is_first_row(&self) -> Self::Expr {
+        SymbolicExpression::IsFirstRow
+    }
+
+    fn is_last_row(&self) -> Self::Expr {
+        SymbolicExpression::IsLastRow
+    }
+
+    fn is_transition_window(&self, size: usize) -> Self::Expr {
+        if size == 2 {
+            SymbolicExpression::IsTransition
+        } else {
+            panic!("uni-stark only supports a window size of 2")
+        }
+    }
+
+    fn assert_zero<I: Into<Self::Expr>>(&mut self, x: I) {
+        self.constraints.push(x.into());
+    }
+}
+
+impl<F: Field> MessageBuilder<AirInteraction<SymbolicExpression<F>>> for UserPrecompileBuilder<F> {
+    fn send(&mut self, message: AirInteraction<SymbolicExpression<F>>, scope: InteractionScope) {
+        self.sends.push((message, scope));
+    }
+
+    fn receive(&mut self, message: AirInteraction<SymbolicExpression<F>>, scope: InteractionScope) {
+        self.receives.push((message, scope));
+    }
+}
+
+impl<F: Field> AirBuilderWithPublicValues for UserPrecompileBuilder<F> {
+    type PublicVar = SymbolicVariable<F>;
+
+    fn public_values(&self) -> &[Self::PublicVar] {
+        &self.public_values
+    }
+}
+
+/// A node of a [`RecordedAir`], whose operands refer to earlier nodes.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Node<F> {
+    Constant(F),
+    Main { offset: usize, column: usize },
+    Public(usize),
+    IsFirstRow,
+    IsLastRow,
+    IsTransition,
+    Add(usize, usize),
+    Sub(usize, usize),
+    Neg(usize),
+    Mul(usize, usize),
+}
+
+/// An interaction of a [`RecordedAir`], with its values given as node indices.
+#[derive(Debug, Clone)]
+pub(crate) struct RecordedInteraction {
+    pub(crate) values: Vec<usize>,
+    pub(crate) multiplicity: usize,
+    pub(crate) kind: InteractionKind,
+    pub(crate) scope: InteractionScope,
+}
+
+/// The constraints and interactions of a user precompile AIR.
+///
+/// The symbolic expressions of [`UserPrecompileBuilder`] are reference counted with `Rc`, so they
+/// are flattened into a list of nodes that can be shared between threads. Shared subexpressions
+/// are only stored once.
+#[derive(Debug, Clone)]
+pub(crate) struct RecordedAir<F> {
+    pub(crate) nodes: Vec<Node<F>>,
+    pub(crate) constraints: Vec<usize>,
+    pub(crate) sends: Vec<RecordedInteraction>,
+    pub(crate) receives: Vec<RecordedInteraction>,
+}
+
+impl<F: Field> RecordedAir<F> {
+    /// Evaluates `air` with a [`UserPrecompileBuilder`] and records the result.
+    pub(crate) fn record<A: Air<UserPrecompileBuilder<F>> + ?Sized>(air: &A) -> Self {
+        let mut builder = UserPrecompileBuilder::new(air.width());
+        air.eval(&mut builder);
+
+        let mut recorder = Recorder { nodes: vec![], cache: HashMap::new() };
+        let constraints = builder.constraints.iter().map(|c| recorder.node(c)).collect();
+        let mut record_interactions =
+            |interactions: &[(AirInteraction<SymbolicExpression<F>>, InteractionScope)]| {
+                interactions
+                    .iter()
+                    .map(|(interaction, scope)| RecordedInteraction {
+                        values: interaction.values.iter().map(|v| recorder.node(v)).collect(),
+                        multiplicity: recorder.node(&interaction.multiplicity),
+                        kind: interaction.kind,
+                        scope: *scope,
+                    })
+                    .collect::<Vec<_>>()
+            };
+        let sends = record_interactions(&builder.sends);
+        let receives = record_interactions(&builder.receives);
+
+        Self { nodes: recorder.nodes, constraints, sends, receives }
+    }
+}
+
+struct Recorder<F> {
+    nodes: Vec<Node<F>>,
+    cache: HashMap<*const (), usize>,
+}
+
+impl<F: Field> Recorder<F> {
+    fn node(&mut self, expression: &SymbolicExpression<F>) -> usize {
+        let key = expression as *const SymbolicExpression<F> as *const ();
+        if let Some(&index) = self.cache.get(&key) {
+            return index;
+        }
+
+        let node = match expression {
+            SymbolicExpression::Constant(c) => Node::Constant(*c),
+            SymbolicExpression::Variable(v) => match v.entry {
+                Entry::Main { offset } => Node::Main { offset, column: v.index },
+                Entry::Public => Node::Public(v.index),
+                entry => panic!("user precompiles cannot use {entry:?} variables"),
+            },
+            SymbolicExpression::IsFirstRow => Node::IsFirstRow,
+            SymbolicExpression::IsLastRow => Node::IsLastRow,
+            SymbolicExpression::IsTransition => Node::IsTransition,
+            SymbolicExpression::Add { x, y, .. } => Node::Add(self.node(x), self.node(y)),
+            SymbolicExpression::Sub { x, y, .. } => Node::Sub(self.node(x), self.node(y)),
+            SymbolicExpression::Neg { x, .. } => Node::Neg(self.node(x)),
+            SymbolicExpression::Mul { x, y, .. } => Node::Mul(self.node(x), self.node(y)),
+        };
+
+        let index = self.nodes.len();
+        self.nodes.push(node);
+        self.cache.insert(key, index);
+        index
+    }
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use p3_air::{Air, AirBuilder, AirBuilderWithPublicValues, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use monerochan_core_executor::{
+    events::PrecompileEvent, syscalls::SyscallCode, ExecutionRecord, Program, RiscvAirId,
+};
+use monerochan_stark::air::{AirInteraction, MachineAir, MONEROCHANAirBuilder};
+
+use super::{
+    builder::{Node, RecordedAir},
+    UserPrecompileAir,
+};
+use crate::utils::next_power_of_two;
+
+/// The chip of a user precompile.
+///
+/// The chip wraps a [`UserPrecompileAir`] and takes the name of the [`RiscvAirId`] of its slot, so
+/// that it can be used in shapes like any other precompile chip.
+#[derive(Clone)]
+pub struct UserPrecompileChip<F: PrimeField32> {
+    code: SyscallCode,
+    air: Arc<dyn UserPrecompileAir<F>>,
+    recorded: Arc<RecordedAir<F>>,
+}
+
+impl<F: PrimeField32> UserPrecompileChip<F> {
+    /// Creates a new [`UserPrecompileChip`] for the user precompile slot `code`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code` is not a user precompile slot.
+    pub fn new(code: SyscallCode, air: Arc<dyn UserPrecompileAir<F>>) -> Self {
+        assert!(code.is_user_precompile(), "{code} is not a user precompile slot");
+        let recorded = Arc::new(RecordedAir::record(&*air));
+        Self { code, air, recorded }
+    }
+
+    /// The syscall code of the precompile.
+    pub const fn code(&self) -> SyscallCode {
+        self.code
+    }
+
+    /// The AIR of the precompile.
+    pub fn air(&self) -> &Arc<dyn UserPrecompileAir<F>> {
+        &self.air
+    }
+
+    /// The ID of the slot the precompile is registered in.
+    pub fn id(&self) -> RiscvAirId {
+        self.code.as_air_id().unwrap()
+    }
+}
+
+impl<F: PrimeField32> MachineAir<F> for UserPrecompileChip<F> {
+    type Record = ExecutionRecord;
+
+    type Program = Program;
+
+    fn name(&self) -> String {
+        self.id().to_string()
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = input
+            .get_precompile_events(self.code)
+            .iter()
+            .map(|(_, event)| {
+                if let PrecompileEvent::User(event) = event {
+                    event
+                } else {
+                    unreachable!()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut trace = self.air.generate_trace(&events, output);
+        assert_eq!(trace.width(), self.width(), "trace width of {} does not match", self.name());
+
+        // Pad the trace with zero rows.
+        let height = next_power_of_two(trace.height(), input.fixed_log2_rows::<F, _>(self));
+        trace.values.resize(height * trace.width(), F::zero());
+        trace
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(self.code).is_empty()
+        }
+    }
+}
+
+impl<F: PrimeField32> BaseAir<F> for UserPrecompileChip<F> {
+    fn width(&self) -> usize {
+        self.air.width()
+    }
+}
+
+impl<F, AB> Air<AB> for UserPrecompileChip<F>
+where
+    F: PrimeField32,
+    AB: MONEROCHANAirBuilder<F = F>,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let rows = [main.row_slice(0).to_vec(), main.row_slice(1).to_vec()];
+        let public_values = builder.public_values().to_vec();
+
+        // Evaluate the recorded expressions in order, so that the operands of each node are
+        // available when it is evaluated.
+        let mut values: Vec<AB::Expr> = Vec::with_capacity(self.recorded.nodes.len());
+        for node in self.recorded.nodes.iter() {
+            let value = match *node {
+                Node::Constant(c) => c.into(),
+                Node::Main { offset, column } => rows[offset][column].into(),
+                Node::Public(index) => public_values[index].into(),
+                Node::IsFirstRow => builder.is_first_row(),
+                Node::IsLastRow => builder.is_last_row(),
+                Node::IsTransition => builder.is_transition(),
+                Node::Add(x, y) => values[x].clone() + values[y].clone(),
+                Node::Sub(x, y) => values[x].clone() - values[y].clone(),
+                Node::Neg(x) => -values[x].clone(),
+                Node::Mul(x, y) => values[x].clone() * values[y].clone(),
+            };
+            values.push(value);
+        }
+
+        for &constraint in self.recorded.constraints.iter() {
+            builder.assert_zero(values[constraint].clone());
+        }
+        for interaction in self.recorded.sends.iter() {
+            builder.send(
+                AirInteraction::new(
+                    interaction.values.iter().map(|&v| values[v].clone()).collect(),
+                    values[interaction.multiplicity].clone(),
+                    interaction.kind,
+                ),
+                interaction.scope,
+            );
+        }
+        for interaction in self.recorded.receives.iter() {
+            builder.receive(
+                AirInteraction::new(
+                    interaction.values.iter().map(|&v| values[v].clone()).collect(),
+                    values[interaction.multiplicity].clone(),
+                    interaction.kind,
+                ),
+                interaction.scope,
+            );
+        }
+    }
+}
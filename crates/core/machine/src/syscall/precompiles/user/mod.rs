@@ -0,0 +1,324 @@
+//! Precompiles defined outside of this crate.
+//!
+//! Four syscall codes, [`SyscallCode::USER_PRECOMPILE_0`] through
+//! [`SyscallCode::USER_PRECOMPILE_3`], are reserved for precompiles that cannot be part of the
+//! default zkVM. A downstream crate adds such a precompile without modifying the zkVM:
+//!
+//! 1. Implement [`Syscall`] for the executor. The implementation reads and writes memory through
+//!    the [`SyscallContext`] and records a [`UserPrecompileEvent`] with
+//!    [`SyscallContext::add_user_precompile_event`].
+//! 2. Implement [`UserPrecompileAir`] for the chip. The AIR is written against the usual builder
+//!    helpers, and must receive the syscall with `receive_syscall` and constrain its memory
+//!    accesses like the built-in precompiles do.
+//! 3. Register both in a [`UserPrecompiles`] with [`UserPrecompiles::register`].
+//! 4. Build the machine with [`crate::riscv::RiscvAir::machine_with_user_precompiles`], the shape
+//!    config with [`crate::shape::CoreShapeConfig::with_user_precompiles`], and pass
+//!    [`UserPrecompiles::syscalls`] to the executor with
+//!    [`MONEROCHANContextBuilder::user_syscalls`].
+//! 5. In the guest, invoke the precompile with
+//!    `monerochan_runtime::syscalls::syscall_user_precompile`.
+//!
+//! The stock [`crate::riscv::RiscvAir::machine`] and executor never contain user precompiles. A
+//! machine with user precompiles has different chips, so it has its own verifying keys: the prover
+//! and the verifier must use the same [`UserPrecompiles`].
+//!
+//! [`SyscallContext`]: monerochan_core_executor::syscalls::SyscallContext
+//! [`SyscallContext::add_user_precompile_event`]: monerochan_core_executor::syscalls::SyscallContext::add_user_precompile_event
+//! [`MONEROCHANContextBuilder::user_syscalls`]: monerochan_core_executor::MONEROCHANContextBuilder::user_syscalls
+
+mod builder;
+mod chip;
+
+use std::sync::Arc;
+
+use p3_air::{Air, BaseAir};
+use p3_field::PrimeField32;
+use p3_matrix::dense::RowMajorMatrix;
+use monerochan_core_executor::{
+    events::UserPrecompileEvent,
+    syscalls::{Syscall, SyscallCode, UserSyscall, UserSyscalls},
+    ExecutionRecord,
+};
+
+pub use builder::UserPrecompileBuilder;
+pub use chip::UserPrecompileChip;
+
+/// The AIR of a user precompile.
+///
+/// Padding rows are filled with zeros, so every constraint and interaction must vanish on a zero
+/// row, typically by gating them with an `is_real` column.
+pub trait UserPrecompileAir<F: PrimeField32>:
+    BaseAir<F> + Air<UserPrecompileBuilder<F>> + Send + Sync + 'static
+{
+    /// The number of rows each event occupies in the trace.
+    fn rows_per_event(&self) -> usize {
+        1
+    }
+
+    /// Generates the rows of the trace for the given events, without padding.
+    ///
+    /// Byte lookups needed by the rows should be added to `output`.
+    fn generate_trace(
+        &self,
+        events: &[&UserPrecompileEvent],
+        output: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F>;
+}
+
+/// The user precompiles of a machine, by slot.
+///
+/// The same registry builds the machine, its shape config and the executor side of the
+/// precompiles, so that they always agree.
+#[derive(Clone)]
+pub struct UserPrecompiles<F: PrimeField32> {
+    chips: Vec<UserPrecompileChip<F>>,
+    syscalls: UserSyscalls,
+}
+
+impl<F: PrimeField32> Default for UserPrecompiles<F> {
+    fn default() -> Self {
+        Self { chips: Vec::new(), syscalls: UserSyscalls::default() }
+    }
+}
+
+impl<F: PrimeField32> UserPrecompiles<F> {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a user precompile in the slot `code`.
+    ///
+    /// Registering a slot twice replaces the previous precompile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `code` is not a user precompile slot.
+    pub fn register(
+        &mut self,
+        code: SyscallCode,
+        syscall: impl Syscall + 'static,
+        air: impl UserPrecompileAir<F>,
+    ) -> &mut Self {
+        let rows_per_event = air.rows_per_event();
+        let chip = UserPrecompileChip::new(code, Arc::new(air));
+        self.syscalls.insert(code, UserSyscall { syscall: Arc::new(syscall), rows_per_event });
+
+        self.chips.retain(|chip| chip.code() != code);
+        self.chips.push(chip);
+        self.chips.sort_by_key(|chip| chip.code() as u32);
+        self
+    }
+
+    /// The chips of the registered precompiles, ordered by slot.
+    #[must_use]
+    pub fn chips(&self) -> &[UserPrecompileChip<F>] {
+        &self.chips
+    }
+
+    /// The executor side of the registered precompiles.
+    #[must_use]
+    pub fn syscalls(&self) -> &UserSyscalls {
+        &self.syscalls
+    }
+
+    /// Whether no precompile is registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.chips.is_empty()
+    }
+}
+
+#[cfg(test)]
+pub mod user_tests {
+    use core::borrow::{Borrow, BorrowMut};
+    use std::mem::size_of;
+
+    use p3_air::{Air, AirBuilder, BaseAir};
+    use p3_baby_bear::BabyBear;
+    use p3_field::{AbstractField, PrimeField32};
+    use p3_matrix::{dense::RowMajorMatrix, Matrix};
+    use monerochan_core_executor::{
+        events::UserPrecompileEvent,
+        syscalls::{Syscall, SyscallCode, SyscallContext},
+        ExecutionRecord, Instruction, Opcode, Program, RiscvAirId, MONEROCHANContext,
+    };
+    use monerochan_derive::AlignedBorrow;
+    use monerochan_stark::{
+        air::{InteractionScope, MachineAir, MONEROCHANAirBuilder},
+        baby_bear_poseidon2::BabyBearPoseidon2,
+        CpuProver, MachineProver, MONEROCHANCoreOpts, StarkGenericConfig,
+    };
+
+    use super::{UserPrecompileAir, UserPrecompiles};
+    use crate::{
+        air::{MemoryAirBuilder, WordAirBuilder},
+        io::MONEROCHANStdin,
+        memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+        riscv::RiscvAir,
+        shape::CoreShapeConfig,
+        utils::{self, prove_core},
+    };
+
+    const CODE: SyscallCode = SyscallCode::USER_PRECOMPILE_3;
+
+    const NUM_COPY_WORD_COLS: usize = size_of::<CopyWordCols<u8>>();
+
+    /// Copies the word at `arg2` to `arg1`.
+    struct CopyWordSyscall;
+
+    #[derive(AlignedBorrow, Default, Debug, Clone, Copy)]
+    #[repr(C)]
+    struct CopyWordCols<T> {
+        shard: T,
+        clk: T,
+        dst_ptr: T,
+        src_ptr: T,
+        src_access: MemoryReadCols<T>,
+        dst_access: MemoryWriteCols<T>,
+        is_real: T,
+    }
+
+    struct CopyWordAir;
+
+    impl Syscall for CopyWordSyscall {
+        fn execute(
+            &self,
+            rt: &mut SyscallContext,
+            syscall_code: SyscallCode,
+            arg1: u32,
+            arg2: u32,
+        ) -> Option<u32> {
+            let clk = rt.clk;
+            let (read_record, value) = rt.mr(arg2);
+            let write_record = rt.mw(arg1, value);
+
+            let event = UserPrecompileEvent {
+                shard: rt.current_shard(),
+                clk,
+                arg1,
+                arg2,
+                read_records: vec![read_record],
+                write_records: vec![write_record],
+                values: vec![],
+                local_mem_access: rt.postprocess(),
+            };
+            rt.add_user_precompile_event(syscall_code, arg1, arg2, event);
+
+            None
+        }
+    }
+
+    impl<F> BaseAir<F> for CopyWordAir {
+        fn width(&self) -> usize {
+            NUM_COPY_WORD_COLS
+        }
+    }
+
+    impl<AB: MONEROCHANAirBuilder> Air<AB> for CopyWordAir {
+        fn eval(&self, builder: &mut AB) {
+            let main = builder.main();
+            let local = main.row_slice(0);
+            let local: &CopyWordCols<AB::Var> = (*local).borrow();
+
+            builder.assert_bool(local.is_real);
+            builder
+                .when(local.is_real)
+                .assert_word_eq(*local.dst_access.value(), *local.src_access.value());
+            builder.eval_memory_access(
+                local.shard,
+                local.clk,
+                local.src_ptr,
+                &local.src_access,
+                local.is_real,
+            );
+            builder.eval_memory_access(
+                local.shard,
+                local.clk,
+                local.dst_ptr,
+                &local.dst_access,
+                local.is_real,
+            );
+            builder.receive_syscall(
+                local.shard,
+                local.clk,
+                AB::F::from_canonical_u32(CODE.syscall_id()),
+                local.dst_ptr,
+                local.src_ptr,
+                local.is_real,
+                InteractionScope::Local,
+            );
+        }
+    }
+
+    impl<F: PrimeField32> UserPrecompileAir<F> for CopyWordAir {
+        fn generate_trace(
+            &self,
+            events: &[&UserPrecompileEvent],
+            output: &mut ExecutionRecord,
+        ) -> RowMajorMatrix<F> {
+            let mut values = vec![F::zero(); events.len() * NUM_COPY_WORD_COLS];
+            for (row, event) in values.chunks_mut(NUM_COPY_WORD_COLS).zip(events) {
+                let cols: &mut CopyWordCols<F> = row.borrow_mut();
+                cols.shard = F::from_canonical_u32(event.shard);
+                cols.clk = F::from_canonical_u32(event.clk);
+                cols.dst_ptr = F::from_canonical_u32(event.arg1);
+                cols.src_ptr = F::from_canonical_u32(event.arg2);
+                cols.src_access.populate(event.read_records[0], output);
+                cols.dst_access.populate(event.write_records[0], output);
+                cols.is_real = F::one();
+            }
+            RowMajorMatrix::new(values, NUM_COPY_WORD_COLS)
+        }
+    }
+
+    #[test]
+    fn test_user_precompile_prove() {
+        utils::setup_logger();
+        let mut user_precompiles = UserPrecompiles::<BabyBear>::new();
+        user_precompiles.register(CODE, CopyWordSyscall, CopyWordAir);
+
+        let (src_ptr, dst_ptr) = (100, 200);
+        let instructions = vec![
+            Instruction::new(Opcode::ADD, 29, 0, 0xdead, false, true),
+            Instruction::new(Opcode::ADD, 30, 0, src_ptr, false, true),
+            Instruction::new(Opcode::SW, 29, 30, 0, false, true),
+            Instruction::new(Opcode::ADD, 5, 0, CODE as u32, false, true),
+            Instruction::new(Opcode::ADD, 10, 0, dst_ptr, false, true),
+            Instruction::new(Opcode::ADD, 11, 0, src_ptr, false, true),
+            Instruction::new(Opcode::ECALL, 5, 10, 11, false, false),
+        ];
+        let mut program = Program::new(instructions, 0, 0);
+
+        let shape_config = CoreShapeConfig::default().with_user_precompiles(&user_precompiles);
+        shape_config.fix_preprocessed_shape(&mut program).unwrap();
+
+        let machine =
+            RiscvAir::machine_with_user_precompiles(BabyBearPoseidon2::new(), &user_precompiles);
+        let prover = CpuProver::new(machine);
+        let (pk, vk) = prover.setup(&program);
+        let context =
+            MONEROCHANContext::builder().user_syscalls(user_precompiles.syscalls().clone()).build();
+        let (proof, _, _) = prove_core(
+            &prover,
+            &pk,
+            &vk,
+            program,
+            &MONEROCHANStdin::new(),
+            MONEROCHANCoreOpts::default(),
+            context,
+            Some(&shape_config),
+            None,
+        )
+        .unwrap();
+
+        let mut challenger = prover.config().challenger();
+        prover.machine().verify(&vk, &proof, &mut challenger).unwrap();
+
+        // Only the machine built with the user precompiles has a chip for the slot.
+        let name = RiscvAirId::UserPrecompile3.to_string();
+        assert!(prover.machine().chips().iter().any(|chip| chip.name() == name));
+        assert!(RiscvAir::<BabyBear>::chips().iter().all(|chip| chip.name() != name));
+    }
+}
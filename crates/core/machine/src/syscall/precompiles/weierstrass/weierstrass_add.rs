@@ -159,16 +159,26 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256r1 => "Secp256r1AddAssign".to_string(),
             CurveType::Bn254 => "Bn254AddAssign".to_string(),
             CurveType::Bls12381 => "Bls12381AddAssign".to_string(),
+            CurveType::Pallas => "PallasAddAssign".to_string(),
+            CurveType::Vesta => "VestaAddAssign".to_string(),
+            CurveType::Grumpkin => "GrumpkinAddAssign".to_string(),
             _ => panic!("Unsupported curve"),
         }
     }
 
+    fn column_names(&self) -> Vec<String> {
+        WeierstrassAddAssignCols::<F, E::BaseField>::column_names()
+    }
+
     fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
         let events = match E::CURVE_TYPE {
             CurveType::Secp256k1 => &input.get_precompile_events(SyscallCode::SECP256K1_ADD),
             CurveType::Secp256r1 => &input.get_precompile_events(SyscallCode::SECP256R1_ADD),
             CurveType::Bn254 => &input.get_precompile_events(SyscallCode::BN254_ADD),
             CurveType::Bls12381 => &input.get_precompile_events(SyscallCode::BLS12381_ADD),
+            CurveType::Pallas => &input.get_precompile_events(SyscallCode::PALLAS_ADD),
+            CurveType::Vesta => &input.get_precompile_events(SyscallCode::VESTA_ADD),
+            CurveType::Grumpkin => &input.get_precompile_events(SyscallCode::GRUMPKIN_ADD),
             _ => panic!("Unsupported curve"),
         };
 
@@ -184,7 +194,10 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                     PrecompileEvent::Secp256k1Add(event) |
                     PrecompileEvent::Secp256r1Add(event) |
                     PrecompileEvent::Bn254Add(event) |
-                    PrecompileEvent::Bls12381Add(event) => {
+                    PrecompileEvent::Bls12381Add(event) |
+                    PrecompileEvent::PallasAdd(event) |
+                    PrecompileEvent::VestaAdd(event) |
+                    PrecompileEvent::GrumpkinAdd(event) => {
                         let mut row = zeroed_f_vec(num_cols);
                         let cols: &mut WeierstrassAddAssignCols<F, E::BaseField> =
                             row.as_mut_slice().borrow_mut();
@@ -211,6 +224,9 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256r1 => input.get_precompile_events(SyscallCode::SECP256R1_ADD),
             CurveType::Bn254 => input.get_precompile_events(SyscallCode::BN254_ADD),
             CurveType::Bls12381 => input.get_precompile_events(SyscallCode::BLS12381_ADD),
+            CurveType::Pallas => input.get_precompile_events(SyscallCode::PALLAS_ADD),
+            CurveType::Vesta => input.get_precompile_events(SyscallCode::VESTA_ADD),
+            CurveType::Grumpkin => input.get_precompile_events(SyscallCode::GRUMPKIN_ADD),
             _ => panic!("Unsupported curve"),
         };
 
@@ -244,7 +260,10 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                         PrecompileEvent::Secp256k1Add(event) |
                         PrecompileEvent::Secp256r1Add(event) |
                         PrecompileEvent::Bn254Add(event) |
-                        PrecompileEvent::Bls12381Add(event) => {
+                        PrecompileEvent::Bls12381Add(event) |
+                        PrecompileEvent::PallasAdd(event) |
+                        PrecompileEvent::VestaAdd(event) |
+                        PrecompileEvent::GrumpkinAdd(event) => {
                             Self::populate_row(event, cols, &mut new_byte_lookup_events);
                         }
                         _ => unreachable!(),
@@ -274,6 +293,13 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                 CurveType::Bls12381 => {
                     !shard.get_precompile_events(SyscallCode::BLS12381_ADD).is_empty()
                 }
+                CurveType::Pallas => {
+                    !shard.get_precompile_events(SyscallCode::PALLAS_ADD).is_empty()
+                }
+                CurveType::Vesta => !shard.get_precompile_events(SyscallCode::VESTA_ADD).is_empty(),
+                CurveType::Grumpkin => {
+                    !shard.get_precompile_events(SyscallCode::GRUMPKIN_ADD).is_empty()
+                }
                 _ => panic!("Unsupported curve"),
             }
         }
@@ -422,6 +448,11 @@ where
             CurveType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_ADD.syscall_id())
             }
+            CurveType::Pallas => AB::F::from_canonical_u32(SyscallCode::PALLAS_ADD.syscall_id()),
+            CurveType::Vesta => AB::F::from_canonical_u32(SyscallCode::VESTA_ADD.syscall_id()),
+            CurveType::Grumpkin => {
+                AB::F::from_canonical_u32(SyscallCode::GRUMPKIN_ADD.syscall_id())
+            }
             _ => panic!("Unsupported curve"),
         };
 
@@ -477,7 +508,8 @@ mod tests {
     use monerochan_stark::CpuProver;
     use test_artifacts::{
         BLS12381_ADD_ELF, BLS12381_DOUBLE_ELF, BLS12381_MUL_ELF, BN254_ADD_ELF, BN254_MUL_ELF,
-        SECP256K1_ADD_ELF, SECP256K1_MUL_ELF, SECP256R1_ADD_ELF,
+        GRUMPKIN_ADD_ELF, PALLAS_ADD_ELF, SECP256K1_ADD_ELF, SECP256K1_MUL_ELF, SECP256R1_ADD_ELF,
+        VESTA_ADD_ELF,
     };
 
     use crate::{
@@ -548,4 +580,28 @@ mod tests {
         let stdin = MONEROCHANStdin::new();
         run_test::<CpuProver<_, _>>(program, stdin).unwrap();
     }
+
+    #[test]
+    fn test_pallas_add_simple() {
+        setup_logger();
+        let program = Program::from(PALLAS_ADD_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+
+    #[test]
+    fn test_vesta_add_simple() {
+        setup_logger();
+        let program = Program::from(VESTA_ADD_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+
+    #[test]
+    fn test_grumpkin_add_simple() {
+        setup_logger();
+        let program = Program::from(GRUMPKIN_ADD_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
 }
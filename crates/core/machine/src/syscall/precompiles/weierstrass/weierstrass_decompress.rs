@@ -153,6 +153,17 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
         }
     }
 
+    fn column_names(&self) -> Vec<String> {
+        let mut names = WeierstrassDecompressCols::<F, E::BaseField>::column_names();
+        match self.sign_rule {
+            SignChoiceRule::LeastSignificantBit => {}
+            SignChoiceRule::Lexicographic => {
+                names.extend(LexicographicChoiceCols::<F, E::BaseField>::column_names());
+            }
+        }
+        names
+    }
+
     fn generate_trace(
         &self,
         input: &ExecutionRecord,
@@ -166,16 +166,26 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256r1 => "Secp256r1DoubleAssign".to_string(),
             CurveType::Bn254 => "Bn254DoubleAssign".to_string(),
             CurveType::Bls12381 => "Bls12381DoubleAssign".to_string(),
+            CurveType::Pallas => "PallasDoubleAssign".to_string(),
+            CurveType::Vesta => "VestaDoubleAssign".to_string(),
+            CurveType::Grumpkin => "GrumpkinDoubleAssign".to_string(),
             _ => panic!("Unsupported curve"),
         }
     }
 
+    fn column_names(&self) -> Vec<String> {
+        WeierstrassDoubleAssignCols::<F, E::BaseField>::column_names()
+    }
+
     fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
         let events = match E::CURVE_TYPE {
             CurveType::Secp256k1 => &input.get_precompile_events(SyscallCode::SECP256K1_DOUBLE),
             CurveType::Secp256r1 => &input.get_precompile_events(SyscallCode::SECP256R1_DOUBLE),
             CurveType::Bn254 => &input.get_precompile_events(SyscallCode::BN254_DOUBLE),
             CurveType::Bls12381 => &input.get_precompile_events(SyscallCode::BLS12381_DOUBLE),
+            CurveType::Pallas => &input.get_precompile_events(SyscallCode::PALLAS_DOUBLE),
+            CurveType::Vesta => &input.get_precompile_events(SyscallCode::VESTA_DOUBLE),
+            CurveType::Grumpkin => &input.get_precompile_events(SyscallCode::GRUMPKIN_DOUBLE),
             _ => panic!("Unsupported curve"),
         };
 
@@ -191,7 +201,10 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                     PrecompileEvent::Secp256k1Double(event) |
                     PrecompileEvent::Secp256r1Double(event) |
                     PrecompileEvent::Bn254Double(event) |
-                    PrecompileEvent::Bls12381Double(event) => {
+                    PrecompileEvent::Bls12381Double(event) |
+                    PrecompileEvent::PallasDouble(event) |
+                    PrecompileEvent::VestaDouble(event) |
+                    PrecompileEvent::GrumpkinDouble(event) => {
                         let mut row = zeroed_f_vec(num_cols);
                         let cols: &mut WeierstrassDoubleAssignCols<F, E::BaseField> =
                             row.as_mut_slice().borrow_mut();
@@ -219,6 +232,9 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
             CurveType::Secp256r1 => input.get_precompile_events(SyscallCode::SECP256R1_DOUBLE),
             CurveType::Bn254 => input.get_precompile_events(SyscallCode::BN254_DOUBLE),
             CurveType::Bls12381 => input.get_precompile_events(SyscallCode::BLS12381_DOUBLE),
+            CurveType::Pallas => input.get_precompile_events(SyscallCode::PALLAS_DOUBLE),
+            CurveType::Vesta => input.get_precompile_events(SyscallCode::VESTA_DOUBLE),
+            CurveType::Grumpkin => input.get_precompile_events(SyscallCode::GRUMPKIN_DOUBLE),
             _ => panic!("Unsupported curve"),
         };
 
@@ -257,7 +273,10 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                         PrecompileEvent::Secp256k1Double(event) |
                         PrecompileEvent::Secp256r1Double(event) |
                         PrecompileEvent::Bn254Double(event) |
-                        PrecompileEvent::Bls12381Double(event) => {
+                        PrecompileEvent::Bls12381Double(event) |
+                        PrecompileEvent::PallasDouble(event) |
+                        PrecompileEvent::VestaDouble(event) |
+                        PrecompileEvent::GrumpkinDouble(event) => {
                             Self::populate_row(event, cols, &mut new_byte_lookup_events);
                         }
                         _ => unreachable!(),
@@ -289,6 +308,15 @@ impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters> MachineAir<F>
                 CurveType::Bls12381 => {
                     !shard.get_precompile_events(SyscallCode::BLS12381_DOUBLE).is_empty()
                 }
+                CurveType::Pallas => {
+                    !shard.get_precompile_events(SyscallCode::PALLAS_DOUBLE).is_empty()
+                }
+                CurveType::Vesta => {
+                    !shard.get_precompile_events(SyscallCode::VESTA_DOUBLE).is_empty()
+                }
+                CurveType::Grumpkin => {
+                    !shard.get_precompile_events(SyscallCode::GRUMPKIN_DOUBLE).is_empty()
+                }
                 _ => panic!("Unsupported curve"),
             }
         }
@@ -460,6 +488,11 @@ where
             CurveType::Bls12381 => {
                 AB::F::from_canonical_u32(SyscallCode::BLS12381_DOUBLE.syscall_id())
             }
+            CurveType::Pallas => AB::F::from_canonical_u32(SyscallCode::PALLAS_DOUBLE.syscall_id()),
+            CurveType::Vesta => AB::F::from_canonical_u32(SyscallCode::VESTA_DOUBLE.syscall_id()),
+            CurveType::Grumpkin => {
+                AB::F::from_canonical_u32(SyscallCode::GRUMPKIN_DOUBLE.syscall_id())
+            }
             _ => panic!("Unsupported curve"),
         };
 
@@ -480,7 +513,8 @@ pub mod tests {
     use monerochan_core_executor::Program;
     use monerochan_stark::CpuProver;
     use test_artifacts::{
-        BLS12381_DOUBLE_ELF, BN254_DOUBLE_ELF, SECP256K1_DOUBLE_ELF, SECP256R1_DOUBLE_ELF,
+        BLS12381_DOUBLE_ELF, BN254_DOUBLE_ELF, GRUMPKIN_DOUBLE_ELF, PALLAS_DOUBLE_ELF,
+        SECP256K1_DOUBLE_ELF, SECP256R1_DOUBLE_ELF, VESTA_DOUBLE_ELF,
     };
 
     use crate::{
@@ -519,4 +553,28 @@ pub mod tests {
         let stdin = MONEROCHANStdin::new();
         run_test::<CpuProver<_, _>>(program, stdin).unwrap();
     }
+
+    #[test]
+    fn test_pallas_double_simple() {
+        setup_logger();
+        let program = Program::from(PALLAS_DOUBLE_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+
+    #[test]
+    fn test_vesta_double_simple() {
+        setup_logger();
+        let program = Program::from(VESTA_DOUBLE_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+
+    #[test]
+    fn test_grumpkin_double_simple() {
+        setup_logger();
+        let program = Program::from(GRUMPKIN_DOUBLE_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
 }
@@ -0,0 +1,725 @@
+//! Columns shared by the ECDSA precompiles.
+//!
+//! Both precompiles compute `[u1] G + [u2] Q` with a double-and-add ladder spanning
+//! [`ECDSA_NUM_BITS`] rows, one per bit of the scalars from the most significant one. The
+//! accumulator starts at [`EcdsaParameters::ecdsa_offset`], so that it never meets the points
+//! added to it for honest inputs, and the offset is removed after the last step. The affine
+//! addition formulas are exceptional when the points share their x-coordinate; the ladder then
+//! stops adding and reports the failure instead of computing a wrong point.
+
+use num::{BigUint, One, Zero};
+use p3_air::AirBuilder;
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_maybe_rayon::prelude::{IntoParallelRefIterator, ParallelBridge, ParallelIterator};
+use monerochan_core_executor::events::{
+    ByteLookupEvent, ByteRecord, EcdsaEvent, FieldOperation, MemoryReadRecord, MemoryWriteRecord,
+    ECDSA_NUM_BITS, ECDSA_WORDS,
+};
+use monerochan_curves::{
+    params::{FieldParameters, Limbs},
+    weierstrass::EcdsaParameters,
+};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::air::{BaseAirBuilder, MONEROCHANAirBuilder, Polynomial};
+
+use crate::{
+    operations::{
+        field::{field_op::FieldOpCols, range::FieldLtCols},
+        IsZeroOperation,
+    },
+    utils::zeroed_f_vec,
+};
+
+/// A set of columns to double a point with the affine formulas.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EcDoubleCols<T, P: FieldParameters> {
+    pub(crate) slope_denominator: FieldOpCols<T, P>,
+    pub(crate) slope_numerator: FieldOpCols<T, P>,
+    pub(crate) slope: FieldOpCols<T, P>,
+    pub(crate) p_x_squared: FieldOpCols<T, P>,
+    pub(crate) p_x_squared_times_3: FieldOpCols<T, P>,
+    pub(crate) slope_squared: FieldOpCols<T, P>,
+    pub(crate) p_x_plus_p_x: FieldOpCols<T, P>,
+    pub(crate) x3_ins: FieldOpCols<T, P>,
+    pub(crate) p_x_minus_x: FieldOpCols<T, P>,
+    pub(crate) y3_ins: FieldOpCols<T, P>,
+    pub(crate) slope_times_p_x_minus_x: FieldOpCols<T, P>,
+}
+
+/// A set of columns to add two points with the affine formulas.
+///
+/// The addition is exceptional when the points have the same x-coordinate. `is_exceptional` is
+/// then set and the result columns do not hold the sum.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EcAddCols<T, P: FieldParameters> {
+    pub is_exceptional: T,
+    pub(crate) slope_numerator: FieldOpCols<T, P>,
+    pub(crate) slope_denominator: FieldOpCols<T, P>,
+    pub(crate) slope_denominator_inverse: FieldOpCols<T, P>,
+    pub(crate) slope: FieldOpCols<T, P>,
+    pub(crate) slope_squared: FieldOpCols<T, P>,
+    pub(crate) p_x_plus_q_x: FieldOpCols<T, P>,
+    pub(crate) x3_ins: FieldOpCols<T, P>,
+    pub(crate) p_x_minus_x: FieldOpCols<T, P>,
+    pub(crate) y3_ins: FieldOpCols<T, P>,
+    pub(crate) slope_times_p_x_minus_x: FieldOpCols<T, P>,
+}
+
+/// A set of columns for one step of the double-and-add ladder of the ECDSA precompiles.
+///
+/// The row with index `i` doubles the accumulator and adds `G` and `Q` for bit `255 - i` of `u1`
+/// and `u2`. The bits are stored shifted: `u1_bits[255]` is the current bit of `u1`, and every
+/// row shifts the bits up by one for the next row. The columns of the final removal of the offset
+/// are only meaningful on the last row of the event.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct EcdsaLadderCols<T, P: FieldParameters> {
+    pub is_real: T,
+    pub shard: T,
+    pub clk: T,
+    pub out_ptr: T,
+    pub in_ptr: T,
+    /// The index of the row within the event, from `0` to `ECDSA_NUM_BITS - 1`.
+    pub index: T,
+    pub is_first: IsZeroOperation<T>,
+    pub is_last: IsZeroOperation<T>,
+    pub is_first_real: T,
+    pub is_last_real: T,
+    pub q_x: Limbs<T, P::Limbs>,
+    pub q_y: Limbs<T, P::Limbs>,
+    pub acc_x: Limbs<T, P::Limbs>,
+    pub acc_y: Limbs<T, P::Limbs>,
+    /// Whether an exceptional addition was met in a previous step.
+    pub failed: T,
+    pub u1_bits: [T; ECDSA_NUM_BITS],
+    pub u2_bits: [T; ECDSA_NUM_BITS],
+    pub(crate) double: EcDoubleCols<T, P>,
+    pub(crate) add_g: EcAddCols<T, P>,
+    pub take_g: T,
+    pub failed_g: T,
+    pub sum_x: Limbs<T, P::Limbs>,
+    pub sum_y: Limbs<T, P::Limbs>,
+    pub(crate) add_q: EcAddCols<T, P>,
+    pub take_q: T,
+    pub failed_q: T,
+    pub out_x: Limbs<T, P::Limbs>,
+    pub out_y: Limbs<T, P::Limbs>,
+    pub(crate) remove_offset: EcAddCols<T, P>,
+    /// Whether the ladder failed, including at the removal of the offset.
+    pub failed_final: T,
+    pub(crate) key_x_range: FieldLtCols<T, P>,
+    pub(crate) key_y_range: FieldLtCols<T, P>,
+}
+
+/// The values of a step of the ladder needed by the next one and by the last row.
+pub struct EcdsaLadderStep {
+    pub acc: (BigUint, BigUint),
+    pub failed: bool,
+    /// The result of the removal of the offset, which is only the point `[u1] G + [u2] Q` if
+    /// `failed_final` is not set.
+    pub key: (BigUint, BigUint),
+    pub failed_final: bool,
+}
+
+impl<F: PrimeField32, P: FieldParameters> EcDoubleCols<F, P> {
+    /// Populates the columns to double `(x, y)` and returns the result.
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        x: &BigUint,
+        y: &BigUint,
+        a: &BigUint,
+    ) -> (BigUint, BigUint) {
+        // slope = (a + 3 x^2) / (2 y).
+        let p_x_squared = self.p_x_squared.populate(record, x, x, FieldOperation::Mul);
+        let p_x_squared_times_3 = self.p_x_squared_times_3.populate(
+            record,
+            &p_x_squared,
+            &BigUint::from(3u32),
+            FieldOperation::Mul,
+        );
+        let slope_numerator =
+            self.slope_numerator.populate(record, a, &p_x_squared_times_3, FieldOperation::Add);
+        let slope_denominator =
+            self.slope_denominator.populate(record, &BigUint::from(2u32), y, FieldOperation::Mul);
+        let slope =
+            self.slope.populate(record, &slope_numerator, &slope_denominator, FieldOperation::Div);
+
+        // x3 = slope^2 - 2 x.
+        let slope_squared =
+            self.slope_squared.populate(record, &slope, &slope, FieldOperation::Mul);
+        let p_x_plus_p_x = self.p_x_plus_p_x.populate(record, x, x, FieldOperation::Add);
+        let x3 = self.x3_ins.populate(record, &slope_squared, &p_x_plus_p_x, FieldOperation::Sub);
+
+        // y3 = slope * (x - x3) - y.
+        let p_x_minus_x = self.p_x_minus_x.populate(record, x, &x3, FieldOperation::Sub);
+        let slope_times_p_x_minus_x = self.slope_times_p_x_minus_x.populate(
+            record,
+            &slope,
+            &p_x_minus_x,
+            FieldOperation::Mul,
+        );
+        let y3 = self.y3_ins.populate(record, &slope_times_p_x_minus_x, y, FieldOperation::Sub);
+
+        (x3, y3)
+    }
+}
+
+impl<V: Copy, P: FieldParameters> EcDoubleCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    /// Constrains the result columns to be the double of `(x, y)`.
+    pub fn eval<AB: MONEROCHANAirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        x: &Limbs<V, P::Limbs>,
+        y: &Limbs<V, P::Limbs>,
+        a: &BigUint,
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        self.p_x_squared.eval(builder, x, x, FieldOperation::Mul, is_real.clone());
+        self.p_x_squared_times_3.eval(
+            builder,
+            &self.p_x_squared.result,
+            &P::to_limbs_field::<AB::Expr, _>(&BigUint::from(3u32)),
+            FieldOperation::Mul,
+            is_real.clone(),
+        );
+        self.slope_numerator.eval(
+            builder,
+            &P::to_limbs_field::<AB::Expr, _>(a),
+            &self.p_x_squared_times_3.result,
+            FieldOperation::Add,
+            is_real.clone(),
+        );
+        self.slope_denominator.eval(
+            builder,
+            &P::to_limbs_field::<AB::Expr, _>(&BigUint::from(2u32)),
+            y,
+            FieldOperation::Mul,
+            is_real.clone(),
+        );
+        self.slope.eval(
+            builder,
+            &self.slope_numerator.result,
+            &self.slope_denominator.result,
+            FieldOperation::Div,
+            is_real.clone(),
+        );
+
+        let slope = &self.slope.result;
+        self.slope_squared.eval(builder, slope, slope, FieldOperation::Mul, is_real.clone());
+        self.p_x_plus_p_x.eval(builder, x, x, FieldOperation::Add, is_real.clone());
+        self.x3_ins.eval(
+            builder,
+            &self.slope_squared.result,
+            &self.p_x_plus_p_x.result,
+            FieldOperation::Sub,
+            is_real.clone(),
+        );
+
+        self.p_x_minus_x.eval(
+            builder,
+            x,
+            &self.x3_ins.result,
+            FieldOperation::Sub,
+            is_real.clone(),
+        );
+        self.slope_times_p_x_minus_x.eval(
+            builder,
+            slope,
+            &self.p_x_minus_x.result,
+            FieldOperation::Mul,
+            is_real.clone(),
+        );
+        self.y3_ins.eval(
+            builder,
+            &self.slope_times_p_x_minus_x.result,
+            y,
+            FieldOperation::Sub,
+            is_real,
+        );
+    }
+}
+
+impl<F: PrimeField32, P: FieldParameters> EcAddCols<F, P> {
+    /// Populates the columns to add `(p_x, p_y)` and `(q_x, q_y)` and returns the result of the
+    /// formulas, which is not the sum if the addition is exceptional.
+    pub fn populate(
+        &mut self,
+        record: &mut impl ByteRecord,
+        p_x: &BigUint,
+        p_y: &BigUint,
+        q_x: &BigUint,
+        q_y: &BigUint,
+    ) -> (BigUint, BigUint) {
+        // slope = (q.y - p.y) / (q.x - p.x), with the inverse of zero replaced by zero.
+        let slope_numerator = self.slope_numerator.populate(record, q_y, p_y, FieldOperation::Sub);
+        let slope_denominator =
+            self.slope_denominator.populate(record, q_x, p_x, FieldOperation::Sub);
+        let is_exceptional = slope_denominator.is_zero();
+        self.is_exceptional = F::from_bool(is_exceptional);
+        let op = if is_exceptional { FieldOperation::Mul } else { FieldOperation::Div };
+        let slope_denominator_inverse = self.slope_denominator_inverse.populate(
+            record,
+            &BigUint::one(),
+            &slope_denominator,
+            op,
+        );
+        let slope = self.slope.populate(
+            record,
+            &slope_numerator,
+            &slope_denominator_inverse,
+            FieldOperation::Mul,
+        );
+
+        // x3 = slope^2 - (p.x + q.x).
+        let slope_squared =
+            self.slope_squared.populate(record, &slope, &slope, FieldOperation::Mul);
+        let p_x_plus_q_x = self.p_x_plus_q_x.populate(record, p_x, q_x, FieldOperation::Add);
+        let x3 = self.x3_ins.populate(record, &slope_squared, &p_x_plus_q_x, FieldOperation::Sub);
+
+        // y3 = slope * (p.x - x3) - p.y.
+        let p_x_minus_x = self.p_x_minus_x.populate(record, p_x, &x3, FieldOperation::Sub);
+        let slope_times_p_x_minus_x = self.slope_times_p_x_minus_x.populate(
+            record,
+            &slope,
+            &p_x_minus_x,
+            FieldOperation::Mul,
+        );
+        let y3 = self.y3_ins.populate(record, &slope_times_p_x_minus_x, p_y, FieldOperation::Sub);
+
+        (x3, y3)
+    }
+}
+
+impl<V: Copy, P: FieldParameters> EcAddCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    /// Constrains the result columns to be the sum of `(p_x, p_y)` and `(q_x, q_y)` unless
+    /// `is_exceptional` is set, in which case the points have the same x-coordinate.
+    pub fn eval<AB: MONEROCHANAirBuilder<Var = V>>(
+        &self,
+        builder: &mut AB,
+        p_x: &Limbs<V, P::Limbs>,
+        p_y: &Limbs<V, P::Limbs>,
+        q_x: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        q_y: &(impl Into<Polynomial<AB::Expr>> + Clone),
+        is_real: impl Into<AB::Expr> + Clone,
+    ) where
+        V: Into<AB::Expr>,
+    {
+        self.slope_numerator.eval(builder, q_y, p_y, FieldOperation::Sub, is_real.clone());
+        self.slope_denominator.eval(builder, q_x, p_x, FieldOperation::Sub, is_real.clone());
+
+        // The inverse of the denominator is constrained unless it is zero, which is exactly when
+        // the addition is exceptional.
+        builder.assert_bool(self.is_exceptional);
+        builder.when(self.is_exceptional).assert_all_zero(self.slope_denominator.result);
+        let one = P::to_limbs_field::<AB::Expr, _>(&BigUint::one());
+        let modulus = Polynomial::from_iter(P::modulus_field_iter::<AB::F>().map(AB::Expr::from));
+        self.slope_denominator_inverse.eval_variable(
+            builder,
+            &one,
+            &self.slope_denominator.result,
+            &modulus,
+            AB::Expr::zero(),
+            AB::Expr::zero(),
+            self.is_exceptional,
+            AB::Expr::one() - self.is_exceptional,
+            is_real.clone(),
+        );
+        self.slope.eval(
+            builder,
+            &self.slope_numerator.result,
+            &self.slope_denominator_inverse.result,
+            FieldOperation::Mul,
+            is_real.clone(),
+        );
+
+        let slope = &self.slope.result;
+        self.slope_squared.eval(builder, slope, slope, FieldOperation::Mul, is_real.clone());
+        self.p_x_plus_q_x.eval(builder, p_x, q_x, FieldOperation::Add, is_real.clone());
+        self.x3_ins.eval(
+            builder,
+            &self.slope_squared.result,
+            &self.p_x_plus_q_x.result,
+            FieldOperation::Sub,
+            is_real.clone(),
+        );
+
+        self.p_x_minus_x.eval(
+            builder,
+            p_x,
+            &self.x3_ins.result,
+            FieldOperation::Sub,
+            is_real.clone(),
+        );
+        self.slope_times_p_x_minus_x.eval(
+            builder,
+            slope,
+            &self.p_x_minus_x.result,
+            FieldOperation::Mul,
+            is_real.clone(),
+        );
+        self.y3_ins.eval(
+            builder,
+            &self.slope_times_p_x_minus_x.result,
+            p_y,
+            FieldOperation::Sub,
+            is_real,
+        );
+    }
+}
+
+impl<F: PrimeField32, P: FieldParameters> EcdsaLadderCols<F, P> {
+    /// Populates the columns locating the row `index` of an event.
+    pub fn populate_control(&mut self, index: usize, is_real: bool) {
+        self.is_real = F::from_bool(is_real);
+        self.index = F::from_canonical_usize(index);
+        let is_first = self.is_first.populate(index as u32) == 1;
+        let is_last = self
+            .is_last
+            .populate_from_field_element(self.index - F::from_canonical_usize(ECDSA_NUM_BITS - 1)) ==
+            1;
+        self.is_first_real = F::from_bool(is_real && is_first);
+        self.is_last_real = F::from_bool(is_real && is_last);
+    }
+
+    /// Populates the step `index` of the ladder computing `[u1] G + [u2] Q` from the accumulator
+    /// `acc` and the failure flag of the previous step.
+    ///
+    /// The byte lookups of the removal of the offset are added to `last_record`, since they are
+    /// only needed on the last row.
+    #[allow(clippy::too_many_arguments)]
+    pub fn populate_ladder<E: EcdsaParameters<BaseField = P>>(
+        &mut self,
+        record: &mut impl ByteRecord,
+        last_record: &mut impl ByteRecord,
+        index: usize,
+        u1: &BigUint,
+        u2: &BigUint,
+        q: &(BigUint, BigUint),
+        acc: &(BigUint, BigUint),
+        failed: bool,
+    ) -> EcdsaLadderStep {
+        let modulus = P::modulus();
+        let bit = (ECDSA_NUM_BITS - 1 - index) as u64;
+        for j in index..ECDSA_NUM_BITS {
+            let shifted = (j - index) as u64;
+            self.u1_bits[j] = F::from_bool(u1.bit(shifted));
+            self.u2_bits[j] = F::from_bool(u2.bit(shifted));
+        }
+        self.q_x = P::to_limbs_field(&q.0);
+        self.q_y = P::to_limbs_field(&q.1);
+        self.acc_x = P::to_limbs_field(&acc.0);
+        self.acc_y = P::to_limbs_field(&acc.1);
+        self.failed = F::from_bool(failed);
+
+        let double = self.double.populate(record, &acc.0, &acc.1, &E::a_int());
+
+        // Add `G` if the bit of `u1` is set.
+        let (g_x, g_y) = E::generator();
+        let sum_g = self.add_g.populate(record, &double.0, &double.1, &g_x, &g_y);
+        let bit_g = u1.bit(bit);
+        let exceptional_g = self.add_g.is_exceptional == F::one();
+        let take_g = bit_g && !exceptional_g && !failed;
+        let failed_g = failed || (bit_g && exceptional_g);
+        self.take_g = F::from_bool(take_g);
+        self.failed_g = F::from_bool(failed_g);
+        let sum = if take_g { sum_g } else { double };
+        self.sum_x = P::to_limbs_field(&sum.0);
+        self.sum_y = P::to_limbs_field(&sum.1);
+
+        // Add `Q` if the bit of `u2` is set.
+        let sum_q = self.add_q.populate(record, &sum.0, &sum.1, &q.0, &q.1);
+        let bit_q = u2.bit(bit);
+        let exceptional_q = self.add_q.is_exceptional == F::one();
+        let take_q = bit_q && !exceptional_q && !failed_g;
+        let failed_q = failed_g || (bit_q && exceptional_q);
+        self.take_q = F::from_bool(take_q);
+        self.failed_q = F::from_bool(failed_q);
+        let out = if take_q { sum_q } else { sum };
+        self.out_x = P::to_limbs_field(&out.0);
+        self.out_y = P::to_limbs_field(&out.1);
+
+        // Remove the offset.
+        let (c_x, c_y) = E::ecdsa_offset_correction();
+        let key = self.remove_offset.populate(last_record, &out.0, &out.1, &c_x, &c_y);
+        let failed_final = failed_q || self.remove_offset.is_exceptional == F::one();
+        self.failed_final = F::from_bool(failed_final);
+        self.key_x_range.populate(last_record, &key.0, &modulus);
+        self.key_y_range.populate(last_record, &key.1, &modulus);
+
+        EcdsaLadderStep { acc: out, failed: failed_q, key, failed_final }
+    }
+}
+
+impl<V: Copy, P: FieldParameters> EcdsaLadderCols<V, P>
+where
+    Limbs<V, P::Limbs>: Copy,
+{
+    /// Constrains the rows of every event to be indexed from `0` to `ECDSA_NUM_BITS - 1` and the
+    /// values of the event to be the same on all its rows.
+    ///
+    /// Padding rows are also grouped in events, and must come after the real ones.
+    pub fn eval_control<AB: MONEROCHANAirBuilder<Var = V>>(&self, builder: &mut AB, next: &Self)
+    where
+        V: Into<AB::Expr>,
+    {
+        builder.assert_bool(self.is_real);
+        IsZeroOperation::<AB::F>::eval(builder, self.index.into(), self.is_first, AB::Expr::one());
+        IsZeroOperation::<AB::F>::eval(
+            builder,
+            self.index.into() - AB::Expr::from_canonical_usize(ECDSA_NUM_BITS - 1),
+            self.is_last,
+            AB::Expr::one(),
+        );
+        builder.assert_eq(self.is_first_real, self.is_real * self.is_first.result);
+        builder.assert_eq(self.is_last_real, self.is_real * self.is_last.result);
+
+        builder.when_first_row().assert_zero(self.index);
+        builder
+            .when_transition()
+            .when_not(self.is_last.result)
+            .assert_eq(next.index, self.index + AB::Expr::one());
+        builder.when_transition().when(self.is_last.result).assert_zero(next.index);
+        builder.when_last_row().when(self.is_real).assert_one(self.is_last.result);
+
+        builder
+            .when_transition()
+            .when_not(self.is_last.result)
+            .assert_eq(next.is_real, self.is_real);
+        builder.when_transition().when_not(self.is_real).assert_zero(next.is_real);
+
+        let values = [
+            (self.shard, next.shard),
+            (self.clk, next.clk),
+            (self.out_ptr, next.out_ptr),
+            (self.in_ptr, next.in_ptr),
+        ];
+        let limbs = self.q_x.into_iter().zip(next.q_x).chain(self.q_y.into_iter().zip(next.q_y));
+        for (local, next) in values.into_iter().chain(limbs) {
+            builder
+                .when_transition()
+                .when(self.is_real)
+                .when_not(self.is_last.result)
+                .assert_eq(local, next);
+        }
+    }
+
+    /// Constrains the ladder computing `[u1] G + [u2] Q`, where `u1` and `u2` are given as bytes
+    /// and `Q` is the point in `q_x` and `q_y`.
+    ///
+    /// The result is in the columns of `remove_offset` on the last row, and is the point
+    /// `[u1] G + [u2] Q` unless `failed_final` is set.
+    pub fn eval_ladder<AB: MONEROCHANAirBuilder<Var = V>, E: EcdsaParameters<BaseField = P>>(
+        &self,
+        builder: &mut AB,
+        next: &Self,
+        u1: &[impl Into<AB::Expr> + Copy],
+        u2: &[impl Into<AB::Expr> + Copy],
+    ) where
+        V: Into<AB::Expr>,
+    {
+        let limbs = |x: &BigUint| P::to_limbs_field::<AB::Expr, AB::F>(x);
+        let (g_x, g_y) = E::generator();
+        let (g_x, g_y) = (limbs(&g_x), limbs(&g_y));
+        let (o_x, o_y) = E::ecdsa_offset();
+        let (c_x, c_y) = E::ecdsa_offset_correction();
+
+        // The ladder starts from the offset, and the bits are those of the scalars.
+        builder.when(self.is_first_real).assert_all_eq(self.acc_x, limbs(&o_x));
+        builder.when(self.is_first_real).assert_all_eq(self.acc_y, limbs(&o_y));
+        builder.when(self.is_first_real).assert_zero(self.failed);
+        for (scalar, bits) in [(u1, &self.u1_bits), (u2, &self.u2_bits)] {
+            for (byte, bits) in scalar.iter().zip(bits.chunks_exact(8)) {
+                let value = bits
+                    .iter()
+                    .enumerate()
+                    .map(|(k, bit)| (*bit).into() * AB::F::from_canonical_u32(1 << k))
+                    .sum::<AB::Expr>();
+                builder.when(self.is_first_real).assert_eq(*byte, value);
+            }
+        }
+        for bit in self.u1_bits.iter().chain(self.u2_bits.iter()) {
+            builder.assert_bool(*bit);
+        }
+
+        // Double the accumulator.
+        self.double.eval(builder, &self.acc_x, &self.acc_y, &E::a_int(), self.is_real);
+        let (double_x, double_y) = (&self.double.x3_ins.result, &self.double.y3_ins.result);
+
+        // Add `G` if the bit of `u1` is set and the ladder has not failed.
+        let bit_g = self.u1_bits[ECDSA_NUM_BITS - 1];
+        let exceptional_g = self.add_g.is_exceptional;
+        self.add_g.eval(builder, double_x, double_y, &g_x, &g_y, self.is_real);
+        builder.assert_eq(
+            self.take_g,
+            bit_g * (AB::Expr::one() - exceptional_g) * (AB::Expr::one() - self.failed),
+        );
+        builder.assert_eq(
+            self.failed_g,
+            self.failed + bit_g * exceptional_g * (AB::Expr::one() - self.failed),
+        );
+        for i in 0..P::NB_LIMBS {
+            builder.assert_eq(
+                self.sum_x[i],
+                double_x[i] + self.take_g * (self.add_g.x3_ins.result[i] - double_x[i]),
+            );
+            builder.assert_eq(
+                self.sum_y[i],
+                double_y[i] + self.take_g * (self.add_g.y3_ins.result[i] - double_y[i]),
+            );
+        }
+
+        // Add `Q` if the bit of `u2` is set and the ladder has not failed.
+        let bit_q = self.u2_bits[ECDSA_NUM_BITS - 1];
+        let exceptional_q = self.add_q.is_exceptional;
+        self.add_q.eval(builder, &self.sum_x, &self.sum_y, &self.q_x, &self.q_y, self.is_real);
+        builder.assert_eq(
+            self.take_q,
+            bit_q * (AB::Expr::one() - exceptional_q) * (AB::Expr::one() - self.failed_g),
+        );
+        builder.assert_eq(
+            self.failed_q,
+            self.failed_g + bit_q * exceptional_q * (AB::Expr::one() - self.failed_g),
+        );
+        for i in 0..P::NB_LIMBS {
+            builder.assert_eq(
+                self.out_x[i],
+                self.sum_x[i] + self.take_q * (self.add_q.x3_ins.result[i] - self.sum_x[i]),
+            );
+            builder.assert_eq(
+                self.out_y[i],
+                self.sum_y[i] + self.take_q * (self.add_q.y3_ins.result[i] - self.sum_y[i]),
+            );
+        }
+
+        // The next step starts from the output of this one, with the bits shifted.
+        for i in 0..P::NB_LIMBS {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(self.is_real);
+            let mut builder = builder.when_not(self.is_last.result);
+            builder.assert_eq(next.acc_x[i], self.out_x[i]);
+            builder.assert_eq(next.acc_y[i], self.out_y[i]);
+        }
+        builder
+            .when_transition()
+            .when(self.is_real)
+            .when_not(self.is_last.result)
+            .assert_eq(next.failed, self.failed_q);
+        for i in 0..ECDSA_NUM_BITS - 1 {
+            let mut builder = builder.when_transition();
+            let mut builder = builder.when(self.is_real);
+            let mut builder = builder.when_not(self.is_last.result);
+            builder.assert_eq(next.u1_bits[i + 1], self.u1_bits[i]);
+            builder.assert_eq(next.u2_bits[i + 1], self.u2_bits[i]);
+        }
+
+        // Remove the offset on the last row.
+        self.remove_offset.eval(
+            builder,
+            &self.out_x,
+            &self.out_y,
+            &limbs(&c_x),
+            &limbs(&c_y),
+            self.is_last_real,
+        );
+        let exceptional_offset = self.remove_offset.is_exceptional;
+        builder.assert_eq(
+            self.failed_final,
+            self.failed_q + exceptional_offset - self.failed_q * exceptional_offset,
+        );
+        let modulus = limbs(&P::modulus());
+        self.key_x_range.eval(
+            builder,
+            &self.remove_offset.x3_ins.result,
+            &modulus,
+            self.is_last_real,
+        );
+        self.key_y_range.eval(
+            builder,
+            &self.remove_offset.y3_ins.result,
+            &modulus,
+            self.is_last_real,
+        );
+    }
+}
+
+/// Returns the words of a field element or a scalar, as laid out in the input of the syscalls.
+pub(crate) fn ecdsa_words(value: &BigUint) -> Vec<u32> {
+    let mut words = value.to_u32_digits();
+    words.resize(ECDSA_WORDS, 0);
+    words
+}
+
+/// Returns an event with the given input and output, whose memory records are only meant to
+/// populate padding rows.
+pub(crate) fn dummy_ecdsa_event(input: Vec<u32>, output: Vec<u32>) -> EcdsaEvent {
+    let input_memory_records = input
+        .iter()
+        .map(|&value| MemoryReadRecord { value, timestamp: 1, ..Default::default() })
+        .collect();
+    let output_memory_records = output
+        .iter()
+        .map(|&value| MemoryWriteRecord {
+            value,
+            timestamp: 1,
+            prev_value: value,
+            ..Default::default()
+        })
+        .collect();
+    EcdsaEvent { input, input_memory_records, output, output_memory_records, ..Default::default() }
+}
+
+/// Returns the byte lookups of the rows of the events, populated with `populate_event`.
+pub(crate) fn ecdsa_byte_lookups<F: PrimeField32>(
+    events: &[&EcdsaEvent],
+    num_cols: usize,
+    populate_event: impl Fn(&EcdsaEvent, &mut [F], &mut Vec<ByteLookupEvent>, bool) + Sync,
+) -> Vec<Vec<ByteLookupEvent>> {
+    events
+        .par_iter()
+        .map(|event| {
+            let mut blu = Vec::new();
+            let mut rows = zeroed_f_vec(num_cols * ECDSA_NUM_BITS);
+            populate_event(event, &mut rows, &mut blu, true);
+            blu
+        })
+        .collect()
+}
+
+/// Generates the trace of the events with `populate_event`, which fills the rows of an event and
+/// marks them as real or not.
+///
+/// The padding rows are the rows of `dummy`, so that every constraint holds on them.
+pub(crate) fn generate_ecdsa_trace<F: PrimeField32>(
+    events: &[&EcdsaEvent],
+    dummy: &EcdsaEvent,
+    num_cols: usize,
+    num_rows: usize,
+    populate_event: impl Fn(&EcdsaEvent, &mut [F], &mut Vec<ByteLookupEvent>, bool) + Sync,
+) -> RowMajorMatrix<F> {
+    let event_size = num_cols * ECDSA_NUM_BITS;
+    let mut dummy_rows = zeroed_f_vec(event_size);
+    populate_event(dummy, &mut dummy_rows, &mut Vec::new(), false);
+
+    let mut values = zeroed_f_vec(num_rows * num_cols);
+    values.chunks_mut(event_size).enumerate().par_bridge().for_each(|(i, rows)| {
+        if let Some(event) = events.get(i) {
+            populate_event(event, rows, &mut Vec::new(), true);
+        } else {
+            rows.copy_from_slice(&dummy_rows[..rows.len()]);
+        }
+    });
+
+    RowMajorMatrix::new(values, num_cols)
+}
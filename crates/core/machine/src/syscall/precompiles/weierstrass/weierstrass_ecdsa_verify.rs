@@ -0,0 +1,473 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use std::marker::PhantomData;
+
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use monerochan_core_executor::{
+    events::{
+        ecdsa_verify_output, ByteLookupEvent, EcdsaEvent, FieldOperation, PrecompileEvent,
+        ECDSA_NUM_BITS, ECDSA_VERIFY_INPUT_WORDS, ECDSA_VERIFY_OUTPUT_WORDS, ECDSA_WORDS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use monerochan_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::EcdsaParameters,
+    CurveType, EllipticCurve,
+};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::air::{
+    BaseAirBuilder, InteractionScope, MONEROCHANAirBuilder, MachineAir, Polynomial,
+};
+
+use super::weierstrass_ecdsa::{
+    dummy_ecdsa_event, ecdsa_byte_lookups, ecdsa_words, generate_ecdsa_trace, EcdsaLadderCols,
+};
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::{
+        field_inner_product::FieldInnerProductCols, field_op::FieldOpCols, range::FieldLtCols,
+    },
+    utils::limbs_from_access,
+};
+
+pub const fn num_weierstrass_ecdsa_verify_cols<
+    P: FieldParameters + NumWords,
+    S: FieldParameters + NumWords,
+>() -> usize {
+    size_of::<WeierstrassEcdsaVerifyCols<u8, P, S>>()
+}
+
+/// A set of columns to verify an ECDSA signature on a Weierstrass curve.
+///
+/// Each event spans [`ECDSA_NUM_BITS`] rows. The input is read and the public key `P` is checked
+/// to be on the curve on the first row, the ladder computes `[z / s] G + [r / s] P` over all rows,
+/// and its x-coordinate is compared to `r` and the status written on the last row. The columns
+/// other than the ladder are the same on every row of the event.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WeierstrassEcdsaVerifyCols<
+    T,
+    P: FieldParameters + NumWords,
+    S: FieldParameters + NumWords,
+> {
+    pub ladder: EcdsaLadderCols<T, P>,
+    pub input_access: [MemoryReadCols<T>; ECDSA_VERIFY_INPUT_WORDS],
+    pub output_access: [MemoryWriteCols<T>; ECDSA_VERIFY_OUTPUT_WORDS],
+    pub(crate) r_range: FieldLtCols<T, S>,
+    pub(crate) s_range: FieldLtCols<T, S>,
+    pub(crate) s_inverse: FieldOpCols<T, S>,
+    pub(crate) u1: FieldOpCols<T, S>,
+    pub(crate) u2: FieldOpCols<T, S>,
+    pub(crate) u1_range: FieldLtCols<T, S>,
+    pub(crate) u2_range: FieldLtCols<T, S>,
+    pub(crate) key_x_range: FieldLtCols<T, P>,
+    pub(crate) key_y_range: FieldLtCols<T, P>,
+    pub(crate) x_2: FieldOpCols<T, P>,
+    pub(crate) x_3: FieldOpCols<T, P>,
+    pub(crate) ax_plus_b: FieldInnerProductCols<T, P>,
+    pub(crate) x_3_plus_b_plus_ax: FieldOpCols<T, P>,
+    pub(crate) y_2: FieldOpCols<T, P>,
+    pub(crate) x_minus_r: FieldOpCols<T, S>,
+    pub(crate) x_minus_r_inverse: FieldOpCols<T, S>,
+    /// Whether the x-coordinate of the result of the ladder is `r` modulo the group order.
+    pub is_x_eq_r: T,
+    /// Whether the signature is valid.
+    pub is_valid: T,
+}
+
+#[derive(Default)]
+pub struct WeierstrassEcdsaVerifyChip<E> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: EllipticCurve + EcdsaParameters> WeierstrassEcdsaVerifyChip<E> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn syscall_code() -> SyscallCode {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => SyscallCode::SECP256K1_ECDSA_VERIFY,
+            CurveType::Secp256r1 => SyscallCode::SECP256R1_ECDSA_VERIFY,
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn events(input: &ExecutionRecord) -> Vec<&EcdsaEvent> {
+        input
+            .get_precompile_events(Self::syscall_code())
+            .iter()
+            .map(|(_, event)| match event {
+                PrecompileEvent::Secp256k1EcdsaVerify(event) |
+                PrecompileEvent::Secp256r1EcdsaVerify(event) => event,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    /// An event verifying `r = 1` and `s = 1` for the message `z = 0` and the generator as the
+    /// public key.
+    fn dummy_event() -> EcdsaEvent {
+        let (g_x, g_y) = E::generator();
+        let mut input = vec![0; ECDSA_VERIFY_INPUT_WORDS];
+        input[..ECDSA_WORDS].copy_from_slice(&ecdsa_words(&g_x));
+        input[ECDSA_WORDS..2 * ECDSA_WORDS].copy_from_slice(&ecdsa_words(&g_y));
+        input[3 * ECDSA_WORDS] = 1;
+        input[4 * ECDSA_WORDS] = 1;
+        let output = ecdsa_verify_output::<E>(&input);
+        dummy_ecdsa_event(input, output)
+    }
+
+    /// Populates the columns computed from the input and returns `u1`, `u2` and `Q`.
+    fn populate_input<F: PrimeField32>(
+        cols: &mut WeierstrassEcdsaVerifyCols<F, E::BaseField, E::ScalarField>,
+        input: &[u32],
+        blu: &mut Vec<ByteLookupEvent>,
+    ) -> (BigUint, BigUint, (BigUint, BigUint)) {
+        let modulus = E::BaseField::modulus();
+        let order = E::ScalarField::modulus();
+        let key_x = BigUint::from_slice(&input[..ECDSA_WORDS]);
+        let key_y = BigUint::from_slice(&input[ECDSA_WORDS..2 * ECDSA_WORDS]);
+        let z = BigUint::from_slice(&input[2 * ECDSA_WORDS..3 * ECDSA_WORDS]);
+        let r = BigUint::from_slice(&input[3 * ECDSA_WORDS..4 * ECDSA_WORDS]);
+        let s = BigUint::from_slice(&input[4 * ECDSA_WORDS..5 * ECDSA_WORDS]);
+
+        // u1 = z / s and u2 = r / s modulo the group order.
+        cols.r_range.populate(blu, &r, &order);
+        cols.s_range.populate(blu, &s, &order);
+        let s_inverse = cols.s_inverse.populate(blu, &BigUint::one(), &s, FieldOperation::Div);
+        let u1 = cols.u1.populate(blu, &z, &s_inverse, FieldOperation::Mul);
+        let u2 = cols.u2.populate(blu, &r, &s_inverse, FieldOperation::Mul);
+        cols.u1_range.populate(blu, &u1, &order);
+        cols.u2_range.populate(blu, &u2, &order);
+
+        // The public key is reduced and y^2 = x^3 + a * x + b.
+        cols.key_x_range.populate(blu, &key_x, &modulus);
+        cols.key_y_range.populate(blu, &key_y, &modulus);
+        let x_2 = cols.x_2.populate(blu, &key_x, &key_x, FieldOperation::Mul);
+        let x_3 = cols.x_3.populate(blu, &x_2, &key_x, FieldOperation::Mul);
+        let ax_plus_b = cols.ax_plus_b.populate(
+            blu,
+            &[E::a_int(), E::b_int()],
+            &[key_x.clone(), BigUint::one()],
+        );
+        cols.x_3_plus_b_plus_ax.populate(blu, &x_3, &ax_plus_b, FieldOperation::Add);
+        cols.y_2.populate(blu, &key_y, &key_y, FieldOperation::Mul);
+
+        (u1, u2, (key_x, key_y))
+    }
+
+    /// Populates the rows of an event.
+    fn populate_event<F: PrimeField32>(
+        event: &EcdsaEvent,
+        rows: &mut [F],
+        blu: &mut Vec<ByteLookupEvent>,
+        is_real: bool,
+    ) {
+        let num_cols = num_weierstrass_ecdsa_verify_cols::<E::BaseField, E::ScalarField>();
+        let r = BigUint::from_slice(&event.input[3 * ECDSA_WORDS..4 * ECDSA_WORDS]);
+
+        // Populate the first row, and copy it to the other rows of the event.
+        let (first, rest) = rows.split_at_mut(num_cols);
+        let (u1, u2, q) = {
+            let cols: &mut WeierstrassEcdsaVerifyCols<F, E::BaseField, E::ScalarField> =
+                first.borrow_mut();
+            cols.ladder.shard = F::from_canonical_u32(event.shard);
+            cols.ladder.clk = F::from_canonical_u32(event.clk);
+            cols.ladder.out_ptr = F::from_canonical_u32(event.out_ptr);
+            cols.ladder.in_ptr = F::from_canonical_u32(event.in_ptr);
+            for (access, record) in cols.input_access.iter_mut().zip(&event.input_memory_records) {
+                access.populate(*record, blu);
+            }
+            for (access, record) in cols.output_access.iter_mut().zip(&event.output_memory_records)
+            {
+                access.populate(*record, blu);
+            }
+            Self::populate_input(cols, &event.input, blu)
+        };
+        for row in rest.chunks_exact_mut(num_cols) {
+            row.copy_from_slice(first);
+        }
+
+        let mut acc = E::ecdsa_offset();
+        let mut failed = false;
+        for (index, row) in rows.chunks_exact_mut(num_cols).enumerate() {
+            let cols: &mut WeierstrassEcdsaVerifyCols<F, E::BaseField, E::ScalarField> =
+                row.borrow_mut();
+            let mut last_blu = Vec::new();
+            cols.ladder.populate_control(index, is_real);
+            let step = cols.ladder.populate_ladder::<E>(
+                blu,
+                &mut last_blu,
+                index,
+                &u1,
+                &u2,
+                &q,
+                &acc,
+                failed,
+            );
+
+            // Compare the x-coordinate of the result to r.
+            let x_minus_r =
+                cols.x_minus_r.populate(&mut last_blu, &step.key.0, &r, FieldOperation::Sub);
+            let is_x_eq_r = x_minus_r.is_zero();
+            let op = if is_x_eq_r { FieldOperation::Mul } else { FieldOperation::Div };
+            cols.x_minus_r_inverse.populate(&mut last_blu, &BigUint::one(), &x_minus_r, op);
+            cols.is_x_eq_r = F::from_bool(is_x_eq_r);
+            cols.is_valid = F::from_bool(is_x_eq_r && !step.failed_final);
+
+            if index == ECDSA_NUM_BITS - 1 {
+                blu.extend(last_blu);
+            }
+            (acc, failed) = (step.acc, step.failed);
+        }
+    }
+}
+
+impl<F: PrimeField32, E: EllipticCurve + EcdsaParameters> MachineAir<F>
+    for WeierstrassEcdsaVerifyChip<E>
+{
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => "Secp256k1EcdsaVerify".to_string(),
+            CurveType::Secp256r1 => "Secp256r1EcdsaVerify".to_string(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        WeierstrassEcdsaVerifyCols::<F, E::BaseField, E::ScalarField>::column_names()
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events = Self::events(input);
+        let num_cols = num_weierstrass_ecdsa_verify_cols::<E::BaseField, E::ScalarField>();
+        for blu in ecdsa_byte_lookups::<F>(&events, num_cols, Self::populate_event) {
+            output.add_byte_lookup_events(blu);
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = Self::events(input);
+        let num_cols = num_weierstrass_ecdsa_verify_cols::<E::BaseField, E::ScalarField>();
+        let num_rows = input.fixed_log2_rows::<F, _>(self).map(|x| 1 << x).unwrap_or(
+            std::cmp::max((events.len() * ECDSA_NUM_BITS).next_power_of_two(), ECDSA_NUM_BITS),
+        );
+        generate_ecdsa_trace(
+            &events,
+            &Self::dummy_event(),
+            num_cols,
+            num_rows,
+            Self::populate_event,
+        )
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(Self::syscall_code()).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F, E: EllipticCurve + EcdsaParameters> BaseAir<F> for WeierstrassEcdsaVerifyChip<E> {
+    fn width(&self) -> usize {
+        num_weierstrass_ecdsa_verify_cols::<E::BaseField, E::ScalarField>()
+    }
+}
+
+impl<AB, E: EllipticCurve + EcdsaParameters> Air<AB> for WeierstrassEcdsaVerifyChip<E>
+where
+    AB: MONEROCHANAirBuilder,
+    Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+    Limbs<AB::Var, <E::ScalarField as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &WeierstrassEcdsaVerifyCols<AB::Var, E::BaseField, E::ScalarField> =
+            (*local).borrow();
+        let next: &WeierstrassEcdsaVerifyCols<AB::Var, E::BaseField, E::ScalarField> =
+            (*next).borrow();
+        let ladder = &local.ladder;
+        let is_first_real = ladder.is_first_real;
+        let is_last_real = ladder.is_last_real;
+
+        ladder.eval_control(builder, &next.ladder);
+
+        let key_x: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.input_access[..ECDSA_WORDS]);
+        let key_y: Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.input_access[ECDSA_WORDS..2 * ECDSA_WORDS]);
+        let z: Limbs<AB::Var, <E::ScalarField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.input_access[2 * ECDSA_WORDS..3 * ECDSA_WORDS]);
+        let r: Limbs<AB::Var, <E::ScalarField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.input_access[3 * ECDSA_WORDS..4 * ECDSA_WORDS]);
+        let s: Limbs<AB::Var, <E::ScalarField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.input_access[4 * ECDSA_WORDS..5 * ECDSA_WORDS]);
+
+        // r is compared on the last row, so it is kept on every row of the event.
+        for i in 0..E::ScalarField::NB_LIMBS {
+            let next_r = next.input_access[3 * ECDSA_WORDS + i / 4].value()[i % 4];
+            builder
+                .when_transition()
+                .when(ladder.is_real)
+                .when_not(ladder.is_last.result)
+                .assert_eq(next_r, r[i]);
+        }
+
+        // u1 = z / s and u2 = r / s modulo the group order, with r and s less than the order.
+        let order = E::ScalarField::to_limbs_field::<AB::Expr, AB::F>(&E::ScalarField::modulus());
+        local.r_range.eval(builder, &r, &order, is_first_real);
+        local.s_range.eval(builder, &s, &order, is_first_real);
+        local.s_inverse.eval(
+            builder,
+            &E::ScalarField::to_limbs_field::<AB::Expr, _>(&BigUint::one()),
+            &s,
+            FieldOperation::Div,
+            is_first_real,
+        );
+        local.u1.eval(builder, &z, &local.s_inverse.result, FieldOperation::Mul, is_first_real);
+        local.u2.eval(builder, &r, &local.s_inverse.result, FieldOperation::Mul, is_first_real);
+        local.u1_range.eval(builder, &local.u1.result, &order, is_first_real);
+        local.u2_range.eval(builder, &local.u2.result, &order, is_first_real);
+
+        // The public key is reduced and on the curve: y^2 = x^3 + a * x + b.
+        let modulus = E::BaseField::to_limbs_field::<AB::Expr, AB::F>(&E::BaseField::modulus());
+        local.key_x_range.eval(builder, &key_x, &modulus, is_first_real);
+        local.key_y_range.eval(builder, &key_y, &modulus, is_first_real);
+        local.x_2.eval(builder, &key_x, &key_x, FieldOperation::Mul, is_first_real);
+        local.x_3.eval(builder, &local.x_2.result, &key_x, FieldOperation::Mul, is_first_real);
+        let b_const = E::BaseField::to_limbs_field::<AB::F, _>(&E::b_int());
+        let a_const = E::BaseField::to_limbs_field::<AB::F, _>(&E::a_int());
+        let params = [a_const, b_const];
+        let p_x: Polynomial<AB::Expr> = key_x.into();
+        let p_one: Polynomial<AB::Expr> =
+            E::BaseField::to_limbs_field::<AB::F, _>(&BigUint::one()).into();
+        local.ax_plus_b.eval::<AB>(builder, &params, &[p_x, p_one], is_first_real);
+        local.x_3_plus_b_plus_ax.eval(
+            builder,
+            &local.x_3.result,
+            &local.ax_plus_b.result,
+            FieldOperation::Add,
+            is_first_real,
+        );
+        local.y_2.eval(builder, &key_y, &key_y, FieldOperation::Mul, is_first_real);
+        builder
+            .when(is_first_real)
+            .assert_all_eq(local.y_2.result, local.x_3_plus_b_plus_ax.result);
+
+        // Q is the public key.
+        builder.when(is_first_real).assert_all_eq(ladder.q_x, key_x);
+        builder.when(is_first_real).assert_all_eq(ladder.q_y, key_y);
+
+        ladder.eval_ladder::<AB, E>(
+            builder,
+            &next.ladder,
+            local.u1.result.0.as_slice(),
+            local.u2.result.0.as_slice(),
+        );
+
+        // Compare the x-coordinate of the result to r modulo the group order: their difference
+        // is zero exactly when it has no inverse.
+        let key_x = &ladder.remove_offset.x3_ins.result;
+        local.x_minus_r.eval(builder, key_x, &r, FieldOperation::Sub, is_last_real);
+        builder.assert_bool(local.is_x_eq_r);
+        builder.when(local.is_x_eq_r).assert_all_zero(local.x_minus_r.result);
+        let order_poly = Polynomial::from_iter(
+            E::ScalarField::modulus_field_iter::<AB::F>().map(AB::Expr::from),
+        );
+        local.x_minus_r_inverse.eval_variable(
+            builder,
+            &E::ScalarField::to_limbs_field::<AB::Expr, _>(&BigUint::one()),
+            &local.x_minus_r.result,
+            &order_poly,
+            AB::Expr::zero(),
+            AB::Expr::zero(),
+            local.is_x_eq_r,
+            AB::Expr::one() - local.is_x_eq_r,
+            is_last_real,
+        );
+
+        // The status is 2 if the ladder failed, and otherwise whether the signature is valid.
+        builder
+            .assert_eq(local.is_valid, local.is_x_eq_r * (AB::Expr::one() - ladder.failed_final));
+        let status = local.output_access[0].value();
+        builder.when(is_last_real).assert_eq(
+            status[0],
+            ladder.failed_final * AB::F::from_canonical_u32(2) + local.is_valid,
+        );
+        builder.when(is_last_real).assert_all_zero([status[1], status[2], status[3]]);
+
+        builder.eval_memory_access_slice(
+            ladder.shard,
+            ladder.clk.into(),
+            ladder.in_ptr,
+            &local.input_access,
+            is_first_real,
+        );
+        builder.eval_memory_access_slice(
+            ladder.shard,
+            ladder.clk + AB::Expr::one(),
+            ladder.out_ptr,
+            &local.output_access,
+            is_last_real,
+        );
+
+        builder.receive_syscall(
+            ladder.shard,
+            ladder.clk,
+            AB::F::from_canonical_u32(Self::syscall_code().syscall_id()),
+            ladder.out_ptr,
+            ladder.in_ptr,
+            is_first_real,
+            InteractionScope::Local,
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use monerochan_core_executor::Program;
+    use monerochan_stark::CpuProver;
+    use test_artifacts::{SECP256K1_ECDSA_VERIFY_ELF, SECP256R1_ECDSA_VERIFY_ELF};
+
+    use crate::{
+        io::MONEROCHANStdin,
+        utils::{run_test, setup_logger},
+    };
+
+    #[test]
+    fn test_secp256k1_ecdsa_verify_simple() {
+        setup_logger();
+        let program = Program::from(SECP256K1_ECDSA_VERIFY_ELF).unwrap();
+        run_test::<CpuProver<_, _>>(program, MONEROCHANStdin::new()).unwrap();
+    }
+
+    #[test]
+    fn test_secp256r1_ecdsa_verify_simple() {
+        setup_logger();
+        let program = Program::from(SECP256R1_ECDSA_VERIFY_ELF).unwrap();
+        run_test::<CpuProver<_, _>>(program, MONEROCHANStdin::new()).unwrap();
+    }
+}
@@ -0,0 +1,502 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use std::marker::PhantomData;
+
+use num::{BigUint, One, Zero};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use monerochan_core_executor::{
+    events::{
+        ecrecover_output, ByteLookupEvent, EcdsaEvent, FieldOperation, PrecompileEvent,
+        ECDSA_NUM_BITS, ECDSA_WORDS, ECRECOVER_INPUT_WORDS, ECRECOVER_OUTPUT_WORDS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use monerochan_curves::{
+    params::{FieldParameters, Limbs, NumLimbs, NumWords},
+    weierstrass::EcdsaParameters,
+    CurveType, EllipticCurve,
+};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::air::{
+    BaseAirBuilder, InteractionScope, MONEROCHANAirBuilder, MachineAir, Polynomial,
+};
+
+use super::weierstrass_ecdsa::{
+    dummy_ecdsa_event, ecdsa_byte_lookups, ecdsa_words, generate_ecdsa_trace, EcdsaLadderCols,
+};
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryReadCols, MemoryWriteCols},
+    operations::field::{
+        field_inner_product::FieldInnerProductCols, field_op::FieldOpCols,
+        field_sqrt::FieldSqrtCols, range::FieldLtCols,
+    },
+    utils::limbs_from_access,
+};
+
+pub const fn num_weierstrass_ecrecover_cols<
+    P: FieldParameters + NumWords,
+    S: FieldParameters + NumWords,
+>() -> usize {
+    size_of::<WeierstrassEcrecoverCols<u8, P, S>>()
+}
+
+/// A set of columns to recover the public key of an ECDSA signature on a Weierstrass curve.
+///
+/// Each event spans [`ECDSA_NUM_BITS`] rows. The input is read and `R` is recovered from `r` and
+/// the recovery id on the first row, the ladder computes `[-z / r] G + [s / r] R` over all rows,
+/// and the output is written on the last row. The columns other than the ladder are the same on
+/// every row of the event.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WeierstrassEcrecoverCols<T, P: FieldParameters + NumWords, S: FieldParameters + NumWords>
+{
+    pub ladder: EcdsaLadderCols<T, P>,
+    pub input_access: [MemoryReadCols<T>; ECRECOVER_INPUT_WORDS],
+    pub output_access: [MemoryWriteCols<T>; ECRECOVER_OUTPUT_WORDS],
+    pub(crate) r_range: FieldLtCols<T, S>,
+    pub(crate) s_range: FieldLtCols<T, S>,
+    pub(crate) r_inverse: FieldOpCols<T, S>,
+    pub(crate) z_times_r_inverse: FieldOpCols<T, S>,
+    pub(crate) u1: FieldOpCols<T, S>,
+    pub(crate) u2: FieldOpCols<T, S>,
+    pub(crate) u1_range: FieldLtCols<T, S>,
+    pub(crate) u2_range: FieldLtCols<T, S>,
+    pub(crate) x_2: FieldOpCols<T, P>,
+    pub(crate) x_3: FieldOpCols<T, P>,
+    pub(crate) ax_plus_b: FieldInnerProductCols<T, P>,
+    pub(crate) x_3_plus_b_plus_ax: FieldOpCols<T, P>,
+    pub(crate) nqr_times_alpha: FieldOpCols<T, P>,
+    /// Whether `r` is the x-coordinate of a point of the curve.
+    pub is_square: T,
+    /// The value whose square root is `R.y`: `r^3 + a r + b` if it is a square, and its product
+    /// with a non-residue otherwise.
+    pub sqrt_input: Limbs<T, P::Limbs>,
+    pub(crate) y: FieldSqrtCols<T, P>,
+    /// Whether the public key was recovered.
+    pub is_recovered: T,
+}
+
+#[derive(Default)]
+pub struct WeierstrassEcrecoverChip<E> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: EllipticCurve + EcdsaParameters> WeierstrassEcrecoverChip<E> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn syscall_code() -> SyscallCode {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => SyscallCode::SECP256K1_ECRECOVER,
+            CurveType::Secp256r1 => SyscallCode::SECP256R1_ECRECOVER,
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn events(input: &ExecutionRecord) -> Vec<&EcdsaEvent> {
+        input
+            .get_precompile_events(Self::syscall_code())
+            .iter()
+            .map(|(_, event)| match event {
+                PrecompileEvent::Secp256k1Ecrecover(event) |
+                PrecompileEvent::Secp256r1Ecrecover(event) => event,
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    /// An event recovering the generator from `r = G.x`, `s = 1` and `z = 0`.
+    fn dummy_event() -> EcdsaEvent {
+        let (g_x, g_y) = E::generator();
+        let mut input = vec![0; ECRECOVER_INPUT_WORDS];
+        input[ECDSA_WORDS..2 * ECDSA_WORDS].copy_from_slice(&ecdsa_words(&g_x));
+        input[2 * ECDSA_WORDS] = 1;
+        input[3 * ECDSA_WORDS] = u32::from(g_y.bit(0));
+        let output = ecrecover_output::<E>(&input);
+        dummy_ecdsa_event(input, output)
+    }
+
+    /// Populates the columns computed from the input and returns `u1`, `u2` and `Q`.
+    fn populate_input<F: PrimeField32>(
+        cols: &mut WeierstrassEcrecoverCols<F, E::BaseField, E::ScalarField>,
+        input: &[u32],
+        blu: &mut Vec<ByteLookupEvent>,
+    ) -> (BigUint, BigUint, (BigUint, BigUint)) {
+        let modulus = E::BaseField::modulus();
+        let order = E::ScalarField::modulus();
+        let z = BigUint::from_slice(&input[..ECDSA_WORDS]);
+        let r = BigUint::from_slice(&input[ECDSA_WORDS..2 * ECDSA_WORDS]);
+        let s = BigUint::from_slice(&input[2 * ECDSA_WORDS..3 * ECDSA_WORDS]);
+        let v = input[3 * ECDSA_WORDS];
+
+        // u1 = -z / r and u2 = s / r modulo the group order.
+        cols.r_range.populate(blu, &r, &order);
+        cols.s_range.populate(blu, &s, &order);
+        let r_inverse = cols.r_inverse.populate(blu, &BigUint::one(), &r, FieldOperation::Div);
+        let z_times_r_inverse =
+            cols.z_times_r_inverse.populate(blu, &z, &r_inverse, FieldOperation::Mul);
+        let u1 = cols.u1.populate(blu, &BigUint::zero(), &z_times_r_inverse, FieldOperation::Sub);
+        let u2 = cols.u2.populate(blu, &s, &r_inverse, FieldOperation::Mul);
+        cols.u1_range.populate(blu, &u1, &order);
+        cols.u2_range.populate(blu, &u2, &order);
+
+        // alpha = r^3 + a * r + b.
+        let x_2 = cols.x_2.populate(blu, &r, &r, FieldOperation::Mul);
+        let x_3 = cols.x_3.populate(blu, &x_2, &r, FieldOperation::Mul);
+        let ax_plus_b =
+            cols.ax_plus_b.populate(blu, &[E::a_int(), E::b_int()], &[r.clone(), BigUint::one()]);
+        let alpha = cols.x_3_plus_b_plus_ax.populate(blu, &x_3, &ax_plus_b, FieldOperation::Add);
+        let nqr_times_alpha =
+            cols.nqr_times_alpha.populate(blu, &E::nqr(), &alpha, FieldOperation::Mul);
+
+        // R.y is the square root of alpha with the parity of the recovery id. If alpha is not a
+        // square, the square root of its product with a non-residue proves it.
+        let is_square = E::sqrt(&alpha).is_some();
+        let sqrt_input = if is_square { alpha } else { nqr_times_alpha };
+        cols.is_square = F::from_bool(is_square);
+        cols.sqrt_input = E::BaseField::to_limbs_field(&sqrt_input);
+        let y = cols.y.populate(blu, &sqrt_input, |n| {
+            let y = E::sqrt(n).unwrap();
+            if y.bit(0) == (v == 1) {
+                y
+            } else {
+                (&modulus - y) % &modulus
+            }
+        });
+
+        let q = if is_square { (r, y) } else { E::generator() };
+        (u1, u2, q)
+    }
+
+    /// Populates the rows of an event.
+    fn populate_event<F: PrimeField32>(
+        event: &EcdsaEvent,
+        rows: &mut [F],
+        blu: &mut Vec<ByteLookupEvent>,
+        is_real: bool,
+    ) {
+        let num_cols = num_weierstrass_ecrecover_cols::<E::BaseField, E::ScalarField>();
+
+        // Populate the first row, and copy it to the other rows of the event.
+        let (first, rest) = rows.split_at_mut(num_cols);
+        let (u1, u2, q) = {
+            let cols: &mut WeierstrassEcrecoverCols<F, E::BaseField, E::ScalarField> =
+                first.borrow_mut();
+            cols.ladder.shard = F::from_canonical_u32(event.shard);
+            cols.ladder.clk = F::from_canonical_u32(event.clk);
+            cols.ladder.out_ptr = F::from_canonical_u32(event.out_ptr);
+            cols.ladder.in_ptr = F::from_canonical_u32(event.in_ptr);
+            for (access, record) in cols.input_access.iter_mut().zip(&event.input_memory_records) {
+                access.populate(*record, blu);
+            }
+            for (access, record) in cols.output_access.iter_mut().zip(&event.output_memory_records)
+            {
+                access.populate(*record, blu);
+            }
+            Self::populate_input(cols, &event.input, blu)
+        };
+        for row in rest.chunks_exact_mut(num_cols) {
+            row.copy_from_slice(first);
+        }
+
+        let mut acc = E::ecdsa_offset();
+        let mut failed = false;
+        for (index, row) in rows.chunks_exact_mut(num_cols).enumerate() {
+            let cols: &mut WeierstrassEcrecoverCols<F, E::BaseField, E::ScalarField> =
+                row.borrow_mut();
+            let mut last_blu = Vec::new();
+            cols.ladder.populate_control(index, is_real);
+            let step = cols.ladder.populate_ladder::<E>(
+                blu,
+                &mut last_blu,
+                index,
+                &u1,
+                &u2,
+                &q,
+                &acc,
+                failed,
+            );
+            cols.is_recovered = F::from_bool(cols.is_square == F::one() && !step.failed_final);
+            if index == ECDSA_NUM_BITS - 1 {
+                blu.extend(last_blu);
+            }
+            (acc, failed) = (step.acc, step.failed);
+        }
+    }
+}
+
+impl<F: PrimeField32, E: EllipticCurve + EcdsaParameters> MachineAir<F>
+    for WeierstrassEcrecoverChip<E>
+{
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => "Secp256k1Ecrecover".to_string(),
+            CurveType::Secp256r1 => "Secp256r1Ecrecover".to_string(),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        WeierstrassEcrecoverCols::<F, E::BaseField, E::ScalarField>::column_names()
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events = Self::events(input);
+        let num_cols = num_weierstrass_ecrecover_cols::<E::BaseField, E::ScalarField>();
+        for blu in ecdsa_byte_lookups::<F>(&events, num_cols, Self::populate_event) {
+            output.add_byte_lookup_events(blu);
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = Self::events(input);
+        let num_cols = num_weierstrass_ecrecover_cols::<E::BaseField, E::ScalarField>();
+        let num_rows = input.fixed_log2_rows::<F, _>(self).map(|x| 1 << x).unwrap_or(
+            std::cmp::max((events.len() * ECDSA_NUM_BITS).next_power_of_two(), ECDSA_NUM_BITS),
+        );
+        generate_ecdsa_trace(
+            &events,
+            &Self::dummy_event(),
+            num_cols,
+            num_rows,
+            Self::populate_event,
+        )
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(Self::syscall_code()).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F, E: EllipticCurve + EcdsaParameters> BaseAir<F> for WeierstrassEcrecoverChip<E> {
+    fn width(&self) -> usize {
+        num_weierstrass_ecrecover_cols::<E::BaseField, E::ScalarField>()
+    }
+}
+
+impl<AB, E: EllipticCurve + EcdsaParameters> Air<AB> for WeierstrassEcrecoverChip<E>
+where
+    AB: MONEROCHANAirBuilder,
+    Limbs<AB::Var, <E::BaseField as NumLimbs>::Limbs>: Copy,
+    Limbs<AB::Var, <E::ScalarField as NumLimbs>::Limbs>: Copy,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let (local, next) = (main.row_slice(0), main.row_slice(1));
+        let local: &WeierstrassEcrecoverCols<AB::Var, E::BaseField, E::ScalarField> =
+            (*local).borrow();
+        let next: &WeierstrassEcrecoverCols<AB::Var, E::BaseField, E::ScalarField> =
+            (*next).borrow();
+        let ladder = &local.ladder;
+        let is_first_real = ladder.is_first_real;
+        let is_last_real = ladder.is_last_real;
+
+        ladder.eval_control(builder, &next.ladder);
+        builder.assert_bool(local.is_square);
+        builder
+            .when_transition()
+            .when(ladder.is_real)
+            .when_not(ladder.is_last.result)
+            .assert_eq(next.is_square, local.is_square);
+
+        let z: Limbs<AB::Var, <E::ScalarField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.input_access[..ECDSA_WORDS]);
+        let r: Limbs<AB::Var, <E::ScalarField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.input_access[ECDSA_WORDS..2 * ECDSA_WORDS]);
+        let s: Limbs<AB::Var, <E::ScalarField as NumLimbs>::Limbs> =
+            limbs_from_access(&local.input_access[2 * ECDSA_WORDS..3 * ECDSA_WORDS]);
+        let v = local.input_access[3 * ECDSA_WORDS].value();
+
+        // The recovery id is a boolean.
+        builder.when(is_first_real).assert_bool(v[0]);
+        builder.when(is_first_real).assert_all_zero([v[1], v[2], v[3]]);
+
+        // u1 = -z / r and u2 = s / r modulo the group order, with r and s less than the order.
+        let order = E::ScalarField::to_limbs_field::<AB::Expr, AB::F>(&E::ScalarField::modulus());
+        local.r_range.eval(builder, &r, &order, is_first_real);
+        local.s_range.eval(builder, &s, &order, is_first_real);
+        local.r_inverse.eval(
+            builder,
+            &E::ScalarField::to_limbs_field::<AB::Expr, _>(&BigUint::one()),
+            &r,
+            FieldOperation::Div,
+            is_first_real,
+        );
+        local.z_times_r_inverse.eval(
+            builder,
+            &z,
+            &local.r_inverse.result,
+            FieldOperation::Mul,
+            is_first_real,
+        );
+        local.u1.eval(
+            builder,
+            &[AB::Expr::zero()].iter(),
+            &local.z_times_r_inverse.result,
+            FieldOperation::Sub,
+            is_first_real,
+        );
+        local.u2.eval(builder, &s, &local.r_inverse.result, FieldOperation::Mul, is_first_real);
+        local.u1_range.eval(builder, &local.u1.result, &order, is_first_real);
+        local.u2_range.eval(builder, &local.u2.result, &order, is_first_real);
+
+        // alpha = r^3 + a * r + b.
+        local.x_2.eval(builder, &r, &r, FieldOperation::Mul, is_first_real);
+        local.x_3.eval(builder, &local.x_2.result, &r, FieldOperation::Mul, is_first_real);
+        let b_const = E::BaseField::to_limbs_field::<AB::F, _>(&E::b_int());
+        let a_const = E::BaseField::to_limbs_field::<AB::F, _>(&E::a_int());
+        let params = [a_const, b_const];
+        let p_x: Polynomial<AB::Expr> = r.into();
+        let p_one: Polynomial<AB::Expr> =
+            E::BaseField::to_limbs_field::<AB::F, _>(&BigUint::one()).into();
+        local.ax_plus_b.eval::<AB>(builder, &params, &[p_x, p_one], is_first_real);
+        local.x_3_plus_b_plus_ax.eval(
+            builder,
+            &local.x_3.result,
+            &local.ax_plus_b.result,
+            FieldOperation::Add,
+            is_first_real,
+        );
+        local.nqr_times_alpha.eval(
+            builder,
+            &E::BaseField::to_limbs_field::<AB::Expr, _>(&E::nqr()),
+            &local.x_3_plus_b_plus_ax.result,
+            FieldOperation::Mul,
+            is_first_real,
+        );
+
+        // R.y is a square root of alpha with the parity of the recovery id. If there is a square
+        // root of alpha times a non-residue instead, alpha is not a square and R does not exist.
+        builder
+            .when(is_first_real)
+            .when(local.is_square)
+            .assert_all_eq(local.sqrt_input, local.x_3_plus_b_plus_ax.result);
+        builder
+            .when(is_first_real)
+            .when_not(local.is_square)
+            .assert_all_eq(local.sqrt_input, local.nqr_times_alpha.result);
+        local.y.eval(builder, &local.sqrt_input, v[0], is_first_real);
+
+        // Q is R if it exists. Otherwise the ladder runs on the generator, and its result is
+        // discarded.
+        let (g_x, g_y) = E::generator();
+        builder.when(is_first_real).when(local.is_square).assert_all_eq(ladder.q_x, r);
+        builder
+            .when(is_first_real)
+            .when(local.is_square)
+            .assert_all_eq(ladder.q_y, local.y.multiplication.result);
+        builder
+            .when(is_first_real)
+            .when_not(local.is_square)
+            .assert_all_eq(ladder.q_x, E::BaseField::to_limbs_field::<AB::Expr, AB::F>(&g_x));
+        builder
+            .when(is_first_real)
+            .when_not(local.is_square)
+            .assert_all_eq(ladder.q_y, E::BaseField::to_limbs_field::<AB::Expr, AB::F>(&g_y));
+
+        ladder.eval_ladder::<AB, E>(
+            builder,
+            &next.ladder,
+            local.u1.result.0.as_slice(),
+            local.u2.result.0.as_slice(),
+        );
+
+        // The output is the public key and the status, the public key being zero unless it was
+        // recovered.
+        builder.assert_eq(
+            local.is_recovered,
+            local.is_square * (AB::Expr::one() - ladder.failed_final),
+        );
+        let key_x = &ladder.remove_offset.x3_ins.result;
+        let key_y = &ladder.remove_offset.y3_ins.result;
+        for i in 0..E::BaseField::NB_LIMBS {
+            builder.when(is_last_real).assert_eq(
+                local.output_access[i / 4].value()[i % 4],
+                local.is_recovered * key_x[i],
+            );
+            builder.when(is_last_real).assert_eq(
+                local.output_access[ECDSA_WORDS + i / 4].value()[i % 4],
+                local.is_recovered * key_y[i],
+            );
+        }
+        let status = local.output_access[2 * ECDSA_WORDS].value();
+        builder.when(is_last_real).assert_eq(
+            status[0],
+            local.is_square * AB::F::from_canonical_u32(2) - local.is_recovered,
+        );
+        builder.when(is_last_real).assert_all_zero([status[1], status[2], status[3]]);
+
+        builder.eval_memory_access_slice(
+            ladder.shard,
+            ladder.clk.into(),
+            ladder.in_ptr,
+            &local.input_access,
+            is_first_real,
+        );
+        builder.eval_memory_access_slice(
+            ladder.shard,
+            ladder.clk + AB::Expr::one(),
+            ladder.out_ptr,
+            &local.output_access,
+            is_last_real,
+        );
+
+        builder.receive_syscall(
+            ladder.shard,
+            ladder.clk,
+            AB::F::from_canonical_u32(Self::syscall_code().syscall_id()),
+            ladder.out_ptr,
+            ladder.in_ptr,
+            is_first_real,
+            InteractionScope::Local,
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use monerochan_core_executor::Program;
+    use monerochan_stark::CpuProver;
+    use test_artifacts::{SECP256K1_ECRECOVER_ELF, SECP256R1_ECRECOVER_ELF};
+
+    use crate::{
+        io::MONEROCHANStdin,
+        utils::{run_test, setup_logger},
+    };
+
+    #[test]
+    fn test_secp256k1_ecrecover_simple() {
+        setup_logger();
+        let program = Program::from(SECP256K1_ECRECOVER_ELF).unwrap();
+        run_test::<CpuProver<_, _>>(program, MONEROCHANStdin::new()).unwrap();
+    }
+
+    #[test]
+    fn test_secp256r1_ecrecover_simple() {
+        setup_logger();
+        let program = Program::from(SECP256R1_ECRECOVER_ELF).unwrap();
+        run_test::<CpuProver<_, _>>(program, MONEROCHANStdin::new()).unwrap();
+    }
+}
@@ -0,0 +1,446 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    mem::size_of,
+};
+use std::marker::PhantomData;
+
+use crate::{
+    air::MemoryAirBuilder,
+    memory::{MemoryCols, MemoryReadCols},
+    operations::IsZeroOperation,
+    utils::zeroed_f_vec,
+};
+use p3_air::{Air, AirBuilder, BaseAir};
+use p3_field::{AbstractField, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
+use p3_maybe_rayon::prelude::{ParallelBridge, ParallelIterator, ParallelSlice};
+use monerochan_core_executor::{
+    events::{
+        msm_op_clk, msm_table_entry_ptr, msm_window_digits, ByteLookupEvent, ByteRecord,
+        EllipticCurveMsmEvent, GlobalInteractionEvent, PrecompileEvent, SyscallEvent,
+        MSM_NUM_WINDOWS, MSM_SCALAR_WORDS, MSM_TABLE_SIZE, MSM_WINDOW_BITS,
+    },
+    syscalls::SyscallCode,
+    ExecutionRecord, Program,
+};
+use monerochan_curves::{
+    params::NumWords, weierstrass::WeierstrassParameters, CurveType, EllipticCurve,
+};
+use monerochan_derive::AlignedBorrow;
+use monerochan_stark::{
+    air::{AirInteraction, InteractionScope, MachineAir, MONEROCHANAirBuilder},
+    InteractionKind,
+};
+use typenum::Unsigned;
+
+pub const fn num_weierstrass_msm_cols<const NUM_POINTS: usize>() -> usize {
+    size_of::<WeierstrassMsmCols<u8, NUM_POINTS>>()
+}
+
+/// A set of columns to drive one chunk of a fixed-window scalar multiplication or MSM on a
+/// Weierstrass curve.
+///
+/// The chip itself does no curve arithmetic: it decomposes the scalar chunks into window digits and
+/// sends the corresponding double and add syscalls to the curve's `WeierstrassDoubleAssignChip` and
+/// `WeierstrassAddAssignChip` through the global interaction bus.
+#[derive(Debug, Clone, AlignedBorrow)]
+#[repr(C)]
+pub struct WeierstrassMsmCols<T, const NUM_POINTS: usize> {
+    pub is_real: T,
+    pub shard: T,
+    pub clk: T,
+    pub p_ptr: T,
+    pub input_ptr: T,
+    pub scalar_access: [[MemoryReadCols<T>; MSM_SCALAR_WORDS]; NUM_POINTS],
+    /// The little endian bits of every window digit, least significant digit first.
+    pub digit_bits: [[[T; MSM_WINDOW_BITS]; MSM_NUM_WINDOWS]; NUM_POINTS],
+    pub digit_is_zero: [[IsZeroOperation<T>; MSM_NUM_WINDOWS]; NUM_POINTS],
+}
+
+#[derive(Default)]
+pub struct WeierstrassMsmChip<E, const NUM_POINTS: usize> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: EllipticCurve, const NUM_POINTS: usize> WeierstrassMsmChip<E, NUM_POINTS> {
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData }
+    }
+
+    fn syscall_code() -> SyscallCode {
+        match (E::CURVE_TYPE, NUM_POINTS) {
+            (CurveType::Secp256k1, 1) => SyscallCode::SECP256K1_SCALAR_MUL,
+            (CurveType::Secp256k1, 2) => SyscallCode::SECP256K1_MSM,
+            (CurveType::Secp256r1, 1) => SyscallCode::SECP256R1_SCALAR_MUL,
+            (CurveType::Secp256r1, 2) => SyscallCode::SECP256R1_MSM,
+            (CurveType::Bn254, 1) => SyscallCode::BN254_SCALAR_MUL,
+            (CurveType::Bn254, 2) => SyscallCode::BN254_MSM,
+            (CurveType::Bls12381, 1) => SyscallCode::BLS12381_SCALAR_MUL,
+            (CurveType::Bls12381, 2) => SyscallCode::BLS12381_MSM,
+            _ => panic!("Unsupported curve or number of points"),
+        }
+    }
+
+    /// The syscall codes of the add and double precompiles the chip delegates to.
+    fn add_double_codes() -> (SyscallCode, SyscallCode) {
+        match E::CURVE_TYPE {
+            CurveType::Secp256k1 => (SyscallCode::SECP256K1_ADD, SyscallCode::SECP256K1_DOUBLE),
+            CurveType::Secp256r1 => (SyscallCode::SECP256R1_ADD, SyscallCode::SECP256R1_DOUBLE),
+            CurveType::Bn254 => (SyscallCode::BN254_ADD, SyscallCode::BN254_DOUBLE),
+            CurveType::Bls12381 => (SyscallCode::BLS12381_ADD, SyscallCode::BLS12381_DOUBLE),
+            _ => panic!("Unsupported curve"),
+        }
+    }
+
+    fn populate_row<F: PrimeField32>(
+        event: &EllipticCurveMsmEvent,
+        cols: &mut WeierstrassMsmCols<F, NUM_POINTS>,
+        new_byte_lookup_events: &mut Vec<ByteLookupEvent>,
+    ) {
+        cols.is_real = F::one();
+        cols.shard = F::from_canonical_u32(event.shard);
+        cols.clk = F::from_canonical_u32(event.clk);
+        cols.p_ptr = F::from_canonical_u32(event.p_ptr);
+        cols.input_ptr = F::from_canonical_u32(event.input_ptr);
+
+        for point in 0..NUM_POINTS {
+            for word in 0..MSM_SCALAR_WORDS {
+                let idx = point * MSM_SCALAR_WORDS + word;
+                cols.scalar_access[point][word]
+                    .populate(event.scalar_memory_records[idx], new_byte_lookup_events);
+            }
+            for digit in 0..MSM_NUM_WINDOWS {
+                let word = event.scalars[point * MSM_SCALAR_WORDS + digit * MSM_WINDOW_BITS / 32];
+                let value =
+                    (word >> ((digit * MSM_WINDOW_BITS) % 32)) & ((1 << MSM_WINDOW_BITS) - 1);
+                for bit in 0..MSM_WINDOW_BITS {
+                    cols.digit_bits[point][digit][bit] = F::from_canonical_u32((value >> bit) & 1);
+                }
+                cols.digit_is_zero[point][digit].populate(value);
+            }
+        }
+    }
+
+    fn dummy_row<F: PrimeField32>() -> Vec<F> {
+        let mut row = zeroed_f_vec(num_weierstrass_msm_cols::<NUM_POINTS>());
+        let cols: &mut WeierstrassMsmCols<F, NUM_POINTS> = row.as_mut_slice().borrow_mut();
+        for point in 0..NUM_POINTS {
+            for digit in 0..MSM_NUM_WINDOWS {
+                cols.digit_is_zero[point][digit].populate(0);
+            }
+        }
+        row
+    }
+}
+
+impl<F: PrimeField32, E: EllipticCurve + WeierstrassParameters, const NUM_POINTS: usize>
+    MachineAir<F> for WeierstrassMsmChip<E, NUM_POINTS>
+{
+    type Record = ExecutionRecord;
+    type Program = Program;
+
+    fn name(&self) -> String {
+        match (E::CURVE_TYPE, NUM_POINTS) {
+            (CurveType::Secp256k1, 1) => "Secp256k1ScalarMulAssign".to_string(),
+            (CurveType::Secp256k1, 2) => "Secp256k1MsmAssign".to_string(),
+            (CurveType::Secp256r1, 1) => "Secp256r1ScalarMulAssign".to_string(),
+            (CurveType::Secp256r1, 2) => "Secp256r1MsmAssign".to_string(),
+            (CurveType::Bn254, 1) => "Bn254ScalarMulAssign".to_string(),
+            (CurveType::Bn254, 2) => "Bn254MsmAssign".to_string(),
+            (CurveType::Bls12381, 1) => "Bls12381ScalarMulAssign".to_string(),
+            (CurveType::Bls12381, 2) => "Bls12381MsmAssign".to_string(),
+            _ => panic!("Unsupported curve or number of points"),
+        }
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        WeierstrassMsmCols::<F, NUM_POINTS>::column_names()
+    }
+
+    fn generate_dependencies(&self, input: &Self::Record, output: &mut Self::Record) {
+        let events = input.get_precompile_events(Self::syscall_code());
+        let (add_code, double_code) = Self::add_double_codes();
+
+        let num_cols = num_weierstrass_msm_cols::<NUM_POINTS>();
+        let chunk_size = std::cmp::max(events.len() / num_cpus::get(), 1);
+
+        let dependencies: Vec<(Vec<ByteLookupEvent>, Vec<GlobalInteractionEvent>)> = events
+            .par_chunks(chunk_size)
+            .map(|ops: &[(SyscallEvent, PrecompileEvent)]| {
+                let mut blu = Vec::new();
+                let mut global = Vec::new();
+                ops.iter().for_each(|(_, op)| match op {
+                    PrecompileEvent::Secp256k1ScalarMul(event) |
+                    PrecompileEvent::Secp256k1Msm(event) |
+                    PrecompileEvent::Secp256r1ScalarMul(event) |
+                    PrecompileEvent::Secp256r1Msm(event) |
+                    PrecompileEvent::Bn254ScalarMul(event) |
+                    PrecompileEvent::Bn254Msm(event) |
+                    PrecompileEvent::Bls12381ScalarMul(event) |
+                    PrecompileEvent::Bls12381Msm(event) => {
+                        let mut row = zeroed_f_vec(num_cols);
+                        let cols: &mut WeierstrassMsmCols<F, NUM_POINTS> =
+                            row.as_mut_slice().borrow_mut();
+                        Self::populate_row(event, cols, &mut blu);
+
+                        // The double and add syscalls sent to the curve's precompile chips.
+                        let mut send = |op: usize, window: usize, code: SyscallCode, arg2: u32| {
+                            global.push(GlobalInteractionEvent {
+                                message: [
+                                    event.shard,
+                                    msm_op_clk(event.clk, NUM_POINTS, window, op),
+                                    code.syscall_id(),
+                                    event.p_ptr,
+                                    arg2,
+                                    0,
+                                    0,
+                                ],
+                                is_receive: false,
+                                kind: InteractionKind::Syscall as u8,
+                            });
+                        };
+                        let digits = msm_window_digits(&event.scalars, NUM_POINTS);
+                        for (window, digits) in digits.iter().enumerate() {
+                            for op in 0..MSM_WINDOW_BITS {
+                                send(op, window, double_code, 0);
+                            }
+                            for (point, &digit) in digits.iter().enumerate() {
+                                if digit != 0 {
+                                    let q_ptr = msm_table_entry_ptr::<E>(
+                                        event.input_ptr,
+                                        NUM_POINTS,
+                                        point,
+                                        digit,
+                                    );
+                                    send(MSM_WINDOW_BITS + 2 * point, window, add_code, q_ptr);
+                                }
+                            }
+                        }
+                    }
+                    _ => unreachable!(),
+                });
+                (blu, global)
+            })
+            .collect();
+
+        for (blu, global) in dependencies {
+            output.add_byte_lookup_events(blu);
+            output.global_interaction_events.extend(global);
+        }
+    }
+
+    fn generate_trace(
+        &self,
+        input: &ExecutionRecord,
+        _: &mut ExecutionRecord,
+    ) -> RowMajorMatrix<F> {
+        let events = input.get_precompile_events(Self::syscall_code());
+
+        let num_cols = num_weierstrass_msm_cols::<NUM_POINTS>();
+        let num_rows = input
+            .fixed_log2_rows::<F, _>(self)
+            .map(|x| 1 << x)
+            .unwrap_or(std::cmp::max(events.len().next_power_of_two(), 4));
+        let mut values = zeroed_f_vec(num_rows * num_cols);
+        let chunk_size = 64;
+
+        let dummy_row = Self::dummy_row::<F>();
+
+        values.chunks_mut(chunk_size * num_cols).enumerate().par_bridge().for_each(|(i, rows)| {
+            rows.chunks_mut(num_cols).enumerate().for_each(|(j, row)| {
+                let idx = i * chunk_size + j;
+                if idx < events.len() {
+                    let mut new_byte_lookup_events = Vec::new();
+                    let cols: &mut WeierstrassMsmCols<F, NUM_POINTS> = row.borrow_mut();
+                    match &events[idx].1 {
+                        PrecompileEvent::Secp256k1ScalarMul(event) |
+                        PrecompileEvent::Secp256k1Msm(event) |
+                        PrecompileEvent::Secp256r1ScalarMul(event) |
+                        PrecompileEvent::Secp256r1Msm(event) |
+                        PrecompileEvent::Bn254ScalarMul(event) |
+                        PrecompileEvent::Bn254Msm(event) |
+                        PrecompileEvent::Bls12381ScalarMul(event) |
+                        PrecompileEvent::Bls12381Msm(event) => {
+                            Self::populate_row(event, cols, &mut new_byte_lookup_events);
+                        }
+                        _ => unreachable!(),
+                    }
+                } else {
+                    row.copy_from_slice(&dummy_row);
+                }
+            });
+        });
+
+        RowMajorMatrix::new(values, num_cols)
+    }
+
+    fn included(&self, shard: &Self::Record) -> bool {
+        if let Some(shape) = shard.shape.as_ref() {
+            shape.included::<F, _>(self)
+        } else {
+            !shard.get_precompile_events(Self::syscall_code()).is_empty()
+        }
+    }
+
+    fn local_only(&self) -> bool {
+        true
+    }
+}
+
+impl<F, E: EllipticCurve, const NUM_POINTS: usize> BaseAir<F>
+    for WeierstrassMsmChip<E, NUM_POINTS>
+{
+    fn width(&self) -> usize {
+        num_weierstrass_msm_cols::<NUM_POINTS>()
+    }
+}
+
+impl<AB, E: EllipticCurve, const NUM_POINTS: usize> Air<AB> for WeierstrassMsmChip<E, NUM_POINTS>
+where
+    AB: MONEROCHANAirBuilder,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0);
+        let local: &WeierstrassMsmCols<AB::Var, NUM_POINTS> = (*local).borrow();
+
+        builder.assert_bool(local.is_real);
+
+        let num_words = <E::BaseField as NumWords>::WordsCurvePoint::USIZE;
+        let (add_code, double_code) = Self::add_double_codes();
+
+        for point in 0..NUM_POINTS {
+            builder.eval_memory_access_slice(
+                local.shard,
+                local.clk.into(),
+                local.input_ptr.into() +
+                    AB::Expr::from_canonical_usize(4 * point * MSM_SCALAR_WORDS),
+                &local.scalar_access[point],
+                local.is_real,
+            );
+        }
+
+        // Decompose every scalar byte into two window digits given by their bits.
+        let digits: Vec<Vec<AB::Expr>> = local
+            .digit_bits
+            .iter()
+            .map(|digits| {
+                digits
+                    .iter()
+                    .map(|bits| {
+                        bits.iter().enumerate().fold(AB::Expr::zero(), |acc, (i, &bit)| {
+                            builder.assert_bool(bit);
+                            builder.when_not(local.is_real).assert_zero(bit);
+                            acc + bit * AB::F::from_canonical_u32(1 << i)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+        let digits_per_byte = 8 / MSM_WINDOW_BITS;
+        for point in 0..NUM_POINTS {
+            for word in 0..MSM_SCALAR_WORDS {
+                let value = local.scalar_access[point][word].value();
+                for byte in 0..4 {
+                    let first_digit = (4 * word + byte) * digits_per_byte;
+                    let byte_from_digits = (0..digits_per_byte).fold(AB::Expr::zero(), |acc, i| {
+                        acc + digits[point][first_digit + i].clone() *
+                            AB::F::from_canonical_u32(1 << (i * MSM_WINDOW_BITS))
+                    });
+                    builder.when(local.is_real).assert_eq(value[byte], byte_from_digits);
+                }
+            }
+            // The padding rows have zero digits, so `is_zero` is checked on every row.
+            for digit in 0..MSM_NUM_WINDOWS {
+                IsZeroOperation::<AB::F>::eval(
+                    builder,
+                    digits[point][digit].clone(),
+                    local.digit_is_zero[point][digit],
+                    AB::Expr::one(),
+                );
+            }
+        }
+
+        // Send the double and add syscalls to the global table, most significant window first.
+        let send_syscall =
+            |builder: &mut AB, op_clk: u32, code: SyscallCode, arg2: AB::Expr, mult: AB::Expr| {
+                builder.send(
+                    AirInteraction::new(
+                        vec![
+                            local.shard.into(),
+                            local.clk + AB::F::from_canonical_u32(op_clk),
+                            AB::Expr::from_canonical_u32(code.syscall_id()),
+                            local.p_ptr.into(),
+                            arg2,
+                            AB::Expr::zero(),
+                            AB::Expr::zero(),
+                            AB::Expr::one(),
+                            AB::Expr::zero(),
+                            AB::Expr::from_canonical_u8(InteractionKind::Syscall as u8),
+                        ],
+                        mult,
+                        InteractionKind::Global,
+                    ),
+                    InteractionScope::Local,
+                );
+            };
+        for window in 0..MSM_NUM_WINDOWS {
+            let digit = MSM_NUM_WINDOWS - 1 - window;
+            for op in 0..MSM_WINDOW_BITS {
+                send_syscall(
+                    builder,
+                    msm_op_clk(0, NUM_POINTS, window, op),
+                    double_code,
+                    AB::Expr::zero(),
+                    local.is_real.into(),
+                );
+            }
+            for point in 0..NUM_POINTS {
+                // The address of `[digit]P`, which is only read when the digit is nonzero.
+                let q_ptr = local.input_ptr.into() +
+                    AB::Expr::from_canonical_usize(
+                        4 * (NUM_POINTS * MSM_SCALAR_WORDS + point * MSM_TABLE_SIZE * num_words),
+                    ) +
+                    (digits[point][digit].clone() - AB::Expr::one()) *
+                        AB::F::from_canonical_usize(4 * num_words);
+                send_syscall(
+                    builder,
+                    msm_op_clk(0, NUM_POINTS, window, MSM_WINDOW_BITS + 2 * point),
+                    add_code,
+                    q_ptr,
+                    AB::Expr::one() - local.digit_is_zero[point][digit].result,
+                );
+            }
+        }
+
+        builder.receive_syscall(
+            local.shard,
+            local.clk,
+            AB::F::from_canonical_u32(Self::syscall_code().syscall_id()),
+            local.p_ptr,
+            local.input_ptr,
+            local.is_real,
+            InteractionScope::Local,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use monerochan_core_executor::Program;
+    use monerochan_stark::CpuProver;
+    use test_artifacts::WEIERSTRASS_MSM_ELF;
+
+    use crate::{
+        io::MONEROCHANStdin,
+        utils::{run_test, setup_logger},
+    };
+
+    #[test]
+    fn test_weierstrass_scalar_mul_and_msm() {
+        setup_logger();
+        let program = Program::from(WEIERSTRASS_MSM_ELF).unwrap();
+        let stdin = MONEROCHANStdin::new();
+        run_test::<CpuProver<_, _>>(program, stdin).unwrap();
+    }
+}
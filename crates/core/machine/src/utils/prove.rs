@@ -35,12 +35,13 @@ use monerochan_core_executor::{
 };
 
 use monerochan_core_executor::{
-    subproof::NoOpSubproofVerifier, ExecutionError, ExecutionRecord, ExecutionReport, Executor,
-    Program, MONEROCHANContext,
+    subproof::NoOpSubproofVerifier, syscalls::UserSyscalls, ExecutionError, ExecutionRecord,
+    ExecutionReport, Executor, Program, MONEROCHANContext,
 };
 use monerochan_stark::{
-    air::PublicValues, shape::OrderedShape, Com, MachineProof, MachineProver, MachineRecord,
-    OpeningProof, PcsProverData, MONEROCHANCoreOpts, ShardProof, StarkGenericConfig, Val,
+    air::PublicValues, shape::OrderedShape, Com, FriConfigError, MachineProof, MachineProver,
+    MachineRecord, OpeningProof, PcsProverData, MONEROCHANCoreOpts, ShardProof, StarkGenericConfig,
+    Val,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -107,6 +108,7 @@ where
     PcsProverData<SC>: Send + Sync,
 {
     // Setup the runtime.
+    let user_syscalls = context.user_syscalls.clone();
     let mut runtime = Box::new(Executor::with_context(program.clone(), opts, context));
     runtime.maximal_shapes = shape_config.map(|config| {
         config.maximal_core_shapes(opts.shard_size.ilog2() as usize).into_iter().collect()
@@ -131,6 +133,7 @@ where
     // Record the start of the process.
     let proving_start = Instant::now();
     let span = tracing::Span::current().clone();
+    let user_syscalls = &user_syscalls;
     std::thread::scope(move |s| {
         let _span = span.enter();
 
@@ -224,6 +227,7 @@ where
                                         &checkpoint,
                                         opts,
                                         shape_config,
+                                        user_syscalls,
                                     )
                                 });
 
@@ -595,6 +599,7 @@ pub fn trace_checkpoint<SC: StarkGenericConfig>(
     file: &File,
     opts: MONEROCHANCoreOpts,
     shape_config: Option<&CoreShapeConfig<SC::Val>>,
+    user_syscalls: &UserSyscalls,
 ) -> (Vec<ExecutionRecord>, ExecutionReport)
 where
     <SC as StarkGenericConfig>::Val: PrimeField32,
@@ -605,6 +610,7 @@ where
     let state: ExecutionState =
         bincode::deserialize_from(&mut reader).expect("failed to deserialize state");
     let mut runtime = Executor::recover(program, state, opts);
+    runtime.register_user_syscalls(user_syscalls);
     runtime.maximal_shapes = shape_config.map(|config| {
         config.maximal_core_shapes(opts.shard_size.ilog2() as usize).into_iter().collect()
     });
@@ -644,4 +650,6 @@ pub enum MONEROCHANCoreProverError {
     IoError(io::Error),
     #[error("serialization error: {0}")]
     SerializationError(bincode::Error),
+    #[error("invalid FRI configuration: {0}")]
+    FriConfig(FriConfigError),
 }
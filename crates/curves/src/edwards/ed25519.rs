@@ -119,6 +119,38 @@ pub fn ed25519_sqrt(a: &BigUint) -> Option<BigUint> {
     Some(beta)
 }
 
+/// The constant `(A - 2) / 4` of the Montgomery form `v^2 = u^3 + A * u^2 + u` of Curve25519.
+pub const CURVE25519_A24: u32 = 121665;
+
+/// Computes one step of the X25519 Montgomery ladder of RFC 7748.
+///
+/// The state holds the projective u-coordinates `(x2 : z2)` and `(x3 : z3)` of two points whose
+/// difference has the affine u-coordinate `x1`. The result is the state `[x2, z2, x3, z3]` with the
+/// first point doubled and the second point replaced by the sum of both points.
+pub fn x25519_ladder_step(x1: &BigUint, state: &[BigUint; 4]) -> [BigUint; 4] {
+    let modulus = &Ed25519BaseField::modulus();
+    let [x2, z2, x3, z3] = state;
+
+    let a = (x2 + z2) % modulus;
+    let aa = (&a * &a) % modulus;
+    let b = (x2 + modulus - z2 % modulus) % modulus;
+    let bb = (&b * &b) % modulus;
+    let e = (&aa + modulus - &bb) % modulus;
+    let c = (x3 + z3) % modulus;
+    let d = (x3 + modulus - z3 % modulus) % modulus;
+    let da = (d * a) % modulus;
+    let cb = (c * b) % modulus;
+
+    let da_plus_cb = (&da + &cb) % modulus;
+    let da_minus_cb = (&da + modulus - &cb) % modulus;
+    let x3 = (&da_plus_cb * &da_plus_cb) % modulus;
+    let z3 = (x1 * &da_minus_cb * &da_minus_cb) % modulus;
+    let x2 = (&aa * &bb) % modulus;
+    let z2 = (&e * (aa + &e * CURVE25519_A24)) % modulus;
+
+    [x2, z2, x3, z3]
+}
+
 pub fn decompress(compressed_point: &CompressedEdwardsY) -> Option<AffinePoint<Ed25519>> {
     let mut point_bytes = *compressed_point.as_bytes();
     let sign = point_bytes[31] >> 7 == 1;
@@ -149,7 +181,7 @@ pub fn decompress(compressed_point: &CompressedEdwardsY) -> Option<AffinePoint<E
 mod tests {
 
     use super::*;
-    use num::traits::ToBytes;
+    use num::{traits::ToBytes, Zero};
 
     const NUM_TEST_CASES: usize = 100;
 
@@ -184,4 +216,70 @@ mod tests {
             point = point.clone() + point.clone();
         }
     }
+
+    fn decode_hex(s: &str) -> Vec<u8> {
+        (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+    }
+
+    /// The X25519 function of RFC 7748, built on [`x25519_ladder_step`].
+    fn x25519(scalar: &str, u: &str) -> String {
+        let mut scalar = decode_hex(scalar);
+        scalar[0] &= 248;
+        scalar[31] &= 127;
+        scalar[31] |= 64;
+        let scalar = BigUint::from_bytes_le(&scalar);
+
+        let mut u = decode_hex(u);
+        u[31] &= 127;
+        let modulus = Ed25519BaseField::modulus();
+        let x1 = BigUint::from_bytes_le(&u) % &modulus;
+
+        let mut state = [BigUint::one(), BigUint::zero(), x1.clone(), BigUint::one()];
+        let mut swap = false;
+        for t in (0..255).rev() {
+            let bit = scalar.bit(t);
+            if swap != bit {
+                state.swap(0, 2);
+                state.swap(1, 3);
+            }
+            swap = bit;
+            state = x25519_ladder_step(&x1, &state);
+        }
+        if swap {
+            state.swap(0, 2);
+            state.swap(1, 3);
+        }
+
+        let [x2, z2, _, _] = state;
+        let z2_inv = z2.modpow(&(&modulus - BigUint::from(2u32)), &modulus);
+        let mut result = ((x2 * z2_inv) % &modulus).to_bytes_le();
+        result.resize(32, 0);
+        result.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    #[test]
+    fn test_x25519_rfc7748_vectors() {
+        // The test vectors of RFC 7748, section 5.2.
+        assert_eq!(
+            x25519(
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c"
+            ),
+            "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552"
+        );
+        assert_eq!(
+            x25519(
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493"
+            ),
+            "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957"
+        );
+
+        // One iteration of the iterated test, starting from the base point.
+        let base = "0900000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(
+            x25519(base, base),
+            "422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079"
+        );
+    }
 }
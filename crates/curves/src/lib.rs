@@ -71,6 +71,9 @@ pub enum CurveType {
     Bn254,
     Ed25519,
     Bls12381,
+    Pallas,
+    Vesta,
+    Grumpkin,
 }
 
 impl Display for CurveType {
@@ -81,6 +84,9 @@ impl Display for CurveType {
             CurveType::Bn254 => write!(f, "Bn254"),
             CurveType::Ed25519 => write!(f, "Ed25519"),
             CurveType::Bls12381 => write!(f, "Bls12381"),
+            CurveType::Pallas => write!(f, "Pallas"),
+            CurveType::Vesta => write!(f, "Vesta"),
+            CurveType::Grumpkin => write!(f, "Grumpkin"),
         }
     }
 }
@@ -1,4 +1,4 @@
-use typenum::{U32, U63};
+use typenum::{U1023, U256, U32, U511, U512, U63};
 
 use num::{BigUint, One};
 use serde::{Deserialize, Serialize};
@@ -33,3 +33,59 @@ impl NumLimbs for U256Field {
     // limb.
     type Witness = U63;
 }
+
+/// The modulus `2^2048` of [`U2048Field`], as a little-endian array of 257 bytes.
+const U2048_MODULUS: [u8; 257] = {
+    let mut modulus = [0u8; 257];
+    modulus[256] = 1;
+    modulus
+};
+
+/// The modulus `2^4096` of [`U4096Field`], as a little-endian array of 513 bytes.
+const U4096_MODULUS: [u8; 513] = {
+    let mut modulus = [0u8; 513];
+    modulus[512] = 1;
+    modulus
+};
+
+/// The parameters of the 2048-bit modular multiplication, used in the same way as [`U256Field`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct U2048Field;
+
+impl FieldParameters for U2048Field {
+    const MODULUS: &'static [u8] = &U2048_MODULUS;
+
+    /// The witness coefficients of a product of 256 limbs are bounded by `2^17`.
+    const WITNESS_OFFSET: usize = 1usize << 17;
+
+    /// The modulus of Uint2048 is 2^2048.
+    fn modulus() -> BigUint {
+        BigUint::one() << 2048
+    }
+}
+
+impl NumLimbs for U2048Field {
+    type Limbs = U256;
+    type Witness = U511;
+}
+
+/// The parameters of the 4096-bit modular multiplication, used in the same way as [`U256Field`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct U4096Field;
+
+impl FieldParameters for U4096Field {
+    const MODULUS: &'static [u8] = &U4096_MODULUS;
+
+    /// The witness coefficients of a product of 512 limbs are bounded by `2^17`.
+    const WITNESS_OFFSET: usize = 1usize << 17;
+
+    /// The modulus of Uint4096 is 2^4096.
+    fn modulus() -> BigUint {
+        BigUint::one() << 4096
+    }
+}
+
+impl NumLimbs for U4096Field {
+    type Limbs = U512;
+    type Witness = U1023;
+}
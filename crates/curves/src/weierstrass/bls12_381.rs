@@ -4,7 +4,7 @@ use num::{BigUint, Num, Zero};
 use serde::{Deserialize, Serialize};
 use typenum::{U48, U94};
 
-use super::{FieldType, FpOpField, SwCurve, WeierstrassParameters};
+use super::{FieldType, FpOpField, FpTowerField, SwCurve, WeierstrassParameters};
 use crate::{
     params::{FieldParameters, NumLimbs},
     CurveType, EllipticCurveParameters,
@@ -55,6 +55,10 @@ impl FpOpField for Bls12381BaseField {
     const FIELD_TYPE: FieldType = FieldType::Bls12381;
 }
 
+impl FpTowerField for Bls12381BaseField {
+    const TOWER_NONRESIDUE: u32 = 1;
+}
+
 impl NumLimbs for Bls12381BaseField {
     type Limbs = U48;
     type Witness = U94;
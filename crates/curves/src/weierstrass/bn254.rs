@@ -3,7 +3,7 @@ use num::{BigUint, Num, Zero};
 use serde::{Deserialize, Serialize};
 use typenum::{U32, U62};
 
-use super::{FieldType, FpOpField, SwCurve, WeierstrassParameters};
+use super::{FieldType, FpOpField, FpTowerField, SwCurve, WeierstrassParameters};
 use crate::{
     params::{FieldParameters, NumLimbs},
     CurveType, EllipticCurveParameters,
@@ -43,6 +43,10 @@ impl FpOpField for Bn254BaseField {
     const FIELD_TYPE: FieldType = FieldType::Bn254;
 }
 
+impl FpTowerField for Bn254BaseField {
+    const TOWER_NONRESIDUE: u32 = 9;
+}
+
 impl NumLimbs for Bn254BaseField {
     type Limbs = U32;
     type Witness = U62;
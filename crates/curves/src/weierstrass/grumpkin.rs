@@ -0,0 +1,110 @@
+//! Modulo defining the Grumpkin curve and its base field. Grumpkin forms a cycle with BN254: its
+//! base field is the scalar field of BN254 and its scalar field is the base field of BN254. The
+//! constants are all taken from https://github.com/AztecProtocol/aztec-packages.
+
+use generic_array::GenericArray;
+use num::{BigUint, Num, Zero};
+use serde::{Deserialize, Serialize};
+use typenum::{U32, U62};
+
+use super::{bn254::Bn254BaseField, SwCurve, WeierstrassParameters};
+use crate::{
+    params::{FieldParameters, NumLimbs},
+    CurveType, EllipticCurveParameters,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Grumpkin curve parameter
+pub struct GrumpkinParameters;
+
+pub type Grumpkin = SwCurve<GrumpkinParameters>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Grumpkin base field parameter, which is the scalar field of BN254.
+pub struct GrumpkinBaseField;
+
+impl FieldParameters for GrumpkinBaseField {
+    const MODULUS: &'static [u8] = &[
+        0x01, 0x00, 0x00, 0xf0, 0x93, 0xf5, 0xe1, 0x43, 0x91, 0x70, 0xb9, 0x79, 0x48, 0xe8, 0x33,
+        0x28, 0x5d, 0x58, 0x81, 0x81, 0xb6, 0x45, 0x50, 0xb8, 0x29, 0xa0, 0x31, 0xe1, 0x72, 0x4e,
+        0x64, 0x30,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_str_radix(
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+            10,
+        )
+        .unwrap()
+    }
+}
+
+impl NumLimbs for GrumpkinBaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for GrumpkinParameters {
+    type BaseField = GrumpkinBaseField;
+
+    const CURVE_TYPE: CurveType = CurveType::Grumpkin;
+}
+
+impl WeierstrassParameters for GrumpkinParameters {
+    const A: GenericArray<u8, U32> = GenericArray::from_array([
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ]);
+
+    /// The coefficient `b = -17`, reduced modulo the base field.
+    const B: GenericArray<u8, U32> = GenericArray::from_array([
+        0xf0, 0xff, 0xff, 0xef, 0x93, 0xf5, 0xe1, 0x43, 0x91, 0x70, 0xb9, 0x79, 0x48, 0xe8, 0x33,
+        0x28, 0x5d, 0x58, 0x81, 0x81, 0xb6, 0x45, 0x50, 0xb8, 0x29, 0xa0, 0x31, 0xe1, 0x72, 0x4e,
+        0x64, 0x30,
+    ]);
+
+    /// The generator `(1, sqrt(-16))`.
+    fn generator() -> (BigUint, BigUint) {
+        let x = BigUint::from(1u32);
+        let y = BigUint::from_str_radix(
+            "17631683881184975370165255887551781615748388533673675138860",
+            10,
+        )
+        .unwrap();
+        (x, y)
+    }
+
+    fn prime_group_order() -> num::BigUint {
+        Bn254BaseField::modulus()
+    }
+
+    fn a_int() -> BigUint {
+        BigUint::zero()
+    }
+
+    fn b_int() -> BigUint {
+        GrumpkinBaseField::modulus() - 17u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::biguint_from_limbs;
+
+    #[test]
+    fn test_weierstrass_biguint_scalar_mul() {
+        assert_eq!(biguint_from_limbs(GrumpkinBaseField::MODULUS), GrumpkinBaseField::modulus());
+        assert_eq!(biguint_from_limbs(&GrumpkinParameters::B), GrumpkinParameters::b_int());
+    }
+
+    #[test]
+    fn test_grumpkin_generator_on_curve() {
+        let (x, y) = GrumpkinParameters::generator();
+        let modulus = GrumpkinBaseField::modulus();
+        assert_eq!((&y * &y) % &modulus, (&x * &x * &x + GrumpkinParameters::b_int()) % &modulus);
+    }
+}
@@ -14,8 +14,11 @@ use crate::utils::{biguint_to_rug, rug_to_biguint};
 
 pub mod bls12_381;
 pub mod bn254;
+pub mod grumpkin;
+pub mod pasta;
 pub mod secp256k1;
 pub mod secp256r1;
+pub mod tower;
 
 /// Parameters that specify a short Weierstrass curve : y^2 = x^3 + ax + b.
 pub trait WeierstrassParameters: EllipticCurveParameters {
@@ -47,6 +50,30 @@ pub trait WeierstrassParameters: EllipticCurveParameters {
     }
 }
 
+/// Parameters of a prime order Weierstrass curve over which ECDSA signatures are verified.
+pub trait EcdsaParameters: WeierstrassParameters {
+    /// The scalar field of the curve, whose modulus is the prime group order.
+    type ScalarField: FieldParameters + NumWords;
+
+    /// A quadratic non-residue of the base field.
+    fn nqr() -> BigUint;
+
+    /// A point of the curve with unknown discrete logarithm.
+    ///
+    /// It is hashed from a fixed tag by try-and-increment, and used as the starting value of the
+    /// double-and-add accumulator of the ECDSA precompiles so that the accumulator never meets
+    /// the points added to it.
+    fn ecdsa_offset() -> (BigUint, BigUint);
+
+    /// The point `-[2^256] O`, where `O` is [`EcdsaParameters::ecdsa_offset`].
+    ///
+    /// Adding it to the accumulator after 256 doublings removes the offset.
+    fn ecdsa_offset_correction() -> (BigUint, BigUint);
+
+    /// Returns a square root of `n` in the base field, or `None` if `n` is not a square.
+    fn sqrt(n: &BigUint) -> Option<BigUint>;
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SwCurve<E>(pub E);
 
@@ -75,6 +102,26 @@ impl<E: WeierstrassParameters> WeierstrassParameters for SwCurve<E> {
     }
 }
 
+impl<E: EcdsaParameters> EcdsaParameters for SwCurve<E> {
+    type ScalarField = E::ScalarField;
+
+    fn nqr() -> BigUint {
+        E::nqr()
+    }
+
+    fn ecdsa_offset() -> (BigUint, BigUint) {
+        E::ecdsa_offset()
+    }
+
+    fn ecdsa_offset_correction() -> (BigUint, BigUint) {
+        E::ecdsa_offset_correction()
+    }
+
+    fn sqrt(n: &BigUint) -> Option<BigUint> {
+        E::sqrt(n)
+    }
+}
+
 impl<E: WeierstrassParameters> EllipticCurveParameters for SwCurve<E> {
     type BaseField = E::BaseField;
 
@@ -283,12 +330,24 @@ impl<E: WeierstrassParameters> AffinePoint<SwCurve<E>> {
 pub enum FieldType {
     Bls12381,
     Bn254,
+    Secp256k1,
+    Secp256k1Scalar,
+    Secp256r1,
+    Secp256r1Scalar,
 }
 
+/// A prime field with add, sub and mul precompiles.
 pub trait FpOpField: FieldParameters + NumWords {
     const FIELD_TYPE: FieldType;
 }
 
+/// The base field of a pairing-friendly curve, with the `Fp2`, `Fp6` and `Fp12` precompiles.
+pub trait FpTowerField: FpOpField {
+    /// The real part `c` of the non-residue `xi = c + u` that defines the extensions
+    /// `Fp6 = Fp2[v] / (v^3 - xi)` and `Fp12 = Fp6[w] / (w^2 - v)`.
+    const TOWER_NONRESIDUE: u32;
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -0,0 +1,187 @@
+//! Modulo defining the Pallas and Vesta curves and their base fields. The two curves form a cycle:
+//! the base field of each one is the scalar field of the other. The constants are all taken from
+//! https://github.com/zcash/pasta_curves.
+
+use generic_array::GenericArray;
+use num::{BigUint, Num, Zero};
+use serde::{Deserialize, Serialize};
+use typenum::{U32, U62};
+
+use super::{SwCurve, WeierstrassParameters};
+use crate::{
+    params::{FieldParameters, NumLimbs},
+    CurveType, EllipticCurveParameters,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Pallas curve parameter
+pub struct PallasParameters;
+
+pub type Pallas = SwCurve<PallasParameters>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Pallas base field parameter
+pub struct PallasBaseField;
+
+impl FieldParameters for PallasBaseField {
+    const MODULUS: &'static [u8] = &[
+        0x01, 0x00, 0x00, 0x00, 0xed, 0x30, 0x2d, 0x99, 0x1b, 0xf9, 0x4c, 0x09, 0xfc, 0x98, 0x46,
+        0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x40,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_str_radix(
+            "40000000000000000000000000000000224698fc094cf91b992d30ed00000001",
+            16,
+        )
+        .unwrap()
+    }
+}
+
+impl NumLimbs for PallasBaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for PallasParameters {
+    type BaseField = PallasBaseField;
+
+    const CURVE_TYPE: CurveType = CurveType::Pallas;
+}
+
+impl WeierstrassParameters for PallasParameters {
+    const A: GenericArray<u8, U32> = GenericArray::from_array([
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ]);
+
+    const B: GenericArray<u8, U32> = GenericArray::from_array([
+        5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ]);
+
+    /// The generator `(-1, 2)`.
+    fn generator() -> (BigUint, BigUint) {
+        let x = PallasBaseField::modulus() - 1u32;
+        let y = BigUint::from(2u32);
+        (x, y)
+    }
+
+    fn prime_group_order() -> num::BigUint {
+        VestaBaseField::modulus()
+    }
+
+    fn a_int() -> BigUint {
+        BigUint::zero()
+    }
+
+    fn b_int() -> BigUint {
+        BigUint::from(5u32)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Vesta curve parameter
+pub struct VestaParameters;
+
+pub type Vesta = SwCurve<VestaParameters>;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Vesta base field parameter
+pub struct VestaBaseField;
+
+impl FieldParameters for VestaBaseField {
+    const MODULUS: &'static [u8] = &[
+        0x01, 0x00, 0x00, 0x00, 0x21, 0xeb, 0x46, 0x8c, 0xdd, 0xa8, 0x94, 0x09, 0xfc, 0x98, 0x46,
+        0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x40,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_str_radix(
+            "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001",
+            16,
+        )
+        .unwrap()
+    }
+}
+
+impl NumLimbs for VestaBaseField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl EllipticCurveParameters for VestaParameters {
+    type BaseField = VestaBaseField;
+
+    const CURVE_TYPE: CurveType = CurveType::Vesta;
+}
+
+impl WeierstrassParameters for VestaParameters {
+    const A: GenericArray<u8, U32> = GenericArray::from_array([
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ]);
+
+    const B: GenericArray<u8, U32> = GenericArray::from_array([
+        5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ]);
+
+    /// The generator `(-1, 2)`.
+    fn generator() -> (BigUint, BigUint) {
+        let x = VestaBaseField::modulus() - 1u32;
+        let y = BigUint::from(2u32);
+        (x, y)
+    }
+
+    fn prime_group_order() -> num::BigUint {
+        PallasBaseField::modulus()
+    }
+
+    fn a_int() -> BigUint {
+        BigUint::zero()
+    }
+
+    fn b_int() -> BigUint {
+        BigUint::from(5u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{utils::biguint_from_limbs, EllipticCurve};
+
+    #[test]
+    fn test_weierstrass_biguint_scalar_mul() {
+        assert_eq!(biguint_from_limbs(PallasBaseField::MODULUS), PallasBaseField::modulus());
+        assert_eq!(biguint_from_limbs(VestaBaseField::MODULUS), VestaBaseField::modulus());
+    }
+
+    #[test]
+    fn test_pasta_generators_on_curve() {
+        fn assert_on_curve<E: WeierstrassParameters>() {
+            let (x, y) = E::generator();
+            let modulus = E::BaseField::modulus();
+            assert_eq!((&y * &y) % &modulus, (&x * &x * &x + E::b_int()) % &modulus);
+        }
+        assert_on_curve::<PallasParameters>();
+        assert_on_curve::<VestaParameters>();
+    }
+
+    #[test]
+    fn test_pallas_double_matches_add() {
+        let generator = Pallas::ec_generator();
+        let doubled = Pallas::ec_double(&generator);
+        let tripled = Pallas::ec_add(&doubled, &generator);
+        assert_eq!(Pallas::ec_add(&tripled, &generator), Pallas::ec_double(&doubled));
+    }
+}
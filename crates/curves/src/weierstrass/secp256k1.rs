@@ -8,12 +8,12 @@ use generic_array::GenericArray;
 use k256::{elliptic_curve::point::DecompressPoint, FieldElement};
 use num::{
     traits::{FromBytes, ToBytes},
-    BigUint, Zero,
+    BigUint, One, Zero,
 };
 use serde::{Deserialize, Serialize};
 use typenum::{U32, U62};
 
-use super::{SwCurve, WeierstrassParameters};
+use super::{EcdsaParameters, FieldType, FpOpField, SwCurve, WeierstrassParameters};
 use crate::{
     params::{FieldParameters, NumLimbs},
     AffinePoint, CurveType, EllipticCurve, EllipticCurveParameters,
@@ -49,6 +49,38 @@ impl NumLimbs for Secp256k1BaseField {
     type Witness = U62;
 }
 
+impl FpOpField for Secp256k1BaseField {
+    const FIELD_TYPE: FieldType = FieldType::Secp256k1;
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Secp256k1 scalar field parameter
+pub struct Secp256k1ScalarField;
+
+impl FieldParameters for Secp256k1ScalarField {
+    const MODULUS: &'static [u8] = &[
+        0x41, 0x41, 0x36, 0xd0, 0x8c, 0x5e, 0xd2, 0xbf, 0x3b, 0xa0, 0x48, 0xaf, 0xe6, 0xdc, 0xae,
+        0xba, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_bytes_le(Self::MODULUS)
+    }
+}
+
+impl NumLimbs for Secp256k1ScalarField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl FpOpField for Secp256k1ScalarField {
+    const FIELD_TYPE: FieldType = FieldType::Secp256k1Scalar;
+}
+
 impl EllipticCurveParameters for Secp256k1Parameters {
     type BaseField = Secp256k1BaseField;
     const CURVE_TYPE: CurveType = CurveType::Secp256k1;
@@ -92,6 +124,51 @@ impl WeierstrassParameters for Secp256k1Parameters {
     }
 }
 
+impl EcdsaParameters for Secp256k1Parameters {
+    type ScalarField = Secp256k1ScalarField;
+
+    fn nqr() -> BigUint {
+        BigUint::from(3u32)
+    }
+
+    /// Hashed from the tag `MONEROCHAN_ECDSA_OFFSET_secp256k1` with SHA-256.
+    fn ecdsa_offset() -> (BigUint, BigUint) {
+        (
+            BigUint::parse_bytes(
+                b"34364e0ece004890bf227f00c0e0788fd55884c138b61f8be0a0247dcbc9f00e",
+                16,
+            )
+            .unwrap(),
+            BigUint::parse_bytes(
+                b"360b07bba261c6396d6e408b6e1b1ad737322c98bc8365ed5e63aacc26f3d296",
+                16,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn ecdsa_offset_correction() -> (BigUint, BigUint) {
+        (
+            BigUint::parse_bytes(
+                b"88c87045d7d73f895cfb395c540ef639e142cb6a97f60ce6c07bae4dbe3c3319",
+                16,
+            )
+            .unwrap(),
+            BigUint::parse_bytes(
+                b"aef2bda14751e10f7251f52671f43b2b64d53338a098a57c924c1f6995b81de7",
+                16,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn sqrt(n: &BigUint) -> Option<BigUint> {
+        let modulus = Self::BaseField::modulus();
+        let euler = n.modpow(&((&modulus - 1u32) >> 1), &modulus);
+        (euler.is_zero() || euler.is_one()).then(|| secp256k1_sqrt(n))
+    }
+}
+
 pub fn secp256k1_decompress<E: EllipticCurve>(bytes_be: &[u8], sign: u32) -> AffinePoint<E> {
     let computed_point =
         k256::AffinePoint::decompress(bytes_be.into(), Choice::from(sign as u8)).unwrap();
@@ -125,6 +202,28 @@ mod tests {
         assert_eq!(biguint_from_limbs(Secp256k1BaseField::MODULUS), Secp256k1BaseField::modulus());
     }
 
+    #[test]
+    fn test_secp256k1_scalar_field_modulus() {
+        assert_eq!(Secp256k1ScalarField::modulus(), Secp256k1Parameters::prime_group_order());
+    }
+
+    #[test]
+    fn test_secp256k1_ecdsa_offset() {
+        let modulus = Secp256k1BaseField::modulus();
+        let (x, y) = Secp256k1Parameters::ecdsa_offset();
+        let rhs = (&x * &x * &x + Secp256k1Parameters::a_int() * &x + Secp256k1Parameters::b_int()) %
+            &modulus;
+        assert_eq!((&y * &y) % &modulus, rhs);
+        assert!(Secp256k1Parameters::sqrt(&(Secp256k1Parameters::nqr() * rhs % &modulus)).is_none());
+
+        let mut point = AffinePoint::<Secp256k1>::new(x, y);
+        for _ in 0..256 {
+            point = point.sw_double();
+        }
+        let (x, y) = Secp256k1Parameters::ecdsa_offset_correction();
+        assert_eq!(point, -AffinePoint::<Secp256k1>::new(x, y));
+    }
+
     #[test]
     fn test_secp256k_sqrt() {
         let mut rng = thread_rng();
@@ -7,13 +7,13 @@ use elliptic_curve::{sec1::ToEncodedPoint, subtle::Choice};
 use generic_array::GenericArray;
 use num::{
     traits::{FromBytes, ToBytes},
-    BigUint,
+    BigUint, One, Zero,
 };
 use p256::{elliptic_curve::point::DecompressPoint, FieldElement};
 use serde::{Deserialize, Serialize};
 use typenum::{U32, U62};
 
-use super::{SwCurve, WeierstrassParameters};
+use super::{EcdsaParameters, FieldType, FpOpField, SwCurve, WeierstrassParameters};
 use crate::{
     params::{FieldParameters, NumLimbs},
     AffinePoint, CurveType, EllipticCurve, EllipticCurveParameters,
@@ -48,6 +48,38 @@ impl NumLimbs for Secp256r1BaseField {
     type Witness = U62;
 }
 
+impl FpOpField for Secp256r1BaseField {
+    const FIELD_TYPE: FieldType = FieldType::Secp256r1;
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+/// Secp256r1 scalar field parameter
+pub struct Secp256r1ScalarField;
+
+impl FieldParameters for Secp256r1ScalarField {
+    const MODULUS: &'static [u8] = &[
+        0x51, 0x25, 0x63, 0xfc, 0xc2, 0xca, 0xb9, 0xf3, 0x84, 0x9e, 0x17, 0xa7, 0xad, 0xfa, 0xe6,
+        0xbc, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+        0xff, 0xff,
+    ];
+
+    /// A rough witness-offset estimate given the size of the limbs and the size of the field.
+    const WITNESS_OFFSET: usize = 1usize << 14;
+
+    fn modulus() -> BigUint {
+        BigUint::from_bytes_le(Self::MODULUS)
+    }
+}
+
+impl NumLimbs for Secp256r1ScalarField {
+    type Limbs = U32;
+    type Witness = U62;
+}
+
+impl FpOpField for Secp256r1ScalarField {
+    const FIELD_TYPE: FieldType = FieldType::Secp256r1Scalar;
+}
+
 impl EllipticCurveParameters for Secp256r1Parameters {
     type BaseField = Secp256r1BaseField;
     const CURVE_TYPE: CurveType = CurveType::Secp256r1;
@@ -98,6 +130,51 @@ impl WeierstrassParameters for Secp256r1Parameters {
     }
 }
 
+impl EcdsaParameters for Secp256r1Parameters {
+    type ScalarField = Secp256r1ScalarField;
+
+    fn nqr() -> BigUint {
+        BigUint::from(3u32)
+    }
+
+    /// Hashed from the tag `MONEROCHAN_ECDSA_OFFSET_secp256r1` with SHA-256.
+    fn ecdsa_offset() -> (BigUint, BigUint) {
+        (
+            BigUint::parse_bytes(
+                b"e9406052f3da6052e0453587b30f7bb762239c3e3418ab21947c16d641af3555",
+                16,
+            )
+            .unwrap(),
+            BigUint::parse_bytes(
+                b"e7a8329637e0a85d5245b1142a1b6e08f84f85c0d2ce53360f5123b847e9f69c",
+                16,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn ecdsa_offset_correction() -> (BigUint, BigUint) {
+        (
+            BigUint::parse_bytes(
+                b"edbd1b7c7d2fde66d6edbbd49c0498e3512e8d45ecb03270e5b3af9b8342aa57",
+                16,
+            )
+            .unwrap(),
+            BigUint::parse_bytes(
+                b"e99376964906102b83927aa389f5e57bd8b54a610441047f03d39f31a45df01d",
+                16,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn sqrt(n: &BigUint) -> Option<BigUint> {
+        let modulus = Self::BaseField::modulus();
+        let euler = n.modpow(&((&modulus - 1u32) >> 1), &modulus);
+        (euler.is_zero() || euler.is_one()).then(|| secp256r1_sqrt(n))
+    }
+}
+
 pub fn secp256r1_decompress<E: EllipticCurve>(bytes_be: &[u8], sign: u32) -> AffinePoint<E> {
     let computed_point =
         p256::AffinePoint::decompress(bytes_be.into(), Choice::from(sign as u8)).unwrap();
@@ -130,6 +207,28 @@ mod tests {
         assert_eq!(biguint_from_limbs(Secp256r1BaseField::MODULUS), Secp256r1BaseField::modulus());
     }
 
+    #[test]
+    fn test_secp256r1_scalar_field_modulus() {
+        assert_eq!(Secp256r1ScalarField::modulus(), Secp256r1Parameters::prime_group_order());
+    }
+
+    #[test]
+    fn test_secp256r1_ecdsa_offset() {
+        let modulus = Secp256r1BaseField::modulus();
+        let (x, y) = Secp256r1Parameters::ecdsa_offset();
+        let rhs = (&x * &x * &x + Secp256r1Parameters::a_int() * &x + Secp256r1Parameters::b_int()) %
+            &modulus;
+        assert_eq!((&y * &y) % &modulus, rhs);
+        assert!(Secp256r1Parameters::sqrt(&(Secp256r1Parameters::nqr() * rhs % &modulus)).is_none());
+
+        let mut point = AffinePoint::<Secp256r1>::new(x, y);
+        for _ in 0..256 {
+            point = point.sw_double();
+        }
+        let (x, y) = Secp256r1Parameters::ecdsa_offset_correction();
+        assert_eq!(point, -AffinePoint::<Secp256r1>::new(x, y));
+    }
+
     #[test]
     fn test_secp256r_sqrt() {
         let mut rng = thread_rng();
@@ -0,0 +1,268 @@
+//! Arithmetic in the sextic towers `Fp2 -> Fp6 -> Fp12` used by the BN254 and BLS12-381 pairings.
+//!
+//! The towers are `Fp2 = Fp[u] / (u^2 + 1)`, `Fp6 = Fp2[v] / (v^3 - xi)` and
+//! `Fp12 = Fp6[w] / (w^2 - v)`, where `xi = c + u` and `c` is [`FpTowerField::TOWER_NONRESIDUE`].
+//! Elements are laid out in memory as the concatenation of their `Fp` coefficients, starting with
+//! the lowest degree ones: an `Fp6` element `c0 + c1 v + c2 v^2` is stored as
+//! `[c0.re, c0.im, c1.re, c1.im, c2.re, c2.im]` and an `Fp12` element `c0 + c1 w` as the `Fp6`
+//! coefficient `c0` followed by `c1`.
+//!
+//! The G2 subgroups of both curves live on twists `y^2 = x^3 + b'` over `Fp2`; since `a = 0` for
+//! both twists the addition and doubling formulas do not depend on `b'`.
+
+use num::{BigUint, Zero};
+
+use super::FpTowerField;
+
+/// The number of `Fp2` coefficients of an `Fp6` element.
+pub const FP6_DEGREE: usize = 3;
+
+/// The number of `Fp2` coefficients of an `Fp12` element.
+pub const FP12_DEGREE: usize = 6;
+
+/// Returns the power of the generator of `Fp[k]` over `Fp2` (`v` for `Fp6` and `w` for `Fp12`)
+/// attached to the `Fp2` coefficient at `index` in the memory layout.
+fn fp2_coefficient_power(degree: usize, index: usize) -> usize {
+    match degree {
+        FP6_DEGREE => index,
+        // The coefficient `c_{h, i}` of `(c_{0,0} + c_{0,1} v + c_{0,2} v^2) + (..) w` is attached
+        // to `v^i w^h = w^{2i + h}`.
+        FP12_DEGREE => 2 * (index % FP6_DEGREE) + index / FP6_DEGREE,
+        _ => panic!("unsupported extension degree: {degree}"),
+    }
+}
+
+/// Returns, for every `Fp` coefficient of the product `x * y` of two elements of the extension of
+/// degree `degree` over `Fp2`, the list of terms `(c, i, j)` such that the coefficient is equal to
+/// `sum c * x[i] * y[j] mod p`, where `x[i]`, `y[j]` are `Fp` coefficients in the memory layout.
+pub fn fp_tower_mul_terms(degree: usize, nonresidue: u32) -> Vec<Vec<(i32, usize, usize)>> {
+    let xi = nonresidue as i32;
+    let mut index_of_power = vec![0; degree];
+    for index in 0..degree {
+        index_of_power[fp2_coefficient_power(degree, index)] = index;
+    }
+
+    let mut terms = vec![Vec::new(); 2 * degree];
+    for i in 0..degree {
+        for j in 0..degree {
+            let power = fp2_coefficient_power(degree, i) + fp2_coefficient_power(degree, j);
+            let (re, im) = (2 * i, 2 * i + 1);
+            let (y_re, y_im) = (2 * j, 2 * j + 1);
+
+            // The real and imaginary part of the product of the two `Fp2` coefficients.
+            let product_re = [(1, re, y_re), (-1, im, y_im)];
+            let product_im = [(1, re, y_im), (1, im, y_re)];
+
+            if power < degree {
+                let k = index_of_power[power];
+                terms[2 * k].extend(product_re);
+                terms[2 * k + 1].extend(product_im);
+            } else {
+                // The generator raised to the `degree` is `xi = c + u`, and
+                // `(a + b u) (c + u) = (c a - b) + (a + c b) u`.
+                let k = index_of_power[power - degree];
+                terms[2 * k].extend(product_re.iter().map(|&(s, a, b)| (xi * s, a, b)));
+                terms[2 * k].extend(product_im.iter().map(|&(s, a, b)| (-s, a, b)));
+                terms[2 * k + 1].extend(product_re);
+                terms[2 * k + 1].extend(product_im.iter().map(|&(s, a, b)| (xi * s, a, b)));
+            }
+        }
+    }
+    terms
+}
+
+/// Evaluates a list of terms as returned by [`fp_tower_mul_terms`] modulo `modulus`.
+pub fn eval_terms(
+    terms: &[(i32, usize, usize)],
+    x: &[BigUint],
+    y: &[BigUint],
+    modulus: &BigUint,
+) -> BigUint {
+    let (positive, negative) =
+        terms.iter().fold((BigUint::zero(), BigUint::zero()), |(pos, neg), &(c, i, j)| {
+            let product = BigUint::from(c.unsigned_abs()) * &x[i] * &y[j];
+            if c >= 0 {
+                (pos + product, neg)
+            } else {
+                (pos, neg + product)
+            }
+        });
+    let negative = negative % modulus;
+    (positive + modulus - negative) % modulus
+}
+
+/// Multiplies two elements of the extension of degree `degree` over `Fp2`, given by their `Fp`
+/// coefficients in the memory layout.
+pub fn fp_tower_mul<P: FpTowerField>(degree: usize, x: &[BigUint], y: &[BigUint]) -> Vec<BigUint> {
+    let modulus = P::modulus();
+    fp_tower_mul_terms(degree, P::TOWER_NONRESIDUE)
+        .iter()
+        .map(|terms| eval_terms(terms, x, y, &modulus))
+        .collect()
+}
+
+fn fp2_mul(a: &[BigUint; 2], b: &[BigUint; 2], modulus: &BigUint) -> [BigUint; 2] {
+    let re = (&a[0] * &b[0] + modulus * modulus - (&a[1] * &b[1]) % (modulus * modulus)) % modulus;
+    let im = (&a[0] * &b[1] + &a[1] * &b[0]) % modulus;
+    [re, im]
+}
+
+fn fp2_sub(a: &[BigUint; 2], b: &[BigUint; 2], modulus: &BigUint) -> [BigUint; 2] {
+    [(&a[0] + modulus - &b[0] % modulus) % modulus, (&a[1] + modulus - &b[1] % modulus) % modulus]
+}
+
+fn fp2_inv(a: &[BigUint; 2], modulus: &BigUint) -> [BigUint; 2] {
+    let norm = (&a[0] * &a[0] + &a[1] * &a[1]) % modulus;
+    assert!(!norm.is_zero(), "division by zero in Fp2");
+    let norm_inv = norm.modpow(&(modulus - BigUint::from(2u32)), modulus);
+    [(&a[0] * &norm_inv) % modulus, ((modulus - &a[1]) * &norm_inv) % modulus]
+}
+
+/// A point `(x, y)` on a twist over `Fp2`, given by the `Fp` coefficients
+/// `[x.re, x.im, y.re, y.im]`.
+pub type G2Point = [BigUint; 4];
+
+fn g2_from_slope(p: &G2Point, q_x: &[BigUint; 2], slope: &[BigUint; 2], m: &BigUint) -> G2Point {
+    let p_x = [p[0].clone(), p[1].clone()];
+    let p_y = [p[2].clone(), p[3].clone()];
+    let x = fp2_sub(&fp2_sub(&fp2_mul(slope, slope, m), &p_x, m), q_x, m);
+    let y = fp2_sub(&fp2_mul(slope, &fp2_sub(&p_x, &x, m), m), &p_y, m);
+    let [x_re, x_im] = x;
+    let [y_re, y_im] = y;
+    [x_re, x_im, y_re, y_im]
+}
+
+/// The slope `(q.y - p.y) / (q.x - p.x)` of the line through two points of a twist over `Fp2`.
+///
+/// Panics if `p.x == q.x`.
+pub fn g2_add_slope<P: FpTowerField>(p: &G2Point, q: &G2Point) -> [BigUint; 2] {
+    let m = &P::modulus();
+    let dx = fp2_sub(&[q[0].clone(), q[1].clone()], &[p[0].clone(), p[1].clone()], m);
+    let dy = fp2_sub(&[q[2].clone(), q[3].clone()], &[p[2].clone(), p[3].clone()], m);
+    fp2_mul(&dy, &fp2_inv(&dx, m), m)
+}
+
+/// The slope `3 p.x^2 / (2 p.y)` of the tangent at a point of a twist over `Fp2`.
+///
+/// Panics if `p.y == 0`.
+pub fn g2_double_slope<P: FpTowerField>(p: &G2Point) -> [BigUint; 2] {
+    let m = &P::modulus();
+    let x = [p[0].clone(), p[1].clone()];
+    let x_sq = fp2_mul(&x, &x, m);
+    let numerator = [(BigUint::from(3u32) * &x_sq[0]) % m, (BigUint::from(3u32) * &x_sq[1]) % m];
+    let denominator = [(BigUint::from(2u32) * &p[2]) % m, (BigUint::from(2u32) * &p[3]) % m];
+    fp2_mul(&numerator, &fp2_inv(&denominator, m), m)
+}
+
+/// Adds two points of a twist over `Fp2` with `p.x != q.x`.
+pub fn g2_add<P: FpTowerField>(p: &G2Point, q: &G2Point) -> G2Point {
+    let slope = g2_add_slope::<P>(p, q);
+    g2_from_slope(p, &[q[0].clone(), q[1].clone()], &slope, &P::modulus())
+}
+
+/// Doubles a point of a twist over `Fp2` with `p.y != 0`.
+pub fn g2_double<P: FpTowerField>(p: &G2Point) -> G2Point {
+    let slope = g2_double_slope::<P>(p);
+    g2_from_slope(p, &[p[0].clone(), p[1].clone()], &slope, &P::modulus())
+}
+
+#[cfg(test)]
+mod tests {
+    use num::{bigint::RandBigInt, BigUint, One};
+    use rand::thread_rng;
+
+    use super::*;
+    use crate::{
+        params::FieldParameters,
+        weierstrass::{bls12_381::Bls12381BaseField, bn254::Bn254BaseField},
+    };
+
+    type Fp2 = [BigUint; 2];
+
+    fn fp2_add(a: &Fp2, b: &Fp2, m: &BigUint) -> Fp2 {
+        [(&a[0] + &b[0]) % m, (&a[1] + &b[1]) % m]
+    }
+
+    fn fp2_mul_by_xi<P: FpTowerField>(a: &Fp2, m: &BigUint) -> Fp2 {
+        fp2_mul(a, &[BigUint::from(P::TOWER_NONRESIDUE), BigUint::one()], m)
+    }
+
+    /// Schoolbook `Fp6` multiplication written directly from the tower definition.
+    fn fp6_mul_reference<P: FpTowerField>(a: &[Fp2; 3], b: &[Fp2; 3], m: &BigUint) -> [Fp2; 3] {
+        let p = |i: usize, j: usize| fp2_mul(&a[i], &b[j], m);
+        let c0 = fp2_add(&p(0, 0), &fp2_mul_by_xi::<P>(&fp2_add(&p(1, 2), &p(2, 1), m), m), m);
+        let c1 = fp2_add(&fp2_add(&p(0, 1), &p(1, 0), m), &fp2_mul_by_xi::<P>(&p(2, 2), m), m);
+        let c2 = fp2_add(&fp2_add(&p(0, 2), &p(1, 1), m), &p(2, 0), m);
+        [c0, c1, c2]
+    }
+
+    fn fp6_add(a: &[Fp2; 3], b: &[Fp2; 3], m: &BigUint) -> [Fp2; 3] {
+        [fp2_add(&a[0], &b[0], m), fp2_add(&a[1], &b[1], m), fp2_add(&a[2], &b[2], m)]
+    }
+
+    /// Schoolbook `Fp12` multiplication written directly from the tower definition.
+    fn fp12_mul_reference<P: FpTowerField>(
+        a: &[[Fp2; 3]; 2],
+        b: &[[Fp2; 3]; 2],
+        m: &BigUint,
+    ) -> [[Fp2; 3]; 2] {
+        let a0b0 = fp6_mul_reference::<P>(&a[0], &b[0], m);
+        let a1b1 = fp6_mul_reference::<P>(&a[1], &b[1], m);
+        // Multiplication by `v` maps `(c0, c1, c2)` to `(xi c2, c0, c1)`.
+        let a1b1_v = [fp2_mul_by_xi::<P>(&a1b1[2], m), a1b1[0].clone(), a1b1[1].clone()];
+        let c0 = fp6_add(&a0b0, &a1b1_v, m);
+        let c1 = fp6_add(
+            &fp6_mul_reference::<P>(&a[0], &b[1], m),
+            &fp6_mul_reference::<P>(&a[1], &b[0], m),
+            m,
+        );
+        [c0, c1]
+    }
+
+    fn random_fp6<P: FpTowerField>() -> [Fp2; 3] {
+        let mut rng = thread_rng();
+        let m = P::modulus();
+        core::array::from_fn(|_| [rng.gen_biguint_below(&m), rng.gen_biguint_below(&m)])
+    }
+
+    fn flatten(a: &[[Fp2; 3]]) -> Vec<BigUint> {
+        a.iter().flatten().flatten().cloned().collect()
+    }
+
+    fn check_tower_mul<P: FpTowerField>() {
+        let m = P::modulus();
+        for _ in 0..10 {
+            let (a, b) = (random_fp6::<P>(), random_fp6::<P>());
+            let expected = fp6_mul_reference::<P>(&a, &b, &m);
+            let result = fp_tower_mul::<P>(FP6_DEGREE, &flatten(&[a]), &flatten(&[b]));
+            assert_eq!(result, flatten(&[expected]));
+
+            let a = [random_fp6::<P>(), random_fp6::<P>()];
+            let b = [random_fp6::<P>(), random_fp6::<P>()];
+            let expected = fp12_mul_reference::<P>(&a, &b, &m);
+            let result = fp_tower_mul::<P>(FP12_DEGREE, &flatten(&a), &flatten(&b));
+            assert_eq!(result, flatten(&expected));
+        }
+    }
+
+    #[test]
+    fn test_bn254_tower_mul() {
+        check_tower_mul::<Bn254BaseField>();
+    }
+
+    #[test]
+    fn test_bls12381_tower_mul() {
+        check_tower_mul::<Bls12381BaseField>();
+    }
+
+    #[test]
+    fn test_tower_mul_terms_weight() {
+        // The heaviest coefficient of an `Fp12` product over BN254 has a total weight of 102.
+        let max_weight = fp_tower_mul_terms(FP12_DEGREE, 9)
+            .iter()
+            .map(|terms| terms.iter().map(|t| t.0.unsigned_abs()).sum::<u32>())
+            .max()
+            .unwrap();
+        assert_eq!(max_weight, 102);
+    }
+}
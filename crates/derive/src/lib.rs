@@ -65,7 +65,49 @@ pub fn aligned_borrow_derive(input: TokenStream) -> TokenStream {
     // clause (`where T: Clone`)
     let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
 
+    // Get the fields (ex. `a`, `b` or `0`, `1`), to name the columns after them.
+    let field_members: Vec<syn::Member> = match &ast.data {
+        Data::Struct(data) => data
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| match &field.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(syn::Index::from(i)),
+            })
+            .collect(),
+        _ => panic!("Expected a struct"),
+    };
+    let field_strings = field_members.iter().map(|member| match member {
+        syn::Member::Named(ident) => ident.to_string(),
+        syn::Member::Unnamed(index) => index.index.to_string(),
+    });
+    let u8_type = quote! { #name<u8 #(, #non_first_generics)*> };
+
     let methods = quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            /// The names of the columns, in the order of the trace. A field spanning several
+            /// columns is named with the index of each of its columns, as in `field[3]`.
+            #[allow(dead_code)]
+            pub fn column_names() -> Vec<String> {
+                let width = std::mem::size_of::<#u8_type>();
+                let mut fields = vec![
+                    #((core::mem::offset_of!(#u8_type, #field_members), #field_strings),)*
+                ];
+                fields.sort_by_key(|(offset, _)| *offset);
+                let ends = fields.iter().skip(1).map(|(offset, _)| *offset).chain([width]);
+                let mut names = Vec::with_capacity(width);
+                for ((offset, field), end) in fields.iter().zip(ends) {
+                    if end - offset == 1 {
+                        names.push(field.to_string());
+                    } else {
+                        names.extend((0..end - offset).map(|i| format!("{field}[{i}]")));
+                    }
+                }
+                names
+            }
+        }
+
         impl #impl_generics core::borrow::Borrow<#name #type_generics> for [#type_generic] #where_clause {
             fn borrow(&self) -> &#name #type_generics {
                 debug_assert_eq!(self.len(), std::mem::size_of::<#name<u8 #(, #non_first_generics)*>>());
@@ -198,6 +240,13 @@ pub fn machine_air_derive(input: TokenStream) -> TokenStream {
                 }
             });
 
+            let column_names_arms = variants.iter().map(|(variant_name, field)| {
+                let field_ty = &field.ty;
+                quote! {
+                    #name::#variant_name(x) => <#field_ty as monerochan_stark::air::MachineAir<F>>::column_names(x)
+                }
+            });
+
             let machine_air = quote! {
                 impl #impl_generics monerochan_stark::air::MachineAir<F> for #name #ty_generics #where_clause {
                     type Record = #execution_record_path;
@@ -262,6 +311,12 @@ pub fn machine_air_derive(input: TokenStream) -> TokenStream {
                             #(#local_only_arms,)*
                         }
                     }
+
+                    fn column_names(&self) -> Vec<String> {
+                        match self {
+                            #(#column_names_arms,)*
+                        }
+                    }
                 }
             };
 
@@ -29,6 +29,11 @@ pub const PROGRAMS: &[TesterProgram] = &[
         include_bytes!("../programs/tendermint/elf"),
         include_bytes!("../programs/tendermint/input.bin"),
     ),
+    TesterProgram::new(
+        "bulletproofs-plus",
+        test_artifacts::BULLETPROOFS_PLUS_ELF,
+        include_bytes!("../programs/bulletproofs-plus/input.bin"),
+    ),
 ];
 
 pub fn load_program(elf: &[u8], input: &[u8]) -> (Vec<u8>, MONEROCHANStdin) {
@@ -0,0 +1,64 @@
+#![allow(clippy::print_stdout)]
+
+use clap::{command, Parser};
+use monerochan::{
+    self, MONEROCHANProofMode, MONEROCHANProofWithPublicValues, MONEROCHANStdin, Prover,
+    ProverClient,
+};
+use test_artifacts::{FIBONACCI_ELF, TENDERMINT_BENCHMARK_ELF};
+
+#[derive(Parser, Clone)]
+#[command(about = "Measure the size of MONEROCHAN proofs in the compact encoding.")]
+struct ProofSizeArgs {
+    /// Only measure the Fibonacci program, which is much faster to prove than Tendermint.
+    #[arg(long)]
+    pub fibonacci_only: bool,
+}
+
+fn main() {
+    monerochan::utils::setup_logger();
+    let args = ProofSizeArgs::parse();
+
+    let mut programs = vec![("fibonacci", FIBONACCI_ELF)];
+    if !args.fibonacci_only {
+        programs.push(("tendermint", TENDERMINT_BENCHMARK_ELF));
+    }
+
+    let client = ProverClient::builder().cpu().build();
+    let mut lines = Vec::new();
+    for (name, elf) in programs {
+        let (pk, vk) = client.setup(elf);
+        for mode in [MONEROCHANProofMode::Core, MONEROCHANProofMode::Compressed] {
+            let proof = client
+                .prove(&pk, &MONEROCHANStdin::new())
+                .mode(mode)
+                .run()
+                .expect("failed to generate proof");
+
+            let size = bincode::serialize(&proof).unwrap().len();
+            let compact_bytes = proof.to_compact_bytes().unwrap();
+
+            // The compact proof must decode to a proof that verifies.
+            let decoded = MONEROCHANProofWithPublicValues::from_compact_bytes(&compact_bytes)
+                .expect("failed to decode compact proof");
+            client.verify(&decoded, &vk).expect("failed to verify compact proof");
+
+            lines.push(format!(
+                "{:<12} {:<12} {:>12} {:>12} {:>9.1}%",
+                name,
+                format!("{mode:?}"),
+                size,
+                compact_bytes.len(),
+                100.0 * (1.0 - compact_bytes.len() as f64 / size as f64)
+            ));
+        }
+    }
+
+    println!(
+        "{:<12} {:<12} {:>12} {:>12} {:>10}",
+        "program", "mode", "bincode", "compact", "saved"
+    );
+    for line in lines {
+        println!("{line}");
+    }
+}
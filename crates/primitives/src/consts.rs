@@ -61,6 +61,9 @@ pub mod fd {
 
         /// The file descriptor through which to access `hook_fp_inverse`.
         pub const FD_FP_INV: u32 = 11;
+
+        /// The file descriptor through which to access `hook_bls12_381_fp2_sqrt`.
+        pub const FD_BLS12_381_FP2_SQRT: u32 = 12;
     }
 }
 
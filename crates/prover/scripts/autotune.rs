@@ -0,0 +1,79 @@
+#![allow(clippy::print_stdout)]
+
+use std::path::PathBuf;
+
+use clap::Parser;
+use monerochan_core_machine::{io::MONEROCHANStdin, utils::setup_logger};
+use monerochan_prover::{
+    autotune::{AutotuneConfig, AutotuneModel, AutotuneReport},
+    components::CpuProverComponents,
+    MONEROCHANProver,
+};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// The path to the program ELF.
+    #[arg(short, long)]
+    elf: PathBuf,
+    /// The path to the bincode-serialized stdin of the program.
+    #[arg(short, long)]
+    stdin: Option<PathBuf>,
+    /// The memory available to the prover, in GiB.
+    #[arg(short, long)]
+    ram_gb: u64,
+    /// The path to the JSON-serialized model of the machine, calibrated on it.
+    #[arg(short, long)]
+    model: PathBuf,
+    /// The number of candidates to print.
+    #[arg(short, long, default_value_t = 10)]
+    top: usize,
+}
+
+fn main() {
+    // Setup logger.
+    setup_logger();
+
+    // Parse arguments.
+    let args = Args::parse();
+    let elf = std::fs::read(&args.elf).expect("failed to read elf");
+    let stdin: MONEROCHANStdin = match &args.stdin {
+        Some(path) => bincode::deserialize(&std::fs::read(path).expect("failed to read stdin"))
+            .expect("failed to deserialize stdin"),
+        None => MONEROCHANStdin::new(),
+    };
+    let model: AutotuneModel =
+        serde_json::from_slice(&std::fs::read(&args.model).expect("failed to read model"))
+            .expect("failed to deserialize model");
+
+    // Tune the options.
+    let prover = MONEROCHANProver::<CpuProverComponents>::new();
+    let config = AutotuneConfig::new(args.ram_gb << 30, model);
+    let AutotuneReport { cycles, best, candidates } =
+        prover.autotune(&elf, &stdin, Default::default(), &config).expect("failed to autotune");
+
+    println!("cycles: {cycles}");
+    println!(
+        "{:>10} {:>6} {:>8} {:>7} {:>14} {:>10} {:>10}",
+        "shard size", "batch", "divisor", "shards", "max lde (GiB)", "peak (GiB)", "time (s)"
+    );
+    let gib = (1u64 << 30) as f64;
+    for candidate in
+        candidates.iter().filter(|c| c.peak_memory <= config.memory_budget).take(args.top)
+    {
+        println!(
+            "{:>10} {:>6} {:>8} {:>7} {:>14.2} {:>10.1} {:>10.0}",
+            format!("2^{}", candidate.log_shard_size),
+            candidate.shard_batch_size,
+            candidate.split_divisor,
+            candidate.num_shards,
+            candidate.max_shard_lde_size as f64 / gib,
+            candidate.peak_memory as f64 / gib,
+            candidate.proving_time,
+        );
+    }
+
+    println!();
+    println!("best: {:?}", best.core_opts());
+    println!("SHARD_SIZE={} SHARD_BATCH_SIZE={}", 1 << best.log_shard_size, best.shard_batch_size);
+}
@@ -0,0 +1,66 @@
+#![allow(clippy::print_stdout)]
+
+use clap::{Parser, ValueEnum};
+use monerochan_core_machine::{riscv::RiscvAir, utils::setup_logger};
+use monerochan_prover::{CompressAir, ShrinkAir, WrapAir};
+use monerochan_stark::{baby_bear_poseidon2::BabyBearPoseidon2, ChipReport, ChipReportTable};
+use p3_baby_bear::BabyBear;
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum Machine {
+    Core,
+    Compress,
+    Shrink,
+    Wrap,
+}
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// The machines to report on, all of them by default.
+    #[arg(short, long, value_enum, value_delimiter = ',')]
+    machines: Vec<Machine>,
+    /// Print the reports as JSON instead of tables.
+    #[arg(short, long, default_value_t = false)]
+    json: bool,
+}
+
+fn main() {
+    // Setup the logger.
+    setup_logger();
+
+    // Parse the arguments.
+    let args = Args::parse();
+    let machines =
+        if args.machines.is_empty() { Machine::value_variants().to_vec() } else { args.machines };
+
+    // The reports do not depend on the configuration of the machines.
+    let config = BabyBearPoseidon2::default;
+    let reports: Vec<(Machine, Vec<ChipReport>)> = machines
+        .into_iter()
+        .map(|machine| {
+            let reports = match machine {
+                Machine::Core => RiscvAir::<BabyBear>::machine(config()).chip_reports(),
+                Machine::Compress => {
+                    CompressAir::<BabyBear>::compress_machine(config()).chip_reports()
+                }
+                Machine::Shrink => ShrinkAir::<BabyBear>::shrink_machine(config()).chip_reports(),
+                Machine::Wrap => WrapAir::<BabyBear>::wrap_machine(config()).chip_reports(),
+            };
+            (machine, reports)
+        })
+        .collect();
+
+    if args.json {
+        let reports = reports
+            .into_iter()
+            .map(|(machine, reports)| (format!("{machine:?}").to_lowercase(), reports))
+            .collect::<std::collections::BTreeMap<_, _>>();
+        println!("{}", serde_json::to_string_pretty(&reports).unwrap());
+    } else {
+        for (machine, reports) in reports {
+            println!("{machine:?} machine:");
+            println!("{}", ChipReportTable(&reports));
+        }
+    }
+}
@@ -0,0 +1,412 @@
+//! Tuning of the core prover options from execution statistics.
+//!
+//! The autotuner executes the program once with the smallest candidate shard size and split
+//! thresholds, collecting the number of events of each shard with a [`RecordEstimator`]. Larger
+//! shard sizes and split thresholds are then simulated by merging consecutive shards, and the LDE
+//! size of every shard is estimated from the costs of the AIRs. An [`AutotuneModel`] turns the LDE
+//! sizes into a peak memory and a proving time, and the fastest candidate that fits in the memory
+//! budget is chosen. The model is an input of the autotuner, since it must be measured on the
+//! machine that proves.
+
+use std::{borrow::Cow, ops::RangeInclusive};
+
+use enum_map::EnumMap;
+use hashbrown::HashMap;
+use p3_baby_bear::BabyBear;
+use serde::{Deserialize, Serialize};
+use monerochan_core_executor::{
+    estimate_riscv_lde_size, estimator::RecordEstimator, ExecutionError, Executor,
+    MONEROCHANContext, Program, RiscvAirId,
+};
+use monerochan_core_machine::io::MONEROCHANStdin;
+use monerochan_stark::{
+    MONEROCHANCoreOpts, MONEROCHANProverOpts, SplitOpts, MAX_DEFERRED_SPLIT_THRESHOLD,
+};
+use thiserror::Error;
+
+use crate::{components::MONEROCHANProverComponents, gas, MONEROCHANProver};
+
+const GIB: f64 = (1u64 << 30) as f64;
+
+/// A model of the resources used to prove the shards, to calibrate for each machine.
+///
+/// There is no default model: the coefficients depend on the CPU, the memory and the prover
+/// components. Measure them by proving a program with a few shard sizes and shard batch sizes on
+/// the machine that will prove, and fitting the peak memory and the proving time of each run.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AutotuneModel {
+    /// The memory used by the prover outside of the shards in flight, in bytes.
+    pub base_memory: u64,
+    /// The peak memory used to prove a shard, per byte of its LDE.
+    pub memory_per_lde_byte: f64,
+    /// The time to prove a shard, per GiB of its LDE, with one shard in flight.
+    pub seconds_per_lde_gib: f64,
+    /// The relative throughput gained by each doubling of the shard batch size.
+    pub batch_throughput_gain: f64,
+    /// The time to prove the recursion of a shard, which does not depend on its size.
+    pub recursion_seconds_per_shard: f64,
+}
+
+/// The candidates and the model of the autotuner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutotuneConfig {
+    /// The memory available to the prover, in bytes.
+    pub memory_budget: u64,
+    /// The candidate shard sizes, as powers of two.
+    pub log_shard_sizes: RangeInclusive<usize>,
+    /// The candidate shard batch sizes.
+    pub shard_batch_sizes: Vec<usize>,
+    /// The candidate divisors of the split thresholds, which must be powers of two.
+    pub split_divisors: Vec<usize>,
+    /// The model of the resources used by the prover.
+    pub model: AutotuneModel,
+}
+
+impl AutotuneConfig {
+    /// Get the default candidates for the given memory budget, in bytes, and the model of the
+    /// machine.
+    #[must_use]
+    pub fn new(memory_budget: u64, model: AutotuneModel) -> Self {
+        Self {
+            memory_budget,
+            log_shard_sizes: 18..=21,
+            shard_batch_sizes: (1..=8).collect(),
+            split_divisors: vec![1, 2, 4, 8],
+            model,
+        }
+    }
+}
+
+/// The predicted cost of proving with a choice of options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutotuneCandidate {
+    /// The log of the shard size.
+    pub log_shard_size: usize,
+    /// The shard batch size.
+    pub shard_batch_size: usize,
+    /// The divisor of the split thresholds.
+    pub split_divisor: usize,
+    /// The number of shards, including the precompile and memory shards.
+    pub num_shards: usize,
+    /// The LDE size of the largest shard, in bytes.
+    pub max_shard_lde_size: u64,
+    /// The predicted peak memory, in bytes.
+    pub peak_memory: u64,
+    /// The predicted proving time, in seconds.
+    pub proving_time: f64,
+}
+
+impl AutotuneCandidate {
+    /// The core prover options of the candidate.
+    #[must_use]
+    pub fn core_opts(&self) -> MONEROCHANCoreOpts {
+        let mut opts = MONEROCHANCoreOpts::default();
+        opts.shard_size = 1 << self.log_shard_size;
+        opts.shard_batch_size = self.shard_batch_size;
+        opts.split_opts = split_opts(self.split_divisor);
+        opts.records_and_traces_channel_capacity = 1;
+        opts.trace_gen_workers = 1;
+        opts
+    }
+
+    /// The prover options of the candidate, with the recursion options of
+    /// [`MONEROCHANProverOpts::cpu`].
+    #[must_use]
+    pub fn prover_opts(&self) -> MONEROCHANProverOpts {
+        let mut opts = MONEROCHANProverOpts { core_opts: self.core_opts(), ..Default::default() };
+        opts.recursion_opts.shard_batch_size = 2;
+        opts.recursion_opts.records_and_traces_channel_capacity = 1;
+        opts.recursion_opts.trace_gen_workers = 1;
+        opts
+    }
+}
+
+/// The result of the autotuner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutotuneReport {
+    /// The number of cycles of the execution.
+    pub cycles: u64,
+    /// The fastest candidate that fits in the memory budget.
+    pub best: AutotuneCandidate,
+    /// All the candidates, sorted by predicted proving time.
+    pub candidates: Vec<AutotuneCandidate>,
+}
+
+/// An error of the autotuner.
+#[derive(Error, Debug)]
+pub enum AutotuneError {
+    #[error("invalid program: {0}")]
+    InvalidProgram(String),
+    #[error("execution failed: {0}")]
+    Execution(#[from] ExecutionError),
+    #[error("invalid autotune config: {0}")]
+    InvalidConfig(&'static str),
+    #[error(
+        "no candidate fits in the memory budget of {memory_budget} bytes, \
+         the smallest predicted peak memory is {min_peak_memory} bytes"
+    )]
+    NoFit { memory_budget: u64, min_peak_memory: u64 },
+}
+
+impl<C: MONEROCHANProverComponents> MONEROCHANProver<C> {
+    /// Choose the core prover options that fit in the memory budget of `config` while minimizing
+    /// the proving time, from a fast execution of the program.
+    pub fn autotune<'a>(
+        &'a self,
+        elf: &[u8],
+        stdin: &MONEROCHANStdin,
+        mut context: MONEROCHANContext<'a>,
+        config: &AutotuneConfig,
+    ) -> Result<AutotuneReport, AutotuneError> {
+        context.subproof_verifier = Some(self);
+        autotune(elf, stdin, context, config)
+    }
+}
+
+/// Choose the core prover options that fit in the memory budget of `config` while minimizing the
+/// proving time, from a fast execution of the program.
+///
+/// If the program verifies proofs, the context must have a subproof verifier.
+pub fn autotune(
+    elf: &[u8],
+    stdin: &MONEROCHANStdin,
+    context: MONEROCHANContext,
+    config: &AutotuneConfig,
+) -> Result<AutotuneReport, AutotuneError> {
+    let min_log_shard_size = *config.log_shard_sizes.start();
+    let max_split_divisor = config.split_divisors.iter().copied().max().unwrap_or(0);
+    if config.log_shard_sizes.is_empty() ||
+        config.shard_batch_sizes.is_empty() ||
+        config.shard_batch_sizes.contains(&0)
+    {
+        return Err(AutotuneError::InvalidConfig("no shard size or shard batch size"));
+    }
+    if max_split_divisor == 0 || !config.split_divisors.iter().all(|d| d.is_power_of_two()) {
+        return Err(AutotuneError::InvalidConfig("split divisors must be powers of two"));
+    }
+
+    // Execute the program with the finest shards.
+    let program = Program::from(elf).map_err(|e| AutotuneError::InvalidProgram(e.to_string()))?;
+    let mut opts = MONEROCHANCoreOpts::default();
+    opts.shard_size = 1 << min_log_shard_size;
+    opts.split_opts = split_opts(max_split_divisor);
+    let mut runtime = Executor::with_context(program, opts, context);
+    runtime.record_estimator = Some(Box::default());
+    runtime.write_vecs(&stdin.buffer);
+    for (proof, vkey) in stdin.proofs.iter() {
+        runtime.write_proof(proof.clone(), vkey.clone());
+    }
+    runtime.run_fast()?;
+    let estimator = runtime.record_estimator.take().unwrap();
+
+    let mut candidates = evaluate_candidates(&estimator, &runtime.costs, config);
+    candidates.sort_by(|a, b| {
+        a.proving_time.total_cmp(&b.proving_time).then(a.peak_memory.cmp(&b.peak_memory))
+    });
+
+    let best =
+        candidates.iter().find(|candidate| candidate.peak_memory <= config.memory_budget).copied();
+    let Some(best) = best else {
+        return Err(AutotuneError::NoFit {
+            memory_budget: config.memory_budget,
+            min_peak_memory: candidates.iter().map(|c| c.peak_memory).min().unwrap_or_default(),
+        });
+    };
+    tracing::info!(
+        "autotuned shard size 2^{}, shard batch size {}, split divisor {}: \
+         {} shards, {:.1} GiB peak memory, {:.0} s",
+        best.log_shard_size,
+        best.shard_batch_size,
+        best.split_divisor,
+        best.num_shards,
+        best.peak_memory as f64 / GIB,
+        best.proving_time,
+    );
+
+    Ok(AutotuneReport { cycles: runtime.state.global_clk, best, candidates })
+}
+
+/// Predict the cost of every candidate from the records of an execution with the smallest shard
+/// size and the largest split divisor.
+fn evaluate_candidates(
+    estimator: &RecordEstimator,
+    costs: &HashMap<RiscvAirId, u64>,
+    config: &AutotuneConfig,
+) -> Vec<AutotuneCandidate> {
+    let min_log_shard_size = *config.log_shard_sizes.start();
+    let max_split_divisor = config.split_divisors.iter().copied().max().unwrap();
+    let model = &config.model;
+
+    let mut candidates = Vec::new();
+    for log_shard_size in config.log_shard_sizes.clone() {
+        for &split_divisor in &config.split_divisors {
+            let merged = RecordEstimator {
+                core_records: merge_core_records(
+                    &estimator.core_records,
+                    1 << (log_shard_size - min_log_shard_size),
+                ),
+                precompile_records: estimator.precompile_records.clone().map(|_, records| {
+                    merge_precompile_records(&records, max_split_divisor / split_divisor)
+                }),
+                memory_global_init_events: estimator.memory_global_init_events,
+                memory_global_finalize_events: estimator.memory_global_finalize_events,
+                ..Default::default()
+            };
+            let lde_sizes = gas::estimated_records(&split_opts(split_divisor), &merged)
+                .map(|record| estimate_shard_lde_size(&record, costs))
+                .collect::<Vec<_>>();
+            let max_shard_lde_size = lde_sizes.iter().copied().max().unwrap_or_default();
+            let total_lde_size = lde_sizes.iter().sum::<u64>() as f64;
+
+            for &shard_batch_size in &config.shard_batch_sizes {
+                let in_flight = max_shard_lde_size as f64 * shard_batch_size as f64;
+                let peak_memory =
+                    model.base_memory + (in_flight * model.memory_per_lde_byte) as u64;
+                let throughput =
+                    1.0 + model.batch_throughput_gain * (shard_batch_size as f64).log2();
+                let proving_time = total_lde_size / GIB * model.seconds_per_lde_gib / throughput +
+                    lde_sizes.len() as f64 * model.recursion_seconds_per_shard;
+                candidates.push(AutotuneCandidate {
+                    log_shard_size,
+                    shard_batch_size,
+                    split_divisor,
+                    num_shards: lde_sizes.len(),
+                    max_shard_lde_size,
+                    peak_memory,
+                    proving_time,
+                });
+            }
+        }
+    }
+    candidates
+}
+
+/// The split options with the thresholds divided by `divisor`, as in [`MONEROCHANProverOpts::cpu`].
+fn split_opts(divisor: usize) -> SplitOpts {
+    SplitOpts::new(MAX_DEFERRED_SPLIT_THRESHOLD).with_divisor(divisor)
+}
+
+/// Merge every `factor` consecutive core shards into one.
+fn merge_core_records(
+    records: &[EnumMap<RiscvAirId, u64>],
+    factor: usize,
+) -> Vec<EnumMap<RiscvAirId, u64>> {
+    records
+        .chunks(factor)
+        .map(|chunk| {
+            let mut merged = EnumMap::default();
+            for record in chunk {
+                for (id, &num_events) in record.iter() {
+                    merged[id] += num_events;
+                }
+            }
+            merged
+        })
+        .collect()
+}
+
+/// Merge every `factor` consecutive precompile shards into one.
+fn merge_precompile_records(records: &[(u64, u64)], factor: usize) -> Vec<(u64, u64)> {
+    records
+        .chunks(factor)
+        .map(|chunk| chunk.iter().fold((0, 0), |(a, b), &(c, d)| (a + c, b + d)))
+        .collect()
+}
+
+/// Estimate the LDE size of a shard in bytes, including its precompile and memory AIRs.
+fn estimate_shard_lde_size(
+    record: &Cow<'_, EnumMap<RiscvAirId, u64>>,
+    costs: &HashMap<RiscvAirId, u64>,
+) -> u64 {
+    let mut lde_size = estimate_riscv_lde_size(**record, costs);
+    for (id, &num_events) in record.iter() {
+        if num_events > 0 &&
+            (id.is_precompile() ||
+                matches!(id, RiscvAirId::MemoryGlobalInit | RiscvAirId::MemoryGlobalFinalize))
+        {
+            let num_rows = (num_events * id.rows_per_event() as u64).next_power_of_two();
+            let cost = costs.get(&id).copied().unwrap_or_default();
+            lde_size += num_rows * cost * (std::mem::size_of::<BabyBear>() << 1) as u64;
+        }
+    }
+    lde_size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use monerochan_core_executor::rv32im_costs;
+
+    fn costs() -> HashMap<RiscvAirId, u64> {
+        rv32im_costs().into_iter().map(|(id, cost)| (id, cost as u64)).collect()
+    }
+
+    fn model() -> AutotuneModel {
+        AutotuneModel {
+            base_memory: 4 << 30,
+            memory_per_lde_byte: 2.5,
+            seconds_per_lde_gib: 6.0,
+            batch_throughput_gain: 0.1,
+            recursion_seconds_per_shard: 1.5,
+        }
+    }
+
+    fn estimator(num_shards: usize, cycles_per_shard: u64) -> RecordEstimator {
+        let shard = EnumMap::from_iter([
+            (RiscvAirId::Cpu, cycles_per_shard),
+            (RiscvAirId::AddSub, cycles_per_shard / 2),
+            (RiscvAirId::MemoryInstrs, cycles_per_shard / 4),
+            (RiscvAirId::MemoryLocal, cycles_per_shard / 8),
+            (RiscvAirId::Global, cycles_per_shard / 4),
+        ]);
+        let mut estimator = RecordEstimator {
+            core_records: vec![shard; num_shards],
+            memory_global_init_events: 1 << 16,
+            memory_global_finalize_events: 1 << 16,
+            ..Default::default()
+        };
+        estimator.precompile_records[RiscvAirId::KeccakPermute] = vec![(100, 1000); 8];
+        estimator
+    }
+
+    #[test]
+    fn test_merge_records() {
+        let estimator = estimator(5, 1 << 18);
+        let merged = merge_core_records(&estimator.core_records, 2);
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[0][RiscvAirId::Cpu], 1 << 19);
+        assert_eq!(merged[2][RiscvAirId::Cpu], 1 << 18);
+
+        let merged =
+            merge_precompile_records(&estimator.precompile_records[RiscvAirId::KeccakPermute], 4);
+        assert_eq!(merged, vec![(400, 4000); 2]);
+    }
+
+    #[test]
+    fn test_evaluate_candidates() {
+        let estimator = estimator(64, 1 << 18);
+        let config = AutotuneConfig::new(16 << 30, model());
+        let candidates = evaluate_candidates(&estimator, &costs(), &config);
+        assert_eq!(candidates.len(), 4 * 8 * 4);
+
+        let find = |log_shard_size, shard_batch_size| {
+            candidates
+                .iter()
+                .find(|c| {
+                    c.log_shard_size == log_shard_size &&
+                        c.shard_batch_size == shard_batch_size &&
+                        c.split_divisor == 1
+                })
+                .unwrap()
+        };
+
+        // Larger shards use more memory, in fewer shards.
+        assert!(find(21, 1).max_shard_lde_size > find(18, 1).max_shard_lde_size);
+        assert!(find(21, 1).num_shards < find(18, 1).num_shards);
+
+        // Larger batches use more memory and less time.
+        assert!(find(18, 4).peak_memory > find(18, 1).peak_memory);
+        assert!(find(18, 4).proving_time < find(18, 1).proving_time);
+    }
+}
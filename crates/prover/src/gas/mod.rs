@@ -22,6 +22,8 @@ pub const GAS_OPTS: MONEROCHANCoreOpts = MONEROCHANCoreOpts {
         keccak: 5461,
         sha_extend: 10922,
         sha_compress: 6553,
+        aes: 8738,
+        chacha20: 13107,
         memory: 1048576,
     },
     trace_gen_workers: 4,
@@ -172,7 +174,8 @@ impl Shapeable for CoreShard<'_> {
             // Skip empty events and filter by precompiles.
             (num_events > 0 && id.is_precompile()).then_some(())?;
             let rows = num_events * id.rows_per_event();
-            let num_global_events = 2 * num_local_mem_events + num_events;
+            let num_global_events =
+                2 * num_local_mem_events + num_events * (1 + id.nested_syscalls_per_event());
             Some((id, (rows, num_local_mem_events, num_global_events)))
         })
     }
@@ -1,7 +1,61 @@
+use std::sync::OnceLock;
+
+use hashbrown::HashMap;
 use itertools::izip;
+use monerochan_core_executor::{rv32im_costs, RiscvAirId};
+use monerochan_stark::shape::Shape;
 
 pub const INPUT_SIZE: usize = 90;
 
+/// The airs the model was fit on, in the order of its input.
+pub const MODEL_AIR_IDS: [RiscvAirId; INPUT_SIZE / 2] = [
+    RiscvAirId::Cpu,
+    RiscvAirId::Program,
+    RiscvAirId::ShaExtend,
+    RiscvAirId::ShaCompress,
+    RiscvAirId::EdAddAssign,
+    RiscvAirId::EdDecompress,
+    RiscvAirId::Secp256k1Decompress,
+    RiscvAirId::Secp256k1AddAssign,
+    RiscvAirId::Secp256k1DoubleAssign,
+    RiscvAirId::Secp256r1Decompress,
+    RiscvAirId::Secp256r1AddAssign,
+    RiscvAirId::Secp256r1DoubleAssign,
+    RiscvAirId::KeccakPermute,
+    RiscvAirId::Bn254AddAssign,
+    RiscvAirId::Bn254DoubleAssign,
+    RiscvAirId::Bls12381AddAssign,
+    RiscvAirId::Bls12381DoubleAssign,
+    RiscvAirId::Uint256MulMod,
+    RiscvAirId::U256XU2048Mul,
+    RiscvAirId::Bls12381FpOpAssign,
+    RiscvAirId::Bls12381Fp2AddSubAssign,
+    RiscvAirId::Bls12381Fp2MulAssign,
+    RiscvAirId::Bn254FpOpAssign,
+    RiscvAirId::Bn254Fp2AddSubAssign,
+    RiscvAirId::Bn254Fp2MulAssign,
+    RiscvAirId::Bls12381Decompress,
+    RiscvAirId::SyscallCore,
+    RiscvAirId::SyscallPrecompile,
+    RiscvAirId::DivRem,
+    RiscvAirId::AddSub,
+    RiscvAirId::Bitwise,
+    RiscvAirId::Mul,
+    RiscvAirId::ShiftRight,
+    RiscvAirId::ShiftLeft,
+    RiscvAirId::Lt,
+    RiscvAirId::MemoryInstrs,
+    RiscvAirId::Auipc,
+    RiscvAirId::Branch,
+    RiscvAirId::Jump,
+    RiscvAirId::SyscallInstrs,
+    RiscvAirId::MemoryGlobalInit,
+    RiscvAirId::MemoryGlobalFinalize,
+    RiscvAirId::MemoryLocal,
+    RiscvAirId::Global,
+    RiscvAirId::Byte,
+];
+
 pub fn predict(input: &[usize; INPUT_SIZE / 2]) -> f64 {
     let input = [input.map(|x| x as f64), input.map(|x| 2f64.powi(x.try_into().unwrap()))].concat();
     // Asserts that the zip does not end early.
@@ -15,6 +69,45 @@ pub fn predict(input: &[usize; INPUT_SIZE / 2]) -> f64 {
         PARAMS.intercept
 }
 
+/// Returns the input of the model for a shape: the log2 heights of the [`MODEL_AIR_IDS`], or zero
+/// for the airs that are not in the shape.
+pub fn model_input(shape: &Shape<RiscvAirId>) -> [usize; INPUT_SIZE / 2] {
+    MODEL_AIR_IDS.map(|id| shape.log2_height(&id).unwrap_or_default())
+}
+
+/// Predicts the raw gas of the airs of a shape that the model was not fit on, from their trace
+/// area. Airs without a cost, the user precompiles, are not counted.
+pub fn predict_unfitted(shape: &Shape<RiscvAirId>) -> f64 {
+    let (costs, raw_gas_per_area) = unfitted_params();
+    shape
+        .iter()
+        .filter(|(id, _)| !MODEL_AIR_IDS.contains(id))
+        .filter_map(|(id, &log2_height)| {
+            costs.get(id).map(|&cost| raw_gas_per_area * (cost << log2_height) as f64)
+        })
+        .sum()
+}
+
+/// The costs of the airs, and the raw gas per unit of trace area of the airs the model was not fit
+/// on.
+///
+/// The raw gas per unit of area is the median, over the airs the model was fit on, of the
+/// coefficient of the height of the air divided by its cost.
+fn unfitted_params() -> &'static (HashMap<RiscvAirId, usize>, f64) {
+    static PARAMS_CELL: OnceLock<(HashMap<RiscvAirId, usize>, f64)> = OnceLock::new();
+    PARAMS_CELL.get_or_init(|| {
+        let costs = rv32im_costs();
+        let height_terms = INPUT_SIZE / 2..INPUT_SIZE;
+        let mut rates = izip!(MODEL_AIR_IDS, height_terms)
+            .filter(|&(_, i)| PARAMS.std[i] > 0.0)
+            .map(|(id, i)| PARAMS.coefs[i] / PARAMS.std[i] / costs[&id] as f64)
+            .collect::<Vec<_>>();
+        rates.sort_by(f64::total_cmp);
+        let raw_gas_per_area = rates[rates.len() / 2];
+        (costs, raw_gas_per_area)
+    })
+}
+
 pub(crate) struct Params<const N: usize> {
     pub mean: [f64; N],
     pub std: [f64; N],
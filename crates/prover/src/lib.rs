@@ -11,6 +11,7 @@
 #![allow(clippy::new_without_default)]
 #![allow(clippy::collapsible_else_if)]
 
+pub mod autotune;
 pub mod build;
 pub mod components;
 pub mod gas;
@@ -84,8 +85,9 @@ use monerochan_recursion_gnark_ffi::{groth16_bn254::Groth16Bn254Prover, plonk_bn
 use monerochan_stark::{
     baby_bear_poseidon2::BabyBearPoseidon2,
     shape::{OrderedShape, Shape},
-    Challenge, MachineProver, MachineProvingKey, MONEROCHANProverOpts, ShardProof, SplitOpts,
-    StarkGenericConfig, StarkVerifyingKey, Val, Word, DIGEST_SIZE,
+    Challenge, FriConfigError, FriSecurityProfile, MachineProver, MachineProvingKey,
+    MONEROCHANProverOpts, ShardProof, SplitOpts, StarkGenericConfig, StarkVerifyingKey, Val, Word,
+    DIGEST_SIZE,
 };
 use tracing::instrument;
 
@@ -169,9 +171,24 @@ impl<C: MONEROCHANProverComponents> MONEROCHANProver<C> {
     }
 
     /// Creates a new [MONEROCHANProver] with lazily initialized components.
+    ///
+    /// The core machine uses the standard FRI security profile. Use [`Self::with_fri_profile`] to
+    /// select another profile.
     pub fn uninitialized() -> Self {
+        Self::with_core_config(CoreSC::new())
+    }
+
+    /// Creates a new [MONEROCHANProver] whose core machine uses the given FRI security profile.
+    ///
+    /// The profile is fixed for the lifetime of the prover, so the core proofs it generates and
+    /// the verifying keys it sets up all use it, unless the options of a proof select another.
+    pub fn with_fri_profile(fri_profile: FriSecurityProfile) -> Result<Self, FriConfigError> {
+        Ok(Self::with_core_config(CoreSC::with_fri_profile(fri_profile)?))
+    }
+
+    fn with_core_config(core_config: CoreSC) -> Self {
         // Initialize the provers.
-        let core_machine = RiscvAir::machine(CoreSC::default());
+        let core_machine = RiscvAir::machine(core_config);
         let core_prover = C::CoreProver::new(core_machine);
 
         let compress_machine = CompressAir::compress_machine(InnerSC::default());
@@ -263,6 +280,20 @@ impl<C: MONEROCHANProverComponents> MONEROCHANProver<C> {
         }
     }
 
+    /// Returns a core prover for the given FRI security profile, or `None` if the core machine
+    /// already uses it.
+    fn core_prover_for_profile(
+        &self,
+        fri_profile: Option<FriSecurityProfile>,
+    ) -> Result<Option<C::CoreProver>, FriConfigError> {
+        let Some(fri_profile) = fri_profile else { return Ok(None) };
+        if self.core_prover.machine().config().fri_profile() == Some(fri_profile) {
+            return Ok(None);
+        }
+        let core_machine = RiscvAir::machine(CoreSC::with_fri_profile(fri_profile)?);
+        Ok(Some(C::CoreProver::new(core_machine)))
+    }
+
     /// Creates a proving key and a verifying key for a given RISC-V ELF.
     #[instrument(name = "setup", level = "debug", skip_all)]
     pub fn setup(
@@ -304,7 +335,8 @@ impl<C: MONEROCHANProverComponents> MONEROCHANProver<C> {
                         let mut shape: Shape<RiscvAirId> = shape.map_err(Box::new)?;
                         shape.extend(preprocessed_shape.iter().map(|(k, v)| (*k, *v)));
                         tracing::debug!("shape for estimated shard {i}: {:?}", &shape.inner);
-                        Ok(gas::predict(enum_map::EnumMap::from_iter(shape).as_array()))
+                        Ok(gas::predict(&gas::model_input(&shape)) +
+                            gas::predict_unfitted(&shape))
                     })
                     .sum::<Result<_, Box<dyn Error>>>()?;
             let gas = gas::final_transform(raw_gas).map_err(Box::new)?;
@@ -376,6 +408,10 @@ impl<C: MONEROCHANProverComponents> MONEROCHANProver<C> {
 
     /// Generate shard proofs which split up and prove the valid execution of a RISC-V program with
     /// the core prover. Uses the provided context.
+    ///
+    /// If the options select a FRI security profile other than the one of the core machine, the
+    /// shards are proven with a core machine of that profile instead. Such a proof verifies
+    /// against the verifying key set up by a prover created with [`Self::with_fri_profile`].
     #[instrument(name = "prove_core", level = "info", skip_all)]
     pub fn prove_core<'a>(
         &'a self,
@@ -390,6 +426,15 @@ impl<C: MONEROCHANProverComponents> MONEROCHANProver<C> {
     ) -> Result<MONEROCHANCoreProof, MONEROCHANCoreProverError> {
         context.subproof_verifier = Some(self);
 
+        // Set up a core prover and a proving key for the FRI security profile of the options, if
+        // it differs from the profile of the core machine.
+        let profile_prover = self
+            .core_prover_for_profile(opts.fri_profile)
+            .map_err(MONEROCHANCoreProverError::FriConfig)?;
+        let profile_pk = profile_prover.as_ref().map(|prover| prover.setup(&program).0);
+        let core_prover = profile_prover.as_ref().unwrap_or(&self.core_prover);
+        let pk_d = profile_pk.as_ref().unwrap_or(pk_d);
+
         // Launch two threads to simultaneously prove the core and compile the first few
         // recursion programs in parallel.
         let span = tracing::Span::current().clone();
@@ -436,7 +481,7 @@ impl<C: MONEROCHANProverComponents> MONEROCHANProver<C> {
 
                 // Prove the core and stream the proofs and shapes.
                 monerochan_core_machine::utils::prove_core_stream::<_, C::CoreProver>(
-                    &self.core_prover,
+                    core_prover,
                     pk,
                     program,
                     stdin,
@@ -480,6 +525,10 @@ impl<C: MONEROCHANProverComponents> MONEROCHANProver<C> {
     }
 
     /// Reduce shards proofs to a single shard proof using the recursion prover.
+    ///
+    /// The allowed recursion vks are generated for the standard FRI security profile, so with vk
+    /// verification enabled only core proofs of that profile can be compressed. The options must
+    /// not select a profile other than the one of the core machine.
     #[instrument(name = "compress", level = "info", skip_all)]
     pub fn compress(
         &self,
@@ -500,6 +549,17 @@ impl<C: MONEROCHANProverComponents> MONEROCHANProver<C> {
             CircuitWitness(Box<MONEROCHANCircuitWitness>),
         }
 
+        let fri_profile = self.core_prover.machine().config().fri_profile();
+        if let Some(found) = opts.fri_profile.filter(|&found| Some(found) != fri_profile) {
+            return Err(MONEROCHANRecursionProverError::FriProfileMismatch {
+                found,
+                expected: fri_profile,
+            });
+        }
+        if self.vk_verification && fri_profile != Some(FriSecurityProfile::Standard) {
+            return Err(MONEROCHANRecursionProverError::NonStandardFriProfile(fri_profile));
+        }
+
         // The batch size for reducing two layers of recursion.
         let batch_size = REDUCE_BATCH_SIZE;
         // The batch size for reducing the first layer of recursion.
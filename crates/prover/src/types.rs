@@ -16,7 +16,10 @@ use monerochan_recursion_circuit::machine::{
 
 use monerochan_recursion_gnark_ffi::proof::{Groth16Bn254Proof, PlonkBn254Proof};
 
-use monerochan_stark::{ShardProof, StarkGenericConfig, StarkProvingKey, StarkVerifyingKey, DIGEST_SIZE};
+use monerochan_stark::{
+    FriSecurityProfile, ShardProof, StarkGenericConfig, StarkProvingKey, StarkVerifyingKey,
+    DIGEST_SIZE,
+};
 use thiserror::Error;
 
 use crate::{
@@ -116,6 +119,9 @@ where
                 inputs.push(BabyBear::from_canonical_u8(*byte));
             }
         }
+        if let Some(tag) = self.fri_profile.and_then(FriSecurityProfile::digest_tag) {
+            inputs.push(BabyBear::from_canonical_u32(tag));
+        }
 
         poseidon2_hash(inputs)
     }
@@ -253,6 +259,10 @@ pub enum MONEROCHANReduceProofWrapper {
 pub enum MONEROCHANRecursionProverError {
     #[error("Runtime error: {0}")]
     RuntimeError(String),
+    #[error("no recursion vks are allowed for the {0:?} FRI security profile")]
+    NonStandardFriProfile(Option<FriSecurityProfile>),
+    #[error("the options select the {found} FRI profile, but the prover uses {expected:?}")]
+    FriProfileMismatch { found: FriSecurityProfile, expected: Option<FriSecurityProfile> },
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -98,6 +98,7 @@ where
             initial_global_cumulative_sum,
             chip_information,
             chip_ordering,
+            fri_profile: self.fri_profile,
         }
     }
 
@@ -161,6 +161,7 @@ pub fn dummy_vk_and_shard_proof<A: MachineAir<BabyBear>>(
         initial_global_cumulative_sum: SepticDigest::<BabyBear>::zero(),
         chip_information: preprocessed_chip_information,
         chip_ordering: preprocessed_chip_ordering,
+        fri_profile: machine.config().fri_profile(),
     };
 
     let shard_proof =
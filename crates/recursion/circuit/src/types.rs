@@ -2,7 +2,7 @@ use hashbrown::HashMap;
 use p3_commit::TwoAdicMultiplicativeCoset;
 use p3_field::{AbstractField, TwoAdicField};
 use p3_matrix::Dimensions;
-use monerochan_stark::septic_digest::SepticDigest;
+use monerochan_stark::{septic_digest::SepticDigest, FriSecurityProfile};
 
 use monerochan_recursion_compiler::ir::{Builder, Ext, Felt};
 
@@ -21,6 +21,7 @@ pub struct VerifyingKeyVariable<C: CircuitConfig<F = SC::Val>, SC: BabyBearFriCo
     pub initial_global_cumulative_sum: SepticDigest<Felt<C::F>>,
     pub chip_information: Vec<(String, TwoAdicMultiplicativeCoset<C::F>, Dimensions)>,
     pub chip_ordering: HashMap<String, usize>,
+    pub fri_profile: Option<FriSecurityProfile>,
 }
 
 #[derive(Clone)]
@@ -92,6 +93,11 @@ impl<C: CircuitConfig<F = SC::Val>, SC: BabyBearFriConfigVariable<C>> VerifyingK
         // Observe the padding.
         let zero: Felt<_> = builder.eval(C::F::zero());
         challenger.observe(builder, zero);
+        // Observe the FRI security profile, unless it is the standard one.
+        if let Some(tag) = self.fri_profile.and_then(FriSecurityProfile::digest_tag) {
+            let tag: Felt<_> = builder.eval(C::F::from_canonical_u32(tag));
+            challenger.observe(builder, tag);
+        }
     }
 
     /// Hash the verifying key + prep domains into a single digest.
@@ -125,6 +131,9 @@ impl<C: CircuitConfig<F = SC::Val>, SC: BabyBearFriConfigVariable<C>> VerifyingK
                 inputs.push(builder.eval(C::F::from_canonical_u8(*byte)));
             }
         }
+        if let Some(tag) = self.fri_profile.and_then(FriSecurityProfile::digest_tag) {
+            inputs.push(builder.eval(C::F::from_canonical_u32(tag)));
+        }
 
         SC::hash(builder, &inputs)
     }
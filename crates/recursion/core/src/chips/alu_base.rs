@@ -71,6 +71,10 @@ impl<F: PrimeField32> MachineAir<F> for BaseAluChip {
         "BaseAlu".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        BaseAluCols::<F>::column_names()
+    }
+
     fn preprocessed_width(&self) -> usize {
         NUM_BASE_ALU_PREPROCESSED_COLS
     }
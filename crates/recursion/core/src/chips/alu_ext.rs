@@ -71,6 +71,10 @@ impl<F: PrimeField32 + BinomiallyExtendable<D>> MachineAir<F> for ExtAluChip {
         "ExtAlu".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        ExtAluCols::<F>::column_names()
+    }
+
     fn preprocessed_width(&self) -> usize {
         NUM_EXT_ALU_PREPROCESSED_COLS
     }
@@ -64,6 +64,10 @@ impl<F: PrimeField32, const DEGREE: usize> MachineAir<F> for BatchFRIChip<DEGREE
         "BatchFRI".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        BatchFRICols::<F>::column_names()
+    }
+
     fn generate_dependencies(&self, _: &Self::Record, _: &mut Self::Record) {
         // This is a no-op.
     }
@@ -79,6 +79,10 @@ impl<F: PrimeField32, const DEGREE: usize> MachineAir<F> for ExpReverseBitsLenCh
         "ExpReverseBitsLen".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        ExpReverseBitsLenCols::<F>::column_names()
+    }
+
     fn generate_dependencies(&self, _: &Self::Record, _: &mut Self::Record) {
         // This is a no-op.
     }
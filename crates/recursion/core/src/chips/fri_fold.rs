@@ -93,6 +93,10 @@ impl<F: PrimeField32, const DEGREE: usize> MachineAir<F> for FriFoldChip<DEGREE>
         "FriFold".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        FriFoldCols::<F>::column_names()
+    }
+
     fn generate_dependencies(&self, _: &Self::Record, _: &mut Self::Record) {
         // This is a no-op.
     }
@@ -57,6 +57,11 @@ impl<F: PrimeField32> MachineAir<F> for MemoryChip<F> {
     fn name(&self) -> String {
         "MemoryVar".to_string()
     }
+
+    fn column_names(&self) -> Vec<String> {
+        MemoryCols::<F>::column_names()
+    }
+
     fn preprocessed_width(&self) -> usize {
         NUM_MEM_PREPROCESSED_INIT_COLS
     }
@@ -11,8 +11,7 @@ use std::mem::size_of;
 #[cfg(feature = "sys")]
 use {
     crate::{
-        chips::poseidon2_skinny::columns::{Poseidon2 as Poseidon2Cols, NUM_POSEIDON2_COLS},
-        instruction::Instruction::Poseidon2,
+        chips::poseidon2_skinny::columns::NUM_POSEIDON2_COLS, instruction::Instruction::Poseidon2,
         Poseidon2Io, Poseidon2SkinnyInstr,
     },
     itertools::Itertools,
@@ -22,7 +21,7 @@ use {
     tracing::instrument,
 };
 
-use super::columns::preprocessed::Poseidon2PreprocessedCols;
+use super::columns::{preprocessed::Poseidon2PreprocessedCols, Poseidon2 as Poseidon2Cols};
 
 const PREPROCESSED_POSEIDON2_WIDTH: usize = size_of::<Poseidon2PreprocessedCols<u8>>();
 pub const OUTPUT_ROUND_IDX: usize = NUM_EXTERNAL_ROUNDS + 2;
@@ -36,6 +35,10 @@ impl<F: PrimeField32, const DEGREE: usize> MachineAir<F> for Poseidon2SkinnyChip
         format!("Poseidon2SkinnyDeg{DEGREE}")
     }
 
+    fn column_names(&self) -> Vec<String> {
+        Poseidon2Cols::<F>::column_names()
+    }
+
     fn generate_dependencies(&self, _: &Self::Record, _: &mut Self::Record) {
         // This is a no-op.
     }
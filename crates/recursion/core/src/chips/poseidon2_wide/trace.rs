@@ -1,7 +1,10 @@
 use crate::ExecutionRecord;
 use p3_field::PrimeField32;
 use p3_matrix::dense::RowMajorMatrix;
-use monerochan_core_machine::utils::next_power_of_two;
+use monerochan_core_machine::{
+    operations::poseidon2::permutation::{Poseidon2Degree3Cols, Poseidon2Degree9Cols},
+    utils::next_power_of_two,
+};
 use monerochan_stark::air::MachineAir;
 use std::mem::size_of;
 
@@ -30,6 +33,14 @@ impl<F: PrimeField32, const DEGREE: usize> MachineAir<F> for Poseidon2WideChip<D
         format!("Poseidon2WideDeg{DEGREE}")
     }
 
+    fn column_names(&self) -> Vec<String> {
+        match DEGREE {
+            3 => Poseidon2Degree3Cols::<F>::column_names(),
+            9 | 17 => Poseidon2Degree9Cols::<F>::column_names(),
+            _ => panic!("Unsupported degree: {DEGREE}"),
+        }
+    }
+
     fn generate_dependencies(&self, _: &Self::Record, _: &mut Self::Record) {
         // This is a no-op.
     }
@@ -60,6 +60,10 @@ impl<F: PrimeField32> MachineAir<F> for PublicValuesChip {
         "PublicValues".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        PublicValuesCols::<F>::column_names()
+    }
+
     fn generate_dependencies(&self, _: &Self::Record, _: &mut Self::Record) {
         // This is a no-op.
     }
@@ -48,6 +48,10 @@ impl<F: PrimeField32> MachineAir<F> for SelectChip {
         "Select".to_string()
     }
 
+    fn column_names(&self) -> Vec<String> {
+        SelectCols::<F>::column_names()
+    }
+
     fn preprocessed_width(&self) -> usize {
         SELECT_PREPROCESSED_COLS
     }
@@ -92,6 +92,7 @@ impl CpuProver {
             context_builder: MONEROCHANContextBuilder::default(),
             core_opts: MONEROCHANCoreOpts::default(),
             recursion_opts: MONEROCHANCoreOpts::recursion(),
+            fri_profile: None,
             mock: self.mock,
         }
     }
@@ -6,7 +6,7 @@ use anyhow::Result;
 use monerochan_core_executor::{IoWriter, MONEROCHANContextBuilder};
 use monerochan_core_machine::io::MONEROCHANStdin;
 use monerochan_prover::MONEROCHANProvingKey;
-use monerochan_stark::{MONEROCHANCoreOpts, MONEROCHANProverOpts};
+use monerochan_stark::{FriSecurityProfile, MONEROCHANCoreOpts, MONEROCHANProverOpts};
 
 use super::CpuProver;
 use crate::{MONEROCHANProofMode, MONEROCHANProofWithPublicValues};
@@ -23,6 +23,7 @@ pub struct CpuProveBuilder<'a> {
     pub(crate) stdin: MONEROCHANStdin,
     pub(crate) core_opts: MONEROCHANCoreOpts,
     pub(crate) recursion_opts: MONEROCHANCoreOpts,
+    pub(crate) fri_profile: Option<FriSecurityProfile>,
     pub(crate) mock: bool,
 }
 
@@ -170,6 +171,31 @@ impl<'a> CpuProveBuilder<'a> {
         self
     }
 
+    /// Set the FRI security profile of the core proofs.
+    ///
+    /// # Details
+    /// By default, the core proofs use the FRI security profile of the prover. A proof generated
+    /// with another profile only verifies against the verifying key of a prover created with that
+    /// profile, and can only be generated in [`MONEROCHANProofMode::Core`] mode.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use monerochan::{include_elf, FriSecurityProfile, Prover, ProverClient, MONEROCHANStdin};
+    ///
+    /// let elf = &[1, 2, 3];
+    /// let stdin = MONEROCHANStdin::new();
+    ///
+    /// let client = ProverClient::builder().cpu().build();
+    /// let (pk, vk) = client.setup(elf);
+    /// let builder =
+    ///     client.prove(&pk, &stdin).fri_profile(FriSecurityProfile::Conjectured128).run();
+    /// ```
+    #[must_use]
+    pub fn fri_profile(mut self, value: FriSecurityProfile) -> Self {
+        self.fri_profile = Some(value);
+        self
+    }
+
     /// Set the shard batch size for proving.
     ///
     /// # Details
@@ -303,9 +329,18 @@ impl<'a> CpuProveBuilder<'a> {
     /// ```
     pub fn run(self) -> Result<MONEROCHANProofWithPublicValues> {
         // Get the arguments.
-        let Self { prover, mode, pk, stdin, mut context_builder, core_opts, recursion_opts, mock } =
-            self;
-        let opts = MONEROCHANProverOpts { core_opts, recursion_opts };
+        let Self {
+            prover,
+            mode,
+            pk,
+            stdin,
+            mut context_builder,
+            core_opts,
+            recursion_opts,
+            fri_profile,
+            mock,
+        } = self;
+        let opts = MONEROCHANProverOpts { core_opts, recursion_opts, fri_profile };
         let context = context_builder.build();
 
         // Dump the program and stdin to files for debugging if `MONEROCHAN_DUMP` is set.
@@ -27,6 +27,7 @@ pub mod cpu;
 pub mod cuda;
 pub mod env;
 pub mod install;
+pub mod merkle;
 #[cfg(feature = "network")]
 pub mod network;
 pub mod utils;
@@ -57,6 +58,7 @@ pub use monerochan_core_executor::{ExecutionReport, Executor, HookEnv, MONEROCHA
 // Re-export the machine/prover primitives.
 pub use monerochan_core_machine::io::MONEROCHANStdin;
 pub use monerochan_primitives::io::MONEROCHANPublicValues;
+pub use monerochan_stark::FriSecurityProfile;
 pub use monerochan_prover::{
     HashableKey, ProverMode, MONEROCHANProver, MONEROCHANProvingKey, MONEROCHANVerifyingKey, MONEROCHAN_CIRCUIT_VERSION,
 };
@@ -0,0 +1,317 @@
+//! # MONEROCHAN Merkle Trees
+//!
+//! Host side construction of the Merkle trees and proofs that are verified in programs with
+//! `monerochan_lib::merkle`. The proof types are shared with the guest library, so a proof can be
+//! written to the program's stdin as is.
+
+use std::{collections::BTreeMap, marker::PhantomData};
+
+use sha2::Digest;
+use tiny_keccak::Hasher;
+
+pub use monerochan_lib::merkle::{
+    compute_root, MerkleHasher, MerkleProof, MultiProof, Poseidon2Hasher, SparseMerkleProof,
+    SparseMerkleVerifier, EMPTY_LEAF, SPARSE_MERKLE_DEPTH,
+};
+
+/// SHA-256, computing the same nodes as `monerochan_lib::merkle::Sha256Hasher`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha256;
+
+impl MerkleHasher for Sha256 {
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().into()
+    }
+}
+
+/// Keccak-256, computing the same nodes as `monerochan_lib::merkle::Keccak256Hasher`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Keccak256;
+
+impl MerkleHasher for Keccak256 {
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = tiny_keccak::Keccak::v256();
+        hasher.update(left);
+        hasher.update(right);
+        let mut hash = [0u8; 32];
+        hasher.finalize(&mut hash);
+        hash
+    }
+}
+
+/// A binary Merkle tree whose leaves are padded with [`EMPTY_LEAF`] up to a power of two.
+#[derive(Debug, Clone)]
+pub struct MerkleTree<H> {
+    /// The nodes of every level, from the leaves to the root.
+    levels: Vec<Vec<[u8; 32]>>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: MerkleHasher> MerkleTree<H> {
+    /// Builds the tree over the given leaves.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no leaves.
+    #[must_use]
+    pub fn new(mut leaves: Vec<[u8; 32]>) -> Self {
+        assert!(!leaves.is_empty(), "a Merkle tree needs at least one leaf");
+        leaves.resize(leaves.len().next_power_of_two(), EMPTY_LEAF);
+
+        let mut levels = vec![leaves];
+        while levels[levels.len() - 1].len() > 1 {
+            let next = levels[levels.len() - 1]
+                .chunks_exact(2)
+                .map(|pair| H::hash_pair(&pair[0], &pair[1]))
+                .collect();
+            levels.push(next);
+        }
+        Self { levels, _hasher: PhantomData }
+    }
+
+    /// The depth of the tree, which is the number of siblings in a proof.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// The root of the tree.
+    #[must_use]
+    pub fn root(&self) -> [u8; 32] {
+        self.levels[self.depth()][0]
+    }
+
+    /// The leaves of the tree, including the padding.
+    #[must_use]
+    pub fn leaves(&self) -> &[[u8; 32]] {
+        &self.levels[0]
+    }
+
+    /// The inclusion proof of the leaf at `index`.
+    #[must_use]
+    pub fn proof(&self, index: u64) -> MerkleProof {
+        let siblings = (0..self.depth())
+            .map(|level| self.levels[level][((index >> level) ^ 1) as usize])
+            .collect();
+        MerkleProof { index, siblings }
+    }
+
+    /// The inclusion proof of the leaves at the given indices.
+    ///
+    /// The indices are sorted and deduplicated, and the leaves must be passed to
+    /// [`MultiProof::verify`] in the order of the proof's `indices`.
+    #[must_use]
+    pub fn multi_proof(&self, indices: &[u64]) -> MultiProof {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+
+        let mut siblings = Vec::new();
+        let mut current = indices.clone();
+        for level in 0..self.depth() {
+            let mut next = Vec::with_capacity(current.len());
+            let mut i = 0;
+            while i < current.len() {
+                let index = current[i];
+                if index & 1 == 0 && current.get(i + 1) == Some(&(index + 1)) {
+                    i += 1;
+                } else {
+                    siblings.push(self.levels[level][(index ^ 1) as usize]);
+                }
+                next.push(index >> 1);
+                i += 1;
+            }
+            current = next;
+        }
+
+        MultiProof { depth: self.depth() as u32, indices, siblings }
+    }
+}
+
+/// A sparse Merkle tree of depth [`SPARSE_MERKLE_DEPTH`] mapping 32-byte keys to 32-byte values.
+///
+/// Absent keys have the value [`EMPTY_LEAF`], so inserting that value removes a key.
+#[derive(Clone)]
+pub struct SparseMerkleTree<H> {
+    leaves: BTreeMap<[u8; 32], [u8; 32]>,
+    empty: SparseMerkleVerifier<H>,
+}
+
+impl<H: MerkleHasher> SparseMerkleTree<H> {
+    /// Creates an empty tree.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { leaves: BTreeMap::new(), empty: SparseMerkleVerifier::new() }
+    }
+
+    /// Sets the value of `key`.
+    pub fn insert(&mut self, key: [u8; 32], value: [u8; 32]) {
+        if value == EMPTY_LEAF {
+            self.leaves.remove(&key);
+        } else {
+            self.leaves.insert(key, value);
+        }
+    }
+
+    /// The value of `key`, which is [`EMPTY_LEAF`] if the key is absent.
+    #[must_use]
+    pub fn get(&self, key: &[u8; 32]) -> [u8; 32] {
+        self.leaves.get(key).copied().unwrap_or(EMPTY_LEAF)
+    }
+
+    /// The root of the tree.
+    #[must_use]
+    pub fn root(&self) -> [u8; 32] {
+        if self.leaves.is_empty() {
+            return self.empty.empty_root();
+        }
+        let leaves = self.leaves.iter().map(|(key, value)| (*key, *value)).collect::<Vec<_>>();
+        self.subtree_root(&leaves, 0)
+    }
+
+    /// The proof of the value of `key`, which is a non-membership proof if the key is absent.
+    #[must_use]
+    pub fn proof(&self, key: &[u8; 32]) -> SparseMerkleProof {
+        let leaves = self.leaves.iter().map(|(key, value)| (*key, *value)).collect::<Vec<_>>();
+
+        let mut bitmap = [0u8; 32];
+        let mut siblings = Vec::new();
+        let mut current = &leaves[..];
+        for depth in 0..SPARSE_MERKLE_DEPTH {
+            let (left, right) = current.split_at(current.partition_point(|(k, _)| !bit(k, depth)));
+            let (path, other) = if bit(key, depth) { (right, left) } else { (left, right) };
+
+            let height = SPARSE_MERKLE_DEPTH - 1 - depth;
+            let sibling = self.subtree_root(other, depth + 1);
+            if sibling != self.empty.empty_subtree(height) {
+                bitmap[height / 8] |= 1 << (height % 8);
+                siblings.push(sibling);
+            }
+            current = path;
+        }
+
+        siblings.reverse();
+        SparseMerkleProof { bitmap, siblings }
+    }
+
+    /// The root of the subtree at `depth` holding the given leaves, which are sorted by key and
+    /// share the subtree's path.
+    fn subtree_root(&self, leaves: &[([u8; 32], [u8; 32])], depth: usize) -> [u8; 32] {
+        if leaves.is_empty() {
+            return self.empty.empty_subtree(SPARSE_MERKLE_DEPTH - depth);
+        }
+        if depth == SPARSE_MERKLE_DEPTH {
+            return leaves[0].1;
+        }
+
+        let (left, right) = leaves.split_at(leaves.partition_point(|(k, _)| !bit(k, depth)));
+        H::hash_pair(&self.subtree_root(left, depth + 1), &self.subtree_root(right, depth + 1))
+    }
+}
+
+impl<H: MerkleHasher> Default for SparseMerkleTree<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Bit `depth` of the key, counted from the most significant bit of the first byte.
+fn bit(key: &[u8; 32], depth: usize) -> bool {
+    (key[depth / 8] >> (7 - depth % 8)) & 1 == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(i: u64) -> [u8; 32] {
+        let mut leaf = [0u8; 32];
+        leaf[..8].copy_from_slice(&(i + 1).to_le_bytes());
+        leaf
+    }
+
+    #[test]
+    fn test_merkle_proofs() {
+        let tree = MerkleTree::<Sha256>::new((0..13).map(leaf).collect());
+        assert_eq!(tree.depth(), 4);
+        let root = tree.root();
+
+        for i in 0..16 {
+            let proof = tree.proof(i);
+            assert!(proof.verify::<Sha256>(&root, &tree.leaves()[i as usize]));
+            assert!(!proof.verify::<Sha256>(&root, &leaf(100)));
+        }
+
+        let proof = tree.multi_proof(&[11, 2, 3, 8, 2]);
+        assert_eq!(proof.indices, vec![2, 3, 8, 11]);
+        let leaves = proof.indices.iter().map(|&i| tree.leaves()[i as usize]).collect::<Vec<_>>();
+        assert!(proof.verify::<Sha256>(&root, &leaves));
+        assert!(!proof.verify::<Sha256>(&root, &leaves[..3]));
+
+        let mut tampered = proof.clone();
+        tampered.indices[3] = 10;
+        assert!(!tampered.verify::<Sha256>(&root, &leaves));
+    }
+
+    #[test]
+    fn test_sparse_merkle_proofs() {
+        let mut tree = SparseMerkleTree::<Keccak256>::new();
+        let empty_root = SparseMerkleVerifier::<Keccak256>::new().empty_root();
+        assert_eq!(tree.root(), empty_root);
+
+        let keys = [[0u8; 32], [0xff; 32], [0x80; 32], [0x7f; 32]];
+        for (i, key) in keys.iter().enumerate() {
+            tree.insert(*key, leaf(i as u64));
+        }
+        let root = tree.root();
+
+        let verifier = SparseMerkleVerifier::<Keccak256>::new();
+        for (i, key) in keys.iter().enumerate() {
+            let proof = tree.proof(key);
+            assert!(verifier.verify(&root, key, &leaf(i as u64), &proof));
+            assert!(!verifier.verify(&root, key, &EMPTY_LEAF, &proof));
+        }
+
+        let absent = [0x81; 32];
+        let proof = tree.proof(&absent);
+        assert!(verifier.verify(&root, &absent, &EMPTY_LEAF, &proof));
+
+        for key in &keys {
+            tree.insert(*key, EMPTY_LEAF);
+        }
+        assert_eq!(tree.root(), empty_root);
+    }
+
+    #[test]
+    fn test_merkle_program() {
+        use crate::{utils, MONEROCHANStdin, Prover, ProverClient};
+
+        fn write_proofs<H: MerkleHasher>(stdin: &mut MONEROCHANStdin) {
+            let tree = MerkleTree::<H>::new((0..10).map(leaf).collect());
+            let proof = tree.multi_proof(&[0, 5, 6, 9]);
+            let leaves =
+                proof.indices.iter().map(|&i| tree.leaves()[i as usize]).collect::<Vec<_>>();
+            stdin.write(&tree.root());
+            stdin.write(&(leaf(7), tree.proof(7)));
+            stdin.write(&(leaves, proof));
+
+            let mut sparse = SparseMerkleTree::<H>::new();
+            sparse.insert([3; 32], leaf(3));
+            sparse.insert([200; 32], leaf(200));
+            stdin.write(&sparse.root());
+            stdin.write(&([3u8; 32], leaf(3), sparse.proof(&[3; 32])));
+            stdin.write(&([4u8; 32], EMPTY_LEAF, sparse.proof(&[4; 32])));
+        }
+
+        utils::setup_logger();
+        let mut stdin = MONEROCHANStdin::new();
+        write_proofs::<Sha256>(&mut stdin);
+        write_proofs::<Keccak256>(&mut stdin);
+
+        let client = ProverClient::builder().cpu().build();
+        client.execute(test_artifacts::MERKLE_ELF, &stdin).run().unwrap();
+    }
+}
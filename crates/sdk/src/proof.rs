@@ -3,7 +3,7 @@
 //! A library of types and functions for MONEROCHAN proofs.
 #![allow(missing_docs)]
 
-use std::{fmt::Debug, fs::File, path::Path};
+use std::{fmt::Debug, path::Path};
 
 use anyhow::{Context, Result};
 use hashbrown::HashMap;
@@ -12,13 +12,19 @@ use p3_field::{extension::BinomialExtensionField, AbstractField, PrimeField};
 use p3_fri::{FriProof, TwoAdicFriPcsProof};
 use serde::{Deserialize, Serialize};
 use monerochan_primitives::io::MONEROCHANPublicValues;
-use monerochan_prover::{Groth16Bn254Proof, HashableKey, PlonkBn254Proof, MONEROCHANProvingKey};
+use monerochan_prover::{
+    Groth16Bn254Proof, HashableKey, PlonkBn254Proof, MONEROCHANProvingKey, MONEROCHANVerifyingKey,
+    MONEROCHAN_CIRCUIT_VERSION,
+};
 use monerochan_stark::{
-    septic_digest::SepticDigest, MONEROCHANReduceProof, ShardCommitment, ShardOpenedValues, ShardProof,
-    StarkVerifyingKey,
+    is_proof_container, septic_digest::SepticDigest, FriSecurityProfile, MONEROCHANReduceProof,
+    ShardCommitment, ShardOpenedValues, ShardProof, StarkVerifyingKey,
 };
 
-pub use monerochan_stark::{MONEROCHANProof, MONEROCHANProofMode};
+pub use monerochan_stark::{
+    CompactMONEROCHANProof, MONEROCHANProof, MONEROCHANProofMode, ProofCompression, ProofContainer,
+    ProofContainerError, ProofDecoder, ProofHeader,
+};
 
 /// A proof generated by the MONEROCHAN RISC-V zkVM bundled together with the public values and the
 /// version.
@@ -56,6 +62,16 @@ impl From<ProofFromNetwork> for MONEROCHANProofWithPublicValues {
     }
 }
 
+/// A [`MONEROCHANProofWithPublicValues`] whose proof is in the compact encoding of
+/// [`CompactMONEROCHANProof`].
+#[derive(Serialize, Deserialize)]
+struct CompactProofWithPublicValues {
+    proof: CompactMONEROCHANProof,
+    public_values: MONEROCHANPublicValues,
+    monerochan_version: String,
+    tee_proof: Option<Vec<u8>>,
+}
+
 impl MONEROCHANProofWithPublicValues {
     /// Creates a new [`MONEROCHANProofWithPublicValues`] from the proof, public values, and MONEROCHAN version.
     ///
@@ -68,25 +84,75 @@ impl MONEROCHANProofWithPublicValues {
         Self { proof, public_values, monerochan_version, tee_proof: None }
     }
 
-    /// Saves the proof to a path.
+    /// Saves the proof to a path, as an uncompressed [`ProofContainer`] without the vkey hash.
     pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
-        bincode::serialize_into(
-            File::create(path.as_ref()).with_context(|| {
-                format!("failed to create file for saving proof: {}", path.as_ref().display())
-            })?,
-            self,
-        )
-        .map_err(Into::into)
+        self.save_with(path, None, ProofCompression::None)
+    }
+
+    /// Saves the proof to a path, as a [`ProofContainer`] with the given compression and with the
+    /// vkey hash of the program if `vk` is given.
+    pub fn save_with(
+        &self,
+        path: impl AsRef<Path>,
+        vk: Option<&MONEROCHANVerifyingKey>,
+        compression: ProofCompression,
+    ) -> Result<()> {
+        let mut container = self.to_container();
+        container.header.vkey_hash = vk.map(HashableKey::bytes32_raw);
+        container.header.compression = compression;
+        std::fs::write(path.as_ref(), container.encode()?).with_context(|| {
+            format!("failed to create file for saving proof: {}", path.as_ref().display())
+        })
+    }
+
+    /// Wraps the proof in a [`ProofContainer`] for its MONEROCHAN version, without the vkey hash.
+    #[must_use]
+    pub fn to_container(&self) -> ProofContainer {
+        let mut container = ProofContainer::new(
+            self.proof.clone(),
+            self.public_values.to_vec(),
+            &self.monerochan_version,
+        );
+        container.header.tee_proof.clone_from(&self.tee_proof);
+        container
+    }
+
+    /// Unwraps the proof from a [`ProofContainer`].
+    #[must_use]
+    pub fn from_container(container: ProofContainer) -> Self {
+        Self {
+            proof: container.proof,
+            public_values: MONEROCHANPublicValues::from(&container.header.public_values),
+            monerochan_version: container.header.circuit_version,
+            tee_proof: container.header.tee_proof,
+        }
     }
 
     /// Loads a proof from a path.
+    ///
+    /// The proof must have been generated by the current circuit version,
+    /// [`MONEROCHAN_CIRCUIT_VERSION`]. Use [`Self::load_with`] to load proofs of other versions.
     pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::load_with(path, &ProofDecoder::new(MONEROCHAN_CIRCUIT_VERSION))
+    }
+
+    /// Loads a proof from a path, decoding it with the given decoder.
+    ///
+    /// Proofs saved before the [`ProofContainer`] format are still loaded, without the checks of
+    /// the decoder.
+    pub fn load_with(path: impl AsRef<Path>, decoder: &ProofDecoder) -> Result<Self> {
+        let bytes = std::fs::read(path.as_ref()).with_context(|| {
+            format!("failed to open file for loading proof: {}", path.as_ref().display())
+        })?;
+        if is_proof_container(&bytes) {
+            let container = decoder
+                .decode(&bytes)
+                .with_context(|| format!("failed to decode proof: {}", path.as_ref().display()))?;
+            return Ok(Self::from_container(container));
+        }
+
         // Try to load a [`Self`] from the file.
-        let maybe_this: Result<Self> =
-            bincode::deserialize_from(File::open(path.as_ref()).with_context(|| {
-                format!("failed to open file for loading proof: {}", path.as_ref().display())
-            })?)
-            .map_err(Into::into);
+        let maybe_this: Result<Self> = bincode::deserialize(&bytes).map_err(Into::into);
 
         // This may be a proof from the monero-chan network, which lacks the TEE proof field.
         match maybe_this {
@@ -95,13 +161,7 @@ impl MONEROCHANProofWithPublicValues {
                 // If the file does not contain a [`Self`], try to load a [`ProofFromNetwork`]
                 // instead.
                 let maybe_proof_from_network: Result<ProofFromNetwork> =
-                    bincode::deserialize_from(File::open(path.as_ref()).with_context(|| {
-                        format!(
-                            "failed to open file for loading proof: {}",
-                            path.as_ref().display()
-                        )
-                    })?)
-                    .map_err(Into::into);
+                    bincode::deserialize(&bytes).map_err(Into::into);
 
                 if let Ok(proof_from_network) = maybe_proof_from_network {
                     // The file contains a [`ProofFromNetwork`], which lacks the TEE proof field.
@@ -114,6 +174,34 @@ impl MONEROCHANProofWithPublicValues {
         }
     }
 
+    /// Serializes the proof with the Merkle opening proofs of its shard proofs deduplicated across
+    /// the FRI queries, see [`CompactMONEROCHANProof`].
+    ///
+    /// Core and compressed proofs are smaller in this encoding than with `bincode`, which makes it
+    /// suited to sending proofs over the network. Use [`Self::from_compact_bytes`] to decode it.
+    pub fn to_compact_bytes(&self) -> Result<Vec<u8>> {
+        let compact = CompactProofWithPublicValues {
+            proof: CompactMONEROCHANProof::from(&self.proof),
+            public_values: self.public_values.clone(),
+            monerochan_version: self.monerochan_version.clone(),
+            tee_proof: self.tee_proof.clone(),
+        };
+        bincode::serialize(&compact).map_err(Into::into)
+    }
+
+    /// Decodes a proof serialized with [`Self::to_compact_bytes`].
+    ///
+    /// The decoded proof is verified like any other proof.
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self> {
+        let compact: CompactProofWithPublicValues = bincode::deserialize(bytes)?;
+        Ok(Self {
+            proof: compact.proof.try_into()?,
+            public_values: compact.public_values,
+            monerochan_version: compact.monerochan_version,
+            tee_proof: compact.tee_proof,
+        })
+    }
+
     /// The proof in the byte encoding the onchain verifiers accepts for [`MONEROCHANProofMode::Groth16`]
     /// and [`MONEROCHANProofMode::Plonk`] proofs.
     ///
@@ -238,6 +326,7 @@ impl MONEROCHANProofWithPublicValues {
                     chip_information: vec![],
                     chip_ordering: HashMap::new(),
                     initial_global_cumulative_sum: SepticDigest::zero(),
+                    fri_profile: Some(FriSecurityProfile::Standard),
                 };
 
                 let proof = MONEROCHANProof::Compressed(Box::new(MONEROCHANReduceProof {
@@ -389,6 +478,77 @@ mod tests {
         let _ = bincode::deserialize::<ProofFromNetwork>(&round_trip_bytes).unwrap();
     }
 
+    #[test]
+    fn test_save_load_proof_container() {
+        let proof = MONEROCHANProofWithPublicValues {
+            proof: MONEROCHANProof::Core(vec![]),
+            public_values: MONEROCHANPublicValues::from(&[1, 2, 3]),
+            monerochan_version: MONEROCHAN_CIRCUIT_VERSION.to_string(),
+            tee_proof: Some(vec![4, 5]),
+        };
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("proof.bin");
+
+        proof.save_with(&path, None, ProofCompression::Deflate).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        let header = ProofHeader::read(&bytes).unwrap();
+        assert_eq!(header.circuit_version, MONEROCHAN_CIRCUIT_VERSION);
+        assert_eq!(header.mode, MONEROCHANProofMode::Core);
+        assert_eq!(header.compression, ProofCompression::Deflate);
+
+        let loaded = MONEROCHANProofWithPublicValues::load(&path).unwrap();
+        assert_eq!(loaded.public_values.as_slice(), &[1, 2, 3]);
+        assert_eq!(loaded.tee_proof, Some(vec![4, 5]));
+
+        // A proof of another circuit version is only loaded with a migration.
+        let old_proof =
+            MONEROCHANProofWithPublicValues { monerochan_version: "v0.0.0".to_string(), ..proof };
+        old_proof.save(&path).unwrap();
+        let err = MONEROCHANProofWithPublicValues::load(&path).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ProofContainerError>(),
+            Some(ProofContainerError::CircuitVersionMismatch { .. })
+        ));
+        let decoder = ProofDecoder::new(MONEROCHAN_CIRCUIT_VERSION)
+            .with_migration("v0.0.0", |_, payload| {
+                bincode::deserialize(payload).map_err(ProofContainerError::Deserialize)
+            });
+        let loaded = MONEROCHANProofWithPublicValues::load_with(&path, &decoder).unwrap();
+        assert_eq!(loaded.monerochan_version, "v0.0.0");
+    }
+
+    #[test]
+    fn test_compact_bytes_round_trip() {
+        let proof = MONEROCHANProofWithPublicValues {
+            proof: MONEROCHANProof::Core(vec![]),
+            public_values: MONEROCHANPublicValues::from(&[1, 2, 3]),
+            monerochan_version: MONEROCHAN_CIRCUIT_VERSION.to_string(),
+            tee_proof: None,
+        };
+        let bytes = proof.to_compact_bytes().unwrap();
+        let decoded = MONEROCHANProofWithPublicValues::from_compact_bytes(&bytes).unwrap();
+        assert!(matches!(decoded.proof, MONEROCHANProof::Core(ref shards) if shards.is_empty()));
+        assert_eq!(decoded.public_values.as_slice(), &[1, 2, 3]);
+        assert_eq!(decoded.monerochan_version, MONEROCHAN_CIRCUIT_VERSION);
+    }
+
+    #[test]
+    fn test_load_legacy_proof() {
+        let proof = MONEROCHANProofWithPublicValues {
+            proof: MONEROCHANProof::Core(vec![]),
+            public_values: MONEROCHANPublicValues::from(&[1, 2, 3]),
+            monerochan_version: "v0.0.0".to_string(),
+            tee_proof: None,
+        };
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("proof.bin");
+        std::fs::write(&path, bincode::serialize(&proof).unwrap()).unwrap();
+
+        let loaded = MONEROCHANProofWithPublicValues::load(&path).unwrap();
+        assert_eq!(loaded.public_values.as_slice(), &[1, 2, 3]);
+        assert_eq!(loaded.monerochan_version, "v0.0.0");
+    }
+
     #[test]
     fn test_round_trip_proof_save_load() {
         use crate::Prover;
@@ -409,5 +569,12 @@ mod tests {
 
         // Verify the loaded proof
         prover.verify(&proof_loaded, &pk.vk).unwrap();
+
+        // The compact encoding is smaller, and decodes to a proof that verifies.
+        let compact_bytes = proof.to_compact_bytes().unwrap();
+        assert!(compact_bytes.len() < bincode::serialize(&proof).unwrap().len());
+        let proof_decoded =
+            MONEROCHANProofWithPublicValues::from_compact_bytes(&compact_bytes).unwrap();
+        prover.verify(&proof_decoded, &pk.vk).unwrap();
     }
 }
@@ -73,6 +73,13 @@ pub trait MachineAir<F: Field>: BaseAir<F> + 'static + Send + Sync {
     fn local_only(&self) -> bool {
         false
     }
+
+    /// The names of the columns of the main trace, used to report failing constraints.
+    ///
+    /// Airs whose main trace is an `AlignedBorrow` struct return its `column_names`.
+    fn column_names(&self) -> Vec<String> {
+        (0..self.width()).map(|i| format!("main[{i}]")).collect()
+    }
 }
 
 /// A program that defines the control flow of a machine through a program counter.
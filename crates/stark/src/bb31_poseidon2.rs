@@ -1,6 +1,8 @@
 #![allow(missing_docs)]
 
-use crate::{Com, StarkGenericConfig, ZeroCommitment};
+use crate::{
+    Com, FriConfigError, FriParameters, FriSecurityProfile, StarkGenericConfig, ZeroCommitment,
+};
 use p3_baby_bear::{BabyBear, DiffusionMatrixBabyBear};
 use p3_challenger::DuplexChallenger;
 use p3_commit::ExtensionMmcs;
@@ -51,32 +53,68 @@ pub fn inner_perm() -> InnerPerm {
     poseidon2_init()
 }
 
-/// The FRI config for monerochan proofs.
-#[must_use]
-pub fn monerochan_fri_config() -> FriConfig<InnerChallengeMmcs> {
+/// Returns the number of FRI queries set by the `FRI_QUERIES` environment variable, or `default`
+/// if it is not set.
+fn num_queries_from_env(default: usize) -> usize {
+    match std::env::var("FRI_QUERIES") {
+        Ok(value) => value.parse().unwrap(),
+        Err(_) => default,
+    }
+}
+
+/// Returns the number of FRI queries of the given security profile.
+///
+/// The `FRI_QUERIES` environment variable only overrides the number of queries of the standard
+/// profile. The other profiles fix their number of queries, so setting it with them is an error.
+fn num_queries_of_profile(profile: FriSecurityProfile) -> Result<usize, FriConfigError> {
+    let num_queries = profile.parameters().num_queries;
+    match profile {
+        FriSecurityProfile::Standard => Ok(num_queries_from_env(num_queries)),
+        _ if std::env::var_os("FRI_QUERIES").is_some() => {
+            Err(FriConfigError::QueriesWithProfile(profile))
+        }
+        _ => Ok(num_queries),
+    }
+}
+
+/// Returns the number of FRI queries of the standard security profile.
+fn num_queries_of_standard_profile() -> usize {
+    num_queries_from_env(FriSecurityProfile::Standard.parameters().num_queries)
+}
+
+/// The FRI config for monerochan proofs with the given security profile and number of queries.
+fn monerochan_fri_config_with_queries(
+    profile: FriSecurityProfile,
+    num_queries: usize,
+) -> FriConfig<InnerChallengeMmcs> {
     let perm = inner_perm();
     let hash = InnerHash::new(perm.clone());
     let compress = InnerCompress::new(perm.clone());
     let challenge_mmcs = InnerChallengeMmcs::new(InnerValMmcs::new(hash, compress));
-    let num_queries = match std::env::var("FRI_QUERIES") {
-        Ok(value) => value.parse().unwrap(),
-        Err(_) => 100,
-    };
-    FriConfig { log_blowup: 1, num_queries, proof_of_work_bits: 16, mmcs: challenge_mmcs }
+    let FriParameters { log_blowup, proof_of_work_bits, .. } = profile.parameters();
+    FriConfig { log_blowup, num_queries, proof_of_work_bits, mmcs: challenge_mmcs }
+}
+
+/// The FRI config for monerochan proofs with the given security profile.
+pub fn monerochan_fri_config_with_profile(
+    profile: FriSecurityProfile,
+) -> Result<FriConfig<InnerChallengeMmcs>, FriConfigError> {
+    Ok(monerochan_fri_config_with_queries(profile, num_queries_of_profile(profile)?))
+}
+
+/// The FRI config for monerochan proofs.
+#[must_use]
+pub fn monerochan_fri_config() -> FriConfig<InnerChallengeMmcs> {
+    monerochan_fri_config_with_queries(
+        FriSecurityProfile::Standard,
+        num_queries_of_standard_profile(),
+    )
 }
 
 /// The FRI config for inner recursion.
 #[must_use]
 pub fn inner_fri_config() -> FriConfig<InnerChallengeMmcs> {
-    let perm = inner_perm();
-    let hash = InnerHash::new(perm.clone());
-    let compress = InnerCompress::new(perm.clone());
-    let challenge_mmcs = InnerChallengeMmcs::new(InnerValMmcs::new(hash, compress));
-    let num_queries = match std::env::var("FRI_QUERIES") {
-        Ok(value) => value.parse().unwrap(),
-        Err(_) => 100,
-    };
-    FriConfig { log_blowup: 1, num_queries, proof_of_work_bits: 16, mmcs: challenge_mmcs }
+    monerochan_fri_config()
 }
 
 /// The recursion config used for recursive reduce circuit.
@@ -142,6 +180,10 @@ impl StarkGenericConfig for BabyBearPoseidon2Inner {
     fn challenger(&self) -> Self::Challenger {
         InnerChallenger::new(self.perm.clone())
     }
+
+    fn fri_profile(&self) -> Option<FriSecurityProfile> {
+        Some(FriSecurityProfile::Standard)
+    }
 }
 
 impl ZeroCommitment<BabyBearPoseidon2Inner> for InnerPcs {
@@ -164,7 +206,10 @@ pub mod baby_bear_poseidon2 {
     use serde::{Deserialize, Serialize};
     use monerochan_primitives::RC_16_30;
 
-    use crate::{Com, StarkGenericConfig, ZeroCommitment, DIGEST_SIZE};
+    use crate::{
+        Com, FriConfigError, FriParameters, FriSecurityProfile, StarkGenericConfig, ZeroCommitment,
+        DIGEST_SIZE,
+    };
 
     pub type Val = BabyBear;
     pub type Challenge = BinomialExtensionField<Val, 4>;
@@ -209,15 +254,30 @@ pub mod baby_bear_poseidon2 {
 
     #[must_use]
     pub fn default_fri_config() -> FriConfig<ChallengeMmcs> {
+        fri_config_with_queries(
+            FriSecurityProfile::Standard,
+            super::num_queries_of_standard_profile(),
+        )
+    }
+
+    /// The FRI config with the parameters of the given security profile.
+    pub fn fri_config_with_profile(
+        profile: FriSecurityProfile,
+    ) -> Result<FriConfig<ChallengeMmcs>, FriConfigError> {
+        Ok(fri_config_with_queries(profile, super::num_queries_of_profile(profile)?))
+    }
+
+    /// The FRI config with the parameters of the given security profile and number of queries.
+    fn fri_config_with_queries(
+        profile: FriSecurityProfile,
+        num_queries: usize,
+    ) -> FriConfig<ChallengeMmcs> {
         let perm = my_perm();
         let hash = MyHash::new(perm.clone());
         let compress = MyCompress::new(perm.clone());
         let challenge_mmcs = ChallengeMmcs::new(ValMmcs::new(hash, compress));
-        let num_queries = match std::env::var("FRI_QUERIES") {
-            Ok(value) => value.parse().unwrap(),
-            Err(_) => 100,
-        };
-        FriConfig { log_blowup: 1, num_queries, proof_of_work_bits: 16, mmcs: challenge_mmcs }
+        let FriParameters { log_blowup, proof_of_work_bits, .. } = profile.parameters();
+        FriConfig { log_blowup, num_queries, proof_of_work_bits, mmcs: challenge_mmcs }
     }
 
     #[must_use]
@@ -226,10 +286,7 @@ pub mod baby_bear_poseidon2 {
         let hash = MyHash::new(perm.clone());
         let compress = MyCompress::new(perm.clone());
         let challenge_mmcs = ChallengeMmcs::new(ValMmcs::new(hash, compress));
-        let num_queries = match std::env::var("FRI_QUERIES") {
-            Ok(value) => value.parse().unwrap(),
-            Err(_) => 50,
-        };
+        let num_queries = super::num_queries_from_env(50);
         FriConfig { log_blowup: 2, num_queries, proof_of_work_bits: 16, mmcs: challenge_mmcs }
     }
 
@@ -239,15 +296,12 @@ pub mod baby_bear_poseidon2 {
         let hash = MyHash::new(perm.clone());
         let compress = MyCompress::new(perm.clone());
         let challenge_mmcs = ChallengeMmcs::new(ValMmcs::new(hash, compress));
-        let num_queries = match std::env::var("FRI_QUERIES") {
-            Ok(value) => value.parse().unwrap(),
-            Err(_) => 33,
-        };
+        let num_queries = super::num_queries_from_env(33);
         FriConfig { log_blowup: 3, num_queries, proof_of_work_bits: 16, mmcs: challenge_mmcs }
     }
 
     enum BabyBearPoseidon2Type {
-        Default,
+        Default(FriSecurityProfile, usize),
         Compressed,
     }
 
@@ -262,14 +316,26 @@ pub mod baby_bear_poseidon2 {
     impl BabyBearPoseidon2 {
         #[must_use]
         pub fn new() -> Self {
+            Self::with_fri_queries(
+                FriSecurityProfile::Standard,
+                super::num_queries_of_standard_profile(),
+            )
+        }
+
+        /// Creates a config with the FRI parameters of the given security profile.
+        pub fn with_fri_profile(profile: FriSecurityProfile) -> Result<Self, FriConfigError> {
+            Ok(Self::with_fri_queries(profile, super::num_queries_of_profile(profile)?))
+        }
+
+        fn with_fri_queries(profile: FriSecurityProfile, num_queries: usize) -> Self {
             let perm = my_perm();
             let hash = MyHash::new(perm.clone());
             let compress = MyCompress::new(perm.clone());
             let val_mmcs = ValMmcs::new(hash, compress);
             let dft = Dft {};
-            let fri_config = default_fri_config();
+            let fri_config = fri_config_with_queries(profile, num_queries);
             let pcs = Pcs::new(27, dft, val_mmcs, fri_config);
-            Self { pcs, perm, config_type: BabyBearPoseidon2Type::Default }
+            Self { pcs, perm, config_type: BabyBearPoseidon2Type::Default(profile, num_queries) }
         }
 
         #[must_use]
@@ -300,7 +366,9 @@ pub mod baby_bear_poseidon2 {
     impl Clone for BabyBearPoseidon2 {
         fn clone(&self) -> Self {
             match self.config_type {
-                BabyBearPoseidon2Type::Default => Self::new(),
+                BabyBearPoseidon2Type::Default(profile, num_queries) => {
+                    Self::with_fri_queries(profile, num_queries)
+                }
                 BabyBearPoseidon2Type::Compressed => Self::compressed(),
             }
         }
@@ -342,6 +410,13 @@ pub mod baby_bear_poseidon2 {
         fn challenger(&self) -> Self::Challenger {
             Challenger::new(self.perm.clone())
         }
+
+        fn fri_profile(&self) -> Option<FriSecurityProfile> {
+            match self.config_type {
+                BabyBearPoseidon2Type::Default(profile, _) => Some(profile),
+                BabyBearPoseidon2Type::Compressed => None,
+            }
+        }
     }
 
     impl ZeroCommitment<BabyBearPoseidon2> for Pcs {
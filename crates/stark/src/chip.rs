@@ -240,6 +240,10 @@ where
     fn local_only(&self) -> bool {
         self.air.local_only()
     }
+
+    fn column_names(&self) -> Vec<String> {
+        self.air.column_names()
+    }
 }
 
 // Implement AIR directly on Chip, evaluating both execution and permutation constraints.
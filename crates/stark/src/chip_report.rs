@@ -0,0 +1,192 @@
+use std::{collections::BTreeMap, fmt};
+
+use p3_air::Air;
+use p3_field::Field;
+use p3_uni_stark::{get_symbolic_constraints, SymbolicAirBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    air::MachineAir,
+    count_permutation_constraints,
+    lookup::{Interaction, InteractionKind},
+    Chip, StarkGenericConfig, StarkMachine, Val, PROOF_MAX_NUM_PVS,
+};
+
+/// The trace widths, constraints and interactions of a chip.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChipReport {
+    /// The name of the chip.
+    pub name: String,
+    /// The width of the preprocessed trace.
+    pub preprocessed_width: usize,
+    /// The width of the main trace.
+    pub main_width: usize,
+    /// The width of the permutation trace, in extension field elements.
+    pub permutation_width: usize,
+    /// The width of the quotient trace, in extension field elements.
+    pub quotient_width: usize,
+    /// The number of main trace constraints of each degree, as computed by
+    /// `SymbolicExpression::degree_multiple`.
+    pub constraints_by_degree: BTreeMap<usize, usize>,
+    /// The number of permutation trace constraints.
+    pub permutation_constraints: usize,
+    /// The number of sends of each interaction kind.
+    pub sends_by_kind: BTreeMap<InteractionKind, usize>,
+    /// The number of receives of each interaction kind.
+    pub receives_by_kind: BTreeMap<InteractionKind, usize>,
+    /// The cost of a row of the chip, see [`Chip::cost`].
+    pub cost: u64,
+}
+
+impl ChipReport {
+    /// The total number of constraints of the chip.
+    #[must_use]
+    pub fn num_constraints(&self) -> usize {
+        self.constraints_by_degree.values().sum::<usize>() + self.permutation_constraints
+    }
+
+    /// The maximum degree of the main trace constraints.
+    #[must_use]
+    pub fn max_constraint_degree(&self) -> usize {
+        self.constraints_by_degree.keys().copied().max().unwrap_or_default()
+    }
+
+    /// The total number of interactions of the chip.
+    #[must_use]
+    pub fn num_interactions(&self) -> usize {
+        self.sends_by_kind.values().sum::<usize>() + self.receives_by_kind.values().sum::<usize>()
+    }
+}
+
+impl<F, A> Chip<F, A>
+where
+    F: Field,
+    A: MachineAir<F> + Air<SymbolicAirBuilder<F>>,
+{
+    /// Returns the report of the trace widths, constraints and interactions of the chip.
+    #[must_use]
+    pub fn report(&self) -> ChipReport {
+        let mut constraints_by_degree = BTreeMap::new();
+        for constraint in
+            get_symbolic_constraints(&self.air, self.preprocessed_width(), PROOF_MAX_NUM_PVS)
+        {
+            *constraints_by_degree.entry(constraint.degree_multiple()).or_default() += 1;
+        }
+        let permutation_constraints = count_permutation_constraints(
+            &self.sends,
+            &self.receives,
+            self.logup_batch_size(),
+            self.air.commit_scope(),
+        );
+
+        ChipReport {
+            name: self.name(),
+            preprocessed_width: self.preprocessed_width(),
+            main_width: self.width(),
+            permutation_width: self.permutation_width(),
+            quotient_width: self.quotient_width(),
+            constraints_by_degree,
+            permutation_constraints,
+            sends_by_kind: count_by_kind(&self.sends),
+            receives_by_kind: count_by_kind(&self.receives),
+            cost: self.cost(),
+        }
+    }
+}
+
+impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>> + Air<SymbolicAirBuilder<Val<SC>>>>
+    StarkMachine<SC, A>
+{
+    /// Returns the reports of the trace widths, constraints and interactions of all the chips.
+    #[must_use]
+    pub fn chip_reports(&self) -> Vec<ChipReport> {
+        self.chips().iter().map(Chip::report).collect()
+    }
+}
+
+fn count_by_kind<F: Field>(interactions: &[Interaction<F>]) -> BTreeMap<InteractionKind, usize> {
+    let mut counts = BTreeMap::new();
+    for interaction in interactions {
+        *counts.entry(interaction.kind).or_default() += 1;
+    }
+    counts
+}
+
+/// Formats chip reports as a table, with a row per chip and a row for the totals.
+pub struct ChipReportTable<'a>(pub &'a [ChipReport]);
+
+impl fmt::Display for ChipReportTable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reports = self.0;
+        let name_width = reports.iter().map(|r| r.name.len()).max().unwrap_or_default().max(5);
+        let max_degree = reports.iter().map(ChipReport::max_constraint_degree).max();
+        let degrees = 0..=max_degree.unwrap_or_default();
+        let kinds = InteractionKind::all_kinds()
+            .into_iter()
+            .filter(|kind| {
+                reports.iter().any(|r| {
+                    r.sends_by_kind.contains_key(kind) || r.receives_by_kind.contains_key(kind)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        write!(
+            f,
+            "{:<name_width$} {:>6} {:>6} {:>6} {:>6}",
+            "chip", "prep", "main", "perm", "quot"
+        )?;
+        for degree in degrees.clone() {
+            write!(f, " {:>6}", format!("deg{degree}"))?;
+        }
+        write!(f, " {:>6}", "perm_c")?;
+        for kind in &kinds {
+            write!(f, " {:>13}", format!("{kind:?}(s/r)"))?;
+        }
+        writeln!(f, " {:>8}", "cost")?;
+
+        let write_row = |f: &mut fmt::Formatter<'_>, report: &ChipReport| {
+            write!(
+                f,
+                "{:<name_width$} {:>6} {:>6} {:>6} {:>6}",
+                report.name,
+                report.preprocessed_width,
+                report.main_width,
+                report.permutation_width,
+                report.quotient_width
+            )?;
+            for degree in degrees.clone() {
+                let count = report.constraints_by_degree.get(&degree).copied().unwrap_or_default();
+                write!(f, " {count:>6}")?;
+            }
+            write!(f, " {:>6}", report.permutation_constraints)?;
+            for kind in &kinds {
+                let sends = report.sends_by_kind.get(kind).copied().unwrap_or_default();
+                let receives = report.receives_by_kind.get(kind).copied().unwrap_or_default();
+                write!(f, " {:>13}", format!("{sends}/{receives}"))?;
+            }
+            writeln!(f, " {:>8}", report.cost)
+        };
+
+        let mut total = ChipReport { name: "total".to_string(), ..Default::default() };
+        for report in reports {
+            write_row(f, report)?;
+
+            total.preprocessed_width += report.preprocessed_width;
+            total.main_width += report.main_width;
+            total.permutation_width += report.permutation_width;
+            total.quotient_width += report.quotient_width;
+            for (&degree, &count) in &report.constraints_by_degree {
+                *total.constraints_by_degree.entry(degree).or_default() += count;
+            }
+            total.permutation_constraints += report.permutation_constraints;
+            for (&kind, &count) in &report.sends_by_kind {
+                *total.sends_by_kind.entry(kind).or_default() += count;
+            }
+            for (&kind, &count) in &report.receives_by_kind {
+                *total.receives_by_kind.entry(kind).or_default() += count;
+            }
+            total.cost += report.cost;
+        }
+        write_row(f, &total)
+    }
+}
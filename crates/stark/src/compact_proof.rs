@@ -0,0 +1,300 @@
+use std::fmt::{self, Display};
+
+use hashbrown::HashMap;
+use p3_air::Air;
+use p3_baby_bear::BabyBear;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    air::MachineAir, baby_bear_poseidon2::BabyBearPoseidon2, Groth16Bn254Proof, InnerSC,
+    MONEROCHANProof, MONEROCHANReduceProof, MachineProof, MachineVerificationError, OpeningProof,
+    PlonkBn254Proof, ShardProof, StarkGenericConfig, StarkMachine, StarkVerifyingKey,
+    VerifierConstraintFolder, DIGEST_SIZE,
+};
+
+type Digest = [BabyBear; DIGEST_SIZE];
+
+/// A shard proof whose Merkle opening proofs are deduplicated across the FRI queries.
+///
+/// The FRI queries open the same Merkle trees at different leaves, so the paths of different
+/// queries share the nodes close to the root. The compact encoding stores every distinct digest of
+/// the opening proofs once, and each opening proof as the indices of its digests.
+///
+/// The encoding is lossless: [`CompactShardProof::into_shard_proof`] returns the original proof,
+/// which is then verified as usual.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CompactShardProof {
+    /// The shard proof, with empty Merkle opening proofs.
+    pub proof: ShardProof<BabyBearPoseidon2>,
+    /// The distinct digests of the Merkle opening proofs.
+    pub digests: Vec<Digest>,
+    /// The Merkle opening proofs, each as its length followed by the indices of its digests in
+    /// `digests`.
+    ///
+    /// The opening proofs are ordered as in [`OpeningProof`]: the batch openings of every query,
+    /// then the commit phase openings of every query.
+    pub paths: Vec<u32>,
+}
+
+impl CompactShardProof {
+    /// Deduplicates the Merkle opening proofs of a shard proof.
+    #[must_use]
+    pub fn new(proof: &ShardProof<BabyBearPoseidon2>) -> Self {
+        let mut proof = proof.clone();
+        let mut indices = HashMap::new();
+        let mut digests = Vec::new();
+        let mut paths = Vec::new();
+        for path in merkle_paths_mut(&mut proof.opening_proof) {
+            paths.push(path.len() as u32);
+            for digest in path.drain(..) {
+                let index = *indices.entry(digest).or_insert_with(|| {
+                    digests.push(digest);
+                    digests.len() as u32 - 1
+                });
+                paths.push(index);
+            }
+        }
+        Self { proof, digests, paths }
+    }
+
+    /// Restores the Merkle opening proofs of the shard proof.
+    pub fn into_shard_proof(self) -> Result<ShardProof<BabyBearPoseidon2>, CompactProofError> {
+        let Self { mut proof, digests, paths } = self;
+        let mut paths = paths.into_iter();
+        for path in merkle_paths_mut(&mut proof.opening_proof) {
+            let len = paths.next().ok_or(CompactProofError::MissingPaths)?;
+            *path = (0..len)
+                .map(|_| {
+                    let index = paths.next().ok_or(CompactProofError::MissingPaths)?;
+                    digests
+                        .get(index as usize)
+                        .copied()
+                        .ok_or(CompactProofError::InvalidDigestIndex(index))
+                })
+                .collect::<Result<_, _>>()?;
+        }
+        if paths.next().is_some() {
+            return Err(CompactProofError::TrailingPaths);
+        }
+        Ok(proof)
+    }
+}
+
+/// Returns the Merkle opening proofs of a PCS proof, in the order of [`CompactShardProof::paths`].
+fn merkle_paths_mut(
+    opening_proof: &mut OpeningProof<BabyBearPoseidon2>,
+) -> impl Iterator<Item = &mut Vec<Digest>> + '_ {
+    let batch_openings = opening_proof
+        .query_openings
+        .iter_mut()
+        .flatten()
+        .map(|batch_opening| &mut batch_opening.opening_proof);
+    let commit_phase_openings = opening_proof
+        .fri_proof
+        .query_proofs
+        .iter_mut()
+        .flat_map(|query_proof| query_proof.commit_phase_openings.iter_mut())
+        .map(|step| &mut step.opening_proof);
+    batch_openings.chain(commit_phase_openings)
+}
+
+/// A compressed proof whose shard proof is in the compact encoding of [`CompactShardProof`].
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CompactReduceProof {
+    /// The compress verifying key associated with the proof.
+    pub vk: StarkVerifyingKey<InnerSC>,
+    /// The shard proof, in the compact encoding.
+    pub proof: CompactShardProof,
+}
+
+/// A [`MONEROCHANProof`] whose shard proofs are in the compact encoding of [`CompactShardProof`].
+///
+/// Plonk and Groth16 proofs have no Merkle opening proofs, so they are kept as they are.
+#[derive(Serialize, Deserialize, Clone)]
+pub enum CompactMONEROCHANProof {
+    /// A core proof.
+    Core(Vec<CompactShardProof>),
+    /// A compressed proof.
+    Compressed(Box<CompactReduceProof>),
+    /// A Plonk proof.
+    Plonk(PlonkBn254Proof),
+    /// A Groth16 proof.
+    Groth16(Groth16Bn254Proof),
+}
+
+impl From<&MONEROCHANProof> for CompactMONEROCHANProof {
+    fn from(proof: &MONEROCHANProof) -> Self {
+        match proof {
+            MONEROCHANProof::Core(shard_proofs) => {
+                Self::Core(shard_proofs.iter().map(CompactShardProof::new).collect())
+            }
+            MONEROCHANProof::Compressed(proof) => Self::Compressed(Box::new(CompactReduceProof {
+                vk: proof.vk.clone(),
+                proof: CompactShardProof::new(&proof.proof),
+            })),
+            MONEROCHANProof::Plonk(proof) => Self::Plonk(proof.clone()),
+            MONEROCHANProof::Groth16(proof) => Self::Groth16(proof.clone()),
+        }
+    }
+}
+
+impl TryFrom<CompactMONEROCHANProof> for MONEROCHANProof {
+    type Error = CompactProofError;
+
+    fn try_from(proof: CompactMONEROCHANProof) -> Result<Self, Self::Error> {
+        Ok(match proof {
+            CompactMONEROCHANProof::Core(shard_proofs) => MONEROCHANProof::Core(
+                shard_proofs
+                    .into_iter()
+                    .map(CompactShardProof::into_shard_proof)
+                    .collect::<Result<_, _>>()?,
+            ),
+            CompactMONEROCHANProof::Compressed(proof) => {
+                let CompactReduceProof { vk, proof } = *proof;
+                MONEROCHANProof::Compressed(Box::new(MONEROCHANReduceProof {
+                    vk,
+                    proof: proof.into_shard_proof()?,
+                }))
+            }
+            CompactMONEROCHANProof::Plonk(proof) => MONEROCHANProof::Plonk(proof),
+            CompactMONEROCHANProof::Groth16(proof) => MONEROCHANProof::Groth16(proof),
+        })
+    }
+}
+
+impl<A> StarkMachine<BabyBearPoseidon2, A>
+where
+    A: MachineAir<BabyBear> + for<'a> Air<VerifierConstraintFolder<'a, BabyBearPoseidon2>>,
+{
+    /// Verifies a proof whose shard proofs are in the compact encoding of [`CompactShardProof`].
+    pub fn verify_compact(
+        &self,
+        vk: &StarkVerifyingKey<BabyBearPoseidon2>,
+        shard_proofs: &[CompactShardProof],
+        challenger: &mut <BabyBearPoseidon2 as StarkGenericConfig>::Challenger,
+    ) -> Result<(), MachineVerificationError<BabyBearPoseidon2>> {
+        let shard_proofs = shard_proofs
+            .iter()
+            .cloned()
+            .map(CompactShardProof::into_shard_proof)
+            .collect::<Result<_, _>>()
+            .map_err(MachineVerificationError::InvalidCompactProof)?;
+        self.verify(vk, &MachineProof { shard_proofs }, challenger)
+    }
+}
+
+/// An error that occurs when restoring the Merkle opening proofs of a [`CompactShardProof`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompactProofError {
+    /// The paths end before the last Merkle opening proof.
+    MissingPaths,
+    /// The paths continue after the last Merkle opening proof.
+    TrailingPaths,
+    /// A path refers to a digest that does not exist.
+    InvalidDigestIndex(u32),
+}
+
+impl Display for CompactProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompactProofError::MissingPaths => write!(f, "missing Merkle paths"),
+            CompactProofError::TrailingPaths => write!(f, "trailing Merkle paths"),
+            CompactProofError::InvalidDigestIndex(index) => {
+                write!(f, "invalid Merkle digest index {index}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CompactProofError {}
+
+#[cfg(test)]
+mod tests {
+    use hashbrown::HashMap;
+    use p3_field::AbstractField;
+    use p3_fri::{BatchOpening, CommitPhaseProofStep, FriProof, QueryProof, TwoAdicFriPcsProof};
+
+    use super::*;
+    use crate::{ShardCommitment, ShardOpenedValues};
+
+    fn digest(i: u32) -> Digest {
+        [BabyBear::from_canonical_u32(i); DIGEST_SIZE]
+    }
+
+    /// A shard proof with the Merkle paths of the given leaves in trees of height 10, where the
+    /// sibling at height `h` on the path of leaf `i` has digest `(h << 16) | ((i >> h) ^ 1)`.
+    fn shard_proof(leaves: &[u32]) -> ShardProof<BabyBearPoseidon2> {
+        let path = |leaf: u32| (0..10).map(|h| digest((h << 16) | ((leaf >> h) ^ 1))).collect();
+        ShardProof {
+            commitment: ShardCommitment {
+                main_commit: digest(1).into(),
+                permutation_commit: digest(2).into(),
+                quotient_commit: digest(3).into(),
+            },
+            opened_values: ShardOpenedValues { chips: vec![] },
+            opening_proof: TwoAdicFriPcsProof {
+                fri_proof: FriProof {
+                    commit_phase_commits: vec![],
+                    query_proofs: leaves
+                        .iter()
+                        .map(|&leaf| QueryProof {
+                            commit_phase_openings: vec![CommitPhaseProofStep {
+                                sibling_value: AbstractField::zero(),
+                                opening_proof: path(leaf >> 1),
+                            }],
+                        })
+                        .collect(),
+                    final_poly: AbstractField::zero(),
+                    pow_witness: BabyBear::zero(),
+                },
+                query_openings: leaves
+                    .iter()
+                    .map(|&leaf| {
+                        vec![BatchOpening {
+                            opened_values: vec![vec![BabyBear::from_canonical_u32(leaf)]],
+                            opening_proof: path(leaf),
+                        }]
+                    })
+                    .collect(),
+            },
+            chip_ordering: HashMap::new(),
+            public_values: vec![BabyBear::one()],
+        }
+    }
+
+    #[test]
+    fn test_compact_shard_proof_round_trip() {
+        let leaves = (0..64).map(|i| (i * 37 + 11) % 1024).collect::<Vec<_>>();
+        let proof = shard_proof(&leaves);
+        let compact = CompactShardProof::new(&proof);
+
+        let restored = compact.clone().into_shard_proof().unwrap();
+        assert_eq!(bincode::serialize(&restored).unwrap(), bincode::serialize(&proof).unwrap());
+
+        // The paths of 64 leaves share the nodes of the top levels of the trees.
+        assert!(compact.digests.len() < 2 * 64 * 10);
+        let size = bincode::serialize(&proof).unwrap().len();
+        let compact_size = bincode::serialize(&compact).unwrap().len();
+        assert!(compact_size < size, "{compact_size} >= {size}");
+    }
+
+    #[test]
+    fn test_compact_shard_proof_errors() {
+        let compact = CompactShardProof::new(&shard_proof(&[1, 2, 3]));
+
+        let mut truncated = compact.clone();
+        truncated.paths.pop();
+        assert_eq!(truncated.into_shard_proof().unwrap_err(), CompactProofError::MissingPaths);
+
+        let mut trailing = compact.clone();
+        trailing.paths.push(0);
+        assert_eq!(trailing.into_shard_proof().unwrap_err(), CompactProofError::TrailingPaths);
+
+        let mut invalid = compact;
+        invalid.paths[1] = u32::MAX;
+        assert_eq!(
+            invalid.into_shard_proof().unwrap_err(),
+            CompactProofError::InvalidDigestIndex(u32::MAX)
+        );
+    }
+}
@@ -5,6 +5,8 @@ use p3_commit::{Pcs, PolynomialSpace};
 use p3_field::{ExtensionField, Field, PrimeField32, TwoAdicField};
 use serde::{de::DeserializeOwned, Serialize};
 
+use crate::FriSecurityProfile;
+
 pub type Domain<SC> = <<SC as StarkGenericConfig>::Pcs as Pcs<
     <SC as StarkGenericConfig>::Challenge,
     <SC as StarkGenericConfig>::Challenger,
@@ -73,6 +75,11 @@ pub trait StarkGenericConfig: 'static + Send + Sync + Serialize + DeserializeOwn
 
     /// Initialize a new challenger.
     fn challenger(&self) -> Self::Challenger;
+
+    /// The FRI security profile of the configuration, if its FRI parameters follow one.
+    fn fri_profile(&self) -> Option<FriSecurityProfile> {
+        None
+    }
 }
 
 pub trait ZeroCommitment<SC: StarkGenericConfig> {
@@ -1,7 +1,10 @@
 use std::{
+    backtrace::Backtrace,
     borrow::Borrow,
+    collections::BTreeMap,
+    fmt,
     panic::{self, AssertUnwindSafe},
-    process::exit,
+    path::Path,
 };
 
 use p3_air::{
@@ -22,8 +25,76 @@ use crate::{
     septic_digest::SepticDigest,
 };
 
+/// The maximum number of failing constraints of a chip kept in a [`ConstraintFailureReport`].
+const MAX_REPORTED_FAILURES: usize = 32;
+
+/// The maximum number of failing rows of a chip shown with their neighbouring rows.
+const MAX_REPORTED_ROWS: usize = 4;
+
+/// The source files skipped when locating the `assert_*` call of a failing constraint, which are
+/// the builders forwarding the assertion to the [`DebugConstraintBuilder`].
+const FORWARDING_SOURCES: [&str; 5] =
+    ["/rustc/", "/library/", "p3-air", "stark/src/debug.rs", "stark/src/air/"];
+
+/// A constraint that failed on a row of a chip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintFailure {
+    /// The row on which the constraint failed.
+    pub row: usize,
+    /// The source location of the `assert_*` call of the constraint, as `file:line:column`, found
+    /// in a backtrace. It is `None` if the backtrace has no debug info, or for panics.
+    pub location: Option<String>,
+    /// The values that failed the constraint.
+    pub message: String,
+}
+
+/// The constraints of a chip that failed in [`debug_constraints`], with the rows they failed on.
+#[derive(Debug, Clone)]
+pub struct ConstraintFailureReport<F> {
+    /// The name of the chip.
+    pub chip: String,
+    /// The height of the main trace of the chip.
+    pub height: usize,
+    /// The names of the columns of the main trace, see [`MachineAir::column_names`].
+    pub column_names: Vec<String>,
+    /// The number of failing constraints, including the ones not kept in `failures`.
+    pub num_failures: usize,
+    /// The number of rows with a failing constraint.
+    pub num_failing_rows: usize,
+    /// The first failing constraints, ordered by row.
+    pub failures: Vec<ConstraintFailure>,
+    /// The first failing rows of the main trace and their neighbouring rows, by index.
+    pub rows: BTreeMap<usize, Vec<F>>,
+}
+
+/// A failing constraint, as recorded by the [`DebugConstraintBuilder`].
+pub(crate) struct RecordedFailure {
+    message: String,
+    backtrace: Option<Backtrace>,
+}
+
+impl RecordedFailure {
+    /// The location of the `assert_*` call in the chip, found by skipping the forwarding builders
+    /// in the backtrace.
+    fn location(&self) -> Option<String> {
+        // Show the locations relative to the workspace root.
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).ancestors().nth(2).unwrap();
+        let workspace = format!("{}/", workspace.display());
+        self.backtrace.as_ref().and_then(|backtrace| {
+            backtrace
+                .to_string()
+                .lines()
+                .filter_map(|line| line.trim().strip_prefix("at "))
+                .find(|location| !FORWARDING_SOURCES.iter().any(|source| location.contains(source)))
+                .map(|location| location.trim_start_matches(&workspace).to_string())
+        })
+    }
+}
+
 /// Checks that the constraints of the given AIR are satisfied, including the permutation trace.
 ///
+/// All the failing constraints are collected into a [`ConstraintFailureReport`].
+///
 /// Note that this does not actually verify the proof.
 #[allow(clippy::too_many_arguments)]
 pub fn debug_constraints<SC, A>(
@@ -35,7 +106,8 @@ pub fn debug_constraints<SC, A>(
     public_values: &[Val<SC>],
     local_cumulative_sum: &SC::Challenge,
     global_cumulative_sum: &SepticDigest<Val<SC>>,
-) where
+) -> Result<(), ConstraintFailureReport<Val<SC>>>
+where
     SC: StarkGenericConfig,
     Val<SC>: PrimeField32,
     A: MachineAir<Val<SC>> + for<'a> Air<DebugConstraintBuilder<'a, Val<SC>, SC::Challenge>>,
@@ -43,11 +115,12 @@ pub fn debug_constraints<SC, A>(
     assert_eq!(main.height(), perm.height());
     let height = main.height();
     if height == 0 {
-        return;
+        return Ok(());
     }
 
-    // Check that constraints are satisfied.
-    (0..height).par_bridge().for_each(|i| {
+    // Evaluate the constraints on a row, capturing a backtrace for each failing constraint if
+    // asked to.
+    let eval_row = |i: usize, capture_backtraces: bool| {
         let i_next = (i + 1) % height;
 
         let main_local = main.row_slice(i);
@@ -93,6 +166,8 @@ pub fn debug_constraints<SC, A>(
             is_last_row: Val::<SC>::zero(),
             is_transition: Val::<SC>::one(),
             public_values,
+            failures: Vec::new(),
+            capture_backtraces,
         };
         if i == 0 {
             builder.is_first_row = Val::<SC>::one();
@@ -101,24 +176,134 @@ pub fn debug_constraints<SC, A>(
             builder.is_last_row = Val::<SC>::one();
             builder.is_transition = Val::<SC>::zero();
         }
-        let result = catch_unwind_silent(AssertUnwindSafe(|| {
+        // The panic hook is global, so it is left alone rather than swapped for each row.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
             chip.eval(&mut builder);
         }));
-        if result.is_err() {
-            eprintln!("local: {main_local:?}");
-            eprintln!("next:  {main_next:?}");
-            eprintln!("failed at row {} of chip {}", i, chip.name());
-            exit(1);
+        let mut failures = builder.failures;
+        if let Err(payload) = result {
+            let payload = payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            let message = format!("panicked: {payload}");
+            failures.push(RecordedFailure { message, backtrace: None });
+        }
+        failures
+    };
+
+    // Check that constraints are satisfied, without backtraces since they are slow to capture.
+    let mut failing_rows = (0..height)
+        .par_bridge()
+        .filter_map(|i| {
+            let failures = eval_row(i, false);
+            (!failures.is_empty()).then_some((i, failures))
+        })
+        .collect::<Vec<_>>();
+
+    if failing_rows.is_empty() {
+        return Ok(());
+    }
+    failing_rows.sort_by_key(|(row, _)| *row);
+
+    // Evaluate the rows of the reported failures again, to locate them with backtraces.
+    let mut num_located = 0;
+    for (row, failures) in &mut failing_rows {
+        if num_located >= MAX_REPORTED_FAILURES {
+            break;
         }
-    });
+        *failures = eval_row(*row, true);
+        num_located += failures.len();
+    }
+
+    let num_failures = failing_rows.iter().map(|(_, failures)| failures.len()).sum();
+    let failures = failing_rows
+        .iter()
+        .flat_map(|(row, failures)| {
+            failures.iter().map(|failure| ConstraintFailure {
+                row: *row,
+                location: failure.location(),
+                message: failure.message.clone(),
+            })
+        })
+        .take(MAX_REPORTED_FAILURES)
+        .collect();
+    let rows = failing_rows
+        .iter()
+        .take(MAX_REPORTED_ROWS)
+        .flat_map(|(row, _)| [(row + height - 1) % height, *row, (row + 1) % height])
+        .map(|row| (row, main.row_slice(row).to_vec()))
+        .collect();
+
+    Err(ConstraintFailureReport {
+        chip: chip.name(),
+        height,
+        column_names: chip.column_names(),
+        num_failures,
+        num_failing_rows: failing_rows.len(),
+        failures,
+        rows,
+    })
 }
 
-fn catch_unwind_silent<F: FnOnce() -> R + panic::UnwindSafe, R>(f: F) -> std::thread::Result<R> {
-    let prev_hook = panic::take_hook();
-    panic::set_hook(Box::new(|_| {}));
-    let result = panic::catch_unwind(f);
-    panic::set_hook(prev_hook);
-    result
+impl<F: fmt::Debug> fmt::Display for ConstraintFailureReport<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "chip {}: {} failing constraints in {} of {} rows",
+            self.chip, self.num_failures, self.num_failing_rows, self.height
+        )?;
+
+        let location =
+            |failure: &ConstraintFailure| failure.location.as_deref().unwrap_or("unknown");
+        let location_width =
+            self.failures.iter().map(|f| location(f).len()).max().unwrap_or_default();
+        writeln!(f, "{:>8}  {:<location_width$}  constraint", "row", "location")?;
+        for failure in &self.failures {
+            writeln!(
+                f,
+                "{:>8}  {:<location_width$}  {}",
+                failure.row,
+                location(failure),
+                failure.message
+            )?;
+        }
+        if self.failures.len() < self.num_failures {
+            writeln!(f, "{:>8}  ... {} more", "", self.num_failures - self.failures.len())?;
+        }
+
+        // Print the failing rows and their neighbours, as one column per row.
+        let values = self
+            .rows
+            .iter()
+            .map(|(row, values)| {
+                (format!("row {row}"), values.iter().map(|v| format!("{v:?}")).collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+        let name_width = self.column_names.iter().map(String::len).max().unwrap_or_default();
+        let value_width = values
+            .iter()
+            .flat_map(|(header, values)| values.iter().chain([header]))
+            .map(String::len)
+            .max()
+            .unwrap_or_default();
+
+        writeln!(f)?;
+        write!(f, "{:<name_width$}", "column")?;
+        for (header, _) in &values {
+            write!(f, "  {header:>value_width$}")?;
+        }
+        writeln!(f)?;
+        for (i, name) in self.column_names.iter().enumerate() {
+            write!(f, "{name:<name_width$}")?;
+            for (_, values) in &values {
+                write!(f, "  {:>value_width$}", values.get(i).map_or("", String::as_str))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
 }
 
 /// Checks that all the interactions between the chips has been satisfied.
@@ -141,6 +326,8 @@ pub struct DebugConstraintBuilder<'a, F: Field, EF: ExtensionField<F>> {
     pub(crate) is_last_row: F,
     pub(crate) is_transition: F,
     pub(crate) public_values: &'a [F],
+    pub(crate) failures: Vec<RecordedFailure>,
+    pub(crate) capture_backtraces: bool,
 }
 
 impl<F, EF> ExtensionBuilder for DebugConstraintBuilder<'_, F, EF>
@@ -156,7 +343,10 @@ where
     where
         I: Into<Self::ExprEF>,
     {
-        assert_eq!(x.into(), EF::zero(), "constraints must evaluate to zero");
+        let x = x.into();
+        if x != EF::zero() {
+            self.record_failure(format!("{x:?} != 0"));
+        }
     }
 }
 
@@ -193,15 +383,18 @@ where
     F: Field,
     EF: ExtensionField<F>,
 {
-    #[allow(clippy::unused_self)]
     #[inline]
-    fn debug_constraint(&self, x: F, y: F) {
+    fn debug_constraint(&mut self, x: F, y: F) {
         if x != y {
-            let backtrace = std::backtrace::Backtrace::force_capture();
-            eprintln!("constraint failed: {x:?} != {y:?}\n{backtrace}");
-            panic!();
+            self.record_failure(format!("{x:?} != {y:?}"));
         }
     }
+
+    /// Records a failing constraint, with a backtrace if `capture_backtraces` is set.
+    fn record_failure(&mut self, message: String) {
+        let backtrace = self.capture_backtraces.then(Backtrace::force_capture);
+        self.failures.push(RecordedFailure { message, backtrace });
+    }
 }
 
 impl<'a, F, EF> AirBuilder for DebugConstraintBuilder<'a, F, EF>
@@ -250,9 +443,7 @@ where
     fn assert_bool<I: Into<Self::Expr>>(&mut self, x: I) {
         let x = x.into();
         if x != F::zero() && x != F::one() {
-            let backtrace = std::backtrace::Backtrace::force_capture();
-            eprintln!("constraint failed: {x:?} is not a bool\n{backtrace}");
-            panic!();
+            self.record_failure(format!("{x:?} is not a bool"));
         }
     }
 }
@@ -23,16 +23,21 @@
 pub mod air;
 mod bb31_poseidon2;
 mod chip;
+mod chip_report;
+mod compact_proof;
 mod config;
+mod core_proof;
 mod debug;
 mod folder;
 mod lookup;
 mod machine;
 mod opts;
 mod permutation;
+mod proof_container;
 mod prover;
 mod quotient;
 mod record;
+mod security;
 pub mod septic_curve;
 pub mod septic_digest;
 pub mod septic_extension;
@@ -44,16 +49,21 @@ mod word;
 
 pub use bb31_poseidon2::*;
 pub use chip::*;
+pub use chip_report::*;
+pub use compact_proof::*;
 pub use config::*;
+pub use core_proof::*;
 pub use debug::*;
 pub use folder::*;
 pub use lookup::*;
 pub use machine::*;
 pub use opts::*;
 pub use permutation::*;
+pub use proof_container::*;
 pub use prover::*;
 pub use quotient::*;
 pub use record::*;
+pub use security::*;
 pub use types::*;
 pub use verifier::*;
 pub use word::*;
@@ -1,7 +1,10 @@
 use p3_baby_bear::BabyBear;
 use p3_field::{AbstractField, Field, PrimeField32, PrimeField64};
 use p3_matrix::Matrix;
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
 
 use super::InteractionKind;
 use crate::{
@@ -24,6 +27,124 @@ pub struct InteractionData<F: Field> {
     pub is_send: bool,
     /// The multiplicity of the interaction.
     pub multiplicity: F,
+    /// The values of the message.
+    pub values: Vec<F>,
+}
+
+/// A send or a receive of a message, in a shard.
+#[derive(Debug)]
+pub struct InteractionOccurrence<F: Field> {
+    /// The index of the shard.
+    pub shard: usize,
+    /// The chip, row and multiplicity of the interaction.
+    pub data: InteractionData<F>,
+}
+
+/// A message whose sends and receives do not balance.
+#[derive(Debug)]
+pub struct UnmatchedInteraction<F: Field> {
+    /// The kind of interaction.
+    pub kind: InteractionKind,
+    /// The values of the message.
+    pub values: Vec<F>,
+    /// The multiplicity of the sends minus the multiplicity of the receives.
+    pub balance: F,
+    /// All the sends and receives of the message.
+    pub occurrences: Vec<InteractionOccurrence<F>>,
+}
+
+/// The messages of an interaction scope whose sends and receives do not balance, as found by
+/// [`find_unmatched_interactions`].
+#[derive(Debug)]
+pub struct InteractionImbalanceReport<F: Field> {
+    /// The scope of the interactions.
+    pub scope: InteractionScope,
+    /// The number of distinct messages.
+    pub num_messages: usize,
+    /// The unbalanced messages, ordered by kind and values.
+    pub unmatched: Vec<UnmatchedInteraction<F>>,
+}
+
+impl<F: PrimeField32> InteractionImbalanceReport<F> {
+    /// Returns whether all the messages balance.
+    #[must_use]
+    pub fn is_balanced(&self) -> bool {
+        self.unmatched.is_empty()
+    }
+
+    /// Returns the unbalanced message of the same kind and length with a balance of the opposite
+    /// sign that differs from the `index`-th one in the fewest values, with the indices of these
+    /// values. This is usually the message that was meant to match it.
+    #[must_use]
+    pub fn closest_counterpart(
+        &self,
+        index: usize,
+    ) -> Option<(&UnmatchedInteraction<F>, Vec<usize>)> {
+        let message = &self.unmatched[index];
+        let sent = field_to_int(message.balance) > 0;
+        self.unmatched
+            .iter()
+            .filter(|other| {
+                other.kind == message.kind &&
+                    other.values.len() == message.values.len() &&
+                    (field_to_int(other.balance) > 0) != sent
+            })
+            .map(|other| {
+                let differing = (0..message.values.len())
+                    .filter(|&i| message.values[i] != other.values[i])
+                    .collect::<Vec<_>>();
+                (other, differing)
+            })
+            .min_by_key(|(_, differing)| differing.len())
+    }
+}
+
+impl<F: PrimeField32> Display for InteractionImbalanceReport<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} of the {} {} messages do not balance",
+            self.unmatched.len(),
+            self.num_messages,
+            self.scope
+        )?;
+        for (i, message) in self.unmatched.iter().enumerate() {
+            let balance = field_to_int(message.balance);
+            writeln!(
+                f,
+                "{} {}: {} {}",
+                message.kind,
+                vec_to_string(message.values.clone()),
+                if balance > 0 {
+                    "sent more than received by"
+                } else {
+                    "received more than sent by"
+                },
+                balance.abs()
+            )?;
+            for occurrence in &message.occurrences {
+                let data = &occurrence.data;
+                writeln!(
+                    f,
+                    "    {} by {} in shard {} at row {} (interaction {}), multiplicity {}",
+                    if data.is_send { "sent" } else { "received" },
+                    data.chip_name,
+                    occurrence.shard,
+                    data.row,
+                    data.interaction_number,
+                    field_to_int(data.multiplicity)
+                )?;
+            }
+            if let Some((counterpart, differing)) = self.closest_counterpart(i) {
+                writeln!(
+                    f,
+                    "    closest unbalanced message {} differs at values {differing:?}",
+                    vec_to_string(counterpart.values.clone())
+                )?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Converts a vector of field elements to a string.
@@ -104,7 +225,7 @@ pub fn debug_interactions<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
                     "{} {} {}",
                     &interaction.scope.to_string(),
                     &interaction.kind.to_string(),
-                    vec_to_string(values)
+                    vec_to_string(values.clone())
                 );
                 key_to_vec_data.entry(key.clone()).or_insert_with(Vec::new).push(InteractionData {
                     chip_name: chip.name(),
@@ -113,6 +234,7 @@ pub fn debug_interactions<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
                     interaction_number: m,
                     is_send,
                     multiplicity: multiplicity_eval,
+                    values,
                 });
                 let current = key_to_count.entry(key.clone()).or_insert(Val::<SC>::zero());
                 if is_send {
@@ -127,8 +249,83 @@ pub fn debug_interactions<SC: StarkGenericConfig, A: MachineAir<Val<SC>>>(
     (key_to_vec_data, key_to_count)
 }
 
+/// Records the sends and receives of the given interaction kinds by all the chips in the shards,
+/// and returns the messages whose sends and receives do not balance, with the chips and rows that
+/// sent or received them.
+///
+/// Local interactions balance within each shard, while global interactions balance across all the
+/// shards.
+pub fn find_unmatched_interactions<SC, A>(
+    machine: &StarkMachine<SC, A>,
+    pkey: &StarkProvingKey<SC>,
+    shards: &[A::Record],
+    interaction_kinds: &[InteractionKind],
+    scope: InteractionScope,
+) -> InteractionImbalanceReport<Val<SC>>
+where
+    SC: StarkGenericConfig,
+    SC::Val: PrimeField32,
+    A: MachineAir<SC::Val>,
+{
+    let mut messages = BTreeMap::new();
+    for (shard_index, shard) in shards.iter().enumerate() {
+        for chip in machine.chips().iter().filter(|chip| chip.included(shard)) {
+            let (data, _) =
+                debug_interactions::<SC, A>(chip, pkey, shard, interaction_kinds.to_vec(), scope);
+            record_occurrences(&mut messages, scope, shard_index, data);
+        }
+    }
+    imbalance_report(scope, messages)
+}
+
+/// The key of a message in a shard: local messages of different shards are distinct, since they
+/// must balance within their shard.
+type MessageKey = (Option<usize>, String);
+
+fn message_key(scope: InteractionScope, shard: usize, key: String) -> MessageKey {
+    ((scope == InteractionScope::Local).then_some(shard), key)
+}
+
+fn record_occurrences<F: Field>(
+    messages: &mut BTreeMap<MessageKey, Vec<InteractionOccurrence<F>>>,
+    scope: InteractionScope,
+    shard: usize,
+    data: BTreeMap<String, Vec<InteractionData<F>>>,
+) {
+    for (key, data) in data {
+        messages
+            .entry(message_key(scope, shard, key))
+            .or_default()
+            .extend(data.into_iter().map(|data| InteractionOccurrence { shard, data }));
+    }
+}
+
+fn imbalance_report<F: PrimeField32>(
+    scope: InteractionScope,
+    messages: BTreeMap<MessageKey, Vec<InteractionOccurrence<F>>>,
+) -> InteractionImbalanceReport<F> {
+    let num_messages = messages.len();
+    let unmatched = messages
+        .into_values()
+        .filter_map(|occurrences| {
+            let balance = occurrences
+                .iter()
+                .map(|o| if o.data.is_send { o.data.multiplicity } else { -o.data.multiplicity })
+                .sum::<F>();
+            (!balance.is_zero()).then(|| UnmatchedInteraction {
+                kind: occurrences[0].data.kind,
+                values: occurrences[0].data.values.clone(),
+                balance,
+                occurrences,
+            })
+        })
+        .collect();
+    InteractionImbalanceReport { scope, num_messages, unmatched }
+}
+
 /// Calculate the number of times we send and receive each event of the given interaction type,
-/// and print out the ones for which the set of sends and receives don't match.
+/// and print out the ones for which the set of sends and receives don't match, with the chips and
+/// rows that sent or received them.
 #[allow(clippy::needless_pass_by_value)]
 pub fn debug_interactions_with_all_chips<SC, A>(
     machine: &StarkMachine<SC, A>,
@@ -142,30 +339,29 @@ where
     SC::Val: PrimeField32,
     A: MachineAir<SC::Val>,
 {
-    if scope == InteractionScope::Local {
-        assert!(shards.len() == 1);
-    }
-
     let mut final_map = BTreeMap::new();
+    let mut messages = BTreeMap::new();
     let mut total = SC::Val::zero();
 
     let chips = machine.chips();
     for chip in chips.iter() {
         let mut total_events = 0;
-        for shard in shards {
+        for (shard_index, shard) in shards.iter().enumerate() {
             if !chip.included(shard) {
                 continue;
             }
             eprintln!("{}", chip.name());
-            let (_, count) =
+            let (data, count) =
                 debug_interactions::<SC, A>(chip, pkey, shard, interaction_kinds.clone(), scope);
+            record_occurrences(&mut messages, scope, shard_index, data);
             total_events += count.len();
-            for (key, value) in count.iter() {
-                let entry =
-                    final_map.entry(key.clone()).or_insert((SC::Val::zero(), BTreeMap::new()));
-                entry.0 += *value;
-                total += *value;
-                *entry.1.entry(chip.name()).or_insert(SC::Val::zero()) += *value;
+            for (key, value) in count {
+                let entry = final_map
+                    .entry(message_key(scope, shard_index, key))
+                    .or_insert((SC::Val::zero(), BTreeMap::new()));
+                entry.0 += value;
+                total += value;
+                *entry.1.entry(chip.name()).or_insert(SC::Val::zero()) += value;
             }
         }
         tracing::info!("{} chip has {} distinct events", chip.name(), total_events);
@@ -175,8 +371,12 @@ where
     tracing::info!("==================");
 
     let mut any_nonzero = false;
-    for (key, (value, chip_values)) in final_map.clone() {
+    for ((shard, key), (value, chip_values)) in final_map.clone() {
         if !Val::<SC>::is_zero(&value) {
+            let key = match shard {
+                Some(shard) => format!("{key} in shard {shard}"),
+                None => key,
+            };
             tracing::info!(
                 "Interaction key: {} Send-Receive Discrepancy: {}",
                 key,
@@ -212,6 +412,7 @@ where
             );
             tracing::info!("check the arguments");
         }
+        tracing::info!("{}", imbalance_report(scope, messages));
     }
 
     !any_nonzero
@@ -2,6 +2,7 @@ use core::fmt::{Debug, Display};
 
 use p3_air::VirtualPairCol;
 use p3_field::Field;
+use serde::{Deserialize, Serialize};
 
 use crate::air::InteractionScope;
 
@@ -19,7 +20,7 @@ pub struct Interaction<F: Field> {
 }
 
 /// The type of interaction for a lookup argument.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum InteractionKind {
     /// Interaction with the memory table, such as read and write.
     Memory = 1,
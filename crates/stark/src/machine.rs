@@ -21,7 +21,8 @@ use crate::{
     count_permutation_constraints,
     lookup::{debug_interactions_with_all_chips, InteractionKind},
     record::MachineRecord,
-    DebugConstraintBuilder, ShardProof, VerifierConstraintFolder,
+    CompactProofError, DebugConstraintBuilder, FriSecurityProfile, ShardProof,
+    VerifierConstraintFolder,
 };
 
 use super::{
@@ -78,6 +79,8 @@ pub struct StarkProvingKey<SC: StarkGenericConfig> {
     pub local_only: Vec<bool>,
     /// The number of total constraints for each chip.
     pub constraints_map: HashMap<String, usize>,
+    /// The FRI security profile of the config the key was generated with.
+    pub fri_profile: Option<FriSecurityProfile>,
 }
 
 impl<SC: StarkGenericConfig> StarkProvingKey<SC> {
@@ -89,6 +92,10 @@ impl<SC: StarkGenericConfig> StarkProvingKey<SC> {
         challenger.observe_slice(&self.initial_global_cumulative_sum.0.y.0);
         // Observe the padding.
         challenger.observe(Val::<SC>::zero());
+        // Observe the FRI security profile, unless it is the standard one.
+        if let Some(tag) = self.fri_profile.and_then(FriSecurityProfile::digest_tag) {
+            challenger.observe(Val::<SC>::from_canonical_u32(tag));
+        }
     }
 }
 
@@ -107,6 +114,14 @@ pub struct StarkVerifyingKey<SC: StarkGenericConfig> {
     pub chip_information: Vec<(String, Dom<SC>, Dimensions)>,
     /// The chip ordering.
     pub chip_ordering: HashMap<String, usize>,
+    /// The FRI security profile of the config the key was generated with.
+    ///
+    /// This field was added in circuit version v5.1.0. It changes the `bincode` layout of the key,
+    /// so keys and compressed proofs serialized by earlier circuit versions no longer deserialize.
+    ///
+    /// Profiles other than the standard one are bound into the digest of the key and into the
+    /// transcript, see [`FriSecurityProfile::digest_tag`].
+    pub fri_profile: Option<FriSecurityProfile>,
 }
 
 impl<SC: StarkGenericConfig> StarkVerifyingKey<SC> {
@@ -118,6 +133,10 @@ impl<SC: StarkGenericConfig> StarkVerifyingKey<SC> {
         challenger.observe_slice(&self.initial_global_cumulative_sum.0.y.0);
         // Observe the padding.
         challenger.observe(Val::<SC>::zero());
+        // Observe the FRI security profile, unless it is the standard one.
+        if let Some(tag) = self.fri_profile.and_then(FriSecurityProfile::digest_tag) {
+            challenger.observe(Val::<SC>::from_canonical_u32(tag));
+        }
     }
 }
 
@@ -274,22 +293,37 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>>> StarkMachine<SC, A> {
             }
 
             if env::var("SKIP_CONSTRAINTS").is_err() {
-                tracing::info_span!("debug constraints").in_scope(|| {
-                    for i in 0..chips.len() {
-                        let preprocessed_trace =
-                            pk.chip_ordering.get(&chips[i].name()).map(|index| &pk.traces[*index]);
-                        debug_constraints::<SC, A>(
-                            chips[i],
-                            preprocessed_trace,
-                            &traces[i].0,
-                            &permutation_traces[i],
-                            &permutation_challenges,
-                            &shard.public_values(),
-                            &chip_cumulative_sums[i].1,
-                            &chip_cumulative_sums[i].0,
-                        );
-                    }
+                let reports = tracing::info_span!("debug constraints").in_scope(|| {
+                    (0..chips.len())
+                        .filter_map(|i| {
+                            let preprocessed_trace = pk
+                                .chip_ordering
+                                .get(&chips[i].name())
+                                .map(|index| &pk.traces[*index]);
+                            debug_constraints::<SC, A>(
+                                chips[i],
+                                preprocessed_trace,
+                                &traces[i].0,
+                                &permutation_traces[i],
+                                &permutation_challenges,
+                                &shard.public_values(),
+                                &chip_cumulative_sums[i].1,
+                                &chip_cumulative_sums[i].0,
+                            )
+                            .err()
+                        })
+                        .collect::<Vec<_>>()
                 });
+
+                // Print all the failing constraints of the shard before failing.
+                if !reports.is_empty() {
+                    for report in &reports {
+                        eprintln!("{report}");
+                    }
+                    let failing_chips =
+                        reports.iter().map(|report| report.chip.as_str()).join(", ");
+                    panic!("constraints failed in chips {failing_chips}");
+                }
             }
         }
 
@@ -442,6 +476,7 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>> + Air<SymbolicAirBuilder<Val
                 chip_ordering: chip_ordering.clone(),
                 local_only,
                 constraints_map,
+                fri_profile: self.config.fri_profile(),
             },
             StarkVerifyingKey {
                 commit,
@@ -449,6 +484,7 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>> + Air<SymbolicAirBuilder<Val
                 initial_global_cumulative_sum,
                 chip_information,
                 chip_ordering,
+                fri_profile: self.config.fri_profile(),
             },
         )
     }
@@ -508,6 +544,14 @@ impl<SC: StarkGenericConfig, A: MachineAir<Val<SC>> + Air<SymbolicAirBuilder<Val
         SC::Challenger: Clone,
         A: for<'a> Air<VerifierConstraintFolder<'a, SC>>,
     {
+        // Check that the proof was made with the FRI parameters the key was generated for.
+        if vk.fri_profile != self.config.fri_profile() {
+            return Err(MachineVerificationError::FriProfileMismatch(
+                vk.fri_profile,
+                self.config.fri_profile(),
+            ));
+        }
+
         // Observe the preprocessed commitment.
         vk.observe_into(challenger);
 
@@ -585,6 +629,10 @@ pub enum MachineVerificationError<SC: StarkGenericConfig> {
     CpuLogDegreeTooLarge(usize),
     /// The verification key is not allowed.
     InvalidVerificationKey,
+    /// The FRI security profile of the verification key does not match the one of the verifier.
+    FriProfileMismatch(Option<FriSecurityProfile>, Option<FriSecurityProfile>),
+    /// The Merkle opening proofs of a compact shard proof could not be restored.
+    InvalidCompactProof(CompactProofError),
 }
 
 impl<SC: StarkGenericConfig> Debug for MachineVerificationError<SC> {
@@ -627,6 +675,16 @@ impl<SC: StarkGenericConfig> Debug for MachineVerificationError<SC> {
             MachineVerificationError::InvalidVerificationKey => {
                 write!(f, "Invalid verification key")
             }
+            MachineVerificationError::FriProfileMismatch(vk_profile, profile) => {
+                write!(
+                    f,
+                    "FRI security profile mismatch: verification key {:?}, verifier {:?}",
+                    vk_profile, profile
+                )
+            }
+            MachineVerificationError::InvalidCompactProof(e) => {
+                write!(f, "Invalid compact proof: {}", e)
+            }
         }
     }
 }
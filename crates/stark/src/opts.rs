@@ -3,13 +3,17 @@ use std::env;
 use serde::{Deserialize, Serialize};
 use sysinfo::System;
 
+use crate::FriSecurityProfile;
+
 const MAX_SHARD_SIZE: usize = 1 << 21;
 const RECURSION_MAX_SHARD_SIZE: usize = 1 << 22;
 const MAX_SHARD_BATCH_SIZE: usize = 8;
 const DEFAULT_TRACE_GEN_WORKERS: usize = 1;
 const DEFAULT_CHECKPOINTS_CHANNEL_CAPACITY: usize = 128;
 const DEFAULT_RECORDS_AND_TRACES_CHANNEL_CAPACITY: usize = 1;
-const MAX_DEFERRED_SPLIT_THRESHOLD: usize = 1 << 15;
+
+/// The split threshold of the deferred events with a divisor of one.
+pub const MAX_DEFERRED_SPLIT_THRESHOLD: usize = 1 << 15;
 
 /// Options to configure the MONEROCHAN prover for core and recursive proofs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -18,6 +22,9 @@ pub struct MONEROCHANProverOpts {
     pub core_opts: MONEROCHANCoreOpts,
     /// Options for the recursion prover.
     pub recursion_opts: MONEROCHANCoreOpts,
+    /// The FRI security profile of the core proofs, or `None` for the profile of the prover.
+    #[serde(default)]
+    pub fri_profile: Option<FriSecurityProfile>,
 }
 
 impl MONEROCHANProverOpts {
@@ -55,12 +62,7 @@ impl MONEROCHANProverOpts {
         opts.core_opts.records_and_traces_channel_capacity = 1;
         opts.core_opts.trace_gen_workers = 1;
 
-        let divisor = 1 << log2_divisor;
-        opts.core_opts.split_opts.deferred /= divisor;
-        opts.core_opts.split_opts.keccak /= divisor;
-        opts.core_opts.split_opts.sha_extend /= divisor;
-        opts.core_opts.split_opts.sha_compress /= divisor;
-        opts.core_opts.split_opts.memory /= divisor;
+        opts.core_opts.split_opts = opts.core_opts.split_opts.with_divisor(1 << log2_divisor);
 
         opts.recursion_opts.shard_batch_size = 2;
         opts.recursion_opts.records_and_traces_channel_capacity = 1;
@@ -120,7 +122,11 @@ pub struct MONEROCHANCoreOpts {
 
 impl Default for MONEROCHANProverOpts {
     fn default() -> Self {
-        Self { core_opts: MONEROCHANCoreOpts::default(), recursion_opts: MONEROCHANCoreOpts::recursion() }
+        Self {
+            core_opts: MONEROCHANCoreOpts::default(),
+            recursion_opts: MONEROCHANCoreOpts::recursion(),
+            fri_profile: None,
+        }
     }
 }
 
@@ -155,12 +161,7 @@ impl Default for MONEROCHANCoreOpts {
                 ),
         };
 
-        let divisor = 1 << default_log2_divisor;
-        opts.split_opts.deferred /= divisor;
-        opts.split_opts.keccak /= divisor;
-        opts.split_opts.sha_extend /= divisor;
-        opts.split_opts.sha_compress /= divisor;
-        opts.split_opts.memory /= divisor;
+        opts.split_opts = opts.split_opts.with_divisor(1 << default_log2_divisor);
 
         opts
     }
@@ -225,6 +226,10 @@ pub struct SplitOpts {
     pub sha_extend: usize,
     /// The threshold for sha compress events.
     pub sha_compress: usize,
+    /// The threshold for aes encrypt block events.
+    pub aes: usize,
+    /// The threshold for chacha20 block events.
+    pub chacha20: usize,
     /// The threshold for memory events.
     pub memory: usize,
 }
@@ -242,9 +247,28 @@ impl SplitOpts {
             keccak: 8 * deferred_split_threshold / 24,
             sha_extend: 32 * deferred_split_threshold / 48,
             sha_compress: 32 * deferred_split_threshold / 80,
+            aes: 8 * deferred_split_threshold / 15,
+            chacha20: 8 * deferred_split_threshold / 10,
             memory: 64 * deferred_split_threshold,
         }
     }
+
+    /// Divide the split thresholds of the events by `divisor`.
+    ///
+    /// The threshold for combining the memory init/finalize events is not divided.
+    #[must_use]
+    pub fn with_divisor(self, divisor: usize) -> Self {
+        Self {
+            combine_memory_threshold: self.combine_memory_threshold,
+            deferred: self.deferred / divisor,
+            keccak: self.keccak / divisor,
+            sha_extend: self.sha_extend / divisor,
+            sha_compress: self.sha_compress / divisor,
+            aes: self.aes / divisor,
+            chacha20: self.chacha20 / divisor,
+            memory: self.memory / divisor,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,519 @@
+//! A versioned, self-describing container format for [`MONEROCHANProof`]s.
+//!
+//! The proof types are serialized with `bincode`, which is not self-describing: a proof written by
+//! one circuit version usually fails to deserialize (or deserializes into garbage) with another. A
+//! proof container wraps the serialized proof in a header that is stable across versions, so that a
+//! decoder can always tell what a file is, which circuit version wrote it and whether it can decode
+//! it.
+//!
+//! # Layout
+//!
+//! All integers are little-endian.
+//!
+//! | Field               | Encoding                                                           |
+//! |---------------------|--------------------------------------------------------------------|
+//! | magic               | the 8 bytes [`PROOF_CONTAINER_MAGIC`]                              |
+//! | format version      | `u16`, currently [`PROOF_CONTAINER_VERSION`]                       |
+//! | header length       | `u32`, the length of the header fields below                       |
+//! | circuit version     | `u16` length followed by UTF-8 bytes                               |
+//! | proof mode          | `u8`: 0 for core, 1 for compressed, 2 for Plonk and 3 for Groth16  |
+//! | compression         | `u8`: 0 for none and 1 for deflate                                 |
+//! | vkey hash           | `u8` presence flag, followed by 32 bytes if present                |
+//! | public values       | `u32` length followed by the bytes                                 |
+//! | TEE proof           | `u8` presence flag, then a `u32` length and the bytes if present   |
+//! | payload checksum    | the SHA-256 hash of the uncompressed payload                       |
+//! | payload             | the `bincode` serialized proof, compressed with the compression    |
+//!
+//! Decoders skip the header bytes past the fields they know, so fields may be appended to the
+//! header without bumping the format version. Only the payload depends on the circuit version: a
+//! payload written by another circuit version is only decoded by a migration registered with
+//! [`ProofDecoder::with_migration`].
+
+use std::fmt::{self, Display};
+
+use miniz_oxide::inflate::TINFLStatus;
+use monerochan_primitives::io::sha256_hash;
+
+use crate::{MONEROCHANProof, MONEROCHANProofMode};
+
+/// The magic bytes at the start of a proof container.
+pub const PROOF_CONTAINER_MAGIC: [u8; 8] = *b"MCHPROOF";
+
+/// The latest version of the proof container format.
+pub const PROOF_CONTAINER_VERSION: u16 = 1;
+
+/// The default limit on the size of a decompressed payload, which keeps a small container from
+/// inflating into an arbitrarily large allocation.
+pub const DEFAULT_MAX_PAYLOAD_SIZE: usize = 1 << 30;
+
+/// The compression of the payload of a proof container.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProofCompression {
+    /// The payload is not compressed.
+    #[default]
+    None,
+    /// The payload is compressed with raw deflate.
+    Deflate,
+}
+
+/// The header of a proof container, which describes the proof it contains.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofHeader {
+    /// The version of the container format the proof was written with.
+    pub format_version: u16,
+    /// The circuit version of the prover that generated the proof.
+    pub circuit_version: String,
+    /// The mode of the proof.
+    pub mode: MONEROCHANProofMode,
+    /// The compression of the payload.
+    pub compression: ProofCompression,
+    /// The vkey hash of the program, as returned by `HashableKey::bytes32_raw`.
+    pub vkey_hash: Option<[u8; 32]>,
+    /// The public values committed by the program.
+    pub public_values: Vec<u8>,
+    /// The integrity proof generated by the TEE server.
+    pub tee_proof: Option<Vec<u8>>,
+}
+
+impl ProofHeader {
+    /// Reads the header of a proof container, without decoding the proof.
+    pub fn read(bytes: &[u8]) -> Result<Self, ProofContainerError> {
+        Self::read_with_payload(bytes).map(|(header, _)| header)
+    }
+
+    /// Reads the header of a proof container, and returns it with the payload checksum and the
+    /// (possibly compressed) payload.
+    fn read_with_payload(bytes: &[u8]) -> Result<(Self, &[u8], &[u8]), ProofContainerError> {
+        let mut reader = Reader(bytes);
+        if reader.take(PROOF_CONTAINER_MAGIC.len())? != PROOF_CONTAINER_MAGIC {
+            return Err(ProofContainerError::NotAContainer);
+        }
+        let format_version = reader.u16()?;
+        if format_version == 0 || format_version > PROOF_CONTAINER_VERSION {
+            return Err(ProofContainerError::UnsupportedFormatVersion(format_version));
+        }
+        let header_len = reader.u32()? as usize;
+        let mut header = Reader(reader.take(header_len)?);
+
+        let circuit_version_len = header.u16()? as usize;
+        let circuit_version = String::from_utf8(header.take(circuit_version_len)?.to_vec())
+            .map_err(|_| ProofContainerError::InvalidHeader("circuit version is not UTF-8"))?;
+        let mode = match header.u8()? {
+            0 => MONEROCHANProofMode::Core,
+            1 => MONEROCHANProofMode::Compressed,
+            2 => MONEROCHANProofMode::Plonk,
+            3 => MONEROCHANProofMode::Groth16,
+            _ => return Err(ProofContainerError::InvalidHeader("unknown proof mode")),
+        };
+        let compression = match header.u8()? {
+            0 => ProofCompression::None,
+            1 => ProofCompression::Deflate,
+            _ => return Err(ProofContainerError::InvalidHeader("unknown compression")),
+        };
+        let vkey_hash =
+            if header.flag()? { Some(header.take(32)?.try_into().unwrap()) } else { None };
+        let public_values_len = header.u32()? as usize;
+        let public_values = header.take(public_values_len)?.to_vec();
+        let tee_proof = if header.flag()? {
+            let tee_proof_len = header.u32()? as usize;
+            Some(header.take(tee_proof_len)?.to_vec())
+        } else {
+            None
+        };
+
+        let checksum = reader.take(32)?;
+        let header = Self {
+            format_version,
+            circuit_version,
+            mode,
+            compression,
+            vkey_hash,
+            public_values,
+            tee_proof,
+        };
+        Ok((header, checksum, reader.0))
+    }
+
+    fn write(&self, proof_mode: MONEROCHANProofMode, out: &mut Vec<u8>) {
+        let mut header = Vec::new();
+        header.extend_from_slice(&(self.circuit_version.len() as u16).to_le_bytes());
+        header.extend_from_slice(self.circuit_version.as_bytes());
+        header.push(match proof_mode {
+            MONEROCHANProofMode::Core => 0,
+            MONEROCHANProofMode::Compressed => 1,
+            MONEROCHANProofMode::Plonk => 2,
+            MONEROCHANProofMode::Groth16 => 3,
+        });
+        header.push(match self.compression {
+            ProofCompression::None => 0,
+            ProofCompression::Deflate => 1,
+        });
+        match &self.vkey_hash {
+            Some(vkey_hash) => {
+                header.push(1);
+                header.extend_from_slice(vkey_hash);
+            }
+            None => header.push(0),
+        }
+        header.extend_from_slice(&(self.public_values.len() as u32).to_le_bytes());
+        header.extend_from_slice(&self.public_values);
+        match &self.tee_proof {
+            Some(tee_proof) => {
+                header.push(1);
+                header.extend_from_slice(&(tee_proof.len() as u32).to_le_bytes());
+                header.extend_from_slice(tee_proof);
+            }
+            None => header.push(0),
+        }
+
+        out.extend_from_slice(&PROOF_CONTAINER_MAGIC);
+        out.extend_from_slice(&PROOF_CONTAINER_VERSION.to_le_bytes());
+        out.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        out.extend_from_slice(&header);
+    }
+}
+
+/// A proof together with the header describing it.
+#[derive(Debug, Clone)]
+pub struct ProofContainer {
+    /// The header of the container.
+    pub header: ProofHeader,
+    /// The proof.
+    pub proof: MONEROCHANProof,
+}
+
+impl ProofContainer {
+    /// Creates an uncompressed container for a proof of the given circuit version, without a vkey
+    /// hash or a TEE proof.
+    #[must_use]
+    pub fn new(proof: MONEROCHANProof, public_values: Vec<u8>, circuit_version: &str) -> Self {
+        let header = ProofHeader {
+            format_version: PROOF_CONTAINER_VERSION,
+            circuit_version: circuit_version.to_string(),
+            mode: MONEROCHANProofMode::from(&proof),
+            compression: ProofCompression::None,
+            vkey_hash: None,
+            public_values,
+            tee_proof: None,
+        };
+        Self { header, proof }
+    }
+
+    /// Encodes the container with the latest format version.
+    ///
+    /// The proof mode is taken from the proof, so `header.mode` and `header.format_version` are
+    /// ignored.
+    pub fn encode(&self) -> Result<Vec<u8>, ProofContainerError> {
+        let payload = bincode::serialize(&self.proof).map_err(ProofContainerError::Serialize)?;
+
+        let mut bytes = Vec::new();
+        self.header.write(MONEROCHANProofMode::from(&self.proof), &mut bytes);
+        bytes.extend_from_slice(&sha256_hash(&payload));
+        match self.header.compression {
+            ProofCompression::None => bytes.extend_from_slice(&payload),
+            ProofCompression::Deflate => {
+                bytes.extend_from_slice(&miniz_oxide::deflate::compress_to_vec(&payload, 6));
+            }
+        }
+        Ok(bytes)
+    }
+}
+
+/// Returns whether the bytes start with the magic bytes of a proof container.
+#[must_use]
+pub fn is_proof_container(bytes: &[u8]) -> bool {
+    bytes.starts_with(&PROOF_CONTAINER_MAGIC)
+}
+
+/// A migration, which decodes the uncompressed payload of a proof written by another circuit
+/// version.
+pub type ProofMigration =
+    dyn Fn(&ProofHeader, &[u8]) -> Result<MONEROCHANProof, ProofContainerError> + Send + Sync;
+
+/// Decodes proof containers written by a circuit version, or by the circuit versions for which a
+/// migration is registered.
+pub struct ProofDecoder {
+    circuit_version: String,
+    max_payload_size: usize,
+    migrations: Vec<(String, Box<ProofMigration>)>,
+}
+
+impl ProofDecoder {
+    /// Creates a decoder for proofs of the given circuit version.
+    #[must_use]
+    pub fn new(circuit_version: &str) -> Self {
+        Self {
+            circuit_version: circuit_version.to_string(),
+            max_payload_size: DEFAULT_MAX_PAYLOAD_SIZE,
+            migrations: Vec::new(),
+        }
+    }
+
+    /// Sets the limit on the size of a decompressed payload, which defaults to
+    /// [`DEFAULT_MAX_PAYLOAD_SIZE`].
+    #[must_use]
+    pub fn with_max_payload_size(mut self, max_payload_size: usize) -> Self {
+        self.max_payload_size = max_payload_size;
+        self
+    }
+
+    /// Registers a migration that decodes the payloads of proofs written by `circuit_version`.
+    ///
+    /// The migration is given the header and the uncompressed payload, which has been checked
+    /// against the payload checksum.
+    #[must_use]
+    pub fn with_migration(
+        mut self,
+        circuit_version: &str,
+        migration: impl Fn(&ProofHeader, &[u8]) -> Result<MONEROCHANProof, ProofContainerError>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.migrations.push((circuit_version.to_string(), Box::new(migration)));
+        self
+    }
+
+    /// Decodes a proof container.
+    pub fn decode(&self, bytes: &[u8]) -> Result<ProofContainer, ProofContainerError> {
+        let (header, checksum, payload) = ProofHeader::read_with_payload(bytes)?;
+
+        let migration = if header.circuit_version == self.circuit_version {
+            None
+        } else {
+            let migration = self
+                .migrations
+                .iter()
+                .find(|(circuit_version, _)| *circuit_version == header.circuit_version)
+                .ok_or_else(|| ProofContainerError::CircuitVersionMismatch {
+                    found: header.circuit_version.clone(),
+                    expected: self.circuit_version.clone(),
+                })?;
+            Some(&migration.1)
+        };
+
+        let payload = match header.compression {
+            ProofCompression::None => payload.to_vec(),
+            ProofCompression::Deflate => {
+                miniz_oxide::inflate::decompress_to_vec_with_limit(payload, self.max_payload_size)
+                    .map_err(|e| match e.status {
+                    TINFLStatus::HasMoreOutput => ProofContainerError::Decompression(format!(
+                        "the payload is larger than {} bytes",
+                        self.max_payload_size
+                    )),
+                    status => ProofContainerError::Decompression(format!("{status:?}")),
+                })?
+            }
+        };
+        if sha256_hash(&payload) != checksum {
+            return Err(ProofContainerError::ChecksumMismatch);
+        }
+
+        let proof = match migration {
+            Some(migration) => migration(&header, &payload)?,
+            None => bincode::deserialize(&payload).map_err(ProofContainerError::Deserialize)?,
+        };
+        let mode = MONEROCHANProofMode::from(&proof);
+        if mode != header.mode {
+            return Err(ProofContainerError::ModeMismatch { header: header.mode, payload: mode });
+        }
+        Ok(ProofContainer { header, proof })
+    }
+}
+
+/// An error that occurs when decoding a proof container.
+#[derive(Debug)]
+pub enum ProofContainerError {
+    /// The bytes do not start with the magic bytes of a proof container.
+    NotAContainer,
+    /// The container was written with a newer format version than this decoder supports.
+    UnsupportedFormatVersion(u16),
+    /// The container ends before the end of a field.
+    Truncated,
+    /// A header field has an invalid value.
+    InvalidHeader(&'static str),
+    /// The proof was generated by a circuit version that the decoder has no migration for.
+    CircuitVersionMismatch {
+        /// The circuit version in the header.
+        found: String,
+        /// The circuit version of the decoder.
+        expected: String,
+    },
+    /// The payload could not be decompressed.
+    Decompression(String),
+    /// The payload does not match the payload checksum.
+    ChecksumMismatch,
+    /// The proof could not be serialized into a payload.
+    Serialize(bincode::Error),
+    /// The payload could not be deserialized into a proof.
+    Deserialize(bincode::Error),
+    /// The mode of the decoded proof does not match the mode in the header.
+    ModeMismatch {
+        /// The proof mode in the header.
+        header: MONEROCHANProofMode,
+        /// The mode of the decoded proof.
+        payload: MONEROCHANProofMode,
+    },
+}
+
+impl Display for ProofContainerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofContainerError::NotAContainer => write!(f, "not a proof container"),
+            ProofContainerError::UnsupportedFormatVersion(version) => write!(
+                f,
+                "unsupported proof container format version {version}, the latest supported \
+                 version is {PROOF_CONTAINER_VERSION}"
+            ),
+            ProofContainerError::Truncated => write!(f, "the proof container is truncated"),
+            ProofContainerError::InvalidHeader(reason) => {
+                write!(f, "invalid proof container header: {reason}")
+            }
+            ProofContainerError::CircuitVersionMismatch { found, expected } => write!(
+                f,
+                "the proof was generated by circuit version {found}, but the decoder expects \
+                 {expected} and has no migration for {found}"
+            ),
+            ProofContainerError::Decompression(reason) => {
+                write!(f, "failed to decompress the proof: {reason}")
+            }
+            ProofContainerError::ChecksumMismatch => {
+                write!(f, "the proof does not match its checksum")
+            }
+            ProofContainerError::Serialize(e) => write!(f, "failed to serialize the proof: {e}"),
+            ProofContainerError::Deserialize(e) => {
+                write!(f, "failed to deserialize the proof: {e}")
+            }
+            ProofContainerError::ModeMismatch { header, payload } => write!(
+                f,
+                "the header describes a {header:?} proof, but the payload is a {payload:?} proof"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ProofContainerError {}
+
+/// A cursor over the bytes of a proof container.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ProofContainerError> {
+        if len > self.0.len() {
+            return Err(ProofContainerError::Truncated);
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, ProofContainerError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, ProofContainerError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, ProofContainerError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn flag(&mut self) -> Result<bool, ProofContainerError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProofContainerError::InvalidHeader("invalid presence flag")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PlonkBn254Proof;
+
+    fn plonk_container() -> ProofContainer {
+        let proof = MONEROCHANProof::Plonk(PlonkBn254Proof {
+            public_inputs: ["1".to_string(), "2".to_string()],
+            encoded_proof: "ab".repeat(64),
+            raw_proof: "cd".repeat(64),
+            plonk_vkey_hash: [7; 32],
+        });
+        let mut container = ProofContainer::new(proof, vec![1, 2, 3], "v5.0.0");
+        container.header.vkey_hash = Some([9; 32]);
+        container.header.tee_proof = Some(vec![4, 5]);
+        container
+    }
+
+    #[test]
+    fn test_proof_container_round_trip() {
+        for compression in [ProofCompression::None, ProofCompression::Deflate] {
+            let mut container = plonk_container();
+            container.header.compression = compression;
+            let bytes = container.encode().unwrap();
+            assert!(is_proof_container(&bytes));
+
+            let decoded = ProofDecoder::new("v5.0.0").decode(&bytes).unwrap();
+            assert_eq!(decoded.header, container.header);
+            let MONEROCHANProof::Plonk(proof) = decoded.proof else { panic!("not a Plonk proof") };
+            assert_eq!(proof.encoded_proof, "ab".repeat(64));
+            assert_eq!(ProofHeader::read(&bytes).unwrap(), container.header);
+        }
+    }
+
+    #[test]
+    fn test_proof_container_errors() {
+        let bytes = plonk_container().encode().unwrap();
+        let decoder = ProofDecoder::new("v5.0.0");
+
+        assert!(matches!(
+            ProofDecoder::new("v6.0.0").decode(&bytes),
+            Err(ProofContainerError::CircuitVersionMismatch { .. })
+        ));
+        assert!(matches!(
+            decoder.decode(&bytes[..bytes.len() / 2]),
+            Err(ProofContainerError::ChecksumMismatch | ProofContainerError::Truncated)
+        ));
+        assert!(matches!(decoder.decode(&bytes[..20]), Err(ProofContainerError::Truncated)));
+        assert!(matches!(decoder.decode(b"not a proof"), Err(ProofContainerError::NotAContainer)));
+
+        let mut newer = bytes.clone();
+        newer[8..10].copy_from_slice(&(PROOF_CONTAINER_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            decoder.decode(&newer),
+            Err(ProofContainerError::UnsupportedFormatVersion(_))
+        ));
+
+        let mut corrupted = bytes;
+        *corrupted.last_mut().unwrap() ^= 1;
+        assert!(matches!(decoder.decode(&corrupted), Err(ProofContainerError::ChecksumMismatch)));
+    }
+
+    #[test]
+    fn test_proof_container_payload_limit() {
+        let mut container = plonk_container();
+        container.header.compression = ProofCompression::Deflate;
+        let bytes = container.encode().unwrap();
+        let payload_size = bincode::serialized_size(&container.proof).unwrap() as usize;
+
+        let decoder = ProofDecoder::new("v5.0.0").with_max_payload_size(payload_size / 2);
+        assert!(matches!(decoder.decode(&bytes), Err(ProofContainerError::Decompression(_))));
+        let decoder = ProofDecoder::new("v5.0.0").with_max_payload_size(2 * payload_size);
+        assert!(decoder.decode(&bytes).is_ok());
+    }
+
+    #[test]
+    fn test_proof_container_migration() {
+        let mut container = plonk_container();
+        container.header.circuit_version = "v4.0.0".to_string();
+        let bytes = container.encode().unwrap();
+
+        let decoder = ProofDecoder::new("v5.0.0").with_migration("v4.0.0", |header, payload| {
+            assert_eq!(header.circuit_version, "v4.0.0");
+            bincode::deserialize(payload).map_err(ProofContainerError::Deserialize)
+        });
+        let decoded = decoder.decode(&bytes).unwrap();
+        assert_eq!(decoded.header.circuit_version, "v4.0.0");
+    }
+}
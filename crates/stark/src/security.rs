@@ -0,0 +1,296 @@
+#![allow(clippy::cast_precision_loss)]
+
+use std::fmt::Display;
+
+use p3_baby_bear::BabyBear;
+use p3_field::PrimeField32;
+use serde::{Deserialize, Serialize};
+
+/// The largest `m` considered in the proximity gaps analysis of [`FriParameters::provable_bits`].
+const MAX_JOHNSON_PARAMETER: usize = 64;
+
+/// A security profile for the FRI parameters of a MONEROCHAN proof.
+///
+/// The profile fixes the blowup factor, the number of queries and the proof-of-work bits. Each
+/// profile targets a number of bits of security for the query phase of FRI. The overall soundness
+/// is also bounded by the size of the challenge field and the evaluation domain, which is reported
+/// by [`FriParameters::security_report`].
+///
+/// The allowed recursion verifying keys are generated for [`FriSecurityProfile::Standard`], so
+/// core proofs made with another profile can only be compressed with `VERIFY_VK=false`. With vk
+/// verification enabled, the prover refuses to compress them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum FriSecurityProfile {
+    /// The parameters MONEROCHAN has always used: a blowup factor of 2, 100 queries and 16 bits of
+    /// proof of work, or 116 bits of conjectured security for the query phase.
+    #[default]
+    Standard,
+    /// 100 bits of provable security for the query phase, up to the Johnson bound.
+    ///
+    /// The provable security of the whole proof system is lower: the commit phase error over the
+    /// degree four extension of BabyBear caps it at about 62 bits for traces of height `2^22`,
+    /// whatever the number of queries. See [`FriParameters::provable_bits`].
+    ProvableQuery100,
+    /// 128 bits of conjectured security for the query phase.
+    Conjectured128,
+    /// A single query and no proof of work, for fast tests. This profile is not secure.
+    FastTest,
+}
+
+impl FriSecurityProfile {
+    /// All the security profiles.
+    pub const ALL: [Self; 4] =
+        [Self::Standard, Self::ProvableQuery100, Self::Conjectured128, Self::FastTest];
+
+    /// Returns the FRI parameters of the profile.
+    #[must_use]
+    pub const fn parameters(self) -> FriParameters {
+        match self {
+            Self::Standard => {
+                FriParameters { log_blowup: 1, num_queries: 100, proof_of_work_bits: 16 }
+            }
+            Self::ProvableQuery100 => {
+                FriParameters { log_blowup: 1, num_queries: 168, proof_of_work_bits: 16 }
+            }
+            Self::Conjectured128 => {
+                FriParameters { log_blowup: 1, num_queries: 112, proof_of_work_bits: 16 }
+            }
+            Self::FastTest => {
+                FriParameters { log_blowup: 1, num_queries: 1, proof_of_work_bits: 0 }
+            }
+        }
+    }
+
+    /// Returns the name of the profile, as accepted by [`FriSecurityProfile::from_name`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::ProvableQuery100 => "provable-query-100",
+            Self::Conjectured128 => "conjectured-128",
+            Self::FastTest => "fast-test",
+        }
+    }
+
+    /// Returns the tag that binds the profile into the digest of a verifying key and into the
+    /// transcript, or `None` for the standard profile, whose keys keep the digests they had
+    /// before profiles existed.
+    #[must_use]
+    pub const fn digest_tag(self) -> Option<u32> {
+        match self {
+            Self::Standard => None,
+            Self::ProvableQuery100 => Some(1),
+            Self::Conjectured128 => Some(2),
+            Self::FastTest => Some(3),
+        }
+    }
+
+    /// Returns the profile with the given name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|profile| profile.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Reports the soundness of the profile for traces of height at most `2^log_max_height`.
+    #[must_use]
+    pub fn security_report(self, log_max_height: usize) -> FriSecurityReport {
+        self.parameters().security_report(log_max_height)
+    }
+}
+
+impl Display for FriSecurityProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The parameters of FRI that determine its soundness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FriParameters {
+    /// The log of the blowup factor.
+    pub log_blowup: usize,
+    /// The number of queries.
+    pub num_queries: usize,
+    /// The number of proof-of-work bits of the query phase.
+    pub proof_of_work_bits: usize,
+}
+
+impl FriParameters {
+    /// Reports the soundness of the parameters for traces of height at most `2^log_max_height`.
+    #[must_use]
+    pub fn security_report(&self, log_max_height: usize) -> FriSecurityReport {
+        FriSecurityReport {
+            parameters: *self,
+            log_max_height,
+            conjectured_query_bits: self.conjectured_query_bits(),
+            conjectured_bits: self.conjectured_bits(log_max_height),
+            provable_query_bits: self.provable_query_bits(),
+            provable_bits: self.provable_bits(log_max_height),
+        }
+    }
+
+    /// The conjectured security of the query phase, `num_queries * log_blowup +
+    /// proof_of_work_bits`.
+    #[must_use]
+    pub fn conjectured_query_bits(&self) -> f64 {
+        (self.num_queries * self.log_blowup + self.proof_of_work_bits) as f64
+    }
+
+    /// The conjectured security of the proof system, following the ethSTARK conjecture.
+    ///
+    /// This is the conjectured security of the query phase, capped by `log |F| - log |D|`, where
+    /// `F` is the challenge field and `D` the largest evaluation domain.
+    #[must_use]
+    pub fn conjectured_bits(&self, log_max_height: usize) -> f64 {
+        let log_domain_size = (log_max_height + self.log_blowup) as f64;
+        self.conjectured_query_bits().min(challenge_field_bits() - log_domain_size)
+    }
+
+    /// The provable security of the query phase in the limit of the Johnson bound,
+    /// `num_queries * log_blowup / 2 + proof_of_work_bits`.
+    #[must_use]
+    pub fn provable_query_bits(&self) -> f64 {
+        (self.num_queries * self.log_blowup) as f64 / 2.0 + self.proof_of_work_bits as f64
+    }
+
+    /// The provable security of the proof system in the list decoding regime up to the Johnson
+    /// bound, following the proximity gaps analysis of FRI (BCIKS20, ethSTARK section 5.10.1).
+    ///
+    /// For a proximity parameter `m >= 3` and a rate `rho`, FRI is sound up to the distance
+    /// `1 - alpha` with `alpha = sqrt(rho) * (1 + 1 / 2m)`. The query phase then has an error of
+    /// `alpha^num_queries * 2^-proof_of_work_bits`, and the commit phase has an error of
+    /// `(m + 1/2)^7 |D|^2 / (3 rho^(3/2) |F|)`. The security is the best over `m` of the smaller of
+    /// the two.
+    #[must_use]
+    pub fn provable_bits(&self, log_max_height: usize) -> f64 {
+        let rate = 2f64.powi(-(self.log_blowup as i32));
+        let log_domain_size = (log_max_height + self.log_blowup) as f64;
+        (3..=MAX_JOHNSON_PARAMETER)
+            .map(|m| {
+                let m = m as f64;
+                let alpha = rate.sqrt() * (1.0 + 0.5 / m);
+                let query_bits =
+                    -(self.num_queries as f64) * alpha.log2() + self.proof_of_work_bits as f64;
+                let commit_bits = challenge_field_bits() -
+                    ((m + 0.5).powi(7) / (3.0 * rate.powf(1.5))).log2() -
+                    2.0 * log_domain_size;
+                query_bits.min(commit_bits)
+            })
+            .fold(0.0, f64::max)
+    }
+}
+
+/// The soundness of a set of FRI parameters, in bits.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FriSecurityReport {
+    /// The FRI parameters.
+    pub parameters: FriParameters,
+    /// The log of the largest trace height the report covers.
+    pub log_max_height: usize,
+    /// The conjectured security of the query phase.
+    pub conjectured_query_bits: f64,
+    /// The conjectured security of the proof system.
+    pub conjectured_bits: f64,
+    /// The provable security of the query phase, up to the Johnson bound.
+    pub provable_query_bits: f64,
+    /// The provable security of the proof system, up to the Johnson bound.
+    pub provable_bits: f64,
+}
+
+impl Display for FriSecurityReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let FriParameters { log_blowup, num_queries, proof_of_work_bits } = self.parameters;
+        writeln!(
+            f,
+            "log_blowup = {log_blowup}, num_queries = {num_queries}, \
+             proof_of_work_bits = {proof_of_work_bits}, log_max_height = {}",
+            self.log_max_height
+        )?;
+        writeln!(
+            f,
+            "conjectured: {:.1} bits ({:.1} bits for the query phase)",
+            self.conjectured_bits, self.conjectured_query_bits
+        )?;
+        write!(
+            f,
+            "provable (Johnson bound): {:.1} bits ({:.1} bits for the query phase)",
+            self.provable_bits, self.provable_query_bits
+        )
+    }
+}
+
+/// An error in the configuration of FRI for a security profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FriConfigError {
+    /// The `FRI_QUERIES` environment variable is set along with a profile that fixes its number
+    /// of queries.
+    QueriesWithProfile(FriSecurityProfile),
+}
+
+impl Display for FriConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FriConfigError::QueriesWithProfile(profile) => {
+                write!(f, "FRI_QUERIES cannot be set with the {profile} FRI security profile")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FriConfigError {}
+
+/// The number of bits of the challenge field, the degree four extension of BabyBear.
+fn challenge_field_bits() -> f64 {
+    4.0 * f64::from(BabyBear::ORDER_U32).log2()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_names() {
+        for profile in FriSecurityProfile::ALL {
+            assert_eq!(FriSecurityProfile::from_name(profile.name()), Some(profile));
+        }
+        assert_eq!(FriSecurityProfile::from_name("unknown"), None);
+    }
+
+    #[test]
+    fn test_query_bits() {
+        let bits = |profile: FriSecurityProfile| profile.security_report(22);
+        assert_eq!(bits(FriSecurityProfile::Standard).conjectured_query_bits, 116.0);
+        assert_eq!(bits(FriSecurityProfile::Conjectured128).conjectured_query_bits, 128.0);
+        assert_eq!(bits(FriSecurityProfile::ProvableQuery100).provable_query_bits, 100.0);
+    }
+
+    #[test]
+    fn test_security_report() {
+        for profile in FriSecurityProfile::ALL {
+            let report = profile.security_report(22);
+            assert!(report.provable_bits <= report.provable_query_bits);
+            assert!(report.provable_bits <= report.conjectured_bits);
+            assert!(report.conjectured_bits <= report.conjectured_query_bits);
+        }
+
+        // The field size caps the conjectured security of large domains.
+        let report = FriSecurityProfile::Conjectured128.security_report(22);
+        assert!(report.conjectured_bits < 128.0);
+        assert!(report.conjectured_bits > 100.0);
+    }
+
+    #[test]
+    fn test_provable_bits() {
+        // The commit phase error caps the provable security of the largest core traces well below
+        // the 100 bits of the query phase.
+        let report = FriSecurityProfile::ProvableQuery100.security_report(22);
+        assert!(report.provable_bits > 60.0);
+        assert!(report.provable_bits < 64.0);
+
+        // More queries still raise the provable security over the standard profile.
+        let standard = FriSecurityProfile::Standard.security_report(22);
+        assert!(report.provable_bits > standard.provable_bits);
+        assert!(standard.provable_bits > 55.0);
+        assert!(standard.provable_bits < 58.0);
+    }
+}
@@ -0,0 +1,38 @@
+#![no_main]
+
+monerochan_runtime::entrypoint!(main);
+
+use monerochan_runtime::lib::aes::{
+    aes128_encrypt_block, aes128_expand_key, aes256_encrypt_block, aes256_expand_key,
+};
+
+fn decode<const N: usize>(hex: &str) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    bytes
+}
+
+pub fn main() {
+    // The example vectors of FIPS 197, appendix C.
+    let plaintext: [u8; 16] = decode("00112233445566778899aabbccddeeff");
+
+    let round_keys = aes128_expand_key(&decode("000102030405060708090a0b0c0d0e0f"));
+    let mut block = plaintext;
+    aes128_encrypt_block(&round_keys, &mut block);
+    assert_eq!(block, decode::<16>("69c4e0d86a7b0430d8cdb78070b4c55a"));
+
+    let round_keys = aes256_expand_key(&decode(
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+    ));
+    let mut block = plaintext;
+    aes256_encrypt_block(&round_keys, &mut block);
+    assert_eq!(block, decode::<16>("8ea2b7ca516745bfeafc49904b496089"));
+
+    // The key expansion example of FIPS 197, appendix A.1, which ends with the word `b6630ca6`.
+    let round_keys = aes128_expand_key(&decode("2b7e151628aed2a6abf7158809cf4f3c"));
+    assert_eq!(round_keys[43].to_le_bytes(), decode::<4>("b6630ca6"));
+
+    println!("done");
+}
@@ -0,0 +1,134 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use monerochan_runtime::lib::bigint::{
+    add_assign, add_with_carry, mod_exp, mul_mod_2048, mul_mod_4096, sub_assign, sub_with_borrow,
+};
+use num::{BigUint, One, Zero};
+use rand::Rng;
+
+fn to_biguint(words: &[u32]) -> BigUint {
+    BigUint::from_slice(words)
+}
+
+fn to_words<const W: usize>(x: &BigUint) -> [u32; W] {
+    let mut words = x.to_u32_digits();
+    words.resize(W, 0);
+    words.try_into().unwrap()
+}
+
+fn random_words<const W: usize>(rng: &mut impl Rng) -> [u32; W] {
+    core::array::from_fn(|_| rng.gen())
+}
+
+fn to_bytes_be(x: &BigUint, len: usize) -> Vec<u8> {
+    let bytes = x.to_bytes_be();
+    let mut padded = vec![0u8; len - bytes.len()];
+    padded.extend_from_slice(&bytes);
+    padded
+}
+
+fn test_add_sub(rng: &mut impl Rng) {
+    let two_256 = BigUint::one() << 256;
+    for _ in 0..20 {
+        let x: [u32; 8] = random_words(rng);
+        let y: [u32; 8] = random_words(rng);
+        let carry: bool = rng.gen();
+
+        let (sum, carry_out) = add_with_carry(&x, &y, carry);
+        let expected = to_biguint(&x) + to_biguint(&y) + carry as u32;
+        assert_eq!(to_biguint(&sum), &expected % &two_256);
+        assert_eq!(carry_out, expected >= two_256);
+
+        let (diff, borrow_out) = sub_with_borrow(&x, &y, carry);
+        let subtrahend = to_biguint(&y) + carry as u32;
+        let expected = (&two_256 + to_biguint(&x) - &subtrahend) % &two_256;
+        assert_eq!(to_biguint(&diff), expected);
+        assert_eq!(borrow_out, to_biguint(&x) < subtrahend);
+    }
+
+    // The carry and borrow propagate through every word.
+    let (sum, carry) = add_with_carry(&[u32::MAX; 8], &[0; 8], true);
+    assert_eq!((sum, carry), ([0; 8], true));
+    let (diff, borrow) = sub_with_borrow(&[0; 8], &[0; 8], true);
+    assert_eq!((diff, borrow), ([u32::MAX; 8], true));
+
+    // Multi-limb addition and subtraction are inverse to each other.
+    let x: [u32; 64] = random_words(rng);
+    let y: [u32; 64] = random_words(rng);
+    let mut z = x;
+    let carry = add_assign(&mut z, &y);
+    assert_eq!(carry, to_biguint(&x) + to_biguint(&y) >= BigUint::one() << 2048);
+    let borrow = sub_assign(&mut z, &y);
+    assert_eq!(carry, borrow);
+    assert_eq!(z, x);
+}
+
+fn test_mul_mod_2048(rng: &mut impl Rng) {
+    for _ in 0..5 {
+        let modulus: [u32; 64] = random_words(rng);
+        let modulus_big = to_biguint(&modulus);
+        let x = to_biguint(&random_words::<64>(rng)) % &modulus_big;
+        let y = to_biguint(&random_words::<64>(rng)) % &modulus_big;
+
+        let result = mul_mod_2048(&to_words(&x), &to_words(&y), &modulus);
+        assert_eq!(to_biguint(&result), (x * y) % modulus_big);
+    }
+
+    // A zero modulus stands for 2^2048.
+    let x = to_biguint(&random_words::<64>(rng));
+    let y = to_biguint(&random_words::<64>(rng));
+    let result = mul_mod_2048(&to_words(&x), &to_words(&y), &[0; 64]);
+    assert_eq!(to_biguint(&result), (x * y) % (BigUint::one() << 2048));
+}
+
+fn test_mul_mod_4096(rng: &mut impl Rng) {
+    for _ in 0..5 {
+        let modulus: [u32; 128] = random_words(rng);
+        let modulus_big = to_biguint(&modulus);
+        let x = to_biguint(&random_words::<128>(rng)) % &modulus_big;
+        let y = to_biguint(&random_words::<128>(rng)) % &modulus_big;
+
+        let result = mul_mod_4096(&to_words(&x), &to_words(&y), &modulus);
+        assert_eq!(to_biguint(&result), (x * y) % modulus_big);
+    }
+
+    // A zero modulus stands for 2^4096.
+    let x = to_biguint(&random_words::<128>(rng));
+    let y = to_biguint(&random_words::<128>(rng));
+    let result = mul_mod_4096(&to_words(&x), &to_words(&y), &[0; 128]);
+    assert_eq!(to_biguint(&result), (x * y) % (BigUint::one() << 4096));
+}
+
+fn test_mod_exp(rng: &mut impl Rng) {
+    // Small values and the edge cases of the EVM MODEXP precompile.
+    assert_eq!(mod_exp(&[3], &[5], &[7]), vec![5]);
+    assert_eq!(mod_exp(&[0, 3], &[0, 0, 5], &[0, 0, 7]), vec![0, 0, 5]);
+    assert_eq!(mod_exp(&[3], &[5], &[0, 0]), vec![0, 0]);
+    assert_eq!(mod_exp(&[3], &[], &[7]), vec![1]);
+    assert_eq!(mod_exp(&[3], &[0], &[1]), vec![0]);
+    assert_eq!(mod_exp(&[], &[5], &[7]), vec![0]);
+
+    // Random moduli of each supported width, with bases longer than the modulus.
+    for modulus_len in [32, 256, 512] {
+        let modulus: Vec<u8> = (0..modulus_len).map(|_| rng.gen()).collect();
+        let base: Vec<u8> = (0..modulus_len + 40).map(|_| rng.gen()).collect();
+        let exponent: Vec<u8> = (0..8).map(|_| rng.gen()).collect();
+
+        let modulus_big = BigUint::from_bytes_be(&modulus);
+        let expected = if modulus_big.is_zero() {
+            BigUint::zero()
+        } else {
+            BigUint::from_bytes_be(&base).modpow(&BigUint::from_bytes_be(&exponent), &modulus_big)
+        };
+        assert_eq!(mod_exp(&base, &exponent, &modulus), to_bytes_be(&expected, modulus_len));
+    }
+}
+
+pub fn main() {
+    let mut rng = rand::thread_rng();
+    test_add_sub(&mut rng);
+    test_mul_mod_2048(&mut rng);
+    test_mul_mod_4096(&mut rng);
+    test_mod_exp(&mut rng);
+}
@@ -0,0 +1,96 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use num_bigint::BigUint;
+use monerochan_runtime::lib::{syscall_bls12381_g2_add, syscall_bls12381_g2_double};
+use std::str::FromStr;
+
+const MODULUS: &str =
+    "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787";
+
+/// The number of words of an Fp element.
+const NUM_WORDS: usize = 12;
+
+/// The coordinates `[x.c0, x.c1, y.c0, y.c1]` of the generator of the BLS12-381 G2 subgroup.
+const GENERATOR: [&str; 4] = [
+    "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+    "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+    "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+    "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be",
+];
+
+type Fp2 = (BigUint, BigUint);
+
+fn fp2_mul(a: &Fp2, b: &Fp2, modulus: &BigUint) -> Fp2 {
+    let re = ((&a.0 * &b.0) % modulus + modulus - (&a.1 * &b.1) % modulus) % modulus;
+    let im = (&a.0 * &b.1 + &a.1 * &b.0) % modulus;
+    (re, im)
+}
+
+fn fp2_sub(a: &Fp2, b: &Fp2, modulus: &BigUint) -> Fp2 {
+    ((&a.0 + modulus - &b.0) % modulus, (&a.1 + modulus - &b.1) % modulus)
+}
+
+fn fp2_inv(a: &Fp2, modulus: &BigUint) -> Fp2 {
+    let norm = (&a.0 * &a.0 + &a.1 * &a.1) % modulus;
+    let norm_inv = norm.modpow(&(modulus - 2u32), modulus);
+    ((&a.0 * &norm_inv) % modulus, ((modulus - &a.1) * &norm_inv) % modulus)
+}
+
+/// Computes the point with x-coordinate `slope^2 - p.x - q_x` on the line through `p` with slope
+/// `slope`.
+fn from_slope(p: &(Fp2, Fp2), q_x: &Fp2, slope: &Fp2, modulus: &BigUint) -> (Fp2, Fp2) {
+    let x = fp2_sub(&fp2_sub(&fp2_mul(slope, slope, modulus), &p.0, modulus), q_x, modulus);
+    let y = fp2_sub(&fp2_mul(slope, &fp2_sub(&p.0, &x, modulus), modulus), &p.1, modulus);
+    (x, y)
+}
+
+fn reference_add(p: &(Fp2, Fp2), q: &(Fp2, Fp2), modulus: &BigUint) -> (Fp2, Fp2) {
+    let dx = fp2_sub(&q.0, &p.0, modulus);
+    let dy = fp2_sub(&q.1, &p.1, modulus);
+    let slope = fp2_mul(&dy, &fp2_inv(&dx, modulus), modulus);
+    from_slope(p, &q.0, &slope, modulus)
+}
+
+fn reference_double(p: &(Fp2, Fp2), modulus: &BigUint) -> (Fp2, Fp2) {
+    let x_sq = fp2_mul(&p.0, &p.0, modulus);
+    let numerator = ((&x_sq.0 * 3u32) % modulus, (&x_sq.1 * 3u32) % modulus);
+    let denominator = ((&p.1 .0 * 2u32) % modulus, (&p.1 .1 * 2u32) % modulus);
+    let slope = fp2_mul(&numerator, &fp2_inv(&denominator, modulus), modulus);
+    from_slope(p, &p.0, &slope, modulus)
+}
+
+fn to_words(p: &(Fp2, Fp2)) -> [u32; 48] {
+    let mut words = Vec::new();
+    for x in [&p.0 .0, &p.0 .1, &p.1 .0, &p.1 .1] {
+        let mut digits = x.to_u32_digits();
+        digits.resize(NUM_WORDS, 0);
+        words.extend(digits);
+    }
+    words.try_into().unwrap()
+}
+
+pub fn main() {
+    let modulus = BigUint::from_str(MODULUS).unwrap();
+    let [x_c0, x_c1, y_c0, y_c1] = GENERATOR.map(|c| BigUint::parse_bytes(c.as_bytes(), 16).unwrap());
+    let generator = ((x_c0, x_c1), (y_c0, y_c1));
+    let generator_words = to_words(&generator);
+
+    let mut expected = generator.clone();
+    let mut words = generator_words;
+    for _ in 0..10 {
+        expected = reference_double(&expected, &modulus);
+        unsafe {
+            syscall_bls12381_g2_double(&mut words);
+        }
+        assert_eq!(words, to_words(&expected));
+
+        expected = reference_add(&expected, &generator, &modulus);
+        unsafe {
+            syscall_bls12381_g2_add(&mut words, &generator_words);
+        }
+        assert_eq!(words, to_words(&expected));
+    }
+
+    println!("All tests passed!");
+}
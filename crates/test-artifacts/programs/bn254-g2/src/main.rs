@@ -0,0 +1,96 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use num_bigint::BigUint;
+use monerochan_runtime::lib::{syscall_bn254_g2_add, syscall_bn254_g2_double};
+use std::str::FromStr;
+
+const MODULUS: &str =
+    "21888242871839275222246405745257275088696311157297823662689037894645226208583";
+
+/// The number of words of an Fp element.
+const NUM_WORDS: usize = 8;
+
+/// The coordinates `[x.c0, x.c1, y.c0, y.c1]` of the generator of the BN254 G2 subgroup.
+const GENERATOR: [&str; 4] = [
+    "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+    "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+    "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+    "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+];
+
+type Fp2 = (BigUint, BigUint);
+
+fn fp2_mul(a: &Fp2, b: &Fp2, modulus: &BigUint) -> Fp2 {
+    let re = ((&a.0 * &b.0) % modulus + modulus - (&a.1 * &b.1) % modulus) % modulus;
+    let im = (&a.0 * &b.1 + &a.1 * &b.0) % modulus;
+    (re, im)
+}
+
+fn fp2_sub(a: &Fp2, b: &Fp2, modulus: &BigUint) -> Fp2 {
+    ((&a.0 + modulus - &b.0) % modulus, (&a.1 + modulus - &b.1) % modulus)
+}
+
+fn fp2_inv(a: &Fp2, modulus: &BigUint) -> Fp2 {
+    let norm = (&a.0 * &a.0 + &a.1 * &a.1) % modulus;
+    let norm_inv = norm.modpow(&(modulus - 2u32), modulus);
+    ((&a.0 * &norm_inv) % modulus, ((modulus - &a.1) * &norm_inv) % modulus)
+}
+
+/// Computes the point with x-coordinate `slope^2 - p.x - q_x` on the line through `p` with slope
+/// `slope`.
+fn from_slope(p: &(Fp2, Fp2), q_x: &Fp2, slope: &Fp2, modulus: &BigUint) -> (Fp2, Fp2) {
+    let x = fp2_sub(&fp2_sub(&fp2_mul(slope, slope, modulus), &p.0, modulus), q_x, modulus);
+    let y = fp2_sub(&fp2_mul(slope, &fp2_sub(&p.0, &x, modulus), modulus), &p.1, modulus);
+    (x, y)
+}
+
+fn reference_add(p: &(Fp2, Fp2), q: &(Fp2, Fp2), modulus: &BigUint) -> (Fp2, Fp2) {
+    let dx = fp2_sub(&q.0, &p.0, modulus);
+    let dy = fp2_sub(&q.1, &p.1, modulus);
+    let slope = fp2_mul(&dy, &fp2_inv(&dx, modulus), modulus);
+    from_slope(p, &q.0, &slope, modulus)
+}
+
+fn reference_double(p: &(Fp2, Fp2), modulus: &BigUint) -> (Fp2, Fp2) {
+    let x_sq = fp2_mul(&p.0, &p.0, modulus);
+    let numerator = ((&x_sq.0 * 3u32) % modulus, (&x_sq.1 * 3u32) % modulus);
+    let denominator = ((&p.1 .0 * 2u32) % modulus, (&p.1 .1 * 2u32) % modulus);
+    let slope = fp2_mul(&numerator, &fp2_inv(&denominator, modulus), modulus);
+    from_slope(p, &p.0, &slope, modulus)
+}
+
+fn to_words(p: &(Fp2, Fp2)) -> [u32; 32] {
+    let mut words = Vec::new();
+    for x in [&p.0 .0, &p.0 .1, &p.1 .0, &p.1 .1] {
+        let mut digits = x.to_u32_digits();
+        digits.resize(NUM_WORDS, 0);
+        words.extend(digits);
+    }
+    words.try_into().unwrap()
+}
+
+pub fn main() {
+    let modulus = BigUint::from_str(MODULUS).unwrap();
+    let [x_c0, x_c1, y_c0, y_c1] = GENERATOR.map(|c| BigUint::from_str(c).unwrap());
+    let generator = ((x_c0, x_c1), (y_c0, y_c1));
+    let generator_words = to_words(&generator);
+
+    let mut expected = generator.clone();
+    let mut words = generator_words;
+    for _ in 0..10 {
+        expected = reference_double(&expected, &modulus);
+        unsafe {
+            syscall_bn254_g2_double(&mut words);
+        }
+        assert_eq!(words, to_words(&expected));
+
+        expected = reference_add(&expected, &generator, &modulus);
+        unsafe {
+            syscall_bn254_g2_add(&mut words, &generator_words);
+        }
+        assert_eq!(words, to_words(&expected));
+    }
+
+    println!("All tests passed!");
+}
@@ -0,0 +1,109 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use num_bigint::BigUint;
+use rand::Rng;
+use monerochan_runtime::lib::{syscall_bn254_fp12_mulmod, syscall_bn254_fp6_mulmod};
+use std::str::FromStr;
+
+const MODULUS: &str =
+    "21888242871839275222246405745257275088696311157297823662689037894645226208583";
+
+/// The number of words of an Fp element.
+const NUM_WORDS: usize = 8;
+
+/// The constant `c` of the non-residue `xi = c + u` defining the tower.
+const XI: u32 = 9;
+
+type Fp2 = (BigUint, BigUint);
+
+fn fp2_mul(a: &Fp2, b: &Fp2, modulus: &BigUint) -> Fp2 {
+    let re = ((&a.0 * &b.0) % modulus + modulus - (&a.1 * &b.1) % modulus) % modulus;
+    let im = (&a.0 * &b.1 + &a.1 * &b.0) % modulus;
+    (re, im)
+}
+
+fn random_fp(modulus: &BigUint) -> BigUint {
+    let mut rng = rand::thread_rng();
+    let bytes = (0..4 * NUM_WORDS).map(|_| rng.gen::<u8>()).collect::<Vec<_>>();
+    BigUint::from_bytes_le(&bytes) % modulus
+}
+
+fn to_words(coeffs: &[Fp2]) -> Vec<u32> {
+    let mut words = Vec::new();
+    for (re, im) in coeffs {
+        for x in [re, im] {
+            let mut digits = x.to_u32_digits();
+            digits.resize(NUM_WORDS, 0);
+            words.extend(digits);
+        }
+    }
+    words
+}
+
+fn from_words(words: &[u32]) -> Vec<Fp2> {
+    words
+        .chunks(2 * NUM_WORDS)
+        .map(|c| (BigUint::from_slice(&c[..NUM_WORDS]), BigUint::from_slice(&c[NUM_WORDS..])))
+        .collect()
+}
+
+/// Multiplies two elements given by their `Fp2` coefficients, where the coefficient at `index` is
+/// attached to the power `power(index)` of a generator `g` with `g^x.len() = xi`.
+fn reference_mul(x: &[Fp2], y: &[Fp2], power: fn(usize) -> usize, modulus: &BigUint) -> Vec<Fp2> {
+    let degree = x.len();
+    let xi = (BigUint::from(XI), BigUint::from(1u32));
+    let mut index_of_power = vec![0; degree];
+    for index in 0..degree {
+        index_of_power[power(index)] = index;
+    }
+
+    let mut result = vec![(BigUint::from(0u32), BigUint::from(0u32)); degree];
+    for i in 0..degree {
+        for j in 0..degree {
+            let mut product = fp2_mul(&x[i], &y[j], modulus);
+            let mut k = power(i) + power(j);
+            if k >= degree {
+                product = fp2_mul(&product, &xi, modulus);
+                k -= degree;
+            }
+            let r = &mut result[index_of_power[k]];
+            r.0 = (&r.0 + &product.0) % modulus;
+            r.1 = (&r.1 + &product.1) % modulus;
+        }
+    }
+    result
+}
+
+pub fn main() {
+    let modulus = BigUint::from_str(MODULUS).unwrap();
+    let random_fp2 = || (random_fp(&modulus), random_fp(&modulus));
+
+    for _ in 0..10 {
+        // Fp6 = Fp2[v] / (v^3 - xi).
+        let x = (0..3).map(|_| random_fp2()).collect::<Vec<_>>();
+        let y = (0..3).map(|_| random_fp2()).collect::<Vec<_>>();
+        let expected = reference_mul(&x, &y, |i| i, &modulus);
+
+        let mut x_words = to_words(&x);
+        let y_words = to_words(&y);
+        unsafe {
+            syscall_bn254_fp6_mulmod(x_words.as_mut_ptr(), y_words.as_ptr());
+        }
+        assert_eq!(from_words(&x_words), expected);
+
+        // Fp12 = Fp6[w] / (w^2 - v), where the coefficient of `v^i w^h` is at index `3 h + i`.
+        let x = (0..6).map(|_| random_fp2()).collect::<Vec<_>>();
+        let y = (0..6).map(|_| random_fp2()).collect::<Vec<_>>();
+        let expected = reference_mul(&x, &y, |i| 2 * (i % 3) + i / 3, &modulus);
+
+        let mut x_words = to_words(&x);
+        let y_words = to_words(&y);
+        unsafe {
+            syscall_bn254_fp12_mulmod(x_words.as_mut_ptr(), y_words.as_ptr());
+        }
+        assert_eq!(from_words(&x_words), expected);
+    }
+
+    println!("All tests passed!");
+}
@@ -0,0 +1,124 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use hex_literal::hex;
+use monerochan_runtime::lib::monero::{BulletproofPlus, BulletproofPlusError};
+
+struct ProofVector {
+    v: &'static [[u8; 32]],
+    a: [u8; 32],
+    a1: [u8; 32],
+    b: [u8; 32],
+    r1: [u8; 32],
+    s1: [u8; 32],
+    d1: [u8; 32],
+    l: &'static [[u8; 32]],
+    r: &'static [[u8; 32]],
+}
+
+impl ProofVector {
+    fn proof(&self) -> BulletproofPlus {
+        BulletproofPlus {
+            v: self.v.to_vec(),
+            a: self.a,
+            a1: self.a1,
+            b: self.b,
+            r1: self.r1,
+            s1: self.s1,
+            d1: self.d1,
+            l: self.l.to_vec(),
+            r: self.r.to_vec(),
+        }
+    }
+}
+
+/// A proof for the amount 10^12, generated following Monero's `bulletproof_plus_PROVE`.
+const SINGLE: ProofVector = ProofVector {
+    v: &[hex!("91bedc00e71daccd1cb1e40300f863c3046edd41c1520a397f715d47fa192aea")],
+    a: hex!("cd9eee4609265f2e625d4bcf455ec981547180f0a876af47caa53128cd687eb8"),
+    a1: hex!("ee8e03f0ebbbdf07092901ff96781580630ade3ed4a41b1de7c841103b89f98c"),
+    b: hex!("bf1eadcb81a97d3c9c79dc6f7ccd1a10a2006e4afa4feb058c330e9c9ef67b18"),
+    r1: hex!("4d8330b1389831f518a40215b514571d8dfc12f4778bba089a97a8f074e6a40a"),
+    s1: hex!("1af7017483c1224dd6da9189874352e932acc846dc738121591c397f2064180b"),
+    d1: hex!("aeefa3160deb5a2b3508c6be5c58399a0501dc2296257c2189cd2b243f1ca70d"),
+    l: &[
+        hex!("05cc3aa79bcfd2602bc6633343a9fc5e2dadb71d245913b9c29ba9e8ca5cf072"),
+        hex!("5c3d26f225e62d332e581cdbbc9efa5560d1f2814c9e1021c36f3dca985a6b09"),
+        hex!("352e7e8c9b5d66c5df2a48bfa58daf505d3ac4cda6e2bb2547bb3794cb1f93e7"),
+        hex!("fed63898beda22e42469a8f7327fbf47dc81889e64e39a782d00214e1207da6b"),
+        hex!("c9c1fc38cba6d8f33fbeac153b8caf995c508d2e265a8e09756a93291b441782"),
+        hex!("97f520c5c181452f3ef835c54e9d35627e11a81fd530f0a5ecb9114d59e72ce5"),
+    ],
+    r: &[
+        hex!("f3fadf8e0a4aa5b3e490c7bcbc9633cb64dddb93b3e8b248fee23174d70a856d"),
+        hex!("7362ac2db706a4a11af6e0b041057bc27e94e86739d5f228e021b0f315976978"),
+        hex!("37555ab7f402c24c66cda3a6671f58c07393dbbbe104af60edbb4e449017dcb8"),
+        hex!("7923c00ec18b8ae3f6ac7e31778b3abedcb31a9911c82a8feb8f66dffdaaf122"),
+        hex!("7fa30ba76d96fbd6f73727b357eacca2c110f1b8a1e9a3911ad661674ccd5e27"),
+        hex!("df04d73fe323810c993bbadac4e52ab1ea214829c8c2581e7762344879fbba13"),
+    ],
+};
+
+/// An aggregated proof for the amounts 5 * 10^6 and 2^64 - 1.
+const AGGREGATED: ProofVector = ProofVector {
+    v: &[
+        hex!("4f6efe0ae1919e788708d1c9b9a4abf0f79ec28782fdad3157de9a6c1ac50948"),
+        hex!("2d9edb4a325ac05510b9c71ba05cf7bde29472e251628ecf943d016d2147197c"),
+    ],
+    a: hex!("a5978b0c766f687f6b0d21c4ccdf949ad8543b550caf558900efc798f1af6ffc"),
+    a1: hex!("61f77d50025d90442514164d527d87d64bbb58accd47c802022ffcf7eed93464"),
+    b: hex!("9d720536e861f89e31cea456192e3a657739e3e5df79749da5b6b4443b985e23"),
+    r1: hex!("79860aadcdcebd54f1af07b58bd281994cd27c36b7759fe6ca8f814acac2750d"),
+    s1: hex!("a575a7acd3d598493df82ea2734f36350726b12420444a85e7e08cb910af1206"),
+    d1: hex!("66b25cf67d23cb7bac45d410e90dadb787349f6423bc0094cbfea58a689d3d03"),
+    l: &[
+        hex!("1bdb6ebca0cb1c4e0198ec71ea24403586cc42617ccde11d0ee077716cbbdce0"),
+        hex!("de51ff06b0f2f9adc1ccad3df49332b0e983d290c5dfacb780ac71528d4c9efa"),
+        hex!("16a560cae404739c853281f642e7595f08066bdb158774551e3aeab5d1bd7bb9"),
+        hex!("ad43c5fe95ec40583dc5cf884c06cc35c76ba82b68e8340d48260e02f7ad122d"),
+        hex!("17708a4d5053eff2bb8f1e80b731fbe4f504f75d4526cb52222bdf133784e464"),
+        hex!("e268dc9af4a210a949e8d64b8b37c69c32328e544c11786f411284348b5a3f26"),
+        hex!("8b41dd55d7a15861b2cd75464f473530417892c6d116aa3a10da60b3dd2f5b2e"),
+    ],
+    r: &[
+        hex!("41bb8fe2f050c8a5bcc1900d3eddfb9cf13610e0646c1e0e0ea09df98ce599b2"),
+        hex!("1a16845775c0c5582a951dbaad6cf3e81e1022ff0e5999f56aa55f6d87615a5c"),
+        hex!("580ff308b4b17d8ea96603cd51ca3094bddddc06c24386e595899b80781200af"),
+        hex!("8f51fd1213cebc1fbb4f04f51e54941f915928b4983f340bbab7b528bb79cfa8"),
+        hex!("8735a0aca9680a753e9cd70fcc3384b85c3030c113754e5f0f55ce0a87a65995"),
+        hex!("3cc4f4732eb57d7d496fbb5d4dfbb68c925a7b0a4bada3dde11930d01d73b1f4"),
+        hex!("e08b8cd04c99a0cd0afdf4c16c83e830c3240df709838b38b850218570ec20c6"),
+    ],
+};
+
+pub fn main() {
+    let single = SINGLE.proof();
+    println!("cycle-tracker-start: verify single");
+    assert_eq!(single.verify(), Ok(()));
+    println!("cycle-tracker-end: verify single");
+
+    let aggregated = AGGREGATED.proof();
+    println!("cycle-tracker-start: verify aggregated");
+    assert_eq!(aggregated.verify(), Ok(()));
+    println!("cycle-tracker-end: verify aggregated");
+
+    // Changing a response breaks the verification equation.
+    let mut tampered = single.clone();
+    tampered.r1[0] ^= 1;
+    assert_eq!(tampered.verify(), Err(BulletproofPlusError::VerificationFailed));
+
+    // The number of rounds must match the number of amounts.
+    let mut fewer_amounts = aggregated.clone();
+    fewer_amounts.v.pop();
+    assert_eq!(fewer_amounts.verify(), Err(BulletproofPlusError::InvalidShape));
+    let mut no_amounts = single.clone();
+    no_amounts.v.clear();
+    assert_eq!(no_amounts.verify(), Err(BulletproofPlusError::InvalidShape));
+
+    // Responses must be canonical scalars.
+    let mut non_canonical = single;
+    non_canonical.d1 = [0xff; 32];
+    assert_eq!(non_canonical.verify(), Err(BulletproofPlusError::NonCanonicalScalar));
+
+    println!("done");
+}
@@ -0,0 +1,43 @@
+#![no_main]
+
+monerochan_runtime::entrypoint!(main);
+
+use monerochan_runtime::lib::chacha20::{chacha20_apply_keystream, chacha20_block};
+
+fn decode<const N: usize>(hex: &str) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    bytes
+}
+
+pub fn main() {
+    let key: [u8; 32] = decode("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+
+    // The block function test vector of RFC 8439, section 2.3.2.
+    let block = chacha20_block(&key, 1, &decode("000000090000004a00000000"));
+    assert_eq!(
+        block,
+        decode::<64>(
+            "10f1e7e4d13b5915500fdd1fa32071c4c7d1f4c733c068030422aa9ac3d46c4e\
+             d2826446079faa0914c2d705d98b02a2b5129cd1de164eb9cbd083e8a2503c4e"
+        )
+    );
+
+    // The encryption test vector of RFC 8439, section 2.4.2, which spans two blocks.
+    let mut data = *b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip \
+                      for the future, sunscreen would be it.";
+    chacha20_apply_keystream(&key, 1, &decode("000000000000004a00000000"), &mut data);
+    assert_eq!(
+        data,
+        decode::<114>(
+            "6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0b\
+             f91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d8\
+             07ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab7793736\
+             5af90bbf74a35be6b40b8eedf2785e42874d"
+        )
+    );
+
+    println!("done");
+}
@@ -0,0 +1,82 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use hex_literal::hex;
+use monerochan_runtime::lib::monero::{Clsag, ClsagError, RingMember};
+
+struct ClsagVector {
+    message: [u8; 32],
+    ring: &'static [([u8; 32], [u8; 32])],
+    pseudo_out: [u8; 32],
+    s: &'static [[u8; 32]],
+    c1: [u8; 32],
+    key_image: [u8; 32],
+    d: [u8; 32],
+}
+
+/// A ring of four members signed by the third one, generated following Monero's `CLSAG_Gen`.
+const VECTOR: ClsagVector = ClsagVector {
+    message: hex!("ed59bc2cc474dab3f02f22e6c12cf0ff855723d0931f030f06218d2db3099255"),
+    ring: &[
+        (
+            hex!("75a705b2ca546fe1d651d058d61ee0872144b4a54c185fe27c9445d3b14ee30d"),
+            hex!("e96b24e275c6ddae3cb9bdbea8f5646e0924d696408a1b935d746bc7c639bb60"),
+        ),
+        (
+            hex!("531854bb63542b15163880bf3ec04bab2982a1fe5eb4184f99b1f4158d2c5f62"),
+            hex!("7bdce6d89f7d75cab9267b4b93146781359647e9c2bb616802a2452e939ecc4f"),
+        ),
+        (
+            hex!("50546332f6eb03759055c019a1ed1ad628922cd1bce5cfcdb1d24b14e46d1ce8"),
+            hex!("081974b6c2c3f5547f48d449de1a94c76ed440eef60359ecf9fb136e186076cc"),
+        ),
+        (
+            hex!("33387c38b4f601658c5ac979563c1e6bbd5c918d4e9376c17dffeee6d7fe2df5"),
+            hex!("3ebca940ade57ce31eb6cec540fecd96e3c95ab8dcdd4869e65733545f975b22"),
+        ),
+    ],
+    pseudo_out: hex!("91bda70a45fe3f3f07400f3fa46ce8c9bc50afa1ac72893d9ea6e661f7c93b4c"),
+    s: &[
+        hex!("cb58a3a4a13819bde5c47dc573d73d5aa6069286fca316f60dbf8b250e69f207"),
+        hex!("b4c1bc4a5f87726c32309aaeb1492daa8c25c676cce9f9b671cdc2d13243f60e"),
+        hex!("ca6e5211d404bdfaa09c3a6ecd51b2da56fec1720b1830baaefbf0e2bb6a8705"),
+        hex!("5b571f15f8ca1daa23619fb7b2c0886088ae14fde7a4bd94940c6df757b14a0e"),
+    ],
+    c1: hex!("0c9321283fef7030fc08cf91ed3fda5fcfcf497f23c2866e9f73fd5c2bcb3f00"),
+    key_image: hex!("1f83e06ed430042f28fc697552c708bd2d8dc14584d811cb0217f4a2e22d178f"),
+    d: hex!("787e5fc8cd8d86045c12c538efe9227f114d8cd76ff26d3d0d6cad762f736ce3"),
+};
+
+pub fn main() {
+    let ring =
+        VECTOR.ring.iter().map(|&(dest, mask)| RingMember { dest, mask }).collect::<Vec<_>>();
+    let sig =
+        Clsag { s: VECTOR.s.to_vec(), c1: VECTOR.c1, key_image: VECTOR.key_image, d: VECTOR.d };
+
+    assert_eq!(sig.verify(&VECTOR.message, &ring, &VECTOR.pseudo_out), Ok(()));
+
+    // A different message or pseudo output breaks the ring.
+    let mut message = VECTOR.message;
+    message[0] ^= 1;
+    assert_eq!(sig.verify(&message, &ring, &VECTOR.pseudo_out), Err(ClsagError::ChallengeMismatch));
+    assert_eq!(
+        sig.verify(&VECTOR.message, &ring, &ring[0].mask),
+        Err(ClsagError::ChallengeMismatch)
+    );
+
+    // The ring size must match the responses.
+    assert_eq!(
+        sig.verify(&VECTOR.message, &ring[1..], &VECTOR.pseudo_out),
+        Err(ClsagError::InvalidRingSize)
+    );
+
+    // Responses must be canonical scalars.
+    let mut non_canonical = sig.clone();
+    non_canonical.s[0] = [0xff; 32];
+    assert_eq!(
+        non_canonical.verify(&VECTOR.message, &ring, &VECTOR.pseudo_out),
+        Err(ClsagError::NonCanonicalScalar)
+    );
+
+    println!("done");
+}
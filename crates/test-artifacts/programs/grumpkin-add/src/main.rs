@@ -0,0 +1,41 @@
+#![no_main]
+
+use monerochan_curves::params::FieldParameters;
+use monerochan_lib::grumpkin::GrumpkinPoint;
+monerochan_runtime::entrypoint!(main);
+
+// generator.
+// 1
+// 17631683881184975370165255887551781615748388533673675138860
+const A: [u8; 64] = [
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    44, 39, 63, 130, 141, 196, 63, 131, 148, 18, 24, 241, 69, 13, 39, 45, 99, 93, 164, 6, 117, 94,
+    19, 207, 2, 0, 0, 0, 0, 0, 0, 0,
+];
+
+// 2 * generator.
+// 3078034153852398078128400807926804309327113743808504829582559963737223069694
+// 12696890884641142049456609402511852099066095483298083855939691685001536962732
+const B: [u8; 64] = [
+    254, 255, 191, 205, 136, 198, 139, 109, 212, 19, 30, 49, 170, 76, 167, 25, 109, 48, 54, 170,
+    205, 73, 235, 221, 133, 250, 170, 39, 8, 27, 206, 6, 172, 128, 63, 164, 231, 231, 123, 70, 191,
+    136, 167, 161, 111, 175, 63, 201, 95, 133, 166, 162, 11, 222, 158, 144, 100, 73, 161, 163, 129,
+    47, 18, 28,
+];
+
+// 3 * generator.
+// 18660890509582237958343981571981920822503400000196279471655180441138020044621
+// 8902249110305491597038405103722863701255802573786510474664632793109847672620
+const C: [u8; 64] = [
+    77, 79, 201, 70, 164, 199, 27, 102, 87, 56, 122, 42, 10, 67, 149, 228, 163, 125, 57, 54, 59,
+    119, 115, 2, 72, 185, 241, 141, 146, 176, 65, 41, 44, 7, 105, 190, 207, 164, 90, 27, 161, 208,
+    167, 73, 115, 68, 59, 45, 170, 109, 56, 231, 94, 244, 240, 190, 8, 35, 137, 140, 147, 126, 174,
+    19,
+];
+
+pub fn main() {
+    common_test_utils::weierstrass_add::test_weierstrass_add::<
+        GrumpkinPoint,
+        { monerochan_lib::grumpkin::N },
+    >(&A, &B, &C, monerochan_curves::weierstrass::grumpkin::GrumpkinBaseField::MODULUS);
+}
@@ -0,0 +1,34 @@
+#![no_main]
+
+use monerochan_runtime::syscalls::syscall_grumpkin_double;
+
+monerochan_runtime::entrypoint!(main);
+
+pub fn main() {
+    for _ in 0..10i64.pow(3) {
+        // generator.
+        // 1
+        // 17631683881184975370165255887551781615748388533673675138860
+        let mut a: [u8; 64] = [
+            1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 44, 39, 63, 130, 141, 196, 63, 131, 148, 18, 24, 241, 69, 13, 39, 45, 99, 93,
+            164, 6, 117, 94, 19, 207, 2, 0, 0, 0, 0, 0, 0, 0,
+        ];
+
+        syscall_grumpkin_double(a.as_mut_ptr() as *mut [u32; 16]);
+
+        // 2 * generator.
+        // 3078034153852398078128400807926804309327113743808504829582559963737223069694
+        // 12696890884641142049456609402511852099066095483298083855939691685001536962732
+        let b: [u8; 64] = [
+            254, 255, 191, 205, 136, 198, 139, 109, 212, 19, 30, 49, 170, 76, 167, 25, 109, 48, 54,
+            170, 205, 73, 235, 221, 133, 250, 170, 39, 8, 27, 206, 6, 172, 128, 63, 164, 231, 231,
+            123, 70, 191, 136, 167, 161, 111, 175, 63, 201, 95, 133, 166, 162, 11, 222, 158, 144,
+            100, 73, 161, 163, 129, 47, 18, 28,
+        ];
+
+        assert_eq!(a, b);
+    }
+
+    println!("done");
+}
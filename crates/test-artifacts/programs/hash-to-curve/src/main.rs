@@ -0,0 +1,105 @@
+#![no_main]
+
+monerochan_runtime::entrypoint!(main);
+
+use monerochan_runtime::lib::{
+    hash_to_curve::{expand_message_xmd, hash_to_g1, hash_to_g2, hash_to_secp256k1},
+    utils::AffinePoint,
+};
+
+/// The big endian hex encoding of little endian words.
+fn to_hex(words: &[u32]) -> String {
+    words.iter().rev().map(|word| format!("{word:08x}")).collect()
+}
+
+pub fn main() {
+    // The expand_message_xmd vectors of RFC 9380, appendix K.1.
+    let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    let vectors: [(&[u8], usize, &str); 3] = [
+        (
+            b"",
+            0x20,
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+        ),
+        (
+            b"abc",
+            0x20,
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+        ),
+        (
+            b"",
+            0x80,
+            "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+        ),
+    ];
+    for (msg, len, expected) in vectors {
+        let uniform = expand_message_xmd(msg, dst, len);
+        let hex = uniform.iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        assert_eq!(hex, expected);
+    }
+
+    // The secp256k1_XMD:SHA-256_SSWU_RO_ vectors of RFC 9380, appendix J.8.1.
+    let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+    let vectors = [
+        (
+            b"",
+            "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+            "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+        ),
+        (
+            b"abc",
+            "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+            "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+        ),
+    ];
+    for (msg, x, y) in vectors {
+        let point = hash_to_secp256k1(msg, dst);
+        let limbs = point.limbs_ref();
+        assert_eq!(to_hex(&limbs[..8]), x);
+        assert_eq!(to_hex(&limbs[8..]), y);
+    }
+
+    // The BLS12381G1_XMD:SHA-256_SSWU_RO_ vectors of RFC 9380, appendix J.9.1.
+    let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    let vectors = [
+        (
+            b"",
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        ),
+        (
+            b"abc",
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        ),
+    ];
+    for (msg, x, y) in vectors {
+        let point = hash_to_g1(msg, dst);
+        let limbs = point.limbs_ref();
+        assert_eq!(to_hex(&limbs[..12]), x);
+        assert_eq!(to_hex(&limbs[12..]), y);
+    }
+
+    // The BLS12381G2_XMD:SHA-256_SSWU_RO_ vectors of RFC 9380, appendix J.10.1, with the
+    // coordinates written as `c0 || c1`.
+    let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+    let vectors = [
+        (
+            b"",
+            "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+            "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd9212424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+        ),
+        (
+            b"abc",
+            "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+            "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba4800aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+        ),
+    ];
+    for (msg, x, y) in vectors {
+        let point = hash_to_g2(msg, dst);
+        assert_eq!(to_hex(&point[..12]) + &to_hex(&point[12..24]), x);
+        assert_eq!(to_hex(&point[24..36]) + &to_hex(&point[36..]), y);
+    }
+
+    println!("done");
+}
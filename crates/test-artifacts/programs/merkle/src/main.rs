@@ -0,0 +1,38 @@
+#![no_main]
+
+monerochan_runtime::entrypoint!(main);
+
+use monerochan_runtime::{
+    io::read,
+    lib::merkle::{
+        Keccak256Hasher, MerkleHasher, MerkleProof, MultiProof, Sha256Hasher, SparseMerkleProof,
+        SparseMerkleVerifier, EMPTY_LEAF,
+    },
+};
+
+/// Checks the proofs written by the SDK test for one hasher.
+fn verify_proofs<H: MerkleHasher>() {
+    let root = read::<[u8; 32]>();
+    let (leaf, proof) = read::<([u8; 32], MerkleProof)>();
+    assert!(proof.verify::<H>(&root, &leaf));
+    assert!(!proof.verify::<H>(&root, &EMPTY_LEAF));
+
+    let (leaves, proof) = read::<(Vec<[u8; 32]>, MultiProof)>();
+    assert!(proof.verify::<H>(&root, &leaves));
+    assert!(!proof.verify::<H>(&root, &leaves[1..]));
+
+    let verifier = SparseMerkleVerifier::<H>::new();
+    let root = read::<[u8; 32]>();
+    let (key, value, proof) = read::<([u8; 32], [u8; 32], SparseMerkleProof)>();
+    assert!(verifier.verify(&root, &key, &value, &proof));
+    assert!(!verifier.verify(&root, &key, &EMPTY_LEAF, &proof));
+
+    let (key, value, proof) = read::<([u8; 32], [u8; 32], SparseMerkleProof)>();
+    assert_eq!(value, EMPTY_LEAF);
+    assert!(verifier.verify(&root, &key, &value, &proof));
+}
+
+pub fn main() {
+    verify_proofs::<Sha256Hasher>();
+    verify_proofs::<Keccak256Hasher>();
+}
@@ -0,0 +1,63 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use hex_literal::hex;
+use monerochan_runtime::lib::{
+    ed25519::Ed25519AffinePoint,
+    monero::{cn_fast_hash, generate_key_image, hash_to_ec},
+};
+
+/// The compressed Ed25519 base point `G`.
+const G: [u8; 32] = hex!("5866666666666666666666666666666666666666666666666666666666666666");
+
+/// Monero's Pedersen commitment generator `H = 8 * decompress(cn_fast_hash(G))`.
+const H: [u8; 32] = hex!("8b655970153799af2aeadc9ff1add0ea6c7251d54154cfa92c173a0dd39c1f94");
+
+/// Vectors of `(secret key, public key, hash_to_ec(public key), key image)`, computed with
+/// Monero's `crypto-ops.c` algorithms.
+const KEY_IMAGE_VECTORS: [([u8; 32], [u8; 32], [u8; 32], [u8; 32]); 3] = [
+    (
+        hex!("7070a4a68b2449025da6db32aaa5728631b964b1cd31a51d06c785010a0ed90e"),
+        hex!("27b8a6df5bef348691d89768c18be5a530ab30187a188d63b5f06e9580de8a9b"),
+        hex!("faea99774dfa3ec6f9a91904a5fbb81b03f3f35ac7fbac34a3178eadfbf3edd0"),
+        hex!("cca946fc1486fb0a623f26cc798f03a2feed923c0d856e007f14e5e33f200738"),
+    ),
+    (
+        hex!("e8ccba9498ca364d66d3fe8fb19a80f9b3f5f1ea4c7b16ec43d099483fd36802"),
+        hex!("d741050c544a28fa534cae5fd2a45d27b0b9f7ea46a116ce105f8c76fc13d3ae"),
+        hex!("d5c27de195b8bea2fb1d7e9babfd553144bbd32ab7a1c183e1fc8e8f2fb528c0"),
+        hex!("049acae2c32409de6ffd49fbd11e3308e7aed9851ea605201b9dd8b129628999"),
+    ),
+    (
+        hex!("de858aad0731cb900c7bd0c1198a88cef31f4b0b323a6fb5182191948f91a308"),
+        hex!("ed615d0156190a11dd2b13c70825d7d1558a79435c42df0e30af4123f7c0159f"),
+        hex!("4a11f5018c48c2d7c8c874310826de437402f35907279d4fcd4a55f9f6a33544"),
+        hex!("14265cd53c44557a0603a7e367d02214a363582db62a84e10e9403a7a439ae80"),
+    ),
+];
+
+pub fn main() {
+    // Keccak-256 with the original padding.
+    assert_eq!(
+        cn_fast_hash(b""),
+        hex!("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+    );
+    assert_eq!(
+        cn_fast_hash(&[0xab; 200]),
+        hex!("8ca353cee0a5c5b999a0916e88da37a16293cf14a73c735d6b5197b50c3d6656")
+    );
+
+    // Point decompression, cofactor multiplication and compression.
+    let mut h = Ed25519AffinePoint::decompress(&cn_fast_hash(&G)).unwrap();
+    h.mul_by_cofactor();
+    assert_eq!(h.compress(), H);
+
+    let g = Ed25519AffinePoint::decompress(&G).unwrap();
+    for (secret_key, public_key, hash_point, key_image) in KEY_IMAGE_VECTORS {
+        assert_eq!(g.mul_scalar(&secret_key).compress(), public_key);
+        assert_eq!(hash_to_ec(&public_key).compress(), hash_point);
+        assert_eq!(generate_key_image(&public_key, &secret_key), key_image);
+    }
+
+    println!("done");
+}
@@ -0,0 +1,40 @@
+#![no_main]
+
+use monerochan_curves::params::FieldParameters;
+use monerochan_lib::pasta::PallasPoint;
+monerochan_runtime::entrypoint!(main);
+
+// generator.
+// 28948022309329048855892746252171976963363056481941560715954676764349967630336
+// 2
+const A: [u8; 64] = [
+    0, 0, 0, 0, 237, 48, 45, 153, 27, 249, 76, 9, 252, 152, 70, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 64, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0,
+];
+
+// 2 * generator.
+// 12664759760331458874453076485325239921471337210849432813230171084403110838275
+// 19449452489080454700052938888178047022259553573804486106032048451047634501628
+const B: [u8; 64] = [
+    3, 0, 0, 176, 103, 197, 3, 19, 252, 172, 17, 68, 238, 226, 254, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 28, 252, 255, 255, 59, 223, 92, 234, 138, 94, 183, 63, 86, 201, 110, 7, 23,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
+];
+
+// 3 * generator.
+// 4027241023027617754036171531542546502751647131375064771810253584944963179107
+// 21762326383673887073830845720227757791980770399450032709429395080608314263493
+const C: [u8; 64] = [
+    99, 210, 50, 235, 59, 138, 240, 183, 92, 252, 245, 90, 222, 71, 246, 255, 76, 223, 78, 71, 167,
+    69, 76, 184, 237, 103, 169, 186, 111, 86, 231, 8, 197, 135, 235, 23, 123, 251, 127, 24, 241,
+    45, 20, 222, 24, 158, 200, 199, 203, 240, 94, 31, 74, 211, 151, 56, 244, 224, 130, 193, 76, 10,
+    29, 48,
+];
+
+pub fn main() {
+    common_test_utils::weierstrass_add::test_weierstrass_add::<
+        PallasPoint,
+        { monerochan_lib::pasta::N },
+    >(&A, &B, &C, monerochan_curves::weierstrass::pasta::PallasBaseField::MODULUS);
+}
@@ -0,0 +1,33 @@
+#![no_main]
+
+use monerochan_runtime::syscalls::syscall_pallas_double;
+
+monerochan_runtime::entrypoint!(main);
+
+pub fn main() {
+    for _ in 0..10i64.pow(3) {
+        // generator.
+        // 28948022309329048855892746252171976963363056481941560715954676764349967630336
+        // 2
+        let mut a: [u8; 64] = [
+            0, 0, 0, 0, 237, 48, 45, 153, 27, 249, 76, 9, 252, 152, 70, 34, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 64, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+
+        syscall_pallas_double(a.as_mut_ptr() as *mut [u32; 16]);
+
+        // 2 * generator.
+        // 12664759760331458874453076485325239921471337210849432813230171084403110838275
+        // 19449452489080454700052938888178047022259553573804486106032048451047634501628
+        let b: [u8; 64] = [
+            3, 0, 0, 176, 103, 197, 3, 19, 252, 172, 17, 68, 238, 226, 254, 14, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 252, 255, 255, 59, 223, 92, 234, 138, 94, 183, 63, 86,
+            201, 110, 7, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
+        ];
+
+        assert_eq!(a, b);
+    }
+
+    println!("done");
+}
@@ -0,0 +1,87 @@
+#![no_main]
+
+monerochan_runtime::entrypoint!(main);
+
+use monerochan_runtime::lib::{
+    ed25519::{Ed25519AffinePoint, Ed25519FieldElement, Ed25519Scalar},
+    ristretto255::{RistrettoPoint, RISTRETTO_BASEPOINT_COMPRESSED},
+};
+
+fn decode(hex: &str) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    bytes
+}
+
+pub fn main() {
+    // The encodings of the small multiples of the generator, RFC 9496 appendix A.1.
+    let multiples = [
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76",
+        "6a493210f7499cd17fecb510ae0cea23a110e8d5b901f8acadd3095c73a3b919",
+        "94741f5d5d52755ece4f23f044ee27d5d1ea1e2bd196b462166b16152a9d0259",
+        "da80862773358b466ffadfe0b3293ab3d9fd53c5ea6c955358f568322daf6a57",
+        "e882b131016b52c1d3337080187cf768423efccbb517bb495ab812c4160ff44e",
+        "f64746d3c92b13050ed8d80236a7f0007c3b3f962f5ba793d19a601ebb1df403",
+        "44f53520926ec81fbd5a387845beb7df85a96a24ece18738bdcfa6a7822a176d",
+        "903293d8f2287ebe10e2374dc1a53e0bc887e592699f02d077d5263cdd55601c",
+        "02622ace8f7303a31cafc63f8fc48fdc16e1c8c8d234b2f0d6685282a9076031",
+        "20706fd788b2720a1ed2a5dad4952b01f413bcf0e7564de8cdc816689e2db95f",
+        "bce83f8ba5dd2fa572864c24ba1810f9522bc6004afe95877ac73241cafdab42",
+        "e4549ee16b9aa03099ca208c67adafcafa4c3f3e4e5303de6026e3ca8ff84460",
+        "aa52e000df2e16f55fb1032fc33bc42742dad6bd5a8fc0be0167436c5948501f",
+        "46376b80f409b29dc2b5f6f0c52591990896e5716f41477cd30085ab7f10301e",
+        "e0c418f7c8d9c4cdd7395b93ea124f3ad99021bb681dfc3302a9d99a2e53e64e",
+    ];
+    let base = RistrettoPoint::basepoint();
+    assert_eq!(base.compress(), RISTRETTO_BASEPOINT_COMPRESSED);
+
+    let mut acc = RistrettoPoint::identity();
+    for (i, hex) in multiples.iter().enumerate() {
+        let encoding = decode(hex);
+        assert_eq!(acc.compress(), encoding);
+
+        let decoded = RistrettoPoint::decompress(&encoding).unwrap();
+        assert!(decoded == acc);
+        assert_eq!(decoded.compress(), encoding);
+        assert!(base.mul_scalar(&Ed25519Scalar::from_u32(i as u32)) == decoded);
+
+        acc = acc + base;
+    }
+
+    // Encodings that must be rejected: a negative field element, the non-canonical encoding of
+    // zero as p, and an element from the RFC 9496 appendix A.2 list that is not a valid encoding.
+    let invalid = [
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+        "26948d35ca62e643e26a83177332e6b6afeb9d08e4268b650f1f5bbd8d81d371",
+    ];
+    for hex in invalid {
+        assert!(RistrettoPoint::decompress(&decode(hex)).is_none());
+    }
+
+    // Adding a point of order 4 does not change the element.
+    let torsion = Ed25519AffinePoint::from_coordinates(
+        Ed25519FieldElement::SQRT_M1,
+        Ed25519FieldElement::ZERO,
+    );
+    let shifted = base + RistrettoPoint(torsion);
+    assert!(shifted == base);
+    assert_eq!(shifted.compress(), RISTRETTO_BASEPOINT_COMPRESSED);
+
+    let two = RistrettoPoint::decompress(&decode(multiples[2])).unwrap();
+    let five = RistrettoPoint::decompress(&decode(multiples[5])).unwrap();
+    let seven = RistrettoPoint::decompress(&decode(multiples[7])).unwrap();
+    assert!(seven - five == two);
+    assert!(-two + seven == five);
+    assert!(
+        RistrettoPoint::multi_scalar_mul(
+            &[Ed25519Scalar::from_u32(5), Ed25519Scalar::from_u32(1)],
+            &[two, five],
+        ) == RistrettoPoint::decompress(&decode(multiples[15])).unwrap()
+    );
+
+    println!("done");
+}
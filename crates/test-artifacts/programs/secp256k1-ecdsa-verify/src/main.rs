@@ -0,0 +1,43 @@
+#![no_main]
+
+use monerochan_runtime::lib::secp256k1::ecdsa_verify;
+
+monerochan_runtime::entrypoint!(main);
+
+// The SHA-256 digest of "hello world".
+const MSG_HASH: [u8; 32] = [
+    185, 77, 39, 185, 147, 77, 62, 8, 165, 46, 82, 215, 218, 125, 171, 250, 196, 132, 239, 227,
+    122, 83, 128, 238, 144, 136, 247, 172, 226, 239, 205, 233,
+];
+
+const SIGNATURE: [u8; 64] = [
+    82, 234, 76, 107, 218, 180, 11, 192, 96, 114, 155, 153, 146, 224, 152, 217, 68, 234, 36, 60,
+    155, 224, 193, 213, 56, 138, 185, 84, 13, 85, 97, 79, 185, 184, 226, 192, 68, 3, 89, 121, 90,
+    54, 191, 235, 0, 45, 171, 106, 234, 2, 214, 2, 191, 160, 10, 206, 185, 203, 147, 9, 182, 239,
+    91, 225,
+];
+
+const PUBLIC_KEY: [u8; 64] = [
+    25, 128, 38, 202, 125, 77, 220, 13, 48, 88, 84, 97, 161, 84, 231, 151, 117, 235, 21, 177, 86,
+    233, 148, 12, 105, 137, 153, 2, 253, 140, 23, 98, 45, 96, 25, 28, 191, 76, 42, 81, 168, 22,
+    105, 231, 102, 51, 12, 198, 116, 102, 142, 177, 17, 208, 218, 84, 204, 251, 215, 223, 219, 79,
+    153, 63,
+];
+
+pub fn main() {
+    for _ in 0..4 {
+        assert!(ecdsa_verify(&PUBLIC_KEY, &MSG_HASH, &SIGNATURE));
+
+        // A different message hash does not verify.
+        let mut msg_hash = MSG_HASH;
+        msg_hash[0] ^= 1;
+        assert!(!ecdsa_verify(&PUBLIC_KEY, &msg_hash, &SIGNATURE));
+
+        // A key that is not on the curve is rejected.
+        let mut public_key = PUBLIC_KEY;
+        public_key[63] ^= 1;
+        assert!(!ecdsa_verify(&public_key, &MSG_HASH, &SIGNATURE));
+    }
+
+    println!("done");
+}
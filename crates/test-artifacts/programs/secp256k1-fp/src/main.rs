@@ -0,0 +1,106 @@
+#![no_main]
+
+monerochan_runtime::entrypoint!(main);
+use std::str::FromStr;
+
+use monerochan_runtime::lib::{
+    syscall_secp256k1_fn_addmod, syscall_secp256k1_fn_mulmod, syscall_secp256k1_fn_submod,
+    syscall_secp256k1_fp_addmod, syscall_secp256k1_fp_mulmod, syscall_secp256k1_fp_submod,
+};
+
+use num_bigint::BigUint;
+use rand::Rng;
+
+const NUM_LIMBS: usize = 8;
+
+type FieldOp = unsafe extern "C" fn(*mut u32, *const u32);
+
+fn apply(op: FieldOp, lhs: &[u32; NUM_LIMBS], rhs: &[u32; NUM_LIMBS]) -> [u32; NUM_LIMBS] {
+    unsafe {
+        let mut lhs_copy = *lhs;
+        op(lhs_copy.as_mut_ptr(), rhs.as_ptr());
+        lhs_copy
+    }
+}
+
+fn random_u32_8() -> [u32; NUM_LIMBS] {
+    let mut rng = rand::thread_rng();
+    let mut arr = [0u32; NUM_LIMBS];
+    for item in arr.iter_mut() {
+        *item = rng.gen();
+    }
+    arr
+}
+
+fn u32_8_to_biguint(arr: &[u32; NUM_LIMBS]) -> BigUint {
+    BigUint::from_slice(arr)
+}
+
+fn biguint_to_u32_8(x: &BigUint) -> [u32; NUM_LIMBS] {
+    let mut digits = x.to_u32_digits();
+    digits.resize(NUM_LIMBS, 0);
+    digits.try_into().unwrap()
+}
+
+/// Checks the add, sub and mul precompiles of a field against `num_bigint`.
+fn test_field(modulus: &BigUint, add: FieldOp, sub: FieldOp, mul: FieldOp) {
+    let zero: [u32; NUM_LIMBS] = [0; NUM_LIMBS];
+    let one: [u32; NUM_LIMBS] = [1, 0, 0, 0, 0, 0, 0, 0];
+    let minus_one = biguint_to_u32_8(&(modulus - 1u32));
+
+    for _ in 0..10 {
+        let a_bigint = u32_8_to_biguint(&random_u32_8()) % modulus;
+        let b_bigint = u32_8_to_biguint(&random_u32_8()) % modulus;
+        let a = biguint_to_u32_8(&a_bigint);
+        let b = biguint_to_u32_8(&b_bigint);
+
+        // Test addition
+        assert_eq!((&a_bigint + &b_bigint) % modulus, u32_8_to_biguint(&apply(add, &a, &b)));
+        assert_eq!(a_bigint, u32_8_to_biguint(&apply(add, &a, &zero)));
+
+        // Test subtraction
+        assert_eq!(
+            (&a_bigint + modulus - &b_bigint) % modulus,
+            u32_8_to_biguint(&apply(sub, &a, &b))
+        );
+        assert_eq!(a_bigint, u32_8_to_biguint(&apply(sub, &a, &zero)));
+
+        // Test multiplication
+        assert_eq!((&a_bigint * &b_bigint) % modulus, u32_8_to_biguint(&apply(mul, &a, &b)));
+        assert_eq!(a_bigint, u32_8_to_biguint(&apply(mul, &a, &one)));
+        assert_eq!(BigUint::ZERO, u32_8_to_biguint(&apply(mul, &a, &zero)));
+    }
+
+    // Test the wrap around at the modulus.
+    assert_eq!(zero, apply(add, &minus_one, &one));
+    assert_eq!(minus_one, apply(sub, &zero, &one));
+    assert_eq!(one, apply(mul, &minus_one, &minus_one));
+}
+
+pub fn main() {
+    // The base field modulus.
+    let p = BigUint::from_str(
+        "115792089237316195423570985008687907853269984665640564039457584007908834671663",
+    )
+    .unwrap();
+    test_field(
+        &p,
+        syscall_secp256k1_fp_addmod,
+        syscall_secp256k1_fp_submod,
+        syscall_secp256k1_fp_mulmod,
+    );
+
+    // The scalar field modulus, i.e. the order of the group.
+    let n = BigUint::from_str(
+        "115792089237316195423570985008687907852837564279074904382605163141518161494337",
+    )
+    .unwrap();
+    test_field(
+        &n,
+        syscall_secp256k1_fn_addmod,
+        syscall_secp256k1_fn_submod,
+        syscall_secp256k1_fn_mulmod,
+    );
+
+    println!("All tests passed!");
+}
@@ -0,0 +1,43 @@
+#![no_main]
+
+use monerochan_runtime::lib::secp256r1::ecrecover;
+
+monerochan_runtime::entrypoint!(main);
+
+// The SHA-256 digest of "hello world".
+const MSG_HASH: [u8; 32] = [
+    185, 77, 39, 185, 147, 77, 62, 8, 165, 46, 82, 215, 218, 125, 171, 250, 196, 132, 239, 227,
+    122, 83, 128, 238, 144, 136, 247, 172, 226, 239, 205, 233,
+];
+
+const SIGNATURE: [u8; 64] = [
+    125, 0, 220, 221, 230, 50, 223, 215, 215, 234, 18, 37, 116, 146, 184, 103, 78, 159, 21, 62, 31,
+    132, 70, 112, 144, 94, 149, 210, 131, 220, 20, 169, 3, 177, 161, 21, 20, 142, 106, 44, 193,
+    118, 4, 118, 222, 185, 226, 181, 114, 90, 93, 182, 34, 16, 102, 109, 43, 233, 8, 72, 215, 51,
+    48, 227,
+];
+
+const PUBLIC_KEY: [u8; 64] = [
+    228, 198, 14, 43, 212, 204, 239, 10, 200, 215, 139, 32, 107, 253, 17, 151, 94, 104, 255, 255,
+    38, 210, 231, 3, 238, 113, 111, 151, 117, 204, 247, 135, 126, 194, 92, 220, 15, 150, 122, 34,
+    157, 35, 87, 246, 123, 133, 191, 137, 39, 37, 106, 220, 247, 34, 94, 18, 176, 57, 42, 51, 31,
+    113, 139, 248,
+];
+
+const RECOVERY_ID: u8 = 1;
+
+pub fn main() {
+    for _ in 0..4 {
+        assert_eq!(ecrecover(&MSG_HASH, &SIGNATURE, RECOVERY_ID), Some(PUBLIC_KEY));
+
+        // The other recovery id recovers a different key.
+        let key = ecrecover(&MSG_HASH, &SIGNATURE, 1 - RECOVERY_ID);
+        assert!(key.is_some_and(|key| key != PUBLIC_KEY));
+
+        // Out of range recovery ids and signatures are rejected.
+        assert_eq!(ecrecover(&MSG_HASH, &SIGNATURE, 2), None);
+        assert_eq!(ecrecover(&MSG_HASH, &[0; 64], RECOVERY_ID), None);
+    }
+
+    println!("done");
+}
@@ -0,0 +1,40 @@
+#![no_main]
+
+use monerochan_curves::params::FieldParameters;
+use monerochan_lib::pasta::VestaPoint;
+monerochan_runtime::entrypoint!(main);
+
+// generator.
+// 28948022309329048855892746252171976963363056481941647379679742748393362948096
+// 2
+const A: [u8; 64] = [
+    0, 0, 0, 0, 33, 235, 70, 140, 221, 168, 148, 9, 252, 152, 70, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 64, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0,
+];
+
+// 2 * generator.
+// 12664759760331458874453076485325239921471337210849470728609887452422096289795
+// 19449452489080454700052938888178047022259553573804544333222327159076790730748
+const B: [u8; 64] = [
+    3, 0, 0, 112, 222, 6, 95, 237, 224, 9, 49, 68, 238, 226, 254, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 28, 252, 255, 255, 43, 250, 165, 63, 218, 116, 225, 111, 86, 201, 110, 7, 23, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
+];
+
+// 3 * generator.
+// 25090067966472946007446590780583652548116456464496053869245354133418193309279
+// 14485812765332067710838382555935059365898177416503303828814702067459945738374
+const C: [u8; 64] = [
+    95, 206, 85, 111, 235, 111, 238, 90, 21, 86, 13, 218, 186, 225, 2, 36, 176, 38, 165, 208, 40,
+    26, 244, 198, 19, 149, 92, 57, 168, 121, 120, 55, 134, 48, 229, 74, 252, 131, 189, 231, 89, 42,
+    188, 206, 74, 217, 69, 123, 71, 98, 8, 247, 190, 174, 59, 113, 22, 77, 154, 17, 242, 173, 6,
+    32,
+];
+
+pub fn main() {
+    common_test_utils::weierstrass_add::test_weierstrass_add::<
+        VestaPoint,
+        { monerochan_lib::pasta::N },
+    >(&A, &B, &C, monerochan_curves::weierstrass::pasta::VestaBaseField::MODULUS);
+}
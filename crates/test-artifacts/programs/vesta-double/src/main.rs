@@ -0,0 +1,33 @@
+#![no_main]
+
+use monerochan_runtime::syscalls::syscall_vesta_double;
+
+monerochan_runtime::entrypoint!(main);
+
+pub fn main() {
+    for _ in 0..10i64.pow(3) {
+        // generator.
+        // 28948022309329048855892746252171976963363056481941647379679742748393362948096
+        // 2
+        let mut a: [u8; 64] = [
+            0, 0, 0, 0, 33, 235, 70, 140, 221, 168, 148, 9, 252, 152, 70, 34, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 64, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+
+        syscall_vesta_double(a.as_mut_ptr() as *mut [u32; 16]);
+
+        // 2 * generator.
+        // 12664759760331458874453076485325239921471337210849470728609887452422096289795
+        // 19449452489080454700052938888178047022259553573804544333222327159076790730748
+        let b: [u8; 64] = [
+            3, 0, 0, 112, 222, 6, 95, 237, 224, 9, 49, 68, 238, 226, 254, 14, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 28, 252, 255, 255, 43, 250, 165, 63, 218, 116, 225, 111, 86,
+            201, 110, 7, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43,
+        ];
+
+        assert_eq!(a, b);
+    }
+
+    println!("done");
+}
@@ -0,0 +1,68 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use monerochan_runtime::lib::{
+    bls12381::Bls12381Point,
+    bn254::Bn254Point,
+    secp256k1::Secp256k1Point,
+    secp256r1::Secp256r1Point,
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassMsmPoint},
+};
+
+const A: [u32; 8] = [
+    0x7f6e5d4c, 0x3b2a1908, 0xf7e6d5c4, 0xb3a29180, 0x0f1e2d3c, 0x4b5a6978, 0x8796a5b4, 0x0c3d2e1f,
+];
+
+const B: [u32; 8] = [
+    0x13579bdf, 0x2468ace0, 0xfdb97531, 0x0eca8642, 0x01234567, 0x89abcdef, 0xfedcba98, 0x07654321,
+];
+
+/// Multiplies `point` by `scalar` with double-and-add over the add and double precompiles.
+fn double_and_add<P: WeierstrassAffinePoint<N>, const N: usize>(point: &P, scalar: &[u32; 8]) -> P {
+    let mut res = P::infinity();
+    for bit in (0..256).rev() {
+        res.double();
+        if (scalar[bit / 32] >> (bit % 32)) & 1 == 1 {
+            res.complete_add_assign(point);
+        }
+    }
+    res
+}
+
+fn assert_points_eq<P: WeierstrassAffinePoint<N>, const N: usize>(a: &P, b: &P) {
+    assert_eq!(a.is_infinity(), b.is_infinity());
+    if !a.is_infinity() {
+        assert_eq!(a.limbs_ref(), b.limbs_ref());
+    }
+}
+
+fn check<P: WeierstrassMsmPoint<N>, const N: usize>() {
+    let g = P::GENERATOR_T;
+    let h = double_and_add(&g, &[5, 0, 0, 0, 0, 0, 0, 0]);
+
+    // Scalar multiplication.
+    assert_points_eq(&g.fixed_window_mul(&A), &double_and_add(&g, &A));
+    assert!(g.fixed_window_mul(&[0; 8]).is_infinity());
+
+    // Two-point MSM.
+    let mut expected = double_and_add(&g, &A);
+    expected.complete_add_assign(&double_and_add(&h, &B));
+    assert_points_eq(&P::fixed_window_msm(&[A, B], &[g.clone(), h.clone()]), &expected);
+
+    // An odd number of points uses both the MSM and the scalar mul syscall, and points at
+    // infinity are skipped.
+    expected.complete_add_assign(&double_and_add(&h, &A));
+    assert_points_eq(
+        &P::fixed_window_msm(&[A, B, B, A], &[g.clone(), h.clone(), P::infinity(), h]),
+        &expected,
+    );
+}
+
+pub fn main() {
+    check::<Secp256k1Point, 16>();
+    check::<Secp256r1Point, 16>();
+    check::<Bn254Point, 16>();
+    check::<Bls12381Point, 24>();
+
+    println!("done");
+}
@@ -20,6 +20,12 @@ pub const CYCLE_TRACKER_ELF: &[u8] = include_elf!("cycle-tracker-test");
 
 pub const ED_ADD_ELF: &[u8] = include_elf!("ed-add-test");
 
+pub const MONERO_ELF: &[u8] = include_elf!("monero-test");
+
+pub const CLSAG_ELF: &[u8] = include_elf!("clsag-test");
+
+pub const BULLETPROOFS_PLUS_ELF: &[u8] = include_elf!("bulletproofs-plus-test");
+
 pub const ED_DECOMPRESS_ELF: &[u8] = include_elf!("ed-decompress-test");
 
 pub const KECCAK_PERMUTE_ELF: &[u8] = include_elf!("keccak-permute-test");
@@ -60,8 +66,12 @@ pub const BLS12381_DOUBLE_ELF: &[u8] = include_elf!("bls12381-double-test");
 
 pub const BLS12381_MUL_ELF: &[u8] = include_elf!("bls12381-mul-test");
 
+pub const WEIERSTRASS_MSM_ELF: &[u8] = include_elf!("weierstrass-msm-test");
+
 pub const UINT256_MUL_ELF: &[u8] = include_elf!("biguint-mul-test");
 
+pub const BIGINT_ELF: &[u8] = include_elf!("bigint-test");
+
 pub const BLS12381_DECOMPRESS_ELF: &[u8] = include_elf!("bls-decompress-test");
 
 pub const VERIFY_PROOF_ELF: &[u8] = include_elf!("verify-proof");
@@ -80,6 +90,50 @@ pub const BN254_FP2_ADDSUB_ELF: &[u8] = include_elf!("bn254-fp2-addsub-test");
 
 pub const BN254_FP2_MUL_ELF: &[u8] = include_elf!("bn254-fp2-mul-test");
 
+pub const SECP256K1_FP_ELF: &[u8] = include_elf!("secp256k1-fp-test");
+
+pub const SECP256R1_FP_ELF: &[u8] = include_elf!("secp256r1-fp-test");
+
+pub const X25519_ELF: &[u8] = include_elf!("x25519-test");
+
+pub const AES_ELF: &[u8] = include_elf!("aes-test");
+
+pub const CHACHA20_ELF: &[u8] = include_elf!("chacha20-test");
+
+pub const PALLAS_ADD_ELF: &[u8] = include_elf!("pallas-add-test");
+
+pub const PALLAS_DOUBLE_ELF: &[u8] = include_elf!("pallas-double-test");
+
+pub const VESTA_ADD_ELF: &[u8] = include_elf!("vesta-add-test");
+
+pub const VESTA_DOUBLE_ELF: &[u8] = include_elf!("vesta-double-test");
+
+pub const RISTRETTO255_ELF: &[u8] = include_elf!("ristretto255-test");
+
+pub const MERKLE_ELF: &[u8] = include_elf!("merkle-test");
+
+pub const GRUMPKIN_ADD_ELF: &[u8] = include_elf!("grumpkin-add-test");
+
+pub const GRUMPKIN_DOUBLE_ELF: &[u8] = include_elf!("grumpkin-double-test");
+
+pub const SECP256K1_ECRECOVER_ELF: &[u8] = include_elf!("secp256k1-ecrecover-test");
+
+pub const SECP256R1_ECRECOVER_ELF: &[u8] = include_elf!("secp256r1-ecrecover-test");
+
+pub const SECP256K1_ECDSA_VERIFY_ELF: &[u8] = include_elf!("secp256k1-ecdsa-verify-test");
+
+pub const SECP256R1_ECDSA_VERIFY_ELF: &[u8] = include_elf!("secp256r1-ecdsa-verify-test");
+
+pub const HASH_TO_CURVE_ELF: &[u8] = include_elf!("hash-to-curve-test");
+
+pub const BLS12381_TOWER_MUL_ELF: &[u8] = include_elf!("bls12381-tower-mul-test");
+
+pub const BLS12381_G2_ELF: &[u8] = include_elf!("bls12381-g2-test");
+
+pub const BN254_TOWER_MUL_ELF: &[u8] = include_elf!("bn254-tower-mul-test");
+
+pub const BN254_G2_ELF: &[u8] = include_elf!("bn254-g2-test");
+
 pub const TENDERMINT_BENCHMARK_ELF: &[u8] = include_elf!("tendermint-benchmark-program");
 
 pub const U256XU2048_MUL_ELF: &[u8] = include_elf!("u256x2048-mul");
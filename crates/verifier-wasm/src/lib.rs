@@ -0,0 +1,147 @@
+//! WebAssembly bindings for the MONEROCHAN verifier.
+//!
+//! The bindings verify Groth16 and Plonk proofs and decode public values. With the `proof`
+//! feature, they also decode the proofs saved with `MONEROCHANProofWithPublicValues::save`, and
+//! with the `compressed` feature they verify compressed proofs.
+//!
+//! The package is built with `wasm-pack build --target web` (or `--target nodejs`), and tested with
+//! `wasm-pack test --node`.
+
+use monerochan_verifier::{Groth16Verifier, PlonkVerifier, GROTH16_VK_BYTES, PLONK_VK_BYTES};
+use wasm_bindgen::prelude::*;
+
+#[cfg(feature = "proof")]
+mod proof;
+mod public_values;
+
+#[cfg(feature = "proof")]
+pub use proof::ProofWithPublicValues;
+pub use public_values::PublicValues;
+
+/// The circuit version of the proofs the bindings verify, which must match the version of the
+/// prover.
+pub const MONEROCHAN_CIRCUIT_VERSION: &str = include_str!("../MONEROCHAN_VERSION");
+
+/// Verifies a Groth16 proof, given as the bytes the onchain verifiers accept, against the public
+/// values and the vkey hash of the program (a hex string prefixed with "0x").
+#[wasm_bindgen(js_name = verifyGroth16)]
+pub fn verify_groth16(proof: &[u8], public_values: &[u8], vkey_hash: &str) -> Result<(), JsError> {
+    Groth16Verifier::verify(proof, public_values, vkey_hash, &GROTH16_VK_BYTES)?;
+    Ok(())
+}
+
+/// Verifies a Plonk proof, given as the bytes the onchain verifiers accept, against the public
+/// values and the vkey hash of the program (a hex string prefixed with "0x").
+#[wasm_bindgen(js_name = verifyPlonk)]
+pub fn verify_plonk(proof: &[u8], public_values: &[u8], vkey_hash: &str) -> Result<(), JsError> {
+    PlonkVerifier::verify(proof, public_values, vkey_hash, &PLONK_VK_BYTES)?;
+    Ok(())
+}
+
+/// Verifies a compressed proof, given as the serialized `MONEROCHANProof`, against the public
+/// values and the serialized verifying key of the program.
+///
+/// Single-shard proofs are rejected, see `CompressedVerifier::verify_monerochan_proof`.
+#[cfg(feature = "compressed")]
+#[wasm_bindgen(js_name = verifyCompressed)]
+pub fn verify_compressed(proof: &[u8], public_values: &[u8], vkey: &[u8]) -> Result<(), JsError> {
+    monerochan_verifier::CompressedVerifier::verify_monerochan_proof_with_vkey(
+        proof,
+        public_values,
+        vkey,
+    )?;
+    Ok(())
+}
+
+/// Computes the vkey hash of a serialized verifying key, as a 32 byte hex string prefixed with
+/// "0x". This is the vkey hash that Groth16 and Plonk proofs are verified against.
+#[cfg(feature = "compressed")]
+#[wasm_bindgen(js_name = vkeyHash)]
+pub fn vkey_hash(vkey: &[u8]) -> Result<String, JsError> {
+    let digest = vkey_hash_babybear(vkey)?;
+    Ok(format!("0x{}", hex::encode(digest_to_bytes32(&digest))))
+}
+
+/// Computes the digest of a serialized verifying key, as eight BabyBear elements.
+#[cfg(feature = "compressed")]
+#[wasm_bindgen(js_name = vkeyHashBabybear)]
+pub fn vkey_hash_babybear(vkey: &[u8]) -> Result<Vec<u32>, JsError> {
+    use monerochan_stark::{baby_bear_poseidon2::BabyBearPoseidon2, StarkVerifyingKey};
+    use p3_field::PrimeField32;
+
+    let vkey: StarkVerifyingKey<BabyBearPoseidon2> = bincode::deserialize(vkey)?;
+    let digest = monerochan_verifier::compressed::internal::hash_babybear(&vkey);
+    Ok(digest.iter().map(PrimeField32::as_canonical_u32).collect())
+}
+
+/// Packs a digest of eight BabyBear elements into a BN254 element, 31 bits at a time with the last
+/// element in the least significant bits, and returns its 32 big-endian bytes.
+#[cfg(feature = "compressed")]
+fn digest_to_bytes32(digest: &[u32]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, word) in digest.iter().rev().enumerate() {
+        for bit in 0..31 {
+            if (word >> bit) & 1 == 1 {
+                let position = 31 * i + bit;
+                bytes[31 - position / 8] |= 1 << (position % 8);
+            }
+        }
+    }
+    bytes
+}
+
+#[cfg(all(test, feature = "compressed"))]
+mod tests {
+    use super::digest_to_bytes32;
+
+    #[test]
+    fn test_digest_to_bytes32() {
+        let mut expected = [0u8; 32];
+        expected[31] = 1;
+        assert_eq!(digest_to_bytes32(&[0, 0, 0, 0, 0, 0, 0, 1]), expected);
+
+        // The first element is shifted by 7 * 31 = 217 bits.
+        let mut expected = [0u8; 32];
+        expected[31 - 217 / 8] = 1 << (217 % 8);
+        assert_eq!(digest_to_bytes32(&[1, 0, 0, 0, 0, 0, 0, 0]), expected);
+
+        // The elements do not overlap.
+        let max = (1 << 31) - 1;
+        let bytes = digest_to_bytes32(&[max; 8]);
+        assert_eq!(bytes[0], 0);
+        assert!(bytes[1..].iter().all(|&byte| byte == 0xff));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_vkey_hash_matches_prover() {
+        use monerochan_prover::{components::CpuProverComponents, HashableKey, MONEROCHANProver};
+        use p3_field::PrimeField32;
+
+        let prover = MONEROCHANProver::<CpuProverComponents>::new();
+        let (_, _, _, vk) = prover.setup(test_artifacts::FIBONACCI_ELF);
+
+        // The verifier hashes the key like the prover does.
+        let digest = monerochan_verifier::compressed::internal::hash_babybear(&vk.vk);
+        assert_eq!(digest, vk.hash_babybear());
+
+        // The digest is packed into the same bytes as `HashableKey::bytes32`.
+        let digest = digest.iter().map(PrimeField32::as_canonical_u32).collect::<Vec<_>>();
+        assert_eq!(digest_to_bytes32(&digest), vk.bytes32_raw());
+        assert_eq!(format!("0x{}", hex::encode(digest_to_bytes32(&digest))), vk.bytes32());
+
+        // Non-standard FRI security profiles are bound into the digest.
+        let mut fast_test_vk = vk.clone();
+        fast_test_vk.vk.fri_profile = Some(monerochan_stark::FriSecurityProfile::FastTest);
+        let fast_test_digest =
+            monerochan_verifier::compressed::internal::hash_babybear(&fast_test_vk.vk);
+        assert_eq!(fast_test_digest, fast_test_vk.hash_babybear());
+        assert_ne!(fast_test_vk.bytes32(), vk.bytes32());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_circuit_version_matches_prover() {
+        assert_eq!(MONEROCHAN_CIRCUIT_VERSION, monerochan_prover::MONEROCHAN_CIRCUIT_VERSION);
+    }
+}
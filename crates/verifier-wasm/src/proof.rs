@@ -0,0 +1,153 @@
+use monerochan_primitives::io::MONEROCHANPublicValues;
+use monerochan_stark::{is_proof_container, MONEROCHANProof, ProofDecoder};
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::{PublicValues, MONEROCHAN_CIRCUIT_VERSION};
+
+/// The layout of a proof saved by `MONEROCHANProofWithPublicValues::save`.
+#[derive(Deserialize)]
+struct SavedProof {
+    proof: MONEROCHANProof,
+    public_values: MONEROCHANPublicValues,
+    monerochan_version: String,
+    tee_proof: Option<Vec<u8>>,
+}
+
+/// The layout of a proof from the monero-chan network, which lacks the TEE proof.
+#[derive(Deserialize)]
+struct ProofFromNetwork {
+    proof: MONEROCHANProof,
+    public_values: MONEROCHANPublicValues,
+    monerochan_version: String,
+}
+
+impl From<ProofFromNetwork> for SavedProof {
+    fn from(value: ProofFromNetwork) -> Self {
+        Self {
+            proof: value.proof,
+            public_values: value.public_values,
+            monerochan_version: value.monerochan_version,
+            tee_proof: None,
+        }
+    }
+}
+
+/// A proof bundled together with its public values and the MONEROCHAN version, as saved by
+/// `MONEROCHANProofWithPublicValues::save`.
+#[wasm_bindgen]
+pub struct ProofWithPublicValues {
+    inner: SavedProof,
+}
+
+#[wasm_bindgen]
+impl ProofWithPublicValues {
+    /// Loads a proof from the bytes of a file written by `MONEROCHANProofWithPublicValues::save`,
+    /// or of a proof from the monero-chan network.
+    ///
+    /// Proof containers are only decoded if they were written by the circuit version of the
+    /// verifier, [`MONEROCHAN_CIRCUIT_VERSION`].
+    pub fn load(bytes: &[u8]) -> Result<ProofWithPublicValues, JsError> {
+        if is_proof_container(bytes) {
+            let container = ProofDecoder::new(MONEROCHAN_CIRCUIT_VERSION).decode(bytes)?;
+            let inner = SavedProof {
+                proof: container.proof,
+                public_values: MONEROCHANPublicValues::from(&container.header.public_values),
+                monerochan_version: container.header.circuit_version,
+                tee_proof: container.header.tee_proof,
+            };
+            return Ok(Self { inner });
+        }
+
+        match bincode::deserialize::<SavedProof>(bytes) {
+            Ok(inner) => Ok(Self { inner }),
+            Err(e) => match bincode::deserialize::<ProofFromNetwork>(bytes) {
+                Ok(proof_from_network) => Ok(Self { inner: proof_from_network.into() }),
+                // Return the original error from trying to load a saved proof.
+                Err(_) => Err(e.into()),
+            },
+        }
+    }
+
+    /// The proof mode: "Core", "Compressed", "Plonk" or "Groth16".
+    #[wasm_bindgen(getter)]
+    pub fn mode(&self) -> String {
+        self.inner.proof.to_string()
+    }
+
+    /// The version of MONEROCHAN that generated the proof.
+    #[wasm_bindgen(getter, js_name = monerochanVersion)]
+    pub fn monerochan_version(&self) -> String {
+        self.inner.monerochan_version.clone()
+    }
+
+    /// The public values committed by the program.
+    #[wasm_bindgen(getter, js_name = publicValues)]
+    pub fn public_values(&self) -> PublicValues {
+        PublicValues::new(self.inner.public_values.as_slice())
+    }
+
+    /// The serialized `MONEROCHANProof`, as accepted by `verifyCompressed`.
+    pub fn proof(&self) -> Result<Vec<u8>, JsError> {
+        Ok(bincode::serialize(&self.inner.proof)?)
+    }
+
+    /// The proof in the byte encoding the onchain verifiers accept, as accepted by `verifyGroth16`
+    /// and `verifyPlonk`. Only Groth16 and Plonk proofs have this encoding.
+    ///
+    /// The bytes consist of the TEE proof, if any, the first four bytes of the Groth16/Plonk vkey
+    /// hash and the encoded proof. Mock proofs are encoded as empty bytes.
+    pub fn bytes(&self) -> Result<Vec<u8>, JsError> {
+        let proof_bytes = self.verifier_bytes()?;
+        if proof_bytes.is_empty() {
+            return Ok(Vec::new());
+        }
+        let tee_proof = self.inner.tee_proof.as_deref().unwrap_or_default();
+        Ok([tee_proof, &proof_bytes[..]].concat())
+    }
+    /// Verifies the proof against the serialized verifying key of the program, with the verifier
+    /// of its proof mode. Core proofs are not supported.
+    #[cfg(feature = "compressed")]
+    pub fn verify(&self, vkey: &[u8]) -> Result<(), JsError> {
+        let public_values = self.inner.public_values.as_slice();
+        match &self.inner.proof {
+            MONEROCHANProof::Compressed(_) => {
+                crate::verify_compressed(&self.proof()?, public_values, vkey)
+            }
+            MONEROCHANProof::Plonk(_) => {
+                let proof = self.verifier_bytes()?;
+                crate::verify_plonk(&proof, public_values, &crate::vkey_hash(vkey)?)
+            }
+            MONEROCHANProof::Groth16(_) => {
+                let proof = self.verifier_bytes()?;
+                crate::verify_groth16(&proof, public_values, &crate::vkey_hash(vkey)?)
+            }
+            MONEROCHANProof::Core(_) => {
+                Err(JsError::new("core proofs are not supported by the wasm verifier"))
+            }
+        }
+    }
+}
+
+impl ProofWithPublicValues {
+    /// The first four bytes of the Groth16/Plonk vkey hash followed by the encoded proof, without
+    /// the TEE proof, as the Groth16 and Plonk verifiers accept them.
+    fn verifier_bytes(&self) -> Result<Vec<u8>, JsError> {
+        let (vkey_hash, encoded_proof) = match &self.inner.proof {
+            MONEROCHANProof::Plonk(proof) => (&proof.plonk_vkey_hash, &proof.encoded_proof),
+            MONEROCHANProof::Groth16(proof) => (&proof.groth16_vkey_hash, &proof.encoded_proof),
+            proof => {
+                return Err(JsError::new(&format!(
+                    "Proof type {proof} is not supported for onchain verification. \
+                     Only Plonk and Groth16 proofs are verifiable onchain"
+                )))
+            }
+        };
+
+        if encoded_proof.is_empty() {
+            return Ok(Vec::new());
+        }
+        let proof_bytes = hex::decode(encoded_proof)?;
+        Ok([&vkey_hash[..4], &proof_bytes[..]].concat())
+    }
+}
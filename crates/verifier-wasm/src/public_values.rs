@@ -0,0 +1,132 @@
+use monerochan_primitives::io::{blake3_hash, sha256_hash, MONEROCHANPublicValues};
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::prelude::*;
+
+/// The public values committed by a program, with a cursor to decode them in the order they were
+/// committed.
+///
+/// The values are decoded like `MONEROCHANPublicValues::read` does, so each `read*` method must
+/// match the type of the value committed at that position.
+#[wasm_bindgen]
+pub struct PublicValues {
+    data: Vec<u8>,
+    ptr: usize,
+}
+
+#[wasm_bindgen]
+impl PublicValues {
+    /// Creates the public values from their bytes.
+    #[wasm_bindgen(constructor)]
+    pub fn new(bytes: &[u8]) -> PublicValues {
+        Self { data: bytes.to_vec(), ptr: 0 }
+    }
+
+    /// The bytes of the public values.
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.data.clone()
+    }
+
+    /// The bytes of the public values, as a hex string prefixed with "0x".
+    pub fn raw(&self) -> String {
+        format!("0x{}", hex::encode(&self.data))
+    }
+
+    /// The SHA-256 hash of the public values.
+    pub fn hash(&self) -> Vec<u8> {
+        sha256_hash(&self.data)
+    }
+
+    /// The Blake3 hash of the public values.
+    #[wasm_bindgen(js_name = blake3Hash)]
+    pub fn blake3_hash(&self) -> Vec<u8> {
+        blake3_hash(&self.data)
+    }
+
+    /// The SHA-256 hash of the public values with the top 3 bits masked, as a 32 byte hex string
+    /// prefixed with "0x". This is the public values hash of the onchain verifiers.
+    #[wasm_bindgen(js_name = hashBn254)]
+    pub fn hash_bn254(&self) -> String {
+        let hash = MONEROCHANPublicValues::from(&self.data).hash_bn254();
+        format!("0x{:0>64}", hash.to_str_radix(16))
+    }
+
+    /// The number of bytes that have not been read yet.
+    #[wasm_bindgen(getter)]
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.ptr
+    }
+
+    /// Reads a `bool`.
+    #[wasm_bindgen(js_name = readBool)]
+    pub fn read_bool(&mut self) -> Result<bool, JsError> {
+        self.read()
+    }
+
+    /// Reads a `u8`.
+    #[wasm_bindgen(js_name = readU8)]
+    pub fn read_u8(&mut self) -> Result<u8, JsError> {
+        self.read()
+    }
+
+    /// Reads a `u16`.
+    #[wasm_bindgen(js_name = readU16)]
+    pub fn read_u16(&mut self) -> Result<u16, JsError> {
+        self.read()
+    }
+
+    /// Reads a `u32`.
+    #[wasm_bindgen(js_name = readU32)]
+    pub fn read_u32(&mut self) -> Result<u32, JsError> {
+        self.read()
+    }
+
+    /// Reads a `u64`, as a `BigInt`.
+    #[wasm_bindgen(js_name = readU64)]
+    pub fn read_u64(&mut self) -> Result<u64, JsError> {
+        self.read()
+    }
+
+    /// Reads an `i32`.
+    #[wasm_bindgen(js_name = readI32)]
+    pub fn read_i32(&mut self) -> Result<i32, JsError> {
+        self.read()
+    }
+
+    /// Reads an `i64`, as a `BigInt`.
+    #[wasm_bindgen(js_name = readI64)]
+    pub fn read_i64(&mut self) -> Result<i64, JsError> {
+        self.read()
+    }
+
+    /// Reads a `String`.
+    #[wasm_bindgen(js_name = readString)]
+    pub fn read_string(&mut self) -> Result<String, JsError> {
+        self.read()
+    }
+
+    /// Reads a `Vec<u8>`.
+    #[wasm_bindgen(js_name = readVec)]
+    pub fn read_vec(&mut self) -> Result<Vec<u8>, JsError> {
+        self.read()
+    }
+
+    /// Reads `len` raw bytes, as committed with `commit_slice`.
+    #[wasm_bindgen(js_name = readSlice)]
+    pub fn read_slice(&mut self, len: usize) -> Result<Vec<u8>, JsError> {
+        if len > self.remaining() {
+            return Err(JsError::new("not enough public values to read"));
+        }
+        let slice = self.data[self.ptr..self.ptr + len].to_vec();
+        self.ptr += len;
+        Ok(slice)
+    }
+}
+
+impl PublicValues {
+    fn read<T: Serialize + DeserializeOwned>(&mut self) -> Result<T, JsError> {
+        let result: T = bincode::deserialize(&self.data[self.ptr..])?;
+        self.ptr += bincode::serialized_size(&result)? as usize;
+        Ok(result)
+    }
+}
@@ -0,0 +1,45 @@
+#![cfg(target_arch = "wasm32")]
+
+use monerochan_primitives::io::MONEROCHANPublicValues;
+use monerochan_verifier_wasm::{verify_groth16, verify_plonk, PublicValues};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn public_values() -> MONEROCHANPublicValues {
+    let mut public_values = MONEROCHANPublicValues::new();
+    public_values.write(&true);
+    public_values.write(&42u32);
+    public_values.write(&u64::MAX);
+    public_values.write(&"monerochan".to_string());
+    public_values.write(&vec![1u8, 2, 3]);
+    public_values.write_slice(&[7; 4]);
+    public_values
+}
+
+#[wasm_bindgen_test]
+fn test_public_values() {
+    let expected = public_values();
+    let mut public_values = PublicValues::new(expected.as_slice());
+
+    assert_eq!(public_values.to_bytes(), expected.to_vec());
+    assert_eq!(public_values.raw(), expected.raw());
+    assert_eq!(public_values.hash(), expected.hash());
+    assert_eq!(public_values.blake3_hash(), expected.blake3_hash());
+
+    assert!(public_values.read_bool().unwrap());
+    assert_eq!(public_values.read_u32().unwrap(), 42);
+    assert_eq!(public_values.read_u64().unwrap(), u64::MAX);
+    assert_eq!(public_values.read_string().unwrap(), "monerochan");
+    assert_eq!(public_values.read_vec().unwrap(), vec![1, 2, 3]);
+    assert_eq!(public_values.read_slice(4).unwrap(), vec![7; 4]);
+    assert_eq!(public_values.remaining(), 0);
+    assert!(public_values.read_u8().is_err());
+    assert!(public_values.read_slice(1).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_reject_invalid_proofs() {
+    let public_values = public_values().to_vec();
+    let vkey_hash = format!("0x{}", "00".repeat(32));
+    assert!(verify_groth16(&[0; 4], &public_values, &vkey_hash).is_err());
+    assert!(verify_plonk(&[0; 4], &public_values, &vkey_hash).is_err());
+}
@@ -0,0 +1,85 @@
+#![cfg(all(target_arch = "wasm32", feature = "proof"))]
+
+use monerochan_primitives::io::MONEROCHANPublicValues;
+use monerochan_stark::{Groth16Bn254Proof, MONEROCHANProof, ProofCompression, ProofContainer};
+use monerochan_verifier_wasm::{ProofWithPublicValues, MONEROCHAN_CIRCUIT_VERSION};
+use serde::Serialize;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[derive(Serialize)]
+struct SavedProof {
+    proof: MONEROCHANProof,
+    public_values: MONEROCHANPublicValues,
+    monerochan_version: String,
+    tee_proof: Option<Vec<u8>>,
+}
+
+#[derive(Serialize)]
+struct ProofFromNetwork {
+    proof: MONEROCHANProof,
+    public_values: MONEROCHANPublicValues,
+    monerochan_version: String,
+}
+
+fn public_values() -> MONEROCHANPublicValues {
+    let mut public_values = MONEROCHANPublicValues::new();
+    public_values.write(&true);
+    public_values.write(&42u32);
+    public_values.write(&u64::MAX);
+    public_values.write(&"monerochan".to_string());
+    public_values.write(&vec![1u8, 2, 3]);
+    public_values.write_slice(&[7; 4]);
+    public_values
+}
+
+fn groth16_proof() -> MONEROCHANProof {
+    MONEROCHANProof::Groth16(Groth16Bn254Proof {
+        encoded_proof: "abcd".to_string(),
+        groth16_vkey_hash: [9; 32],
+        ..Default::default()
+    })
+}
+
+#[wasm_bindgen_test]
+fn test_load_proof() {
+    let saved = SavedProof {
+        proof: groth16_proof(),
+        public_values: public_values(),
+        monerochan_version: "v5.2.8".to_string(),
+        tee_proof: Some(vec![5; 2]),
+    };
+    let proof = ProofWithPublicValues::load(&bincode::serialize(&saved).unwrap()).unwrap();
+    assert_eq!(proof.mode(), "Groth16");
+    assert_eq!(proof.monerochan_version(), "v5.2.8");
+    assert_eq!(proof.public_values().to_bytes(), saved.public_values.to_vec());
+    assert_eq!(proof.bytes().unwrap(), vec![5, 5, 9, 9, 9, 9, 0xab, 0xcd]);
+    assert_eq!(proof.proof().unwrap(), bincode::serialize(&saved.proof).unwrap());
+
+    // A proof from the network lacks the TEE proof.
+    let from_network = ProofFromNetwork {
+        proof: groth16_proof(),
+        public_values: public_values(),
+        monerochan_version: "v5.2.8".to_string(),
+    };
+    let proof = ProofWithPublicValues::load(&bincode::serialize(&from_network).unwrap()).unwrap();
+    assert_eq!(proof.bytes().unwrap(), vec![9, 9, 9, 9, 0xab, 0xcd]);
+
+    assert!(ProofWithPublicValues::load(&[1, 2, 3]).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_load_proof_container() {
+    let mut container =
+        ProofContainer::new(groth16_proof(), public_values().to_vec(), MONEROCHAN_CIRCUIT_VERSION);
+    container.header.compression = ProofCompression::Deflate;
+    container.header.tee_proof = Some(vec![5; 2]);
+    let proof = ProofWithPublicValues::load(&container.encode().unwrap()).unwrap();
+    assert_eq!(proof.mode(), "Groth16");
+    assert_eq!(proof.monerochan_version(), MONEROCHAN_CIRCUIT_VERSION);
+    assert_eq!(proof.public_values().to_bytes(), public_values().to_vec());
+    assert_eq!(proof.bytes().unwrap(), vec![5, 5, 9, 9, 9, 9, 0xab, 0xcd]);
+
+    // Containers written by another circuit version are rejected.
+    container.header.circuit_version = "v0.0.0".to_string();
+    assert!(ProofWithPublicValues::load(&container.encode().unwrap()).is_err());
+}
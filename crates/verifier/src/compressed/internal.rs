@@ -14,10 +14,10 @@ use monerochan_recursion_core::{
     air::{RecursionPublicValues, NUM_PV_ELMS_TO_HASH},
     machine::RecursionAir,
 };
-use monerochan_stark::{baby_bear_poseidon2::BabyBearPoseidon2, *};
+use monerochan_stark::{air::PV_DIGEST_NUM_WORDS, baby_bear_poseidon2::BabyBearPoseidon2, *};
 
 use super::CompressedError;
-use crate::{blake3_hash, hash_public_inputs, hash_public_inputs_with_fn};
+use crate::{blake3_hash, sha256_hash};
 
 // NOTE: that all these constants and types are checked by monerochan_prover::tests::monerochan_verifier_valid.
 // If you add a new proof, you MUST add to the test in that crate.
@@ -66,6 +66,16 @@ pub fn verify_monerochan_proof(
     verify_monerochan_reduce_proof(reduce_proof.as_ref(), monerochan_public_inputs, vkey_hash)
 }
 
+/// Like [`verify_monerochan_proof`], but takes the MONEROCHAN verifying key instead of its hash.
+pub fn verify_monerochan_proof_with_vkey(
+    monerochan_proof: &MONEROCHANProof,
+    monerochan_public_inputs: &[u8],
+    monerochan_vkey: &StarkVerifyingKey<SC>,
+) -> Result<(), CompressedError> {
+    let vkey_hash = hash_babybear(monerochan_vkey);
+    verify_monerochan_proof(monerochan_proof, monerochan_public_inputs, &vkey_hash)
+}
+
 // The rest of the functions in this file have been copied from elsewhere with slight modifications.
 
 /// Verify a compressed proof.
@@ -90,16 +100,8 @@ pub fn verify_monerochan_reduce_proof(
     compress_machine.verify(compress_vk, &machine_proof, &mut challenger)?;
 
     // Validate the MONEROCHAN public values against the committed digest.
-    let committed_value_digest_bytes = public_values
-        .committed_value_digest
-        .iter()
-        .flat_map(|w| w.0.iter().map(|x| x.as_canonical_u32() as u8))
-        .collect::<Vec<_>>();
-
-    if committed_value_digest_bytes.as_slice() != hash_public_inputs(monerochan_public_inputs).as_slice() &&
-        committed_value_digest_bytes.as_slice() !=
-            hash_public_inputs_with_fn(monerochan_public_inputs, blake3_hash)
-    {
+    let committed_value_digest = &public_values.committed_value_digest;
+    if !is_committed_value_digest_valid(committed_value_digest, monerochan_public_inputs) {
         return Err(CompressedError::PublicValuesMismatch);
     }
 
@@ -134,6 +136,23 @@ pub fn verify_monerochan_reduce_proof(
     Ok(())
 }
 
+/// Check that the committed value digest is the SHA-256 or the Blake3 hash of the public values.
+///
+/// Unlike the Plonk and Groth16 public inputs, the digest committed by the zkVM is not masked to
+/// 253 bits.
+pub(crate) fn is_committed_value_digest_valid(
+    committed_value_digest: &[Word<BabyBear>; PV_DIGEST_NUM_WORDS],
+    monerochan_public_inputs: &[u8],
+) -> bool {
+    let committed_value_digest_bytes = committed_value_digest
+        .iter()
+        .flat_map(|w| w.0.iter().map(|x| x.as_canonical_u32() as u8))
+        .collect::<Vec<_>>();
+
+    committed_value_digest_bytes.as_slice() == sha256_hash(monerochan_public_inputs).as_slice() ||
+        committed_value_digest_bytes.as_slice() == blake3_hash(monerochan_public_inputs).as_slice()
+}
+
 /// Compute the digest of the public values.
 fn recursion_public_values_digest(
     config: &SC,
@@ -153,7 +172,8 @@ fn is_recursion_public_values_valid(
     public_values.digest.iter().copied().eq(expected_digest)
 }
 
-fn hash_babybear(this: &StarkVerifyingKey<BabyBearPoseidon2>) -> [BabyBear; DIGEST_SIZE] {
+/// Compute the digest of a verifying key, as `HashableKey::hash_babybear` does.
+pub fn hash_babybear(this: &StarkVerifyingKey<BabyBearPoseidon2>) -> [BabyBear; DIGEST_SIZE] {
     let mut num_inputs = DIGEST_SIZE + 1 + 14 + (7 * this.chip_information.len());
     for (name, _, _) in this.chip_information.iter() {
         num_inputs += name.len();
@@ -177,6 +197,9 @@ fn hash_babybear(this: &StarkVerifyingKey<BabyBearPoseidon2>) -> [BabyBear; DIGE
             inputs.push(BabyBear::from_canonical_u8(*byte));
         }
     }
+    if let Some(tag) = this.fri_profile.and_then(FriSecurityProfile::digest_tag) {
+        inputs.push(BabyBear::from_canonical_u32(tag));
+    }
 
     poseidon2_hash(inputs)
 }
@@ -6,7 +6,9 @@ use thiserror::Error;
 
 pub mod internal;
 
-use internal::{verify_monerochan_proof, verify_monerochan_reduce_proof, F, SC};
+use internal::{
+    verify_monerochan_proof, verify_monerochan_proof_with_vkey, verify_monerochan_reduce_proof, F, SC,
+};
 
 /// A reason why the verifier rejects a given proof.
 #[derive(Debug, Error)]
@@ -18,6 +20,8 @@ pub enum CompressedError {
     Mode(MONEROCHANProofMode),
     #[error("failed to deserialize vkey hash: {0}")]
     DeserializeVkeyHash(Box<bincode::ErrorKind>),
+    #[error("failed to deserialize vkey: {0}")]
+    DeserializeVkey(Box<bincode::ErrorKind>),
     #[error("failed to verify proof: {0}")]
     ProofRejected(#[from] MachineVerificationError<SC>),
     #[error("single-shard proofs are currently unsupported by this verifier")]
@@ -64,6 +68,35 @@ impl CompressedVerifier {
 
         Ok(())
     }
+
+    /// Like [`CompressedVerifier::verify_monerochan_proof`], but takes the serialized MONEROCHAN
+    /// verifying key instead of its hash.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof bytes.
+    /// * `monerochan_vkey` - The MONEROCHAN verifying key bytes.
+    ///
+    /// The verifying key may be serialized in the following manner:
+    ///
+    /// ```ignore
+    /// let (pk, vk) = client.setup(ELF);
+    /// let monerochan_vkey = bincode::serialize(&vk).unwrap();
+    /// ```
+    pub fn verify_monerochan_proof_with_vkey(
+        monerochan_proof: &[u8],
+        monerochan_public_inputs: &[u8],
+        monerochan_vkey: &[u8],
+    ) -> Result<(), CompressedError> {
+        let monerochan_proof: MONEROCHANProof =
+            bincode::deserialize(monerochan_proof).map_err(CompressedError::DeserializeProof)?;
+        let vkey: StarkVerifyingKey<SC> =
+            bincode::deserialize(monerochan_vkey).map_err(CompressedError::DeserializeVkey)?;
+
+        verify_monerochan_proof_with_vkey(&monerochan_proof, monerochan_public_inputs, &vkey)?;
+
+        Ok(())
+    }
     pub fn verify_monerochan_reduce_proof(
         monerochan_reduce_proof: &[u8],
         monerochan_public_inputs: &[u8],
@@ -0,0 +1,128 @@
+//! A verifier for MONEROCHAN "core" proofs.
+//!
+//! The verifier evaluates the constraints of the RISC-V AIRs of `monerochan-core-machine`, which
+//! needs `std`, so this module is not available on no-std targets.
+
+use alloc::boxed::Box;
+use core::borrow::Borrow;
+
+use monerochan_core_machine::{cpu::MAX_CPU_LOG_DEGREE, riscv::RiscvAir};
+use monerochan_stark::{air::PublicValues, baby_bear_poseidon2::BabyBearPoseidon2, *};
+use thiserror::Error;
+
+use crate::compressed::internal::{is_committed_value_digest_valid, SC};
+
+/// A reason why the verifier rejects a given proof.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CoreError {
+    #[error("failed to deserialize proof: {0}")]
+    DeserializeProof(Box<bincode::ErrorKind>),
+    #[error("unexpected proof mode: expected {exp:?}, got {0:?}", exp = MONEROCHANProofMode::Core)]
+    Mode(MONEROCHANProofMode),
+    #[error("failed to deserialize vkey: {0}")]
+    DeserializeVkey(Box<bincode::ErrorKind>),
+    #[error("failed to verify proof: {0}")]
+    ProofRejected(#[from] MachineVerificationError<SC>),
+    #[error("given public values do not match the commitment in the proof")]
+    PublicValuesMismatch,
+    #[error("the vkey has the FRI security profile {found:?}, expected {expected}")]
+    FriProfileMismatch { found: Option<FriSecurityProfile>, expected: FriSecurityProfile },
+    #[error("invalid FRI configuration: {0}")]
+    FriConfig(FriConfigError),
+}
+
+/// A verifier for MONEROCHAN "core" proofs.
+#[derive(Debug)]
+pub struct CoreVerifier;
+impl CoreVerifier {
+    /// Attempts to verify an MONEROCHAN "core" proof, as generated by the MONEROCHAN SDK.
+    /// Returns `Ok` if the proof verifies or `Err` with the reason that verification failed.
+    ///
+    /// The checks are the same as the ones of `MONEROCHANProver::verify`, without the prover. The
+    /// proof must be generated with the standard FRI security profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof bytes.
+    /// * `monerochan_vkey` - The MONEROCHAN verifying key bytes.
+    ///
+    /// The arguments may be generated in the following manner:
+    ///
+    /// ```ignore
+    /// use monerochan::{ProverClient, MONEROCHANProof};
+    /// let client = ProverClient::builder().cpu().build();
+    /// let (pk, vk) = client.setup(ELF);
+    /// let monerochan_vkey = bincode::serialize(&vk).unwrap();
+    /// let proof: MONEROCHANProof = match client.prove(&pk, &stdin).core().run().unwrap().proof;
+    /// ```
+    pub fn verify_monerochan_proof(
+        monerochan_proof: &[u8],
+        monerochan_public_inputs: &[u8],
+        monerochan_vkey: &[u8],
+    ) -> Result<(), CoreError> {
+        let monerochan_proof: MONEROCHANProof =
+            bincode::deserialize(monerochan_proof).map_err(CoreError::DeserializeProof)?;
+        let vkey: StarkVerifyingKey<SC> =
+            bincode::deserialize(monerochan_vkey).map_err(CoreError::DeserializeVkey)?;
+
+        let MONEROCHANProof::Core(shard_proofs) = &monerochan_proof else {
+            return Err(CoreError::Mode((&monerochan_proof).into()));
+        };
+        verify_core_proof(shard_proofs, monerochan_public_inputs, &vkey)
+    }
+}
+
+/// Verify the shard proofs of a core proof generated with the standard FRI security profile.
+pub fn verify_core_proof(
+    shard_proofs: &[ShardProof<SC>],
+    monerochan_public_inputs: &[u8],
+    vkey: &StarkVerifyingKey<SC>,
+) -> Result<(), CoreError> {
+    verify_core_proof_with_profile(
+        shard_proofs,
+        monerochan_public_inputs,
+        vkey,
+        FriSecurityProfile::Standard,
+    )
+}
+
+/// Verify the shard proofs of a core proof generated with the given FRI security profile.
+///
+/// The profile is chosen by the caller and not read from the verifying key, which is untrusted:
+/// keys with another profile are rejected.
+pub fn verify_core_proof_with_profile(
+    shard_proofs: &[ShardProof<SC>],
+    monerochan_public_inputs: &[u8],
+    vkey: &StarkVerifyingKey<SC>,
+    fri_profile: FriSecurityProfile,
+) -> Result<(), CoreError> {
+    if vkey.fri_profile != Some(fri_profile) {
+        return Err(CoreError::FriProfileMismatch {
+            found: vkey.fri_profile,
+            expected: fri_profile,
+        });
+    }
+
+    verify_core_public_values(shard_proofs, vkey.pc_start, MAX_CPU_LOG_DEGREE)?;
+
+    // Validate the MONEROCHAN public values against the digest committed in the last shard.
+    // SAFETY: The proof is already checked to not be empty.
+    let public_values: &PublicValues<Word<_>, _> =
+        shard_proofs.last().unwrap().public_values.as_slice().borrow();
+    if !is_committed_value_digest_valid(
+        &public_values.committed_value_digest,
+        monerochan_public_inputs,
+    ) {
+        return Err(CoreError::PublicValuesMismatch);
+    }
+
+    let config = BabyBearPoseidon2::with_fri_profile(fri_profile).map_err(CoreError::FriConfig)?;
+    let core_machine = RiscvAir::machine(config);
+
+    let mut challenger = core_machine.config().challenger();
+    let machine_proof = MachineProof { shard_proofs: shard_proofs.to_vec() };
+    core_machine.verify(vkey, &machine_proof, &mut challenger)?;
+
+    Ok(())
+}
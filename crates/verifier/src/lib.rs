@@ -1,5 +1,10 @@
 //! This crate provides verifiers for MONEROCHAN Groth16 and Plonk BN254 proofs in a no-std environment.
 //! It is patched for efficient verification within the MONEROCHAN zkVM context.
+//!
+//! With the `compressed` and `core` features, it also verifies MONEROCHAN compressed and core STARK
+//! proofs without depending on the prover. The `core` feature evaluates the RISC-V AIRs of
+//! `monerochan-core-machine`, which needs `std`, so it enables the `std` feature and core proofs
+//! cannot be verified on no-std targets.
 
 #![cfg_attr(not(any(feature = "std", test)), no_std)]
 extern crate alloc;
@@ -21,6 +26,11 @@ pub mod compressed;
 #[cfg(feature = "compressed")]
 pub use compressed::{CompressedError, CompressedVerifier};
 
+#[cfg(feature = "core")]
+pub mod core_proof;
+#[cfg(feature = "core")]
+pub use core_proof::{CoreError, CoreVerifier};
+
 mod constants;
 pub mod converter;
 mod error;
@@ -311,6 +311,88 @@ fn test_verify_invalid_plonk(#[case] elf: &[u8]) {
     assert!(matches!(result, Err(PlonkError::GeneralError(Error::InvalidData))));
 }
 
+#[cfg(feature = "core")]
+#[rstest]
+#[case(FIBONACCI_ELF)]
+#[case(FIBONACCI_BLAKE3_ELF)]
+#[serial]
+fn test_core_verifier(#[case] elf: &[u8]) {
+    // Set up the pk and vk.
+    let client = ProverClient::from_env();
+    let (pk, vk) = client.setup(elf);
+
+    // Generate the core proof.
+    let monerochan_proof_with_public_values =
+        client.prove(&pk, &MONEROCHANStdin::new()).core().run().unwrap();
+
+    // Verify with the prover.
+    client.verify(&monerochan_proof_with_public_values, &vk).expect("Proof is invalid");
+
+    // Verify without the prover.
+    let proof = bincode::serialize(&monerochan_proof_with_public_values.proof).unwrap();
+    let mut public_inputs = monerochan_proof_with_public_values.public_values.to_vec();
+    let vkey = bincode::serialize(&vk).unwrap();
+    crate::CoreVerifier::verify_monerochan_proof(&proof, &public_inputs, &vkey)
+        .expect("Core proof is invalid");
+
+    // The verifier does not take the FRI parameters from the vkey.
+    let mut forged_vk = vk.clone();
+    forged_vk.vk.fri_profile = Some(monerochan_stark::FriSecurityProfile::FastTest);
+    assert_ne!(forged_vk.bytes32(), vk.bytes32());
+    let forged_vkey = bincode::serialize(&forged_vk).unwrap();
+    let result = crate::CoreVerifier::verify_monerochan_proof(&proof, &public_inputs, &forged_vkey);
+    assert!(matches!(result, Err(crate::CoreError::FriProfileMismatch { .. })));
+
+    // Both verifiers reject other public values.
+    public_inputs.push(0);
+    let mut tampered = monerochan_proof_with_public_values.clone();
+    tampered.public_values = monerochan::MONEROCHANPublicValues::from(&public_inputs);
+    assert!(client.verify(&tampered, &vk).is_err());
+    let result = crate::CoreVerifier::verify_monerochan_proof(&proof, &public_inputs, &vkey);
+    assert!(matches!(result, Err(crate::CoreError::PublicValuesMismatch)));
+}
+
+#[cfg(feature = "compressed")]
+#[rstest]
+#[case(FIBONACCI_ELF)]
+#[case(FIBONACCI_BLAKE3_ELF)]
+#[serial]
+fn test_compressed_verifier(#[case] elf: &[u8]) {
+    // Set up the pk and vk.
+    let client = ProverClient::from_env();
+    let (pk, vk) = client.setup(elf);
+
+    // Generate a multi-shard compressed proof, since the verifier rejects single-shard proofs.
+    let monerochan_proof_with_public_values = client
+        .prove(&pk, &MONEROCHANStdin::new())
+        .compressed()
+        .shard_size(1 << 12)
+        .run()
+        .unwrap();
+
+    // Verify with the prover.
+    client.verify(&monerochan_proof_with_public_values, &vk).expect("Proof is invalid");
+
+    // Verify without the prover, with the vkey and with its hash.
+    let proof = bincode::serialize(&monerochan_proof_with_public_values.proof).unwrap();
+    let mut public_inputs = monerochan_proof_with_public_values.public_values.to_vec();
+    let vkey = bincode::serialize(&vk).unwrap();
+    let vkey_hash = bincode::serialize(&vk.hash_babybear()).unwrap();
+    crate::CompressedVerifier::verify_monerochan_proof_with_vkey(&proof, &public_inputs, &vkey)
+        .expect("Compressed proof is invalid");
+    crate::CompressedVerifier::verify_monerochan_proof(&proof, &public_inputs, &vkey_hash)
+        .expect("Compressed proof is invalid");
+
+    // Both verifiers reject other public values.
+    public_inputs.push(0);
+    let mut tampered = monerochan_proof_with_public_values.clone();
+    tampered.public_values = monerochan::MONEROCHANPublicValues::from(&public_inputs);
+    assert!(client.verify(&tampered, &vk).is_err());
+    let result =
+        crate::CompressedVerifier::verify_monerochan_proof_with_vkey(&proof, &public_inputs, &vkey);
+    assert!(matches!(result, Err(crate::CompressedError::PublicValuesMismatch)));
+}
+
 #[serial]
 #[test]
 fn test_vkeys() {
@@ -0,0 +1,48 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Encrypts a block in place with AES-128, given the 11 expanded round keys.
+///
+/// ### Safety
+///
+/// The caller must ensure that `block` and `round_keys` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_aes128_encrypt_block(block: *mut [u32; 4], round_keys: *const [u32; 44]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::AES128_ENCRYPT_BLOCK,
+            in("a0") block,
+            in("a1") round_keys,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Encrypts a block in place with AES-256, given the 15 expanded round keys.
+///
+/// ### Safety
+///
+/// The caller must ensure that `block` and `round_keys` are valid pointers to data that is aligned
+/// along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_aes256_encrypt_block(block: *mut [u32; 4], round_keys: *const [u32; 60]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::AES256_ENCRYPT_BLOCK,
+            in("a0") block,
+            in("a1") round_keys,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
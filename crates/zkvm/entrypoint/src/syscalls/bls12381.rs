@@ -79,3 +79,56 @@ pub extern "C" fn syscall_bls12381_decompress(point: &mut [u8; 96], sign_bit: bo
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Runs one 64-bit chunk of a fixed-window Bls12381 scalar multiplication.
+///
+/// The accumulator `p` is multiplied by 2^64 and the window table multiples selected by the scalar
+/// chunk are added to it. `input` points to two scalar words followed by the table
+/// `[P, 2P, ..., 15P]`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `input` are valid pointers to data that is aligned along a
+/// four byte boundary, and that no intermediate sum hits the point at infinity or doubles a point.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_scalar_mul(p: *mut [u32; 24], input: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_SCALAR_MUL,
+            in("a0") p,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Runs one 64-bit chunk of a fixed-window Bls12381 two-point multi-scalar multiplication.
+///
+/// Like [`syscall_bls12381_scalar_mul`], but `input` holds two scalar words for each of the two points,
+/// followed by the table `[P, 2P, ..., 15P]` of each point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `input` are valid pointers to data that is aligned along a
+/// four byte boundary, and that no intermediate sum hits the point at infinity or doubles a point.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_msm(p: *mut [u32; 24], input: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_MSM,
+            in("a0") p,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
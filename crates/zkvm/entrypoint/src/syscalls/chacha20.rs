@@ -0,0 +1,26 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Executes the ChaCha20 block function on the given state, overwriting it with the keystream
+/// block.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` is valid pointer to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_chacha20_block(state: *mut [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::CHACHA20_BLOCK,
+            in("a0") state,
+            in("a1") 0
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
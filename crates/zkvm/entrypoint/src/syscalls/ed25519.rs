@@ -57,3 +57,31 @@ pub extern "C" fn syscall_ed_decompress(point: &mut [u8; 64]) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Runs one step of the X25519 Montgomery ladder of RFC 7748.
+///
+/// The state `[x2, z2, x3, z3]` holds the projective u-coordinates of two points whose difference
+/// has the u-coordinate `x1`. It is overwritten with the doubling of the first point and the sum of
+/// both points, with every coordinate reduced modulo `2^255 - 19`. The conditional swap of the
+/// ladder is left to the caller.
+///
+/// ### Safety
+///
+/// The caller must ensure that `state` and `x1` are valid pointers to data that is aligned along a
+/// four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_x25519_ladder_step(state: *mut [u32; 32], x1: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::X25519_LADDER_STEP,
+            in("a0") state,
+            in("a1") x1,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -240,3 +240,183 @@ pub extern "C" fn syscall_bn254_fp2_mulmod(x: *mut u32, y: *const u32) {
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// BLS12-381 Fp6 multiplication operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_fp6_mulmod(x: *mut u32, y: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_FP6_MUL,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// BLS12-381 Fp12 multiplication operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_fp12_mulmod(x: *mut u32, y: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_FP12_MUL,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Adds two points of the BLS12-381 G2 subgroup, given in affine coordinates over Fp2.
+///
+/// The result is stored in the first point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `q` are valid pointers to data that is aligned along a four
+/// byte boundary, and that `p` and `q` have distinct x-coordinates.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_g2_add(p: *mut [u32; 48], q: *const [u32; 48]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_G2_ADD,
+            in("a0") p,
+            in("a1") q,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Doubles a point of the BLS12-381 G2 subgroup, given in affine coordinates over Fp2.
+///
+/// The result is stored in the first point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` is valid pointer to data that is aligned along a four byte
+/// boundary, and that `p` has a non-zero y-coordinate.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bls12381_g2_double(p: *mut [u32; 48]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BLS12381_G2_DOUBLE,
+            in("a0") p,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// BN254 Fp6 multiplication operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_fp6_mulmod(x: *mut u32, y: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BN254_FP6_MUL,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// BN254 Fp12 multiplication operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_fp12_mulmod(x: *mut u32, y: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BN254_FP12_MUL,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Adds two points of the BN254 G2 subgroup, given in affine coordinates over Fp2.
+///
+/// The result is stored in the first point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `q` are valid pointers to data that is aligned along a four
+/// byte boundary, and that `p` and `q` have distinct x-coordinates.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_g2_add(p: *mut [u32; 32], q: *const [u32; 32]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BN254_G2_ADD,
+            in("a0") p,
+            in("a1") q,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Doubles a point of the BN254 G2 subgroup, given in affine coordinates over Fp2.
+///
+/// The result is stored in the first point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` is valid pointer to data that is aligned along a four byte
+/// boundary, and that `p` has a non-zero y-coordinate.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_bn254_g2_double(p: *mut [u32; 32]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::BN254_G2_DOUBLE,
+            in("a0") p,
+            in("a1") 0,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,53 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Adds two Grumpkin points.
+///
+/// The result is stored in the first point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `q` are valid pointers to data that is aligned along a four
+/// byte boundary. Additionally, the caller must ensure that `p` and `q` are valid points on the
+/// grumpkin curve, and that `p` and `q` are not equal to each other.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_grumpkin_add(p: *mut [u32; 16], q: *mut [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::GRUMPKIN_ADD,
+            in("a0") p,
+            in("a1") q
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Double a Grumpkin point.
+///
+/// The result is stored in-place in the supplied buffer.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` is valid pointer to data that is aligned along a four byte
+/// boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_grumpkin_double(p: *mut [u32; 16]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::GRUMPKIN_DOUBLE,
+            in("a0") p,
+            in("a1") 0
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -1,40 +1,54 @@
+mod aes;
 mod bigint;
 mod bls12381;
 mod bn254;
+mod chacha20;
 mod ed25519;
 mod fptower;
+mod grumpkin;
 mod halt;
 mod io;
 mod keccak_permute;
 mod memory;
+mod pasta;
 mod secp256k1;
 mod secp256r1;
 mod sha_compress;
 mod sha_extend;
 mod sys;
 mod u256x2048_mul;
+mod uint256_add_sub;
 mod uint256_mul;
+mod uint_mulmod;
 mod unconstrained;
+mod user;
 #[cfg(feature = "verify")]
 mod verify;
 
+pub use aes::*;
 pub use bigint::*;
 pub use bls12381::*;
 pub use bn254::*;
+pub use chacha20::*;
 pub use ed25519::*;
 pub use fptower::*;
+pub use grumpkin::*;
 pub use halt::*;
 pub use io::*;
 pub use keccak_permute::*;
 pub use memory::*;
+pub use pasta::*;
 pub use secp256k1::*;
 pub use secp256r1::*;
 pub use sha_compress::*;
 pub use sha_extend::*;
 pub use sys::*;
 pub use u256x2048_mul::*;
+pub use uint256_add_sub::*;
 pub use uint256_mul::*;
+pub use uint_mulmod::*;
 pub use unconstrained::*;
+pub use user::*;
 #[cfg(feature = "verify")]
 pub use verify::*;
 
@@ -157,3 +171,153 @@ pub const BN254_FP2_SUB: u32 = 0x00_01_01_2A;
 
 /// Executes the `BN254_FP2_MUL` precompile.
 pub const BN254_FP2_MUL: u32 = 0x00_01_01_2B;
+
+/// Executes the `BN254_FP6_MUL` precompile.
+pub const BN254_FP6_MUL: u32 = 0x00_01_01_30;
+
+/// Executes the `BN254_FP12_MUL` precompile.
+pub const BN254_FP12_MUL: u32 = 0x00_01_01_31;
+
+/// Executes the `BN254_G2_ADD` precompile.
+pub const BN254_G2_ADD: u32 = 0x00_01_01_32;
+
+/// Executes the `BN254_G2_DOUBLE` precompile.
+pub const BN254_G2_DOUBLE: u32 = 0x00_01_01_33;
+
+/// Executes the `BLS12381_FP6_MUL` precompile.
+pub const BLS12381_FP6_MUL: u32 = 0x00_01_01_34;
+
+/// Executes the `BLS12381_FP12_MUL` precompile.
+pub const BLS12381_FP12_MUL: u32 = 0x00_01_01_35;
+
+/// Executes the `BLS12381_G2_ADD` precompile.
+pub const BLS12381_G2_ADD: u32 = 0x00_01_01_36;
+
+/// Executes the `BLS12381_G2_DOUBLE` precompile.
+pub const BLS12381_G2_DOUBLE: u32 = 0x00_01_01_37;
+
+/// Executes the `SECP256K1_SCALAR_MUL` precompile.
+pub const SECP256K1_SCALAR_MUL: u32 = 0x00_60_01_38;
+
+/// Executes the `SECP256K1_MSM` precompile.
+pub const SECP256K1_MSM: u32 = 0x00_80_01_39;
+
+/// Executes the `SECP256R1_SCALAR_MUL` precompile.
+pub const SECP256R1_SCALAR_MUL: u32 = 0x00_60_01_3A;
+
+/// Executes the `SECP256R1_MSM` precompile.
+pub const SECP256R1_MSM: u32 = 0x00_80_01_3B;
+
+/// Executes the `BN254_SCALAR_MUL` precompile.
+pub const BN254_SCALAR_MUL: u32 = 0x00_60_01_3C;
+
+/// Executes the `BN254_MSM` precompile.
+pub const BN254_MSM: u32 = 0x00_80_01_3D;
+
+/// Executes the `BLS12381_SCALAR_MUL` precompile.
+pub const BLS12381_SCALAR_MUL: u32 = 0x00_60_01_3E;
+
+/// Executes the `BLS12381_MSM` precompile.
+pub const BLS12381_MSM: u32 = 0x00_80_01_3F;
+
+/// Executes the `UINT256_ADD_CARRY` precompile.
+pub const UINT256_ADD_CARRY: u32 = 0x00_01_01_40;
+
+/// Executes the `UINT256_SUB_BORROW` precompile.
+pub const UINT256_SUB_BORROW: u32 = 0x00_01_01_41;
+
+/// Executes the `UINT2048_MUL` precompile.
+pub const UINT2048_MUL: u32 = 0x00_01_01_42;
+
+/// Executes the `UINT4096_MUL` precompile.
+pub const UINT4096_MUL: u32 = 0x00_01_01_43;
+
+/// Executes the `SECP256K1_FP_ADD` precompile.
+pub const SECP256K1_FP_ADD: u32 = 0x00_01_01_44;
+
+/// Executes the `SECP256K1_FP_SUB` precompile.
+pub const SECP256K1_FP_SUB: u32 = 0x00_01_01_45;
+
+/// Executes the `SECP256K1_FP_MUL` precompile.
+pub const SECP256K1_FP_MUL: u32 = 0x00_01_01_46;
+
+/// Executes the `SECP256K1_FN_ADD` precompile.
+pub const SECP256K1_FN_ADD: u32 = 0x00_01_01_47;
+
+/// Executes the `SECP256K1_FN_SUB` precompile.
+pub const SECP256K1_FN_SUB: u32 = 0x00_01_01_48;
+
+/// Executes the `SECP256K1_FN_MUL` precompile.
+pub const SECP256K1_FN_MUL: u32 = 0x00_01_01_49;
+
+/// Executes the `SECP256R1_FP_ADD` precompile.
+pub const SECP256R1_FP_ADD: u32 = 0x00_01_01_4A;
+
+/// Executes the `SECP256R1_FP_SUB` precompile.
+pub const SECP256R1_FP_SUB: u32 = 0x00_01_01_4B;
+
+/// Executes the `SECP256R1_FP_MUL` precompile.
+pub const SECP256R1_FP_MUL: u32 = 0x00_01_01_4C;
+
+/// Executes the `SECP256R1_FN_ADD` precompile.
+pub const SECP256R1_FN_ADD: u32 = 0x00_01_01_4D;
+
+/// Executes the `SECP256R1_FN_SUB` precompile.
+pub const SECP256R1_FN_SUB: u32 = 0x00_01_01_4E;
+
+/// Executes the `SECP256R1_FN_MUL` precompile.
+pub const SECP256R1_FN_MUL: u32 = 0x00_01_01_4F;
+
+/// Executes the `X25519_LADDER_STEP` precompile.
+pub const X25519_LADDER_STEP: u32 = 0x00_01_01_50;
+
+/// Executes the `AES128_ENCRYPT_BLOCK` precompile.
+pub const AES128_ENCRYPT_BLOCK: u32 = 0x00_01_01_51;
+
+/// Executes the `AES256_ENCRYPT_BLOCK` precompile.
+pub const AES256_ENCRYPT_BLOCK: u32 = 0x00_01_01_52;
+
+/// Executes the `CHACHA20_BLOCK` precompile.
+pub const CHACHA20_BLOCK: u32 = 0x00_00_01_53;
+
+/// Executes `PALLAS_ADD`.
+pub const PALLAS_ADD: u32 = 0x00_01_01_54;
+
+/// Executes `PALLAS_DOUBLE`.
+pub const PALLAS_DOUBLE: u32 = 0x00_00_01_55;
+
+/// Executes `VESTA_ADD`.
+pub const VESTA_ADD: u32 = 0x00_01_01_56;
+
+/// Executes `VESTA_DOUBLE`.
+pub const VESTA_DOUBLE: u32 = 0x00_00_01_57;
+
+/// Executes `GRUMPKIN_ADD`.
+pub const GRUMPKIN_ADD: u32 = 0x00_01_01_58;
+
+/// Executes `GRUMPKIN_DOUBLE`.
+pub const GRUMPKIN_DOUBLE: u32 = 0x00_00_01_59;
+
+/// Executes the `SECP256K1_ECRECOVER` precompile.
+pub const SECP256K1_ECRECOVER: u32 = 0x00_01_01_5A;
+
+/// Executes the `SECP256R1_ECRECOVER` precompile.
+pub const SECP256R1_ECRECOVER: u32 = 0x00_01_01_5B;
+
+/// Executes the `SECP256K1_ECDSA_VERIFY` precompile.
+pub const SECP256K1_ECDSA_VERIFY: u32 = 0x00_01_01_5C;
+
+/// Executes the `SECP256R1_ECDSA_VERIFY` precompile.
+pub const SECP256R1_ECDSA_VERIFY: u32 = 0x00_01_01_5D;
+
+/// Executes the precompile registered in user slot 0.
+pub const USER_PRECOMPILE_0: u32 = 0x00_01_01_E0;
+
+/// Executes the precompile registered in user slot 1.
+pub const USER_PRECOMPILE_1: u32 = 0x00_01_01_E1;
+
+/// Executes the precompile registered in user slot 2.
+pub const USER_PRECOMPILE_2: u32 = 0x00_01_01_E2;
+
+/// Executes the precompile registered in user slot 3.
+pub const USER_PRECOMPILE_3: u32 = 0x00_01_01_E3;
@@ -84,3 +84,235 @@ pub extern "C" fn syscall_secp256k1_decompress(point: &mut [u8; 64], is_odd: boo
     #[cfg(not(target_os = "zkvm"))]
     unreachable!()
 }
+
+/// Runs one 64-bit chunk of a fixed-window Secp256k1 scalar multiplication.
+///
+/// The accumulator `p` is multiplied by 2^64 and the window table multiples selected by the scalar
+/// chunk are added to it. `input` points to two scalar words followed by the table
+/// `[P, 2P, ..., 15P]`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `input` are valid pointers to data that is aligned along a
+/// four byte boundary, and that no intermediate sum hits the point at infinity or doubles a point.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_scalar_mul(p: *mut [u32; 16], input: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_SCALAR_MUL,
+            in("a0") p,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Runs one 64-bit chunk of a fixed-window Secp256k1 two-point multi-scalar multiplication.
+///
+/// Like [`syscall_secp256k1_scalar_mul`], but `input` holds two scalar words for each of the two points,
+/// followed by the table `[P, 2P, ..., 15P]` of each point.
+///
+/// ### Safety
+///
+/// The caller must ensure that `p` and `input` are valid pointers to data that is aligned along a
+/// four byte boundary, and that no intermediate sum hits the point at infinity or doubles a point.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_msm(p: *mut [u32; 16], input: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_MSM,
+            in("a0") p,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Secp256k1 base field addition operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_fp_addmod(x: *mut u32, y: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_FP_ADD,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Secp256k1 base field subtraction operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_fp_submod(x: *mut u32, y: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_FP_SUB,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Secp256k1 base field multiplication operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_fp_mulmod(x: *mut u32, y: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_FP_MUL,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Secp256k1 scalar field addition operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_fn_addmod(x: *mut u32, y: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_FN_ADD,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Secp256k1 scalar field subtraction operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_fn_submod(x: *mut u32, y: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_FN_SUB,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Secp256k1 scalar field multiplication operation.
+///
+/// The result is written over the first input.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_fn_mulmod(x: *mut u32, y: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_FN_MUL,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Recovers the Secp256k1 public key of an ECDSA signature.
+///
+/// `input` holds the message hash `z`, the signature `r` and `s` and the recovery id `v`, as
+/// little-endian words. `out` receives the x- and y-coordinates of the public key followed by the
+/// status: `1` if the key was recovered, `0` if `r` is not the x-coordinate of a point of the
+/// curve, and `2` if the precompile could not compute the key and the caller must recover it
+/// another way. The key is zero unless the status is `1`.
+///
+/// ### Safety
+///
+/// The caller must ensure that `out` and `input` are valid pointers to data that is aligned along
+/// a four byte boundary, that `r` is in `[1, n)`, `s` is in `[0, n)` and `v` is `0` or `1`, where
+/// `n` is the order of the curve.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_ecrecover(out: *mut [u32; 17], input: *const [u32; 25]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_ECRECOVER,
+            in("a0") out,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Verifies a Secp256k1 ECDSA signature.
+///
+/// `input` holds the x- and y-coordinates of the public key, the message hash `z` and the
+/// signature `r` and `s`, as little-endian words. `out` receives the status: `1` if the signature
+/// is valid, `0` if it is not, and `2` if the precompile could not decide and the caller must
+/// verify the signature another way.
+///
+/// ### Safety
+///
+/// The caller must ensure that `out` and `input` are valid pointers to data that is aligned along
+/// a four byte boundary, that the public key is a reduced point of the curve, that `r` is in
+/// `[0, n)` and that `s` is in `[1, n)`, where `n` is the order of the curve.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_secp256k1_ecdsa_verify(out: *mut u32, input: *const [u32; 40]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::SECP256K1_ECDSA_VERIFY,
+            in("a0") out,
+            in("a1") input,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
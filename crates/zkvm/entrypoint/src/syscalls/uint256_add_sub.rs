@@ -0,0 +1,56 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Uint256 addition with carry.
+///
+/// The first eight words of `x` hold the first input and the ninth word holds the incoming carry,
+/// which must be 0 or 1. The sum is written over the first input and the outgoing carry over the
+/// carry word.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_add_with_carry(x: *mut [u32; 9], y: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT256_ADD_CARRY,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Uint256 subtraction with borrow.
+///
+/// The first eight words of `x` hold the first input and the ninth word holds the incoming borrow,
+/// which must be 0 or 1. The difference is written over the first input and the outgoing borrow
+/// over the borrow word.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint256_sub_with_borrow(x: *mut [u32; 9], y: *const [u32; 8]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT256_SUB_BORROW,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
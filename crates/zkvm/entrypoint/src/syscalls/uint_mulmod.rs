@@ -0,0 +1,56 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Uint2048 modular multiplication.
+///
+/// The modulus is stored right after `y`, and a zero modulus stands for 2^2048. The result is
+/// written over the first input. The product `x * y` must be smaller than `modulus * 2^2048`,
+/// which holds whenever one of the inputs is smaller than the modulus.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint2048_mulmod(x: *mut [u32; 64], y: *const [u32; 64]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT2048_MUL,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
+
+/// Uint4096 modular multiplication.
+///
+/// The modulus is stored right after `y`, and a zero modulus stands for 2^4096. The result is
+/// written over the first input. The product `x * y` must be smaller than `modulus * 2^4096`,
+/// which holds whenever one of the inputs is smaller than the modulus.
+///
+/// ### Safety
+///
+/// The caller must ensure that `x` and `y` are valid pointers to data that is aligned along a four
+/// byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_uint4096_mulmod(x: *mut [u32; 128], y: *const [u32; 128]) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") crate::syscalls::UINT4096_MUL,
+            in("a0") x,
+            in("a1") y,
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,29 @@
+#[cfg(target_os = "zkvm")]
+use core::arch::asm;
+
+/// Executes the user precompile registered in the slot `code`, which must be one of
+/// [`crate::syscalls::USER_PRECOMPILE_0`] through [`crate::syscalls::USER_PRECOMPILE_3`].
+///
+/// The meaning of `arg1` and `arg2` is defined by the precompile. By convention, `arg1` points to
+/// the output and `arg2` to the input.
+///
+/// ### Safety
+///
+/// The caller must ensure that the pointers are valid for the accesses the precompile performs
+/// and are aligned along a four byte boundary.
+#[allow(unused_variables)]
+#[no_mangle]
+pub extern "C" fn syscall_user_precompile(code: u32, arg1: *mut u32, arg2: *const u32) {
+    #[cfg(target_os = "zkvm")]
+    unsafe {
+        asm!(
+            "ecall",
+            in("t0") code,
+            in("a0") arg1,
+            in("a1") arg2
+        );
+    }
+
+    #[cfg(not(target_os = "zkvm"))]
+    unreachable!()
+}
@@ -0,0 +1,206 @@
+//! Multi-precision unsigned integer arithmetic on top of the `UINT256_ADD_CARRY`,
+//! `UINT256_SUB_BORROW`, `UINT256_MUL`, `UINT2048_MUL` and `UINT4096_MUL` precompiles.
+//!
+//! Integers are little-endian arrays of `u32` words, except for [`mod_exp`] which takes big-endian
+//! bytes like the EVM `MODEXP` precompile.
+
+use core::cmp::Ordering;
+
+use crate::{
+    syscall_uint2048_mulmod, syscall_uint256_add_with_carry, syscall_uint256_mulmod,
+    syscall_uint256_sub_with_borrow, syscall_uint4096_mulmod,
+};
+
+/// The second input of a modular multiplication, laid out as the precompiles expect it.
+#[repr(C)]
+struct MulModInput<const W: usize> {
+    y: [u32; W],
+    modulus: [u32; W],
+}
+
+/// Adds `x`, `y` and the incoming carry, returning the sum and the outgoing carry.
+pub fn add_with_carry(x: &[u32; 8], y: &[u32; 8], carry: bool) -> ([u32; 8], bool) {
+    let mut x_and_carry = [0u32; 9];
+    x_and_carry[..8].copy_from_slice(x);
+    x_and_carry[8] = carry as u32;
+    unsafe {
+        syscall_uint256_add_with_carry(&mut x_and_carry, y);
+    }
+    (x_and_carry[..8].try_into().unwrap(), x_and_carry[8] == 1)
+}
+
+/// Subtracts `y` and the incoming borrow from `x`, returning the difference and the outgoing
+/// borrow.
+pub fn sub_with_borrow(x: &[u32; 8], y: &[u32; 8], borrow: bool) -> ([u32; 8], bool) {
+    let mut x_and_borrow = [0u32; 9];
+    x_and_borrow[..8].copy_from_slice(x);
+    x_and_borrow[8] = borrow as u32;
+    unsafe {
+        syscall_uint256_sub_with_borrow(&mut x_and_borrow, y);
+    }
+    (x_and_borrow[..8].try_into().unwrap(), x_and_borrow[8] == 1)
+}
+
+/// Sets `x` to `x + y` modulo `2^(32 * x.len())` and returns the carry.
+///
+/// Panics if the lengths differ or are not a multiple of eight words.
+pub fn add_assign(x: &mut [u32], y: &[u32]) -> bool {
+    assert_eq!(x.len(), y.len());
+    assert_eq!(x.len() % 8, 0, "the length must be a multiple of eight words");
+    let mut carry = false;
+    for (x, y) in x.chunks_exact_mut(8).zip(y.chunks_exact(8)) {
+        let (sum, carry_out) = add_with_carry(x.try_into().unwrap(), y.try_into().unwrap(), carry);
+        x.copy_from_slice(&sum);
+        carry = carry_out;
+    }
+    carry
+}
+
+/// Sets `x` to `x - y` modulo `2^(32 * x.len())` and returns the borrow.
+///
+/// Panics if the lengths differ or are not a multiple of eight words.
+pub fn sub_assign(x: &mut [u32], y: &[u32]) -> bool {
+    assert_eq!(x.len(), y.len());
+    assert_eq!(x.len() % 8, 0, "the length must be a multiple of eight words");
+    let mut borrow = false;
+    for (x, y) in x.chunks_exact_mut(8).zip(y.chunks_exact(8)) {
+        let (diff, borrow_out) =
+            sub_with_borrow(x.try_into().unwrap(), y.try_into().unwrap(), borrow);
+        x.copy_from_slice(&diff);
+        borrow = borrow_out;
+    }
+    borrow
+}
+
+/// Computes `x * y % modulus` with the `UINT256_MUL` precompile, where a zero modulus stands for
+/// `2^256`.
+///
+/// The product must be smaller than `modulus * 2^256`, which holds whenever one of the inputs is
+/// smaller than the modulus.
+pub fn mul_mod_256(x: &[u32; 8], y: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    let mut result = *x;
+    let input = MulModInput { y: *y, modulus: *modulus };
+    unsafe {
+        syscall_uint256_mulmod(&mut result, &input as *const MulModInput<8> as *const [u32; 8]);
+    }
+    result
+}
+
+/// Computes `x * y % modulus` with the `UINT2048_MUL` precompile, where a zero modulus stands for
+/// `2^2048`.
+///
+/// The product must be smaller than `modulus * 2^2048`, which holds whenever one of the inputs is
+/// smaller than the modulus.
+pub fn mul_mod_2048(x: &[u32; 64], y: &[u32; 64], modulus: &[u32; 64]) -> [u32; 64] {
+    let mut result = *x;
+    let input = MulModInput { y: *y, modulus: *modulus };
+    unsafe {
+        syscall_uint2048_mulmod(&mut result, &input as *const MulModInput<64> as *const [u32; 64]);
+    }
+    result
+}
+
+/// Computes `x * y % modulus` with the `UINT4096_MUL` precompile, where a zero modulus stands for
+/// `2^4096`.
+///
+/// The product must be smaller than `modulus * 2^4096`, which holds whenever one of the inputs is
+/// smaller than the modulus.
+pub fn mul_mod_4096(x: &[u32; 128], y: &[u32; 128], modulus: &[u32; 128]) -> [u32; 128] {
+    let mut result = *x;
+    let input = MulModInput { y: *y, modulus: *modulus };
+    unsafe {
+        syscall_uint4096_mulmod(
+            &mut result,
+            &input as *const MulModInput<128> as *const [u32; 128],
+        );
+    }
+    result
+}
+
+/// Computes `base^exponent % modulus` for big-endian inputs of arbitrary length, with the same
+/// semantics as the EVM `MODEXP` precompile.
+///
+/// The result has the length of `modulus`. Moduli of up to 4096 bits are supported, and the
+/// smallest of the 256, 2048 and 4096-bit multiplication precompiles that fits is used.
+pub fn mod_exp(base: &[u8], exponent: &[u8], modulus: &[u8]) -> Vec<u8> {
+    let modulus_len = modulus.iter().skip_while(|&&byte| byte == 0).count();
+    let result = match modulus_len {
+        0 => return vec![0; modulus.len()],
+        1..=32 => mod_exp_words(base, exponent, &words_from_be(modulus), mul_mod_256).to_vec(),
+        33..=256 => mod_exp_words(base, exponent, &words_from_be(modulus), mul_mod_2048).to_vec(),
+        257..=512 => mod_exp_words(base, exponent, &words_from_be(modulus), mul_mod_4096).to_vec(),
+        _ => panic!("moduli of more than 4096 bits are not supported"),
+    };
+
+    let mut bytes = vec![0u8; modulus.len().saturating_sub(4 * result.len())];
+    let result_bytes = result.iter().rev().flat_map(|word| word.to_be_bytes()).collect::<Vec<_>>();
+    bytes.extend_from_slice(&result_bytes[result_bytes.len().saturating_sub(modulus.len())..]);
+    bytes
+}
+
+type MulMod<const W: usize> = fn(&[u32; W], &[u32; W], &[u32; W]) -> [u32; W];
+
+/// Square-and-multiply over `W`-word integers, for a nonzero modulus.
+fn mod_exp_words<const W: usize>(
+    base: &[u8],
+    exponent: &[u8],
+    modulus: &[u32; W],
+    mul_mod: MulMod<W>,
+) -> [u32; W] {
+    let mut one = [0u32; W];
+    one[0] = 1;
+
+    let base = reduce(base, modulus, mul_mod);
+    let mut result = mul_mod(&one, &one, modulus);
+    for byte in exponent.iter().skip_while(|&&byte| byte == 0) {
+        for bit in (0..8).rev() {
+            result = mul_mod(&result, &result, modulus);
+            if (byte >> bit) & 1 == 1 {
+                result = mul_mod(&result, &base, modulus);
+            }
+        }
+    }
+    result
+}
+
+/// Reduces a big-endian integer of arbitrary length modulo a nonzero `W`-word modulus.
+fn reduce<const W: usize>(bytes: &[u8], modulus: &[u32; W], mul_mod: MulMod<W>) -> [u32; W] {
+    let mut one = [0u32; W];
+    one[0] = 1;
+
+    // Multiplying by one reduces any `W`-word value, as the product is smaller than `2^(32 * W)`.
+    let mut chunks = bytes.rchunks(4 * W).rev().map(|chunk| words_from_be::<W>(chunk));
+    let mut result = mul_mod(&chunks.next().unwrap_or([0; W]), &one, modulus);
+
+    // Horner's rule in base `2^(32 * W)` for the remaining chunks.
+    let mut radix = None;
+    for chunk in chunks {
+        // The radix `2^(32 * W) mod modulus`, computed from `2^(32 * W) - modulus`.
+        let radix = *radix.get_or_insert_with(|| {
+            let mut neg_modulus = [0u32; W];
+            sub_assign(&mut neg_modulus, modulus);
+            mul_mod(&neg_modulus, &one, modulus)
+        });
+        result = mul_mod(&result, &radix, modulus);
+        let chunk = mul_mod(&chunk, &one, modulus);
+        if add_assign(&mut result, &chunk) || cmp(&result, modulus) != Ordering::Less {
+            sub_assign(&mut result, modulus);
+        }
+    }
+    result
+}
+
+/// Converts big-endian bytes to little-endian words, dropping bytes beyond `4 * W`.
+fn words_from_be<const W: usize>(bytes: &[u8]) -> [u32; W] {
+    let mut words = [0u32; W];
+    for (word, chunk) in words.iter_mut().zip(bytes.rchunks(4)) {
+        let mut word_bytes = [0u8; 4];
+        word_bytes[4 - chunk.len()..].copy_from_slice(chunk);
+        *word = u32::from_be_bytes(word_bytes);
+    }
+    words
+}
+
+fn cmp(x: &[u32], y: &[u32]) -> Ordering {
+    x.iter().rev().cmp(y.iter().rev())
+}
@@ -2,7 +2,8 @@ use std::io::ErrorKind;
 
 use crate::{
     syscall_bls12381_add, syscall_bls12381_decompress, syscall_bls12381_double,
-    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+    syscall_bls12381_msm, syscall_bls12381_scalar_mul,
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassMsmPoint, WeierstrassPoint},
 };
 
 /// The number of limbs in [Bls12381AffinePoint].
@@ -83,6 +84,35 @@ impl AffinePoint<N> for Bls12381Point {
     }
 }
 
+impl WeierstrassMsmPoint<N> for Bls12381Point {
+    /// The first curve point whose x-coordinate is at least
+    /// `sha256("monerochan msm offset bls12381") mod p`.
+    const MSM_OFFSET: [u32; N] = [
+        775181384, 679753706, 2921942625, 13385204, 1176532511, 1590843647, 3066059331, 2907797702,
+        0, 0, 0, 0, 18073029, 2187040325, 719658504, 1588589008, 2683270777, 4089068969,
+        2519147037, 3096613149, 2411979953, 4205284177, 1018283250, 255659821,
+    ];
+
+    const MSM_OFFSET_CORRECTION: [u32; N] = [
+        3533975442, 1648175417, 1879936754, 1238502127, 2617166221, 3965671193, 869594163,
+        499633301, 1215519596, 3776671188, 3780287670, 283451132, 2771197740, 3394462521,
+        3884210964, 1124436998, 3740461208, 2705028877, 560211014, 1944775495, 3931674628,
+        1211726302, 3286493750, 318908622,
+    ];
+
+    fn scalar_mul_chunk(acc: &mut [u32; N], input: &[u32]) {
+        unsafe {
+            syscall_bls12381_scalar_mul(acc, input.as_ptr());
+        }
+    }
+
+    fn msm_chunk(acc: &mut [u32; N], input: &[u32]) {
+        unsafe {
+            syscall_bls12381_msm(acc, input.as_ptr());
+        }
+    }
+}
+
 /// Decompresses a compressed public key using bls12381_decompress precompile.
 pub fn decompress_pubkey(compressed_key: &[u8; 48]) -> Result<[u8; 96], ErrorKind> {
     let mut decompressed_key = [0u8; 96];
@@ -1,6 +1,6 @@
 use crate::{
-    syscall_bn254_add, syscall_bn254_double,
-    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+    syscall_bn254_add, syscall_bn254_double, syscall_bn254_msm, syscall_bn254_scalar_mul,
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassMsmPoint, WeierstrassPoint},
 };
 
 /// The number of limbs in [Bn254AffinePoint].
@@ -75,3 +75,31 @@ impl AffinePoint<N> for Bn254Point {
         }
     }
 }
+
+impl WeierstrassMsmPoint<N> for Bn254Point {
+    /// The first curve point whose x-coordinate is at least
+    /// `sha256("monerochan msm offset bn254") mod p`.
+    const MSM_OFFSET: [u32; N] = [
+        3859974580, 145535133, 3288110203, 3830561895, 827515721, 2625767503, 2065663612,
+        126636140, 1465378500, 2075159331, 1106150516, 3410766364, 392845824, 2005897362,
+        2524089396, 477227669,
+    ];
+
+    const MSM_OFFSET_CORRECTION: [u32; N] = [
+        1689131932, 2116494430, 451561689, 1829238973, 1076664885, 589657155, 3551208614,
+        455685016, 2188577861, 3929458281, 3341750153, 3988583680, 832106513, 1717659541,
+        2090121558, 116363435,
+    ];
+
+    fn scalar_mul_chunk(acc: &mut [u32; N], input: &[u32]) {
+        unsafe {
+            syscall_bn254_scalar_mul(acc, input.as_ptr());
+        }
+    }
+
+    fn msm_chunk(acc: &mut [u32; N], input: &[u32]) {
+        unsafe {
+            syscall_bn254_msm(acc, input.as_ptr());
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! The ChaCha20 stream cipher of RFC 8439, with the block function run by the `CHACHA20_BLOCK`
+//! precompile.
+
+use crate::syscall_chacha20_block;
+
+/// The constant words `"expand 32-byte k"` at the start of the state.
+const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+/// Computes the 64 byte keystream block for the given key, block counter and nonce.
+pub fn chacha20_block(key: &[u8; 32], counter: u32, nonce: &[u8; 12]) -> [u8; 64] {
+    let mut state = [0u32; 16];
+    state[..4].copy_from_slice(&CONSTANTS);
+    for (word, chunk) in state[4..12].iter_mut().zip(key.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    state[12] = counter;
+    for (word, chunk) in state[13..].iter_mut().zip(nonce.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    unsafe {
+        syscall_chacha20_block(&mut state);
+    }
+
+    let mut block = [0u8; 64];
+    for (chunk, word) in block.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    block
+}
+
+/// Encrypts or decrypts `data` in place by xoring it with the keystream, starting at the given
+/// block counter.
+pub fn chacha20_apply_keystream(key: &[u8; 32], counter: u32, nonce: &[u8; 12], data: &mut [u8]) {
+    for (i, chunk) in data.chunks_mut(64).enumerate() {
+        let block = chacha20_block(key, counter.wrapping_add(i as u32), nonce);
+        for (byte, key_byte) in chunk.iter_mut().zip(block) {
+            *byte ^= key_byte;
+        }
+    }
+}
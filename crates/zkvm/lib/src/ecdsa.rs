@@ -11,7 +11,7 @@
 //! In summary, MONEROCHAN overrides curve arithmetic entirely, and patches upstream field operations
 //! to be more efficient in the VM, such as `sqrt` or `inverse`.
 
-use crate::utils::AffinePoint as MONEROCHANAffinePointTrait;
+use crate::utils::{AffinePoint as MONEROCHANAffinePointTrait, WeierstrassMsmPoint};
 
 use elliptic_curve::{
     ff, generic_array::typenum::consts::U32, subtle::CtOption, CurveArithmetic, FieldBytes,
@@ -26,6 +26,9 @@ pub use affine::AffinePoint;
 pub mod projective;
 pub use projective::ProjectivePoint;
 
+/// ECDSA key recovery and signature verification with the MONEROCHAN precompiles.
+pub mod signature;
+
 /// NOTE: The only supported ECDSA curves are secp256k1 and secp256r1, which both
 /// have 8 limbs in their field elements.
 const POINT_LIMBS: usize = 8 * 2;
@@ -82,8 +85,16 @@ pub trait Field<C: ECDSACurve>: ff::PrimeField {
 pub type FieldElement<C> = <C as ECDSACurve>::FieldElement;
 
 /// Alias trait for the [`MONEROCHANAffinePointTrait`] with 32 byte field elements.
+///
+/// Scalar multiplications go through the curve's [`WeierstrassMsmPoint`] precompiles.
 pub trait ECDSAPoint:
-    MONEROCHANAffinePointTrait<POINT_LIMBS> + Clone + Copy + Debug + Send + Sync
+    MONEROCHANAffinePointTrait<POINT_LIMBS>
+    + WeierstrassMsmPoint<POINT_LIMBS>
+    + Clone
+    + Copy
+    + Debug
+    + Send
+    + Sync
 {
     #[inline]
     fn from(x: &[u8], y: &[u8]) -> Self {
@@ -92,7 +103,13 @@ pub trait ECDSAPoint:
 }
 
 impl<P> ECDSAPoint for P where
-    P: MONEROCHANAffinePointTrait<POINT_LIMBS> + Clone + Copy + Debug + Send + Sync
+    P: MONEROCHANAffinePointTrait<POINT_LIMBS>
+        + WeierstrassMsmPoint<POINT_LIMBS>
+        + Clone
+        + Copy
+        + Debug
+        + Send
+        + Sync
 {
 }
 
@@ -7,6 +7,7 @@
 //! of projective arithmetic for performance.
 
 use super::{AffinePoint, ECDSACurve, MONEROCHANAffinePointTrait};
+use crate::utils::WeierstrassMsmPoint;
 
 use elliptic_curve::{
     group::{cofactor::CofactorGroup, prime::PrimeGroup},
@@ -132,14 +133,10 @@ impl<C: ECDSACurve> MulByGenerator for ProjectivePoint<C> {}
 
 impl<C: ECDSACurve> LinearCombination for ProjectivePoint<C> {
     fn lincomb(x: &Self, k: &Self::Scalar, y: &Self, l: &Self::Scalar) -> Self {
-        let x = x.to_zkvm_point();
-        let y = y.to_zkvm_point();
+        let points = [x.to_zkvm_point(), y.to_zkvm_point()];
+        let scalars = [be_bytes_to_le_words(k.to_repr()), be_bytes_to_le_words(l.to_repr())];
 
-        let a_bits_le = be_bytes_to_le_bits(k.to_repr().as_ref());
-        let b_bits_le = be_bytes_to_le_bits(l.to_repr().as_ref());
-
-        let monerochan_point =
-            C::MONEROCHANAffinePoint::multi_scalar_multiplication(&a_bits_le, x, &b_bits_le, y);
+        let monerochan_point = C::MONEROCHANAffinePoint::fixed_window_msm(&scalars, &points);
 
         Self::from_zkvm_point(monerochan_point)
     }
@@ -152,7 +149,8 @@ impl<C: ECDSACurve, T: Borrow<C::Scalar>> Mul<T> for ProjectivePoint<C> {
 
     fn mul(mut self, rhs: T) -> Self::Output {
         let monerochan_point = self.as_mut_zkvm_point();
-        monerochan_point.mul_assign(&be_bytes_to_le_words(rhs.borrow().to_repr()));
+        *monerochan_point =
+            monerochan_point.fixed_window_mul(&be_bytes_to_le_words(rhs.borrow().to_repr()));
 
         self
     }
@@ -160,7 +158,9 @@ impl<C: ECDSACurve, T: Borrow<C::Scalar>> Mul<T> for ProjectivePoint<C> {
 
 impl<C: ECDSACurve, T: Borrow<C::Scalar>> MulAssign<T> for ProjectivePoint<C> {
     fn mul_assign(&mut self, rhs: T) {
-        self.as_mut_zkvm_point().mul_assign(&be_bytes_to_le_words(rhs.borrow().to_repr()));
+        let monerochan_point = self.as_mut_zkvm_point();
+        *monerochan_point =
+            monerochan_point.fixed_window_mul(&be_bytes_to_le_words(rhs.borrow().to_repr()));
     }
 }
 
@@ -405,18 +405,3 @@ fn be_bytes_to_le_words<T: AsMut<[u8]>>(mut bytes: T) -> [u32; 8] {
     let mut iter = bytes.chunks(4).map(|b| u32::from_le_bytes(b.try_into().unwrap()));
     core::array::from_fn(|_| iter.next().unwrap())
 }
-
-/// Convert big-endian bytes with the most significant bit first to little-endian bytes with the
-/// least significant bit first. Panics: If the bytes have len > 32.
-#[inline]
-fn be_bytes_to_le_bits(be_bytes: &[u8]) -> [bool; 256] {
-    let mut bits = [false; 256];
-    // Reverse the byte order to little-endian.
-    for (i, &byte) in be_bytes.iter().rev().enumerate() {
-        for j in 0..8 {
-            // Flip the bit order so the least significant bit is now the first bit of the chunk.
-            bits[i * 8 + j] = ((byte >> j) & 1) == 1;
-        }
-    }
-    bits
-}
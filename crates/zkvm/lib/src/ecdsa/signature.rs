@@ -0,0 +1,214 @@
+//! ECDSA public key recovery and signature verification with the ecrecover and ECDSA verify
+//! precompiles.
+//!
+//! The precompiles run a double-and-add ladder with incomplete affine addition formulas. When the
+//! ladder meets one of their exceptional cases, which never happens for honestly generated
+//! signatures, the precompile reports it and the result is computed again with the fixed-window
+//! MSM precompiles.
+
+use core::cmp::Ordering;
+
+use crate::{
+    bigint::{add_with_carry, mod_exp, mul_mod_256, sub_with_borrow},
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassMsmPoint},
+};
+
+/// The number of limbs of the points with ECDSA precompiles.
+const N: usize = 16;
+
+/// The status of the precompiles when the key was recovered or the signature verified.
+const STATUS_SUCCESS: u32 = 1;
+
+/// The status of the precompiles when the result must be computed another way.
+const STATUS_FALLBACK: u32 = 2;
+
+/// A curve point with ecrecover and ECDSA verify precompiles.
+///
+/// All constants are little-endian words.
+pub trait EcdsaPrecompilePoint: WeierstrassMsmPoint<N> + Copy {
+    /// The modulus of the base field.
+    const BASE_MODULUS: [u32; 8];
+
+    /// The order of the curve, which must be smaller than the base field modulus but larger than
+    /// half of it.
+    const ORDER: [u32; 8];
+
+    /// The `a` coefficient in the curve equation.
+    const EQUATION_A: [u32; 8];
+
+    /// The `b` coefficient in the curve equation.
+    const EQUATION_B: [u32; 8];
+
+    /// Runs the ecrecover syscall of the curve.
+    fn ecrecover_syscall(out: &mut [u32; 17], input: &[u32; 25]);
+
+    /// Runs the ECDSA verify syscall of the curve.
+    fn ecdsa_verify_syscall(out: &mut u32, input: &[u32; 40]);
+
+    /// Returns the point with the given x-coordinate and y-coordinate parity, which must exist.
+    fn decompress(x: &[u32; 8], is_odd: bool) -> Self;
+}
+
+/// Recovers the public key of an ECDSA signature.
+///
+/// The message hash and the signature `r || s` are big-endian, and the recovery id is the parity
+/// of the y-coordinate of the point with x-coordinate `r`. Returns the public key as the
+/// big-endian coordinates `x || y`, or `None` if the signature does not recover any key.
+pub fn ecrecover<P: EcdsaPrecompilePoint>(
+    msg_hash: &[u8; 32],
+    signature: &[u8; 64],
+    recovery_id: u8,
+) -> Option<[u8; 64]> {
+    let z = words_from_be(msg_hash);
+    let r = words_from_be(&signature[..32]);
+    let s = words_from_be(&signature[32..]);
+    if recovery_id > 1 || !is_scalar::<P>(&r) || !is_scalar::<P>(&s) {
+        return None;
+    }
+
+    let mut input = [0u32; 25];
+    input[..8].copy_from_slice(&z);
+    input[8..16].copy_from_slice(&r);
+    input[16..24].copy_from_slice(&s);
+    input[24] = recovery_id as u32;
+    let mut out = [0u32; 17];
+    P::ecrecover_syscall(&mut out, &input);
+
+    let key = match out[16] {
+        STATUS_SUCCESS => P::new(out[..16].try_into().unwrap()),
+        STATUS_FALLBACK => {
+            // The key is `[-z / r] G + [s / r] R`.
+            let r_inv = inverse_mod_order::<P>(&r);
+            let u1 = neg_mod(&mul_mod_256(&z, &r_inv, &P::ORDER), &P::ORDER);
+            let u2 = mul_mod_256(&s, &r_inv, &P::ORDER);
+            let point = P::decompress(&r, recovery_id == 1);
+            let key = P::fixed_window_msm(&[u1, u2], &[P::new(P::GENERATOR), point]);
+            if key.is_infinity() {
+                return None;
+            }
+            key
+        }
+        _ => return None,
+    };
+
+    let limbs = key.limbs_ref();
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&words_to_be(limbs[..8].try_into().unwrap()));
+    bytes[32..].copy_from_slice(&words_to_be(limbs[8..].try_into().unwrap()));
+    Some(bytes)
+}
+
+/// Verifies an ECDSA signature.
+///
+/// The public key `x || y`, the message hash and the signature `r || s` are big-endian. Returns
+/// `false` if the public key is not a point of the curve.
+pub fn ecdsa_verify<P: EcdsaPrecompilePoint>(
+    public_key: &[u8; 64],
+    msg_hash: &[u8; 32],
+    signature: &[u8; 64],
+) -> bool {
+    let x = words_from_be(&public_key[..32]);
+    let y = words_from_be(&public_key[32..]);
+    let z = words_from_be(msg_hash);
+    let r = words_from_be(&signature[..32]);
+    let s = words_from_be(&signature[32..]);
+    if !is_scalar::<P>(&r) || !is_scalar::<P>(&s) || !is_on_curve::<P>(&x, &y) {
+        return false;
+    }
+
+    let mut input = [0u32; 40];
+    input[..8].copy_from_slice(&x);
+    input[8..16].copy_from_slice(&y);
+    input[16..24].copy_from_slice(&z);
+    input[24..32].copy_from_slice(&r);
+    input[32..].copy_from_slice(&s);
+    let mut status = 0;
+    P::ecdsa_verify_syscall(&mut status, &input);
+
+    if status != STATUS_FALLBACK {
+        return status == STATUS_SUCCESS;
+    }
+
+    // The signature is valid if the x-coordinate of `[z / s] G + [r / s] P` is `r` modulo the
+    // order.
+    let s_inv = inverse_mod_order::<P>(&s);
+    let u1 = mul_mod_256(&z, &s_inv, &P::ORDER);
+    let u2 = mul_mod_256(&r, &s_inv, &P::ORDER);
+    let key = P::new(input[..16].try_into().unwrap());
+    let point = P::fixed_window_msm(&[u1, u2], &[P::new(P::GENERATOR), key]);
+    if point.is_infinity() {
+        return false;
+    }
+    let mut point_x: [u32; 8] = point.limbs_ref()[..8].try_into().unwrap();
+    if cmp(&point_x, &P::ORDER) != Ordering::Less {
+        point_x = sub_with_borrow(&point_x, &P::ORDER, false).0;
+    }
+    point_x == r
+}
+
+/// Returns whether `x` is in `[1, n)`, where `n` is the order of the curve.
+fn is_scalar<P: EcdsaPrecompilePoint>(x: &[u32; 8]) -> bool {
+    x.iter().any(|&word| word != 0) && cmp(x, &P::ORDER) == Ordering::Less
+}
+
+/// Returns whether `(x, y)` is a point of the curve with reduced coordinates.
+fn is_on_curve<P: EcdsaPrecompilePoint>(x: &[u32; 8], y: &[u32; 8]) -> bool {
+    let modulus = &P::BASE_MODULUS;
+    if cmp(x, modulus) != Ordering::Less || cmp(y, modulus) != Ordering::Less {
+        return false;
+    }
+
+    let x_3 = mul_mod_256(&mul_mod_256(x, x, modulus), x, modulus);
+    let ax = mul_mod_256(&P::EQUATION_A, x, modulus);
+    let rhs = add_mod(&add_mod(&x_3, &ax, modulus), &P::EQUATION_B, modulus);
+    mul_mod_256(y, y, modulus) == rhs
+}
+
+/// Computes `x^-1 mod n`, where `n` is the prime order of the curve.
+fn inverse_mod_order<P: EcdsaPrecompilePoint>(x: &[u32; 8]) -> [u32; 8] {
+    let mut two = [0u32; 8];
+    two[0] = 2;
+    let exponent = sub_with_borrow(&P::ORDER, &two, false).0;
+    let inverse = mod_exp(&words_to_be(x), &words_to_be(&exponent), &words_to_be(&P::ORDER));
+    words_from_be(&inverse)
+}
+
+/// Computes `x + y mod modulus` for reduced `x` and `y`.
+fn add_mod(x: &[u32; 8], y: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    let (sum, carry) = add_with_carry(x, y, false);
+    if carry || cmp(&sum, modulus) != Ordering::Less {
+        sub_with_borrow(&sum, modulus, false).0
+    } else {
+        sum
+    }
+}
+
+/// Computes `-x mod modulus` for a reduced `x`.
+fn neg_mod(x: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    if x.iter().all(|&word| word == 0) {
+        return *x;
+    }
+    sub_with_borrow(modulus, x, false).0
+}
+
+/// Converts 32 big-endian bytes to little-endian words.
+fn words_from_be(bytes: &[u8]) -> [u32; 8] {
+    let mut words = [0u32; 8];
+    for (word, chunk) in words.iter_mut().zip(bytes.rchunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    words
+}
+
+/// Converts little-endian words to 32 big-endian bytes.
+fn words_to_be(words: &[u32; 8]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, word) in bytes.rchunks_exact_mut(4).zip(words) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    bytes
+}
+
+fn cmp(x: &[u32; 8], y: &[u32; 8]) -> Ordering {
+    x.iter().rev().cmp(y.iter().rev())
+}
@@ -1,4 +1,6 @@
-use crate::{syscall_ed_add, utils::AffinePoint};
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::{syscall_ed_add, syscall_uint256_mulmod, utils::AffinePoint};
 
 /// The number of limbs in [Ed25519AffinePoint].
 pub const N: usize = 16;
@@ -62,4 +64,461 @@ impl Ed25519AffinePoint {
     pub fn identity() -> Self {
         Self(Self::IDENTITY)
     }
+
+    /// Creates a point from its affine coordinates.
+    pub fn from_coordinates(x: Ed25519FieldElement, y: Ed25519FieldElement) -> Self {
+        let mut limbs = [0u32; N];
+        limbs[..N / 2].copy_from_slice(&x.0);
+        limbs[N / 2..].copy_from_slice(&y.0);
+        Self(limbs)
+    }
+
+    /// The x-coordinate of the point.
+    pub fn x(&self) -> Ed25519FieldElement {
+        Ed25519FieldElement(self.0[..N / 2].try_into().unwrap())
+    }
+
+    /// The y-coordinate of the point.
+    pub fn y(&self) -> Ed25519FieldElement {
+        Ed25519FieldElement(self.0[N / 2..].try_into().unwrap())
+    }
+
+    /// Encodes the point as its y-coordinate with the sign of x in the top bit.
+    pub fn compress(&self) -> [u8; 32] {
+        let mut bytes = self.y().to_bytes();
+        bytes[31] |= (self.x().is_negative() as u8) << 7;
+        bytes
+    }
+
+    /// Decodes a compressed point, returning `None` if the encoding is not canonical or not on the
+    /// curve.
+    pub fn decompress(bytes: &[u8; 32]) -> Option<Self> {
+        let sign = bytes[31] >> 7;
+        let mut y_bytes = *bytes;
+        y_bytes[31] &= 0x7f;
+        let y = Ed25519FieldElement::from_canonical_bytes(&y_bytes)?;
+
+        // x^2 = (y^2 - 1) / (d * y^2 + 1).
+        let yy = y.square();
+        let u = yy - Ed25519FieldElement::ONE;
+        let v = Ed25519FieldElement::D * yy + Ed25519FieldElement::ONE;
+        let mut x = Ed25519FieldElement::sqrt_ratio_candidate(&u, &v);
+        let vxx = v * x.square();
+        if vxx != u {
+            if vxx != -u {
+                return None;
+            }
+            x = x * Ed25519FieldElement::SQRT_M1;
+        }
+
+        if x.is_zero() && sign == 1 {
+            return None;
+        }
+        if x.is_negative() as u8 != sign {
+            x = -x;
+        }
+        Some(Self::from_coordinates(x, y))
+    }
+
+    /// Multiplies the point by the cofactor 8.
+    pub fn mul_by_cofactor(&mut self) {
+        for _ in 0..3 {
+            self.double();
+        }
+    }
+
+    /// Multiplies the point by the given little endian scalar.
+    pub fn mul_scalar(&self, scalar: &[u8; 32]) -> Self {
+        let mut res = *self;
+        res.mul_assign(&crate::utils::bytes_to_words_le(scalar));
+        res
+    }
+
+    /// Computes the sum of `scalars[i] * points[i]` for little endian scalars.
+    ///
+    /// Small inputs share the doublings between all the points, larger ones use Pippenger's bucket
+    /// method.
+    pub fn multi_scalar_mul(scalars: &[[u8; 32]], points: &[Self]) -> Self {
+        assert_eq!(scalars.len(), points.len());
+        if points.len() >= PIPPENGER_THRESHOLD {
+            return Self::pippenger(scalars, points);
+        }
+
+        let mut res = Self::identity();
+        for bit in (0..256).rev() {
+            res.double();
+            for (scalar, point) in scalars.iter().zip(points.iter()) {
+                if (scalar[bit / 8] >> (bit % 8)) & 1 == 1 {
+                    res.add_assign(point);
+                }
+            }
+        }
+        res
+    }
+
+    /// Pippenger's bucket method: the scalars are split into windows of `c` bits, and in each
+    /// window the points are first added into the bucket of their digit, after which
+    /// `sum_k k * bucket_k` is accumulated with two running sums.
+    fn pippenger(scalars: &[[u8; 32]], points: &[Self]) -> Self {
+        let c = (points.len().ilog2() as usize).saturating_sub(2).clamp(3, 10);
+        let mut buckets = vec![Self::identity(); (1 << c) - 1];
+
+        let mut res = Self::identity();
+        for start in (0..256).step_by(c).rev() {
+            if !res.is_identity() {
+                for _ in 0..c {
+                    res.double();
+                }
+            }
+
+            buckets.iter_mut().for_each(|bucket| *bucket = Self::identity());
+            for (scalar, point) in scalars.iter().zip(points.iter()) {
+                let digit = window_digit(scalar, start, c);
+                if digit != 0 {
+                    buckets[digit - 1].add_assign(point);
+                }
+            }
+
+            let mut running = Self::identity();
+            let mut window_sum = Self::identity();
+            for bucket in buckets.iter().rev() {
+                if !bucket.is_identity() {
+                    running.add_assign(bucket);
+                }
+                if !running.is_identity() {
+                    window_sum.add_assign(&running);
+                }
+            }
+            res.add_assign(&window_sum);
+        }
+        res
+    }
+}
+
+/// The number of points from which [`Ed25519AffinePoint::multi_scalar_mul`] switches to
+/// Pippenger's method.
+const PIPPENGER_THRESHOLD: usize = 32;
+
+/// Reads the `c` bits of a little endian scalar starting at bit `start`.
+fn window_digit(scalar: &[u8; 32], start: usize, c: usize) -> usize {
+    (start..(start + c).min(256))
+        .rev()
+        .fold(0, |digit, bit| (digit << 1) | ((scalar[bit / 8] >> (bit % 8)) & 1) as usize)
+}
+
+impl Neg for Ed25519AffinePoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_coordinates(-self.x(), self.y())
+    }
+}
+
+/// The Ed25519 base field modulus `2^255 - 19` as little endian words.
+const MODULUS: [u32; 8] = [
+    0xffffffed, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x7fffffff,
+];
+
+/// The exponent `p - 2` used for inversion.
+const MODULUS_MINUS_TWO: [u32; 8] = [
+    0xffffffeb, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x7fffffff,
+];
+
+/// The exponent `(p - 5) / 8` used for square roots.
+const MODULUS_MINUS_FIVE_OVER_EIGHT: [u32; 8] = [
+    0xfffffffd, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0x0fffffff,
+];
+
+/// An element of the Ed25519 base field, stored fully reduced as little endian words.
+///
+/// Multiplications are done with the `UINT256_MUL` precompile.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519FieldElement(pub [u32; 8]);
+
+impl Ed25519FieldElement {
+    pub const ZERO: Self = Self([0; 8]);
+
+    pub const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// The curve constant `d = -121665 / 121666`.
+    pub const D: Self = Self([
+        0x135978a3, 0x75eb4dca, 0x4141d8ab, 0x00700a4d, 0x7779e898, 0x8cc74079, 0x2b6ffe73,
+        0x52036cee,
+    ]);
+
+    /// The square root of -1 given by `2^((p - 1) / 4)`.
+    pub const SQRT_M1: Self = Self([
+        0x4a0ea0b0, 0xc4ee1b27, 0xad2fe478, 0x2f431806, 0x3dfbd7a7, 0x2b4d0099, 0x4fc1df0b,
+        0x2b832480,
+    ]);
+
+    /// Creates a field element from a small integer.
+    pub const fn from_u32(value: u32) -> Self {
+        Self([value, 0, 0, 0, 0, 0, 0, 0])
+    }
+
+    /// Creates a field element from little endian bytes, reducing all 256 bits modulo p.
+    pub fn from_bytes_mod_order(bytes: &[u8; 32]) -> Self {
+        let value: [u32; 8] = crate::utils::bytes_to_words_le(bytes).try_into().unwrap();
+        Self(value) * Self::ONE
+    }
+
+    /// Creates a field element from little endian bytes, returning `None` if the value is not
+    /// smaller than p.
+    pub fn from_canonical_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let value: [u32; 8] = crate::utils::bytes_to_words_le(bytes).try_into().unwrap();
+        let (_, borrow) = sub_words(&value, &MODULUS);
+        borrow.then_some(Self(value))
+    }
+
+    /// The little endian bytes of the field element.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        crate::utils::words_to_bytes_le(&self.0).try_into().unwrap()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+
+    /// Whether the field element is odd, which is the sign convention of Ed25519.
+    pub fn is_negative(&self) -> bool {
+        self.0[0] & 1 == 1
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    /// Raises the field element to the given little endian exponent.
+    pub fn pow(&self, exp: &[u32; 8]) -> Self {
+        let mut res = Self::ONE;
+        for i in (0..256).rev() {
+            res = res.square();
+            if (exp[i / 32] >> (i % 32)) & 1 == 1 {
+                res = res * *self;
+            }
+        }
+        res
+    }
+
+    /// The multiplicative inverse, or zero for zero.
+    pub fn invert(&self) -> Self {
+        self.pow(&MODULUS_MINUS_TWO)
+    }
+
+    /// Computes `(u / v)^((p + 3) / 8)` as `u * v^3 * (u * v^7)^((p - 5) / 8)`.
+    ///
+    /// If `u / v` is a square, the result or the result times [`Self::SQRT_M1`] is its square
+    /// root.
+    pub fn sqrt_ratio_candidate(u: &Self, v: &Self) -> Self {
+        let v3 = v.square() * *v;
+        let v7 = v3.square() * *v;
+        *u * v3 * (*u * v7).pow(&MODULUS_MINUS_FIVE_OVER_EIGHT)
+    }
+
+    /// Computes the non-negative square root of `u / v`, or of `SQRT_M1 * u / v` if `u / v` is not
+    /// a square, returning whether `u / v` was a square.
+    ///
+    /// This is `SQRT_RATIO_M1` from RFC 9496. If `u` is zero the result is `(true, 0)`, and if only
+    /// `v` is zero it is `(false, 0)`.
+    pub fn sqrt_ratio_i(u: &Self, v: &Self) -> (bool, Self) {
+        let r = Self::sqrt_ratio_candidate(u, v);
+        let check = *v * r.square();
+        let correct = check == *u;
+        let flipped = check == -*u;
+        let flipped_i = check == -*u * Self::SQRT_M1;
+
+        let r = if flipped || flipped_i { r * Self::SQRT_M1 } else { r };
+        (correct || flipped, r.abs())
+    }
+
+    /// The absolute value of the field element, i.e. whichever of `self` and `-self` is not
+    /// negative.
+    pub fn abs(&self) -> Self {
+        if self.is_negative() {
+            -*self
+        } else {
+            *self
+        }
+    }
+}
+
+impl Add for Ed25519FieldElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(add_mod(&self.0, &rhs.0, &MODULUS))
+    }
+}
+
+impl Sub for Ed25519FieldElement {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(sub_mod(&self.0, &rhs.0, &MODULUS))
+    }
+}
+
+impl Neg for Ed25519FieldElement {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl Mul for Ed25519FieldElement {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(mul_mod(&self.0, &rhs.0, &MODULUS))
+    }
+}
+
+/// The order `l = 2^252 + 27742317777372353535851937790883648493` of the Ed25519 prime order
+/// subgroup as little endian words.
+const GROUP_ORDER: [u32; 8] = [
+    0x5cf5d3ed, 0x5812631a, 0xa2f79cd6, 0x14def9de, 0x00000000, 0x00000000, 0x00000000, 0x10000000,
+];
+
+/// `l - 2`, the exponent of the scalar inversion.
+const GROUP_ORDER_MINUS_TWO: [u32; 8] = [
+    0x5cf5d3eb, 0x5812631a, 0xa2f79cd6, 0x14def9de, 0x00000000, 0x00000000, 0x00000000, 0x10000000,
+];
+
+/// An Ed25519 scalar, stored fully reduced modulo the group order as little endian words.
+///
+/// Multiplications are done with the `UINT256_MUL` precompile.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519Scalar(pub [u32; 8]);
+
+impl Ed25519Scalar {
+    pub const ZERO: Self = Self([0; 8]);
+
+    pub const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Creates a scalar from a small integer.
+    pub const fn from_u32(value: u32) -> Self {
+        Self([value, 0, 0, 0, 0, 0, 0, 0])
+    }
+
+    /// Creates a scalar from little endian bytes, reducing all 256 bits modulo the group order.
+    ///
+    /// This is `sc_reduce32` in Monero.
+    pub fn from_bytes_mod_order(bytes: &[u8; 32]) -> Self {
+        let value: [u32; 8] = crate::utils::bytes_to_words_le(bytes).try_into().unwrap();
+        Self(value) * Self::ONE
+    }
+
+    /// Creates a scalar from little endian bytes, returning `None` if the value is not smaller than
+    /// the group order.
+    ///
+    /// This is `sc_check` in Monero.
+    pub fn from_canonical_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let value: [u32; 8] = crate::utils::bytes_to_words_le(bytes).try_into().unwrap();
+        let (_, borrow) = sub_words(&value, &GROUP_ORDER);
+        borrow.then_some(Self(value))
+    }
+
+    /// The little endian bytes of the scalar.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        crate::utils::words_to_bytes_le(&self.0).try_into().unwrap()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+
+    /// Computes the inverse of the scalar as `self^(l - 2)`. The inverse of zero is zero.
+    pub fn invert(&self) -> Self {
+        let mut res = Self::ONE;
+        for i in (0..256).rev() {
+            res = res * res;
+            if (GROUP_ORDER_MINUS_TWO[i / 32] >> (i % 32)) & 1 == 1 {
+                res = res * *self;
+            }
+        }
+        res
+    }
+}
+
+impl Add for Ed25519Scalar {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(add_mod(&self.0, &rhs.0, &GROUP_ORDER))
+    }
+}
+
+impl Sub for Ed25519Scalar {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(sub_mod(&self.0, &rhs.0, &GROUP_ORDER))
+    }
+}
+
+impl Mul for Ed25519Scalar {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(mul_mod(&self.0, &rhs.0, &GROUP_ORDER))
+    }
+}
+
+/// Adds two values reduced modulo `modulus`, which must be below 2^255 so the sum cannot overflow.
+fn add_mod(a: &[u32; 8], b: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    let (sum, _) = add_words(a, b);
+    let (reduced, borrow) = sub_words(&sum, modulus);
+    if borrow {
+        sum
+    } else {
+        reduced
+    }
+}
+
+/// Subtracts two values reduced modulo `modulus`.
+fn sub_mod(a: &[u32; 8], b: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    let (diff, borrow) = sub_words(a, b);
+    if borrow {
+        add_words(&diff, modulus).0
+    } else {
+        diff
+    }
+}
+
+/// Multiplies two values modulo `modulus` with the `UINT256_MUL` precompile.
+fn mul_mod(a: &[u32; 8], b: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    let mut result = *a;
+    let mut y_and_modulus = [0u32; 16];
+    y_and_modulus[..8].copy_from_slice(b);
+    y_and_modulus[8..].copy_from_slice(modulus);
+    unsafe {
+        syscall_uint256_mulmod(&mut result, y_and_modulus.as_ptr() as *const [u32; 8]);
+    }
+    result
+}
+
+fn add_words(a: &[u32; 8], b: &[u32; 8]) -> ([u32; 8], bool) {
+    let mut res = [0u32; 8];
+    let mut carry = false;
+    for ((res, a), b) in res.iter_mut().zip(a).zip(b) {
+        let (sum, c1) = a.overflowing_add(*b);
+        let (sum, c2) = sum.overflowing_add(carry as u32);
+        *res = sum;
+        carry = c1 || c2;
+    }
+    (res, carry)
+}
+
+fn sub_words(a: &[u32; 8], b: &[u32; 8]) -> ([u32; 8], bool) {
+    let mut res = [0u32; 8];
+    let mut borrow = false;
+    for ((res, a), b) in res.iter_mut().zip(a).zip(b) {
+        let (diff, b1) = a.overflowing_sub(*b);
+        let (diff, b2) = diff.overflowing_sub(borrow as u32);
+        *res = diff;
+        borrow = b1 || b2;
+    }
+    (res, borrow)
 }
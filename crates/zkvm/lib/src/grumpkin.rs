@@ -0,0 +1,77 @@
+use crate::{
+    syscall_grumpkin_add, syscall_grumpkin_double,
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+};
+
+/// The number of limbs in [GrumpkinPoint].
+pub const N: usize = 16;
+
+/// A point on the Grumpkin curve.
+#[derive(Copy, Clone)]
+#[repr(align(4))]
+pub struct GrumpkinPoint(pub WeierstrassPoint<N>);
+
+impl WeierstrassAffinePoint<N> for GrumpkinPoint {
+    fn infinity() -> Self {
+        Self(WeierstrassPoint::Infinity)
+    }
+
+    fn is_infinity(&self) -> bool {
+        matches!(self.0, WeierstrassPoint::Infinity)
+    }
+}
+
+impl AffinePoint<N> for GrumpkinPoint {
+    /// The generator is `(1, sqrt(-16))`, the point used by the Aztec Grumpkin implementation.
+    const GENERATOR: [u32; N] = [
+        1, 0, 0, 0, 0, 0, 0, 0, 2185176876, 2201994381, 4044886676, 757534021, 111435107,
+        3474153077, 2, 0,
+    ];
+
+    const GENERATOR_T: Self = Self(WeierstrassPoint::Affine(Self::GENERATOR));
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(WeierstrassPoint::Affine(limbs))
+    }
+
+    fn identity() -> Self {
+        Self::infinity()
+    }
+
+    fn is_identity(&self) -> bool {
+        self.is_infinity()
+    }
+
+    fn limbs_ref(&self) -> &[u32; N] {
+        match &self.0 {
+            WeierstrassPoint::Infinity => panic!("Infinity point has no limbs"),
+            WeierstrassPoint::Affine(limbs) => limbs,
+        }
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; N] {
+        match &mut self.0 {
+            WeierstrassPoint::Infinity => panic!("Infinity point has no limbs"),
+            WeierstrassPoint::Affine(limbs) => limbs,
+        }
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        unsafe {
+            syscall_grumpkin_add(a, b);
+        }
+    }
+
+    fn complete_add_assign(&mut self, other: &Self) {
+        self.weierstrass_add_assign(other);
+    }
+
+    fn double(&mut self) {
+        let a = self.limbs_mut();
+        unsafe {
+            syscall_grumpkin_double(a);
+        }
+    }
+}
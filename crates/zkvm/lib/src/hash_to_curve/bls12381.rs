@@ -0,0 +1,527 @@
+//! The `BLS12381G1_XMD:SHA-256_SSWU_*` and `BLS12381G2_XMD:SHA-256_SSWU_*` suites, which map to G1
+//! through an 11-isogeny and to G2 through a 3-isogeny.
+
+use super::{hash_to_field, map_to_curve, Bls12381Fp, Bls12381Fp2, SswuCurve};
+use crate::{
+    bls12381::Bls12381Point,
+    syscall_bls12381_g2_add, syscall_bls12381_g2_double,
+    utils::{AffinePoint, WeierstrassAffinePoint},
+};
+
+/// The G1 suite of BLS12-381.
+pub struct Bls12381G1;
+
+/// The G2 suite of BLS12-381.
+pub struct Bls12381G2;
+
+impl SswuCurve for Bls12381G1 {
+    type Field = Bls12381Fp;
+
+    const A: Bls12381Fp = G1_A;
+    const B: Bls12381Fp = G1_B;
+    const Z: Bls12381Fp = G1_Z;
+    const ISO_X_NUM: &'static [Bls12381Fp] = &G1_ISO_X_NUM;
+    const ISO_X_DEN: &'static [Bls12381Fp] = &G1_ISO_X_DEN;
+    const ISO_Y_NUM: &'static [Bls12381Fp] = &G1_ISO_Y_NUM;
+    const ISO_Y_DEN: &'static [Bls12381Fp] = &G1_ISO_Y_DEN;
+}
+
+impl SswuCurve for Bls12381G2 {
+    type Field = Bls12381Fp2;
+
+    const A: Bls12381Fp2 = G2_A;
+    const B: Bls12381Fp2 = G2_B;
+    const Z: Bls12381Fp2 = G2_Z;
+    const ISO_X_NUM: &'static [Bls12381Fp2] = &G2_ISO_X_NUM;
+    const ISO_X_DEN: &'static [Bls12381Fp2] = &G2_ISO_X_DEN;
+    const ISO_Y_NUM: &'static [Bls12381Fp2] = &G2_ISO_Y_NUM;
+    const ISO_Y_DEN: &'static [Bls12381Fp2] = &G2_ISO_Y_DEN;
+}
+
+/// Hashes a message to G1 with the `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite.
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> Bls12381Point {
+    let u = hash_to_field(msg, dst, 2);
+    let mut point = g1_point(map_to_curve::<Bls12381G1>(&u[0]));
+    point.weierstrass_add_assign(&g1_point(map_to_curve::<Bls12381G1>(&u[1])));
+    clear_cofactor_g1(point)
+}
+
+/// Encodes a message to G1 with the `BLS12381G1_XMD:SHA-256_SSWU_NU_` suite.
+pub fn encode_to_g1(msg: &[u8], dst: &[u8]) -> Bls12381Point {
+    let u = hash_to_field(msg, dst, 1);
+    clear_cofactor_g1(g1_point(map_to_curve::<Bls12381G1>(&u[0])))
+}
+
+/// Hashes a message to G2 with the `BLS12381G2_XMD:SHA-256_SSWU_RO_` suite.
+///
+/// The point is returned as `[x.c0, x.c1, y.c0, y.c1]`, with all zeros for the point at infinity,
+/// which is the encoding of `monerochan_lib::pairing`.
+pub fn hash_to_g2(msg: &[u8], dst: &[u8]) -> [u32; 48] {
+    let u = hash_to_field(msg, dst, 2);
+    let mut point = g2_point(map_to_curve::<Bls12381G2>(&u[0]));
+    g2_add_assign(&mut point, &g2_point(map_to_curve::<Bls12381G2>(&u[1])));
+    clear_cofactor_g2(&point)
+}
+
+/// Encodes a message to G2 with the `BLS12381G2_XMD:SHA-256_SSWU_NU_` suite, in the encoding of
+/// [`hash_to_g2`].
+pub fn encode_to_g2(msg: &[u8], dst: &[u8]) -> [u32; 48] {
+    let u = hash_to_field(msg, dst, 1);
+    clear_cofactor_g2(&g2_point(map_to_curve::<Bls12381G2>(&u[0])))
+}
+
+fn g1_point(point: Option<(Bls12381Fp, Bls12381Fp)>) -> Bls12381Point {
+    match point {
+        Some((x, y)) => {
+            let mut limbs = [0; 24];
+            limbs[..12].copy_from_slice(&x.0);
+            limbs[12..].copy_from_slice(&y.0);
+            Bls12381Point::new(limbs)
+        }
+        None => Bls12381Point::infinity(),
+    }
+}
+
+fn g2_point(point: Option<(Bls12381Fp2, Bls12381Fp2)>) -> [u32; 48] {
+    let mut limbs = [0; 48];
+    if let Some((x, y)) = point {
+        limbs[..24].copy_from_slice(&x.0);
+        limbs[24..].copy_from_slice(&y.0);
+    }
+    limbs
+}
+
+/// Multiplies a point of the curve by the effective cofactor `1 - z` of G1.
+fn clear_cofactor_g1(mut point: Bls12381Point) -> Bls12381Point {
+    if !point.is_infinity() {
+        point.mul_assign(&G1_H_EFF);
+    }
+    point
+}
+
+/// Multiplies a point of the twist by the effective cofactor of G2, with a double-and-add from
+/// the most significant bit.
+fn clear_cofactor_g2(point: &[u32; 48]) -> [u32; 48] {
+    let mut res = [0; 48];
+    for i in (0..G2_H_EFF.len() * 32).rev() {
+        g2_double_assign(&mut res);
+        if (G2_H_EFF[i / 32] >> (i % 32)) & 1 == 1 {
+            g2_add_assign(&mut res, point);
+        }
+    }
+    res
+}
+
+fn g2_is_infinity(point: &[u32; 48]) -> bool {
+    point.iter().all(|&limb| limb == 0)
+}
+
+/// Adds two points of the twist, handling the point at infinity and equal x-coordinates.
+fn g2_add_assign(p: &mut [u32; 48], q: &[u32; 48]) {
+    if g2_is_infinity(q) {
+        return;
+    }
+    if g2_is_infinity(p) {
+        *p = *q;
+        return;
+    }
+
+    if p[..24] == q[..24] {
+        if p[24..] == q[24..] {
+            g2_double_assign(p);
+        } else {
+            *p = [0; 48];
+        }
+        return;
+    }
+    unsafe { syscall_bls12381_g2_add(p, q) }
+}
+
+/// Doubles a point of the twist, handling the point at infinity and points of order two.
+fn g2_double_assign(p: &mut [u32; 48]) {
+    if p[24..].iter().all(|&limb| limb == 0) {
+        *p = [0; 48];
+        return;
+    }
+    unsafe { syscall_bls12381_g2_double(p) }
+}
+
+/// The effective cofactor `1 - z` of G1, as the little endian words of a 384-bit scalar.
+const G1_H_EFF: [u32; 12] = [0x00010001, 0xd2010000, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// The effective cofactor `3 * (z^2 - 1) * h2` of G2, where `h2` is the cofactor of G2, as little
+/// endian words.
+const G2_H_EFF: [u32; 20] = [
+    0xaaa95551, 0xe8020005, 0xdebbf6b4, 0x59894c0a, 0x6689f6a3, 0xe954cbc0, 0xd7477c1a, 0x2ec0ec69,
+    0x5d1212b0, 0x6d82bf01, 0x8731db95, 0x329c2f17, 0x1508ffe1, 0x9986ff03, 0x145ad768, 0x88e2a8e9,
+    0xa91b3528, 0x584c6a0e, 0xf2ee75b3, 0x0bc69f08,
+];
+
+/// The coefficient `A'` of the curve 11-isogenous to G1.
+const G1_A: Bls12381Fp = Bls12381Fp([
+    0x2d584c1d, 0x5cf42808, 0xa0e0f97f, 0x98936f8d, 0xefd881ac, 0xd8e8981a, 0x83ee66a8, 0xb0ea9853,
+    0xc96d4982, 0x3d693a02, 0xa3b8e943, 0x00144698,
+]);
+
+/// The coefficient `B'` of the curve 11-isogenous to G1.
+const G1_B: Bls12381Fp = Bls12381Fp([
+    0x8e172be0, 0xd1cc48e9, 0x316ceaa5, 0x5a23215a, 0xcef35ef5, 0xa0b9c14f, 0xf24f4070, 0x2016c1f0,
+    0x753eee3b, 0x018b12e8, 0x11688030, 0x12e2908d,
+]);
+
+/// The non-square `Z = 11` of the G1 suite.
+const G1_Z: Bls12381Fp = Bls12381Fp([
+    0x0000000b, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0x00000000, 0x00000000, 0x00000000, 0x00000000,
+]);
+
+/// The coefficients of the 11-isogeny map of G1, in ascending degree.
+const G1_ISO_X_NUM: [Bls12381Fp; 12] = [
+    Bls12381Fp([
+        0x734649b7, 0xaeac1662, 0xf2e62d6e, 0x5610c2d5, 0xcdb4e2c8, 0xf2627b56, 0xa2d7005f,
+        0x6b303e88, 0xd9981585, 0xb809101d, 0x1e833340, 0x11a05f2b,
+    ]),
+    Bls12381Fp([
+        0xb3cb83bb, 0xe834eef1, 0xf318c356, 0x4838f2a6, 0x70d1e86b, 0xf565e33c, 0x2f6a8417,
+        0x7c17e75b, 0x2147a81c, 0x0588bab2, 0xe943ab2f, 0x17294ed3,
+    ]),
+    Bls12381Fp([
+        0xac9edcb0, 0xe0179f9d, 0x2a09729f, 0x958c3e3d, 0xec68e25c, 0x6878e501, 0x295983e5,
+        0xce032473, 0xd10a9a1b, 0x1d1048c5, 0xb97678ec, 0x0d54005d,
+    ]),
+    Bls12381Fp([
+        0x1d9b6861, 0xc5b38864, 0xe3107193, 0x5336e25c, 0xf1b33083, 0xf1b33289, 0x6a8dbf25,
+        0xd7f5e465, 0x07e55412, 0x4e0609d3, 0x6fcc6db7, 0x1778e716,
+    ]),
+    Bls12381Fp([
+        0xac8895d9, 0x51154ce9, 0x301e77c4, 0x985a286f, 0x982fac18, 0x086eeb65, 0x1257fb3f,
+        0x99db995a, 0xe4118e54, 0x6642b4b3, 0x3199f443, 0x0e99726a,
+    ]),
+    Bls12381Fp([
+        0x6f652983, 0xcd13c1c6, 0xcae73d19, 0xa0870d2d, 0x97e68f90, 0x9ed3ab90, 0xd952799b,
+        0xdb3cb17d, 0xf7a74ab5, 0x01d1201b, 0x0d7313ff, 0x1630c325,
+    ]),
+    Bls12381Fp([
+        0xa139ed84, 0xddd7f225, 0xc1052eca, 0x8da25128, 0xf9c86b2a, 0x9008e218, 0x4f0f8ce1,
+        0xb1158626, 0x8ae652bf, 0x6a3726c3, 0x3fe44d29, 0x0d6ed655,
+    ]),
+    Bls12381Fp([
+        0xe3f0c88e, 0x9ccb5618, 0xc8f475af, 0x39b7c8f8, 0xf0f27533, 0xa682c62e, 0x275b4db1,
+        0x356de5ab, 0xd1117e53, 0xe8743884, 0x01abdbe2, 0x17b81e77,
+    ]),
+    Bls12381Fp([
+        0x8497e317, 0x6d71986a, 0x96b74e95, 0x4fa295f2, 0x28c5d1de, 0xa2c596c9, 0xe79f5574,
+        0xc43b756c, 0x63be990d, 0x7b90b335, 0xf9a78fc4, 0x080d3cf1,
+    ]),
+    Bls12381Fp([
+        0xbe390c9e, 0x7f241067, 0xdc032779, 0xa3190b2e, 0xf4bb1b7f, 0x676314ba, 0x3a0c5c99,
+        0xdd2ecb80, 0x5d138f22, 0x2e0c3751, 0x1bcfa7c4, 0x169b1f8e,
+    ]),
+    Bls12381Fp([
+        0x1605fb7b, 0xca67df3f, 0x8c285dec, 0xf69b771f, 0x03b14866, 0xd50af360, 0xe6787f96,
+        0xfa7dccdd, 0xd2565b0d, 0x72d8ec09, 0x79ce07e2, 0x10321da0,
+    ]),
+    Bls12381Fp([
+        0x8ba2d229, 0xa9c8ba2e, 0xb64d391f, 0xc24b1b80, 0xc24c6b68, 0x23c0bf1b, 0x22c837bc,
+        0x31d79d7e, 0x81edee3d, 0xbd1e9623, 0x8e260e70, 0x06e08c24,
+    ]),
+];
+const G1_ISO_X_DEN: [Bls12381Fp; 11] = [
+    Bls12381Fp([
+        0x40d21b1c, 0x993cf9fa, 0xbe343df8, 0xb558d681, 0x7fc8ac62, 0x9c958861, 0xa35b48ba,
+        0x01d5ef4b, 0x4bd3fa6f, 0x18b2e62f, 0x8cff19ae, 0x08ca8d54,
+    ]),
+    Bls12381Fp([
+        0xc82b3bff, 0xe5c8276e, 0x6cb026e9, 0x13daa884, 0x8c48bf57, 0x0126c258, 0x0cf0800c,
+        0x7041e8ca, 0x98e53636, 0x48b47112, 0xeb559c43, 0x12561a5d,
+    ]),
+    Bls12381Fp([
+        0x5cb83e19, 0xfcc239ba, 0x7c94fedc, 0xd6a3d096, 0xb11aceac, 0xfca64e00, 0x5a718cd1,
+        0x6f89416f, 0xbff2991f, 0x8137e629, 0x57a3225e, 0x0b2962fe,
+    ]),
+    Bls12381Fp([
+        0x8dc62cd8, 0x130de893, 0x3eecf5c4, 0x4976d524, 0xc28d6fd0, 0x54cca8ab, 0x16b16551,
+        0x5b08243f, 0x7c40eb54, 0xc83aafef, 0xa58ae2fe, 0x03425581,
+    ]),
+    Bls12381Fp([
+        0x3532a21e, 0x539d395b, 0x1f35781d, 0x9bd29ba8, 0x33b306da, 0x8d6b44e8, 0xa12062bb,
+        0xffdfc759, 0x43e7a07d, 0x0a6f1d5f, 0x022914a8, 0x13a8e162,
+    ]),
+    Bls12381Fp([
+        0x9f6304a5, 0xc02df9a2, 0xc4228f11, 0x7400d24b, 0x24b8982f, 0x0a43bcef, 0xce9cad4d,
+        0x395735e9, 0x0506c6e9, 0x55390f7f, 0xe4e667b9, 0x0e7355f8,
+    ]),
+    Bls12381Fp([
+        0x6ee84a3a, 0xec257449, 0x8f0de06c, 0xea73b353, 0x62aede9c, 0x4e2e0730, 0xaf53a189,
+        0x570f5799, 0xe0596721, 0x0f3e0c63, 0xf1693619, 0x0772caac,
+    ]),
+    Bls12381Fp([
+        0xbdcc5a5e, 0x11f7d99b, 0x9d11e2d3, 0x0fa5b948, 0xf9822c58, 0x1996e1cd, 0x1bca68a8,
+        0x6e7f63c2, 0x74cf0199, 0x30b3f5b0, 0x9d64a8b2, 0x14a7ac2a,
+    ]),
+    Bls12381Fp([
+        0x79a1d641, 0x4776ec3a, 0xabba4370, 0x03826692, 0x7f398835, 0x74100da6, 0x7161366b,
+        0xe07f8d1d, 0xafc7a3cc, 0x5e920b3d, 0xada54f82, 0x0a10ecf6,
+    ]),
+    Bls12381Fp([
+        0x68ecdd0a, 0x2d6384d1, 0x4b786500, 0x93174e4b, 0x78f31c15, 0x76df5339, 0x96f7d037,
+        0xf682b4ee, 0xb3a56680, 0x476d6e3e, 0xb9e92ad4, 0x095fc13a,
+    ]),
+    Bls12381Fp([
+        0x00000001, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    ]),
+];
+const G1_ISO_Y_NUM: [Bls12381Fp; 16] = [
+    Bls12381Fp([
+        0x9707bb33, 0xbe984571, 0x9b3ba3c2, 0xcd0c7aee, 0x956543d3, 0x2b52af6c, 0x48a86952,
+        0x11ad138e, 0x4980dcfa, 0x259d1f09, 0x1ba24ee0, 0x090d97c8,
+    ]),
+    Bls12381Fp([
+        0x2e41c696, 0xe097e75a, 0x962fa8bf, 0xd6c56711, 0xeb67ad34, 0x0f906343, 0x254f383d,
+        0x1223e96c, 0x76fb4683, 0xd51036d7, 0x04ee5811, 0x134996a1,
+    ]),
+    Bls12381Fp([
+        0xc72de1f6, 0xb8dfe240, 0x28b00523, 0xd26d5216, 0x91400da7, 0xc344be4b, 0x58a31ce2,
+        0x2552e2d6, 0x6a3b4994, 0xf4a384c8, 0xaa966e66, 0x00cc786b,
+    ]),
+    Bls12381Fp([
+        0xb0e5f4cb, 0xa6355c77, 0x9ec61dec, 0xde405aba, 0x03251cf9, 0x09e4a3ec, 0x0eeb791c,
+        0xd42aa7b9, 0xd8746757, 0x7898751a, 0xe8981c21, 0x01f86376,
+    ]),
+    Bls12381Fp([
+        0xf2e8fedb, 0x41b6daec, 0x099040a8, 0x2ee7f8dc, 0x21351adc, 0x79833fd2, 0xe3ce50b8,
+        0x195536fb, 0xa21529c4, 0x5caf4fe2, 0xefe0ff13, 0x08cc03fd,
+    ]),
+    Bls12381Fp([
+        0x3633a5f0, 0x99b23ab1, 0xc95a8072, 0x203f6326, 0x3ad5544e, 0x76505c3d, 0xafadb7bd,
+        0x74a7d0d4, 0xb8f0a6a0, 0x2211e11d, 0x40634b6a, 0x16603fca,
+    ]),
+    Bls12381Fp([
+        0x5fe9d6f2, 0xc961f885, 0x460f415e, 0x47a87ac2, 0x4d634f37, 0x5231413c, 0x2be184cb,
+        0xe75bb8ca, 0x27796b3c, 0xb2c977d0, 0xcfac1bbc, 0x04ab0b9b,
+    ]),
+    Bls12381Fp([
+        0x1870fb29, 0xa15e4ca3, 0xfedfe935, 0x42f64550, 0xc26c8426, 0xfd038da6, 0xe3bdd81f,
+        0x170a05bf, 0x2ca6c674, 0xde9926bd, 0x333ab86f, 0x0987c8d5,
+    ]),
+    Bls12381Fp([
+        0x7bdba587, 0x60370e57, 0xc78607a3, 0x69d65201, 0x1f20cabe, 0x1e8b6e6a, 0x679dc26c,
+        0x8f3abd16, 0x1e4da1bb, 0xe88c9e22, 0xbd96684b, 0x09fc4018,
+    ]),
+    Bls12381Fp([
+        0xca731c30, 0x2bafaaeb, 0xdd4eba6f, 0x9b3f7055, 0xd1e4d43b, 0x06985e7e, 0x915af6fe,
+        0xc42a0ca7, 0xada14a23, 0x223abde7, 0x1186bdb5, 0x0e1bba7a,
+    ]),
+    Bls12381Fp([
+        0xd011c132, 0xe813711a, 0xce3fbafc, 0x31bf3a5c, 0x6389e610, 0xd1183e41, 0x6caf493f,
+        0xcd2fcbcb, 0x1d43fb93, 0x0dfd0b8f, 0x937cd1be, 0x19713e47,
+    ]),
+    Bls12381Fp([
+        0xd0074d8e, 0xce07c8a4, 0x1b44d606, 0x49d9cdf4, 0x911f6432, 0x2e6bfe7f, 0xaaf0c246,
+        0x523559b8, 0xfed2edcc, 0xb918c143, 0x8f36f6de, 0x18b46a90,
+    ]),
+    Bls12381Fp([
+        0x0b971ef8, 0x0d4c04f0, 0x919211f2, 0x06c851c1, 0x07b4633f, 0xc02710e8, 0x3426b08e,
+        0x7aa7b12a, 0x04f53f44, 0xd1550960, 0x101b9399, 0x0b182cac,
+    ]),
+    Bls12381Fp([
+        0xdb980133, 0x42d9d3f5, 0x1c232a64, 0xc6cf90ad, 0x3c40659c, 0x13e6632d, 0x0d4c1580,
+        0x757b3b08, 0x7be315dc, 0x72fc00ae, 0xad1eca9b, 0x0245a394,
+    ]),
+    Bls12381Fp([
+        0x5475224b, 0x866b1e71, 0x6579afb7, 0x6ba1049b, 0x396a7ce4, 0xd9ab0f5d, 0xd7e86568,
+        0x5e673d81, 0x48c4a3fc, 0x02a159f7, 0x5e44cf11, 0x05c12964,
+    ]),
+    Bls12381Fp([
+        0x69c8b604, 0x04b456be, 0xfec01c77, 0xb665027e, 0x95af01b2, 0x57add4fa, 0x84965a39,
+        0xcb181d8f, 0x42df2eb5, 0x4ea50b3b, 0x990f03ce, 0x15e6be4e,
+    ]),
+];
+const G1_ISO_Y_DEN: [Bls12381Fp; 16] = [
+    Bls12381Fp([
+        0xb03663c1, 0x01479253, 0xf60c206d, 0x07f3688e, 0x5be72e7a, 0xeec3232b, 0x78980be6,
+        0x601a6de5, 0xfad0eae9, 0x52181140, 0x3a9c98b2, 0x16112c4c,
+    ]),
+    Bls12381Fp([
+        0x2e49a03d, 0x32f6102c, 0x63529e35, 0x78a42607, 0xf453e01f, 0xa4a10356, 0x31c4d59c,
+        0x85c84ff7, 0x43c348b8, 0x1a0cbd6c, 0x2381201e, 0x1962d75c,
+    ]),
+    Bls12381Fp([
+        0x3dbf67f2, 0x1e2538b5, 0x36f96f89, 0xa6757cd6, 0x279cd2ec, 0x0c35a5dd, 0x51ae7f31,
+        0x78c48555, 0x6e8eb157, 0x6faaae7d, 0x6640da27, 0x058df330,
+    ]),
+    Bls12381Fp([
+        0x445f5416, 0xa8d26d98, 0xc28297ad, 0x727364f2, 0xe726af41, 0x123da489, 0xddbcd30e,
+        0xd115c5db, 0x89edb4d1, 0xf20d23bf, 0x798e5395, 0x16b7d288,
+    ]),
+    Bls12381Fp([
+        0x11a5001d, 0xda391423, 0x0fd2eded, 0xa20b15dc, 0xc9dec916, 0x542eda0f, 0x0f69bbb0,
+        0xc6d19c9f, 0xf8228ddc, 0xb00cc912, 0x545f43e4, 0x0be0e079,
+    ]),
+    Bls12381Fp([
+        0xaaf9b7ac, 0x02c6477f, 0xdfa9cce2, 0x49f38db9, 0x6f0f5a64, 0xc5ecd87b, 0x5550d881,
+        0xb70152c6, 0xac783182, 0x9fb266ea, 0x7186db2d, 0x08d9e529,
+    ]),
+    Bls12381Fp([
+        0x126a775c, 0x3d1a1399, 0xa58b1fb9, 0xd5fa9c01, 0x400a0051, 0x5dd365bc, 0x8d0cf8ef,
+        0x5eecfdfa, 0xace9824b, 0xc3ba8734, 0x8a99db2f, 0x166007c0,
+    ]),
+    Bls12381Fp([
+        0x15812ed9, 0x60ee415a, 0x0801dee4, 0xb920f5b0, 0x06357132, 0xfeb34fd2, 0xfa1f4fd7,
+        0xe5a4375e, 0xbba6ff6e, 0x03bcddfa, 0xbe3ea7ea, 0x16a3ef08,
+    ]),
+    Bls12381Fp([
+        0x55535d4a, 0x6b233d9d, 0xbb924883, 0x52cfe2f7, 0x4bf39b48, 0xabc5750c, 0xc6af5920,
+        0xf9fb0ce4, 0xd1d74cc4, 0x1a1be54f, 0x336c6123, 0x1866c8ed,
+    ]),
+    Bls12381Fp([
+        0xb8913f55, 0x346ef48b, 0xd529b35e, 0xc7385ea3, 0x7ea7d4fb, 0x5308592e, 0xe13d87bb,
+        0x3216f763, 0xd94a8490, 0xea820597, 0xa70a6e1c, 0x167a55cd,
+    ]),
+    Bls12381Fp([
+        0xba8f6aa8, 0x00f8b49c, 0x4f830604, 0x71a5c29f, 0xe636a5c8, 0x0e591b36, 0xbb61a629,
+        0x9c6dd039, 0x1ad2911d, 0x48f010a0, 0xeea405bd, 0x04d2f259,
+    ]),
+    Bls12381Fp([
+        0xe2561092, 0x9684b529, 0x6f7ebbea, 0x16f96898, 0xcea79135, 0x8c0f9a88, 0xefce42d2,
+        0x7f94ff8a, 0x48c50c47, 0xf5852c1e, 0x481d033f, 0x0accbb67,
+    ]),
+    Bls12381Fp([
+        0x573345cc, 0x1e99b138, 0xe3b90ac1, 0x93000763, 0xa00d9b86, 0x7d5ceef9, 0x8adf0226,
+        0x543346d9, 0xb45f1496, 0xc3613144, 0x4c767fe3, 0x0ad6b951,
+    ]),
+    Bls12381Fp([
+        0x26ed06f7, 0xd1fadc13, 0x8714cc80, 0x420517bd, 0x7942480e, 0xcb748df2, 0xe72927c1,
+        0xbf565b94, 0x53cd76f2, 0x628bdd0d, 0xeb2e4f3b, 0x02660400,
+    ]),
+    Bls12381Fp([
+        0x1d634b8f, 0x4415473a, 0xf1349780, 0x5ca2f570, 0x356caa20, 0x324efcd6, 0xe273b853,
+        0x71c40f65, 0x0d7819c1, 0x6b24255e, 0x16ddc03e, 0x0e0fa1d8,
+    ]),
+    Bls12381Fp([
+        0x00000001, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    ]),
+];
+
+/// The coefficient `A' = 240 * u` of the curve 3-isogenous to the G2 twist.
+const G2_A: Bls12381Fp2 = Bls12381Fp2([
+    0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x000000f0, 0x00000000, 0x00000000, 0x00000000,
+    0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+]);
+
+/// The coefficient `B' = 1012 * (1 + u)` of the curve 3-isogenous to the G2 twist.
+const G2_B: Bls12381Fp2 = Bls12381Fp2([
+    0x000003f4, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x000003f4, 0x00000000, 0x00000000, 0x00000000,
+    0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+]);
+
+/// The non-square `Z = -(2 + u)` of the G2 suite.
+const G2_Z: Bls12381Fp2 = Bls12381Fp2([
+    0xffffaaa9, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84,
+    0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea, 0xffffaaaa, 0xb9feffff, 0xb153ffff, 0x1eabfffe,
+    0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84, 0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+]);
+
+/// The coefficients of the 3-isogeny map of G2, in ascending degree.
+const G2_ISO_X_NUM: [Bls12381Fp2; 4] = [
+    Bls12381Fp2([
+        0xaaaa97d6, 0x6238aaaa, 0x43d9c71c, 0x5c2638e3, 0xc50ae15d, 0x88b58423, 0xfd3a042a,
+        0x32c52d39, 0x47d7ed85, 0xbb5b7a9a, 0x7e8e333e, 0x05c75950, 0xaaaa97d6, 0x6238aaaa,
+        0x43d9c71c, 0x5c2638e3, 0xc50ae15d, 0x88b58423, 0xfd3a042a, 0x32c52d39, 0x47d7ed85,
+        0xbb5b7a9a, 0x7e8e333e, 0x05c75950,
+    ]),
+    Bls12381Fp2([
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xffffc71a, 0x26a9ffff,
+        0xcb8d5555, 0x1472aaa9, 0x4f20a418, 0x9a208c6b, 0xf7ae0c7f, 0x984f87ad, 0xd787c88f,
+        0x32126fce, 0x7baa99bc, 0x11560bf1,
+    ]),
+    Bls12381Fp2([
+        0xffffc71e, 0x26a9ffff, 0xcb8d5555, 0x1472aaa9, 0x4f20a418, 0x9a208c6b, 0xf7ae0c7f,
+        0x984f87ad, 0xd787c88f, 0x32126fce, 0x7baa99bc, 0x11560bf1, 0xffffe38d, 0x9354ffff,
+        0xe5c6aaaa, 0x0a395554, 0xa790520c, 0xcd104635, 0xfbd7063f, 0xcc27c3d6, 0x6bc3e447,
+        0x190937e7, 0xbdd54cde, 0x08ab05f8,
+    ]),
+    Bls12381Fp2([
+        0xaaaa5ed1, 0x88e2aaaa, 0x0f671c71, 0x7098e38d, 0x142b8575, 0x22d6108f, 0xf4e810aa,
+        0xcb14b4e7, 0x1f5fb614, 0xed6dea69, 0xfa38ccfa, 0x171d6541, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000,
+    ]),
+];
+const G2_ISO_X_DEN: [Bls12381Fp2; 3] = [
+    Bls12381Fp2([
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xffffaa63, 0xb9feffff,
+        0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84, 0x434bacd7,
+        0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+    ]),
+    Bls12381Fp2([
+        0x0000000c, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xffffaa9f, 0xb9feffff,
+        0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84, 0x434bacd7,
+        0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+    ]),
+    Bls12381Fp2([
+        0x00000001, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000,
+    ]),
+];
+const G2_ISO_Y_NUM: [Bls12381Fp2; 4] = [
+    Bls12381Fp2([
+        0x71c6d706, 0x12cfc71c, 0xf8c92f68, 0xfc8c25eb, 0x7d27e500, 0xf54439d8, 0xa07f649b,
+        0x0f7da5d4, 0x076d1193, 0x59a4c18b, 0x7ab4113b, 0x1530477c, 0x71c6d706, 0x12cfc71c,
+        0xf8c92f68, 0xfc8c25eb, 0x7d27e500, 0xf54439d8, 0xa07f649b, 0x0f7da5d4, 0x076d1193,
+        0x59a4c18b, 0x7ab4113b, 0x1530477c,
+    ]),
+    Bls12381Fp2([
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xaaaa97be, 0x6238aaaa,
+        0x43d9c71c, 0x5c2638e3, 0xc50ae15d, 0x88b58423, 0xfd3a042a, 0x32c52d39, 0x47d7ed85,
+        0xbb5b7a9a, 0x7e8e333e, 0x05c75950,
+    ]),
+    Bls12381Fp2([
+        0xffffc71c, 0x26a9ffff, 0xcb8d5555, 0x1472aaa9, 0x4f20a418, 0x9a208c6b, 0xf7ae0c7f,
+        0x984f87ad, 0xd787c88f, 0x32126fce, 0x7baa99bc, 0x11560bf1, 0xffffe38f, 0x9354ffff,
+        0xe5c6aaaa, 0x0a395554, 0xa790520c, 0xcd104635, 0xfbd7063f, 0xcc27c3d6, 0x6bc3e447,
+        0x190937e7, 0xbdd54cde, 0x08ab05f8,
+    ]),
+    Bls12381Fp2([
+        0x1c718b10, 0xe1b371c7, 0x56dc4bd9, 0x4e79097a, 0x9aa27452, 0xb0e977c6, 0xa1e26286,
+        0x761b0f37, 0xe3811ad0, 0xfbf7043d, 0x3b6cf79b, 0x124c9ad4, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000,
+    ]),
+];
+const G2_ISO_Y_DEN: [Bls12381Fp2; 4] = [
+    Bls12381Fp2([
+        0xffffa8fb, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf,
+        0x64774b84, 0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea, 0xffffa8fb, 0xb9feffff,
+        0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84, 0x434bacd7,
+        0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+    ]),
+    Bls12381Fp2([
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xffffa9d3, 0xb9feffff,
+        0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84, 0x434bacd7,
+        0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+    ]),
+    Bls12381Fp2([
+        0x00000012, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0xffffaa99, 0xb9feffff,
+        0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84, 0x434bacd7,
+        0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+    ]),
+    Bls12381Fp2([
+        0x00000001, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000, 0x00000000, 0x00000000,
+    ]),
+];
@@ -0,0 +1,316 @@
+//! The base fields of BLS12-381 and secp256k1 on the field precompiles.
+//!
+//! Elements are little endian words, and every element produced by the precompiles is reduced, so
+//! elements can be compared word by word. Hinted inverses and square roots are reduced by a
+//! multiplication with one before they are used.
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+use super::SswuField;
+use crate::{
+    io::{
+        self, FD_BLS12_381_FP2_SQRT, FD_BLS12_381_INVERSE, FD_BLS12_381_SQRT, FD_FP_INV, FD_FP_SQRT,
+    },
+    syscall_bls12381_fp2_addmod, syscall_bls12381_fp2_mulmod, syscall_bls12381_fp2_submod,
+    syscall_bls12381_fp_addmod, syscall_bls12381_fp_mulmod, syscall_bls12381_fp_submod,
+    syscall_secp256k1_fp_addmod, syscall_secp256k1_fp_mulmod, syscall_secp256k1_fp_submod,
+};
+
+/// An element of the BLS12-381 base field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bls12381Fp(pub [u32; 12]);
+
+/// An element `c0 + c1 * u` of `Fp2 = Fp[u] / (u^2 + 1)` over the BLS12-381 base field, as the
+/// words of `c0` followed by the words of `c1`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bls12381Fp2(pub [u32; 24]);
+
+/// An element of the secp256k1 base field.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1Fp(pub [u32; 8]);
+
+/// The secp256k1 base field modulus, in big endian.
+const SECP256K1_MODULUS: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe, 0xff, 0xff, 0xfc, 0x2f,
+];
+
+macro_rules! impl_field_ops {
+    ($ty:ident, $add:ident, $sub:ident, $mul:ident) => {
+        impl Add for $ty {
+            type Output = Self;
+
+            fn add(mut self, rhs: Self) -> Self {
+                unsafe { $add(self.0.as_mut_ptr(), rhs.0.as_ptr()) };
+                self
+            }
+        }
+
+        impl Sub for $ty {
+            type Output = Self;
+
+            fn sub(mut self, rhs: Self) -> Self {
+                unsafe { $sub(self.0.as_mut_ptr(), rhs.0.as_ptr()) };
+                self
+            }
+        }
+
+        impl Mul for $ty {
+            type Output = Self;
+
+            fn mul(mut self, rhs: Self) -> Self {
+                unsafe { $mul(self.0.as_mut_ptr(), rhs.0.as_ptr()) };
+                self
+            }
+        }
+
+        impl Neg for $ty {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                <Self as SswuField>::ZERO - self
+            }
+        }
+    };
+}
+
+impl_field_ops!(
+    Bls12381Fp,
+    syscall_bls12381_fp_addmod,
+    syscall_bls12381_fp_submod,
+    syscall_bls12381_fp_mulmod
+);
+impl_field_ops!(
+    Bls12381Fp2,
+    syscall_bls12381_fp2_addmod,
+    syscall_bls12381_fp2_submod,
+    syscall_bls12381_fp2_mulmod
+);
+impl_field_ops!(
+    Secp256k1Fp,
+    syscall_secp256k1_fp_addmod,
+    syscall_secp256k1_fp_submod,
+    syscall_secp256k1_fp_mulmod
+);
+
+impl Bls12381Fp {
+    /// The element `2^256`.
+    const TWO_POW_256: Self = Self([0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0]);
+
+    /// The non-square `2` used by `hook_bls12_381_sqrt`.
+    const NQR: Self = Self([2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Creates an element from at most 48 big endian bytes.
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        Self(words_from_be_bytes(bytes))
+    }
+
+    /// The big endian encoding of the element.
+    pub fn to_be_bytes(&self) -> [u8; 48] {
+        words_to_be_bytes(&self.0)
+    }
+}
+
+impl SswuField for Bls12381Fp {
+    const ZERO: Self = Self([0; 12]);
+    const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    const HASH_LEN: usize = 64;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let (hi, lo) = bytes.split_at(32);
+        Self::from_be_bytes(hi) * Self::TWO_POW_256 + Self::from_be_bytes(lo)
+    }
+
+    fn inv0(&self) -> Self {
+        if *self == Self::ZERO {
+            return Self::ZERO;
+        }
+
+        io::write(FD_BLS12_381_INVERSE, &self.to_be_bytes());
+        let inv = Self::from_be_bytes(&io::read_vec()) * Self::ONE;
+        assert!(*self * inv == Self::ONE, "invalid hint: wrong inverse");
+        inv
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        if *self == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+
+        io::write(FD_BLS12_381_SQRT, &self.to_be_bytes());
+        let is_square = io::read_vec()[0] == 1;
+        let root = Self::from_be_bytes(&io::read_vec()) * Self::ONE;
+
+        // Otherwise the root is of `NQR * self`, which proves that `self` is not a square.
+        let square = if is_square { *self } else { Self::NQR * *self };
+        assert!(root.square() == square, "invalid hint: wrong square root");
+        is_square.then_some(root)
+    }
+
+    fn sgn0(&self) -> bool {
+        self.0[0] & 1 == 1
+    }
+}
+
+impl Bls12381Fp2 {
+    /// The non-square `1 + u` used by `hook_bls12_381_fp2_sqrt`.
+    const NQR: Self =
+        Self([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    /// Creates an element from its coefficients.
+    pub fn new(c0: Bls12381Fp, c1: Bls12381Fp) -> Self {
+        let mut words = [0; 24];
+        words[..12].copy_from_slice(&c0.0);
+        words[12..].copy_from_slice(&c1.0);
+        Self(words)
+    }
+
+    /// The coefficient `c0`.
+    pub fn c0(&self) -> Bls12381Fp {
+        Bls12381Fp(self.0[..12].try_into().unwrap())
+    }
+
+    /// The coefficient `c1`.
+    pub fn c1(&self) -> Bls12381Fp {
+        Bls12381Fp(self.0[12..].try_into().unwrap())
+    }
+}
+
+impl SswuField for Bls12381Fp2 {
+    const ZERO: Self = Self([0; 24]);
+    const ONE: Self =
+        Self([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    const HASH_LEN: usize = 2 * Bls12381Fp::HASH_LEN;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let (c0, c1) = bytes.split_at(Bls12381Fp::HASH_LEN);
+        Self::new(Bls12381Fp::from_uniform_bytes(c0), Bls12381Fp::from_uniform_bytes(c1))
+    }
+
+    fn inv0(&self) -> Self {
+        // `1 / (c0 + c1 * u) = (c0 - c1 * u) / (c0^2 + c1^2)`.
+        let (c0, c1) = (self.c0(), self.c1());
+        let norm_inv = (c0.square() + c1.square()).inv0();
+        Self::new(c0 * norm_inv, -(c1 * norm_inv))
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        if *self == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+
+        let mut input = [0u8; 96];
+        input[..48].copy_from_slice(&self.c0().to_be_bytes());
+        input[48..].copy_from_slice(&self.c1().to_be_bytes());
+        io::write(FD_BLS12_381_FP2_SQRT, &input);
+        let is_square = io::read_vec()[0] == 1;
+        let root = io::read_vec();
+        let root = Self::new(
+            Bls12381Fp::from_be_bytes(&root[..48]),
+            Bls12381Fp::from_be_bytes(&root[48..]),
+        ) * Self::ONE;
+
+        // Otherwise the root is of `NQR * self`, which proves that `self` is not a square.
+        let square = if is_square { *self } else { Self::NQR * *self };
+        assert!(root.square() == square, "invalid hint: wrong square root");
+        is_square.then_some(root)
+    }
+
+    fn sgn0(&self) -> bool {
+        let (c0, c1) = (self.c0(), self.c1());
+        c0.sgn0() || (c0 == Bls12381Fp::ZERO && c1.sgn0())
+    }
+}
+
+impl Secp256k1Fp {
+    /// The element `2^256`, which is `2^32 + 977`.
+    const TWO_POW_256: Self = Self([977, 1, 0, 0, 0, 0, 0, 0]);
+
+    /// The non-square `-1` used by `hook_fp_sqrt`.
+    const NQR: Self = Self([
+        0xfffffc2e, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+        0xffffffff,
+    ]);
+
+    /// Creates an element from at most 32 big endian bytes.
+    pub fn from_be_bytes(bytes: &[u8]) -> Self {
+        Self(words_from_be_bytes(bytes))
+    }
+
+    /// The big endian encoding of the element.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        words_to_be_bytes(&self.0)
+    }
+}
+
+impl SswuField for Secp256k1Fp {
+    const ZERO: Self = Self([0; 8]);
+    const ONE: Self = Self([1, 0, 0, 0, 0, 0, 0, 0]);
+    const HASH_LEN: usize = 48;
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let (hi, lo) = bytes.split_at(16);
+        Self::from_be_bytes(hi) * Self::TWO_POW_256 + Self::from_be_bytes(lo)
+    }
+
+    fn inv0(&self) -> Self {
+        if *self == Self::ZERO {
+            return Self::ZERO;
+        }
+
+        let mut input = Vec::with_capacity(4 + 2 * 32);
+        input.extend_from_slice(&32u32.to_be_bytes());
+        input.extend_from_slice(&self.to_be_bytes());
+        input.extend_from_slice(&SECP256K1_MODULUS);
+        io::write(FD_FP_INV, &input);
+        let inv = Self::from_be_bytes(&io::read_vec()) * Self::ONE;
+        assert!(*self * inv == Self::ONE, "invalid hint: wrong inverse");
+        inv
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        if *self == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+
+        let mut input = Vec::with_capacity(4 + 3 * 32);
+        input.extend_from_slice(&32u32.to_be_bytes());
+        input.extend_from_slice(&self.to_be_bytes());
+        input.extend_from_slice(&SECP256K1_MODULUS);
+        input.extend_from_slice(&Self::NQR.to_be_bytes());
+        io::write(FD_FP_SQRT, &input);
+        let is_square = io::read_vec()[0] == 1;
+        let root = Self::from_be_bytes(&io::read_vec()) * Self::ONE;
+
+        // Otherwise the root is of `NQR * self`, which proves that `self` is not a square.
+        let square = if is_square { *self } else { Self::NQR * *self };
+        assert!(root.square() == square, "invalid hint: wrong square root");
+        is_square.then_some(root)
+    }
+
+    fn sgn0(&self) -> bool {
+        self.0[0] & 1 == 1
+    }
+}
+
+/// Converts big endian bytes to little endian words, padding with zeros.
+fn words_from_be_bytes<const W: usize>(bytes: &[u8]) -> [u32; W] {
+    assert!(bytes.len() <= 4 * W, "too many bytes for a field element");
+    let mut padded = vec![0u8; 4 * W];
+    padded[4 * W - bytes.len()..].copy_from_slice(bytes);
+
+    let mut words = [0u32; W];
+    for (word, chunk) in words.iter_mut().rev().zip(padded.chunks_exact(4)) {
+        *word = u32::from_be_bytes(chunk.try_into().unwrap());
+    }
+    words
+}
+
+/// Converts little endian words to big endian bytes.
+fn words_to_be_bytes<const W: usize, const B: usize>(words: &[u32; W]) -> [u8; B] {
+    let mut bytes = [0u8; B];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(words.iter().rev()) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    bytes
+}
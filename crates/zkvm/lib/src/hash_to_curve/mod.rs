@@ -0,0 +1,250 @@
+//! Hashing to elliptic curves as specified in RFC 9380.
+//!
+//! The suites `BLS12381G1_XMD:SHA-256_SSWU_RO_`, `BLS12381G2_XMD:SHA-256_SSWU_RO_` and
+//! `secp256k1_XMD:SHA-256_SSWU_RO_` are supported, along with their `_NU_` variants. A message is
+//! expanded with [`expand_message_xmd`] on the SHA-256 precompiles, and every field element is sent
+//! through the simplified SWU map onto an isogenous curve and then through the isogeny onto the
+//! target curve.
+//!
+//! The field arithmetic runs on the `fptower` Fp and Fp2 precompiles. Inverses and square roots
+//! are hinted by hooks and checked in the guest, so a dishonest host can only make the hash panic.
+
+mod bls12381;
+mod field;
+mod secp256k1;
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+pub use bls12381::{encode_to_g1, encode_to_g2, hash_to_g1, hash_to_g2, Bls12381G1, Bls12381G2};
+pub use field::{Bls12381Fp, Bls12381Fp2, Secp256k1Fp};
+pub use secp256k1::{encode_to_secp256k1, hash_to_secp256k1, Secp256k1};
+
+use crate::{merkle::SHA256_IV, syscall_sha256_compress, syscall_sha256_extend};
+
+/// The maximum length in bytes of a domain separation tag.
+pub const MAX_DST_LEN: usize = 255;
+
+/// A field on which the simplified SWU map is computed.
+pub trait SswuField:
+    'static
+    + Copy
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// The number of uniform bytes hashed into one field element, which is `m * L` in RFC 9380.
+    const HASH_LEN: usize;
+
+    /// Reduces `HASH_LEN` uniform big endian bytes to a field element, as in `hash_to_field`.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+
+    /// The inverse of the element, or zero if the element is zero.
+    fn inv0(&self) -> Self;
+
+    /// A square root of the element, or `None` if the element is not a square.
+    fn sqrt(&self) -> Option<Self>;
+
+    /// The sign of the element, `sgn0` in RFC 9380.
+    fn sgn0(&self) -> bool;
+
+    /// The square of the element.
+    fn square(&self) -> Self {
+        *self * *self
+    }
+}
+
+/// A curve that is hashed to through the simplified SWU map on an isogenous curve
+/// `E': y^2 = x^3 + A' * x + B'`.
+pub trait SswuCurve {
+    /// The base field of the curve.
+    type Field: SswuField;
+
+    /// The coefficient `A'` of the isogenous curve.
+    const A: Self::Field;
+
+    /// The coefficient `B'` of the isogenous curve.
+    const B: Self::Field;
+
+    /// The non-square `Z` of the suite.
+    const Z: Self::Field;
+
+    /// The coefficients of the numerator of the isogeny's x-coordinate, in ascending degree.
+    const ISO_X_NUM: &'static [Self::Field];
+
+    /// The coefficients of the denominator of the isogeny's x-coordinate, in ascending degree.
+    const ISO_X_DEN: &'static [Self::Field];
+
+    /// The coefficients of the numerator of the isogeny's y-coordinate, in ascending degree.
+    const ISO_Y_NUM: &'static [Self::Field];
+
+    /// The coefficients of the denominator of the isogeny's y-coordinate, in ascending degree.
+    const ISO_Y_DEN: &'static [Self::Field];
+}
+
+/// Expands a message into `len` uniform bytes with SHA-256, as specified in RFC 9380, section
+/// 5.3.1.
+///
+/// # Panics
+///
+/// Panics if `len` is larger than 8160 or if the tag is longer than [`MAX_DST_LEN`].
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    let ell = len.div_ceil(32);
+    assert!(ell <= 255, "expand_message_xmd: requested length is too large");
+    assert!(dst.len() <= MAX_DST_LEN, "expand_message_xmd: domain separation tag is too long");
+    let dst_len = [dst.len() as u8];
+
+    let mut hasher = Sha256::new();
+    hasher.update(&[0; 64]);
+    hasher.update(msg);
+    hasher.update(&(len as u16).to_be_bytes());
+    hasher.update(&[0]);
+    hasher.update(dst);
+    hasher.update(&dst_len);
+    let b_0 = hasher.finalize();
+
+    // `b_1 = H(b_0 || 1 || DST')` and `b_i = H((b_0 xor b_(i - 1)) || i || DST')`.
+    let mut out = Vec::with_capacity(ell * 32);
+    let mut b_i = [0u8; 32];
+    for i in 1..=ell {
+        let mut chained = b_0;
+        for (byte, prev) in chained.iter_mut().zip(b_i.iter()) {
+            *byte ^= prev;
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(&chained);
+        hasher.update(&[i as u8]);
+        hasher.update(dst);
+        hasher.update(&dst_len);
+        b_i = hasher.finalize();
+        out.extend_from_slice(&b_i);
+    }
+
+    out.truncate(len);
+    out
+}
+
+/// Hashes a message to `count` field elements, as specified in RFC 9380, section 5.2.
+pub fn hash_to_field<F: SswuField>(msg: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+    expand_message_xmd(msg, dst, count * F::HASH_LEN)
+        .chunks_exact(F::HASH_LEN)
+        .map(F::from_uniform_bytes)
+        .collect()
+}
+
+/// Maps a field element to an affine point of the curve, or `None` for the point at infinity.
+///
+/// This is the simplified SWU map of RFC 9380, section 6.6.2, followed by the isogeny map.
+pub fn map_to_curve<C: SswuCurve>(u: &C::Field) -> Option<(C::Field, C::Field)> {
+    let (x, y) = map_to_curve_simple_swu::<C>(u);
+    iso_map::<C>(&x, &y)
+}
+
+/// Maps a field element to an affine point of the isogenous curve.
+fn map_to_curve_simple_swu<C: SswuCurve>(u: &C::Field) -> (C::Field, C::Field) {
+    let one = C::Field::ONE;
+    let zu2 = C::Z * u.square();
+    let tv1 = zu2.square() + zu2;
+
+    let x1 = if tv1 == C::Field::ZERO {
+        C::B * (C::Z * C::A).inv0()
+    } else {
+        -C::B * C::A.inv0() * (one + tv1.inv0())
+    };
+    let gx1 = (x1.square() + C::A) * x1 + C::B;
+
+    // If `gx1` is not a square then `gx2 = Z^3 * u^6 * gx1` is, since `Z` is not a square.
+    let (x, y) = match gx1.sqrt() {
+        Some(y1) => (x1, y1),
+        None => {
+            let x2 = zu2 * x1;
+            let gx2 = (x2.square() + C::A) * x2 + C::B;
+            (x2, gx2.sqrt().expect("gx2 must be a square when gx1 is not"))
+        }
+    };
+
+    if u.sgn0() == y.sgn0() {
+        (x, y)
+    } else {
+        (x, -y)
+    }
+}
+
+/// Maps an affine point of the isogenous curve to the curve, or `None` for the point at infinity.
+fn iso_map<C: SswuCurve>(x: &C::Field, y: &C::Field) -> Option<(C::Field, C::Field)> {
+    let x_den = eval_polynomial(C::ISO_X_DEN, x);
+    let y_den = eval_polynomial(C::ISO_Y_DEN, x);
+    if x_den == C::Field::ZERO || y_den == C::Field::ZERO {
+        return None;
+    }
+
+    let x_num = eval_polynomial(C::ISO_X_NUM, x);
+    let y_num = eval_polynomial(C::ISO_Y_NUM, x);
+    Some((x_num * x_den.inv0(), *y * y_num * y_den.inv0()))
+}
+
+/// Evaluates a polynomial with coefficients in ascending degree at `x`.
+fn eval_polynomial<F: SswuField>(coefficients: &[F], x: &F) -> F {
+    coefficients.iter().rev().fold(F::ZERO, |acc, coefficient| acc * *x + *coefficient)
+}
+
+/// An incremental SHA-256 hasher on the `SHA_EXTEND` and `SHA_COMPRESS` precompiles.
+struct Sha256 {
+    state: [u32; 8],
+    block: [u8; 64],
+    len: usize,
+}
+
+impl Sha256 {
+    fn new() -> Self {
+        Self { state: SHA256_IV, block: [0; 64], len: 0 }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let offset = self.len % 64;
+            let n = data.len().min(64 - offset);
+            self.block[offset..offset + n].copy_from_slice(&data[..n]);
+            self.len += n;
+            data = &data[n..];
+
+            if offset + n == 64 {
+                self.compress();
+            }
+        }
+    }
+
+    fn finalize(mut self) -> [u8; 32] {
+        let bit_len = (self.len as u64) * 8;
+        self.update(&[0x80]);
+        while self.len % 64 != 56 {
+            self.update(&[0]);
+        }
+        self.update(&bit_len.to_be_bytes());
+
+        let mut hash = [0u8; 32];
+        for (bytes, word) in hash.chunks_exact_mut(4).zip(self.state.iter()) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+        hash
+    }
+
+    fn compress(&mut self) {
+        let mut w = [0u32; 64];
+        for (word, bytes) in w.iter_mut().zip(self.block.chunks_exact(4)) {
+            *word = u32::from_be_bytes(bytes.try_into().unwrap());
+        }
+        unsafe {
+            syscall_sha256_extend(&mut w);
+            syscall_sha256_compress(&mut w, &mut self.state);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! The `secp256k1_XMD:SHA-256_SSWU_*` suites, which map to secp256k1 through a 3-isogeny.
+
+use super::{hash_to_field, map_to_curve, Secp256k1Fp, SswuCurve};
+use crate::{
+    secp256k1::Secp256k1Point,
+    utils::{AffinePoint, WeierstrassAffinePoint},
+};
+
+/// The secp256k1 suite.
+pub struct Secp256k1;
+
+impl SswuCurve for Secp256k1 {
+    type Field = Secp256k1Fp;
+
+    const A: Secp256k1Fp = A;
+    const B: Secp256k1Fp = B;
+    const Z: Secp256k1Fp = Z;
+    const ISO_X_NUM: &'static [Secp256k1Fp] = &ISO_X_NUM;
+    const ISO_X_DEN: &'static [Secp256k1Fp] = &ISO_X_DEN;
+    const ISO_Y_NUM: &'static [Secp256k1Fp] = &ISO_Y_NUM;
+    const ISO_Y_DEN: &'static [Secp256k1Fp] = &ISO_Y_DEN;
+}
+
+/// Hashes a message to secp256k1 with the `secp256k1_XMD:SHA-256_SSWU_RO_` suite.
+pub fn hash_to_secp256k1(msg: &[u8], dst: &[u8]) -> Secp256k1Point {
+    let u = hash_to_field(msg, dst, 2);
+    let mut point = curve_point(map_to_curve::<Secp256k1>(&u[0]));
+    point.weierstrass_add_assign(&curve_point(map_to_curve::<Secp256k1>(&u[1])));
+    point
+}
+
+/// Encodes a message to secp256k1 with the `secp256k1_XMD:SHA-256_SSWU_NU_` suite.
+pub fn encode_to_secp256k1(msg: &[u8], dst: &[u8]) -> Secp256k1Point {
+    let u = hash_to_field(msg, dst, 1);
+    curve_point(map_to_curve::<Secp256k1>(&u[0]))
+}
+
+fn curve_point(point: Option<(Secp256k1Fp, Secp256k1Fp)>) -> Secp256k1Point {
+    match point {
+        Some((x, y)) => {
+            let mut limbs = [0; 16];
+            limbs[..8].copy_from_slice(&x.0);
+            limbs[8..].copy_from_slice(&y.0);
+            Secp256k1Point::new(limbs)
+        }
+        None => Secp256k1Point::infinity(),
+    }
+}
+
+/// The coefficient `A'` of the curve 3-isogenous to secp256k1.
+const A: Secp256k1Fp = Secp256k1Fp([
+    0x1a444533, 0x405447c0, 0xcb6f0e5d, 0xe953d363, 0xf0f5d272, 0xa08a5558, 0xdd661adc, 0x3f8731ab,
+]);
+
+/// The coefficient `B' = 1771` of the curve 3-isogenous to secp256k1.
+const B: Secp256k1Fp = Secp256k1Fp([
+    0x000006eb, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+]);
+
+/// The non-square `Z = -11` of the suite.
+const Z: Secp256k1Fp = Secp256k1Fp([
+    0xfffffc24, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+]);
+
+/// The coefficients of the 3-isogeny map, in ascending degree.
+const ISO_X_NUM: [Secp256k1Fp; 4] = [
+    Secp256k1Fp([
+        0xaaaaa8c7, 0x8e38e38d, 0xe38e38e3, 0x38e38e38, 0x8e38e38e, 0xe38e38e3, 0x38e38e38,
+        0x8e38e38e,
+    ]),
+    Secp256k1Fp([
+        0xf17c6581, 0xdfff1044, 0x0bf63b92, 0xd595d2fc, 0xa7fd44c5, 0xb9f315ce, 0x0bc321d5,
+        0x07d3d4c8,
+    ]),
+    Secp256k1Fp([
+        0x3d9dd262, 0x4ecbd0b5, 0x037c4031, 0xe4506144, 0xca25caec, 0xe2a413de, 0x23f234e6,
+        0x534c328d,
+    ]),
+    Secp256k1Fp([
+        0xaaaaa88c, 0x8e38e38d, 0xe38e38e3, 0x38e38e38, 0x8e38e38e, 0xe38e38e3, 0x38e38e38,
+        0x8e38e38e,
+    ]),
+];
+const ISO_X_DEN: [Secp256k1Fp; 3] = [
+    Secp256k1Fp([
+        0x781eb49b, 0x9fe6b745, 0x42f8487d, 0x86cd4095, 0xb7b640dd, 0x9ca34ccb, 0x3d94918a,
+        0xd3577119,
+    ]),
+    Secp256k1Fp([
+        0x2a8c6d14, 0xc52a5661, 0x1f5e41bb, 0x06d36b64, 0x1b542254, 0xf7c4b2d5, 0x4383dc1d,
+        0xedadc6f6,
+    ]),
+    Secp256k1Fp([
+        0x00000001, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000,
+    ]),
+];
+const ISO_Y_NUM: [Secp256k1Fp; 4] = [
+    Secp256k1Fp([
+        0x8e38e23c, 0xa12f684b, 0x12f684bd, 0x2f684bda, 0xf684bda1, 0x684bda12, 0x84bda12f,
+        0x4bda12f6,
+    ]),
+    Secp256k1Fp([
+        0x201d71a3, 0xdffc90fc, 0xd686da6f, 0x647ab046, 0x12a0a6d5, 0xa9d0a54b, 0xd5cb7c0f,
+        0xc75e0c32,
+    ]),
+    Secp256k1Fp([
+        0x9ecee931, 0xa765e85a, 0x01be2018, 0x722830a2, 0x6512e576, 0x715209ef, 0x91f91a73,
+        0x29a61946,
+    ]),
+    Secp256k1Fp([
+        0x38e38d84, 0x84bda12f, 0x4bda12f6, 0xbda12f68, 0xda12f684, 0xa12f684b, 0x12f684bd,
+        0x2f684bda,
+    ]),
+];
+const ISO_Y_DEN: [Secp256k1Fp; 4] = [
+    Secp256k1Fp([
+        0xfffff93b, 0xfffffffe, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+        0xffffffff,
+    ]),
+    Secp256k1Fp([
+        0x685c2573, 0xdfb425d2, 0xc8e8d978, 0x9467c1bf, 0x2722c298, 0xd5e9e663, 0xb8bdb49f,
+        0x7a06534b,
+    ]),
+    Secp256k1Fp([
+        0xbfd2a76f, 0xa7bf8192, 0x2f0d6299, 0x0a3d2116, 0xa8fe337e, 0xf3a70c3f, 0x6545ca2c,
+        0x6484aa71,
+    ]),
+    Secp256k1Fp([
+        0x00000001, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+        0x00000000,
+    ]),
+];
@@ -3,18 +3,29 @@
 //! Documentation for these syscalls can be found in the zkVM entrypoint
 //! `monerochan_runtime::syscalls` module.
 
+pub mod aes;
+pub mod bigint;
 pub mod bls12381;
 pub mod bn254;
+pub mod chacha20;
 
 #[cfg(feature = "ecdsa")]
 pub mod ecdsa;
 
 pub mod ed25519;
+pub mod grumpkin;
+pub mod hash_to_curve;
 pub mod io;
+pub mod merkle;
+pub mod monero;
+pub mod pairing;
+pub mod pasta;
+pub mod ristretto255;
 pub mod secp256k1;
 pub mod secp256r1;
 pub mod unconstrained;
 pub mod utils;
+pub mod x25519;
 
 #[cfg(feature = "verify")]
 pub mod verify;
@@ -41,6 +52,21 @@ extern "C" {
     /// Executes an Ed25519 curve decompression on the given point.
     pub fn syscall_ed_decompress(point: &mut [u8; 64]);
 
+    /// Executes a step of the X25519 Montgomery ladder on the given state.
+    pub fn syscall_x25519_ladder_step(state: *mut [u32; 32], x1: *const [u32; 8]);
+
+    /// Encrypts the given block in place with AES-128, given the expanded round keys.
+    pub fn syscall_aes128_encrypt_block(block: *mut [u32; 4], round_keys: *const [u32; 44]);
+
+    /// Encrypts the given block in place with AES-256, given the expanded round keys.
+    pub fn syscall_aes256_encrypt_block(block: *mut [u32; 4], round_keys: *const [u32; 60]);
+
+    /// Executes the ChaCha20 block function on the given state.
+    pub fn syscall_chacha20_block(state: *mut [u32; 16]);
+
+    /// Executes the user precompile registered in the slot `code`.
+    pub fn syscall_user_precompile(code: u32, arg1: *mut u32, arg2: *const u32);
+
     /// Executes an Sepc256k1 curve addition on the given points.
     pub fn syscall_secp256k1_add(p: *mut [u32; 16], q: *const [u32; 16]);
 
@@ -65,6 +91,24 @@ extern "C" {
     /// Executes a Bn254 curve doubling on the given point.
     pub fn syscall_bn254_double(p: *mut [u32; 16]);
 
+    /// Executes a Pallas curve addition on the given points.
+    pub fn syscall_pallas_add(p: *mut [u32; 16], q: *const [u32; 16]);
+
+    /// Executes a Pallas curve doubling on the given point.
+    pub fn syscall_pallas_double(p: *mut [u32; 16]);
+
+    /// Executes a Vesta curve addition on the given points.
+    pub fn syscall_vesta_add(p: *mut [u32; 16], q: *const [u32; 16]);
+
+    /// Executes a Vesta curve doubling on the given point.
+    pub fn syscall_vesta_double(p: *mut [u32; 16]);
+
+    /// Executes a Grumpkin curve addition on the given points.
+    pub fn syscall_grumpkin_add(p: *mut [u32; 16], q: *const [u32; 16]);
+
+    /// Executes a Grumpkin curve doubling on the given point.
+    pub fn syscall_grumpkin_double(p: *mut [u32; 16]);
+
     /// Executes a BLS12-381 curve addition on the given points.
     pub fn syscall_bls12381_add(p: *mut [u32; 24], q: *const [u32; 24]);
 
@@ -84,6 +128,19 @@ extern "C" {
         lo: *mut [u32; 64],
         hi: *mut [u32; 8],
     );
+
+    /// Executes an uint256 addition with carry on the given inputs.
+    pub fn syscall_uint256_add_with_carry(x: *mut [u32; 9], y: *const [u32; 8]);
+
+    /// Executes an uint256 subtraction with borrow on the given inputs.
+    pub fn syscall_uint256_sub_with_borrow(x: *mut [u32; 9], y: *const [u32; 8]);
+
+    /// Executes an uint2048 multiplication on the given inputs.
+    pub fn syscall_uint2048_mulmod(x: *mut [u32; 64], y: *const [u32; 64]);
+
+    /// Executes an uint4096 multiplication on the given inputs.
+    pub fn syscall_uint4096_mulmod(x: *mut [u32; 128], y: *const [u32; 128]);
+
     /// Enters unconstrained mode.
     pub fn syscall_enter_unconstrained() -> bool;
 
@@ -150,6 +207,102 @@ extern "C" {
     /// Executes a BN254 Fp2 multiplication on the given inputs.
     pub fn syscall_bn254_fp2_mulmod(p: *mut u32, q: *const u32);
 
+    /// Executes a BLS12-381 Fp6 multiplication on the given inputs.
+    pub fn syscall_bls12381_fp6_mulmod(p: *mut u32, q: *const u32);
+
+    /// Executes a BLS12-381 Fp12 multiplication on the given inputs.
+    pub fn syscall_bls12381_fp12_mulmod(p: *mut u32, q: *const u32);
+
+    /// Executes a BLS12-381 G2 addition on the given points.
+    pub fn syscall_bls12381_g2_add(p: *mut [u32; 48], q: *const [u32; 48]);
+
+    /// Executes a BLS12-381 G2 doubling on the given point.
+    pub fn syscall_bls12381_g2_double(p: *mut [u32; 48]);
+
+    /// Executes a BN254 Fp6 multiplication on the given inputs.
+    pub fn syscall_bn254_fp6_mulmod(p: *mut u32, q: *const u32);
+
+    /// Executes a BN254 Fp12 multiplication on the given inputs.
+    pub fn syscall_bn254_fp12_mulmod(p: *mut u32, q: *const u32);
+
+    /// Executes a BN254 G2 addition on the given points.
+    pub fn syscall_bn254_g2_add(p: *mut [u32; 32], q: *const [u32; 32]);
+
+    /// Executes a BN254 G2 doubling on the given point.
+    pub fn syscall_bn254_g2_double(p: *mut [u32; 32]);
+
+    /// Executes one chunk of a secp256k1 fixed-window scalar multiplication.
+    pub fn syscall_secp256k1_scalar_mul(p: *mut [u32; 16], input: *const u32);
+
+    /// Executes one chunk of a secp256k1 fixed-window two-point multi-scalar multiplication.
+    pub fn syscall_secp256k1_msm(p: *mut [u32; 16], input: *const u32);
+
+    /// Executes one chunk of a secp256r1 fixed-window scalar multiplication.
+    pub fn syscall_secp256r1_scalar_mul(p: *mut [u32; 16], input: *const u32);
+
+    /// Executes one chunk of a secp256r1 fixed-window two-point multi-scalar multiplication.
+    pub fn syscall_secp256r1_msm(p: *mut [u32; 16], input: *const u32);
+
+    /// Executes one chunk of a BN254 fixed-window scalar multiplication.
+    pub fn syscall_bn254_scalar_mul(p: *mut [u32; 16], input: *const u32);
+
+    /// Executes one chunk of a BN254 fixed-window two-point multi-scalar multiplication.
+    pub fn syscall_bn254_msm(p: *mut [u32; 16], input: *const u32);
+
+    /// Executes one chunk of a BLS12-381 fixed-window scalar multiplication.
+    pub fn syscall_bls12381_scalar_mul(p: *mut [u32; 24], input: *const u32);
+
+    /// Executes one chunk of a BLS12-381 fixed-window two-point multi-scalar multiplication.
+    pub fn syscall_bls12381_msm(p: *mut [u32; 24], input: *const u32);
+
+    /// Executes a secp256k1 base field addition on the given inputs.
+    pub fn syscall_secp256k1_fp_addmod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256k1 base field subtraction on the given inputs.
+    pub fn syscall_secp256k1_fp_submod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256k1 base field multiplication on the given inputs.
+    pub fn syscall_secp256k1_fp_mulmod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256k1 scalar field addition on the given inputs.
+    pub fn syscall_secp256k1_fn_addmod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256k1 scalar field subtraction on the given inputs.
+    pub fn syscall_secp256k1_fn_submod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256k1 scalar field multiplication on the given inputs.
+    pub fn syscall_secp256k1_fn_mulmod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256r1 base field addition on the given inputs.
+    pub fn syscall_secp256r1_fp_addmod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256r1 base field subtraction on the given inputs.
+    pub fn syscall_secp256r1_fp_submod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256r1 base field multiplication on the given inputs.
+    pub fn syscall_secp256r1_fp_mulmod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256r1 scalar field addition on the given inputs.
+    pub fn syscall_secp256r1_fn_addmod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256r1 scalar field subtraction on the given inputs.
+    pub fn syscall_secp256r1_fn_submod(p: *mut u32, q: *const u32);
+
+    /// Executes a secp256r1 scalar field multiplication on the given inputs.
+    pub fn syscall_secp256r1_fn_mulmod(p: *mut u32, q: *const u32);
+
+    /// Recovers the secp256k1 public key of an ECDSA signature.
+    pub fn syscall_secp256k1_ecrecover(out: *mut [u32; 17], input: *const [u32; 25]);
+
+    /// Recovers the secp256r1 public key of an ECDSA signature.
+    pub fn syscall_secp256r1_ecrecover(out: *mut [u32; 17], input: *const [u32; 25]);
+
+    /// Verifies a secp256k1 ECDSA signature.
+    pub fn syscall_secp256k1_ecdsa_verify(out: *mut u32, input: *const [u32; 40]);
+
+    /// Verifies a secp256r1 ECDSA signature.
+    pub fn syscall_secp256r1_ecdsa_verify(out: *mut u32, input: *const [u32; 40]);
+
     /// Reads a buffer from the input stream.
     pub fn read_vec_raw() -> ReadVecResult;
 }
@@ -0,0 +1,315 @@
+//! Merkle proof verification for binary and sparse Merkle trees.
+//!
+//! Inner nodes are `H(left || right)` of two 32-byte children, and leaves are 32-byte digests that
+//! the caller hashes with whatever domain separation its protocol uses. The SHA-256 and Keccak-256
+//! hashers run on the `SHA_EXTEND`/`SHA_COMPRESS` and `KECCAK_PERMUTE` precompiles, and no
+//! verification allocates per node.
+//!
+//! The host side helpers that build trees and proofs in this format live in `monerochan::merkle`.
+
+use core::marker::PhantomData;
+
+use p3_baby_bear::BabyBear;
+use p3_field::{AbstractField, PrimeField32};
+use p3_symmetric::CryptographicHasher;
+use serde::{Deserialize, Serialize};
+use monerochan_primitives::POSEIDON2_HASHER;
+
+use crate::{syscall_keccak_permute, syscall_sha256_compress, syscall_sha256_extend};
+
+/// The depth of a sparse Merkle tree, in which the path of a leaf is the bits of its 32-byte key.
+pub const SPARSE_MERKLE_DEPTH: usize = 256;
+
+/// The value of an absent leaf in a sparse Merkle tree.
+pub const EMPTY_LEAF: [u8; 32] = [0; 32];
+
+/// A two-to-one hash of Merkle tree nodes.
+pub trait MerkleHasher {
+    /// Hashes the concatenation of two child nodes into their parent.
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32];
+}
+
+/// SHA-256, with one `SHA_EXTEND` and two `SHA_COMPRESS` calls per node.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha256Hasher;
+
+/// The initial SHA-256 hash value.
+pub(crate) const SHA256_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// The extended message schedule of the padding block of a 64-byte message, which is the same for
+/// every node.
+const SHA256_PADDING_SCHEDULE: [u32; 64] = [
+    0x80000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000,
+    0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000000, 0x00000200,
+    0x80000000, 0x01400000, 0x00205000, 0x00005088, 0x22000800, 0x22550014, 0x05089742, 0xa0000020,
+    0x5a880000, 0x005c9400, 0x0016d49d, 0xfa801f00, 0xd33225d0, 0x11675959, 0xf6e6bfda, 0xb30c1549,
+    0x08b2b050, 0x9d7c4c27, 0x0ce2a393, 0x88e6e1ea, 0xa52b4335, 0x67a16f49, 0xd732016f, 0x4eeb2e91,
+    0x5dbf55e5, 0x8eee2335, 0xe2bc5ec2, 0xa83f4394, 0x45ad78f7, 0x36f3d0cd, 0xd99c05e8, 0xb0511dc7,
+    0x69bc7ac4, 0xbd11375b, 0xe3ba71e5, 0x3b209ff2, 0x18feee17, 0xe25ad9e7, 0x13375046, 0x0515089d,
+    0x4f0d0f04, 0x2627484e, 0x310128d2, 0xc668b434, 0x420841cc, 0x62d311b8, 0xe59ba771, 0x85a7a484,
+];
+
+impl MerkleHasher for Sha256Hasher {
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut w = [0u32; 64];
+        for (word, bytes) in w.iter_mut().zip(left.chunks_exact(4).chain(right.chunks_exact(4))) {
+            *word = u32::from_be_bytes(bytes.try_into().unwrap());
+        }
+
+        let mut state = SHA256_IV;
+        let mut padding = SHA256_PADDING_SCHEDULE;
+        unsafe {
+            syscall_sha256_extend(&mut w);
+            syscall_sha256_compress(&mut w, &mut state);
+            syscall_sha256_compress(&mut padding, &mut state);
+        }
+
+        let mut hash = [0u8; 32];
+        for (bytes, word) in hash.chunks_exact_mut(4).zip(state.iter()) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+        hash
+    }
+}
+
+/// Keccak-256 with the original Keccak padding, as used by Ethereum, with one `KECCAK_PERMUTE`
+/// call per node.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Keccak256Hasher;
+
+impl MerkleHasher for Keccak256Hasher {
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut state = [0u64; 25];
+        for (lane, bytes) in state.iter_mut().zip(left.chunks_exact(8).chain(right.chunks_exact(8)))
+        {
+            *lane = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        // The 64-byte message fits in a single 136-byte block.
+        state[8] ^= 0x01;
+        state[16] ^= 0x80 << 56;
+        unsafe {
+            syscall_keccak_permute(&mut state);
+        }
+
+        let mut hash = [0u8; 32];
+        for (bytes, lane) in hash.chunks_exact_mut(8).zip(state.iter()) {
+            bytes.copy_from_slice(&lane.to_le_bytes());
+        }
+        hash
+    }
+}
+
+/// The BabyBear Poseidon2 sponge used by the recursion circuits, computed in software.
+///
+/// A node is eight BabyBear elements encoded as little endian `u32`s, and the input words are
+/// reduced into the field, so leaves should be canonical Poseidon2 digests.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Poseidon2Hasher;
+
+impl MerkleHasher for Poseidon2Hasher {
+    fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let input = left
+            .chunks_exact(4)
+            .chain(right.chunks_exact(4))
+            .map(|bytes| BabyBear::from_wrapped_u32(u32::from_le_bytes(bytes.try_into().unwrap())));
+        let digest = POSEIDON2_HASHER.hash_iter(input);
+
+        let mut hash = [0u8; 32];
+        for (bytes, element) in hash.chunks_exact_mut(4).zip(digest.iter()) {
+            bytes.copy_from_slice(&element.as_canonical_u32().to_le_bytes());
+        }
+        hash
+    }
+}
+
+/// Computes the root of a binary Merkle tree from a leaf, its index and its siblings ordered from
+/// the leaf to the root.
+pub fn compute_root<H: MerkleHasher>(
+    leaf: &[u8; 32],
+    index: u64,
+    siblings: &[[u8; 32]],
+) -> [u8; 32] {
+    let mut node = *leaf;
+    for (level, sibling) in siblings.iter().enumerate() {
+        node = if (index >> level) & 1 == 0 {
+            H::hash_pair(&node, sibling)
+        } else {
+            H::hash_pair(sibling, &node)
+        };
+    }
+    node
+}
+
+/// An inclusion proof of a single leaf of a binary Merkle tree of depth `siblings.len()`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    /// The index of the leaf.
+    pub index: u64,
+    /// The siblings on the path, ordered from the leaf to the root.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl MerkleProof {
+    /// Whether `leaf` is at the proof's index in the tree with the given root.
+    pub fn verify<H: MerkleHasher>(&self, root: &[u8; 32], leaf: &[u8; 32]) -> bool {
+        if self.siblings.len() > 64 || index_out_of_range(self.index, self.siblings.len()) {
+            return false;
+        }
+        compute_root::<H>(leaf, self.index, &self.siblings) == *root
+    }
+}
+
+/// An inclusion proof of several leaves of a binary Merkle tree, in which the nodes that can be
+/// computed from the proven leaves are not repeated.
+///
+/// The siblings are the nodes that are needed but not computable, in order of level from the
+/// leaves up, and in order of index within a level.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiProof {
+    /// The depth of the tree.
+    pub depth: u32,
+    /// The indices of the proven leaves, in strictly increasing order.
+    pub indices: Vec<u64>,
+    /// The sibling nodes not computable from the proven leaves.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+impl MultiProof {
+    /// Whether `leaves[i]` is at `indices[i]` in the tree with the given root, for every `i`.
+    ///
+    /// The nodes of each level overwrite the ones below them in a single working buffer.
+    pub fn verify<H: MerkleHasher>(&self, root: &[u8; 32], leaves: &[[u8; 32]]) -> bool {
+        let depth = self.depth as usize;
+        if leaves.is_empty() || leaves.len() != self.indices.len() || depth > 64 {
+            return false;
+        }
+        if self.indices.windows(2).any(|pair| pair[0] >= pair[1]) ||
+            index_out_of_range(self.indices[self.indices.len() - 1], depth)
+        {
+            return false;
+        }
+
+        let mut nodes =
+            self.indices.iter().copied().zip(leaves.iter().copied()).collect::<Vec<_>>();
+        let mut siblings = self.siblings.iter();
+        for _ in 0..depth {
+            let mut read = 0;
+            let mut write = 0;
+            while read < nodes.len() {
+                let (index, node) = nodes[read];
+                let parent = if index & 1 == 1 {
+                    let Some(sibling) = siblings.next() else { return false };
+                    H::hash_pair(sibling, &node)
+                } else if read + 1 < nodes.len() && nodes[read + 1].0 == index + 1 {
+                    read += 1;
+                    H::hash_pair(&node, &nodes[read].1)
+                } else {
+                    let Some(sibling) = siblings.next() else { return false };
+                    H::hash_pair(&node, sibling)
+                };
+                nodes[write] = (index >> 1, parent);
+                read += 1;
+                write += 1;
+            }
+            nodes.truncate(write);
+        }
+
+        siblings.next().is_none() && nodes.len() == 1 && nodes[0].1 == *root
+    }
+}
+
+/// A proof of the value of a key in a sparse Merkle tree, which is [`EMPTY_LEAF`] for absent keys.
+///
+/// Siblings that are the root of an empty subtree are left out and marked by a clear bit in the
+/// bitmap.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SparseMerkleProof {
+    /// Bit `i` of the little endian bitmap is set if the sibling at height `i` is present.
+    pub bitmap: [u8; 32],
+    /// The present siblings, ordered from the leaf to the root.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+/// Verifies [`SparseMerkleProof`]s against the precomputed roots of the empty subtrees.
+#[derive(Clone)]
+pub struct SparseMerkleVerifier<H> {
+    empty: [[u8; 32]; SPARSE_MERKLE_DEPTH],
+    _hasher: PhantomData<H>,
+}
+
+impl<H: MerkleHasher> SparseMerkleVerifier<H> {
+    /// Computes the roots of the empty subtrees of every height.
+    pub fn new() -> Self {
+        let mut empty = [EMPTY_LEAF; SPARSE_MERKLE_DEPTH];
+        for height in 1..SPARSE_MERKLE_DEPTH {
+            empty[height] = H::hash_pair(&empty[height - 1], &empty[height - 1]);
+        }
+        Self { empty, _hasher: PhantomData }
+    }
+
+    /// The root of the empty subtree of the given height.
+    pub fn empty_subtree(&self, height: usize) -> [u8; 32] {
+        self.empty[height]
+    }
+
+    /// The root of the empty tree.
+    pub fn empty_root(&self) -> [u8; 32] {
+        let top = &self.empty[SPARSE_MERKLE_DEPTH - 1];
+        H::hash_pair(top, top)
+    }
+
+    /// Computes the root of the tree in which `key` has `value`, or `None` if the number of
+    /// siblings does not match the bitmap.
+    ///
+    /// Bit `i` of the key, counted from the most significant bit of `key[0]`, is the direction
+    /// taken at depth `i` from the root, with a set bit going right.
+    pub fn compute_root(
+        &self,
+        key: &[u8; 32],
+        value: &[u8; 32],
+        proof: &SparseMerkleProof,
+    ) -> Option<[u8; 32]> {
+        let mut siblings = proof.siblings.iter();
+        let mut node = *value;
+        for height in 0..SPARSE_MERKLE_DEPTH {
+            let sibling = if (proof.bitmap[height / 8] >> (height % 8)) & 1 == 1 {
+                siblings.next()?
+            } else {
+                &self.empty[height]
+            };
+
+            let depth = SPARSE_MERKLE_DEPTH - 1 - height;
+            node = if (key[depth / 8] >> (7 - depth % 8)) & 1 == 0 {
+                H::hash_pair(&node, sibling)
+            } else {
+                H::hash_pair(sibling, &node)
+            };
+        }
+        siblings.next().is_none().then_some(node)
+    }
+
+    /// Whether `key` has `value` in the tree with the given root. Passing [`EMPTY_LEAF`] as the
+    /// value checks that the key is absent.
+    pub fn verify(
+        &self,
+        root: &[u8; 32],
+        key: &[u8; 32],
+        value: &[u8; 32],
+        proof: &SparseMerkleProof,
+    ) -> bool {
+        self.compute_root(key, value, proof).is_some_and(|computed| computed == *root)
+    }
+}
+
+impl<H: MerkleHasher> Default for SparseMerkleVerifier<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether `index` does not fit in a tree of the given depth.
+fn index_out_of_range(index: u64, depth: usize) -> bool {
+    depth < 64 && index >> depth != 0
+}
@@ -0,0 +1,305 @@
+//! Monero primitives on top of the Ed25519, Keccak and `UINT256_MUL` precompiles.
+//!
+//! The functions follow Monero's `crypto-ops.c` and `crypto.cpp`, so that hashes to points and key
+//! images match the ones computed by Monero nodes and wallets.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ed25519::{Ed25519AffinePoint, Ed25519FieldElement, Ed25519Scalar},
+    syscall_keccak_permute,
+    utils::AffinePoint,
+};
+
+pub mod bulletproofs_plus;
+pub use bulletproofs_plus::{BulletproofPlus, BulletproofPlusError};
+
+/// The domain separator of the CLSAG signing key aggregation hash.
+const HASH_KEY_CLSAG_AGG_0: &[u8] = b"CLSAG_agg_0";
+
+/// The domain separator of the CLSAG commitment key aggregation hash.
+const HASH_KEY_CLSAG_AGG_1: &[u8] = b"CLSAG_agg_1";
+
+/// The domain separator of the CLSAG round hash.
+const HASH_KEY_CLSAG_ROUND: &[u8] = b"CLSAG_round";
+
+/// The compressed Ed25519 base point.
+const BASE_POINT: [u8; 32] = [
+    0x58, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+    0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66, 0x66,
+];
+
+/// The order of the Ed25519 prime order subgroup as little endian bytes.
+const GROUP_ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+/// The rate of Keccak-256 in bytes.
+const KECCAK_RATE: usize = 136;
+
+/// The Montgomery curve constant `A` of Curve25519.
+const MONTGOMERY_A: Ed25519FieldElement = Ed25519FieldElement::from_u32(486662);
+
+/// `sqrt(-2 * A * (A + 2))`.
+const FFFB1: Ed25519FieldElement = Ed25519FieldElement([
+    0xcde3bdff, 0xdd58968a, 0x46d58c72, 0x2e6bfb57, 0xbe04e61c, 0xdf563ac8, 0x2529e4e8, 0x018e0410,
+]);
+
+/// `sqrt(2 * A * (A + 2))`.
+const FFFB2: Ed25519FieldElement = Ed25519FieldElement([
+    0x9f83650d, 0x2d219b7c, 0xfba90820, 0xae21fcb9, 0x3fe9a041, 0x6e2bae48, 0xfba5d309, 0x32f9e1f5,
+]);
+
+/// `sqrt(-sqrt(-1) * A * (A + 2))`.
+const FFFB3: Ed25519FieldElement = Ed25519FieldElement([
+    0x17302c66, 0x581b7d87, 0xa5964229, 0x4024ff4e, 0x3f0da2ed, 0xb8954640, 0x14c208ef, 0x674a110d,
+]);
+
+/// `sqrt(sqrt(-1) * A * (A + 2))`.
+const FFFB4: Ed25519FieldElement = Ed25519FieldElement([
+    0x494c6e67, 0x7ac2e6fc, 0x5ec0b5b6, 0x11b903f7, 0x8108bcd1, 0xd93f0b77, 0xef982406, 0x65bc0cfc,
+]);
+
+/// Monero's `cn_fast_hash`, which is Keccak-256 with the original Keccak padding.
+pub fn cn_fast_hash(data: &[u8]) -> [u8; 32] {
+    let mut state = [0u64; 25];
+
+    let mut blocks = data.chunks_exact(KECCAK_RATE);
+    for block in &mut blocks {
+        keccak_absorb(&mut state, block);
+    }
+
+    let remainder = blocks.remainder();
+    let mut last_block = [0u8; KECCAK_RATE];
+    last_block[..remainder.len()].copy_from_slice(remainder);
+    last_block[remainder.len()] ^= 0x01;
+    last_block[KECCAK_RATE - 1] ^= 0x80;
+    keccak_absorb(&mut state, &last_block);
+
+    let mut hash = [0u8; 32];
+    for (bytes, lane) in hash.chunks_exact_mut(8).zip(state.iter()) {
+        bytes.copy_from_slice(&lane.to_le_bytes());
+    }
+    hash
+}
+
+/// XORs a block into the Keccak state and permutes it.
+fn keccak_absorb(state: &mut [u64; 25], block: &[u8]) {
+    for (lane, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+        *lane ^= u64::from_le_bytes(bytes.try_into().unwrap());
+    }
+    unsafe {
+        syscall_keccak_permute(state);
+    }
+}
+
+/// Maps 32 bytes to a point on Ed25519, like Monero's `ge_fromfe_frombytes_vartime`.
+///
+/// The bytes are read as a 256-bit little endian integer reduced modulo p. The resulting point is
+/// not multiplied by the cofactor.
+pub fn ge_fromfe_frombytes_vartime(bytes: &[u8; 32]) -> Ed25519AffinePoint {
+    let u = Ed25519FieldElement::from_bytes_mod_order(bytes);
+
+    // v = 2 * u^2, w = 2 * u^2 + 1, x = w^2 - 2 * A^2 * u^2.
+    let v = u.square() + u.square();
+    let w = v + Ed25519FieldElement::ONE;
+    let mut x = w.square() - MONTGOMERY_A.square() * v;
+
+    // r_x = (w / x)^((p + 3) / 8).
+    let mut r_x = Ed25519FieldElement::sqrt_ratio_candidate(&w, &x);
+    x = r_x.square() * x;
+
+    let (z, sign) = if w - x == Ed25519FieldElement::ZERO {
+        r_x = r_x * FFFB2 * u;
+        (-MONTGOMERY_A * v, false)
+    } else if w + x == Ed25519FieldElement::ZERO {
+        r_x = r_x * FFFB1 * u;
+        (-MONTGOMERY_A * v, false)
+    } else {
+        // Otherwise w / x is not a square, and exactly one of w = x * sqrt(-1) and
+        // w = -x * sqrt(-1) holds.
+        x = x * Ed25519FieldElement::SQRT_M1;
+        r_x = if w - x == Ed25519FieldElement::ZERO { r_x * FFFB4 } else { r_x * FFFB3 };
+        (-MONTGOMERY_A, true)
+    };
+
+    if r_x.is_negative() != sign {
+        r_x = -r_x;
+    }
+
+    // The projective point is (r_x * (z + w) : z - w : z + w).
+    let y = (z - w) * (z + w).invert();
+    Ed25519AffinePoint::from_coordinates(r_x, y)
+}
+
+/// Monero's `hash_to_ec`, which hashes a public key to a point in the prime order subgroup.
+pub fn hash_to_ec(public_key: &[u8; 32]) -> Ed25519AffinePoint {
+    let mut point = ge_fromfe_frombytes_vartime(&cn_fast_hash(public_key));
+    point.mul_by_cofactor();
+    point
+}
+
+/// Monero's `generate_key_image`, which computes the compressed key image `x * Hp(P)` of the public
+/// key `P` with the little endian secret key `x`.
+pub fn generate_key_image(public_key: &[u8; 32], secret_key: &[u8; 32]) -> [u8; 32] {
+    hash_to_ec(public_key).mul_scalar(secret_key).compress()
+}
+
+/// Monero's `hash_to_scalar`, which reduces `cn_fast_hash` modulo the group order.
+pub fn hash_to_scalar(data: &[u8]) -> Ed25519Scalar {
+    Ed25519Scalar::from_bytes_mod_order(&cn_fast_hash(data))
+}
+
+/// A member of a CLSAG ring: an output key and its amount commitment.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct RingMember {
+    /// The compressed output public key `P`.
+    pub dest: [u8; 32],
+    /// The compressed amount commitment `C`.
+    pub mask: [u8; 32],
+}
+
+/// A CLSAG ring signature, as stored in RingCT transactions.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Clsag {
+    /// The responses, one for each ring member.
+    pub s: Vec<[u8; 32]>,
+    /// The challenge of the first ring member.
+    pub c1: [u8; 32],
+    /// The compressed key image `I`.
+    pub key_image: [u8; 32],
+    /// The compressed commitment key image `D`, multiplied by the inverse of the cofactor.
+    pub d: [u8; 32],
+}
+
+/// Errors that can occur when verifying a [`Clsag`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClsagError {
+    /// The ring is empty or has a different size than the responses.
+    InvalidRingSize,
+    /// A response or the challenge is not a canonical scalar.
+    NonCanonicalScalar,
+    /// A ring member or the pseudo output commitment is not a valid point.
+    InvalidPoint,
+    /// The key image is not a point of the prime order subgroup other than the identity.
+    InvalidKeyImage,
+    /// The commitment key image is not a valid point or is of small order.
+    InvalidAuxiliaryKey,
+    /// A round hash is zero.
+    ZeroChallenge,
+    /// The challenge computed for the first ring member does not match the signature.
+    ChallengeMismatch,
+}
+
+impl Clsag {
+    /// Verifies the signature over `message` for the given ring and pseudo output commitment,
+    /// following Monero's `verRctCLSAGSimple`.
+    ///
+    /// In addition, the key image is checked to be in the prime order subgroup, as Monero does
+    /// when validating transaction inputs.
+    pub fn verify(
+        &self,
+        message: &[u8; 32],
+        ring: &[RingMember],
+        pseudo_out: &[u8; 32],
+    ) -> Result<(), ClsagError> {
+        let n = ring.len();
+        if n == 0 || self.s.len() != n {
+            return Err(ClsagError::InvalidRingSize);
+        }
+
+        let s = self
+            .s
+            .iter()
+            .map(Ed25519Scalar::from_canonical_bytes)
+            .collect::<Option<Vec<_>>>()
+            .ok_or(ClsagError::NonCanonicalScalar)?;
+        let c1 =
+            Ed25519Scalar::from_canonical_bytes(&self.c1).ok_or(ClsagError::NonCanonicalScalar)?;
+
+        let key_image =
+            Ed25519AffinePoint::decompress(&self.key_image).ok_or(ClsagError::InvalidKeyImage)?;
+        if key_image.is_identity() || !key_image.mul_scalar(&GROUP_ORDER).is_identity() {
+            return Err(ClsagError::InvalidKeyImage);
+        }
+        let mut d =
+            Ed25519AffinePoint::decompress(&self.d).ok_or(ClsagError::InvalidAuxiliaryKey)?;
+        d.mul_by_cofactor();
+        if d.is_identity() {
+            return Err(ClsagError::InvalidAuxiliaryKey);
+        }
+
+        let pseudo_out_point =
+            Ed25519AffinePoint::decompress(pseudo_out).ok_or(ClsagError::InvalidPoint)?;
+        let neg_pseudo_out = -pseudo_out_point;
+        let members = ring
+            .iter()
+            .map(|member| {
+                let dest = Ed25519AffinePoint::decompress(&member.dest)?;
+                let mut mask = Ed25519AffinePoint::decompress(&member.mask)?;
+                mask.add_assign(&neg_pseudo_out);
+                Some((dest, mask))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(ClsagError::InvalidPoint)?;
+
+        // The aggregation hashes commit to the ring, the key images and the pseudo output.
+        let mut agg_to_hash = Vec::with_capacity(32 * (2 * n + 4));
+        agg_to_hash.extend_from_slice(&domain_separator(HASH_KEY_CLSAG_AGG_0));
+        ring.iter().for_each(|member| agg_to_hash.extend_from_slice(&member.dest));
+        ring.iter().for_each(|member| agg_to_hash.extend_from_slice(&member.mask));
+        agg_to_hash.extend_from_slice(&self.key_image);
+        agg_to_hash.extend_from_slice(&self.d);
+        agg_to_hash.extend_from_slice(pseudo_out);
+        let mu_p = hash_to_scalar(&agg_to_hash);
+        agg_to_hash[..32].copy_from_slice(&domain_separator(HASH_KEY_CLSAG_AGG_1));
+        let mu_c = hash_to_scalar(&agg_to_hash);
+
+        // The round hash commits to the ring, the pseudo output, the message and `L` and `R`.
+        let mut round_to_hash = Vec::with_capacity(32 * (2 * n + 5));
+        round_to_hash.extend_from_slice(&domain_separator(HASH_KEY_CLSAG_ROUND));
+        ring.iter().for_each(|member| round_to_hash.extend_from_slice(&member.dest));
+        ring.iter().for_each(|member| round_to_hash.extend_from_slice(&member.mask));
+        round_to_hash.extend_from_slice(pseudo_out);
+        round_to_hash.extend_from_slice(message);
+        let prefix_len = round_to_hash.len();
+
+        let base_point = Ed25519AffinePoint::decompress(&BASE_POINT).unwrap();
+        let mut c = c1;
+        for (i, (dest, mask)) in members.iter().enumerate() {
+            let c_p = (mu_p * c).to_bytes();
+            let c_c = (mu_c * c).to_bytes();
+            let s_i = s[i].to_bytes();
+
+            // L = s_i * G + c_p * P_i + c_c * (C_i - C_offset).
+            let l =
+                Ed25519AffinePoint::multi_scalar_mul(&[s_i, c_p, c_c], &[base_point, *dest, *mask]);
+            // R = s_i * Hp(P_i) + c_p * I + c_c * D.
+            let r = Ed25519AffinePoint::multi_scalar_mul(
+                &[s_i, c_p, c_c],
+                &[hash_to_ec(&ring[i].dest), key_image, d],
+            );
+
+            round_to_hash.truncate(prefix_len);
+            round_to_hash.extend_from_slice(&l.compress());
+            round_to_hash.extend_from_slice(&r.compress());
+            c = hash_to_scalar(&round_to_hash);
+            if c.is_zero() {
+                return Err(ClsagError::ZeroChallenge);
+            }
+        }
+
+        if c != c1 {
+            return Err(ClsagError::ChallengeMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// Pads a domain separator with zeros to 32 bytes, as Monero does for its hash keys.
+fn domain_separator(key: &[u8]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[..key.len()].copy_from_slice(key);
+    bytes
+}
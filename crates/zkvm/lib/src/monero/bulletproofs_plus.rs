@@ -0,0 +1,236 @@
+//! Bulletproofs+ range proofs, following Monero's `bulletproofs_plus.cc`.
+//!
+//! A proof shows that each of the committed amounts `V_j = gamma_j * G + v_j * H` is in
+//! `[0, 2^64)`. Verification is reduced to a single multi-scalar multiplication over
+//! `2 * M * 64 + M + 2 * log2(M * 64) + 5` points which has to be the identity.
+
+use serde::{Deserialize, Serialize};
+
+use super::{cn_fast_hash, hash_to_ec, hash_to_scalar, BASE_POINT};
+use crate::{
+    ed25519::{Ed25519AffinePoint, Ed25519Scalar},
+    utils::AffinePoint,
+};
+
+/// The number of bits of each committed amount.
+const N: usize = 64;
+
+/// The maximum number of amounts in an aggregated proof.
+pub const MAX_AMOUNTS: usize = 16;
+
+/// The domain separator of the vector generators.
+const HASH_KEY_BULLETPROOF_PLUS_EXPONENT: &[u8] = b"bulletproof_plus";
+
+/// The domain separator of the initial transcript.
+const HASH_KEY_BULLETPROOF_PLUS_TRANSCRIPT: &[u8] = b"bulletproof_plus_transcript";
+
+/// Monero's compressed amount generator `H`.
+const H: [u8; 32] = [
+    0x8b, 0x65, 0x59, 0x70, 0x15, 0x37, 0x99, 0xaf, 0x2a, 0xea, 0xdc, 0x9f, 0xf1, 0xad, 0xd0, 0xea,
+    0x6c, 0x72, 0x51, 0xd5, 0x41, 0x54, 0xcf, 0xa9, 0x2c, 0x17, 0x3a, 0x0d, 0xd3, 0x9c, 0x1f, 0x94,
+];
+
+/// An aggregated Bulletproofs+ range proof, as stored in RingCT transactions.
+///
+/// All points are compressed and multiplied by the inverse of the cofactor.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BulletproofPlus {
+    /// The amount commitments `V`.
+    pub v: Vec<[u8; 32]>,
+    /// The commitment `A` to the bits of the amounts.
+    pub a: [u8; 32],
+    /// The commitment `A1` of the final round of the inner product argument.
+    pub a1: [u8; 32],
+    /// The commitment `B` of the final round of the inner product argument.
+    pub b: [u8; 32],
+    /// The response `r1`.
+    pub r1: [u8; 32],
+    /// The response `s1`.
+    pub s1: [u8; 32],
+    /// The response `d1`.
+    pub d1: [u8; 32],
+    /// The left commitments of the inner product rounds.
+    pub l: Vec<[u8; 32]>,
+    /// The right commitments of the inner product rounds.
+    pub r: Vec<[u8; 32]>,
+}
+
+/// Errors that can occur when verifying a [`BulletproofPlus`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BulletproofPlusError {
+    /// There are no amounts, more than [`MAX_AMOUNTS`], or the number of rounds does not match.
+    InvalidShape,
+    /// A response is not a canonical scalar.
+    NonCanonicalScalar,
+    /// A commitment is not a valid point.
+    InvalidPoint,
+    /// A transcript challenge is zero.
+    ZeroChallenge,
+    /// The verification equation does not hold.
+    VerificationFailed,
+}
+
+impl BulletproofPlus {
+    /// Verifies that every amount committed to in `v` is a 64 bit value.
+    pub fn verify(&self) -> Result<(), BulletproofPlusError> {
+        let m = self.v.len();
+        if m == 0 || m > MAX_AMOUNTS {
+            return Err(BulletproofPlusError::InvalidShape);
+        }
+        let padded_m = m.next_power_of_two();
+        let mn = padded_m * N;
+        let rounds = mn.ilog2() as usize;
+        if self.l.len() != rounds || self.r.len() != rounds {
+            return Err(BulletproofPlusError::InvalidShape);
+        }
+
+        let r1 = canonical_scalar(&self.r1)?;
+        let s1 = canonical_scalar(&self.s1)?;
+        let d1 = canonical_scalar(&self.d1)?;
+
+        // Replay the transcript to recover the challenges.
+        let mut transcript =
+            hash_to_ec(&cn_fast_hash(HASH_KEY_BULLETPROOF_PLUS_TRANSCRIPT)).compress();
+        transcript_update(&mut transcript, &[&hash_to_scalar(&self.v.concat()).to_bytes()]);
+        let y = nonzero(transcript_update(&mut transcript, &[&self.a]))?;
+        let z = nonzero(hash_to_scalar(&transcript))?;
+        transcript = z.to_bytes();
+        let challenges = self
+            .l
+            .iter()
+            .zip(self.r.iter())
+            .map(|(l, r)| nonzero(transcript_update(&mut transcript, &[l, r])))
+            .collect::<Result<Vec<_>, _>>()?;
+        let e = nonzero(transcript_update(&mut transcript, &[&self.a1, &self.b]))?;
+        let challenge_invs = challenges.iter().map(Ed25519Scalar::invert).collect::<Vec<_>>();
+
+        // The powers `y^0, ..., y^(MN + 1)`.
+        let y_pows = powers(y, Ed25519Scalar::ONE, mn + 2);
+        let sum_y = y_pows[1..=mn].iter().fold(Ed25519Scalar::ZERO, |acc, &y_pow| acc + y_pow);
+
+        // The powers `z^(2 * (j + 1))` weighting the amounts.
+        let z_sq = z * z;
+        let z_sq_pows = powers(z_sq, z_sq, padded_m);
+        let sum_z_sq = z_sq_pows.iter().fold(Ed25519Scalar::ZERO, |acc, &z_pow| acc + z_pow);
+        let two_pow_n_minus_one = Ed25519Scalar([u32::MAX, u32::MAX, 0, 0, 0, 0, 0, 0]);
+        let sum_d = two_pow_n_minus_one * sum_z_sq;
+
+        // The products of the round challenges folding each vector generator, with the first round
+        // selecting the most significant bit of the index.
+        let mut challenge_products = vec![Ed25519Scalar::ZERO; mn];
+        challenge_products[0] = challenge_invs[0];
+        challenge_products[1] = challenges[0];
+        for j in 1..rounds {
+            for k in (0..1 << j).rev() {
+                let parent = challenge_products[k];
+                challenge_products[2 * k] = parent * challenge_invs[j];
+                challenge_products[2 * k + 1] = parent * challenges[j];
+            }
+        }
+
+        let e_sq = e * e;
+        let e_r1 = e * r1;
+        let e_s1 = e * s1;
+        let e_sq_z = e_sq * z;
+
+        let mut scalars = Vec::with_capacity(2 * mn + m + 2 * rounds + 5);
+        let mut points = Vec::with_capacity(2 * mn + m + 2 * rounds + 5);
+
+        // The vector generators.
+        let mut y_inv_pow = Ed25519Scalar::ONE;
+        let y_inv = y.invert();
+        let mut d_i = Ed25519Scalar::ZERO;
+        for i in 0..mn {
+            d_i = if i % N == 0 { z_sq_pows[i / N] } else { d_i + d_i };
+
+            scalars.push(Ed25519Scalar::ZERO - e_sq_z - e_r1 * challenge_products[i] * y_inv_pow);
+            points.push(get_exponent(2 * i + 1));
+            scalars.push(e_sq * (d_i * y_pows[mn - i] + z) - e_s1 * challenge_products[mn - 1 - i]);
+            points.push(get_exponent(2 * i));
+
+            y_inv_pow = y_inv_pow * y_inv;
+        }
+
+        // The amount and blinding generators.
+        let zeta = (z - z_sq) * sum_y - z * y_pows[mn + 1] * sum_d;
+        scalars.push(e_sq * zeta - r1 * y * s1);
+        points.push(Ed25519AffinePoint::decompress(&H).unwrap());
+        scalars.push(Ed25519Scalar::ZERO - d1);
+        points.push(Ed25519AffinePoint::decompress(&BASE_POINT).unwrap());
+
+        // The proof commitments.
+        scalars.push(e_sq);
+        points.push(decompress_commitment(&self.a)?);
+        scalars.push(e);
+        points.push(decompress_commitment(&self.a1)?);
+        scalars.push(Ed25519Scalar::ONE);
+        points.push(decompress_commitment(&self.b)?);
+        for (v, z_sq_pow) in self.v.iter().zip(z_sq_pows.iter()) {
+            scalars.push(e_sq * y_pows[mn + 1] * *z_sq_pow);
+            points.push(decompress_commitment(v)?);
+        }
+        for (j, (l, r)) in self.l.iter().zip(self.r.iter()).enumerate() {
+            scalars.push(e_sq * challenges[j] * challenges[j]);
+            points.push(decompress_commitment(l)?);
+            scalars.push(e_sq * challenge_invs[j] * challenge_invs[j]);
+            points.push(decompress_commitment(r)?);
+        }
+
+        let scalars = scalars.iter().map(Ed25519Scalar::to_bytes).collect::<Vec<_>>();
+        if !Ed25519AffinePoint::multi_scalar_mul(&scalars, &points).is_identity() {
+            return Err(BulletproofPlusError::VerificationFailed);
+        }
+        Ok(())
+    }
+}
+
+/// Monero's `get_exponent`, which derives the vector generator with the given index from `H`.
+///
+/// The generators `Gi` have odd and the generators `Hi` even indices.
+fn get_exponent(index: usize) -> Ed25519AffinePoint {
+    let mut data = Vec::with_capacity(32 + HASH_KEY_BULLETPROOF_PLUS_EXPONENT.len() + 10);
+    data.extend_from_slice(&H);
+    data.extend_from_slice(HASH_KEY_BULLETPROOF_PLUS_EXPONENT);
+    let mut index = index;
+    while index >= 0x80 {
+        data.push((index as u8 & 0x7f) | 0x80);
+        index >>= 7;
+    }
+    data.push(index as u8);
+    hash_to_ec(&cn_fast_hash(&data))
+}
+
+/// Monero's `transcript_update`, which hashes the transcript together with new proof elements and
+/// replaces it with the resulting challenge.
+fn transcript_update(transcript: &mut [u8; 32], elements: &[&[u8; 32]]) -> Ed25519Scalar {
+    let mut data = Vec::with_capacity(32 * (elements.len() + 1));
+    data.extend_from_slice(transcript);
+    elements.iter().for_each(|element| data.extend_from_slice(*element));
+    let challenge = hash_to_scalar(&data);
+    *transcript = challenge.to_bytes();
+    challenge
+}
+
+/// The `len` powers `first, first * base, first * base^2, ...`.
+fn powers(base: Ed25519Scalar, first: Ed25519Scalar, len: usize) -> Vec<Ed25519Scalar> {
+    core::iter::successors(Some(first), |&pow| Some(pow * base)).take(len).collect()
+}
+
+fn nonzero(challenge: Ed25519Scalar) -> Result<Ed25519Scalar, BulletproofPlusError> {
+    if challenge.is_zero() {
+        return Err(BulletproofPlusError::ZeroChallenge);
+    }
+    Ok(challenge)
+}
+
+fn canonical_scalar(bytes: &[u8; 32]) -> Result<Ed25519Scalar, BulletproofPlusError> {
+    Ed25519Scalar::from_canonical_bytes(bytes).ok_or(BulletproofPlusError::NonCanonicalScalar)
+}
+
+/// Decompresses a commitment and multiplies it by the cofactor, undoing the scaling by its inverse.
+fn decompress_commitment(bytes: &[u8; 32]) -> Result<Ed25519AffinePoint, BulletproofPlusError> {
+    let mut point =
+        Ed25519AffinePoint::decompress(bytes).ok_or(BulletproofPlusError::InvalidPoint)?;
+    point.mul_by_cofactor();
+    Ok(point)
+}
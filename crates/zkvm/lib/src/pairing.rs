@@ -0,0 +1,709 @@
+//! Pairing checks on BN254 and BLS12-381.
+//!
+//! The pairing is the optimal ate pairing `e(P, Q) = m(P, Q)^((p^12 - 1) / r)`, where the Miller
+//! function `m` is `f_{6x + 2, Q}(P) l_{[6x + 2] Q, π(Q)}(P) l_{[6x + 2] Q + π(Q), -π^2(Q)}(P)` on
+//! BN254 and `f_{x, Q}(P)` on BLS12-381, and `π` is the Frobenius endomorphism. The final
+//! exponentiation is split into the easy part `(p^6 - 1)(p^2 + 1)`, computed with the Frobenius,
+//! and the hard part `(p^4 - p^2 + 1) / r`, computed in the cyclotomic subgroup from powers of the
+//! curve parameter `x`. On BLS12-381, the hard part raises to three times this exponent, which
+//! gives the cube of the pairing and does not change whether a product of pairings is equal to
+//! one.
+//!
+//! The Miller loop and the final exponentiation are computed with the Fp2, Fp12 and G2
+//! precompiles, using the tower `Fp12 = Fp6[w] / (w^2 - v)`, `Fp6 = Fp2[v] / (v^3 - xi)` and its
+//! memory layout described in the `fptower` syscalls.
+//!
+//! Points are given in affine coordinates as little endian words: a G1 point as `[x, y]` and a G2
+//! point as `[x.c0, x.c1, y.c0, y.c1]`. The point at infinity is encoded as all zeros.
+//! [`bn254_pairing_check`] and [`bls12381_pairing_check`] check that G2 points are valid with
+//! [`check_g2_point`]. The caller must ensure that coordinates of G1 points are reduced and that
+//! G1 points lie in the prime order subgroup.
+
+use crate::{
+    syscall_bls12381_fp12_mulmod, syscall_bls12381_fp2_addmod, syscall_bls12381_fp2_mulmod,
+    syscall_bls12381_fp2_submod, syscall_bls12381_g2_add, syscall_bls12381_g2_double,
+    syscall_bn254_fp12_mulmod, syscall_bn254_fp2_addmod, syscall_bn254_fp2_mulmod,
+    syscall_bn254_fp2_submod, syscall_bn254_g2_add, syscall_bn254_g2_double,
+};
+
+/// The type of the sextic twist on which the G2 subgroup of a curve is defined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TwistType {
+    /// The twist `y^2 = x^3 + b / xi`, mapped to the curve by `(x, y) -> (x w^2, y w^3)`.
+    D,
+    /// The twist `y^2 = x^3 + b xi`, mapped to the curve by `(x, y) -> (x / w^2, y / w^3)`.
+    M,
+}
+
+/// Errors that can occur when checking a G2 point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PairingError {
+    /// A coordinate is not reduced modulo the base field modulus.
+    NonCanonicalCoordinate,
+    /// The point does not lie on the twist.
+    NotOnCurve,
+    /// The point does not lie in the prime order subgroup.
+    NotInSubgroup,
+}
+
+/// A pairing friendly curve whose extension field and G2 arithmetic is provided by precompiles.
+pub trait PairingCurve {
+    /// The number of words of a base field element.
+    const NUM_WORDS: usize;
+
+    /// The base field modulus `p`, as little endian words.
+    const MODULUS: &'static [u32];
+
+    /// The integer `c` such that `xi = c + u`.
+    const NONRESIDUE: u32;
+
+    /// The constant `b'` of the twist `y^2 = x^3 + b'`, as an Fp2 element.
+    const TWIST_B: &'static [u32];
+
+    /// The type of the twist on which the G2 subgroup is defined.
+    const TWIST_TYPE: TwistType;
+
+    /// The curve parameter `x`.
+    const X: i128;
+
+    /// The parameter of the Miller loop, `6x + 2` for BN curves and `x` for BLS12 curves.
+    ///
+    /// For a negative parameter the result of the Miller loop is conjugated, which inverts it once
+    /// raised to the final exponent.
+    const ATE_LOOP_COUNT: i128;
+
+    /// Whether the Miller loop ends with the lines through `π(Q)` and `-π^2(Q)`, as for BN curves.
+    const FROBENIUS_LINES: bool;
+
+    /// The coefficient `xi^((p - 1) / 6)` of the Frobenius, as an Fp2 element.
+    const FROBENIUS_COEFF: &'static [u32];
+
+    /// A multiple of the hard part of the final exponent, not divisible by `r`, written as
+    /// `sum_i sum_j c_ij x^j p^i`.
+    ///
+    /// The row `i` holds the coefficients `c_ij` for increasing powers `j` of `x`.
+    const HARD_PART: &'static [&'static [i8]];
+
+    /// The scalar `k` such that `ψ(Q) = [k] Q` exactly for the points `Q` of the G2 subgroup,
+    /// where `ψ` is the Frobenius endomorphism of the twist.
+    const PSI_SCALAR: i128;
+
+    /// Sets `x` to `x + y` in Fp2.
+    fn fp2_add(x: &mut [u32], y: &[u32]);
+
+    /// Sets `x` to `x - y` in Fp2.
+    fn fp2_sub(x: &mut [u32], y: &[u32]);
+
+    /// Sets `x` to `x * y` in Fp2.
+    fn fp2_mul(x: &mut [u32], y: &[u32]);
+
+    /// Sets `x` to `x * y` in Fp12.
+    fn fp12_mul(x: &mut [u32], y: &[u32]);
+
+    /// Sets `x` to `x^2` in Fp12.
+    fn fp12_square(x: &mut [u32]);
+
+    /// Sets `p` to `p + q` on the twist. The points must have distinct x-coordinates.
+    fn g2_add(p: &mut [u32], q: &[u32]);
+
+    /// Sets `p` to `2 p` on the twist.
+    fn g2_double(p: &mut [u32]);
+}
+
+/// The BN254 curve.
+pub struct Bn254;
+
+/// The BLS12-381 curve.
+pub struct Bls12381;
+
+impl PairingCurve for Bn254 {
+    const NUM_WORDS: usize = 8;
+    const MODULUS: &'static [u32] = &BN254_MODULUS;
+    const NONRESIDUE: u32 = 9;
+    const TWIST_B: &'static [u32] = &BN254_TWIST_B;
+    const TWIST_TYPE: TwistType = TwistType::D;
+    const X: i128 = 0x44e992b44a6909f1;
+    const ATE_LOOP_COUNT: i128 = 0x19d797039be763ba8;
+    const FROBENIUS_LINES: bool = true;
+    const FROBENIUS_COEFF: &'static [u32] = &BN254_FROBENIUS_COEFF;
+    // `λ = λ_0 + λ_1 p + λ_2 p^2 + p^3`, with `λ_0 = -36x^3 - 30x^2 - 18x - 2`,
+    // `λ_1 = -36x^3 - 18x^2 - 12x + 1` and `λ_2 = 6x^2 + 1`.
+    const HARD_PART: &'static [&'static [i8]] =
+        &[&[-2, -18, -30, -36], &[1, -12, -18, -36], &[1, 0, 6, 0], &[1, 0, 0, 0]];
+    const PSI_SCALAR: i128 = 0x6f4d8248eeb859fbf83e9682e87cfd46;
+
+    fn fp2_add(x: &mut [u32], y: &[u32]) {
+        unsafe { syscall_bn254_fp2_addmod(x.as_mut_ptr(), y.as_ptr()) }
+    }
+
+    fn fp2_sub(x: &mut [u32], y: &[u32]) {
+        unsafe { syscall_bn254_fp2_submod(x.as_mut_ptr(), y.as_ptr()) }
+    }
+
+    fn fp2_mul(x: &mut [u32], y: &[u32]) {
+        unsafe { syscall_bn254_fp2_mulmod(x.as_mut_ptr(), y.as_ptr()) }
+    }
+
+    fn fp12_mul(x: &mut [u32], y: &[u32]) {
+        unsafe { syscall_bn254_fp12_mulmod(x.as_mut_ptr(), y.as_ptr()) }
+    }
+
+    fn fp12_square(x: &mut [u32]) {
+        let ptr = x.as_mut_ptr();
+        unsafe { syscall_bn254_fp12_mulmod(ptr, ptr) }
+    }
+
+    fn g2_add(p: &mut [u32], q: &[u32]) {
+        unsafe { syscall_bn254_g2_add(p.as_mut_ptr() as *mut _, q.as_ptr() as *const _) }
+    }
+
+    fn g2_double(p: &mut [u32]) {
+        unsafe { syscall_bn254_g2_double(p.as_mut_ptr() as *mut _) }
+    }
+}
+
+impl PairingCurve for Bls12381 {
+    const NUM_WORDS: usize = 12;
+    const MODULUS: &'static [u32] = &BLS12381_MODULUS;
+    const NONRESIDUE: u32 = 1;
+    const TWIST_B: &'static [u32] = &BLS12381_TWIST_B;
+    const TWIST_TYPE: TwistType = TwistType::M;
+    const X: i128 = -0xd201000000010000;
+    const ATE_LOOP_COUNT: i128 = -0xd201000000010000;
+    const FROBENIUS_LINES: bool = false;
+    const FROBENIUS_COEFF: &'static [u32] = &BLS12381_FROBENIUS_COEFF;
+    // `3 λ = (x - 1)^2 (x + p) (x^2 + p^2 - 1) + 3`.
+    const HARD_PART: &'static [&'static [i8]] =
+        &[&[3, -1, 2, 0, -2, 1], &[-1, 2, 0, -2, 1, 0], &[0, 1, -2, 1, 0, 0], &[1, -2, 1, 0, 0, 0]];
+    const PSI_SCALAR: i128 = -0xd201000000010000;
+
+    fn fp2_add(x: &mut [u32], y: &[u32]) {
+        unsafe { syscall_bls12381_fp2_addmod(x.as_mut_ptr(), y.as_ptr()) }
+    }
+
+    fn fp2_sub(x: &mut [u32], y: &[u32]) {
+        unsafe { syscall_bls12381_fp2_submod(x.as_mut_ptr(), y.as_ptr()) }
+    }
+
+    fn fp2_mul(x: &mut [u32], y: &[u32]) {
+        unsafe { syscall_bls12381_fp2_mulmod(x.as_mut_ptr(), y.as_ptr()) }
+    }
+
+    fn fp12_mul(x: &mut [u32], y: &[u32]) {
+        unsafe { syscall_bls12381_fp12_mulmod(x.as_mut_ptr(), y.as_ptr()) }
+    }
+
+    fn fp12_square(x: &mut [u32]) {
+        let ptr = x.as_mut_ptr();
+        unsafe { syscall_bls12381_fp12_mulmod(ptr, ptr) }
+    }
+
+    fn g2_add(p: &mut [u32], q: &[u32]) {
+        unsafe { syscall_bls12381_g2_add(p.as_mut_ptr() as *mut _, q.as_ptr() as *const _) }
+    }
+
+    fn g2_double(p: &mut [u32]) {
+        unsafe { syscall_bls12381_g2_double(p.as_mut_ptr() as *mut _) }
+    }
+}
+
+/// Checks that `prod e(P_i, Q_i) == 1` for the given pairs of G1 and G2 points on BN254, after
+/// checking the G2 points.
+pub fn bn254_pairing_check(pairs: &[([u32; 16], [u32; 32])]) -> Result<bool, PairingError> {
+    for (_, q) in pairs {
+        check_g2_point::<Bn254>(q)?;
+    }
+    Ok(pairing_check::<Bn254>(pairs.iter().map(|(p, q)| (&p[..], &q[..]))))
+}
+
+/// Checks that `prod e(P_i, Q_i) == 1` for the given pairs of G1 and G2 points on BLS12-381,
+/// after checking the G2 points.
+pub fn bls12381_pairing_check(pairs: &[([u32; 24], [u32; 48])]) -> Result<bool, PairingError> {
+    for (_, q) in pairs {
+        check_g2_point::<Bls12381>(q)?;
+    }
+    Ok(pairing_check::<Bls12381>(pairs.iter().map(|(p, q)| (&p[..], &q[..]))))
+}
+
+/// Checks that `prod e(P_i, Q_i) == 1` for the given pairs of G1 and G2 points.
+///
+/// The points are not checked, see [`check_g2_point`].
+pub fn pairing_check<'a, C: PairingCurve>(
+    pairs: impl IntoIterator<Item = (&'a [u32], &'a [u32])>,
+) -> bool {
+    let f = multi_miller_loop::<C>(pairs);
+    final_exponentiation::<C>(&f) == fp12_one::<C>()
+}
+
+/// Checks that a G2 point has reduced coordinates and lies in the prime order subgroup of the
+/// twist, or is the point at infinity.
+///
+/// Subgroup membership is checked with `ψ(Q) == [k] Q`, where `k` is
+/// [`PairingCurve::PSI_SCALAR`], which only holds for the points of the subgroup.
+pub fn check_g2_point<C: PairingCurve>(q: &[u32]) -> Result<(), PairingError> {
+    let fp2_len = 2 * C::NUM_WORDS;
+    assert_eq!(q.len(), 2 * fp2_len);
+    if q.iter().all(|&w| w == 0) {
+        return Ok(());
+    }
+
+    let is_reduced = |x: &[u32]| x.iter().rev().lt(C::MODULUS.iter().rev());
+    if !q.chunks(C::NUM_WORDS).all(is_reduced) {
+        return Err(PairingError::NonCanonicalCoordinate);
+    }
+
+    // `y^2 == x^3 + b'`.
+    let (x, y) = q.split_at(fp2_len);
+    let mut lhs = y.to_vec();
+    C::fp2_mul(&mut lhs, y);
+    let mut rhs = x.to_vec();
+    C::fp2_mul(&mut rhs, x);
+    C::fp2_mul(&mut rhs, x);
+    C::fp2_add(&mut rhs, C::TWIST_B);
+    if lhs != rhs {
+        return Err(PairingError::NotOnCurve);
+    }
+
+    match g2_mul::<C>(q, C::PSI_SCALAR) {
+        Some(r) if is_psi::<C>(q, &r) => Ok(()),
+        _ => Err(PairingError::NotInSubgroup),
+    }
+}
+
+/// Computes the product of the Miller functions `m(P_i, Q_i)` of the optimal ate pairing, up to
+/// factors that are cancelled by the final exponentiation.
+pub fn multi_miller_loop<'a, C: PairingCurve>(
+    pairs: impl IntoIterator<Item = (&'a [u32], &'a [u32])>,
+) -> Vec<u32> {
+    let fp2_len = 2 * C::NUM_WORDS;
+
+    // Pairs involving the point at infinity contribute a trivial factor.
+    let pairs = pairs
+        .into_iter()
+        .inspect(|(p, q)| {
+            assert_eq!(p.len(), 2 * C::NUM_WORDS);
+            assert_eq!(q.len(), 4 * C::NUM_WORDS);
+        })
+        .filter(|(p, q)| p.iter().any(|&w| w != 0) && q.iter().any(|&w| w != 0))
+        .collect::<Vec<_>>();
+    let mut ts = pairs.iter().map(|(_, q)| q.to_vec()).collect::<Vec<_>>();
+
+    let mut f = fp12_one::<C>();
+    let loop_count = C::ATE_LOOP_COUNT.unsigned_abs();
+    let num_bits = 128 - loop_count.leading_zeros();
+    for bit in (0..num_bits - 1).rev() {
+        C::fp12_square(&mut f);
+
+        for ((p, _), t) in pairs.iter().zip(ts.iter_mut()) {
+            // The tangent at `t` has slope `3 t.x^2 / 2 t.y`.
+            let (t_x, t_y) = t.split_at(fp2_len);
+            let mut dx = t_y.to_vec();
+            C::fp2_add(&mut dx, t_y);
+            let mut dy = t_x.to_vec();
+            C::fp2_mul(&mut dy, t_x);
+            let x_sq = dy.clone();
+            C::fp2_add(&mut dy, &x_sq);
+            C::fp2_add(&mut dy, &x_sq);
+
+            C::fp12_mul(&mut f, &line::<C>(&dx, &dy, t, p));
+            C::g2_double(t);
+        }
+
+        if (loop_count >> bit) & 1 == 1 {
+            for ((p, q), t) in pairs.iter().zip(ts.iter_mut()) {
+                C::fp12_mul(&mut f, &chord::<C>(t, q, p));
+                C::g2_add(t, q);
+            }
+        }
+    }
+
+    if C::ATE_LOOP_COUNT < 0 {
+        f = fp12_conjugate::<C>(&f);
+    }
+
+    if C::FROBENIUS_LINES {
+        for ((p, q), t) in pairs.iter().zip(ts.iter_mut()) {
+            let q1 = psi::<C>(q);
+            let mut q2 = psi::<C>(&q1);
+            let neg_y = fp2_neg::<C>(&q2[fp2_len..]);
+            q2[fp2_len..].copy_from_slice(&neg_y);
+
+            C::fp12_mul(&mut f, &chord::<C>(t, &q1, p));
+            C::g2_add(t, &q1);
+            C::fp12_mul(&mut f, &chord::<C>(t, &q2, p));
+        }
+    }
+    f
+}
+
+/// Raises the output of the Miller loop to the final exponent `(p^12 - 1) / r`, or to the multiple
+/// of it given by [`PairingCurve::HARD_PART`].
+pub fn final_exponentiation<C: PairingCurve>(f: &[u32]) -> Vec<u32> {
+    let fp6_len = 6 * C::NUM_WORDS;
+
+    // The easy part. Writing `f = f0 + f1 w`, the product `f conj(f) = f0^2 - f1^2 v` lies in Fp6,
+    // so `f^(p^6 - 1) = conj(f) / f = conj(f)^2 / (f conj(f))`.
+    let conjugate = fp12_conjugate::<C>(f);
+    let mut norm = f.to_vec();
+    C::fp12_mul(&mut norm, &conjugate);
+    let mut norm_inverse = fp6_inverse::<C>(&norm[..fp6_len]);
+    norm_inverse.resize(12 * C::NUM_WORDS, 0);
+    let mut f = conjugate;
+    C::fp12_square(&mut f);
+    C::fp12_mul(&mut f, &norm_inverse);
+    let mut result = frobenius::<C>(&f, 2);
+    C::fp12_mul(&mut result, &f);
+
+    // The hard part, as the product of the `π^i(f^(x^j))^c_ij`. The bases are raised to the small
+    // coefficients together, by square and multiply.
+    let mut powers = vec![result];
+    for _ in 1..C::HARD_PART[0].len() {
+        let power = cyclotomic_exp::<C>(powers.last().unwrap(), C::X);
+        powers.push(power);
+    }
+    let mut bases = Vec::new();
+    for (i, row) in C::HARD_PART.iter().enumerate() {
+        for (power, &c) in powers.iter().zip(row.iter()).filter(|&(_, &c)| c != 0) {
+            let base = frobenius::<C>(power, i);
+            let base = if c < 0 { fp12_conjugate::<C>(&base) } else { base };
+            bases.push((base, c.unsigned_abs()));
+        }
+    }
+    let num_bits = bases.iter().map(|(_, c)| 8 - c.leading_zeros()).max().unwrap_or(0);
+    let mut result = fp12_one::<C>();
+    for bit in (0..num_bits).rev() {
+        C::fp12_square(&mut result);
+        for (base, c) in &bases {
+            if (c >> bit) & 1 == 1 {
+                C::fp12_mul(&mut result, base);
+            }
+        }
+    }
+    result
+}
+
+fn fp2_one<C: PairingCurve>() -> Vec<u32> {
+    let mut one = vec![0; 2 * C::NUM_WORDS];
+    one[0] = 1;
+    one
+}
+
+fn fp12_one<C: PairingCurve>() -> Vec<u32> {
+    let mut one = vec![0; 12 * C::NUM_WORDS];
+    one[0] = 1;
+    one
+}
+
+fn fp2_neg<C: PairingCurve>(x: &[u32]) -> Vec<u32> {
+    let mut result = vec![0; 2 * C::NUM_WORDS];
+    C::fp2_sub(&mut result, x);
+    result
+}
+
+/// Returns `x^p`, the Fp2 element with negated imaginary part.
+fn fp2_conjugate<C: PairingCurve>(x: &[u32]) -> Vec<u32> {
+    let n = C::NUM_WORDS;
+    let mut result = fp2_neg::<C>(x);
+    result[..n].copy_from_slice(&x[..n]);
+    result
+}
+
+/// Returns `1 / x`, or zero for `x = 0`.
+///
+/// The inverse is `conj(x) / (x conj(x))`, where the norm `x conj(x)` lies in Fp and is inverted
+/// by raising it to `p - 2`.
+fn fp2_inverse<C: PairingCurve>(x: &[u32]) -> Vec<u32> {
+    let conjugate = fp2_conjugate::<C>(x);
+    let mut norm = x.to_vec();
+    C::fp2_mul(&mut norm, &conjugate);
+
+    // The low word of the modulus is odd and larger than two.
+    let mut exponent = C::MODULUS.to_vec();
+    exponent[0] -= 2;
+    let mut result = fp2_one::<C>();
+    for word in exponent.iter().rev() {
+        for bit in (0..32).rev() {
+            let square = result.clone();
+            C::fp2_mul(&mut result, &square);
+            if (word >> bit) & 1 == 1 {
+                C::fp2_mul(&mut result, &norm);
+            }
+        }
+    }
+    C::fp2_mul(&mut result, &conjugate);
+    result
+}
+
+/// Returns `1 / a` for an element `a = a0 + a1 v + a2 v^2` of Fp6, or zero for `a = 0`.
+fn fp6_inverse<C: PairingCurve>(a: &[u32]) -> Vec<u32> {
+    let fp2_len = 2 * C::NUM_WORDS;
+    let mul = |x: &[u32], y: &[u32]| {
+        let mut result = x.to_vec();
+        C::fp2_mul(&mut result, y);
+        result
+    };
+    let mut xi = vec![0; fp2_len];
+    xi[0] = C::NONRESIDUE;
+    xi[C::NUM_WORDS] = 1;
+    let (a0, rest) = a.split_at(fp2_len);
+    let (a1, a2) = rest.split_at(fp2_len);
+
+    // `c0 = a0^2 - xi a1 a2`, `c1 = xi a2^2 - a0 a1` and `c2 = a1^2 - a0 a2`.
+    let mut c0 = mul(a0, a0);
+    C::fp2_sub(&mut c0, &mul(&xi, &mul(a1, a2)));
+    let mut c1 = mul(&xi, &mul(a2, a2));
+    C::fp2_sub(&mut c1, &mul(a0, a1));
+    let mut c2 = mul(a1, a1);
+    C::fp2_sub(&mut c2, &mul(a0, a2));
+
+    // The norm `a0 c0 + xi (a2 c1 + a1 c2)` lies in Fp2.
+    let mut norm = mul(a2, &c1);
+    C::fp2_add(&mut norm, &mul(a1, &c2));
+    let mut norm = mul(&xi, &norm);
+    C::fp2_add(&mut norm, &mul(a0, &c0));
+    let norm_inverse = fp2_inverse::<C>(&norm);
+
+    [c0, c1, c2].iter().flat_map(|c| mul(c, &norm_inverse)).collect()
+}
+
+/// Returns `f^(p^6)`, the Fp12 element with negated coefficients of `w`.
+///
+/// In the cyclotomic subgroup, which contains the output of the easy part of the final
+/// exponentiation, this is the inverse of `f`.
+fn fp12_conjugate<C: PairingCurve>(f: &[u32]) -> Vec<u32> {
+    let fp2_len = 2 * C::NUM_WORDS;
+    let mut result = f.to_vec();
+    for index in 3..6 {
+        let coeff = fp2_neg::<C>(&f[index * fp2_len..(index + 1) * fp2_len]);
+        result[index * fp2_len..(index + 1) * fp2_len].copy_from_slice(&coeff);
+    }
+    result
+}
+
+/// Returns the coefficients `xi^(j (p^k - 1) / 6)` of the `k`-th power of the Frobenius, for
+/// `j` from 0 to 5.
+///
+/// They are the products of the `xi^(j (p - 1) / 6)^(p^i)` for `i < k`, where raising an Fp2
+/// element to `p` conjugates it.
+fn frobenius_coefficients<C: PairingCurve>(k: usize) -> Vec<Vec<u32>> {
+    let mut gammas = vec![fp2_one::<C>()];
+    for _ in 1..6 {
+        let mut gamma = gammas.last().unwrap().clone();
+        C::fp2_mul(&mut gamma, C::FROBENIUS_COEFF);
+        gammas.push(gamma);
+    }
+
+    let mut result = vec![fp2_one::<C>(); 6];
+    for i in 0..k {
+        for (coeff, gamma) in result.iter_mut().zip(gammas.iter()) {
+            if i % 2 == 0 {
+                C::fp2_mul(coeff, gamma);
+            } else {
+                C::fp2_mul(coeff, &fp2_conjugate::<C>(gamma));
+            }
+        }
+    }
+    result
+}
+
+/// Returns `f^(p^k)`.
+///
+/// The coefficient of `v^i w^h` multiplies `w^(2i + h)`, and `w^(p^k) = xi^((p^k - 1) / 6) w`.
+fn frobenius<C: PairingCurve>(f: &[u32], k: usize) -> Vec<u32> {
+    if k == 0 {
+        return f.to_vec();
+    }
+    let gammas = frobenius_coefficients::<C>(k);
+    let mut result = Vec::with_capacity(f.len());
+    for (index, coeff) in f.chunks(2 * C::NUM_WORDS).enumerate() {
+        let mut coeff = if k % 2 == 1 { fp2_conjugate::<C>(coeff) } else { coeff.to_vec() };
+        let (h, i) = (index / 3, index % 3);
+        C::fp2_mul(&mut coeff, &gammas[2 * i + h]);
+        result.extend(coeff);
+    }
+    result
+}
+
+/// Returns `f^e` for an element `f` of the cyclotomic subgroup, using conjugation for negative
+/// exponents.
+fn cyclotomic_exp<C: PairingCurve>(f: &[u32], e: i128) -> Vec<u32> {
+    let exponent = e.unsigned_abs();
+    let mut result = fp12_one::<C>();
+    for bit in (0..128 - exponent.leading_zeros()).rev() {
+        C::fp12_square(&mut result);
+        if (exponent >> bit) & 1 == 1 {
+            C::fp12_mul(&mut result, f);
+        }
+    }
+    if e < 0 {
+        fp12_conjugate::<C>(&result)
+    } else {
+        result
+    }
+}
+
+/// Returns `ψ(q)`, the image of a point of the twist under the Frobenius endomorphism of the
+/// curve, that is `(conj(x) w^(2 (p - 1)), conj(y) w^(3 (p - 1)))` for D-type twists and
+/// `(conj(x) / w^(2 (p - 1)), conj(y) / w^(3 (p - 1)))` for M-type twists.
+fn psi<C: PairingCurve>(q: &[u32]) -> Vec<u32> {
+    let gammas = frobenius_coefficients::<C>(1);
+    let mut result = Vec::with_capacity(q.len());
+    for (coord, gamma) in q.chunks(2 * C::NUM_WORDS).zip(&gammas[2..4]) {
+        let mut coord = fp2_conjugate::<C>(coord);
+        match C::TWIST_TYPE {
+            TwistType::D => C::fp2_mul(&mut coord, gamma),
+            TwistType::M => C::fp2_mul(&mut coord, &fp2_inverse::<C>(gamma)),
+        }
+        result.extend(coord);
+    }
+    result
+}
+
+/// Checks that `ψ(q) == r`. For M-type twists, `r` is multiplied by the coefficients of `ψ` rather
+/// than dividing by them.
+fn is_psi<C: PairingCurve>(q: &[u32], r: &[u32]) -> bool {
+    match C::TWIST_TYPE {
+        TwistType::D => psi::<C>(q) == r,
+        TwistType::M => {
+            let fp2_len = 2 * C::NUM_WORDS;
+            let gammas = frobenius_coefficients::<C>(1);
+            q.chunks(fp2_len).zip(r.chunks(fp2_len)).zip(&gammas[2..4]).all(|((q, r), gamma)| {
+                let mut r = r.to_vec();
+                C::fp2_mul(&mut r, gamma);
+                fp2_conjugate::<C>(q) == r
+            })
+        }
+    }
+}
+
+/// Returns `[k] q` for a point `q` of the twist other than the point at infinity, or `None` for
+/// the point at infinity.
+///
+/// Unlike the precompiles, this handles sums of equal or opposite points and doublings of points
+/// of order two, which occur for points outside the G2 subgroup. The precompiles return reduced
+/// coordinates, so points are compared by their words.
+fn g2_mul<C: PairingCurve>(q: &[u32], k: i128) -> Option<Vec<u32>> {
+    let fp2_len = 2 * C::NUM_WORDS;
+    let scalar = k.unsigned_abs();
+    let mut result: Option<Vec<u32>> = None;
+    for bit in (0..128 - scalar.leading_zeros()).rev() {
+        if let Some(mut r) = result.take() {
+            if r[fp2_len..].iter().any(|&w| w != 0) {
+                C::g2_double(&mut r);
+                result = Some(r);
+            }
+        }
+        if (scalar >> bit) & 1 == 1 {
+            result = match result.take() {
+                None => Some(q.to_vec()),
+                Some(mut r) if r[..fp2_len] != q[..fp2_len] => {
+                    C::g2_add(&mut r, q);
+                    Some(r)
+                }
+                Some(mut r)
+                    if r[fp2_len..] == q[fp2_len..] && r[fp2_len..].iter().any(|&w| w != 0) =>
+                {
+                    C::g2_double(&mut r);
+                    Some(r)
+                }
+                Some(_) => None,
+            };
+        }
+    }
+    if k < 0 {
+        if let Some(r) = &mut result {
+            let neg_y = fp2_neg::<C>(&r[fp2_len..]);
+            r[fp2_len..].copy_from_slice(&neg_y);
+        }
+    }
+    result
+}
+
+/// Evaluates at `p` the line through `t` and `q`, which must have distinct x-coordinates.
+fn chord<C: PairingCurve>(t: &[u32], q: &[u32], p: &[u32]) -> Vec<u32> {
+    // The line through `t` and `q` has slope `(q.y - t.y) / (q.x - t.x)`.
+    let fp2_len = 2 * C::NUM_WORDS;
+    let mut dx = q[..fp2_len].to_vec();
+    C::fp2_sub(&mut dx, &t[..fp2_len]);
+    let mut dy = q[fp2_len..].to_vec();
+    C::fp2_sub(&mut dy, &t[fp2_len..]);
+    line::<C>(&dx, &dy, t, p)
+}
+
+/// Evaluates at `p` the line through `t` with direction `(dx, dy)`, scaled by `dx`.
+///
+/// The line is `dx (y - t.y) - dy (x - t.x)` on the twist. Mapped to the curve, it is a sparse
+/// element of Fp12, multiplied by `w^3` for M-type twists; factors in proper subfields of Fp12 are
+/// cancelled by the final exponentiation.
+fn line<C: PairingCurve>(dx: &[u32], dy: &[u32], t: &[u32], p: &[u32]) -> Vec<u32> {
+    let n = C::NUM_WORDS;
+    let fp2_len = 2 * n;
+    let (t_x, t_y) = t.split_at(fp2_len);
+    let embed = |x: &[u32]| {
+        let mut result = vec![0; fp2_len];
+        result[..n].copy_from_slice(x);
+        result
+    };
+
+    // The constant coefficient `dy * t.x - dx * t.y`.
+    let mut c0 = dy.to_vec();
+    C::fp2_mul(&mut c0, t_x);
+    let mut dx_t_y = dx.to_vec();
+    C::fp2_mul(&mut dx_t_y, t_y);
+    C::fp2_sub(&mut c0, &dx_t_y);
+
+    // The coefficients `dx * p.y` and `-dy * p.x`.
+    let mut c_y = dx.to_vec();
+    C::fp2_mul(&mut c_y, &embed(&p[n..]));
+    let mut dy_p_x = dy.to_vec();
+    C::fp2_mul(&mut dy_p_x, &embed(&p[..n]));
+    let mut c_x = vec![0; fp2_len];
+    C::fp2_sub(&mut c_x, &dy_p_x);
+
+    // The Fp2 coefficient of `v^i w^h` is stored at index `3 h + i`.
+    let (index_0, index_x, index_y) = match C::TWIST_TYPE {
+        // `c_y + c_x w + c0 w^3`.
+        TwistType::D => (4, 3, 0),
+        // `c0 + c_x w^2 + c_y w^3`.
+        TwistType::M => (0, 1, 4),
+    };
+    let mut result = vec![0; 12 * n];
+    result[index_0 * fp2_len..(index_0 + 1) * fp2_len].copy_from_slice(&c0);
+    result[index_x * fp2_len..(index_x + 1) * fp2_len].copy_from_slice(&c_x);
+    result[index_y * fp2_len..(index_y + 1) * fp2_len].copy_from_slice(&c_y);
+    result
+}
+
+/// The base field modulus of BN254, as little endian words.
+const BN254_MODULUS: [u32; 8] = [
+    0xd87cfd47, 0x3c208c16, 0x6871ca8d, 0x97816a91, 0x8181585d, 0xb85045b6, 0xe131a029, 0x30644e72,
+];
+
+/// The constant `3 / (9 + u)` of the BN254 twist.
+const BN254_TWIST_B: [u32; 16] = [
+    0x24a138e5, 0x3267e6dc, 0x59dbefa3, 0xb5b4c5e5, 0x1be06ac3, 0x81be1899, 0xceb8aaae, 0x2b149d40,
+    0x85c315d2, 0xe4a2bd06, 0xe52d1852, 0xa74fa084, 0xeed8fdf4, 0xcd2cafad, 0x3af0fed4, 0x009713b0,
+];
+
+/// The coefficient `(9 + u)^((p - 1) / 6)` of the BN254 Frobenius.
+const BN254_FROBENIUS_COEFF: [u32; 16] = [
+    0xdcc9e470, 0xd60b35da, 0x292f2176, 0x5c521e08, 0x76e68b60, 0xe8b99fdd, 0x2865a7df, 0x1284b71c,
+    0x80f362ac, 0xca5cf05f, 0x8eeec7e5, 0x74799277, 0x12150b8e, 0xa6327cfe, 0xb4fae7e6, 0x246996f3,
+];
+
+/// The base field modulus of BLS12-381, as little endian words.
+const BLS12381_MODULUS: [u32; 12] = [
+    0xffffaaab, 0xb9feffff, 0xb153ffff, 0x1eabfffe, 0xf6b0f624, 0x6730d2a0, 0xf38512bf, 0x64774b84,
+    0x434bacd7, 0x4b1ba7b6, 0x397fe69a, 0x1a0111ea,
+];
+
+/// The constant `4 (1 + u)` of the BLS12-381 twist.
+const BLS12381_TWIST_B: [u32; 24] =
+    [4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+/// The coefficient `(1 + u)^((p - 1) / 6)` of the BLS12-381 Frobenius.
+const BLS12381_FROBENIUS_COEFF: [u32; 24] = [
+    0x92235fb8, 0x8d0775ed, 0x63e7813d, 0xf67ea53d, 0x84bab9c4, 0x7b2443d7, 0x3cbd5f4f, 0x0fd603fd,
+    0x202c0d1f, 0xc231beb4, 0x02bb0667, 0x1904d3bf, 0x6ddc4af3, 0x2cf78a12, 0x4d6c7ec2, 0x282d5ac1,
+    0x71f63c5f, 0xec0c8ec9, 0xb6c7b36f, 0x54a14787, 0x231f9fb8, 0x88e9e902, 0x36c4e032, 0x00fc3e2b,
+];
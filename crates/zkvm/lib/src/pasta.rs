@@ -0,0 +1,143 @@
+use crate::{
+    syscall_pallas_add, syscall_pallas_double, syscall_vesta_add, syscall_vesta_double,
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+};
+
+/// The number of limbs in [PallasPoint] and [VestaPoint].
+pub const N: usize = 16;
+
+/// A point on the Pallas curve.
+#[derive(Copy, Clone)]
+#[repr(align(4))]
+pub struct PallasPoint(pub WeierstrassPoint<N>);
+
+impl WeierstrassAffinePoint<N> for PallasPoint {
+    fn infinity() -> Self {
+        Self(WeierstrassPoint::Infinity)
+    }
+
+    fn is_infinity(&self) -> bool {
+        matches!(self.0, WeierstrassPoint::Infinity)
+    }
+}
+
+impl AffinePoint<N> for PallasPoint {
+    /// The generator is `(-1, 2)`, the point used by the `pasta_curves` crate.
+    const GENERATOR: [u32; N] =
+        [0, 2569875693, 156039451, 575052028, 0, 0, 0, 1073741824, 2, 0, 0, 0, 0, 0, 0, 0];
+
+    const GENERATOR_T: Self = Self(WeierstrassPoint::Affine(Self::GENERATOR));
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(WeierstrassPoint::Affine(limbs))
+    }
+
+    fn identity() -> Self {
+        Self::infinity()
+    }
+
+    fn is_identity(&self) -> bool {
+        self.is_infinity()
+    }
+
+    fn limbs_ref(&self) -> &[u32; N] {
+        match &self.0 {
+            WeierstrassPoint::Infinity => panic!("Infinity point has no limbs"),
+            WeierstrassPoint::Affine(limbs) => limbs,
+        }
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; N] {
+        match &mut self.0 {
+            WeierstrassPoint::Infinity => panic!("Infinity point has no limbs"),
+            WeierstrassPoint::Affine(limbs) => limbs,
+        }
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        unsafe {
+            syscall_pallas_add(a, b);
+        }
+    }
+
+    fn complete_add_assign(&mut self, other: &Self) {
+        self.weierstrass_add_assign(other);
+    }
+
+    fn double(&mut self) {
+        let a = self.limbs_mut();
+        unsafe {
+            syscall_pallas_double(a);
+        }
+    }
+}
+
+/// A point on the Vesta curve.
+#[derive(Copy, Clone)]
+#[repr(align(4))]
+pub struct VestaPoint(pub WeierstrassPoint<N>);
+
+impl WeierstrassAffinePoint<N> for VestaPoint {
+    fn infinity() -> Self {
+        Self(WeierstrassPoint::Infinity)
+    }
+
+    fn is_infinity(&self) -> bool {
+        matches!(self.0, WeierstrassPoint::Infinity)
+    }
+}
+
+impl AffinePoint<N> for VestaPoint {
+    /// The generator is `(-1, 2)`, the point used by the `pasta_curves` crate.
+    const GENERATOR: [u32; N] =
+        [0, 2353457953, 160737501, 575052028, 0, 0, 0, 1073741824, 2, 0, 0, 0, 0, 0, 0, 0];
+
+    const GENERATOR_T: Self = Self(WeierstrassPoint::Affine(Self::GENERATOR));
+
+    fn new(limbs: [u32; N]) -> Self {
+        Self(WeierstrassPoint::Affine(limbs))
+    }
+
+    fn identity() -> Self {
+        Self::infinity()
+    }
+
+    fn is_identity(&self) -> bool {
+        self.is_infinity()
+    }
+
+    fn limbs_ref(&self) -> &[u32; N] {
+        match &self.0 {
+            WeierstrassPoint::Infinity => panic!("Infinity point has no limbs"),
+            WeierstrassPoint::Affine(limbs) => limbs,
+        }
+    }
+
+    fn limbs_mut(&mut self) -> &mut [u32; N] {
+        match &mut self.0 {
+            WeierstrassPoint::Infinity => panic!("Infinity point has no limbs"),
+            WeierstrassPoint::Affine(limbs) => limbs,
+        }
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        let a = self.limbs_mut();
+        let b = other.limbs_ref();
+        unsafe {
+            syscall_vesta_add(a, b);
+        }
+    }
+
+    fn complete_add_assign(&mut self, other: &Self) {
+        self.weierstrass_add_assign(other);
+    }
+
+    fn double(&mut self) {
+        let a = self.limbs_mut();
+        unsafe {
+            syscall_vesta_double(a);
+        }
+    }
+}
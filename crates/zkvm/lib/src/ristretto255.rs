@@ -0,0 +1,146 @@
+//! The ristretto255 prime order group of RFC 9496, built on top of Ed25519.
+//!
+//! Group operations run on the underlying Edwards points with the `ED_ADD` precompile, and the
+//! encoding and decoding use the [`Ed25519FieldElement`] arithmetic.
+
+use core::ops::{Add, Neg, Sub};
+
+use crate::{
+    ed25519::{Ed25519AffinePoint, Ed25519FieldElement, Ed25519Scalar},
+    utils::AffinePoint,
+};
+
+/// The length of an encoded ristretto255 element.
+pub const RISTRETTO_ENCODED_BYTES: usize = 32;
+
+/// The encoding of the ristretto255 generator, which is the Ed25519 base point.
+pub const RISTRETTO_BASEPOINT_COMPRESSED: [u8; RISTRETTO_ENCODED_BYTES] = [
+    0xe2, 0xf2, 0xae, 0x0a, 0x6a, 0xbc, 0x4e, 0x71, 0xa8, 0x84, 0xa9, 0x61, 0xc5, 0x00, 0x51, 0x5f,
+    0x58, 0xe3, 0x0b, 0x6a, 0xa5, 0x82, 0xdd, 0x8d, 0xb6, 0xa6, 0x59, 0x45, 0xe0, 0x8d, 0x2d, 0x76,
+];
+
+/// The constant `1 / sqrt(a - d)` with `a = -1`.
+const INVSQRT_A_MINUS_D: Ed25519FieldElement = Ed25519FieldElement([
+    0x805d40ea, 0x99c8fdaa, 0x5a4172be, 0x9d2f1617, 0xfe01d840, 0x16c27b91, 0xcfaffca2, 0x786c8905,
+]);
+
+/// An element of the ristretto255 group.
+///
+/// The element is represented by any of the Ed25519 points of its coset, so two values may hold
+/// different points and still compare equal.
+#[derive(Copy, Clone)]
+pub struct RistrettoPoint(pub Ed25519AffinePoint);
+
+impl RistrettoPoint {
+    pub fn identity() -> Self {
+        Self(Ed25519AffinePoint::identity())
+    }
+
+    /// The ristretto255 generator.
+    pub fn basepoint() -> Self {
+        Self(Ed25519AffinePoint::new(Ed25519AffinePoint::GENERATOR))
+    }
+
+    /// Encodes the element as specified in RFC 9496, section 4.3.2.
+    pub fn compress(&self) -> [u8; RISTRETTO_ENCODED_BYTES] {
+        let one = Ed25519FieldElement::ONE;
+        let (x, y) = (self.0.x(), self.0.y());
+        let t = x * y;
+
+        let u1 = (one + y) * (one - y);
+        let u2 = x * y;
+        let (_, invsqrt) = Ed25519FieldElement::sqrt_ratio_i(&one, &(u1 * u2.square()));
+        let den1 = invsqrt * u1;
+        let den2 = invsqrt * u2;
+        let z_inv = den1 * den2 * t;
+
+        let rotate = (t * z_inv).is_negative();
+        let (x, y, den_inv) = if rotate {
+            (
+                y * Ed25519FieldElement::SQRT_M1,
+                x * Ed25519FieldElement::SQRT_M1,
+                den1 * INVSQRT_A_MINUS_D,
+            )
+        } else {
+            (x, y, den2)
+        };
+        let y = if (x * z_inv).is_negative() { -y } else { y };
+
+        (den_inv * (one - y)).abs().to_bytes()
+    }
+
+    /// Decodes an element as specified in RFC 9496, section 4.3.1, returning `None` if the bytes
+    /// are not the canonical encoding of a group element.
+    pub fn decompress(bytes: &[u8; RISTRETTO_ENCODED_BYTES]) -> Option<Self> {
+        let one = Ed25519FieldElement::ONE;
+        let s = Ed25519FieldElement::from_canonical_bytes(bytes)?;
+        if s.is_negative() {
+            return None;
+        }
+
+        let ss = s.square();
+        let u1 = one - ss;
+        let u2 = one + ss;
+        let u2_sqr = u2.square();
+        let v = -(Ed25519FieldElement::D * u1.square()) - u2_sqr;
+        let (was_square, invsqrt) = Ed25519FieldElement::sqrt_ratio_i(&one, &(v * u2_sqr));
+
+        let den_x = invsqrt * u2;
+        let den_y = invsqrt * den_x * v;
+        let x = ((s + s) * den_x).abs();
+        let y = u1 * den_y;
+        if !was_square || (x * y).is_negative() || y.is_zero() {
+            return None;
+        }
+        Some(Self(Ed25519AffinePoint::from_coordinates(x, y)))
+    }
+
+    /// Multiplies the element by the given scalar.
+    pub fn mul_scalar(&self, scalar: &Ed25519Scalar) -> Self {
+        Self(self.0.mul_scalar(&scalar.to_bytes()))
+    }
+
+    /// Computes the sum of `scalars[i] * points[i]`.
+    pub fn multi_scalar_mul(scalars: &[Ed25519Scalar], points: &[Self]) -> Self {
+        let scalars = scalars.iter().map(Ed25519Scalar::to_bytes).collect::<Vec<_>>();
+        let points = points.iter().map(|point| point.0).collect::<Vec<_>>();
+        Self(Ed25519AffinePoint::multi_scalar_mul(&scalars, &points))
+    }
+}
+
+impl PartialEq for RistrettoPoint {
+    /// Two points represent the same element if `x1 * y2 == y1 * x2` or `y1 * y2 == x1 * x2`.
+    fn eq(&self, other: &Self) -> bool {
+        let (x1, y1) = (self.0.x(), self.0.y());
+        let (x2, y2) = (other.0.x(), other.0.y());
+        x1 * y2 == y1 * x2 || y1 * y2 == x1 * x2
+    }
+}
+
+impl Eq for RistrettoPoint {}
+
+impl Add for RistrettoPoint {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut res = self.0;
+        res.add_assign(&rhs.0);
+        Self(res)
+    }
+}
+
+impl Neg for RistrettoPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Sub for RistrettoPoint {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
@@ -1,6 +1,9 @@
 use crate::{
-    syscall_secp256k1_add, syscall_secp256k1_double,
-    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+    ecdsa::signature::{self, EcdsaPrecompilePoint},
+    syscall_secp256k1_add, syscall_secp256k1_decompress, syscall_secp256k1_double,
+    syscall_secp256k1_ecdsa_verify, syscall_secp256k1_ecrecover, syscall_secp256k1_msm,
+    syscall_secp256k1_scalar_mul,
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassMsmPoint, WeierstrassPoint},
 };
 
 /// The number of limbs in [Secp256k1Point].
@@ -79,3 +82,94 @@ impl AffinePoint<N> for Secp256k1Point {
         }
     }
 }
+
+impl WeierstrassMsmPoint<N> for Secp256k1Point {
+    /// The first curve point whose x-coordinate is at least
+    /// `sha256("monerochan msm offset secp256k1") mod p`.
+    const MSM_OFFSET: [u32; N] = [
+        2873828544, 3867433794, 1660438897, 1767377442, 296446704, 3324902135, 2811622507,
+        1367276077, 1000299453, 2350864382, 1076557591, 315571955, 3796732433, 1598752605,
+        2738808746, 3043791763,
+    ];
+
+    const MSM_OFFSET_CORRECTION: [u32; N] = [
+        296174264, 463730147, 420678962, 4142786836, 4141261009, 859285354, 1818710952, 111230052,
+        3044415364, 3379485437, 3663760872, 4286977903, 3742623654, 1967515938, 1727751974,
+        4206474590,
+    ];
+
+    fn scalar_mul_chunk(acc: &mut [u32; N], input: &[u32]) {
+        unsafe {
+            syscall_secp256k1_scalar_mul(acc, input.as_ptr());
+        }
+    }
+
+    fn msm_chunk(acc: &mut [u32; N], input: &[u32]) {
+        unsafe {
+            syscall_secp256k1_msm(acc, input.as_ptr());
+        }
+    }
+}
+
+impl EcdsaPrecompilePoint for Secp256k1Point {
+    const BASE_MODULUS: [u32; 8] = [
+        0xFFFFFC2F, 0xFFFFFFFE, 0xFFFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF,
+        0xFFFFFFFF,
+    ];
+
+    const ORDER: [u32; 8] = [
+        0xD0364141, 0xBFD25E8C, 0xAF48A03B, 0xBAAEDCE6, 0xFFFFFFFE, 0xFFFFFFFF, 0xFFFFFFFF,
+        0xFFFFFFFF,
+    ];
+
+    const EQUATION_A: [u32; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
+
+    const EQUATION_B: [u32; 8] = [7, 0, 0, 0, 0, 0, 0, 0];
+
+    fn ecrecover_syscall(out: &mut [u32; 17], input: &[u32; 25]) {
+        unsafe {
+            syscall_secp256k1_ecrecover(out, input);
+        }
+    }
+
+    fn ecdsa_verify_syscall(out: &mut u32, input: &[u32; 40]) {
+        unsafe {
+            syscall_secp256k1_ecdsa_verify(out, input);
+        }
+    }
+
+    fn decompress(x: &[u32; 8], is_odd: bool) -> Self {
+        /// The decompress syscall needs its buffer to be aligned along a four byte boundary.
+        #[repr(align(4))]
+        struct Buffer([u8; 64]);
+
+        let mut buffer = Buffer([0; 64]);
+        for (chunk, word) in buffer.0[..32].rchunks_exact_mut(4).zip(x) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        unsafe {
+            syscall_secp256k1_decompress(&mut buffer.0, is_odd);
+        }
+
+        let mut limbs = [0u32; N];
+        limbs[..8].copy_from_slice(x);
+        for (limb, chunk) in limbs[8..].iter_mut().zip(buffer.0[32..].rchunks_exact(4)) {
+            *limb = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+        Self::new(limbs)
+    }
+}
+
+/// Recovers the Secp256k1 public key of an ECDSA signature with the ecrecover precompile.
+///
+/// See [`signature::ecrecover`].
+pub fn ecrecover(msg_hash: &[u8; 32], signature: &[u8; 64], recovery_id: u8) -> Option<[u8; 64]> {
+    signature::ecrecover::<Secp256k1Point>(msg_hash, signature, recovery_id)
+}
+
+/// Verifies a Secp256k1 ECDSA signature with the ECDSA verify precompile.
+///
+/// See [`signature::ecdsa_verify`].
+pub fn ecdsa_verify(public_key: &[u8; 64], msg_hash: &[u8; 32], signature: &[u8; 64]) -> bool {
+    signature::ecdsa_verify::<Secp256k1Point>(public_key, msg_hash, signature)
+}
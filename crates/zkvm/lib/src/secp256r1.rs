@@ -1,6 +1,9 @@
 use crate::{
-    syscall_secp256r1_add, syscall_secp256r1_double,
-    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassPoint},
+    ecdsa::signature::{self, EcdsaPrecompilePoint},
+    syscall_secp256r1_add, syscall_secp256r1_decompress, syscall_secp256r1_double,
+    syscall_secp256r1_ecdsa_verify, syscall_secp256r1_ecrecover, syscall_secp256r1_msm,
+    syscall_secp256r1_scalar_mul,
+    utils::{AffinePoint, WeierstrassAffinePoint, WeierstrassMsmPoint, WeierstrassPoint},
 };
 
 /// The number of limbs in [Secp256r1Point].
@@ -78,3 +81,100 @@ impl AffinePoint<N> for Secp256r1Point {
         }
     }
 }
+
+impl WeierstrassMsmPoint<N> for Secp256r1Point {
+    /// The first curve point whose x-coordinate is at least
+    /// `sha256("monerochan msm offset secp256r1") mod p`.
+    const MSM_OFFSET: [u32; N] = [
+        241355222, 3611762718, 1624903631, 1332375867, 2914409999, 2262487847, 87567907, 329647127,
+        1808323222, 202965784, 1136258362, 1387547543, 3036321353, 985557187, 830935722,
+        3605334282,
+    ];
+
+    const MSM_OFFSET_CORRECTION: [u32; N] = [
+        2914578201, 2736678176, 748916771, 2517244789, 1747294120, 3864315243, 3230162134,
+        1200984109, 2246962431, 3368289093, 3923138433, 4027702755, 607955307, 3153271359,
+        1098807725, 3070907173,
+    ];
+
+    fn scalar_mul_chunk(acc: &mut [u32; N], input: &[u32]) {
+        unsafe {
+            syscall_secp256r1_scalar_mul(acc, input.as_ptr());
+        }
+    }
+
+    fn msm_chunk(acc: &mut [u32; N], input: &[u32]) {
+        unsafe {
+            syscall_secp256r1_msm(acc, input.as_ptr());
+        }
+    }
+}
+
+impl EcdsaPrecompilePoint for Secp256r1Point {
+    const BASE_MODULUS: [u32; 8] = [
+        0xFFFFFFFF, 0xFFFFFFFF, 0xFFFFFFFF, 0x00000000, 0x00000000, 0x00000000, 0x00000001,
+        0xFFFFFFFF,
+    ];
+
+    const ORDER: [u32; 8] = [
+        0xFC632551, 0xF3B9CAC2, 0xA7179E84, 0xBCE6FAAD, 0xFFFFFFFF, 0xFFFFFFFF, 0x00000000,
+        0xFFFFFFFF,
+    ];
+
+    const EQUATION_A: [u32; 8] = [
+        0xFFFFFFFC, 0xFFFFFFFF, 0xFFFFFFFF, 0x00000000, 0x00000000, 0x00000000, 0x00000001,
+        0xFFFFFFFF,
+    ];
+
+    const EQUATION_B: [u32; 8] = [
+        0x27D2604B, 0x3BCE3C3E, 0xCC53B0F6, 0x651D06B0, 0x769886BC, 0xB3EBBD55, 0xAA3A93E7,
+        0x5AC635D8,
+    ];
+
+    fn ecrecover_syscall(out: &mut [u32; 17], input: &[u32; 25]) {
+        unsafe {
+            syscall_secp256r1_ecrecover(out, input);
+        }
+    }
+
+    fn ecdsa_verify_syscall(out: &mut u32, input: &[u32; 40]) {
+        unsafe {
+            syscall_secp256r1_ecdsa_verify(out, input);
+        }
+    }
+
+    fn decompress(x: &[u32; 8], is_odd: bool) -> Self {
+        /// The decompress syscall needs its buffer to be aligned along a four byte boundary.
+        #[repr(align(4))]
+        struct Buffer([u8; 64]);
+
+        let mut buffer = Buffer([0; 64]);
+        for (chunk, word) in buffer.0[..32].rchunks_exact_mut(4).zip(x) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        unsafe {
+            syscall_secp256r1_decompress(&mut buffer.0, is_odd);
+        }
+
+        let mut limbs = [0u32; N];
+        limbs[..8].copy_from_slice(x);
+        for (limb, chunk) in limbs[8..].iter_mut().zip(buffer.0[32..].rchunks_exact(4)) {
+            *limb = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+        Self::new(limbs)
+    }
+}
+
+/// Recovers the Secp256r1 public key of an ECDSA signature with the ecrecover precompile.
+///
+/// See [`signature::ecrecover`].
+pub fn ecrecover(msg_hash: &[u8; 32], signature: &[u8; 64], recovery_id: u8) -> Option<[u8; 64]> {
+    signature::ecrecover::<Secp256r1Point>(msg_hash, signature, recovery_id)
+}
+
+/// Verifies a Secp256r1 ECDSA signature with the ECDSA verify precompile.
+///
+/// See [`signature::ecdsa_verify`].
+pub fn ecdsa_verify(public_key: &[u8; 64], msg_hash: &[u8; 32], signature: &[u8; 64]) -> bool {
+    signature::ecdsa_verify::<Secp256r1Point>(public_key, msg_hash, signature)
+}
@@ -193,3 +193,85 @@ pub trait WeierstrassAffinePoint<const N: usize>: AffinePoint<N> {
         self.add_assign(other);
     }
 }
+
+/// The number of words of each scalar consumed by one scalar mul or MSM syscall.
+const MSM_CHUNK_WORDS: usize = 2;
+
+/// The number of precomputed multiples `[1]P, .., [15]P` of each point passed to the scalar mul
+/// and MSM syscalls.
+const MSM_TABLE_SIZE: usize = 15;
+
+/// A trait for affine points on Weierstrass curves with fixed-window scalar multiplication and
+/// multi-scalar multiplication precompiles.
+///
+/// Each syscall consumes 64 bits of every scalar, and the accumulator it updates can never be the
+/// point at infinity. The accumulator therefore starts at a fixed offset point `R`, ends at
+/// `[2^256]R + sum`, and the offset is removed with a complete addition of `[-2^256]R`.
+pub trait WeierstrassMsmPoint<const N: usize>: WeierstrassAffinePoint<N> {
+    /// The offset point `R` the accumulator starts from.
+    const MSM_OFFSET: [u32; N];
+
+    /// The point `[-2^256]R`, which removes the offset from the accumulator.
+    const MSM_OFFSET_CORRECTION: [u32; N];
+
+    /// Runs one chunk of a fixed-window scalar multiplication of a single point.
+    fn scalar_mul_chunk(acc: &mut [u32; N], input: &[u32]);
+
+    /// Runs one chunk of a fixed-window multi-scalar multiplication of two points.
+    fn msm_chunk(acc: &mut [u32; N], input: &[u32]);
+
+    /// Multiplies `self` by the given little endian 256-bit scalar.
+    fn fixed_window_mul(&self, scalar: &[u32; 8]) -> Self {
+        Self::fixed_window_msm(&[*scalar], core::slice::from_ref(self))
+    }
+
+    /// Computes the sum of `scalars[i] * points[i]` for little endian 256-bit scalars.
+    ///
+    /// The points must lie in the prime order subgroup of the curve.
+    fn fixed_window_msm(scalars: &[[u32; 8]], points: &[Self]) -> Self {
+        assert_eq!(scalars.len(), points.len());
+
+        let terms = scalars
+            .iter()
+            .zip(points.iter())
+            .filter(|(_, point)| !point.is_infinity())
+            .collect::<Vec<_>>();
+
+        let mut res = Self::infinity();
+        for group in terms.chunks(2) {
+            // The input holds the current scalar chunk of each point, followed by the window table
+            // of each point.
+            let table_offset = group.len() * MSM_CHUNK_WORDS;
+            let mut input = vec![0u32; table_offset + group.len() * MSM_TABLE_SIZE * N];
+            for (i, (_, point)) in group.iter().enumerate() {
+                let mut multiple = (*point).clone();
+                for digit in 0..MSM_TABLE_SIZE {
+                    if digit > 0 {
+                        multiple.complete_add_assign(point);
+                    }
+                    let start = table_offset + (i * MSM_TABLE_SIZE + digit) * N;
+                    input[start..start + N].copy_from_slice(multiple.limbs_ref());
+                }
+            }
+
+            let mut acc = Self::MSM_OFFSET;
+            for chunk in (0..8 / MSM_CHUNK_WORDS).rev() {
+                for (i, (scalar, _)) in group.iter().enumerate() {
+                    input[i * MSM_CHUNK_WORDS..(i + 1) * MSM_CHUNK_WORDS].copy_from_slice(
+                        &scalar[chunk * MSM_CHUNK_WORDS..(chunk + 1) * MSM_CHUNK_WORDS],
+                    );
+                }
+                if group.len() == 1 {
+                    Self::scalar_mul_chunk(&mut acc, &input);
+                } else {
+                    Self::msm_chunk(&mut acc, &input);
+                }
+            }
+
+            let mut sum = Self::new(acc);
+            sum.complete_add_assign(&Self::new(Self::MSM_OFFSET_CORRECTION));
+            res.complete_add_assign(&sum);
+        }
+        res
+    }
+}
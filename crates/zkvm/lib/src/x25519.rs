@@ -0,0 +1,61 @@
+//! The X25519 function of RFC 7748, with the Montgomery ladder run by the `X25519_LADDER_STEP`
+//! precompile.
+
+use crate::{ed25519::Ed25519FieldElement, syscall_x25519_ladder_step};
+
+/// The u-coordinate of the Curve25519 base point.
+pub const X25519_BASEPOINT: [u8; 32] = [
+    9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// Computes the X25519 function of RFC 7748 on a little endian scalar and u-coordinate.
+///
+/// The scalar is clamped and the most significant bit of the u-coordinate is ignored, as
+/// specified by the RFC. A low order input point yields the all-zero output, which callers doing a
+/// key exchange may want to reject.
+pub fn x25519(scalar: &[u8; 32], u: &[u8; 32]) -> [u8; 32] {
+    let mut scalar = *scalar;
+    scalar[0] &= 248;
+    scalar[31] &= 127;
+    scalar[31] |= 64;
+
+    let mut u = *u;
+    u[31] &= 127;
+    let x1 = Ed25519FieldElement::from_bytes_mod_order(&u);
+
+    // The ladder state `[x2, z2, x3, z3]`, starting from the point at infinity and the input point.
+    let mut state = [0u32; 32];
+    state[0] = 1;
+    state[16..24].copy_from_slice(&x1.0);
+    state[24] = 1;
+
+    let mut swap = false;
+    for t in (0..255).rev() {
+        let bit = (scalar[t / 8] >> (t % 8)) & 1 == 1;
+        if swap != bit {
+            swap_points(&mut state);
+        }
+        swap = bit;
+        unsafe {
+            syscall_x25519_ladder_step(&mut state, &x1.0);
+        }
+    }
+    if swap {
+        swap_points(&mut state);
+    }
+
+    let x2 = Ed25519FieldElement(state[0..8].try_into().unwrap());
+    let z2 = Ed25519FieldElement(state[8..16].try_into().unwrap());
+    (x2 * z2.invert()).to_bytes()
+}
+
+/// Computes the X25519 public key of a scalar, i.e. the scalar times the base point.
+pub fn x25519_base(scalar: &[u8; 32]) -> [u8; 32] {
+    x25519(scalar, &X25519_BASEPOINT)
+}
+
+/// Swaps the two points of the ladder state.
+fn swap_points(state: &mut [u32; 32]) {
+    let (first, second) = state.split_at_mut(16);
+    first.swap_with_slice(second);
+}
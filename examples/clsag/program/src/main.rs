@@ -0,0 +1,23 @@
+//! Proves spend authority over one output of a Monero ring without revealing which one.
+
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use monerochan_runtime::lib::monero::{Clsag, RingMember};
+
+pub fn main() {
+    let message = monerochan_runtime::io::read::<[u8; 32]>();
+    let ring = monerochan_runtime::io::read::<Vec<RingMember>>();
+    let pseudo_out = monerochan_runtime::io::read::<[u8; 32]>();
+    let sig = monerochan_runtime::io::read::<Clsag>();
+
+    println!("cycle-tracker-start: clsag-verify");
+    sig.verify(&message, &ring, &pseudo_out).expect("invalid CLSAG signature");
+    println!("cycle-tracker-end: clsag-verify");
+
+    // Commit to the signed statement and the key image, which links double spends.
+    monerochan_runtime::io::commit(&message);
+    monerochan_runtime::io::commit(&ring);
+    monerochan_runtime::io::commit(&pseudo_out);
+    monerochan_runtime::io::commit(&sig.key_image);
+}
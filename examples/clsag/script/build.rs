@@ -0,0 +1,3 @@
+fn main() {
+    monerochan_build::build_program("../program");
+}
@@ -0,0 +1,104 @@
+use hex_literal::hex;
+use monerochan::{include_elf, utils, MONEROCHANStdin, ProverClient};
+use monerochan_lib::monero::{Clsag, RingMember};
+
+/// The ELF we want to execute inside the zkVM.
+const ELF: &[u8] = include_elf!("clsag-program");
+
+fn main() {
+    // Setup a tracer for logging.
+    utils::setup_logger();
+
+    // A ring of eleven outputs signed by the first one.
+    let message = hex!("1fb2dc39f5892206a3d81730e7aef7de53f481c6af43df88693c14bb603b0ed7");
+    let ring = vec![
+        RingMember {
+            dest: hex!("07bc37cfbdc9d2d6820f23c2dcb0bbd6aab56990e6cab868b8abd78885ffeabd"),
+            mask: hex!("cc0fed2ee7c86377b7a3e947f33cf266d8938252e95643db92796208f02c138e"),
+        },
+        RingMember {
+            dest: hex!("9373408e7cd20e49aca1d8ef039d97f684fc80227c630ebe2540d3c40b1cdb8d"),
+            mask: hex!("9bf14703f11f2261f925613633edb6093437c9c944ac6793f9d9114fc1b251bf"),
+        },
+        RingMember {
+            dest: hex!("aa179842013a3c2296fc383b241795aca185e4732aca66f2345e2a0fc9247166"),
+            mask: hex!("d52b44e0ea9d2b6a8d10721be58f3a5766c19a3d0f1150a5ec4a4dbdd7ee1d3a"),
+        },
+        RingMember {
+            dest: hex!("4abe9746b238ee83465ac9f07884b4084c370b0d9a1fda0d728913f480fd8ef8"),
+            mask: hex!("63aaf004097e38e50b9a03333b46c23673c5aa773449c17c2ff0daefda8e44ae"),
+        },
+        RingMember {
+            dest: hex!("0dc6e2106bb7d1e02acf546af5139aa060bdf178b8e3e5a2a8451b680b3003d8"),
+            mask: hex!("1951a28847b50e2c09ce3637a5569419cac5ca628532dcfea9563c361901d54b"),
+        },
+        RingMember {
+            dest: hex!("e97c2fcf3685b8827c40b344e810cbde01c01986d7970167a6c53aa64ab456ef"),
+            mask: hex!("0708c0935c34fe2116f6373bc159b78d7e593b48cbad4ad2c19649da6ce72328"),
+        },
+        RingMember {
+            dest: hex!("5f184cf8f07c8e06370034e417dff7e7d8ba88048d1588b6fed2623d2abc9891"),
+            mask: hex!("fdacdf8611bfce5feda420ab4e3b324c2c93c8109522ca4dd94239ec83da3be5"),
+        },
+        RingMember {
+            dest: hex!("99f1ba33d484b2fe9c26acf5f71b66430b226eee18a3c222e34865ddc43d8538"),
+            mask: hex!("fa395e8d6634665ad497cebb015c413d4fe434522c2601c027ff5822936619c2"),
+        },
+        RingMember {
+            dest: hex!("920a9d4deca317a540f1e3dce4e133504207496870af33e82e1fecc02e424d6d"),
+            mask: hex!("07a50264260d0032f938af9a8f9522ef79d33b12aad52be192bfea1b21206e3b"),
+        },
+        RingMember {
+            dest: hex!("60c24c40ff5386f43347d2c30bfa3adcec996daea0da905897501a4f85845617"),
+            mask: hex!("40578a14c323959fcb69628af7d62a347b43ba65ceb3f02cec18bee1c8cbed12"),
+        },
+        RingMember {
+            dest: hex!("cc9fd0c328016002f395eb98e71f164c409e78019467fd88f8950f962fa90ad5"),
+            mask: hex!("04d59f3c951b792a7182e28fde81dd637a965710c90df1bb37d3e9035bd1f3d9"),
+        },
+    ];
+    let pseudo_out = hex!("0fd51b0da7761ce2b0fdcc3bf69726e9ada79b7d65b0d1fdd1d2099d05eb566a");
+    let sig = Clsag {
+        s: vec![
+            hex!("55b5df10c2e74c24e5462b98a853b2cd1486e83ded250683be90d86ca2fd0a04"),
+            hex!("d40124113f2e8251005a460fb264ce905a8e1b49f013300d5913402bf0a56e09"),
+            hex!("c759f098e2a0a94f172491b5a4e1cbc4173083ddea73cebc5b5f497fb2a4aa05"),
+            hex!("768eccbb8614d3b7507a6a0b2319d129dc06b144310a7dd298dadd2981ad4609"),
+            hex!("f59fa418993c6aa0a58826c46137d55a5f301a54fb5610b1ac8dab8590240202"),
+            hex!("4b5d38ea67f6d827fd878fabce1f56a02c828b61431508c49ff8e010c5b2ac0e"),
+            hex!("b6e2e4d6d9ac5ad6968a69891388dc482154fc21b2256333e3ec15545376510e"),
+            hex!("61a2cca1ff9532b8fdaef82bcdc2a2c3d9208c0b6161145164fee265f23b8008"),
+            hex!("018f56ca5ec78217d6bc76ba680147f408b20c80f99796612f933b0bdd92ef05"),
+            hex!("7c6b276dbdc5c85045aa24c4264d449176ffed377ecb9bc8300117914b697c04"),
+            hex!("e6fd4d10768e6a4dd7492b1a5f89e161c7121c6a6273f0ced58a300a0b518505"),
+        ],
+        c1: hex!("418dc4f873fe0a0f9eb616cfc54a88358be80f0b7b78581a1c8afe6b957e7a04"),
+        key_image: hex!("527b5d4f000c6da86c48c16923cea2663289c7ac231ee9740da807c33866143c"),
+        d: hex!("456054ba49fc7da3f79ca27916aa8d73e4dee1bd7229b8af0ce8f8f374934be3"),
+    };
+
+    let mut stdin = MONEROCHANStdin::new();
+    stdin.write(&message);
+    stdin.write(&ring);
+    stdin.write(&pseudo_out);
+    stdin.write(&sig);
+
+    // Execute the program to check the signature and report the cycle count.
+    let client = ProverClient::from_env();
+    let (_, report) = client.execute(ELF, &stdin).run().expect("execution failed");
+    println!("executed: {}", report);
+
+    // Generate and verify the proof.
+    let (pk, vk) = client.setup(ELF);
+    let mut proof = client.prove(&pk, &stdin).run().expect("proving failed");
+    client.verify(&proof, &vk).expect("verification failed");
+
+    // The key image is the last public value.
+    let _ = proof.public_values.read::<[u8; 32]>();
+    let _ = proof.public_values.read::<Vec<RingMember>>();
+    let _ = proof.public_values.read::<[u8; 32]>();
+    let key_image = proof.public_values.read::<[u8; 32]>();
+    assert_eq!(key_image, sig.key_image);
+
+    println!("successfully generated and verified proof for the program!")
+}
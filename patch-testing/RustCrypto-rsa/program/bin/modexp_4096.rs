@@ -0,0 +1,40 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use monerochan_runtime::lib::bigint::mod_exp;
+use rsa::sha2::{Digest, Sha256};
+
+/// The DER encoding of the SHA-256 `DigestInfo` prefix used by PKCS#1 v1.5 signatures.
+const SHA256_DIGEST_INFO_PREFIX: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01, 0x05,
+    0x00, 0x04, 0x20,
+];
+
+pub fn main() {
+    let times: u8 = monerochan_runtime::io::read();
+
+    for _ in 0..times {
+        verify_inner();
+    }
+}
+
+fn verify_inner() {
+    let modulus: Vec<u8> = monerochan_runtime::io::read();
+    let exponent: Vec<u8> = monerochan_runtime::io::read();
+    let signature: Vec<u8> = monerochan_runtime::io::read();
+    let data: Vec<u8> = monerochan_runtime::io::read();
+
+    assert_eq!(modulus.len(), 512);
+    assert_eq!(signature.len(), modulus.len());
+
+    // The encoded message is `0x00 || 0x01 || 0xff.. || 0x00 || DigestInfo || hash`.
+    let encoded = mod_exp(&signature, &exponent, &modulus);
+    let hash = Sha256::digest(&data);
+    let suffix = [&SHA256_DIGEST_INFO_PREFIX[..], &hash[..]].concat();
+    let padding_len = encoded.len() - suffix.len() - 3;
+
+    assert_eq!(&encoded[..2], &[0x00, 0x01]);
+    assert!(encoded[2..2 + padding_len].iter().all(|&byte| byte == 0xff));
+    assert_eq!(encoded[2 + padding_len], 0x00);
+    assert_eq!(&encoded[3 + padding_len..], &suffix[..]);
+}
@@ -28,15 +28,45 @@ pub fn test_pkcs_verify_100(
     |_| {}
 }
 
+#[monerochan_test::monerochan_test("rsa_test_modexp_4096", syscalls = [UINT4096_MUL], gpu, prove)]
+pub fn test_modexp_4096_10(
+    stdin: &mut monerochan::MONEROCHANStdin,
+) -> impl FnOnce(monerochan::MONEROCHANPublicValues) {
+    use rsa::traits::PublicKeyParts;
+
+    let times: u8 = 10;
+
+    stdin.write(&times);
+
+    for _ in 0..times {
+        let (signature, verifying_key, data) = sign_inner_with_bits(4096);
+
+        // Check that the original crate also validates this signature.
+        assert!(verifying_key.verify(&data, &signature).is_ok());
+
+        let public_key = RsaPublicKey::from(verifying_key);
+        stdin.write(&public_key.n().to_bytes_be());
+        stdin.write(&public_key.e().to_bytes_be());
+        stdin.write(&signature.to_vec());
+        stdin.write(&data);
+    }
+
+    |_| {}
+}
+
 #[cfg(test)]
 fn sign_inner() -> (Signature, VerifyingKey<Sha256>, Vec<u8>) {
+    sign_inner_with_bits(2048)
+}
+
+#[cfg(test)]
+fn sign_inner_with_bits(bits: usize) -> (Signature, VerifyingKey<Sha256>, Vec<u8>) {
     use rsa::pkcs1v15::SigningKey;
     use rsa::sha2::Sha256;
     use rsa::signature::{Keypair, RandomizedSigner};
     use rsa::RsaPrivateKey;
 
     let mut rng = rand::thread_rng();
-    let bits = 2048;
     let private_key = RsaPrivateKey::new(&mut rng, bits).expect("failed to generate a key");
     let signing_key = SigningKey::<Sha256>::new(private_key);
     let verifying_key = signing_key.verifying_key();
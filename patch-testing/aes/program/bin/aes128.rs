@@ -0,0 +1,24 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use aes::{
+    cipher::{BlockEncrypt, KeyInit},
+    Aes128,
+};
+
+/// Emits AES128_ENCRYPT_BLOCK syscalls.
+pub fn main() {
+    let times = monerochan_runtime::io::read::<usize>();
+
+    for _ in 0..times {
+        let key = monerochan_runtime::io::read_vec();
+        let mut blocks = monerochan_runtime::io::read::<Vec<[u8; 16]>>();
+
+        let cipher = Aes128::new_from_slice(&key).unwrap();
+        for block in blocks.iter_mut() {
+            cipher.encrypt_block(block.into());
+        }
+
+        monerochan_runtime::io::commit(&blocks);
+    }
+}
@@ -0,0 +1,55 @@
+/// Writes random keys and blocks to the stdin, and returns the expected ciphertexts.
+fn write_random_blocks<C: aes::cipher::KeyInit + aes::cipher::BlockEncrypt>(
+    stdin: &mut monerochan::MONEROCHANStdin,
+) -> Vec<Vec<[u8; 16]>> {
+    let times: usize = 10;
+    stdin.write(&times);
+
+    let mut expected = Vec::with_capacity(times);
+    for _ in 0..times {
+        let key: Vec<u8> = (0..C::key_size()).map(|_| rand::random()).collect();
+        let num_blocks = rand::random::<usize>() % 8 + 1;
+        let blocks: Vec<[u8; 16]> = (0..num_blocks).map(|_| rand::random()).collect();
+
+        let cipher = C::new_from_slice(&key).unwrap();
+        stdin.write_vec(key);
+        stdin.write(&blocks);
+
+        let mut ciphertexts = blocks.clone();
+        for block in ciphertexts.iter_mut() {
+            cipher.encrypt_block(block.into());
+        }
+        expected.push(ciphertexts);
+    }
+    expected
+}
+
+#[monerochan_test::monerochan_test("aes128_patch_test", syscalls = [AES128_ENCRYPT_BLOCK], gpu, prove)]
+fn test_aes128_encrypt_blocks(
+    stdin: &mut monerochan::MONEROCHANStdin,
+) -> impl FnOnce(monerochan::MONEROCHANPublicValues) {
+    let expected = write_random_blocks::<aes::Aes128>(stdin);
+
+    move |mut public| {
+        for ciphertexts in expected {
+            let committed = public.read::<Vec<[u8; 16]>>();
+
+            assert_eq!(ciphertexts, committed);
+        }
+    }
+}
+
+#[monerochan_test::monerochan_test("aes256_patch_test", syscalls = [AES256_ENCRYPT_BLOCK], gpu, prove)]
+fn test_aes256_encrypt_blocks(
+    stdin: &mut monerochan::MONEROCHANStdin,
+) -> impl FnOnce(monerochan::MONEROCHANPublicValues) {
+    let expected = write_random_blocks::<aes::Aes256>(stdin);
+
+    move |mut public| {
+        for ciphertexts in expected {
+            let committed = public.read::<Vec<[u8; 16]>>();
+
+            assert_eq!(ciphertexts, committed);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+/// Converts a big endian field element to little endian words.
+fn to_words(bytes: &[u8]) -> Vec<u32> {
+    bytes.rchunks(4).map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap())).collect()
+}
+
+pub fn main() {
+    let times = monerochan_lib::io::read::<u8>();
+
+    for _ in 0..times {
+        let num_pairs = monerochan_lib::io::read::<u8>();
+
+        let mut pairs = Vec::new();
+        for _ in 0..num_pairs {
+            // Uncompressed points are `x || y` for G1 and `x.c1 || x.c0 || y.c1 || y.c0` for G2.
+            let p: Vec<u8> = monerochan_lib::io::read();
+            let q: Vec<u8> = monerochan_lib::io::read();
+
+            let p: [u32; 24] = [to_words(&p[..48]), to_words(&p[48..])].concat().try_into().unwrap();
+            let q: [u32; 48] = [
+                to_words(&q[48..96]),
+                to_words(&q[..48]),
+                to_words(&q[144..]),
+                to_words(&q[96..144]),
+            ]
+            .concat()
+            .try_into()
+            .unwrap();
+            pairs.push((p, q));
+        }
+
+        let result = monerochan_lib::pairing::bls12381_pairing_check(&pairs).unwrap();
+
+        monerochan_lib::io::commit(&result);
+    }
+}
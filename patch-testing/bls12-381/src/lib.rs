@@ -290,3 +290,47 @@ pub fn test_mul_fp2_100(stdin: &mut monerochan::MONEROCHANStdin) -> impl FnOnce(
         }
     }
 }
+
+#[monerochan_test::monerochan_test("bls12_381_pairing_check_test", syscalls = [BLS12381_FP2_ADD, BLS12381_FP2_SUB, BLS12381_FP2_MUL, BLS12381_FP12_MUL, BLS12381_G2_ADD, BLS12381_G2_DOUBLE], gpu, prove)]
+pub fn test_pairing_check_10(stdin: &mut monerochan::MONEROCHANStdin) -> impl FnOnce(monerochan::MONEROCHANPublicValues) {
+    use bls12_381::{multi_miller_loop, G1Affine, G2Affine, G2Prepared, Gt, Scalar};
+    use group::ff::Field;
+
+    let times: u8 = 10;
+    stdin.write(&times);
+
+    let mut unpatched_results: Vec<bool> = Vec::new();
+
+    while unpatched_results.len() < times as usize {
+        let a = Scalar::random(&mut rand::thread_rng());
+        let b = Scalar::random(&mut rand::thread_rng());
+
+        // Every other check is expected to fail.
+        let c = if unpatched_results.len() % 2 == 0 { a * b } else { a * b + Scalar::ONE };
+
+        let pairs = [
+            (G1Affine::from(G1Affine::generator() * a), G2Affine::from(G2Affine::generator() * b)),
+            (G1Affine::from(-(G1Affine::generator() * c)), G2Affine::generator()),
+        ];
+
+        stdin.write(&(pairs.len() as u8));
+        for (p, q) in pairs.iter() {
+            stdin.write(&p.to_uncompressed().to_vec());
+            stdin.write(&q.to_uncompressed().to_vec());
+        }
+
+        let prepared = pairs.iter().map(|(p, q)| (p, G2Prepared::from(*q))).collect::<Vec<_>>();
+        let terms = prepared.iter().map(|(p, q)| (*p, q)).collect::<Vec<_>>();
+        let result = multi_miller_loop(&terms).final_exponentiation() == Gt::identity();
+
+        unpatched_results.push(result);
+    }
+
+    |mut public| {
+        for res in unpatched_results {
+            let zk_res = public.read::<bool>();
+
+            assert_eq!(res, zk_res);
+        }
+    }
+}
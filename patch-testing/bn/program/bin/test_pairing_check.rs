@@ -0,0 +1,32 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+/// Converts a big endian field element to little endian words.
+fn to_words(bytes: &[u8]) -> Vec<u32> {
+    bytes.rchunks(4).map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap())).collect()
+}
+
+pub fn main() {
+    let times = monerochan_lib::io::read::<u8>();
+
+    for _ in 0..times {
+        let num_pairs = monerochan_lib::io::read::<u8>();
+
+        let mut pairs = Vec::new();
+        for _ in 0..num_pairs {
+            // The G1 point `[x, y]` and the G2 point `[x.real, x.imaginary, y.real, y.imaginary]`.
+            let p: Vec<Vec<u8>> = monerochan_lib::io::read();
+            let q: Vec<Vec<u8>> = monerochan_lib::io::read();
+
+            let p: [u32; 16] =
+                p.iter().flat_map(|c| to_words(c)).collect::<Vec<_>>().try_into().unwrap();
+            let q: [u32; 32] =
+                q.iter().flat_map(|c| to_words(c)).collect::<Vec<_>>().try_into().unwrap();
+            pairs.push((p, q));
+        }
+
+        let result = monerochan_lib::pairing::bn254_pairing_check(&pairs).unwrap();
+
+        monerochan_lib::io::commit(&result);
+    }
+}
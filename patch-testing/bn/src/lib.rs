@@ -197,3 +197,58 @@ pub fn test_bn_test_g1_double_100(
 
     |_| {}
 }
+
+#[monerochan_test::monerochan_test("bn_test_pairing_check", syscalls = [BN254_FP2_ADD, BN254_FP2_SUB, BN254_FP2_MUL, BN254_FP12_MUL, BN254_G2_ADD, BN254_G2_DOUBLE], gpu, prove)]
+pub fn test_bn_test_pairing_check_10(
+    stdin: &mut monerochan::MONEROCHANStdin,
+) -> impl FnOnce(monerochan::MONEROCHANPublicValues) {
+    use substrate_bn::{pairing_batch, AffineG1, AffineG2, Fq, Fr, Group, Gt, G1, G2};
+
+    let rng = &mut rand::thread_rng();
+
+    let times: u8 = 10;
+    stdin.write(&times);
+
+    let to_bytes = |x: Fq| {
+        let mut bytes = [0u8; 32];
+        x.to_big_endian(&mut bytes).unwrap();
+        bytes.to_vec()
+    };
+
+    let mut unpatched_results: Vec<bool> = Vec::new();
+
+    while unpatched_results.len() < times as usize {
+        let a = Fr::random(rng);
+        let b = Fr::random(rng);
+
+        // Every other check is expected to fail.
+        let c = if unpatched_results.len() % 2 == 0 { a * b } else { a * b + Fr::one() };
+
+        let pairs = [(G1::one() * a, G2::one() * b), (-(G1::one() * c), G2::one())];
+
+        stdin.write(&(pairs.len() as u8));
+        for (p, q) in pairs.iter() {
+            let p = AffineG1::from_jacobian(*p).unwrap();
+            let q = AffineG2::from_jacobian(*q).unwrap();
+            stdin.write(&vec![to_bytes(p.x()), to_bytes(p.y())]);
+            stdin.write(&vec![
+                to_bytes(q.x().real()),
+                to_bytes(q.x().imaginary()),
+                to_bytes(q.y().real()),
+                to_bytes(q.y().imaginary()),
+            ]);
+        }
+
+        let result = pairing_batch(&pairs) == Gt::one();
+
+        unpatched_results.push(result);
+    }
+
+    |mut public| {
+        for res in unpatched_results {
+            let zk_res = public.read::<bool>();
+
+            assert_eq!(res, zk_res);
+        }
+    }
+}
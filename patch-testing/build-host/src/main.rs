@@ -1,4 +1,6 @@
+pub use aes_patched;
 pub use bls12_381_patched;
+pub use chacha20_patched;
 pub use crypto_bigint_patched;
 pub use curve25519_dalek_ng_patched;
 pub use curve25519_dalek_patched;
@@ -0,0 +1,23 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use chacha20::{
+    cipher::{KeyIvInit, StreamCipher},
+    ChaCha20,
+};
+
+/// Emits CHACHA20_BLOCK syscalls.
+pub fn main() {
+    let times = monerochan_runtime::io::read::<usize>();
+
+    for _ in 0..times {
+        let key = monerochan_runtime::io::read::<[u8; 32]>();
+        let nonce = monerochan_runtime::io::read::<[u8; 12]>();
+        let mut data = monerochan_runtime::io::read_vec();
+
+        let mut cipher = ChaCha20::new(&key.into(), &nonce.into());
+        cipher.apply_keystream(&mut data);
+
+        monerochan_runtime::io::commit(&data);
+    }
+}
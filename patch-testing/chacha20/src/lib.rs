@@ -0,0 +1,36 @@
+#[monerochan_test::monerochan_test("chacha20_patch_test", syscalls = [CHACHA20_BLOCK], gpu, prove)]
+fn test_chacha20_apply_keystream(
+    stdin: &mut monerochan::MONEROCHANStdin,
+) -> impl FnOnce(monerochan::MONEROCHANPublicValues) {
+    use chacha20::{
+        cipher::{KeyIvInit, StreamCipher},
+        ChaCha20,
+    };
+
+    let times: usize = 10;
+    stdin.write(&times);
+
+    let mut ciphertexts = Vec::with_capacity(times);
+    for _ in 0..times {
+        let key: [u8; 32] = rand::random();
+        let nonce: [u8; 12] = rand::random();
+        let data_len = rand::random::<usize>() % 1024;
+        let data: Vec<u8> = (0..data_len).map(|_| rand::random::<u8>()).collect();
+
+        stdin.write(&key);
+        stdin.write(&nonce);
+        stdin.write_vec(data.clone());
+
+        let mut ciphertext = data;
+        ChaCha20::new(&key.into(), &nonce.into()).apply_keystream(&mut ciphertext);
+        ciphertexts.push(ciphertext);
+    }
+
+    move |mut public| {
+        for ciphertext in ciphertexts {
+            let committed = public.read::<Vec<u8>>();
+
+            assert_eq!(ciphertext, committed);
+        }
+    }
+}
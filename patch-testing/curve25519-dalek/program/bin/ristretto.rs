@@ -0,0 +1,30 @@
+#![no_main]
+monerochan_runtime::entrypoint!(main);
+
+use curve25519_dalek::ristretto::CompressedRistretto;
+use curve25519_dalek::scalar::Scalar;
+
+/// Emits ED_ADD syscalls through the ristretto group law.
+fn main() {
+    let times: usize = monerochan_runtime::io::read();
+
+    for _ in 0..times {
+        let bytes1: [u8; 32] = monerochan_runtime::io::read();
+        let bytes2: [u8; 32] = monerochan_runtime::io::read();
+        let scalar: [u8; 32] = monerochan_runtime::io::read();
+
+        let point1 = CompressedRistretto(bytes1).decompress();
+        let point2 = CompressedRistretto(bytes2).decompress();
+
+        let result = match (point1, point2) {
+            (Some(point1), Some(point2)) => {
+                let scalar = Scalar::from_bytes_mod_order(scalar);
+                let sum = point1 + point2;
+                assert_eq!(sum - point2, point1);
+                Some((sum * scalar).compress().to_bytes())
+            }
+            _ => None,
+        };
+        monerochan_runtime::io::commit(&result);
+    }
+}
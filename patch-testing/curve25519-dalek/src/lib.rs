@@ -190,3 +190,45 @@ fn test_zero_mul(_stdin: &mut monerochan::MONEROCHANStdin) -> impl FnOnce(monero
 
     move |_| {}
 }
+
+#[monerochan_test::monerochan_test("curve25519_ristretto", syscalls = [ED_ADD])]
+fn test_ristretto_add_then_multiply(
+    stdin: &mut monerochan::MONEROCHANStdin,
+) -> impl FnOnce(monerochan::MONEROCHANPublicValues) {
+    use curve25519_dalek::{
+        ristretto::{CompressedRistretto, RistrettoPoint},
+        scalar::Scalar,
+    };
+
+    let times = monerochan_test::DEFAULT_CORPUS_COUNT as usize;
+    stdin.write(&times);
+
+    let mut result_vec = Vec::with_capacity(times);
+    for i in 0..times {
+        let point1 = RistrettoPoint::random(&mut rand::thread_rng());
+        let point2 = RistrettoPoint::random(&mut rand::thread_rng());
+        let scalar = rand::random::<[u8; 32]>();
+
+        let bytes1 = point1.compress().to_bytes();
+        let mut bytes2 = point2.compress().to_bytes();
+        // Every tenth pair gets a corrupted second encoding, which is usually invalid.
+        if i % 10 == 0 {
+            bytes2[0] ^= 1 << 2;
+        }
+        stdin.write(&bytes1);
+        stdin.write(&bytes2);
+        stdin.write(&scalar);
+
+        let result = CompressedRistretto(bytes2).decompress().map(|point2| {
+            let scalar = Scalar::from_bytes_mod_order(scalar);
+            ((point1 + point2) * scalar).compress().to_bytes()
+        });
+        result_vec.push(result);
+    }
+
+    move |mut public| {
+        for expected_result in result_vec {
+            assert_eq!(public.read::<Option<[u8; 32]>>(), expected_result);
+        }
+    }
+}